@@ -0,0 +1,60 @@
+import {benchmark, report} from "@stablelib/benchmark";
+import {
+    bbsGenerateKeyPair,
+    bbsGenerateSignatureParams,
+    bbsPublicKeyHandle,
+    bbsSigParamsHandle,
+    bbsSign,
+    bbsVerify,
+    bbsVerifyWithHandles,
+    boundCheckSnarkSetup,
+    generateBoundCheckLegoProverStatement,
+    generateBoundCheckWitness,
+    generateCompositeProofG1,
+    generateCompositeProofG1WithHandle,
+    generateFieldElementFromNumber,
+    generateProofSpecG1,
+    initializeWasm,
+    proofSpecG1Handle,
+} from "../lib";
+import {generateMessages} from "./helper";
+
+// Functions taking handles clone the deserialized object as the underlying libraries take it by value. Comparing
+// them with the functions taking bytes shows the cost of cloning is small against deserializing.
+export const benchmarkHandles = async (
+    numberOfMessages: number
+): Promise<void> => {
+    await initializeWasm();
+
+    const sigParams = bbsGenerateSignatureParams(numberOfMessages);
+    const keypair = bbsGenerateKeyPair(sigParams);
+    const messages = generateMessages(numberOfMessages, 100);
+    const signature = bbsSign(messages, keypair.secret_key, sigParams, true);
+    const paramsHandle = bbsSigParamsHandle(sigParams);
+    const pkHandle = bbsPublicKeyHandle(keypair.public_key);
+    report(
+        `BBS Verify ${numberOfMessages} message(s)`,
+        benchmark(() => bbsVerify(messages, signature, keypair.public_key, sigParams, true))
+    );
+    report(
+        `BBS Verify ${numberOfMessages} message(s) with handles`,
+        benchmark(() => bbsVerifyWithHandles(messages, signature, pkHandle, paramsHandle, true))
+    );
+    paramsHandle.free();
+    pkHandle.free();
+
+    const snarkPk = boundCheckSnarkSetup(false);
+    const statements = [generateBoundCheckLegoProverStatement(10, 100, snarkPk, false)];
+    const proofSpec = generateProofSpecG1(statements, [], []);
+    const specHandle = proofSpecG1Handle(statements, [], []);
+    const witnesses = [generateBoundCheckWitness(generateFieldElementFromNumber(50))];
+    report(
+        'Bound check proof',
+        benchmark(() => generateCompositeProofG1(proofSpec, witnesses))
+    );
+    report(
+        'Bound check proof with proof spec handle',
+        benchmark(() => generateCompositeProofG1WithHandle(specHandle, witnesses))
+    );
+    specHandle.free();
+};
@@ -16,6 +16,7 @@ import { benchmarkBBSPlus } from "./bbs-plus";
 import { benchmarkPS } from "./ps";
 import { benchmarkBBS } from "./bbs";
 import { benchmarkBoundCheckSnark } from "./bound-check-snark";
+import { benchmarkHandles } from "./handles";
 
 // ------------------------------ Sign/Verify/CreateProof/VerifyProof 1, 100 byte message ------------------------------
 benchmarkBBS(1, 100, 0);
@@ -114,3 +115,5 @@ benchmarkPS(100, 1000, 60);
 // -------------------------------------------------------------------------------------------------------------------------
 
 benchmarkBoundCheckSnark();
+
+benchmarkHandles(100);
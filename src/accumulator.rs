@@ -1,5 +1,5 @@
 use crate::utils::{
-    fr_from_jsvalue, fr_from_uint8_array, fr_to_jsvalue, fr_to_uint8_array,
+    fr_from_jsvalue, fr_from_uint8_array, fr_to_jsvalue, fr_to_uint8_array, from_value,
     g1_affine_from_uint8_array, g1_affine_to_uint8_array, get_seeded_rng, js_array_from_frs,
     js_array_to_fr_vec, random_bytes, set_panic_hook,
 };
@@ -19,24 +19,28 @@ use vb_accumulator::prelude::{
 };
 use zeroize::Zeroize;
 
-use crate::Fr;
+use crate::{
+    native::{self, ErrorCode},
+    schnorr::ProofOfPossessionG2,
+    Fr,
+};
 
 // Trying to keep types at one place so changing the curve is easier
-pub(crate) type AccumSk = SecretKey<Fr>;
+pub type AccumSk = SecretKey<Fr>;
 pub type AccumPk = PublicKey<Bls12_381>;
 pub type AccumSetupParams = SetupParams<Bls12_381>;
-pub(crate) type AccumKeypair = Keypair<Bls12_381>;
-pub(crate) type PositiveAccum = PositiveAccumulator<Bls12_381>;
-pub(crate) type UniversalAccum = UniversalAccumulator<Bls12_381>;
-pub(crate) type MembershipWit = MembershipWitness<<Bls12_381 as Pairing>::G1Affine>;
-pub(crate) type NonMembershipWit = NonMembershipWitness<<Bls12_381 as Pairing>::G1Affine>;
-pub(crate) type Omega = Omega_<<Bls12_381 as Pairing>::G1Affine>;
+pub type AccumKeypair = Keypair<Bls12_381>;
+pub type PositiveAccum = PositiveAccumulator<Bls12_381>;
+pub type UniversalAccum = UniversalAccumulator<Bls12_381>;
+pub type MembershipWit = MembershipWitness<<Bls12_381 as Pairing>::G1Affine>;
+pub type NonMembershipWit = NonMembershipWitness<<Bls12_381 as Pairing>::G1Affine>;
+pub type Omega = Omega_<<Bls12_381 as Pairing>::G1Affine>;
 pub type MembershipPrk = MembershipProvingKey<<Bls12_381 as Pairing>::G1Affine>;
 pub type NonMembershipPrk = NonMembershipProvingKey<<Bls12_381 as Pairing>::G1Affine>;
-pub(crate) type MemProtocol = MembershipProofProtocol<Bls12_381>;
-pub(crate) type MemProof = MembershipProof<Bls12_381>;
-pub(crate) type NonMemProtocol = NonMembershipProofProtocol<Bls12_381>;
-pub(crate) type NonMemProof = NonMembershipProof<Bls12_381>;
+pub type MemProtocol = MembershipProofProtocol<Bls12_381>;
+pub type MemProof = MembershipProof<Bls12_381>;
+pub type NonMemProtocol = NonMembershipProofProtocol<Bls12_381>;
+pub type NonMemProof = NonMembershipProof<Bls12_381>;
 
 use crate::common::VerifyResponse;
 
@@ -45,9 +49,8 @@ use crate::common::VerifyResponse;
 #[wasm_bindgen(js_name = generateAccumulatorParams)]
 pub fn generate_accumulator_params(label: Option<Vec<u8>>) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let params = AccumSetupParams::new::<Blake2b512>(&label);
-    Ok(obj_to_uint8array!(&params, false, "SetupParams"))
+    let params = native::accumulator::generate_params(label.as_deref())?;
+    Ok(obj_to_uint8array!(&params, false, "AccumSetupParams"))
 }
 
 /// Check if parameters are valid. Before verifying witness or using for proof verification,
@@ -64,10 +67,7 @@ pub fn accumulator_is_params_valid(params: js_sys::Uint8Array) -> Result<bool, J
 #[wasm_bindgen(js_name = generateAccumulatorSecretKey)]
 pub fn accumulator_generate_secret_key(seed: Option<Vec<u8>>) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let mut seed = seed.unwrap_or_else(random_bytes);
-    let sk = AccumSk::generate_using_seed::<Blake2b512>(&seed);
-    seed.zeroize();
-
+    let sk = native::accumulator::generate_secret_key(seed.as_deref())?;
     serde_wasm_bindgen::to_value(&sk).map_err(JsValue::from)
 }
 
@@ -78,10 +78,10 @@ pub fn accumulator_generate_public_key(
     params: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let params = deserialize_params(params)?;
-    let pk = AccumKeypair::public_key_from_secret_key(&sk, &params);
-    Ok(obj_to_uint8array!(&pk, false, "PublicKeyG2"))
+    let pk = native::accumulator::generate_public_key(&sk, &params);
+    Ok(obj_to_uint8array!(&pk, false, "AccumPk"))
 }
 
 /// Check if public key is valid. Before verifying witness or using for proof verification,
@@ -93,6 +93,38 @@ pub fn accumulator_is_pubkey_valid(public_key: js_sys::Uint8Array) -> Result<boo
     Ok(pk.is_valid())
 }
 
+/// Proof of possession of the secret key of the public key generated by `generateAccumulatorPublicKey`, bound to
+/// `context` like the DID of the accumulator manager or a verifier's nonce
+#[wasm_bindgen(js_name = generateAccumulatorProofOfPossession)]
+pub fn accumulator_generate_proof_of_possession(
+    secret_key: JsValue,
+    params: js_sys::Uint8Array,
+    context: Vec<u8>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk: AccumSk = from_value(secret_key)?;
+    let params = deserialize_params(params)?;
+    let proof = native::accumulator::generate_proof_of_possession(&sk, &params, &context)?;
+    Ok(obj_to_uint8array!(&proof, false, "ProofOfPossession"))
+}
+
+/// Verify the proof of possession for the public key and `context`. This doesn't check that the public key is
+/// valid, `isAccumulatorPublicKeyValid` should be used for that.
+#[wasm_bindgen(js_name = verifyAccumulatorProofOfPossession)]
+pub fn accumulator_verify_proof_of_possession(
+    proof: js_sys::Uint8Array,
+    public_key: js_sys::Uint8Array,
+    params: js_sys::Uint8Array,
+    context: Vec<u8>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(ProofOfPossessionG2, proof, false, "ProofOfPossession");
+    let pk = deserialize_public_key(public_key)?;
+    let params = deserialize_params(params)?;
+    let result = native::accumulator::verify_proof_of_possession(&proof, &pk, &params, &context);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
 /// Generate private and public key from given params and optional `seed`.
 /// Pass the `seed` argument to generate keys deterministically.
 #[wasm_bindgen(js_name = generateAccumulatorKeyPair)]
@@ -102,8 +134,7 @@ pub fn accumulator_generate_keypair(
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
     let params = deserialize_params(params)?;
-    let seed = seed.unwrap_or(random_bytes());
-    let keypair = AccumKeypair::generate_using_seed::<Blake2b512>(&seed, &params);
+    let keypair = native::accumulator::generate_keypair(&params, seed.as_deref())?;
     serde_wasm_bindgen::to_value(&keypair).map_err(JsValue::from)
 }
 
@@ -122,7 +153,7 @@ pub fn accumulator_get_element_from_bytes(bytes: Vec<u8>) -> Result<JsValue, JsV
 pub fn positive_accumulator_initialize(params: js_sys::Uint8Array) -> Result<JsValue, JsValue> {
     set_panic_hook();
     let params = deserialize_params(params)?;
-    let accum = PositiveAccum::initialize(&params);
+    let accum = native::accumulator::positive_accumulator_initialize(&params);
     serde_wasm_bindgen::to_value(&accum).map_err(JsValue::from)
 }
 
@@ -130,7 +161,7 @@ pub fn positive_accumulator_initialize(params: js_sys::Uint8Array) -> Result<JsV
 #[wasm_bindgen(js_name = positiveAccumulatorGetAccumulated)]
 pub fn positive_accumulator_get_accumulated(accum: JsValue) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(accum)?;
+    let accum: PositiveAccum = from_value(accum)?;
     let a = g1_affine_to_uint8_array(accum.value())?;
     Ok(a)
 }
@@ -142,13 +173,11 @@ pub fn positive_accumulator_add(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: PositiveAccum = from_value(existing_accum)?;
     let element = fr_from_uint8_array(element, true)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
-
-    let new_value = accum.compute_new_post_add(&element, &sk);
-
-    serde_wasm_bindgen::to_value(&PositiveAccum::from_value(new_value)).map_err(JsValue::from)
+    let sk: AccumSk = from_value(secret_key)?;
+    let accum = native::accumulator::positive_accumulator_add(&accum, &element, &sk);
+    serde_wasm_bindgen::to_value(&accum).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = positiveAccumulatorRemove)]
@@ -158,12 +187,11 @@ pub fn positive_accumulator_remove(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: PositiveAccum = from_value(existing_accum)?;
     let element = fr_from_uint8_array(element, true)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
-    let new_value = accum.compute_new_post_remove(&element, &sk);
-
-    serde_wasm_bindgen::to_value(&PositiveAccum::from_value(new_value)).map_err(JsValue::from)
+    let sk: AccumSk = from_value(secret_key)?;
+    let accum = native::accumulator::positive_accumulator_remove(&accum, &element, &sk);
+    serde_wasm_bindgen::to_value(&accum).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = positiveAccumulatorMembershipWitness)]
@@ -173,8 +201,12 @@ pub fn positive_accumulator_membership_witness(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(accum)?;
-    crate::get_membership_witness!(accum, element, secret_key)
+    let accum: PositiveAccum = from_value(accum)?;
+    let element = fr_from_uint8_array(element, true)?;
+    let sk: AccumSk = from_value(secret_key)?;
+    let witness =
+        native::accumulator::positive_accumulator_membership_witness(&accum, &element, &sk);
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = positiveAccumulatorVerifyMembership)]
@@ -187,8 +219,17 @@ pub fn positive_accumulator_verify_membership(
 ) -> Result<bool, JsValue> {
     set_panic_hook();
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
-    let accum = PositiveAccum::from_accumulated(accumulated);
-    crate::verify_membership!(accum, element, witness, public_key, params)
+    let element = fr_from_uint8_array(element, true)?;
+    let witness: MembershipWit = from_value(witness)?;
+    let pk = deserialize_public_key(public_key)?;
+    let params = deserialize_params(params)?;
+    Ok(native::accumulator::positive_accumulator_verify_membership(
+        accumulated,
+        &element,
+        &witness,
+        &pk,
+        &params,
+    ))
 }
 
 /// Creates the initial elements that depend on the order of the curve and thus can be considered fixed.
@@ -206,7 +247,7 @@ pub fn universal_accumulator_compute_initial_fv(
     secret_key: JsValue,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let initial_elements = js_array_to_fr_vec(&initial_elements)?;
     let f_v = UniversalAccum::compute_initial_f_V(&initial_elements, &sk);
     fr_to_uint8_array(&f_v)
@@ -234,7 +275,11 @@ pub fn universal_accumulator_initialize_given_f_v(
     set_panic_hook();
     let f_v = fr_from_uint8_array(f_v, true)?;
     let params = deserialize_params(params)?;
-    let accum = UniversalAccum::initialize_given_f_V(f_v, &params, max_size as u64);
+    let accum = native::accumulator::universal_accumulator_initialize_given_f_v(
+        f_v,
+        &params,
+        max_size as u64,
+    );
     serde_wasm_bindgen::to_value(&accum).map_err(JsValue::from)
 }
 
@@ -243,7 +288,7 @@ pub fn universal_accumulator_get_accumulated(
     accum: JsValue,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(accum)?;
+    let accum: UniversalAccum = from_value(accum)?;
     let a = g1_affine_to_uint8_array(accum.value())?;
     Ok(a)
 }
@@ -255,11 +300,11 @@ pub fn universal_accumulator_add(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: UniversalAccum = from_value(existing_accum)?;
     let element = fr_from_uint8_array(element, true)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
-    let (f_v, v) = accum.compute_new_post_add(&element, &sk);
-    serde_wasm_bindgen::to_value(&accum.get_updated(f_v, v)).map_err(JsValue::from)
+    let sk: AccumSk = from_value(secret_key)?;
+    let accum = native::accumulator::universal_accumulator_add(&accum, &element, &sk);
+    serde_wasm_bindgen::to_value(&accum).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = universalAccumulatorRemove)]
@@ -269,11 +314,11 @@ pub fn universal_accumulator_remove(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: UniversalAccum = from_value(existing_accum)?;
     let element = fr_from_uint8_array(element, true)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
-    let (f_v, v) = accum.compute_new_post_remove(&element, &sk);
-    serde_wasm_bindgen::to_value(&accum.get_updated(f_v, v)).map_err(JsValue::from)
+    let sk: AccumSk = from_value(secret_key)?;
+    let accum = native::accumulator::universal_accumulator_remove(&accum, &element, &sk);
+    serde_wasm_bindgen::to_value(&accum).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = universalAccumulatorMembershipWitness)]
@@ -283,8 +328,12 @@ pub fn universal_accumulator_membership_witness(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(accum)?;
-    crate::get_membership_witness!(accum, element, secret_key)
+    let accum: UniversalAccum = from_value(accum)?;
+    let element = fr_from_uint8_array(element, true)?;
+    let sk: AccumSk = from_value(secret_key)?;
+    let witness =
+        native::accumulator::universal_accumulator_membership_witness(&accum, &element, &sk);
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = universalAccumulatorVerifyMembership)]
@@ -297,9 +346,19 @@ pub fn universal_accumulator_verify_membership(
 ) -> Result<bool, JsValue> {
     set_panic_hook();
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
-    // Only care about accumulated
-    let accum = UniversalAccum::from_accumulated(accumulated);
-    crate::verify_membership!(accum, member, witness, public_key, params)
+    let member = fr_from_uint8_array(member, true)?;
+    let witness: MembershipWit = from_value(witness)?;
+    let pk = deserialize_public_key(public_key)?;
+    let params = deserialize_params(params)?;
+    Ok(
+        native::accumulator::universal_accumulator_verify_membership(
+            accumulated,
+            &member,
+            &witness,
+            &pk,
+            &params,
+        ),
+    )
 }
 
 #[wasm_bindgen(js_name = universalAccumulatorComputeD)]
@@ -338,22 +397,16 @@ pub fn universal_accumulator_non_membership_witness(
     params: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(accum)?;
+    let accum: UniversalAccum = from_value(accum)?;
     let element = fr_from_uint8_array(non_member, true)?;
     let d = fr_from_uint8_array(d, true)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let params = deserialize_params(params)?;
-    serde_wasm_bindgen::to_value(
-        &accum
-            .compute_non_membership_witness_given_d(d, &element, &sk, &params)
-            .map_err(|e| {
-                JsValue::from(&format!(
-                    "Evaluating compute_non_membership_witness_given_d returned error: {:?}",
-                    e
-                ))
-            })?,
+    let witness = native::accumulator::universal_accumulator_non_membership_witness(
+        &accum, d, &element, &sk, &params,
     )
-    .map_err(JsValue::from)
+    .map_err(|e| e.context("Evaluating compute_non_membership_witness_given_d returned error"))?;
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = universalAccumulatorVerifyNonMembership)]
@@ -366,13 +419,19 @@ pub fn universal_accumulator_verify_non_membership(
 ) -> Result<bool, JsValue> {
     set_panic_hook();
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
-    // Only care about accumulated
-    let accum = UniversalAccum::from_accumulated(accumulated);
     let non_member = fr_from_uint8_array(non_member, true)?;
-    let witness: NonMembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: NonMembershipWit = from_value(witness)?;
     let pk = deserialize_public_key(public_key)?;
     let params = deserialize_params(params)?;
-    Ok(accum.verify_non_membership(&non_member, &witness, &pk, &params))
+    Ok(
+        native::accumulator::universal_accumulator_verify_non_membership(
+            accumulated,
+            &non_member,
+            &witness,
+            &pk,
+            &params,
+        ),
+    )
 }
 
 #[wasm_bindgen(js_name = positiveAccumulatorAddBatch)]
@@ -382,9 +441,9 @@ pub fn positive_accumulator_add_batch(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: PositiveAccum = from_value(existing_accum)?;
     let elems = js_array_to_fr_vec(&elements)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let new_value = accum.compute_new_post_add_batch(&elems, &sk);
     serde_wasm_bindgen::to_value(&PositiveAccum::from_value(new_value)).map_err(JsValue::from)
 }
@@ -396,9 +455,9 @@ pub fn positive_accumulator_remove_batch(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: PositiveAccum = from_value(existing_accum)?;
     let elems = js_array_to_fr_vec(&elements)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let new_value = accum.compute_new_post_remove_batch(&elems, &sk);
     serde_wasm_bindgen::to_value(&PositiveAccum::from_value(new_value)).map_err(JsValue::from)
 }
@@ -411,10 +470,10 @@ pub fn positive_accumulator_batch_updates(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: PositiveAccum = from_value(existing_accum)?;
     let adds = js_array_to_fr_vec(&additions)?;
     let removes = js_array_to_fr_vec(&removals)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let new_value = accum.compute_new_post_batch_updates(&adds, &removes, &sk);
     serde_wasm_bindgen::to_value(&PositiveAccum::from_value(new_value)).map_err(JsValue::from)
 }
@@ -426,7 +485,7 @@ pub fn positive_accumulator_membership_witnesses_for_batch(
     secret_key: JsValue,
 ) -> Result<js_sys::Array, JsValue> {
     set_panic_hook();
-    let accum: PositiveAccum = serde_wasm_bindgen::from_value(accum)?;
+    let accum: PositiveAccum = from_value(accum)?;
     crate::get_membership_witnesses_for_batch!(accum, elements, secret_key)
 }
 
@@ -437,9 +496,9 @@ pub fn universal_accumulator_add_batch(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: UniversalAccum = from_value(existing_accum)?;
     let elems = js_array_to_fr_vec(&elements)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let (f_v, v) = accum.compute_new_post_add_batch(&elems, &sk);
     serde_wasm_bindgen::to_value(&UniversalAccum::from_value(f_v, v, accum.max_size()))
         .map_err(JsValue::from)
@@ -452,9 +511,9 @@ pub fn universal_accumulator_remove_batch(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: UniversalAccum = from_value(existing_accum)?;
     let elems = js_array_to_fr_vec(&elements)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let (f_v, v) = accum.compute_new_post_remove_batch(&elems, &sk);
     serde_wasm_bindgen::to_value(&UniversalAccum::from_value(f_v, v, accum.max_size()))
         .map_err(JsValue::from)
@@ -468,10 +527,10 @@ pub fn universal_accumulator_batch_updates(
     secret_key: JsValue,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(existing_accum)?;
+    let accum: UniversalAccum = from_value(existing_accum)?;
     let adds = js_array_to_fr_vec(&additions)?;
     let removes = js_array_to_fr_vec(&removals)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let (f_v, v) = accum.compute_new_post_batch_updates(&adds, &removes, &sk);
     serde_wasm_bindgen::to_value(&UniversalAccum::from_value(f_v, v, accum.max_size()))
         .map_err(JsValue::from)
@@ -484,7 +543,7 @@ pub fn universal_accumulator_membership_witnesses_for_batch(
     secret_key: JsValue,
 ) -> Result<js_sys::Array, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(accum)?;
+    let accum: UniversalAccum = from_value(accum)?;
     crate::get_membership_witnesses_for_batch!(accum, elements, secret_key)
 }
 
@@ -539,18 +598,17 @@ pub fn universal_accumulator_non_membership_witnesses_for_batch(
     params: js_sys::Uint8Array,
 ) -> Result<js_sys::Array, JsValue> {
     set_panic_hook();
-    let accum: UniversalAccum = serde_wasm_bindgen::from_value(accum)?;
+    let accum: UniversalAccum = from_value(accum)?;
     let d = js_array_to_fr_vec(&d)?;
     let non_members = js_array_to_fr_vec(&non_members)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let params = deserialize_params(params)?;
     let witnesses = accum
         .compute_non_membership_witness_for_batch_given_d(d, &non_members, &sk, &params)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating compute_non_membership_witness_for_batch_given_d returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context(
+                "Evaluating compute_non_membership_witness_for_batch_given_d returned error",
+            )
         })?;
     let result = js_sys::Array::new();
     for witness in witnesses {
@@ -567,7 +625,7 @@ pub fn update_membership_witness_post_add(
     old_accumulated: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: MembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: MembershipWit = from_value(witness)?;
     crate::update_witness_post_add!(witness, member, addition, old_accumulated)
 }
 
@@ -579,7 +637,7 @@ pub fn update_membership_witness_post_remove(
     new_accumulated: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: MembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: MembershipWit = from_value(witness)?;
     crate::update_witness_post_remove!(witness, member, removal, new_accumulated)
 }
 
@@ -591,7 +649,7 @@ pub fn update_non_membership_witness_post_add(
     old_accumulated: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: NonMembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: NonMembershipWit = from_value(witness)?;
     crate::update_witness_post_add!(witness, non_member, addition, old_accumulated)
 }
 
@@ -603,7 +661,7 @@ pub fn update_non_membership_witness_post_remove(
     new_accumulated: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: NonMembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: NonMembershipWit = from_value(witness)?;
     crate::update_witness_post_remove!(witness, non_member, removal, new_accumulated)
 }
 
@@ -660,7 +718,7 @@ pub fn public_info_for_witness_update(
     let existing_accumulated = g1_affine_from_uint8_array(old_accumulated)?;
     let additions = js_array_to_fr_vec(&additions)?;
     let removals = js_array_to_fr_vec(&removals)?;
-    let sk: AccumSk = serde_wasm_bindgen::from_value(secret_key)?;
+    let sk: AccumSk = from_value(secret_key)?;
     let omega = Omega::new(&additions, &removals, &existing_accumulated, &sk);
     Ok(obj_to_uint8array!(&omega, false, "Omega"))
 }
@@ -674,7 +732,7 @@ pub fn update_membership_witness_using_public_info_after_batch_update(
     public_info: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: MembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: MembershipWit = from_value(witness)?;
     crate::update_witness_single_batch!(witness, member, additions, removals, public_info)
 }
 
@@ -687,7 +745,7 @@ pub fn update_membership_witness_using_public_info_after_multiple_batch_updates(
     public_info: js_sys::Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: MembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: MembershipWit = from_value(witness)?;
     crate::update_witness_multiple_batches!(witness, member, additions, removals, public_info)
 }
 
@@ -700,7 +758,7 @@ pub fn update_non_membership_witness_using_public_info_after_batch_update(
     public_info: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: NonMembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: NonMembershipWit = from_value(witness)?;
     crate::update_witness_single_batch!(witness, non_member, additions, removals, public_info)
 }
 
@@ -713,7 +771,7 @@ pub fn update_non_membership_witness_using_public_info_after_multiple_batch_upda
     public_info: js_sys::Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let witness: NonMembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: NonMembershipWit = from_value(witness)?;
     crate::update_witness_multiple_batches!(witness, non_member, additions, removals, public_info)
 }
 
@@ -722,7 +780,7 @@ pub fn generate_membership_proving_key(
     label: Option<Vec<u8>>,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
+    let label = label.map_or_else(random_bytes, Ok)?;
     let prk = MembershipPrk::new::<Blake2b512>(&label);
     Ok(obj_to_uint8array!(&prk, false, "MembershipProvingKey"))
 }
@@ -732,7 +790,7 @@ pub fn generate_non_membership_proving_key(
     label: Option<Vec<u8>>,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
+    let label = label.map_or_else(random_bytes, Ok)?;
     let prk = NonMembershipPrk::new::<Blake2b512>(&label);
     Ok(obj_to_uint8array!(&prk, false, "NonMembershipProvingKey"))
 }
@@ -762,7 +820,7 @@ pub fn accumulator_initialize_membership_proof(
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
 
-    let witness: MembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: MembershipWit = from_value(witness)?;
     let prk = obj_from_uint8array!(MembershipPrk, proving_key, false);
     crate::init_proof_protocol!(
         MemProtocol,
@@ -781,7 +839,7 @@ pub fn accumulator_gen_membership_proof(
     challenge: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let protocol: MemProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: MemProtocol = from_value(protocol)?;
     let challenge = fr_from_uint8_array(challenge, false)?;
     serde_wasm_bindgen::to_value(&protocol.gen_proof(&challenge)).map_err(JsValue::from)
 }
@@ -796,7 +854,7 @@ pub fn accumulator_verify_membership_proof(
     proving_key: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let proof: MemProof = serde_wasm_bindgen::from_value(proof)?;
+    let proof: MemProof = from_value(proof)?;
     let prk = obj_from_uint8array!(MembershipPrk, proving_key, false, "MembershipPrk");
     crate::verify_proof!(proof, accumulated, challenge, public_key, params, prk)
 }
@@ -810,7 +868,7 @@ pub fn accumulator_challenge_contribution_from_membership_protocol(
     proving_key: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let protocol: MemProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: MemProtocol = from_value(protocol)?;
     let prk = obj_from_uint8array!(MembershipPrk, proving_key, false, "MembershipPrk");
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
     let pk = deserialize_public_key(public_key)?;
@@ -819,10 +877,7 @@ pub fn accumulator_challenge_contribution_from_membership_protocol(
     protocol
         .challenge_contribution(&accumulated, &pk, &params, &prk, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -836,7 +891,7 @@ pub fn accumulator_challenge_contribution_from_membership_proof(
     proving_key: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let proof: MemProof = serde_wasm_bindgen::from_value(proof)?;
+    let proof: MemProof = from_value(proof)?;
     let prk = obj_from_uint8array!(MembershipPrk, proving_key, false, "MembershipPrk");
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
     let pk = deserialize_public_key(public_key)?;
@@ -845,10 +900,7 @@ pub fn accumulator_challenge_contribution_from_membership_proof(
     proof
         .challenge_contribution(&accumulated, &pk, &params, &prk, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -864,7 +916,7 @@ pub fn accumulator_initialize_non_membership_proof(
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
 
-    let witness: NonMembershipWit = serde_wasm_bindgen::from_value(witness)?;
+    let witness: NonMembershipWit = from_value(witness)?;
     let prk = obj_from_uint8array!(NonMembershipPrk, proving_key, false, "NonMembershipPrk");
     crate::init_proof_protocol!(
         NonMemProtocol,
@@ -883,7 +935,7 @@ pub fn accumulator_gen_non_membership_proof(
     challenge: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let protocol: NonMemProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: NonMemProtocol = from_value(protocol)?;
     let challenge = fr_from_uint8_array(challenge, false)?;
     serde_wasm_bindgen::to_value(&protocol.gen_proof(&challenge)).map_err(JsValue::from)
 }
@@ -898,7 +950,7 @@ pub fn accumulator_verify_non_membership_proof(
     proving_key: js_sys::Uint8Array,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let proof: NonMemProof = serde_wasm_bindgen::from_value(proof)?;
+    let proof: NonMemProof = from_value(proof)?;
     let prk = obj_from_uint8array!(NonMembershipPrk, proving_key, false, "NonMembershipPrk");
     crate::verify_proof!(proof, accumulated, challenge, public_key, params, prk)
 }
@@ -912,7 +964,7 @@ pub fn accumulator_challenge_contribution_from_non_membership_protocol(
     proving_key: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let protocol: NonMemProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: NonMemProtocol = from_value(protocol)?;
     let prk = obj_from_uint8array!(NonMembershipPrk, proving_key, false, "NonMembershipPrk");
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
     let pk = deserialize_public_key(public_key)?;
@@ -921,10 +973,7 @@ pub fn accumulator_challenge_contribution_from_non_membership_protocol(
     protocol
         .challenge_contribution(&accumulated, &pk, &params, &prk, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -938,7 +987,7 @@ pub fn accumulator_challenge_contribution_from_non_membership_proof(
     proving_key: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let proof: NonMemProof = serde_wasm_bindgen::from_value(proof)?;
+    let proof: NonMemProof = from_value(proof)?;
     let prk = obj_from_uint8array!(NonMembershipPrk, proving_key, false, "NonMembershipPrk");
     let accumulated = g1_affine_from_uint8_array(accumulated)?;
     let pk = deserialize_public_key(public_key)?;
@@ -947,49 +996,46 @@ pub fn accumulator_challenge_contribution_from_non_membership_proof(
     proof
         .challenge_contribution(&accumulated, &pk, &params, &prk, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
 
 pub(crate) fn deserialize_params(bytes: js_sys::Uint8Array) -> Result<AccumSetupParams, JsValue> {
-    ark_serialize::CanonicalDeserialize::deserialize_compressed(&bytes.to_vec()[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Failed to deserialize accumulator params from bytes due to error: {:?}",
-            e
-        ))
-    })
+    let bytes = bytes.to_vec();
+    native::envelope::open(&bytes, Some("AccumSetupParams"), true)
+        .and_then(|b| {
+            Ok(ark_serialize::CanonicalDeserialize::deserialize_compressed(
+                b,
+            )?)
+        })
+        .map_err(|e| {
+            e.context("Failed to deserialize accumulator params from bytes")
+                .into()
+        })
 }
 
 pub(crate) fn deserialize_public_key(bytes: js_sys::Uint8Array) -> Result<AccumPk, JsValue> {
-    ark_serialize::CanonicalDeserialize::deserialize_compressed(&bytes.to_vec()[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Failed to deserialize accumulator public key from bytes due to error: {:?}",
-            e
-        ))
-    })
+    let bytes = bytes.to_vec();
+    native::envelope::open(&bytes, Some("AccumPk"), true)
+        .and_then(|b| {
+            Ok(ark_serialize::CanonicalDeserialize::deserialize_compressed(
+                b,
+            )?)
+        })
+        .map_err(|e| {
+            e.context("Failed to deserialize accumulator public key from bytes")
+                .into()
+        })
 }
 
 #[macro_use]
 mod macros {
-    #[macro_export]
-    macro_rules! get_membership_witness {
-        ($accum: ident, $element: ident, $sk: ident) => {{
-            let element = fr_from_uint8_array($element, true)?;
-            let sk: AccumSk = serde_wasm_bindgen::from_value($sk)?;
-            let new_value = $accum.compute_membership_witness(&element, &sk);
-            serde_wasm_bindgen::to_value(&new_value).map_err(JsValue::from)
-        }};
-    }
-
     #[macro_export]
     macro_rules! get_membership_witnesses_for_batch {
         ($accum: ident, $elements: ident, $sk: ident) => {{
             let elems = js_array_to_fr_vec(&$elements)?;
-            let sk: AccumSk = serde_wasm_bindgen::from_value($sk)?;
+            let sk: AccumSk = from_value($sk)?;
             let witnesses = $accum.compute_membership_witness_for_batch(&elems, &sk);
 
             let result = js_sys::Array::new();
@@ -1000,17 +1046,6 @@ mod macros {
         }};
     }
 
-    #[macro_export]
-    macro_rules! verify_membership {
-        ($accum: ident, $element: ident, $witness: ident, $pk: ident, $params: ident) => {{
-            let element = fr_from_uint8_array($element, true)?;
-            let witness: MembershipWit = serde_wasm_bindgen::from_value($witness)?;
-            let pk = deserialize_public_key($pk)?;
-            let params = deserialize_params($params)?;
-            Ok($accum.verify_membership(&element, &witness, &pk, &params))
-        }};
-    }
-
     #[macro_export]
     macro_rules! update_witness_post_add {
         ($witness:expr, $element: ident, $addition: ident, $old_accumulated: ident) => {{
@@ -1035,10 +1070,7 @@ mod macros {
             let new_wit = $witness
                 .update_after_removal(&element, &removal, &new_accumulated)
                 .map_err(|e| {
-                    JsValue::from(&format!(
-                        "Evaluating update_after_removal returned error: {:?}",
-                        e
-                    ))
+                    native::Error::from(e).context("Evaluating update_after_removal returned error")
                 })?;
             serde_wasm_bindgen::to_value(&new_wit).map_err(JsValue::from)
         }};
@@ -1050,22 +1082,26 @@ mod macros {
             let element = fr_from_uint8_array($element, true)?;
             let additions = js_array_to_fr_vec(&$additions)?;
             let removals = js_array_to_fr_vec(&$removals)?;
-            let public_info: Omega = ark_serialize::CanonicalDeserialize::deserialize_compressed(&$public_info.to_vec()[..]).map_err(|e| {
-                JsValue::from(&format!(
-                    "Failed to deserialize public info from bytes due to error: {:?}",
-                    e
-                ))
+            let public_info: Omega = ark_serialize::CanonicalDeserialize::deserialize_compressed(
+                &$public_info.to_vec()[..],
+            )
+            .map_err(|e| {
+                native::Error::from(e).context("Failed to deserialize public info from bytes")
             })?;
             let new_witness = $witness
-                .update_using_public_info_after_batch_updates(&additions, &removals, &public_info, &element)
+                .update_using_public_info_after_batch_updates(
+                    &additions,
+                    &removals,
+                    &public_info,
+                    &element,
+                )
                 .map_err(|e| {
-                    JsValue::from(&format!(
-                        "Evaluating update_using_public_info_after_batch_updates returned error: {:?}",
-                        e
-                    ))
+                    native::Error::from(e).context(
+                        "Evaluating update_using_public_info_after_batch_updates returned error",
+                    )
                 })?;
             serde_wasm_bindgen::to_value(&new_witness).map_err(JsValue::from)
-        }}
+        }};
     }
 
     #[macro_export]
@@ -1078,27 +1114,21 @@ mod macros {
                 for i in 0..size {
                     let adds = js_array_to_fr_vec(&js_sys::Array::from(&$additions.get(i)))?;
                     let rems = js_array_to_fr_vec(&js_sys::Array::from(&$removals.get(i)))?;
-                    let bytes: Vec<u8> = serde_wasm_bindgen::from_value($public_info.get(i))?;
-                    let p: Omega = ark_serialize::CanonicalDeserialize::deserialize_compressed(&bytes[..]).map_err(|e| JsValue::from(&format!(
-                            "Failed to deserialize public info from bytes due to error: {:?}",
-                            e
-                        )))?;
+                    let bytes: Vec<u8> = from_value($public_info.get(i))?;
+                    let p: Omega = ark_serialize::CanonicalDeserialize::deserialize_compressed(&bytes[..]).map_err(|e| native::Error::from(e).context("Failed to deserialize public info from bytes"))?;
                     updates_and_public_info.push((adds, rems, p));
                 }
                 let new_witness = $witness.update_using_public_info_after_multiple_batch_updates(updates_and_public_info.iter().map(|(a, r, p)| (a.as_slice(), r.as_slice(), p)).collect::<Vec<_>>(), &element)
                 .map_err(|e| {
-                    JsValue::from(&format!(
-                        "Evaluating update_using_public_info_after_multiple_batch_updates returned error: {:?}",
-                        e
-                    ))
+                    native::Error::from(e).context("Evaluating update_using_public_info_after_multiple_batch_updates returned error")
                 })?;
                 let w = serde_wasm_bindgen::to_value(&new_witness).map_err(JsValue::from)?;
                 Ok(w)
             } else {
-                Err(JsValue::from(&format!(
+                Err(native::Error::new(ErrorCode::MessageCountMismatch, format!(
                     "Expected same but found different lengths for additions, removals and public info: {} {} {}",
                     $additions.length(), $removals.length(), $public_info.length()
-                )))
+                )).into())
             }
         }}
     }
@@ -1111,7 +1141,7 @@ mod macros {
             let pk = deserialize_public_key($public_key)?;
             let params = deserialize_params($params)?;
 
-            let mut rng = get_seeded_rng();
+            let mut rng = get_seeded_rng()?;
             let protocol = $protocol::init(
                 &mut rng,
                 &element,
@@ -1133,18 +1163,10 @@ mod macros {
             let pk = deserialize_public_key($public_key)?;
             let params = deserialize_params($params)?;
 
-            match $proof.verify(&accumulated, &challenge, pk.clone(), params.clone(), &$prk) {
-                Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-                    verified: true,
-                    error: None,
-                })
-                .unwrap()),
-                Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-                    verified: false,
-                    error: Some(format!("{:?}", e)),
-                })
-                .unwrap()),
-            }
+            let result = $proof
+                .verify(&accumulated, &challenge, pk.clone(), params.clone(), &$prk)
+                .map_err($crate::native::Error::from);
+            Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
         }};
     }
 
@@ -1155,10 +1177,10 @@ mod macros {
             let additions = js_array_to_fr_vec(&$additions)?;
             let removals = js_array_to_fr_vec(&$removals)?;
             let old_accumulated = g1_affine_from_uint8_array($old_accumulated)?;
-            let sk: AccumSk = serde_wasm_bindgen::from_value($secret_key)?;
+            let sk: AccumSk = from_value($secret_key)?;
             let mut wits = Vec::with_capacity($witnesses.length() as usize);
             for w in $witnesses.values() {
-                wits.push(serde_wasm_bindgen::from_value::<$wit_type>(w.unwrap())?);
+                wits.push(from_value::<$wit_type>(w.unwrap())?);
             }
             let new_wits = $wit_type::update_using_secret_key_after_batch_updates(
                 &additions,
@@ -1169,17 +1191,16 @@ mod macros {
                 &sk,
             )
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Evaluating update_using_secret_key_after_batch_updates returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e).context(
+                    "Evaluating update_using_secret_key_after_batch_updates returned error",
+                )
             })?;
             let result = js_sys::Array::new();
             for w in new_wits {
                 result.push(&serde_wasm_bindgen::to_value(&w).map_err(JsValue::from)?);
             }
             Ok(result)
-        }}
+        }};
     }
 }
 
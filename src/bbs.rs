@@ -1,21 +1,23 @@
 use crate::utils::{
-    fr_from_uint8_array, g1_affine_from_uint8_array, g1_affine_to_jsvalue,
-    g1_affine_to_uint8_array, get_seeded_rng, js_set_to_btree_set, random_bytes, set_panic_hook,
+    fr_from_uint8_array, from_value, g1_affine_from_uint8_array, g1_affine_to_jsvalue,
+    g1_affine_to_uint8_array, js_set_to_btree_set, set_panic_hook,
 };
 
-use bbs_plus::{proof::MessageOrBlinding, setup::MultiMessageSignatureParams};
+use bbs_plus::setup::MultiMessageSignatureParams;
 use wasm_bindgen::prelude::*;
 
 use crate::{
     common::VerifyResponse,
+    handles::{BbsPublicKeyHandle, BbsSigParamsHandle},
+    native::{self, ErrorCode},
+    schnorr::ProofOfPossessionG2,
     utils::{encode_messages_as_js_array_to_fr_vec, encode_messages_as_js_map_to_fr_btreemap},
     Fr, G1Affine,
 };
 use ark_bls12_381::Bls12_381;
-use ark_std::collections::BTreeMap;
 use bbs_plus::prelude::{
-    KeypairG2, PoKOfSignature23G1Proof, PoKOfSignature23G1Protocol, PublicKeyG2, SecretKey,
-    Signature23G1, SignatureParams23G1,
+    PoKOfSignature23G1Proof, PoKOfSignature23G1Protocol, PublicKeyG2, SecretKey, Signature23G1,
+    SignatureParams23G1,
 };
 use blake2::Blake2b512;
 use dock_crypto_utils::{concat_slices, hashing_utils::affine_group_elem_from_try_and_incr};
@@ -24,36 +26,35 @@ use zeroize::Zeroize;
 pub type BBSSecretKey = SecretKey<Fr>;
 pub type BBSSigParams = SignatureParams23G1<Bls12_381>;
 pub type BBSPublicKey = PublicKeyG2<Bls12_381>;
-pub(crate) type BBSSignature = Signature23G1<Bls12_381>;
-pub(crate) type BBSPoKOfSigProtocol = PoKOfSignature23G1Protocol<Bls12_381>;
-pub(crate) type BBSPoKOfSigProof = PoKOfSignature23G1Proof<Bls12_381>;
+pub type BBSSignature = Signature23G1<Bls12_381>;
+pub type BBSPoKOfSigProtocol = PoKOfSignature23G1Protocol<Bls12_381>;
+pub type BBSPoKOfSigProof = PoKOfSignature23G1Proof<Bls12_381>;
 
 #[wasm_bindgen(js_name = bbsGenerateSignatureParams)]
 pub fn bbs_generate_params(message_count: u32, label: Option<Vec<u8>>) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let params = BBSSigParams::new::<Blake2b512>(&label, message_count);
+    let params = native::bbs::generate_params(message_count, label.as_deref())?;
     serde_wasm_bindgen::to_value(&params).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = bbsIsSignatureParamsValid)]
 pub fn bbs_is_params_valid(params: JsValue) -> Result<bool, JsValue> {
     set_panic_hook();
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     Ok(params.is_valid())
 }
 
 #[wasm_bindgen(js_name = bbsSignatureParamsMaxSupportedMsgs)]
 pub fn bbs_params_max_supported_msgs(params: JsValue) -> Result<usize, JsValue> {
     set_panic_hook();
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     Ok(params.supported_message_count())
 }
 
 #[wasm_bindgen(js_name = bbsSignatureParamsToBytes)]
 pub fn bbs_params_to_bytes(params: JsValue) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     Ok(obj_to_uint8array!(&params, false, "BBSSigParams"))
 }
 
@@ -67,8 +68,7 @@ pub fn bbs_params_from_bytes(bytes: js_sys::Uint8Array) -> Result<JsValue, JsVal
 #[wasm_bindgen(js_name = bbsGenerateSigningKey)]
 pub fn bbs_generate_secret_key(seed: Option<Vec<u8>>) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let seed = seed.unwrap_or_else(random_bytes);
-    let sk = BBSSecretKey::generate_using_seed::<Blake2b512>(&seed);
+    let sk = native::bbs::generate_secret_key(seed.as_deref())?;
     Ok(obj_to_uint8array!(&sk, true, "BBSSecretKey"))
 }
 
@@ -79,8 +79,8 @@ pub fn bbs_generate_public_key(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let sk = obj_from_uint8array!(BBSSecretKey, secret_key, true, "BBSSecretKey");
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
-    let pk = BBSPublicKey::generate_using_secret_key_and_bbs23_params(&sk, &params);
+    let params: BBSSigParams = from_value(params)?;
+    let pk = native::bbs::generate_public_key(&sk, &params);
     Ok(obj_to_uint8array!(&pk, false, "BBSPublicKey"))
 }
 
@@ -91,13 +91,44 @@ pub fn bbs_is_pubkey_valid(public_key: js_sys::Uint8Array) -> Result<bool, JsVal
     Ok(pk.is_valid())
 }
 
+/// Proof of possession of the secret key of the public key generated by `bbsGeneratePublicKey`. `context` should
+/// be something like the DID of the key's owner or a nonce given by the verifier so that the proof can't be
+/// replayed for another key or registration.
+#[wasm_bindgen(js_name = bbsGenerateProofOfPossession)]
+pub fn bbs_generate_proof_of_possession(
+    secret_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSSecretKey, secret_key, true, "BBSSecretKey");
+    let params: BBSSigParams = from_value(params)?;
+    let proof = native::bbs::generate_proof_of_possession(&sk, &params, &context)?;
+    Ok(obj_to_uint8array!(&proof, false, "ProofOfPossession"))
+}
+
+/// Verify the proof of possession for the public key and `context`. This doesn't check that the public key is
+/// valid, `bbsIsPublicKeyValid` should be used for that.
+#[wasm_bindgen(js_name = bbsVerifyProofOfPossession)]
+pub fn bbs_verify_proof_of_possession(
+    proof: js_sys::Uint8Array,
+    public_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(ProofOfPossessionG2, proof, false, "ProofOfPossession");
+    let pk = obj_from_uint8array!(BBSPublicKey, public_key, false, "BBSPublicKey");
+    let params: BBSSigParams = from_value(params)?;
+    let result = native::bbs::verify_proof_of_possession(&proof, &pk, &params, &context);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
 #[wasm_bindgen(js_name = bbsGenerateKeyPair)]
 pub fn bbs_generate_keypair(params: JsValue, seed: Option<Vec<u8>>) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
-    let mut seed = seed.unwrap_or_else(random_bytes);
-    let keypair = KeypairG2::generate_using_seed_and_bbs23_params::<Blake2b512>(&seed, &params);
-    seed.zeroize();
+    let params: BBSSigParams = from_value(params)?;
+    let keypair = native::bbs::generate_keypair(&params, seed.as_deref())?;
     serde_wasm_bindgen::to_value(&keypair).map_err(JsValue::from)
 }
 
@@ -106,16 +137,17 @@ pub fn bbs_get_bases_for_commitment(
     params: JsValue,
     indices_to_commit: js_sys::Array,
 ) -> Result<js_sys::Array, JsValue> {
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let bases = js_sys::Array::new();
 
     for i in indices_to_commit.values() {
-        let index: usize = serde_wasm_bindgen::from_value(i.unwrap())?;
+        let index: usize = from_value(i.unwrap())?;
         if index >= params.supported_message_count() {
-            return Err(JsValue::from(&format!(
-                "Invalid index {:?} to get signature param",
-                index
-            )));
+            return Err(native::Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!("Invalid index {:?} to get signature param", index),
+            )
+            .into());
         }
         bases.push(&g1_affine_to_jsvalue(&params.h[index])?);
     }
@@ -130,16 +162,9 @@ pub fn bbs_commit_to_message(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&messages_to_commit, encode_messages)?;
-    let msgs_ref = msgs
-        .iter()
-        .map(|(i, m)| (*i, m))
-        .collect::<BTreeMap<_, _>>();
-
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
-    match params.commit_to_messages(msgs_ref) {
-        Ok(comm) => g1_affine_to_uint8_array(&comm),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let params: BBSSigParams = from_value(params)?;
+    let comm = native::bbs::commit_to_messages(&msgs, &params)?;
+    g1_affine_to_uint8_array(&comm)
 }
 
 #[wasm_bindgen(js_name = bbsSign)]
@@ -151,14 +176,25 @@ pub fn bbs_sign(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let sk = obj_from_uint8array!(BBSSecretKey, secret_key, true, "BBSSecretKey");
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    let sig = native::bbs::sign(&messages, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSSignature"))
+}
 
-    let mut rng = get_seeded_rng();
-    match BBSSignature::new(&mut rng, &messages, &sk, &params) {
-        Ok(sig) => Ok(obj_to_uint8array!(&sig, true, "BBSSignature")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+/// Same as `bbs_sign` but takes a handle to the params
+#[wasm_bindgen(js_name = bbsSignWithHandles)]
+pub fn bbs_sign_with_handles(
+    messages: js_sys::Array,
+    secret_key: js_sys::Uint8Array,
+    params: &BbsSigParamsHandle,
+    encode_messages: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSSecretKey, secret_key, true, "BBSSecretKey");
+    let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    let sig = native::bbs::sign(&messages, &sk, &params.0)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSSignature"))
 }
 
 #[wasm_bindgen(js_name = bbsBlindSign)]
@@ -172,18 +208,10 @@ pub fn bbs_blind_sign(
     set_panic_hook();
     let commitment = g1_affine_from_uint8_array(commitment)?;
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&uncommitted_messages, encode_messages)?;
-    let msgs_ref = msgs
-        .iter()
-        .map(|(i, m)| (*i, m))
-        .collect::<BTreeMap<_, _>>();
     let sk = obj_from_uint8array!(BBSSecretKey, secret_key, true, "BBSSecretKey");
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
-
-    let mut rng = get_seeded_rng();
-    match BBSSignature::new_with_committed_messages(&mut rng, &commitment, msgs_ref, &sk, &params) {
-        Ok(sig) => Ok(obj_to_uint8array!(&sig, true, "BBSSignature")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let params: BBSSigParams = from_value(params)?;
+    let sig = native::bbs::blind_sign(&commitment, &msgs, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSSignature"))
 }
 
 #[wasm_bindgen(js_name = bbsVerify)]
@@ -195,24 +223,35 @@ pub fn bbs_verify(
     encode_messages: bool,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    // let signature: SigG1 = serde_wasm_bindgen::from_value(signature)?;
+    // let signature: SigG1 = from_value(signature)?;
     let signature = obj_from_uint8array!(BBSSignature, signature, true);
     let pk = obj_from_uint8array!(BBSPublicKey, public_key, false, "BBSPublicKey");
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    let result = native::bbs::verify(&messages, &signature, pk, params);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
 
-    match signature.verify(messages.as_slice(), pk, params) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("{:?}", e)),
-        })
-        .unwrap()),
-    }
+/// Same as `bbs_verify` but takes handles to the public key and params
+#[wasm_bindgen(js_name = bbsVerifyWithHandles)]
+pub fn bbs_verify_with_handles(
+    messages: js_sys::Array,
+    signature: js_sys::Uint8Array,
+    public_key: &BbsPublicKeyHandle,
+    params: &BbsSigParamsHandle,
+    encode_messages: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let signature = obj_from_uint8array!(BBSSignature, signature, true);
+    let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    // Verification in `bbs_plus` takes the public key and params by value so they are cloned
+    let result = native::bbs::verify(
+        &messages,
+        &signature,
+        public_key.0.clone(),
+        params.0.clone(),
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
 
 #[wasm_bindgen(js_name = bbsInitializeProofOfKnowledgeOfSignature)]
@@ -227,28 +266,19 @@ pub fn bbs_initialize_proof_of_knowledge_of_signature(
     set_panic_hook();
 
     let signature = obj_from_uint8array!(BBSSignature, signature, true);
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     // TODO: Avoid this hack of passing false, create separate method to parse
-    let mut blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
+    let blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
-    let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices);
-    let msg_iter = messages.iter().enumerate().map(|(idx, message)| {
-        if revealed_indices.contains(&idx) {
-            MessageOrBlinding::RevealMessage(message)
-        } else if let Some(blinding) = blindings.remove(&idx) {
-            MessageOrBlinding::BlindMessageWithConcreteBlinding { message, blinding }
-        } else {
-            MessageOrBlinding::BlindMessageRandomly(message)
-        }
-    });
-
-    let mut rng = get_seeded_rng();
-    match BBSPoKOfSigProtocol::init(&mut rng, &signature, &params, msg_iter) {
-        Ok(sig) => Ok(serde_wasm_bindgen::to_value(&sig)
-            .map_err(JsValue::from)
-            .unwrap()),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices)?;
+    let protocol = native::bbs::initialize_proof_of_knowledge_of_signature(
+        &signature,
+        &params,
+        &messages,
+        blindings,
+        &revealed_indices,
+    )?;
+    serde_wasm_bindgen::to_value(&protocol).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = bbsGenProofOfKnowledgeOfSignature)]
@@ -257,13 +287,10 @@ pub fn bbs_gen_proof(
     challenge: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let protocol: BBSPoKOfSigProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: BBSPoKOfSigProtocol = from_value(protocol)?;
     let challenge = fr_from_uint8_array(challenge, false)?;
-    match protocol.gen_proof(&challenge) {
-        // Ok(proof) => Ok(serde_wasm_bindgen::to_value(&proof).map_err(|e| JsValue::from(e)).unwrap()),
-        Ok(proof) => Ok(obj_to_uint8array!(&proof, false, "BBS+ProofG1")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let proof = native::bbs::generate_proof_of_knowledge_of_signature(protocol, &challenge)?;
+    Ok(obj_to_uint8array!(&proof, false, "BBS+ProofG1"))
 }
 
 #[wasm_bindgen(js_name = bbsVerifyProofOfKnowledgeOfSignature)]
@@ -276,26 +303,17 @@ pub fn bbs_verify_proof(
     encode_messages: bool,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    // let proof: PoKOfSigProof = serde_wasm_bindgen::from_value(proof)?;
+    // let proof: PoKOfSigProof = from_value(proof)?;
     let proof: BBSPoKOfSigProof = obj_from_uint8array!(BBSPoKOfSigProof, proof, false);
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let public_key = obj_from_uint8array!(BBSPublicKey, public_key, false, "BBSPublicKey");
     let challenge = fr_from_uint8_array(challenge, false)?;
 
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
-
-    match proof.verify(&msgs, &challenge, public_key, params) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("{:?}", e)),
-        })
-        .unwrap()),
-    }
+    let result = native::bbs::verify_proof_of_knowledge_of_signature(
+        &proof, &msgs, &challenge, public_key, params,
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
 
 #[wasm_bindgen(js_name = bbsChallengeContributionFromProtocol)]
@@ -306,17 +324,14 @@ pub fn bbs_challenge_contribution_from_protocol(
     encode_messages: bool,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let protocol: BBSPoKOfSigProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: BBSPoKOfSigProtocol = from_value(protocol)?;
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let mut bytes = vec![];
     protocol
         .challenge_contribution(&msgs, &params, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -329,18 +344,15 @@ pub fn bbs_challenge_contribution_from_proof(
     encode_messages: bool,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    // let proof: PoKOfSigProof = serde_wasm_bindgen::from_value(proof)?;
+    // let proof: PoKOfSigProof = from_value(proof)?;
     let proof: BBSPoKOfSigProof = obj_from_uint8array!(BBSPoKOfSigProof, proof, false);
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let mut bytes = vec![];
     proof
         .challenge_contribution(&msgs, &params, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -1,22 +1,24 @@
 use crate::utils::{
-    fr_from_uint8_array, g1_affine_from_uint8_array, g1_affine_to_jsvalue,
+    fr_from_uint8_array, from_value, g1_affine_from_uint8_array, g1_affine_to_jsvalue,
     g1_affine_to_uint8_array, g2_affine_from_uint8_array, g2_affine_to_jsvalue,
-    g2_affine_to_uint8_array, get_seeded_rng, js_set_to_btree_set, random_bytes, set_panic_hook,
+    g2_affine_to_uint8_array, js_set_to_btree_set, set_panic_hook,
 };
 
-use bbs_plus::{proof::MessageOrBlinding, setup::MultiMessageSignatureParams};
+use bbs_plus::setup::MultiMessageSignatureParams;
 use wasm_bindgen::prelude::*;
 
 use crate::{
     common::VerifyResponse,
+    handles::{BbsPlusPublicKeyG2Handle, BbsPlusSigParamsG1Handle},
+    native::{self, ErrorCode},
+    schnorr::{ProofOfPossessionG1, ProofOfPossessionG2},
     utils::{encode_messages_as_js_array_to_fr_vec, encode_messages_as_js_map_to_fr_btreemap},
     Fr, G1Affine, G2Affine,
 };
 use ark_bls12_381::Bls12_381;
-use ark_std::collections::BTreeMap;
 use bbs_plus::prelude::{
-    KeypairG1, KeypairG2, PoKOfSignatureG1Proof, PoKOfSignatureG1Protocol, PublicKeyG1,
-    PublicKeyG2, SecretKey, SignatureG1, SignatureG2, SignatureParamsG1, SignatureParamsG2,
+    PoKOfSignatureG1Proof, PoKOfSignatureG1Protocol, PublicKeyG1, PublicKeyG2, SecretKey,
+    SignatureG1, SignatureG2, SignatureParamsG1, SignatureParamsG2,
 };
 use blake2::Blake2b512;
 use dock_crypto_utils::{concat_slices, hashing_utils::affine_group_elem_from_try_and_incr};
@@ -27,10 +29,10 @@ pub type BBSPlusSigParamsG1 = SignatureParamsG1<Bls12_381>;
 pub type BBSPlusSigParamsG2 = SignatureParamsG2<Bls12_381>;
 pub type BBSPlusPublicKeyG1 = PublicKeyG1<Bls12_381>;
 pub type BBSPlusPublicKeyG2 = PublicKeyG2<Bls12_381>;
-pub(crate) type BBSPlusSigG1 = SignatureG1<Bls12_381>;
-pub(crate) type BBSPlusSigG2 = SignatureG2<Bls12_381>;
-pub(crate) type BBSPlusPoKOfSigProtocol = PoKOfSignatureG1Protocol<Bls12_381>;
-pub(crate) type BBSPlusPoKOfSigProof = PoKOfSignatureG1Proof<Bls12_381>;
+pub type BBSPlusSigG1 = SignatureG1<Bls12_381>;
+pub type BBSPlusSigG2 = SignatureG2<Bls12_381>;
+pub type BBSPlusPoKOfSigProtocol = PoKOfSignatureG1Protocol<Bls12_381>;
+pub type BBSPlusPoKOfSigProof = PoKOfSignatureG1Proof<Bls12_381>;
 
 #[wasm_bindgen(js_name = bbsPlusGenerateSignatureParamsG1)]
 pub fn bbs_plus_generate_g1_params(
@@ -38,22 +40,21 @@ pub fn bbs_plus_generate_g1_params(
     label: Option<Vec<u8>>,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let params = BBSPlusSigParamsG1::new::<Blake2b512>(&label, message_count);
+    let params = native::bbs_plus::generate_params_g1(message_count, label.as_deref())?;
     serde_wasm_bindgen::to_value(&params).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = bbsPlusIsSignatureParamsG1Valid)]
 pub fn bbs_plus_is_params_g1_valid(params: JsValue) -> Result<bool, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     Ok(params.is_valid())
 }
 
 #[wasm_bindgen(js_name = bbsPlusSignatureParamsG1MaxSupportedMsgs)]
 pub fn bbs_plus_params_g1_max_supported_msgs(params: JsValue) -> Result<usize, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     Ok(params.supported_message_count())
 }
 
@@ -63,29 +64,28 @@ pub fn bbs_plus_generate_g2_params(
     label: Option<Vec<u8>>,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let params = BBSPlusSigParamsG2::new::<Blake2b512>(&label, message_count);
+    let params = native::bbs_plus::generate_params_g2(message_count, label.as_deref())?;
     serde_wasm_bindgen::to_value(&params).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = bbsPlusIsSignatureParamsG2Valid)]
 pub fn bbs_plus_is_params_g2_valid(params: JsValue) -> Result<bool, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     Ok(params.is_valid())
 }
 
 #[wasm_bindgen(js_name = bbsPlusSignatureParamsG2MaxSupportedMsgs)]
 pub fn bbs_plus_params_g2_max_supported_msgs(params: JsValue) -> Result<usize, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     Ok(params.supported_message_count())
 }
 
 #[wasm_bindgen(js_name = bbsPlusSignatureParamsG1ToBytes)]
 pub fn bbs_plus_params_g1_to_bytes(params: JsValue) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     Ok(obj_to_uint8array!(&params, false, "BBSPlusSigParamsG1"))
 }
 
@@ -99,7 +99,7 @@ pub fn bbs_plus_params_g1_from_bytes(bytes: js_sys::Uint8Array) -> Result<JsValu
 #[wasm_bindgen(js_name = bbsPlusSignatureParamsG2ToBytes)]
 pub fn bbs_plus_params_g2_to_bytes(params: JsValue) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     Ok(obj_to_uint8array!(&params, false, "BBSPlusSigParamsG2"))
 }
 
@@ -113,8 +113,7 @@ pub fn bbs_plus_params_g2_from_bytes(bytes: js_sys::Uint8Array) -> Result<JsValu
 #[wasm_bindgen(js_name = bbsPlusGenerateSigningKey)]
 pub fn bbs_plus_generate_secret_key(seed: Option<Vec<u8>>) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let seed = seed.unwrap_or_else(random_bytes);
-    let sk = BBSPlusSigningKey::generate_using_seed::<Blake2b512>(&seed);
+    let sk = native::bbs_plus::generate_secret_key(seed.as_deref())?;
     Ok(obj_to_uint8array!(&sk, true, "BBSPlusSigningKey"))
 }
 
@@ -125,8 +124,8 @@ pub fn bbs_plus_generate_public_key_g1(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
-    let pk = BBSPlusPublicKeyG1::generate_using_secret_key(&sk, &params);
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
+    let pk = native::bbs_plus::generate_public_key_g1(&sk, &params);
     Ok(obj_to_uint8array!(&pk, false, "BBSPlusPublicKeyG1"))
 }
 
@@ -137,6 +136,36 @@ pub fn bbs_plus_is_pubkey_g1_valid(public_key: js_sys::Uint8Array) -> Result<boo
     Ok(pk.is_valid())
 }
 
+/// Proof of possession of the secret key of the public key generated by `bbsPlusGeneratePublicKeyG1`, bound to
+/// `context` like the DID of the key's owner or a verifier's nonce
+#[wasm_bindgen(js_name = bbsPlusGenerateProofOfPossessionG1)]
+pub fn bbs_plus_generate_proof_of_possession_g1(
+    secret_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
+    let proof = native::bbs_plus::generate_proof_of_possession_g1(&sk, &params, &context)?;
+    Ok(obj_to_uint8array!(&proof, false, "ProofOfPossession"))
+}
+
+#[wasm_bindgen(js_name = bbsPlusVerifyProofOfPossessionG1)]
+pub fn bbs_plus_verify_proof_of_possession_g1(
+    proof: js_sys::Uint8Array,
+    public_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(ProofOfPossessionG1, proof, false, "ProofOfPossession");
+    let pk = obj_from_uint8array!(BBSPlusPublicKeyG1, public_key, false, "BBSPlusPublicKeyG1");
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
+    let result = native::bbs_plus::verify_proof_of_possession_g1(&proof, &pk, &params, &context);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
 #[wasm_bindgen(js_name = bbsPlusGeneratePublicKeyG2)]
 pub fn bbs_plus_generate_public_key_g2(
     secret_key: js_sys::Uint8Array,
@@ -144,8 +173,8 @@ pub fn bbs_plus_generate_public_key_g2(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
-    let pk = BBSPlusPublicKeyG2::generate_using_secret_key(&sk, &params);
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    let pk = native::bbs_plus::generate_public_key_g2(&sk, &params);
     Ok(obj_to_uint8array!(&pk, false, "BBSPlusPublicKeyG2"))
 }
 
@@ -156,16 +185,46 @@ pub fn bbs_plus_is_pubkey_g2_valid(public_key: js_sys::Uint8Array) -> Result<boo
     Ok(pk.is_valid())
 }
 
+/// Proof of possession of the secret key of the public key generated by `bbsPlusGeneratePublicKeyG2`, bound to
+/// `context` like the DID of the key's owner or a verifier's nonce
+#[wasm_bindgen(js_name = bbsPlusGenerateProofOfPossessionG2)]
+pub fn bbs_plus_generate_proof_of_possession_g2(
+    secret_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    let proof = native::bbs_plus::generate_proof_of_possession_g2(&sk, &params, &context)?;
+    Ok(obj_to_uint8array!(&proof, false, "ProofOfPossession"))
+}
+
+/// Verify the proof of possession for the public key and `context`. This doesn't check that the public key is
+/// valid, `bbsPlusIsPublicKeyG2Valid` should be used for that.
+#[wasm_bindgen(js_name = bbsPlusVerifyProofOfPossessionG2)]
+pub fn bbs_plus_verify_proof_of_possession_g2(
+    proof: js_sys::Uint8Array,
+    public_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(ProofOfPossessionG2, proof, false, "ProofOfPossession");
+    let pk = obj_from_uint8array!(BBSPlusPublicKeyG2, public_key, false, "BBSPlusPublicKeyG2");
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    let result = native::bbs_plus::verify_proof_of_possession_g2(&proof, &pk, &params, &context);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
 #[wasm_bindgen(js_name = bbsPlusGenerateKeyPairG1)]
 pub fn bbs_plus_generate_g1_keypair(
     params: JsValue,
     seed: Option<Vec<u8>>,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
-    let mut seed = seed.unwrap_or_else(random_bytes);
-    let keypair = KeypairG1::generate_using_seed::<Blake2b512>(&seed, &params);
-    seed.zeroize();
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
+    let keypair = native::bbs_plus::generate_keypair_g1(&params, seed.as_deref())?;
     serde_wasm_bindgen::to_value(&keypair).map_err(JsValue::from)
 }
 
@@ -175,10 +234,8 @@ pub fn bbs_plus_generate_g2_keypair(
     seed: Option<Vec<u8>>,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
-    let mut seed = seed.unwrap_or_else(random_bytes);
-    let keypair = KeypairG2::generate_using_seed::<Blake2b512>(&seed, &params);
-    seed.zeroize();
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    let keypair = native::bbs_plus::generate_keypair_g2(&params, seed.as_deref())?;
     serde_wasm_bindgen::to_value(&keypair).map_err(JsValue::from)
 }
 
@@ -187,16 +244,17 @@ pub fn bbs_plus_get_bases_for_commitment_g1(
     params: JsValue,
     indices_to_commit: js_sys::Array,
 ) -> Result<js_sys::Array, JsValue> {
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let bases = js_sys::Array::new();
     bases.push(&g1_affine_to_jsvalue(&params.h_0)?);
     for i in indices_to_commit.values() {
-        let index: usize = serde_wasm_bindgen::from_value(i.unwrap())?;
+        let index: usize = from_value(i.unwrap())?;
         if index >= params.supported_message_count() {
-            return Err(JsValue::from(&format!(
-                "Invalid index {:?} to get signature param",
-                index
-            )));
+            return Err(native::Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!("Invalid index {:?} to get signature param", index),
+            )
+            .into());
         }
         bases.push(&g1_affine_to_jsvalue(&params.h[index])?);
     }
@@ -208,16 +266,17 @@ pub fn bbs_plus_get_bases_for_commitment_g2(
     params: JsValue,
     indices_to_commit: js_sys::Array,
 ) -> Result<js_sys::Array, JsValue> {
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     let bases = js_sys::Array::new();
     bases.push(&g2_affine_to_jsvalue(&params.h_0)?);
     for i in indices_to_commit.values() {
-        let index: usize = serde_wasm_bindgen::from_value(i.unwrap())?;
+        let index: usize = from_value(i.unwrap())?;
         if index >= params.supported_message_count() {
-            return Err(JsValue::from(&format!(
-                "Invalid index {:?} to get signature param",
-                index
-            )));
+            return Err(native::Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!("Invalid index {:?} to get signature param", index),
+            )
+            .into());
         }
         bases.push(&g2_affine_to_jsvalue(&params.h[index])?);
     }
@@ -233,17 +292,10 @@ pub fn bbs_plus_commit_to_message_in_g1(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&messages_to_commit, encode_messages)?;
-    let msgs_ref = msgs
-        .iter()
-        .map(|(i, m)| (*i, m))
-        .collect::<BTreeMap<_, _>>();
-
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let blinding = fr_from_uint8_array(blinding, true)?;
-    match params.commit_to_messages(msgs_ref, &blinding) {
-        Ok(comm) => g1_affine_to_uint8_array(&comm),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let comm = native::bbs_plus::commit_to_messages_in_g1(&msgs, &blinding, &params)?;
+    g1_affine_to_uint8_array(&comm)
 }
 
 #[wasm_bindgen(js_name = bbsPlusCommitMsgsInG2)]
@@ -254,19 +306,11 @@ pub fn bbs_plus_commit_to_message_in_g2(
     encode_messages: bool,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&messages_to_commit, encode_messages)?;
-    let msgs_ref = msgs
-        .iter()
-        .map(|(i, m)| (*i, m))
-        .collect::<BTreeMap<_, _>>();
-
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     let blinding = fr_from_uint8_array(blinding, true)?;
-    match params.commit_to_messages(msgs_ref, &blinding) {
-        Ok(comm) => g2_affine_to_uint8_array(&comm),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let comm = native::bbs_plus::commit_to_messages_in_g2(&msgs, &blinding, &params)?;
+    g2_affine_to_uint8_array(&comm)
 }
 
 #[wasm_bindgen(js_name = bbsPlusSignG1)]
@@ -278,14 +322,25 @@ pub fn bbs_plus_sign_g1(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    let sig = native::bbs_plus::sign_g1(&messages, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG1"))
+}
 
-    let mut rng = get_seeded_rng();
-    match BBSPlusSigG1::new(&mut rng, &messages, &sk, &params) {
-        Ok(sig) => Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG1")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+/// Same as `bbs_plus_sign_g1` but takes a handle to the params
+#[wasm_bindgen(js_name = bbsPlusSignG1WithHandles)]
+pub fn bbs_plus_sign_g1_with_handles(
+    messages: js_sys::Array,
+    secret_key: js_sys::Uint8Array,
+    params: &BbsPlusSigParamsG1Handle,
+    encode_messages: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
+    let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    let sig = native::bbs_plus::sign_g1(&messages, &sk, &params.0)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG1"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusBlindSignG1)]
@@ -299,18 +354,10 @@ pub fn bbs_plus_blind_sign_g1(
     set_panic_hook();
     let commitment = g1_affine_from_uint8_array(commitment)?;
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&uncommitted_messages, encode_messages)?;
-    let msgs_ref = msgs
-        .iter()
-        .map(|(i, m)| (*i, m))
-        .collect::<BTreeMap<_, _>>();
     let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
-
-    let mut rng = get_seeded_rng();
-    match BBSPlusSigG1::new_with_committed_messages(&mut rng, &commitment, msgs_ref, &sk, &params) {
-        Ok(sig) => Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG1")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    let sig = native::bbs_plus::blind_sign_g1(&commitment, &msgs, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG1"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusUnblindSigG1)]
@@ -321,11 +368,8 @@ pub fn bbs_plus_unblind_sig_g1(
     set_panic_hook();
     let signature = obj_from_uint8array!(BBSPlusSigG1, blind_signature, true);
     let blinding = fr_from_uint8_array(blinding, true)?;
-    Ok(obj_to_uint8array!(
-        &signature.unblind(&blinding),
-        true,
-        "BBSPlusSigG1"
-    ))
+    let signature = native::bbs_plus::unblind_signature_g1(signature, &blinding);
+    Ok(obj_to_uint8array!(&signature, true, "BBSPlusSigG1"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusVerifyG1)]
@@ -339,21 +383,32 @@ pub fn bbs_plus_verify_g1(
     set_panic_hook();
     let signature = obj_from_uint8array!(BBSPlusSigG1, signature, true);
     let pk = obj_from_uint8array!(BBSPlusPublicKeyG2, public_key, false, "BBSPlusPublicKeyG2");
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    let result = native::bbs_plus::verify_g1(&messages, &signature, pk, params);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
 
-    match signature.verify(messages.as_slice(), pk, params) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("{:?}", e)),
-        })
-        .unwrap()),
-    }
+/// Same as `bbs_plus_verify_g1` but takes handles to the public key and params
+#[wasm_bindgen(js_name = bbsPlusVerifyG1WithHandles)]
+pub fn bbs_plus_verify_g1_with_handles(
+    messages: js_sys::Array,
+    signature: js_sys::Uint8Array,
+    public_key: &BbsPlusPublicKeyG2Handle,
+    params: &BbsPlusSigParamsG1Handle,
+    encode_messages: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let signature = obj_from_uint8array!(BBSPlusSigG1, signature, true);
+    let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+    // Cloning is needed as for `bbs_verify_with_handles`
+    let result = native::bbs_plus::verify_g1(
+        &messages,
+        &signature,
+        public_key.0.clone(),
+        params.0.clone(),
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
 
 #[wasm_bindgen(js_name = bbsPlusSignG2)]
@@ -365,15 +420,10 @@ pub fn bbs_plus_sign_g2(
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
-
-    let mut rng = get_seeded_rng();
-    match BBSPlusSigG2::new(&mut rng, &messages, &sk, &params) {
-        // Ok(sig) => Ok(serde_wasm_bindgen::to_value(&sig).map_err(|e| JsValue::from(e)).unwrap()),
-        Ok(sig) => Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG2")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let sig = native::bbs_plus::sign_g2(&messages, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG2"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusBlindSignG2)]
@@ -387,19 +437,10 @@ pub fn bbs_plus_blind_sign_g2(
     set_panic_hook();
     let commitment = g2_affine_from_uint8_array(commitment)?;
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&uncommitted_messages, encode_messages)?;
-    let msgs_ref = msgs
-        .iter()
-        .map(|(i, m)| (*i, m))
-        .collect::<BTreeMap<_, _>>();
     let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
-
-    let mut rng = get_seeded_rng();
-    match BBSPlusSigG2::new_with_committed_messages(&mut rng, &commitment, msgs_ref, &sk, &params) {
-        // Ok(sig) => Ok(serde_wasm_bindgen::to_value(&sig).map_err(|e| JsValue::from(e)).unwrap()),
-        Ok(sig) => Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG2")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
+    let sig = native::bbs_plus::blind_sign_g2(&commitment, &msgs, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "BBSPlusSigG2"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusUnblindSigG2)]
@@ -410,11 +451,8 @@ pub fn bbs_plus_unblind_sig_g2(
     set_panic_hook();
     let signature = obj_from_uint8array!(BBSPlusSigG2, blind_signature, true);
     let blinding = fr_from_uint8_array(blinding, true)?;
-    Ok(obj_to_uint8array!(
-        &signature.unblind(&blinding),
-        true,
-        "BBSPlusSigG2"
-    ))
+    let signature = native::bbs_plus::unblind_signature_g2(signature, &blinding);
+    Ok(obj_to_uint8array!(&signature, true, "BBSPlusSigG2"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusVerifyG2)]
@@ -426,24 +464,12 @@ pub fn bbs_plus_verify_g2(
     encode_messages: bool,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-
     let signature = obj_from_uint8array!(BBSPlusSigG2, signature, true);
     let pk = obj_from_uint8array!(BBSPlusPublicKeyG1, public_key, false, "BBSPlusPublicKeyG1");
-    let params: BBSPlusSigParamsG2 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG2 = from_value(params)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
-
-    match signature.verify(messages.as_slice(), &pk, &params) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("{:?}", e)),
-        })
-        .unwrap()),
-    }
+    let result = native::bbs_plus::verify_g2(&messages, &signature, &pk, &params);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
 
 #[wasm_bindgen(js_name = bbsPlusInitializeProofOfKnowledgeOfSignature)]
@@ -458,28 +484,19 @@ pub fn bbs_plus_initialize_proof_of_knowledge_of_signature(
     set_panic_hook();
 
     let signature = obj_from_uint8array!(BBSPlusSigG1, signature, true);
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     // TODO: Avoid this hack of passing false, create separate method to parse
-    let mut blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
+    let blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
     let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
-    let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices);
-    let msg_iter = messages.iter().enumerate().map(|(idx, message)| {
-        if revealed_indices.contains(&idx) {
-            MessageOrBlinding::RevealMessage(message)
-        } else if let Some(blinding) = blindings.remove(&idx) {
-            MessageOrBlinding::BlindMessageWithConcreteBlinding { message, blinding }
-        } else {
-            MessageOrBlinding::BlindMessageRandomly(message)
-        }
-    });
-
-    let mut rng = get_seeded_rng();
-    match BBSPlusPoKOfSigProtocol::init(&mut rng, &signature, &params, msg_iter) {
-        Ok(sig) => Ok(serde_wasm_bindgen::to_value(&sig)
-            .map_err(JsValue::from)
-            .unwrap()),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices)?;
+    let protocol = native::bbs_plus::initialize_proof_of_knowledge_of_signature(
+        &signature,
+        &params,
+        &messages,
+        blindings,
+        &revealed_indices,
+    )?;
+    serde_wasm_bindgen::to_value(&protocol).map_err(JsValue::from)
 }
 
 #[wasm_bindgen(js_name = bbsPlusGenProofOfKnowledgeOfSignature)]
@@ -488,12 +505,10 @@ pub fn bbs_plus_gen_proof(
     challenge: js_sys::Uint8Array,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let protocol: BBSPlusPoKOfSigProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: BBSPlusPoKOfSigProtocol = from_value(protocol)?;
     let challenge = fr_from_uint8_array(challenge, false)?;
-    match protocol.gen_proof(&challenge) {
-        Ok(proof) => Ok(obj_to_uint8array!(&proof, false, "BBS+ProofG1")),
-        Err(e) => Err(JsValue::from(&format!("{:?}", e))),
-    }
+    let proof = native::bbs_plus::generate_proof_of_knowledge_of_signature(protocol, &challenge)?;
+    Ok(obj_to_uint8array!(&proof, false, "BBS+ProofG1"))
 }
 
 #[wasm_bindgen(js_name = bbsPlusVerifyProofOfKnowledgeOfSignature)]
@@ -506,27 +521,18 @@ pub fn bbs_plus_verify_proof(
     encode_messages: bool,
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    // let proof: PoKOfSigProof = serde_wasm_bindgen::from_value(proof)?;
+    // let proof: PoKOfSigProof = from_value(proof)?;
     let proof: BBSPlusPoKOfSigProof = obj_from_uint8array!(BBSPlusPoKOfSigProof, proof, false);
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let public_key =
         obj_from_uint8array!(BBSPlusPublicKeyG2, public_key, false, "BBSPlusPublicKeyG2");
     let challenge = fr_from_uint8_array(challenge, false)?;
 
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
-
-    match proof.verify(&msgs, &challenge, public_key, params) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("{:?}", e)),
-        })
-        .unwrap()),
-    }
+    let result = native::bbs_plus::verify_proof_of_knowledge_of_signature(
+        &proof, &msgs, &challenge, public_key, params,
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
 
 #[wasm_bindgen(js_name = bbsPlusChallengeContributionFromProtocol)]
@@ -537,17 +543,14 @@ pub fn bbs_plus_challenge_contribution_from_protocol(
     encode_messages: bool,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let protocol: BBSPlusPoKOfSigProtocol = serde_wasm_bindgen::from_value(protocol)?;
+    let protocol: BBSPlusPoKOfSigProtocol = from_value(protocol)?;
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let mut bytes = vec![];
     protocol
         .challenge_contribution(&msgs, &params, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -560,18 +563,15 @@ pub fn bbs_plus_challenge_contribution_from_proof(
     encode_messages: bool,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    // let proof: PoKOfSigProof = serde_wasm_bindgen::from_value(proof)?;
+    // let proof: PoKOfSigProof = from_value(proof)?;
     let proof: BBSPlusPoKOfSigProof = obj_from_uint8array!(BBSPlusPoKOfSigProof, proof, false);
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let mut bytes = vec![];
     proof
         .challenge_contribution(&msgs, &params, &mut bytes)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
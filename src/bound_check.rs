@@ -1,7 +1,10 @@
 use ark_bls12_381::Bls12_381;
 use proof_system::prelude::generate_snark_srs_bound_check;
 
-use crate::utils::{get_seeded_rng, set_panic_hook};
+use crate::{
+    native,
+    utils::{get_seeded_rng, set_panic_hook},
+};
 use wasm_bindgen::prelude::*;
 use zeroize::Zeroize;
 
@@ -9,12 +12,9 @@ use zeroize::Zeroize;
 #[wasm_bindgen(js_name = boundCheckSnarkSetup)]
 pub fn bound_check_snark_setup(return_uncompressed: bool) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let mut rng = get_seeded_rng();
+    let mut rng = get_seeded_rng()?;
     let snark_pk = generate_snark_srs_bound_check::<Bls12_381, _>(&mut rng).map_err(|e| {
-        JsValue::from(&format!(
-            "SNARK setup for bound check returned error: {:?}",
-            e
-        ))
+        native::Error::from(e).context("SNARK setup for bound check returned error")
     })?;
     Ok(if return_uncompressed {
         obj_to_uint8array_uncompressed!(&snark_pk, "LegoProvingKey")
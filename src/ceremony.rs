@@ -0,0 +1,259 @@
+use crate::{
+    common::VerifyResponse,
+    legosnark::LegoProvingKey,
+    native::{
+        self,
+        ceremony::{CeremonyTranscript, Phase2Key},
+    },
+    saver::{EncGens, SaverSnarkPk},
+    utils::set_panic_hook,
+};
+use ark_bls12_381::Bls12_381;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use js_sys::{Array, Uint8Array};
+use legogroth16::circom::R1CS;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+// Phase 2 setup ceremony for the proving keys of bound checks, R1CS circuits and SAVER. The initial key is created
+// from the powers of tau of a phase 1 ceremony, a `.ptau` file of snarkjs, with `boundCheckSnarkSetupFromPowersOfTau`,
+// `r1csSnarkSetupFromPowersOfTau` or `saverSnarkSetupFromPowersOfTau`, which anyone can do to get the same key. It
+// is passed to the first contributor who calls `legoCeremonyContribute` or `saverCeremonyContribute` without a
+// transcript and publishes the returned key and transcript. Each following contributor does the same with the key
+// and transcript of the previous one. Anyone can check a contribution with `legoCeremonyVerifyContribution` or
+// `saverCeremonyVerifyContribution`. At the end, the key is finalized with `legoCeremonyFinalize` or
+// `saverCeremonyFinalize` which verify all contributions from the initial key, which the verifier should create
+// from the powers of tau itself. Keys are taken and returned in compressed or uncompressed form as `uncompressed`
+// says.
+
+/// Create the initial proving key of the ceremony for the bound check circuit of `boundCheckSnarkSetup` from the
+/// ptau file `ptau`
+#[wasm_bindgen(js_name = boundCheckSnarkSetupFromPowersOfTau)]
+pub fn bound_check_snark_setup_from_powers_of_tau(
+    ptau: Uint8Array,
+    uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let snark_pk = native::powers_of_tau::bound_check_proving_key(&ptau.to_vec())
+        .map_err(|e| e.context("SNARK setup for bound check returned error"))?;
+    key_to_uint8array(&snark_pk, uncompressed, "LegoProvingKey")
+}
+
+/// Create the initial proving key of the ceremony for an R1CS returned by `r1csFromBytes` from the ptau file `ptau`.
+/// `commit_witness_count` is as for `r1csSnarkSetup`.
+#[wasm_bindgen(js_name = r1csSnarkSetupFromPowersOfTau)]
+pub fn r1cs_snark_setup_from_powers_of_tau(
+    r1cs: Uint8Array,
+    commit_witness_count: u32,
+    ptau: Uint8Array,
+    uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let r1cs = obj_from_uint8array!(R1CS<Bls12_381>, r1cs, false, "R1CS");
+    let snark_pk =
+        native::powers_of_tau::r1cs_proving_key(r1cs, commit_witness_count, &ptau.to_vec())
+            .map_err(|e| e.context("SNARK setup for R1CS returned error"))?;
+    key_to_uint8array(&snark_pk, uncompressed, "LegoProvingKey")
+}
+
+/// Create the initial snark proving key of SAVER for the ceremony from the ptau file `ptau`. Returns an array of the
+/// key and the encryption generators to use with it, which are always compressed. The decryptor's keys are created
+/// by `saverCeremonyFinalize`.
+#[wasm_bindgen(js_name = saverSnarkSetupFromPowersOfTau)]
+pub fn saver_snark_setup_from_powers_of_tau(
+    chunk_bit_size: u8,
+    ptau: Uint8Array,
+    uncompressed: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let (snark_pk, enc_gens) =
+        native::powers_of_tau::saver_proving_key(chunk_bit_size, &ptau.to_vec())
+            .map_err(|e| e.context("SNARK setup for SAVER returned error"))?;
+    let setup = Array::new();
+    let snark_pk = key_to_uint8array(&snark_pk, uncompressed, "SaverSnarkPk")?;
+    let enc_gens = obj_to_uint8array!(&enc_gens, false, "EncryptionGenerators");
+    setup.push(&snark_pk);
+    setup.push(&enc_gens);
+    Ok(setup)
+}
+
+/// Contribute to the ceremony for a LegoGroth16 proving key. `transcript` is the one returned with `snark_pk` by
+/// the previous contributor and not given for the first contribution to the initial key. Returns an array of the
+/// updated proving key and transcript.
+#[wasm_bindgen(js_name = legoCeremonyContribute)]
+pub fn lego_ceremony_contribute(
+    snark_pk: Uint8Array,
+    transcript: Option<Uint8Array>,
+    uncompressed: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let snark_pk: LegoProvingKey = key_from_uint8array(snark_pk, uncompressed, "LegoProvingKey")?;
+    contribute(&snark_pk, transcript, uncompressed, "LegoProvingKey")
+}
+
+/// Verify that `new_snark_pk` is `previous_snark_pk` updated by the last contribution in `transcript`
+#[wasm_bindgen(js_name = legoCeremonyVerifyContribution)]
+pub fn lego_ceremony_verify_contribution(
+    previous_snark_pk: Uint8Array,
+    new_snark_pk: Uint8Array,
+    transcript: Uint8Array,
+    uncompressed: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let previous: LegoProvingKey =
+        key_from_uint8array(previous_snark_pk, uncompressed, "LegoProvingKey")?;
+    let new: LegoProvingKey = key_from_uint8array(new_snark_pk, uncompressed, "LegoProvingKey")?;
+    let transcript = obj_from_uint8array!(
+        CeremonyTranscript,
+        transcript,
+        false,
+        "SnarkCeremonyTranscript"
+    );
+    let result = native::ceremony::verify_contribution(&previous, &new, &transcript);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Verify all contributions in `transcript` from the initial proving key to the final one and return the final
+/// proving key, to be used like the one from `boundCheckSnarkSetup` or `r1csSnarkSetup`
+#[wasm_bindgen(js_name = legoCeremonyFinalize)]
+pub fn lego_ceremony_finalize(
+    initial_snark_pk: Uint8Array,
+    final_snark_pk: Uint8Array,
+    transcript: Uint8Array,
+    uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let initial: LegoProvingKey =
+        key_from_uint8array(initial_snark_pk, uncompressed, "LegoProvingKey")?;
+    let final_pk: LegoProvingKey =
+        key_from_uint8array(final_snark_pk, uncompressed, "LegoProvingKey")?;
+    verify_ceremony(&initial, &final_pk, transcript)?;
+    key_to_uint8array(&final_pk, uncompressed, "LegoProvingKey")
+}
+
+/// Same as `lego_ceremony_contribute` but for the snark proving key of SAVER
+#[wasm_bindgen(js_name = saverCeremonyContribute)]
+pub fn saver_ceremony_contribute(
+    snark_pk: Uint8Array,
+    transcript: Option<Uint8Array>,
+    uncompressed: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let snark_pk: SaverSnarkPk = key_from_uint8array(snark_pk, uncompressed, "SaverSnarkPk")?;
+    contribute(&snark_pk, transcript, uncompressed, "SaverSnarkPk")
+}
+
+/// Same as `lego_ceremony_verify_contribution` but for the snark proving key of SAVER
+#[wasm_bindgen(js_name = saverCeremonyVerifyContribution)]
+pub fn saver_ceremony_verify_contribution(
+    previous_snark_pk: Uint8Array,
+    new_snark_pk: Uint8Array,
+    transcript: Uint8Array,
+    uncompressed: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let previous: SaverSnarkPk =
+        key_from_uint8array(previous_snark_pk, uncompressed, "SaverSnarkPk")?;
+    let new: SaverSnarkPk = key_from_uint8array(new_snark_pk, uncompressed, "SaverSnarkPk")?;
+    let transcript = obj_from_uint8array!(
+        CeremonyTranscript,
+        transcript,
+        false,
+        "SnarkCeremonyTranscript"
+    );
+    let result = native::ceremony::verify_contribution(&previous, &new, &transcript);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Verify all contributions in `transcript` from the initial snark proving key of SAVER to the final one. As the
+/// decryptor's keys depend on the snark proving key, new ones are created for the final key like
+/// `saverRotateKeys` does, so this should be called by the decryptor. Returns an array of the final snark proving
+/// key, secret key, encryption key and decryption key like `saverDecryptorSetup`.
+#[wasm_bindgen(js_name = saverCeremonyFinalize)]
+pub fn saver_ceremony_finalize(
+    initial_snark_pk: Uint8Array,
+    final_snark_pk: Uint8Array,
+    transcript: Uint8Array,
+    enc_gens: Uint8Array,
+    chunk_bit_size: u8,
+    uncompressed: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let initial: SaverSnarkPk =
+        key_from_uint8array(initial_snark_pk, uncompressed, "SaverSnarkPk")?;
+    let final_pk: SaverSnarkPk = key_from_uint8array(final_snark_pk, uncompressed, "SaverSnarkPk")?;
+    verify_ceremony(&initial, &final_pk, transcript)?;
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let (sk, ek, dk) = native::saver::rotate_keys(&final_pk, &enc_gens, chunk_bit_size)
+        .map_err(|e| e.context("Key generation returned error"))?;
+    let setup = Array::new();
+    let snark_pk = key_to_uint8array(&final_pk, uncompressed, "SaverSnarkPk")?;
+    let sk = obj_to_uint8array!(&sk, true, "SaverSk");
+    let ek = obj_to_uint8array!(&ek, false, "SaverEk");
+    let dk = obj_to_uint8array!(&dk, false, "SaverDk");
+    setup.push(&snark_pk);
+    setup.push(&sk);
+    setup.push(&ek);
+    setup.push(&dk);
+    Ok(setup)
+}
+
+fn contribute<K: Phase2Key + CanonicalSerialize>(
+    snark_pk: &K,
+    transcript: Option<Uint8Array>,
+    uncompressed: bool,
+    name: &str,
+) -> Result<Array, JsValue> {
+    let transcript = match transcript {
+        Some(t) => obj_from_uint8array!(CeremonyTranscript, t, false, "SnarkCeremonyTranscript"),
+        None => CeremonyTranscript::default(),
+    };
+    let (new_pk, new_transcript) = native::ceremony::contribute(snark_pk, &transcript)
+        .map_err(|e| e.context("Contribution returned error"))?;
+    let result = Array::new();
+    let new_pk = key_to_uint8array(&new_pk, uncompressed, name)?;
+    let new_transcript = obj_to_uint8array!(&new_transcript, false, "SnarkCeremonyTranscript");
+    result.push(&new_pk);
+    result.push(&new_transcript);
+    Ok(result)
+}
+
+fn verify_ceremony<K: Phase2Key>(
+    initial_snark_pk: &K,
+    final_snark_pk: &K,
+    transcript: Uint8Array,
+) -> Result<(), JsValue> {
+    let transcript = obj_from_uint8array!(
+        CeremonyTranscript,
+        transcript,
+        false,
+        "SnarkCeremonyTranscript"
+    );
+    native::ceremony::verify_ceremony(initial_snark_pk, final_snark_pk, &transcript)
+        .map_err(|e| e.context("Ceremony verification failed"))?;
+    Ok(())
+}
+
+fn key_from_uint8array<K: CanonicalDeserialize>(
+    snark_pk: Uint8Array,
+    uncompressed: bool,
+    name: &str,
+) -> Result<K, JsValue> {
+    Ok(if uncompressed {
+        obj_from_uint8array_uncompressed!(K, snark_pk, name)
+    } else {
+        obj_from_uint8array!(K, snark_pk, false, name)
+    })
+}
+
+fn key_to_uint8array<K: CanonicalSerialize>(
+    snark_pk: &K,
+    uncompressed: bool,
+    name: &str,
+) -> Result<Uint8Array, JsValue> {
+    Ok(if uncompressed {
+        obj_to_uint8array_uncompressed!(snark_pk, name)
+    } else {
+        obj_to_uint8array!(snark_pk, false, name)
+    })
+}
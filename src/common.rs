@@ -1,14 +1,16 @@
 use crate::{
+    native::{self, ErrorCode},
     utils::{
         self, field_element_from_u64, fr_from_uint8_array, fr_to_jsvalue, fr_to_uint8_array,
-        g1_affine_to_uint8_array, g2_affine_to_uint8_array, is_positive_safe_integer,
+        from_value, g1_affine_to_uint8_array, g2_affine_to_uint8_array, is_positive_safe_integer,
         js_array_to_fr_vec, js_array_to_g1_affine_vec, js_array_to_g2_affine_vec, random_bytes,
-        set_panic_hook,
+        set_panic_hook, RngSource,
     },
     Fr, G1Affine, G1Projective, G2Affine, G2Projective,
 };
 use ark_ec::{CurveGroup, VariableBaseMSM};
 use ark_serialize::CanonicalSerialize;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
 use blake2::Blake2b512;
 use serde::{Deserialize, Serialize};
 
@@ -26,8 +28,6 @@ pub fn encode_messages_for_signing(
     messages: js_sys::Array,
     indices_to_encode: Option<js_sys::Array>,
 ) -> Result<js_sys::Array, JsValue> {
-    use serde_wasm_bindgen::from_value;
-
     set_panic_hook();
     let encoded = js_sys::Array::new();
 
@@ -35,10 +35,11 @@ pub fn encode_messages_for_signing(
         for i in indices_to_encode.values() {
             let index: u32 = from_value(i.unwrap())?;
             if index >= messages.length() {
-                return Err(JsValue::from(&format!(
-                    "Invalid index {:?} to get message",
-                    index
-                )));
+                return Err(native::Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!("Invalid index {:?} to get message", index),
+                )
+                .into());
             }
             let msg: Vec<u8> = from_value(messages.get(index))?;
             let fr = utils::encode_message_for_signing(&msg);
@@ -59,21 +60,21 @@ pub fn encode_messages_for_signing(
 #[wasm_bindgen(js_name = generateRandomG1Element)]
 pub fn generate_random_g1_element(seed: Option<Vec<u8>>) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let g = random_g1(seed);
+    let g = random_g1(seed)?;
     g1_affine_to_uint8_array(&g)
 }
 
 #[wasm_bindgen(js_name = generateRandomG2Element)]
 pub fn generate_random_g2_element(seed: Option<Vec<u8>>) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let g = random_g2(seed);
+    let g = random_g2(seed)?;
     g2_affine_to_uint8_array(&g)
 }
 
 #[wasm_bindgen(js_name = generateRandomFieldElement)]
 pub fn generate_random_field_element(seed: Option<Vec<u8>>) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let f = random_ff(seed);
+    let f = random_ff(seed)?;
     fr_to_uint8_array(&f)
 }
 
@@ -81,10 +82,11 @@ pub fn generate_random_field_element(seed: Option<Vec<u8>>) -> Result<js_sys::Ui
 pub fn field_element_from_number(number: js_sys::Number) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
     if !is_positive_safe_integer(&number) {
-        return Err(JsValue::from(&format!(
+        return Err(native::Error::invalid_argument(format!(
             "number should be a safe positive integers but instead found {:?}",
             number
-        )));
+        ))
+        .into());
     }
     fr_to_uint8_array(&field_element_from_u64(number.value_of() as u64))
 }
@@ -111,10 +113,7 @@ pub fn field_element_as_bytes(
     let f = fr_from_uint8_array(element, element_is_secret)?;
     let mut bytes = vec![];
     f.serialize_compressed(&mut bytes).map_err(|e| {
-        JsValue::from(&format!(
-            "Serializing field element to bytes returned error: {:?}",
-            e
-        ))
+        native::Error::from(e).context("Serializing field element to bytes returned error")
     })?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
@@ -150,22 +149,57 @@ fn fr_uin8_array_from_bytes_hash(bytes: &[u8]) -> js_sys::Uint8Array {
     fr_to_uint8_array(&f).unwrap()
 }
 
-pub fn random_ff(seed: Option<Vec<u8>>) -> Fr {
-    let seed = seed.unwrap_or_else(random_bytes);
-    dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(&seed)
+/// Use `seed` as the source of all randomness so that signatures, proofs, keys, etc are deterministic. This is meant
+/// for tests and test vectors only and must not be used in production. The same seed followed by the same sequence of
+/// calls gives the same outputs.
+#[wasm_bindgen(js_name = setRngSeed)]
+pub fn set_rng_seed(seed: Vec<u8>) -> Result<(), JsValue> {
+    set_panic_hook();
+    let seed: [u8; 32] = seed.try_into().map_err(|s: Vec<u8>| {
+        native::Error::invalid_argument(format!("Seed should be of 32 bytes but found {}", s.len()))
+    })?;
+    utils::set_rng_source(RngSource::Seeded(Box::new(StdRng::from_seed(seed))));
+    Ok(())
 }
 
-pub fn random_g1(seed: Option<Vec<u8>>) -> G1Affine {
-    let seed = seed.unwrap_or_else(random_bytes);
-    dock_crypto_utils::hashing_utils::affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(
-        &seed,
+/// Use the function `callback` as the source of all randomness, like an HSM. It is called with the number of bytes
+/// needed and should return a `Uint8Array` of that many bytes. Randomized functions throw if the callback fails.
+#[wasm_bindgen(js_name = setRngSource)]
+pub fn set_rng_source(callback: js_sys::Function) -> Result<(), JsValue> {
+    set_panic_hook();
+    // Fail now rather than in some later call
+    utils::entropy_from_callback(&callback, 32)?;
+    utils::set_rng_source(RngSource::Callback(callback));
+    Ok(())
+}
+
+/// Go back to using the platform's entropy source for all randomness
+#[wasm_bindgen(js_name = resetRngSource)]
+pub fn reset_rng_source() {
+    set_panic_hook();
+    utils::set_rng_source(RngSource::Entropy);
+}
+
+pub fn random_ff(seed: Option<Vec<u8>>) -> native::Result<Fr> {
+    let seed = seed.map_or_else(random_bytes, Ok)?;
+    Ok(dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(&seed))
+}
+
+pub fn random_g1(seed: Option<Vec<u8>>) -> native::Result<G1Affine> {
+    let seed = seed.map_or_else(random_bytes, Ok)?;
+    Ok(
+        dock_crypto_utils::hashing_utils::affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(
+            &seed,
+        ),
     )
 }
 
-pub fn random_g2(seed: Option<Vec<u8>>) -> G2Affine {
-    let seed = seed.unwrap_or_else(random_bytes);
-    dock_crypto_utils::hashing_utils::affine_group_elem_from_try_and_incr::<G2Affine, Blake2b512>(
-        &seed,
+pub fn random_g2(seed: Option<Vec<u8>>) -> native::Result<G2Affine> {
+    let seed = seed.map_or_else(random_bytes, Ok)?;
+    Ok(
+        dock_crypto_utils::hashing_utils::affine_group_elem_from_try_and_incr::<G2Affine, Blake2b512>(
+            &seed,
+        ),
     )
 }
 
@@ -173,6 +207,10 @@ pub fn random_g2(seed: Option<Vec<u8>>) -> G2Affine {
 pub struct VerifyResponse {
     pub verified: bool,
     pub error: Option<String>,
+    /// Code of the error as returned by `native::ErrorCode::as_str`
+    pub code: Option<String>,
+    /// Category of the error as returned by `native::ErrorCategory::as_str`
+    pub category: Option<String>,
 }
 
 impl VerifyResponse {
@@ -182,10 +220,29 @@ impl VerifyResponse {
     }
 }
 
+impl From<native::Result<()>> for VerifyResponse {
+    fn from(result: native::Result<()>) -> Self {
+        match result {
+            Ok(_) => VerifyResponse {
+                verified: true,
+                error: None,
+                code: None,
+                category: None,
+            },
+            Err(e) => VerifyResponse {
+                verified: false,
+                error: Some(e.to_string()),
+                code: Some(e.code().as_str().to_string()),
+                category: Some(e.category().as_str().to_string()),
+            },
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! adapt_params {
     ($params:ident, $generating_label: ident, $new_count: ident, $sig_type: ident, $sig_group: ident) => {{
-        let mut params: $sig_type = serde_wasm_bindgen::from_value($params)?;
+        let mut params: $sig_type = from_value($params)?;
         let current_count = params.supported_message_count();
         if current_count > $new_count {
             for _ in 0..(current_count - $new_count) {
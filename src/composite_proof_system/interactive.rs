@@ -0,0 +1,551 @@
+use ark_bls12_381::Bls12_381;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    collections::BTreeMap,
+    rand::{rngs::StdRng, RngCore, SeedableRng},
+    UniformRand,
+};
+use js_sys::Uint8Array;
+use proof_system::{
+    prelude::{ProofSystemError, Statement, StatementProof, WitnessRef},
+    sub_protocols::{
+        accumulator::{AccumulatorMembershipSubProtocol, AccumulatorNonMembershipSubProtocol},
+        bbs_23::PoKBBSSigG1SubProtocol as PoKBBSSig23G1SubProtocol,
+        bbs_plus::PoKBBSSigG1SubProtocol,
+        ps_signature::PSSignaturePoK,
+        schnorr::SchnorrProtocol,
+        SubProtocol,
+    },
+};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    common::VerifyResponse,
+    composite_proof_system::{
+        proof_details::get_statement_proof_details, proof_spec::statement_type_name, ProofG1,
+        ProofSpec, SecretWitnesses, StatementProofG1, Witness, Witnesses,
+    },
+    native::{self, envelope, Error, ErrorCode},
+    utils::{fr_from_uint8_array, fr_to_uint8_array, from_value, get_seeded_rng, set_panic_hook},
+    Fr, G1Affine,
+};
+
+/// State of the prover of an interactive composite proof. It contains the witnesses so it's kept in wasm memory
+/// unless serialized with `toBytes`, and it's used up by responding to a challenge as responding to 2 different
+/// challenges for the same commitments reveals the witnesses. Must be freed by calling `free` on it if the prover
+/// doesn't respond.
+#[wasm_bindgen]
+pub struct InteractiveProverState(Option<ProverState>);
+
+#[wasm_bindgen]
+impl InteractiveProverState {
+    /// Serialize the state, e.g. to keep it between the commitments and the response when the prover can't keep
+    /// it in memory. The bytes contain the witnesses so they must be kept secret. A state restored from them
+    /// creates the same commitments, so it must be used to respond only once and the bytes deleted after:
+    /// responding with 2 copies of the state to different challenges reveals the witnesses.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Uint8Array, JsValue> {
+        set_panic_hook();
+        let mut bytes = self.serialize()?;
+        let b = Uint8Array::from(bytes.as_slice());
+        bytes.zeroize();
+        Ok(b)
+    }
+
+    /// Restore the state serialized with `toBytes`. See `toBytes` for why it must be used only once.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: Uint8Array) -> Result<InteractiveProverState, JsValue> {
+        set_panic_hook();
+        let mut bytes = bytes.to_vec();
+        let state = Self::deserialize(&bytes);
+        bytes.zeroize();
+        Ok(state?)
+    }
+}
+
+/// The commitments are recreated from `seed` on each call rather than kept as the sub-protocols borrow the
+/// proof spec
+struct ProverState {
+    proof_spec: ProofSpec<G1Affine>,
+    witnesses: SecretWitnesses,
+    seed: [u8; 32],
+}
+
+impl Drop for ProverState {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+/// Start an interactive (3-move) composite proof in group G1 of the statements in `proof_spec`. Unlike a proof
+/// created with `generateCompositeProofG1`, the challenge comes from the verifier so the proof isn't transferable.
+/// Only statements proven with Schnorr protocols are supported, i.e. BBS+, BBS and PS signatures, accumulator
+/// membership and non-membership and Pedersen commitments. Returns the prover's state to be passed to
+/// `compositeProofCommitments` and `compositeProofRespond`.
+#[wasm_bindgen(js_name = compositeProofInit)]
+pub fn composite_proof_init(
+    proof_spec: Uint8Array,
+    witnesses: js_sys::Array,
+) -> Result<InteractiveProverState, JsValue> {
+    set_panic_hook();
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpec<G1Affine>, proof_spec, "ProofSpec");
+    let mut wits = SecretWitnesses(Witnesses::new());
+    for w in witnesses.values() {
+        let wit: Witness = from_value(w.unwrap())?;
+        wits.0.add(wit);
+    }
+    Ok(InteractiveProverState::new(proof_spec, wits)?)
+}
+
+/// Get the commitments that the prover sends to the verifier as the first message
+#[wasm_bindgen(js_name = compositeProofCommitments)]
+pub fn composite_proof_commitments(state: &InteractiveProverState) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    Ok(Uint8Array::from(state.commitments()?.as_slice()))
+}
+
+/// Get the proof for the verifier's `challenge` that the prover sends as the last message. The state is used up
+/// by this so it fails when called again with the same state.
+#[wasm_bindgen(js_name = compositeProofRespond)]
+pub fn composite_proof_respond(
+    state: &mut InteractiveProverState,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let challenge = fr_from_uint8_array(challenge, false)?;
+    let proof = state.respond(&challenge)?;
+    Ok(obj_to_uint8array!(&proof, false, "Proof"))
+}
+
+impl InteractiveProverState {
+    pub fn new(
+        proof_spec: ProofSpec<G1Affine>,
+        witnesses: SecretWitnesses,
+    ) -> native::Result<Self> {
+        let mut seed = [0; 32];
+        get_seeded_rng()?.fill_bytes(&mut seed);
+        let state = ProverState {
+            proof_spec,
+            witnesses,
+            seed,
+        };
+        // Fail early if the statements can't be proven interactively
+        init_sub_protocols(&state)?;
+        Ok(Self(Some(state)))
+    }
+
+    pub fn commitments(&self) -> native::Result<Vec<u8>> {
+        let state = self.state()?;
+        let sub_protocols = init_sub_protocols(state)?;
+        commitments(&state.proof_spec, &sub_protocols)
+            .map_err(|e| Error::from(e).context("Getting commitments returned error"))
+    }
+
+    /// Create the proof for `challenge`. The state is zeroized and can't be used after this, whether it
+    /// succeeds or not.
+    pub fn respond(&mut self, challenge: &Fr) -> native::Result<ProofG1> {
+        self.state()?;
+        let state = self.0.take().unwrap();
+        let sub_protocols = init_sub_protocols(&state)?;
+        let mut statement_proofs = Vec::with_capacity(sub_protocols.len());
+        for mut sp in sub_protocols {
+            statement_proofs.push(
+                sp.gen_proof_contribution(challenge)
+                    .map_err(|e| Error::from(e).context("Generating proof returned error"))?,
+            );
+        }
+        Ok(ProofG1 {
+            statement_proofs,
+            nonce: None,
+            aggregated_groth16: None,
+            aggregated_legogroth16: None,
+        })
+    }
+
+    /// Serialize the state in an envelope if envelope output is enabled
+    pub fn serialize(&self) -> native::Result<Vec<u8>> {
+        let state = self.state()?;
+        let mut bytes = envelope::header_for_output("InteractiveProverState", true)
+            .map(|h| h.to_vec())
+            .unwrap_or_default();
+        let res = state
+            .proof_spec
+            .serialize_compressed(&mut bytes)
+            .and_then(|_| state.witnesses.0.serialize_compressed(&mut bytes))
+            .and_then(|_| state.seed.serialize_compressed(&mut bytes));
+        if let Err(e) = res {
+            bytes.zeroize();
+            return Err(
+                Error::from(e).context("Failed to serialize an InteractiveProverState to bytes")
+            );
+        }
+        Ok(bytes)
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> native::Result<Self> {
+        let (proof_spec, witnesses, seed) =
+            envelope::open(bytes, Some("InteractiveProverState"), true)
+                .and_then(|b| {
+                    Ok(<(ProofSpec<G1Affine>, Witnesses, [u8; 32])>::deserialize_compressed(b)?)
+                })
+                .map_err(|e| {
+                    e.context("Failed to deserialize an InteractiveProverState from bytes")
+                })?;
+        Ok(Self(Some(ProverState {
+            proof_spec,
+            witnesses: SecretWitnesses(witnesses),
+            seed,
+        })))
+    }
+
+    fn state(&self) -> native::Result<&ProverState> {
+        self.0.as_ref().ok_or_else(|| {
+            Error::invalid_argument("Prover has already responded to a challenge with this state")
+        })
+    }
+}
+
+/// Called by the verifier on receiving the prover's commitments to get the challenge sent to the prover. The verifier
+/// keeps the commitments and the challenge to verify the proof with `compositeProofVerify`.
+#[wasm_bindgen(js_name = compositeProofChallenge)]
+pub fn composite_proof_challenge() -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    fr_to_uint8_array(&Fr::rand(&mut get_seeded_rng()?))
+}
+
+/// Verify the proof received from the prover for the commitments received earlier and the challenge sent.
+#[wasm_bindgen(js_name = compositeProofVerify)]
+pub fn composite_proof_verify(
+    proof: Uint8Array,
+    proof_spec: Uint8Array,
+    commitments: Vec<u8>,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(ProofG1, proof, false);
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpec<G1Affine>, proof_spec, "ProofSpec");
+    let challenge = fr_from_uint8_array(challenge, false)?;
+    let res = verify(&proof, &proof_spec, &commitments, &challenge);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(res)).unwrap())
+}
+
+/// Create and initialize a sub-protocol for each statement. Randomness comes from the state's seed so the same
+/// commitments are created each time.
+fn init_sub_protocols(
+    state: &ProverState,
+) -> native::Result<Vec<SubProtocol<'_, Bls12_381, G1Affine>>> {
+    init_statement_sub_protocols(
+        &state.proof_spec,
+        &state.witnesses.0,
+        &mut StdRng::from_seed(state.seed),
+    )
+}
+
+/// Create and initialize a sub-protocol for each statement of `proof_spec` given the witnesses
+pub(crate) fn init_statement_sub_protocols<'a, R: RngCore>(
+    proof_spec: &'a ProofSpec<G1Affine>,
+    witnesses: &Witnesses,
+    rng: &mut R,
+) -> native::Result<Vec<SubProtocol<'a, Bls12_381, G1Affine>>> {
+    proof_spec
+        .validate()
+        .map_err(|e| Error::from(e).context("Invalid proof spec"))?;
+    if proof_spec.statements.len() != witnesses.len() {
+        return Err(Error::invalid_argument(format!(
+            "Need a witness for each of {} statements but found {} witnesses",
+            proof_spec.statements.len(),
+            witnesses.len()
+        )));
+    }
+
+    // Same blinding for witnesses that are equal so that their responses are equal
+    let mut blindings = BTreeMap::<WitnessRef, Fr>::new();
+    for eq_wits in proof_spec.meta_statements.disjoint_witness_equalities() {
+        let blinding = Fr::rand(rng);
+        for wr in eq_wits.0 {
+            blindings.insert(wr, blinding);
+        }
+    }
+
+    let sp = &proof_spec.setup_params;
+    let init_error = |i: usize, e: ProofSystemError| {
+        Error::from(e).context(format!("Initializing statement {} returned error", i))
+    };
+    let mut sub_protocols = Vec::with_capacity(proof_spec.statements.len());
+    for (i, (statement, witness)) in proof_spec
+        .statements
+        .0
+        .iter()
+        .zip(witnesses.0.iter().cloned())
+        .enumerate()
+    {
+        let mut blindings_for = |keys: Vec<usize>| {
+            keys.into_iter()
+                .filter_map(|k| blindings.remove(&(i, k)).map(|b| (k, b)))
+                .collect::<BTreeMap<_, _>>()
+        };
+        match (statement, witness) {
+            (Statement::PoKBBSSignatureG1(s), Witness::PoKBBSSignatureG1(w)) => {
+                let b = blindings_for(w.unrevealed_messages.keys().copied().collect());
+                let params = s.get_sig_params(sp, i).map_err(|e| init_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| init_error(i, e))?;
+                let mut p = PoKBBSSigG1SubProtocol::new(i, &s.revealed_messages, params, pk);
+                p.init(rng, b, w).map_err(|e| init_error(i, e))?;
+                sub_protocols.push(SubProtocol::PoKBBSSignatureG1(p));
+            }
+            (Statement::PoKBBSSignature23G1(s), Witness::PoKBBSSignature23G1(w)) => {
+                let b = blindings_for(w.unrevealed_messages.keys().copied().collect());
+                let params = s.get_sig_params(sp, i).map_err(|e| init_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| init_error(i, e))?;
+                let mut p = PoKBBSSig23G1SubProtocol::new(i, &s.revealed_messages, params, pk);
+                p.init(rng, b, w).map_err(|e| init_error(i, e))?;
+                sub_protocols.push(SubProtocol::PoKBBSSignature23G1(p));
+            }
+            (Statement::PoKPSSignature(s), Witness::PoKPSSignature(w)) => {
+                let b = blindings_for(w.unrevealed_messages.keys().copied().collect());
+                let params = s.get_sig_params(sp, i).map_err(|e| init_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| init_error(i, e))?;
+                let mut p = PSSignaturePoK::new(i, &s.revealed_messages, params, pk);
+                p.init(rng, b, w).map_err(|e| init_error(i, e))?;
+                sub_protocols.push(SubProtocol::PSSignaturePoK(p));
+            }
+            (Statement::AccumulatorMembership(s), Witness::AccumulatorMembership(w)) => {
+                let b = blindings_for(vec![0]).remove(&0);
+                let params = s.get_params(sp, i).map_err(|e| init_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| init_error(i, e))?;
+                let prk = s.get_proving_key(sp, i).map_err(|e| init_error(i, e))?;
+                let mut p =
+                    AccumulatorMembershipSubProtocol::new(i, params, pk, prk, s.accumulator_value);
+                p.init(rng, b, w).map_err(|e| init_error(i, e))?;
+                sub_protocols.push(SubProtocol::AccumulatorMembership(p));
+            }
+            (Statement::AccumulatorNonMembership(s), Witness::AccumulatorNonMembership(w)) => {
+                let b = blindings_for(vec![0]).remove(&0);
+                let params = s.get_params(sp, i).map_err(|e| init_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| init_error(i, e))?;
+                let prk = s.get_proving_key(sp, i).map_err(|e| init_error(i, e))?;
+                let mut p = AccumulatorNonMembershipSubProtocol::new(
+                    i,
+                    params,
+                    pk,
+                    prk,
+                    s.accumulator_value,
+                );
+                p.init(rng, b, w).map_err(|e| init_error(i, e))?;
+                sub_protocols.push(SubProtocol::AccumulatorNonMembership(p));
+            }
+            (Statement::PedersenCommitment(s), Witness::PedersenCommitment(w)) => {
+                let b = blindings_for((0..w.len()).collect());
+                let comm_key = s.get_commitment_key(sp, i).map_err(|e| init_error(i, e))?;
+                let mut p = SchnorrProtocol::new(i, comm_key, s.commitment);
+                p.init(rng, b, w).map_err(|e| init_error(i, e))?;
+                sub_protocols.push(SubProtocol::PoKDiscreteLogs(p));
+            }
+            (s, w) => return Err(unsupported_error(i, s, Some(&w))),
+        }
+    }
+    if !blindings.is_empty() {
+        return Err(Error::new(
+            ErrorCode::IndexOutOfBounds,
+            format!(
+                "Witness equalities refer to witnesses {:?} that don't exist",
+                blindings.keys().collect::<Vec<_>>()
+            ),
+        ));
+    }
+    Ok(sub_protocols)
+}
+
+pub(crate) fn commitments(
+    proof_spec: &ProofSpec<G1Affine>,
+    sub_protocols: &[SubProtocol<'_, Bls12_381, G1Affine>],
+) -> Result<Vec<u8>, ProofSystemError> {
+    let mut bytes = vec![];
+    if let Some(ctx) = &proof_spec.context {
+        bytes.extend_from_slice(ctx);
+    }
+    for sp in sub_protocols {
+        sp.challenge_contribution(&mut bytes)?;
+    }
+    Ok(bytes)
+}
+
+/// Verify the proof received from the prover for the commitments received earlier and the challenge sent
+pub fn verify(
+    proof: &ProofG1,
+    proof_spec: &ProofSpec<G1Affine>,
+    commitments: &[u8],
+    challenge: &Fr,
+) -> native::Result<()> {
+    let bytes = verify_statement_proofs(&proof.statement_proofs, proof_spec, challenge)?;
+    // The commitments in the proof must be the ones received before sending the challenge
+    if bytes != commitments {
+        return Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Proof isn't for the commitments received before the challenge",
+        ));
+    }
+    Ok(())
+}
+
+/// Verify the proof of each statement of `proof_spec` for `challenge` and the witness equalities among them. Returns
+/// the commitments of the proofs as `commitments` returns them for the prover.
+pub(crate) fn verify_statement_proofs(
+    statement_proofs: &[StatementProofG1],
+    proof_spec: &ProofSpec<G1Affine>,
+    challenge: &Fr,
+) -> native::Result<Vec<u8>> {
+    proof_spec
+        .validate()
+        .map_err(|e| Error::from(e).context("Invalid proof spec"))?;
+    if statement_proofs.len() != proof_spec.statements.len() {
+        return Err(Error::invalid_argument(format!(
+            "Proof has {} statement proofs but proof spec has {} statements",
+            statement_proofs.len(),
+            proof_spec.statements.len()
+        )));
+    }
+    let sp = &proof_spec.setup_params;
+    let mut bytes = vec![];
+    if let Some(ctx) = &proof_spec.context {
+        bytes.extend_from_slice(ctx);
+    }
+    let mut responses = BTreeMap::<WitnessRef, Vec<u8>>::new();
+    for (i, (statement, statement_proof)) in proof_spec
+        .statements
+        .0
+        .iter()
+        .zip(statement_proofs.iter())
+        .enumerate()
+    {
+        let details = get_statement_proof_details(i, statement, statement_proof, proof_spec)?;
+        for (j, r) in details.responses {
+            responses.insert((i, j), r);
+        }
+        match (statement, statement_proof) {
+            (Statement::PoKBBSSignatureG1(s), StatementProof::PoKBBSSignatureG1(p)) => {
+                let params = s.get_sig_params(sp, i).map_err(|e| verify_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| verify_error(i, e))?;
+                p.challenge_contribution(&s.revealed_messages, params, &mut bytes)
+                    .map_err(|e| verify_error(i, e))?;
+                PoKBBSSigG1SubProtocol::new(i, &s.revealed_messages, params, pk)
+                    .verify_proof_contribution(challenge, p, pk.clone(), params.clone(), &mut None)
+                    .map_err(|e| verify_error(i, e))?;
+            }
+            (Statement::PoKBBSSignature23G1(s), StatementProof::PoKBBSSignature23G1(p)) => {
+                let params = s.get_sig_params(sp, i).map_err(|e| verify_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| verify_error(i, e))?;
+                p.challenge_contribution(&s.revealed_messages, params, &mut bytes)
+                    .map_err(|e| verify_error(i, e))?;
+                PoKBBSSig23G1SubProtocol::new(i, &s.revealed_messages, params, pk)
+                    .verify_proof_contribution(challenge, p, pk.clone(), params.clone(), &mut None)
+                    .map_err(|e| verify_error(i, e))?;
+            }
+            (Statement::PoKPSSignature(s), StatementProof::PoKPSSignature(p)) => {
+                let params = s.get_sig_params(sp, i).map_err(|e| verify_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| verify_error(i, e))?;
+                p.challenge_contribution(&mut bytes, pk, params)
+                    .map_err(|e| verify_error(i, e))?;
+                PSSignaturePoK::new(i, &s.revealed_messages, params, pk)
+                    .verify_proof_contribution(challenge, p, pk.clone(), params.clone(), &mut None)
+                    .map_err(|e| verify_error(i, e))?;
+            }
+            (Statement::AccumulatorMembership(s), StatementProof::AccumulatorMembership(p)) => {
+                let params = s.get_params(sp, i).map_err(|e| verify_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| verify_error(i, e))?;
+                let prk = s.get_proving_key(sp, i).map_err(|e| verify_error(i, e))?;
+                p.challenge_contribution(&s.accumulator_value, pk, params, prk, &mut bytes)
+                    .map_err(|e| verify_error(i, e))?;
+                AccumulatorMembershipSubProtocol::new(i, params, pk, prk, s.accumulator_value)
+                    .verify_proof_contribution(challenge, p, pk.clone(), params.clone(), &mut None)
+                    .map_err(|e| verify_error(i, e))?;
+            }
+            (
+                Statement::AccumulatorNonMembership(s),
+                StatementProof::AccumulatorNonMembership(p),
+            ) => {
+                let params = s.get_params(sp, i).map_err(|e| verify_error(i, e))?;
+                let pk = s.get_public_key(sp, i).map_err(|e| verify_error(i, e))?;
+                let prk = s.get_proving_key(sp, i).map_err(|e| verify_error(i, e))?;
+                p.challenge_contribution(&s.accumulator_value, pk, params, prk, &mut bytes)
+                    .map_err(|e| verify_error(i, e))?;
+                AccumulatorNonMembershipSubProtocol::new(i, params, pk, prk, s.accumulator_value)
+                    .verify_proof_contribution(challenge, p, pk.clone(), params.clone(), &mut None)
+                    .map_err(|e| verify_error(i, e))?;
+            }
+            (Statement::PedersenCommitment(s), StatementProof::PedersenCommitment(p)) => {
+                let comm_key = s
+                    .get_commitment_key(sp, i)
+                    .map_err(|e| verify_error(i, e))?;
+                SchnorrProtocol::compute_challenge_contribution(
+                    comm_key,
+                    &s.commitment,
+                    &p.t,
+                    &mut bytes,
+                )
+                .map_err(|e| verify_error(i, e))?;
+                SchnorrProtocol::new(i, comm_key, s.commitment)
+                    .verify_proof_contribution_as_struct(challenge, p)
+                    .map_err(|e| verify_error(i, e))?;
+            }
+            (s, _) => return Err(unsupported_error(i, s, None)),
+        }
+    }
+    for eq_wits in proof_spec.meta_statements.disjoint_witness_equalities() {
+        let mut expected: Option<&Vec<u8>> = None;
+        for wr in eq_wits.0.iter() {
+            let r = responses.get(wr).ok_or_else(|| {
+                Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!("No response for witness {:?} in an equality", wr),
+                )
+            })?;
+            match expected {
+                Some(e) if e != r => {
+                    return Err(Error::new(
+                        ErrorCode::UnsatisfiedWitnessEquality,
+                        format!("Witness equality {:?} not satisfied", eq_wits.0),
+                    ))
+                }
+                _ => expected = Some(r),
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+fn verify_error<E: Into<Error>>(i: usize, e: E) -> Error {
+    e.into()
+        .context(format!("Verifying statement {} returned error", i))
+}
+
+fn unsupported_error(
+    i: usize,
+    statement: &Statement<Bls12_381, G1Affine>,
+    witness: Option<&Witness>,
+) -> Error {
+    let supported = matches!(
+        statement,
+        Statement::PoKBBSSignatureG1(_)
+            | Statement::PoKBBSSignature23G1(_)
+            | Statement::PoKPSSignature(_)
+            | Statement::AccumulatorMembership(_)
+            | Statement::AccumulatorNonMembership(_)
+            | Statement::PedersenCommitment(_)
+    );
+    let name = statement_type_name(statement);
+    let message = if !supported {
+        format!(
+            "Statement {} of type {} isn't supported in interactive proofs",
+            i, name
+        )
+    } else if witness.is_some() {
+        format!("Witness {} isn't for a statement of type {}", i, name)
+    } else {
+        format!("Proof {} isn't for a statement of type {}", i, name)
+    };
+    Error::invalid_argument(message)
+}
@@ -1,3 +1,7 @@
+pub mod interactive;
+pub mod or_statement;
+pub mod proof_details;
+pub mod proof_spec;
 pub mod setup_params;
 pub mod statement;
 
@@ -8,16 +12,25 @@ use crate::{
     bbs::BBSSignature,
     bbs_plus::BBSPlusSigG1,
     common::VerifyResponse,
+    handles::ProofSpecG1Handle,
+    native::{
+        self,
+        composite_proof_system::{DkgithEncryption, SaverMultiRecipientEncryption},
+        dkgith::{DkgithParams, DkgithProof, DkgithPublicKey},
+        saver::MultiRecipientProof,
+        ErrorCode,
+    },
     ps::PSSignature,
+    r1cs::js_array_to_signal_values,
+    saver::objs_from_js_array,
     utils::{
-        encode_messages_as_js_map_to_fr_btreemap, fr_from_uint8_array, get_seeded_rng,
+        encode_messages_as_js_map_to_fr_btreemap, fr_from_uint8_array, from_value,
         js_array_to_fr_vec, set_panic_hook,
     },
     Fr, G1Affine,
 };
 use ark_bls12_381::Bls12_381;
 use ark_ec::{pairing::Pairing, AffineRepr};
-use blake2::Blake2b512;
 use js_sys::Uint8Array;
 use proof_system::{
     prelude::{
@@ -34,11 +47,31 @@ pub(crate) type PoKBBSPlusSigWit = witness::PoKBBSSignatureG1<Bls12_381>;
 pub(crate) type PokPSSigWit = witness::PoKPSSignature<Bls12_381>;
 pub(crate) type AccumMemWit = witness::Membership<Bls12_381>;
 pub(crate) type AccumNonMemWit = witness::NonMembership<Bls12_381>;
-pub(crate) type ProofSpec<G> = proof_system::proof_spec::ProofSpec<Bls12_381, G>;
-pub(crate) type Proof<G> = proof::Proof<Bls12_381, G>;
-pub(crate) type ProofG1 = proof::Proof<Bls12_381, G1Affine>;
+pub type ProofSpec<G> = proof_system::proof_spec::ProofSpec<Bls12_381, G>;
+pub type Proof<G> = proof::Proof<Bls12_381, G>;
+pub type ProofG1 = proof::Proof<Bls12_381, G1Affine>;
 pub(crate) type StatementProofG1 = proof_system::prelude::StatementProof<Bls12_381, G1Affine>;
 
+/// Witnesses that are zeroized when dropped so that they are on every path out of the function owning them. The
+/// witnesses for signatures and accumulators zeroize themselves so only the others are zeroized here.
+pub struct SecretWitnesses(pub Witnesses);
+
+impl Drop for SecretWitnesses {
+    fn drop(&mut self) {
+        for w in self.0 .0.iter_mut() {
+            match w {
+                Witness::PedersenCommitment(w) => w.zeroize(),
+                Witness::PoKPSSignature(w) => {
+                    w.unrevealed_messages.values_mut().for_each(|m| m.zeroize())
+                }
+                Witness::Saver(w) | Witness::BoundCheckLegoGroth16(w) => w.zeroize(),
+                Witness::R1CSLegoGroth16(w) => w.zeroize(),
+                _ => (),
+            }
+        }
+    }
+}
+
 #[wasm_bindgen(js_name = generatePoKBBSSignatureWitness)]
 pub fn generate_pok_bbs_sig_witness(
     signature: Uint8Array,
@@ -85,7 +118,7 @@ pub fn generate_accumulator_membership_witness(
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
     let element = fr_from_uint8_array(element, true)?;
-    let accum_witness: MembershipWit = serde_wasm_bindgen::from_value(accum_witness)?;
+    let accum_witness: MembershipWit = from_value(accum_witness)?;
     let witness = AccumMemWit::new_as_witness(element, accum_witness);
     serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
 }
@@ -97,7 +130,7 @@ pub fn generate_accumulator_non_membership_witness(
 ) -> Result<JsValue, JsValue> {
     set_panic_hook();
     let element = fr_from_uint8_array(element, true)?;
-    let accum_witness: NonMembershipWit = serde_wasm_bindgen::from_value(accum_witness)?;
+    let accum_witness: NonMembershipWit = from_value(accum_witness)?;
     let witness = AccumNonMemWit::new_as_witness(element, accum_witness);
     serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
 }
@@ -110,20 +143,157 @@ pub fn generate_pedersen_commitment_witness(elements: js_sys::Array) -> Result<J
     serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
 }
 
+/// Create the witness for a linear relation statement from the witnesses of the other statements. `witnesses` are
+/// the witnesses of all statements before the linear relation statement and `witness_refs` is the same as the one
+/// passed to `generateLinearRelationMetaStatements`.
+#[wasm_bindgen(js_name = generateLinearRelationWitness)]
+pub fn generate_linear_relation_witness(
+    witnesses: js_sys::Array,
+    witness_refs: js_sys::Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let mut elements = Vec::with_capacity(witness_refs.length() as usize);
+    for wr in witness_refs.iter() {
+        let (s_idx, w_idx) = statement::parse_witness_ref(&wr)?;
+        if s_idx >= witnesses.length() as usize {
+            return Err(native::Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!(
+                    "Witness reference ({}, {}) is for statement {} but only {} witnesses given",
+                    s_idx,
+                    w_idx,
+                    s_idx,
+                    witnesses.length()
+                ),
+            )
+            .into());
+        }
+        let witness: Witness = from_value(witnesses.get(s_idx as u32))?;
+        elements.push(get_witness_element(&witness, w_idx).ok_or_else(|| {
+            native::Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!("Statement {} has no witness at index {}", s_idx, w_idx),
+            )
+        })?);
+    }
+    let witness = Witness::PedersenCommitment(elements);
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
+}
+
+/// Create the witness for the statement created with `generateBBSPlusBlindSignatureRequestStatement`. `blinding` and
+/// `messages_to_commit` are the same as passed to `bbsPlusCommitMsgsInG1`.
+#[wasm_bindgen(js_name = generateBBSPlusBlindSignatureRequestWitness)]
+pub fn generate_bbs_plus_blind_signature_request_witness(
+    messages_to_commit: js_sys::Map,
+    blinding: Uint8Array,
+    encode_messages: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let msgs = encode_messages_as_js_map_to_fr_btreemap(&messages_to_commit, encode_messages)?;
+    let mut elements = vec![fr_from_uint8_array(blinding, true)?];
+    elements.extend(msgs.into_values());
+    let witness = Witness::PedersenCommitment(elements);
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
+}
+
+/// Create the witness for the statement created with `generateBBSBlindSignatureRequestStatement`. `messages_to_commit`
+/// is the same as passed to `bbsCommitMsgs`.
+#[wasm_bindgen(js_name = generateBBSBlindSignatureRequestWitness)]
+pub fn generate_bbs_blind_signature_request_witness(
+    messages_to_commit: js_sys::Map,
+    encode_messages: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let msgs = encode_messages_as_js_map_to_fr_btreemap(&messages_to_commit, encode_messages)?;
+    let witness = Witness::PedersenCommitment(msgs.into_values().collect());
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
+}
+
+/// Create the witnesses for the statements created with `generatePSBlindSignatureRequestStatements`, in the same
+/// order. `messages_to_commit` and `blindings` map each committed message index to the message and blinding passed to
+/// `psMessageCommitment`.
+#[wasm_bindgen(js_name = generatePSBlindSignatureRequestWitnesses)]
+pub fn generate_ps_blind_signature_request_witnesses(
+    messages_to_commit: js_sys::Map,
+    blindings: js_sys::Map,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let msgs = encode_messages_as_js_map_to_fr_btreemap(&messages_to_commit, false)?;
+    let mut blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
+    let witnesses = js_sys::Array::new();
+    for (i, m) in msgs {
+        let blinding = blindings.remove(&i).ok_or_else(|| {
+            native::Error::invalid_argument(format!("No blinding for message index {}", i))
+        })?;
+        let witness = Witness::PedersenCommitment(vec![blinding, m]);
+        witnesses.push(&serde_wasm_bindgen::to_value(&witness)?);
+    }
+    if !blindings.is_empty() {
+        return Err(native::Error::invalid_argument(format!(
+            "Blindings given for indices {:?} that have no message",
+            blindings.keys().collect::<Vec<_>>()
+        ))
+        .into());
+    }
+    Ok(witnesses)
+}
+
+/// Create a proof spec in group G1. When "OR" meta-statements created by `generateOrMetaStatement` are given,
+/// the statements are proven together with these and `or_witness_equalities`, created by
+/// `generateOrWitnessEquality`, says which witnesses of the statements are equal to witnesses of the "OR"
+/// meta-statements. The returned proof spec is then to be used with `generateCompositeProofG1WithOrMetaStatements`
+/// and `verifyCompositeProofG1WithOrMetaStatements`.
 #[wasm_bindgen(js_name = generateProofSpecG1)]
 pub fn generate_proof_spec_g1(
     statements: js_sys::Array,
     meta_statements: js_sys::Array,
     setup_params: js_sys::Array,
     context: Option<Vec<u8>>,
+    or_meta_statements: Option<js_sys::Array>,
+    or_witness_equalities: Option<js_sys::Array>,
 ) -> Result<Uint8Array, JsValue> {
     set_panic_hook();
-    gen_proof_spec::<<Bls12_381 as Pairing>::G1Affine>(
+    let or_meta_statements = or_meta_statements.unwrap_or_default();
+    let or_witness_equalities = or_witness_equalities.unwrap_or_default();
+    if or_meta_statements.length() == 0 {
+        if or_witness_equalities.length() > 0 {
+            return Err(native::Error::invalid_argument(
+                "Witness equalities with OR meta-statements given without OR meta-statements",
+            )
+            .into());
+        }
+        return gen_proof_spec::<<Bls12_381 as Pairing>::G1Affine>(
+            statements,
+            meta_statements,
+            setup_params,
+            context,
+        );
+    }
+    let (stmts, meta_stmts, setup_params) = parse_statements_meta_statements_and_setup_params(
         statements,
         meta_statements,
         setup_params,
-        context,
-    )
+    )?;
+    let mut equalities = Vec::with_capacity(or_witness_equalities.length() as usize);
+    for e in or_witness_equalities.values() {
+        let e = Uint8Array::new(&e.unwrap());
+        equalities.push(obj_from_uint8array!(
+            or_statement::OrWitnessEquality,
+            &e,
+            false,
+            "OrWitnessEquality"
+        ));
+    }
+    let proof_spec = or_statement::ProofSpecWithOrG1 {
+        proof_spec: ProofSpec::new(stmts, meta_stmts, setup_params, context),
+        or_meta_statements: or_statement::parse_or_meta_statements(&or_meta_statements)?,
+        or_witness_equalities: equalities,
+    };
+    proof_spec.validate()?;
+    Ok(obj_to_uint8array_uncompressed!(
+        &proof_spec,
+        "ProofSpecWithOr"
+    ))
 }
 
 #[wasm_bindgen(js_name = isProofSpecG1Valid)]
@@ -211,6 +381,23 @@ pub fn generate_composite_proof_g1_with_deconstructed_proof_spec(
     gen_proof_given_proof_spec_obj::<<Bls12_381 as Pairing>::G1Affine>(proof_spec, witnesses, nonce)
 }
 
+/// Same as `generate_composite_proof_g1` but takes a handle to the `ProofSpec` so that it isn't deserialized again
+/// for each proof
+#[wasm_bindgen(js_name = generateCompositeProofG1WithHandle)]
+pub fn generate_composite_proof_g1_with_handle(
+    proof_spec: &ProofSpecG1Handle,
+    witnesses: js_sys::Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    // The prover of `proof_system` takes the `ProofSpec` by value so it is cloned
+    gen_proof_given_proof_spec_obj::<<Bls12_381 as Pairing>::G1Affine>(
+        proof_spec.0.clone(),
+        witnesses,
+        nonce,
+    )
+}
+
 #[wasm_bindgen(js_name = verifyCompositeProofG1)]
 pub fn verify_composite_proof_g1(
     proof: Uint8Array,
@@ -258,6 +445,180 @@ pub fn verify_composite_proof_g1_with_deconstructed_proof_spec(
     verify_proof_given_proof_spec_obj::<<Bls12_381 as Pairing>::G1Affine>(proof_spec, proof, nonce)
 }
 
+/// Same as `verify_composite_proof_g1` but takes a handle to the `ProofSpec` so that it isn't deserialized again
+/// for each proof
+#[wasm_bindgen(js_name = verifyCompositeProofG1WithHandle)]
+pub fn verify_composite_proof_g1_with_handle(
+    proof: Uint8Array,
+    proof_spec: &ProofSpecG1Handle,
+    nonce: Option<Vec<u8>>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    // The verifier of `proof_system` takes the `ProofSpec` by value so it has to be cloned, see
+    // `generate_composite_proof_g1_with_handle` for its cost
+    verify_proof_given_proof_spec_obj::<<Bls12_381 as Pairing>::G1Affine>(
+        proof_spec.0.clone(),
+        proof,
+        nonce,
+    )
+}
+
+/// Verify the composite proof and the proofs returned by `dkgithEncrypt` for its statements created with
+/// `generateDkgithStatement`. `dkgith_proofs` maps the index of such a statement to an array of the proof, the
+/// params and the auditor's public key. Each proof is verified for the commitment in its statement and must have
+/// been created with this composite proof's `nonce`.
+#[wasm_bindgen(js_name = verifyCompositeProofG1WithDkgith)]
+pub fn verify_composite_proof_g1_with_dkgith(
+    proof: Uint8Array,
+    proof_spec: Uint8Array,
+    nonce: Option<Vec<u8>>,
+    dkgith_proofs: js_sys::Map,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof_spec = obj_from_uint8array_uncompressed!(
+        ProofSpec::<<Bls12_381 as Pairing>::G1Affine>,
+        proof_spec,
+        "ProofSpecG1"
+    );
+    let proof = obj_from_uint8array!(ProofG1, proof, false);
+    let mut encryptions = Vec::with_capacity(dkgith_proofs.size() as usize);
+    for e in dkgith_proofs.entries() {
+        let entry = js_sys::Array::from(&e.unwrap());
+        let statement_index: usize = from_value(entry.get(0))?;
+        let value = js_sys::Array::from(&entry.get(1));
+        if value.length() != 3 {
+            return Err(native::Error::invalid_argument(format!(
+                "Expected an array of the DKGitH proof, params and public key for statement index {}",
+                statement_index
+            ))
+            .into());
+        }
+        encryptions.push(DkgithEncryption {
+            statement_index,
+            proof: obj_from_uint8array!(
+                DkgithProof,
+                Uint8Array::new(&value.get(0)),
+                false,
+                "DkgithProof"
+            ),
+            params: obj_from_uint8array!(
+                DkgithParams,
+                Uint8Array::new(&value.get(1)),
+                false,
+                "DkgithParams"
+            ),
+            public_key: obj_from_uint8array!(
+                DkgithPublicKey,
+                Uint8Array::new(&value.get(2)),
+                false,
+                "DkgithPublicKey"
+            ),
+        });
+    }
+    let result = native::composite_proof_system::verify_proof_with_dkgith(
+        proof,
+        proof_spec,
+        nonce,
+        &encryptions,
+    )
+    .map_err(|e| e.context("Verifying proof returned error"));
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Verify the composite proof and the proofs returned by `saverEncryptForRecipients` for its statements created with
+/// `generateSaverMultiRecipientStatement`. `encryptions` maps the index of such a statement to an array of the
+/// array of ciphertexts, the proof, the array of encryption keys, the array of snark verification keys of each
+/// recipient and the chunk bit size. Each proof is verified for the commitment and commitment key in its statement
+/// and must have been created with this composite proof's `nonce`. If `uncompressed_public_params` is true, it
+/// expects the encryption keys and snark verification keys in uncompressed form.
+#[wasm_bindgen(js_name = verifyCompositeProofG1WithSaverMultiRecipient)]
+pub fn verify_composite_proof_g1_with_saver_multi_recipient(
+    proof: Uint8Array,
+    proof_spec: Uint8Array,
+    nonce: Option<Vec<u8>>,
+    encryptions: js_sys::Map,
+    uncompressed_public_params: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof_spec = obj_from_uint8array_uncompressed!(
+        ProofSpec::<<Bls12_381 as Pairing>::G1Affine>,
+        proof_spec,
+        "ProofSpecG1"
+    );
+    let proof = obj_from_uint8array!(ProofG1, proof, false);
+    let mut parsed = Vec::with_capacity(encryptions.size() as usize);
+    for e in encryptions.entries() {
+        let entry = js_sys::Array::from(&e.unwrap());
+        let statement_index: usize = from_value(entry.get(0))?;
+        let value = js_sys::Array::from(&entry.get(1));
+        if value.length() != 5 {
+            return Err(native::Error::invalid_argument(format!(
+                "Expected an array of the ciphertexts, proof, encryption keys, snark verification keys and chunk bit size for statement index {}",
+                statement_index
+            ))
+            .into());
+        }
+        parsed.push(SaverMultiRecipientEncryption {
+            statement_index,
+            ciphertexts: objs_from_js_array(
+                &js_sys::Array::from(&value.get(0)),
+                false,
+                "SaverCiphertext",
+            )?,
+            proof: obj_from_uint8array!(
+                MultiRecipientProof,
+                Uint8Array::new(&value.get(1)),
+                false,
+                "SaverMultiRecipientProof"
+            ),
+            encryption_keys: objs_from_js_array(
+                &js_sys::Array::from(&value.get(2)),
+                uncompressed_public_params,
+                "SaverEk",
+            )?,
+            snark_vks: objs_from_js_array(
+                &js_sys::Array::from(&value.get(3)),
+                uncompressed_public_params,
+                "SaverSnarkVk",
+            )?,
+            chunk_bit_size: from_value(value.get(4))?,
+        });
+    }
+    let result = native::composite_proof_system::verify_proof_with_saver_multi_recipient(
+        proof, proof_spec, nonce, &parsed,
+    )
+    .map_err(|e| e.context("Verifying proof returned error"));
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Create the witness for the statement created with `generateDkgithStatement`. `message` and `blinding` are the
+/// encoded message passed to and the blinding returned by `dkgithEncrypt`.
+#[wasm_bindgen(js_name = generateDkgithWitness)]
+pub fn generate_dkgith_witness(
+    message: Uint8Array,
+    blinding: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let message = fr_from_uint8_array(message, true)?;
+    let blinding = fr_from_uint8_array(blinding, true)?;
+    let witness = Witness::PedersenCommitment(vec![message, blinding]);
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
+}
+
+/// Create the witness for the statement created with `generateSaverMultiRecipientStatement`. `message` and
+/// `blinding` are the encoded message passed to and the blinding returned by `saverEncryptForRecipients`.
+#[wasm_bindgen(js_name = generateSaverMultiRecipientWitness)]
+pub fn generate_saver_multi_recipient_witness(
+    message: Uint8Array,
+    blinding: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let message = fr_from_uint8_array(message, true)?;
+    let blinding = fr_from_uint8_array(blinding, true)?;
+    let witness = Witness::PedersenCommitment(vec![message, blinding]);
+    serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
+}
+
 #[wasm_bindgen(js_name = generateSaverWitness)]
 pub fn generate_saver_witness(message: Uint8Array) -> Result<JsValue, JsValue> {
     set_panic_hook();
@@ -311,14 +672,14 @@ pub fn generate_r1cs_circom_witness(
     for p in privates.values() {
         let name_as_js_val = p.unwrap();
         let vals = js_sys::Array::from(&input_wires.get(&name_as_js_val));
-        let name: String = serde_wasm_bindgen::from_value(name_as_js_val)?;
-        r1cs_wit.set_private(name, js_array_to_fr_vec(&vals)?);
+        let name: String = from_value(name_as_js_val)?;
+        r1cs_wit.set_private(name, js_array_to_signal_values(&vals)?);
     }
     for p in publics.values() {
         let name_as_js_val = p.unwrap();
         let vals = js_sys::Array::from(&input_wires.get(&name_as_js_val));
-        let name: String = serde_wasm_bindgen::from_value(name_as_js_val)?;
-        r1cs_wit.set_public(name, js_array_to_fr_vec(&vals)?);
+        let name: String = from_value(name_as_js_val)?;
+        r1cs_wit.set_public(name, js_array_to_signal_values(&vals)?);
     }
     let witness = Witness::R1CSLegoGroth16(r1cs_wit);
     serde_wasm_bindgen::to_value(&witness).map_err(JsValue::from)
@@ -338,7 +699,7 @@ pub fn parse_statements_meta_statements_and_setup_params<G: AffineRepr>(
 > {
     let mut meta_stmts = MetaStatements::new();
     for ms in meta_statements.values() {
-        let meta_stmt: MetaStatement = serde_wasm_bindgen::from_value(ms.unwrap())?;
+        let meta_stmt: MetaStatement = from_value(ms.unwrap())?;
         meta_stmts.add(meta_stmt);
     }
     let mut stmts = Statements::<Bls12_381, G>::new();
@@ -398,13 +759,11 @@ fn gen_proof_given_proof_spec_obj<G: AffineRepr<ScalarField = Fr>>(
 ) -> Result<Uint8Array, JsValue> {
     let mut wits: Witnesses = witness::Witnesses::new();
     for w in witnesses.values() {
-        let wit: Witness = serde_wasm_bindgen::from_value(w.unwrap())?;
+        let wit: Witness = from_value(w.unwrap())?;
         wits.add(wit);
     }
-    let mut rng = get_seeded_rng();
-    let proof =
-        Proof::<G>::new::<_, Blake2b512>(&mut rng, proof_spec, wits, nonce, Default::default())
-            .map_err(|e| JsValue::from(&format!("Generating proof returned error: {:?}", e)))?;
+    let proof = native::composite_proof_system::generate_proof(proof_spec, wits, nonce)
+        .map_err(|e| e.context("Generating proof returned error"))?;
     Ok(obj_to_uint8array!(&proof, false, "Proof"))
 }
 
@@ -414,19 +773,9 @@ fn verify_proof_given_proof_spec_obj<G: AffineRepr<ScalarField = Fr>>(
     nonce: Option<Vec<u8>>,
 ) -> Result<JsValue, JsValue> {
     let proof = obj_from_uint8array!(Proof<G>, proof, false);
-    let mut rng = get_seeded_rng();
-    match proof.verify::<_, Blake2b512>(&mut rng, proof_spec, nonce, Default::default()) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("Verifying proof returned error {:?}", e)),
-        })
-        .unwrap()),
-    }
+    let result = native::composite_proof_system::verify_proof(proof, proof_spec, nonce);
+    let result = result.map_err(|e| e.context("Verifying proof returned error"));
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
 
 fn get_ciphertext_from_proof(
@@ -434,17 +783,39 @@ fn get_ciphertext_from_proof(
     statement_index: usize,
 ) -> Result<Uint8Array, JsValue> {
     let statement_proof = proof.statement_proof(statement_index).map_err(|_| {
-        JsValue::from(&format!(
-            "Did not find StatementProof at the given index {}",
-            statement_index
-        ))
+        native::Error::new(
+            ErrorCode::IndexOutOfBounds,
+            format!(
+                "Did not find StatementProof at the given index {}",
+                statement_index
+            ),
+        )
     })?;
     if let StatementProofG1::Saver(s) = statement_proof {
         Ok(obj_to_uint8array!(&s.ciphertext, false, "SaverCiphertext"))
     } else {
-        Err(JsValue::from(&format!(
+        Err(native::Error::invalid_argument(format!(
             "StatementProof at index {} wasn't for Saver",
             statement_index
-        )))
+        ))
+        .into())
+    }
+}
+
+/// Get the witness at index `idx` as the composite proof system refers to it
+pub(super) fn get_witness_element(witness: &Witness, idx: usize) -> Option<Fr> {
+    match witness {
+        Witness::PoKBBSSignatureG1(w) => w.unrevealed_messages.get(&idx).copied(),
+        Witness::PoKBBSSignature23G1(w) => w.unrevealed_messages.get(&idx).copied(),
+        Witness::PoKPSSignature(w) => w.unrevealed_messages.get(&idx).copied(),
+        Witness::AccumulatorMembership(w) if idx == 0 => Some(w.element),
+        Witness::AccumulatorNonMembership(w) if idx == 0 => Some(w.element),
+        Witness::PedersenCommitment(w) => w.get(idx).copied(),
+        Witness::Saver(w) | Witness::BoundCheckLegoGroth16(w) if idx == 0 => Some(*w),
+        Witness::R1CSLegoGroth16(w) => w
+            .get_first_n_private_inputs(idx as u32 + 1)
+            .ok()
+            .map(|inputs| inputs[idx]),
+        _ => None,
     }
 }
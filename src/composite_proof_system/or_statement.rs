@@ -0,0 +1,825 @@
+use ark_bls12_381::Bls12_381;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    rand::RngCore,
+    UniformRand, Zero,
+};
+use blake2::Blake2b512;
+use dock_crypto_utils::{concat_slices, hashing_utils::affine_group_elem_from_try_and_incr};
+use js_sys::Uint8Array;
+use proof_system::{
+    prelude::{EqualWitnesses, Statement, StatementProof, WitnessRef},
+    statement::ped_comm::PedersenCommitment,
+    statement_proof::PedersenCommitmentProof,
+};
+use schnorr_pok::{compute_random_oracle_challenge, SchnorrResponse};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    common::VerifyResponse,
+    composite_proof_system::{
+        get_witness_element,
+        interactive::{commitments, init_statement_sub_protocols, verify_statement_proofs},
+        statement::parse_witness_ref,
+        ProofG1, ProofSpec, SecretWitnesses, StatementProofG1, Witness, Witnesses,
+    },
+    issuer_hiding::{simulate_bbs_plus_proof, simulate_bbs_proof},
+    native::{self, Error, ErrorCode},
+    utils::{from_value, g1_affine_from_uint8_array, get_seeded_rng, set_panic_hook},
+    Fr, G1Affine,
+};
+
+/// A disjunction ("OR") of proof specs in group G1. A proof for it convinces the verifier that the prover knows
+/// the witnesses of at least one of the proof specs without revealing which one, e.g. that the holder has a
+/// credential from issuer A or one from issuer B. Follows the OR composition of Cramer, Damgård and Schoenmakers
+/// so only statements whose proofs can be simulated are supported, i.e. Pedersen commitments and proofs of
+/// knowledge of BBS+ and BBS signatures. Simulating the proof of knowledge of a signature needs the signer's public
+/// key in group G1 so `public_keys_g1` has these for each proof spec, keyed by the index of the signature
+/// statement. Witness equalities are supported within each proof spec.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct OrMetaStatement {
+    pub proof_specs: Vec<ProofSpec<G1Affine>>,
+    pub public_keys_g1: Vec<BTreeMap<usize, G1Affine>>,
+}
+
+/// Proof for an `OrMetaStatement`. Contains a challenge and a proof for each statement of each proof spec.
+/// The challenges add up to the challenge computed from the transcript.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct OrProof {
+    pub challenges: Vec<Fr>,
+    pub proofs: Vec<Vec<StatementProofG1>>,
+}
+
+/// Equality of the witness `witness_ref` of the statements of a proof spec and a witness of the "OR"
+/// meta-statement at `or_index`. `branch_witness_refs` has the equal witness in each proof spec of the
+/// meta-statement so that the equality holds whichever of them is satisfied.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct OrWitnessEquality {
+    pub witness_ref: WitnessRef,
+    pub or_index: usize,
+    pub branch_witness_refs: Vec<WitnessRef>,
+}
+
+/// Proof spec whose statements are proven together with `OrMetaStatement`s, created by `generateProofSpecG1`
+/// when it's given "OR" meta-statements. Each witness equality in `or_witness_equalities` is proven with a
+/// Pedersen commitment to the witness, which the composite proof and every proof spec of the "OR" meta-statement
+/// prove to open to their equal witness. So a proof for an "OR" meta-statement can only be used with the
+/// composite proof whose witnesses it's about.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProofSpecWithOrG1 {
+    pub proof_spec: ProofSpec<G1Affine>,
+    pub or_meta_statements: Vec<OrMetaStatement>,
+    pub or_witness_equalities: Vec<OrWitnessEquality>,
+}
+
+/// Composite proof along with a proof for each of the `OrMetaStatement`s of a `ProofSpecWithOrG1`, all created for
+/// the same nonce and verified together. `commitments` has the commitment to the witness of each of its witness
+/// equalities.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CompositeProofWithOrG1 {
+    pub proof: ProofG1,
+    pub or_proofs: Vec<OrProof>,
+    pub commitments: Vec<G1Affine>,
+}
+
+/// Label to create the bases of the commitments that prove the witness equalities of a `ProofSpecWithOrG1`
+const OR_WITNESS_EQUALITY_LABEL: &[u8] = b"OR-WITNESS-EQUALITY";
+
+/// Create an "OR" meta-statement from the given proof specs in group G1, i.e. the proof will prove that at least
+/// one of the proof specs is satisfied. Each proof spec is created using `generateProofSpecG1` and can contain
+/// Pedersen commitment statements, BBS+ and BBS signature statements and witness equalities among them.
+/// `public_keys_g1` has a map for each proof spec from the index of each signature statement to the signer's
+/// public key in group G1 as created by `bbsPlusGeneratePublicKeyG1ForIssuerHiding` or
+/// `bbsGeneratePublicKeyG1ForIssuerHiding`. It can be omitted if no proof spec has signature statements.
+#[wasm_bindgen(js_name = generateOrMetaStatement)]
+pub fn generate_or_meta_statement(
+    proof_specs: js_sys::Array,
+    public_keys_g1: Option<js_sys::Array>,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let mut specs = Vec::with_capacity(proof_specs.length() as usize);
+    for s in proof_specs.values() {
+        let s = Uint8Array::new(&s.unwrap());
+        specs.push(obj_from_uint8array_uncompressed!(
+            ProofSpec<G1Affine>,
+            &s,
+            "ProofSpec"
+        ));
+    }
+    let keys = match public_keys_g1 {
+        Some(public_keys_g1) => {
+            let mut keys = Vec::with_capacity(public_keys_g1.length() as usize);
+            for m in public_keys_g1.values() {
+                let mut k = BTreeMap::new();
+                for e in js_sys::Map::from(m.unwrap()).entries() {
+                    let arr = js_sys::Array::from(&e.unwrap());
+                    let index: usize = from_value(arr.get(0))?;
+                    k.insert(
+                        index,
+                        g1_affine_from_uint8_array(Uint8Array::new(&arr.get(1)))?,
+                    );
+                }
+                keys.push(k);
+            }
+            keys
+        }
+        None => vec![BTreeMap::new(); specs.len()],
+    };
+    let meta_statement = OrMetaStatement {
+        proof_specs: specs,
+        public_keys_g1: keys,
+    };
+    meta_statement.validate()?;
+    Ok(obj_to_uint8array_uncompressed!(
+        &meta_statement,
+        "OrMetaStatement"
+    ))
+}
+
+/// Create a proof for the "OR" meta-statement given the witnesses for the proof spec at index `satisfied_index`.
+/// The proof does not reveal `satisfied_index`.
+#[wasm_bindgen(js_name = generateOrProofG1)]
+pub fn generate_or_proof_g1(
+    or_meta_statement: Uint8Array,
+    satisfied_index: usize,
+    witnesses: js_sys::Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let meta_statement =
+        obj_from_uint8array_uncompressed!(OrMetaStatement, or_meta_statement, "OrMetaStatement");
+    let witnesses = parse_witnesses(&witnesses)?;
+    let mut rng = get_seeded_rng()?;
+    let proof = meta_statement
+        .prove(&mut rng, satisfied_index, witnesses, &nonce)
+        .map_err(|e| e.context("Generating OR proof returned error"))?;
+    Ok(obj_to_uint8array!(&proof, false, "OrProof"))
+}
+
+#[wasm_bindgen(js_name = verifyOrProofG1)]
+pub fn verify_or_proof_g1(
+    proof: Uint8Array,
+    or_meta_statement: Uint8Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let meta_statement =
+        obj_from_uint8array_uncompressed!(OrMetaStatement, or_meta_statement, "OrMetaStatement");
+    let proof = obj_from_uint8array!(OrProof, proof, false, "OrProof");
+    let result = meta_statement
+        .verify(&proof, &nonce)
+        .map_err(|e| e.context("Verifying OR proof returned error"));
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Create the witness equality of the witness `witness_ref` of the statements passed to `generateProofSpecG1` and
+/// a witness of the "OR" meta-statement at `or_index` of the ones passed along. `branch_witness_refs` has the
+/// equal witness in each proof spec of that meta-statement, in the same order. Witness references are 2 element
+/// arrays `[statement index, witness index]`.
+#[wasm_bindgen(js_name = generateOrWitnessEquality)]
+pub fn generate_or_witness_equality(
+    witness_ref: JsValue,
+    or_index: usize,
+    branch_witness_refs: js_sys::Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let equality = OrWitnessEquality {
+        witness_ref: parse_witness_ref(&witness_ref)?,
+        or_index,
+        branch_witness_refs: branch_witness_refs
+            .iter()
+            .map(|wr| parse_witness_ref(&wr))
+            .collect::<Result<_, _>>()?,
+    };
+    Ok(obj_to_uint8array!(&equality, false, "OrWitnessEquality"))
+}
+
+/// Create a composite proof of the statements of a proof spec created by `generateProofSpecG1` with "OR"
+/// meta-statements along with a proof for each of these, such that the verifier checks them together with
+/// `verifyCompositeProofG1WithOrMetaStatements`. E.g. to prove possession of a credential and that the holder
+/// has one of several other credentials with the same secret. `satisfied_indices` and `or_witnesses` have the index
+/// of the satisfied proof spec and its witnesses for each "OR" meta-statement.
+#[wasm_bindgen(js_name = generateCompositeProofG1WithOrMetaStatements)]
+pub fn generate_composite_proof_g1_with_or_meta_statements(
+    proof_spec: Uint8Array,
+    witnesses: js_sys::Array,
+    satisfied_indices: js_sys::Array,
+    or_witnesses: js_sys::Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpecWithOrG1, proof_spec, "ProofSpecWithOr");
+    let mut indices = Vec::with_capacity(satisfied_indices.length() as usize);
+    for (i, idx) in satisfied_indices.iter().enumerate() {
+        indices.push(serde_wasm_bindgen::from_value(idx).map_err(|e| {
+            Error::invalid_argument(format!("Invalid satisfied index {}: {}", i, e))
+        })?);
+    }
+    let mut or_wits = Vec::with_capacity(or_witnesses.length() as usize);
+    for w in or_witnesses.iter() {
+        or_wits.push(parse_witnesses(&js_sys::Array::from(&w))?);
+    }
+    let mut wits = Witnesses::new();
+    for w in witnesses.values() {
+        let wit: Witness = from_value(w.unwrap())?;
+        wits.add(wit);
+    }
+    let mut rng = get_seeded_rng()?;
+    let proof = proof_spec
+        .prove(&mut rng, SecretWitnesses(wits), &indices, or_wits, nonce)
+        .map_err(|e| e.context("Generating proof returned error"))?;
+    Ok(obj_to_uint8array!(&proof, false, "CompositeProofWithOr"))
+}
+
+/// Verify a proof created with `generateCompositeProofG1WithOrMetaStatements`. It's valid only if the composite
+/// proof and the proofs of all "OR" meta-statements are.
+#[wasm_bindgen(js_name = verifyCompositeProofG1WithOrMetaStatements)]
+pub fn verify_composite_proof_g1_with_or_meta_statements(
+    proof: Uint8Array,
+    proof_spec: Uint8Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(CompositeProofWithOrG1, proof, false, "CompositeProofWithOr");
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpecWithOrG1, proof_spec, "ProofSpecWithOr");
+    let result = proof_spec.verify(proof, nonce);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+impl OrMetaStatement {
+    pub fn validate(&self) -> native::Result<()> {
+        if self.proof_specs.len() < 2 {
+            return Err(Error::invalid_argument(
+                "OR meta-statement needs at least 2 proof specs",
+            ));
+        }
+        if self.public_keys_g1.len() != self.proof_specs.len() {
+            return Err(Error::new(
+                ErrorCode::MessageCountMismatch,
+                format!(
+                    "Expected public keys in G1 for {} proof specs but found {}",
+                    self.proof_specs.len(),
+                    self.public_keys_g1.len()
+                ),
+            ));
+        }
+        for (i, (spec, keys)) in self
+            .proof_specs
+            .iter()
+            .zip(self.public_keys_g1.iter())
+            .enumerate()
+        {
+            spec.validate()
+                .map_err(|e| Error::from(e).context(format!("Proof spec {} is invalid", i)))?;
+            let sp = &spec.setup_params;
+            for (j, s) in spec.statements.0.iter().enumerate() {
+                // The key in G1 must be for the same secret key as the one in G2 else the simulated proofs
+                // wouldn't verify
+                let pairs = match s {
+                    Statement::PedersenCommitment(_) => None,
+                    Statement::PoKBBSSignatureG1(s) => {
+                        let params = s.get_sig_params(sp, j)?;
+                        let pk = s.get_public_key(sp, j)?;
+                        Some((params.g1, params.g2, pk.0))
+                    }
+                    Statement::PoKBBSSignature23G1(s) => {
+                        let params = s.get_sig_params(sp, j)?;
+                        let pk = s.get_public_key(sp, j)?;
+                        Some((params.g1, params.g2, pk.0))
+                    }
+                    _ => {
+                        return Err(Error::invalid_argument(format!(
+                            "Statement {} of proof spec {} can't be used in an OR meta-statement",
+                            j, i
+                        )))
+                    }
+                };
+                match (pairs, keys.get(&j)) {
+                    (None, None) => (),
+                    (Some((g1, g2, pk)), Some(pk_g1)) => {
+                        if Bls12_381::pairing(*pk_g1, g2) != Bls12_381::pairing(g1, pk) {
+                            return Err(Error::new(
+                                ErrorCode::IncompatibleParams,
+                                format!(
+                                    "Public key in G1 for statement {} of proof spec {} doesn't match the public key",
+                                    j, i
+                                ),
+                            ));
+                        }
+                    }
+                    (Some(_), None) => {
+                        return Err(Error::invalid_argument(format!(
+                            "Public key in G1 for statement {} of proof spec {} is missing",
+                            j, i
+                        )))
+                    }
+                    (None, Some(_)) => {
+                        return Err(Error::invalid_argument(format!(
+                            "Public key in G1 given for statement {} of proof spec {} that isn't for a signature",
+                            j, i
+                        )))
+                    }
+                }
+            }
+            if let Some(j) = keys.keys().find(|j| **j >= spec.statements.len()) {
+                return Err(Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!(
+                        "Public key in G1 given for statement {} but proof spec {} has {} statements",
+                        j,
+                        i,
+                        spec.statements.len()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Create a proof given the witnesses of the proof spec at index `satisfied_index`. The witnesses are zeroized
+    /// whether the proof is created or not.
+    pub fn prove<R: RngCore>(
+        &self,
+        rng: &mut R,
+        satisfied_index: usize,
+        witnesses: SecretWitnesses,
+        nonce: &Option<Vec<u8>>,
+    ) -> native::Result<OrProof> {
+        self.validate()?;
+        if satisfied_index >= self.proof_specs.len() {
+            return Err(Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!(
+                    "Index {} is invalid as there are only {} proof specs",
+                    satisfied_index,
+                    self.proof_specs.len()
+                ),
+            ));
+        }
+        let real_spec = &self.proof_specs[satisfied_index];
+        let sub_protocols = init_statement_sub_protocols(real_spec, &witnesses.0, rng)?;
+        drop(witnesses);
+
+        let mut challenges = vec![Fr::zero(); self.proof_specs.len()];
+        let mut proofs = vec![vec![]; self.proof_specs.len()];
+        let mut bytes = self.transcript_prefix(nonce)?;
+        for (i, spec) in self.proof_specs.iter().enumerate() {
+            if i == satisfied_index {
+                bytes.extend(commitments(real_spec, &sub_protocols)?);
+            } else {
+                challenges[i] = Fr::rand(rng);
+                proofs[i] = self.simulate(rng, i, &challenges[i])?;
+                // Simulated proofs are verified like the real ones to get their commitments
+                bytes.extend(verify_statement_proofs(&proofs[i], spec, &challenges[i])?);
+            }
+        }
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes);
+        challenges[satisfied_index] = challenge
+            - challenges
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != satisfied_index)
+                .fold(Fr::zero(), |a, (_, c)| a + c);
+        for mut sp in sub_protocols {
+            proofs[satisfied_index].push(sp.gen_proof_contribution(&challenges[satisfied_index])?);
+        }
+        verify_statement_proofs(
+            &proofs[satisfied_index],
+            real_spec,
+            &challenges[satisfied_index],
+        )
+        .map_err(|e| {
+            Error::invalid_argument(format!(
+                "Witnesses don't satisfy proof spec {}: {}",
+                satisfied_index, e
+            ))
+        })?;
+        Ok(OrProof { challenges, proofs })
+    }
+
+    pub fn verify(&self, proof: &OrProof, nonce: &Option<Vec<u8>>) -> native::Result<()> {
+        self.validate()?;
+        if proof.challenges.len() != self.proof_specs.len()
+            || proof.proofs.len() != self.proof_specs.len()
+        {
+            return Err(Error::invalid_argument(format!(
+                "Expected challenges and proofs for {} proof specs but found {} and {}",
+                self.proof_specs.len(),
+                proof.challenges.len(),
+                proof.proofs.len()
+            )));
+        }
+        let mut bytes = self.transcript_prefix(nonce)?;
+        for (i, (spec, (proofs, challenge))) in self
+            .proof_specs
+            .iter()
+            .zip(proof.proofs.iter().zip(proof.challenges.iter()))
+            .enumerate()
+        {
+            bytes.extend(
+                verify_statement_proofs(proofs, spec, challenge)
+                    .map_err(|e| e.context(format!("Proof for proof spec {} is invalid", i)))?,
+            );
+        }
+        let challenge = compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes);
+        let sum = proof.challenges.iter().fold(Fr::zero(), |a, c| a + c);
+        if sum != challenge {
+            return Err(Error::new(
+                ErrorCode::InvalidProof,
+                "Challenges don't add up to the challenge of the proof",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Simulate the proofs of the statements of the proof spec at index `i` for `challenge`. Witnesses that are
+    /// equal get the same response.
+    fn simulate<R: RngCore>(
+        &self,
+        rng: &mut R,
+        i: usize,
+        challenge: &Fr,
+    ) -> native::Result<Vec<StatementProofG1>> {
+        let spec = &self.proof_specs[i];
+        let mut responses = BTreeMap::<WitnessRef, Fr>::new();
+        for EqualWitnesses(eq) in spec.meta_statements.disjoint_witness_equalities() {
+            let r = Fr::rand(rng);
+            for wr in eq {
+                responses.insert(wr, r);
+            }
+        }
+        let mut responses_for = |j: usize| {
+            let rest = responses.split_off(&(j + 1, 0));
+            let resp = ark_std::mem::replace(&mut responses, rest);
+            resp.into_iter()
+                .map(|((_, w), r)| (w, r))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let sp = &spec.setup_params;
+        let mut proofs = Vec::with_capacity(spec.statements.len());
+        for (j, s) in spec.statements.0.iter().enumerate() {
+            // Responses of witnesses of earlier statements were taken already
+            let resp = responses_for(j);
+            match s {
+                Statement::PedersenCommitment(s) => {
+                    let bases = s.get_commitment_key(sp, j)?;
+                    if let Some(w) = resp.keys().find(|w| **w >= bases.len()) {
+                        return Err(Error::new(
+                            ErrorCode::IndexOutOfBounds,
+                            format!(
+                                "Witness reference {:?} of proof spec {} is invalid",
+                                (j, w),
+                                i
+                            ),
+                        ));
+                    }
+                    let r = (0..bases.len())
+                        .map(|w| match resp.get(&w) {
+                            Some(r) => *r,
+                            None => Fr::rand(rng),
+                        })
+                        .collect::<Vec<_>>();
+                    let t = (<G1Affine as AffineRepr>::Group::msm_unchecked(bases, &r)
+                        - s.commitment * challenge)
+                        .into_affine();
+                    proofs.push(StatementProof::PedersenCommitment(
+                        PedersenCommitmentProof::new(t, SchnorrResponse(r)),
+                    ));
+                }
+                Statement::PoKBBSSignatureG1(s) => {
+                    let params = s.get_sig_params(sp, j)?;
+                    proofs.push(StatementProof::PoKBBSSignatureG1(simulate_bbs_plus_proof(
+                        rng,
+                        params,
+                        self.public_key_g1(i, j)?,
+                        &s.revealed_messages,
+                        challenge,
+                        &resp,
+                    )?));
+                }
+                Statement::PoKBBSSignature23G1(s) => {
+                    let params = s.get_sig_params(sp, j)?;
+                    proofs.push(StatementProof::PoKBBSSignature23G1(simulate_bbs_proof(
+                        rng,
+                        params,
+                        self.public_key_g1(i, j)?,
+                        &s.revealed_messages,
+                        challenge,
+                        &resp,
+                    )?));
+                }
+                _ => {
+                    return Err(Error::invalid_argument(format!(
+                        "Statement {} of proof spec {} can't be used in an OR meta-statement",
+                        j, i
+                    )))
+                }
+            }
+        }
+        if !responses.is_empty() {
+            return Err(Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!(
+                    "Witness equalities of proof spec {} refer to witnesses {:?} that don't exist",
+                    i,
+                    responses.keys().collect::<Vec<_>>()
+                ),
+            ));
+        }
+        Ok(proofs)
+    }
+
+    fn public_key_g1(&self, i: usize, j: usize) -> native::Result<&G1Affine> {
+        self.public_keys_g1[i].get(&j).ok_or_else(|| {
+            Error::invalid_argument(format!(
+                "Public key in G1 for statement {} of proof spec {} is missing",
+                j, i
+            ))
+        })
+    }
+
+    /// The proof is bound to the nonce and the whole meta-statement
+    fn transcript_prefix(&self, nonce: &Option<Vec<u8>>) -> native::Result<Vec<u8>> {
+        let mut bytes = vec![];
+        if let Some(n) = nonce {
+            bytes.extend_from_slice(n);
+        }
+        self.serialize_compressed(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+impl ProofSpecWithOrG1 {
+    pub fn validate(&self) -> native::Result<()> {
+        self.proof_spec
+            .validate()
+            .map_err(|e| Error::from(e).context("Invalid proof spec"))?;
+        for (i, m) in self.or_meta_statements.iter().enumerate() {
+            m.validate()
+                .map_err(|e| e.context(format!("OR meta-statement {} is invalid", i)))?;
+        }
+        for (i, eq) in self.or_witness_equalities.iter().enumerate() {
+            if eq.witness_ref.0 >= self.proof_spec.statements.len() {
+                return Err(Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!(
+                        "Witness equality {} refers to statement {} but there are only {} statements",
+                        i,
+                        eq.witness_ref.0,
+                        self.proof_spec.statements.len()
+                    ),
+                ));
+            }
+            let m = self.or_meta_statements.get(eq.or_index).ok_or_else(|| {
+                Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!(
+                        "Witness equality {} refers to OR meta-statement {} but there are only {}",
+                        i,
+                        eq.or_index,
+                        self.or_meta_statements.len()
+                    ),
+                )
+            })?;
+            if eq.branch_witness_refs.len() != m.proof_specs.len() {
+                return Err(Error::new(
+                    ErrorCode::MessageCountMismatch,
+                    format!(
+                        "Witness equality {} has {} witness references for an OR meta-statement of {} proof specs",
+                        i,
+                        eq.branch_witness_refs.len(),
+                        m.proof_specs.len()
+                    ),
+                ));
+            }
+            for (j, (spec, wr)) in m
+                .proof_specs
+                .iter()
+                .zip(eq.branch_witness_refs.iter())
+                .enumerate()
+            {
+                if wr.0 >= spec.statements.len() {
+                    return Err(Error::new(
+                        ErrorCode::IndexOutOfBounds,
+                        format!(
+                            "Witness equality {} refers to statement {} of proof spec {} which has {} statements",
+                            i,
+                            wr.0,
+                            j,
+                            spec.statements.len()
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Create the composite proof and the proofs of the "OR" meta-statements. `satisfied_indices` and
+    /// `or_witnesses` have the index of the satisfied proof spec and its witnesses for each "OR" meta-statement.
+    /// The witnesses are zeroized whether the proof is created or not.
+    pub fn prove<R: RngCore>(
+        &self,
+        rng: &mut R,
+        mut witnesses: SecretWitnesses,
+        satisfied_indices: &[usize],
+        mut or_witnesses: Vec<SecretWitnesses>,
+        nonce: Option<Vec<u8>>,
+    ) -> native::Result<CompositeProofWithOrG1> {
+        self.validate()?;
+        let count = self.or_meta_statements.len();
+        if satisfied_indices.len() != count || or_witnesses.len() != count {
+            return Err(Error::new(
+                ErrorCode::MessageCountMismatch,
+                format!(
+                    "Expected a satisfied index and witnesses for {} OR meta-statements but found {} and {}",
+                    count,
+                    satisfied_indices.len(),
+                    or_witnesses.len()
+                ),
+            ));
+        }
+        let [g, h] = or_witness_equality_bases();
+        let mut commitments = Vec::with_capacity(self.or_witness_equalities.len());
+        for eq in &self.or_witness_equalities {
+            let w = witness_value(&witnesses.0, eq.witness_ref)?;
+            let r = Fr::rand(rng);
+            commitments.push((g * w + h * r).into_affine());
+            // In the order that `with_commitments` adds the statements
+            witnesses.0.add(Witness::PedersenCommitment(vec![w, r]));
+            or_witnesses[eq.or_index]
+                .0
+                .add(Witness::PedersenCommitment(vec![w, r]));
+        }
+        let (proof_spec, or_meta_statements) = self.with_commitments(&commitments)?;
+        let mut or_proofs = Vec::with_capacity(count);
+        for (i, ((m, idx), wits)) in or_meta_statements
+            .iter()
+            .zip(satisfied_indices.iter())
+            .zip(or_witnesses)
+            .enumerate()
+        {
+            or_proofs.push(m.prove(rng, *idx, wits, &nonce).map_err(|e| {
+                e.context(format!(
+                    "Generating proof for OR meta-statement {} returned error",
+                    i
+                ))
+            })?);
+        }
+        let (proof, _) =
+            native::composite_proof_system::generate_proof(proof_spec, witnesses.0.clone(), nonce)?;
+        Ok(CompositeProofWithOrG1 {
+            proof,
+            or_proofs,
+            commitments,
+        })
+    }
+
+    /// Verify the composite proof and the proofs of the "OR" meta-statements for the same nonce
+    pub fn verify(
+        &self,
+        proof: CompositeProofWithOrG1,
+        nonce: Option<Vec<u8>>,
+    ) -> native::Result<()> {
+        self.validate()?;
+        if proof.or_proofs.len() != self.or_meta_statements.len() {
+            return Err(Error::new(
+                ErrorCode::MessageCountMismatch,
+                format!(
+                    "Expected proofs for {} OR meta-statements but found {}",
+                    self.or_meta_statements.len(),
+                    proof.or_proofs.len()
+                ),
+            ));
+        }
+        let (proof_spec, or_meta_statements) = self.with_commitments(&proof.commitments)?;
+        for (i, (m, p)) in or_meta_statements
+            .iter()
+            .zip(proof.or_proofs.iter())
+            .enumerate()
+        {
+            m.verify(p, &nonce).map_err(|e| {
+                e.context(format!(
+                    "Verifying proof for OR meta-statement {} returned error",
+                    i
+                ))
+            })?;
+        }
+        native::composite_proof_system::verify_proof(proof.proof, proof_spec, nonce)
+            .map_err(|e| e.context("Verifying proof returned error"))
+    }
+
+    /// The proof spec and the "OR" meta-statements with a Pedersen commitment statement for each of `commitments`
+    /// whose witness is equal to the witnesses of the witness equality it's for
+    fn with_commitments(
+        &self,
+        commitments: &[G1Affine],
+    ) -> native::Result<(ProofSpec<G1Affine>, Vec<OrMetaStatement>)> {
+        if commitments.len() != self.or_witness_equalities.len() {
+            return Err(Error::new(
+                ErrorCode::InvalidProof,
+                format!(
+                    "Expected commitments for {} witness equalities but found {}",
+                    self.or_witness_equalities.len(),
+                    commitments.len()
+                ),
+            ));
+        }
+        let bases = or_witness_equality_bases().to_vec();
+        let mut proof_spec = self.proof_spec.clone();
+        let mut or_meta_statements = self.or_meta_statements.clone();
+        for (eq, commitment) in self.or_witness_equalities.iter().zip(commitments) {
+            let statement = PedersenCommitment::new_statement_from_params::<Bls12_381>(
+                bases.clone(),
+                *commitment,
+            );
+            let index = proof_spec.statements.add(statement.clone());
+            proof_spec
+                .meta_statements
+                .add_witness_equality(EqualWitnesses(BTreeSet::from([eq.witness_ref, (index, 0)])));
+            for (spec, wr) in or_meta_statements[eq.or_index]
+                .proof_specs
+                .iter_mut()
+                .zip(eq.branch_witness_refs.iter())
+            {
+                let index = spec.statements.add(statement.clone());
+                spec.meta_statements
+                    .add_witness_equality(EqualWitnesses(BTreeSet::from([*wr, (index, 0)])));
+            }
+        }
+        Ok((proof_spec, or_meta_statements))
+    }
+}
+
+/// Bases of the commitments that prove the witness equalities of a `ProofSpecWithOrG1`
+fn or_witness_equality_bases() -> [G1Affine; 2] {
+    [
+        affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(&concat_slices!(
+            OR_WITNESS_EQUALITY_LABEL,
+            b" : g"
+        )),
+        affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(&concat_slices!(
+            OR_WITNESS_EQUALITY_LABEL,
+            b" : h"
+        )),
+    ]
+}
+
+/// Value of the witness `wr` among `witnesses`. Only witnesses of statements proven with Schnorr protocols can be
+/// in a witness equality with an "OR" meta-statement.
+fn witness_value(witnesses: &Witnesses, wr: WitnessRef) -> native::Result<Fr> {
+    let (s, w) = wr;
+    let value = witnesses
+        .0
+        .get(s)
+        .filter(|x| {
+            matches!(
+                x,
+                Witness::PoKBBSSignatureG1(_)
+                    | Witness::PoKBBSSignature23G1(_)
+                    | Witness::PoKPSSignature(_)
+                    | Witness::PedersenCommitment(_)
+                    | Witness::AccumulatorMembership(_)
+                    | Witness::AccumulatorNonMembership(_)
+            )
+        })
+        .and_then(|x| get_witness_element(x, w));
+    value.ok_or_else(|| {
+        Error::new(
+            ErrorCode::IndexOutOfBounds,
+            format!(
+                "No witness {:?} that can be in a witness equality with an OR meta-statement",
+                wr
+            ),
+        )
+    })
+}
+
+pub(super) fn parse_or_meta_statements(
+    array: &js_sys::Array,
+) -> Result<Vec<OrMetaStatement>, JsValue> {
+    let mut meta_statements = Vec::with_capacity(array.length() as usize);
+    for m in array.values() {
+        let m = Uint8Array::new(&m.unwrap());
+        meta_statements.push(obj_from_uint8array_uncompressed!(
+            OrMetaStatement,
+            &m,
+            "OrMetaStatement"
+        ));
+    }
+    Ok(meta_statements)
+}
+
+fn parse_witnesses(array: &js_sys::Array) -> Result<SecretWitnesses, JsValue> {
+    let mut witnesses = SecretWitnesses(Witnesses::new());
+    for w in array.values() {
+        let w: Witness = from_value(w.unwrap())?;
+        witnesses.0.add(w);
+    }
+    Ok(witnesses)
+}
@@ -0,0 +1,280 @@
+use ark_bls12_381::Bls12_381;
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use bbs_plus::prelude::MultiMessageSignatureParams;
+use js_sys::Uint8Array;
+use proof_system::prelude::{Statement, StatementProof};
+use serde::Serialize;
+use serde_with::{serde_as, Bytes};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    composite_proof_system::{
+        proof_spec::{frs_map_to_bytes, statement_type_name},
+        Proof, ProofSpec,
+    },
+    native::{self, to_bytes, Error, ErrorCode},
+    utils::set_panic_hook,
+    G1Affine,
+};
+
+/// Data of a single `StatementProof` of a composite proof, along with what its `Statement` makes public
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementProofDetails {
+    pub index: usize,
+    #[serde(rename = "type")]
+    pub statement_type: String,
+    #[serde_as(as = "Option<BTreeMap<_, Bytes>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revealed_messages: Option<BTreeMap<usize, Vec<u8>>>,
+    /// Accumulator value that the accumulator statements prove (non-)membership in
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accumulated: Option<Vec<u8>>,
+    /// Name of the commitment mapped to its bytes
+    #[serde_as(as = "BTreeMap<_, Bytes>")]
+    pub commitments: BTreeMap<String, Vec<u8>>,
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ciphertext: Option<Vec<u8>>,
+    /// Index of the witness in the statement mapped to the Schnorr response for it
+    #[serde_as(as = "BTreeMap<_, Bytes>")]
+    pub responses: BTreeMap<usize, Vec<u8>>,
+}
+
+/// Get the revealed messages, accumulator values, commitments, ciphertexts and responses for the witnesses of each statement
+/// from a composite proof in group G1 created using `proof_spec`. Returns an array with an object for each
+/// statement. Meant to be called by the verifier after verifying the proof.
+#[wasm_bindgen(js_name = extractFromCompositeProofG1)]
+pub fn extract_from_composite_proof_g1(
+    proof: Uint8Array,
+    proof_spec: Uint8Array,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(Proof<G1Affine>, proof, false);
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpec<G1Affine>, proof_spec, "ProofSpec");
+    extract(&proof, &proof_spec)
+}
+
+/// Same as `extractFromCompositeProofG1` but for a composite proof in group G2.
+#[wasm_bindgen(js_name = extractFromCompositeProofG2)]
+pub fn extract_from_composite_proof_g2(
+    proof: Uint8Array,
+    proof_spec: Uint8Array,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(Proof<<Bls12_381 as Pairing>::G2Affine>, proof, false);
+    let proof_spec = obj_from_uint8array_uncompressed!(
+        ProofSpec<<Bls12_381 as Pairing>::G2Affine>,
+        proof_spec,
+        "ProofSpec"
+    );
+    extract(&proof, &proof_spec)
+}
+
+fn extract<G: AffineRepr>(
+    proof: &Proof<G>,
+    proof_spec: &ProofSpec<G>,
+) -> Result<js_sys::Array, JsValue> {
+    if proof.statement_proofs.len() != proof_spec.statements.len() {
+        return Err(Error::new(
+            ErrorCode::InvalidArgument,
+            format!(
+                "Proof has {} statement proofs but proof spec has {} statements",
+                proof.statement_proofs.len(),
+                proof_spec.statements.len()
+            ),
+        )
+        .into());
+    }
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    let details = js_sys::Array::new();
+    for (i, (statement, statement_proof)) in proof_spec
+        .statements
+        .0
+        .iter()
+        .zip(proof.statement_proofs.iter())
+        .enumerate()
+    {
+        let d = get_statement_proof_details(i, statement, statement_proof, proof_spec)?;
+        details.push(&d.serialize(&serializer).map_err(JsValue::from)?);
+    }
+    Ok(details)
+}
+
+pub fn get_statement_proof_details<G: AffineRepr>(
+    i: usize,
+    statement: &Statement<Bls12_381, G>,
+    statement_proof: &StatementProof<Bls12_381, G>,
+    proof_spec: &ProofSpec<G>,
+) -> native::Result<StatementProofDetails> {
+    let mut details = StatementProofDetails {
+        index: i,
+        statement_type: statement_type_name(statement).to_string(),
+        ..Default::default()
+    };
+    let sp = &proof_spec.setup_params;
+    match (statement, statement_proof) {
+        (Statement::PoKBBSSignatureG1(s), StatementProof::PoKBBSSignatureG1(p)) => {
+            let revealed_msg_ids = s.revealed_messages.keys().copied().collect::<BTreeSet<_>>();
+            let sig_params = s.get_sig_params(sp, i).map_err(|e| details_error(i, e))?;
+            for j in 0..sig_params.supported_message_count() {
+                if !revealed_msg_ids.contains(&j) {
+                    let resp = p
+                        .get_resp_for_message(j, &revealed_msg_ids)
+                        .map_err(|e| details_error(i, e))?;
+                    details.responses.insert(j, to_bytes(resp)?);
+                }
+            }
+            details.revealed_messages = Some(frs_map_to_bytes(&s.revealed_messages)?);
+        }
+        (Statement::PoKBBSSignature23G1(s), StatementProof::PoKBBSSignature23G1(p)) => {
+            let revealed_msg_ids = s.revealed_messages.keys().copied().collect::<BTreeSet<_>>();
+            let sig_params = s.get_sig_params(sp, i).map_err(|e| details_error(i, e))?;
+            for j in 0..sig_params.supported_message_count() {
+                if !revealed_msg_ids.contains(&j) {
+                    let resp = p
+                        .get_resp_for_message(j, &revealed_msg_ids)
+                        .map_err(|e| details_error(i, e))?;
+                    details.responses.insert(j, to_bytes(resp)?);
+                }
+            }
+            details.revealed_messages = Some(frs_map_to_bytes(&s.revealed_messages)?);
+        }
+        (Statement::PoKPSSignature(s), StatementProof::PoKPSSignature(p)) => {
+            let revealed_msg_ids = s.revealed_messages.keys().copied().collect::<Vec<_>>();
+            let sig_params = s.get_sig_params(sp, i).map_err(|e| details_error(i, e))?;
+            for j in 0..sig_params.supported_message_count() {
+                if !s.revealed_messages.contains_key(&j) {
+                    let resp = p
+                        .response_for_message(j, revealed_msg_ids.iter().copied())
+                        .map_err(|e| details_error(i, e))?;
+                    details.responses.insert(j, to_bytes(resp)?);
+                }
+            }
+            details.revealed_messages = Some(frs_map_to_bytes(&s.revealed_messages)?);
+        }
+        (Statement::AccumulatorMembership(s), StatementProof::AccumulatorMembership(p)) => {
+            details.accumulated = Some(to_bytes(&s.accumulator_value)?);
+            details
+                .responses
+                .insert(0, to_bytes(p.get_schnorr_response_for_element())?);
+        }
+        (Statement::AccumulatorNonMembership(s), StatementProof::AccumulatorNonMembership(p)) => {
+            details.accumulated = Some(to_bytes(&s.accumulator_value)?);
+            details
+                .responses
+                .insert(0, to_bytes(p.get_schnorr_response_for_element())?);
+        }
+        (Statement::PedersenCommitment(s), StatementProof::PedersenCommitment(p)) => {
+            details
+                .commitments
+                .insert("commitment".to_string(), to_bytes(&s.commitment)?);
+            details.commitments.insert("t".to_string(), to_bytes(&p.t)?);
+            for (j, resp) in p.response.0.iter().enumerate() {
+                details.responses.insert(j, to_bytes(resp)?);
+            }
+        }
+        (Statement::SaverProver(_) | Statement::SaverVerifier(_), StatementProof::Saver(p)) => {
+            details.ciphertext = Some(to_bytes(&p.ciphertext)?);
+            details
+                .commitments
+                .insert("chunks".to_string(), to_bytes(&p.comm_chunks)?);
+            details
+                .commitments
+                .insert("combined".to_string(), to_bytes(&p.comm_combined)?);
+            let resp = p
+                .get_schnorr_response_for_combined_message()
+                .map_err(|e| details_error(i, e))?;
+            details.responses.insert(0, to_bytes(resp)?);
+        }
+        (
+            Statement::SaverProver(_) | Statement::SaverVerifier(_),
+            StatementProof::SaverWithAggregation(p),
+        ) => {
+            details.ciphertext = Some(to_bytes(&p.ciphertext)?);
+            details
+                .commitments
+                .insert("chunks".to_string(), to_bytes(&p.comm_chunks)?);
+            details
+                .commitments
+                .insert("combined".to_string(), to_bytes(&p.comm_combined)?);
+            let resp = p
+                .get_schnorr_response_for_combined_message()
+                .map_err(|e| details_error(i, e))?;
+            details.responses.insert(0, to_bytes(resp)?);
+        }
+        (
+            Statement::BoundCheckLegoGroth16Prover(_) | Statement::BoundCheckLegoGroth16Verifier(_),
+            StatementProof::BoundCheckLegoGroth16(p),
+        ) => {
+            details
+                .commitments
+                .insert("snark".to_string(), to_bytes(&p.snark_proof.d)?);
+            let resp = p
+                .get_schnorr_response_for_message()
+                .map_err(|e| details_error(i, e))?;
+            details.responses.insert(0, to_bytes(resp)?);
+        }
+        (
+            Statement::BoundCheckLegoGroth16Prover(_) | Statement::BoundCheckLegoGroth16Verifier(_),
+            StatementProof::BoundCheckLegoGroth16WithAggregation(p),
+        ) => {
+            details
+                .commitments
+                .insert("snark".to_string(), to_bytes(&p.commitment)?);
+            let resp = p
+                .get_schnorr_response_for_message()
+                .map_err(|e| details_error(i, e))?;
+            details.responses.insert(0, to_bytes(resp)?);
+        }
+        (
+            Statement::R1CSCircomProver(_) | Statement::R1CSCircomVerifier(_),
+            StatementProof::R1CSLegoGroth16(p),
+        ) => {
+            details
+                .commitments
+                .insert("snark".to_string(), to_bytes(&p.snark_proof.d)?);
+            // The last response is for the randomness of the commitment and not for a witness
+            for j in 0..p.sp.response.len().saturating_sub(1) {
+                let resp = p
+                    .get_schnorr_response_for_message(j)
+                    .map_err(|e| details_error(i, e))?;
+                details.responses.insert(j, to_bytes(resp)?);
+            }
+        }
+        (
+            Statement::R1CSCircomProver(_) | Statement::R1CSCircomVerifier(_),
+            StatementProof::R1CSLegoGroth16WithAggregation(p),
+        ) => {
+            details
+                .commitments
+                .insert("snark".to_string(), to_bytes(&p.commitment)?);
+            for j in 0..p.sp.response.len().saturating_sub(1) {
+                let resp = p
+                    .get_schnorr_response_for_message(j)
+                    .map_err(|e| details_error(i, e))?;
+                details.responses.insert(j, to_bytes(resp)?);
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "StatementProof at index {} isn't for a statement of type {}",
+                    i, details.statement_type
+                ),
+            ))
+        }
+    }
+    Ok(details)
+}
+
+fn details_error<E: Into<Error>>(i: usize, e: E) -> Error {
+    e.into()
+        .context(format!("Getting details of statement {} returned error", i))
+}
@@ -0,0 +1,706 @@
+use ark_bls12_381::Bls12_381;
+use ark_ec::AffineRepr;
+use ark_std::collections::{BTreeMap, BTreeSet};
+use js_sys::Uint8Array;
+use proof_system::{
+    error::ProofSystemError,
+    prelude::{EqualWitnesses, MetaStatement, MetaStatements, SetupParams, Statement},
+};
+use serde::Serialize;
+use serde_with::{serde_as, Bytes};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    common::VerifyResponse,
+    composite_proof_system::ProofSpec,
+    native::{self, to_bytes, Error, ErrorCode},
+    utils::set_panic_hook,
+    Fr, G1Affine,
+};
+
+/// Human-readable description of a single `Statement` of a `ProofSpec`. Only the fields relevant to
+/// the statement's type are set.
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementDescription {
+    pub index: usize,
+    #[serde(rename = "type")]
+    pub statement_type: String,
+    #[serde_as(as = "Option<BTreeMap<_, Bytes>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revealed_messages: Option<BTreeMap<usize, Vec<u8>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u64>,
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accumulated: Option<Vec<u8>>,
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commitment: Option<Vec<u8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_bit_size: Option<u8>,
+    #[serde_as(as = "Option<Vec<Bytes>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_inputs: Option<Vec<Vec<u8>>>,
+    /// Name of the parameter mapped to the index of the `SetupParams` it refers to
+    pub param_refs: BTreeMap<String, usize>,
+    /// Names of the parameters that are given in the statement itself rather than as references
+    pub inline_params: Vec<String>,
+}
+
+/// Human-readable description of a `ProofSpec`
+#[serde_as]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofSpecDescription {
+    pub statements: Vec<StatementDescription>,
+    /// Each item is a list of witness references (as `[statement index, witness index]`) that are equal
+    pub witness_equalities: Vec<Vec<(usize, usize)>>,
+    /// Type of each `SetupParams`
+    pub setup_params: Vec<String>,
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<Vec<u8>>,
+}
+
+/// Returns a description of a `ProofSpec` in group G1 listing its statements, meta-statements, setup
+/// params and context. The description is JSON compatible, i.e. maps are returned as objects.
+#[wasm_bindgen(js_name = describeProofSpec)]
+pub fn describe_proof_spec(proof_spec: Uint8Array) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpec<G1Affine>, proof_spec, "ProofSpec");
+    let description = describe(&proof_spec)?;
+    description
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(JsValue::from)
+}
+
+/// Check if `proof_spec` matches `template_spec`, both in group G1. They match when they have the same
+/// context and the same statements in the same order, i.e. statements of the same type with the same revealed
+/// messages, bounds, accumulator values, commitments, public inputs, parameters and keys. Parameters are compared
+/// by value so one spec can give a parameter inline while the other refers to it in its setup params. Every
+/// witness equality of `template_spec` must also be enforced by `proof_spec` but `proof_spec` can enforce
+/// more equalities. Meant to be called by the verifier with a presented `proof_spec` and its own `template_spec`
+/// before verifying a proof.
+#[wasm_bindgen(js_name = proofSpecSatisfiedBy)]
+pub fn proof_spec_satisfied_by(
+    proof_spec: Uint8Array,
+    template_spec: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof_spec =
+        obj_from_uint8array_uncompressed!(ProofSpec<G1Affine>, proof_spec, "ProofSpec");
+    let template_spec =
+        obj_from_uint8array_uncompressed!(ProofSpec<G1Affine>, template_spec, "ProofSpec");
+    let result = check_proof_spec_matches(&proof_spec, &template_spec);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+pub fn describe<G: AffineRepr>(proof_spec: &ProofSpec<G>) -> native::Result<ProofSpecDescription> {
+    let mut statements = Vec::with_capacity(proof_spec.statements.len());
+    for (i, s) in proof_spec.statements.0.iter().enumerate() {
+        statements.push(describe_statement(i, s)?);
+    }
+    Ok(ProofSpecDescription {
+        statements,
+        witness_equalities: proof_spec
+            .meta_statements
+            .0
+            .iter()
+            .map(|m| match m {
+                MetaStatement::WitnessEquality(EqualWitnesses(e)) => e.iter().cloned().collect(),
+            })
+            .collect(),
+        setup_params: proof_spec
+            .setup_params
+            .iter()
+            .map(|s| setup_param_type_name(s).to_string())
+            .collect(),
+        context: proof_spec.context.clone(),
+    })
+}
+
+pub fn statement_type_name<G: AffineRepr>(statement: &Statement<Bls12_381, G>) -> &'static str {
+    match statement {
+        Statement::PoKBBSSignatureG1(_) => "PoKBBSSignatureG1",
+        Statement::PedersenCommitment(_) => "PedersenCommitment",
+        Statement::AccumulatorMembership(_) => "AccumulatorMembership",
+        Statement::AccumulatorNonMembership(_) => "AccumulatorNonMembership",
+        Statement::SaverProver(_) => "SaverProver",
+        Statement::SaverVerifier(_) => "SaverVerifier",
+        Statement::BoundCheckLegoGroth16Prover(_) => "BoundCheckLegoGroth16Prover",
+        Statement::BoundCheckLegoGroth16Verifier(_) => "BoundCheckLegoGroth16Verifier",
+        Statement::R1CSCircomProver(_) => "R1CSCircomProver",
+        Statement::R1CSCircomVerifier(_) => "R1CSCircomVerifier",
+        Statement::PoKPSSignature(_) => "PoKPSSignature",
+        Statement::PoKBBSSignature23G1(_) => "PoKBBSSignature23G1",
+    }
+}
+
+pub fn setup_param_type_name<G: AffineRepr>(param: &SetupParams<Bls12_381, G>) -> &'static str {
+    match param {
+        SetupParams::BBSPlusSignatureParams(_) => "BBSPlusSignatureParams",
+        SetupParams::BBSPlusPublicKey(_) => "BBSPlusPublicKey",
+        SetupParams::VbAccumulatorParams(_) => "VbAccumulatorParams",
+        SetupParams::VbAccumulatorPublicKey(_) => "VbAccumulatorPublicKey",
+        SetupParams::VbAccumulatorMemProvingKey(_) => "VbAccumulatorMemProvingKey",
+        SetupParams::VbAccumulatorNonMemProvingKey(_) => "VbAccumulatorNonMemProvingKey",
+        SetupParams::PedersenCommitmentKey(_) => "PedersenCommitmentKey",
+        SetupParams::SaverEncryptionGens(_) => "SaverEncryptionGens",
+        SetupParams::SaverCommitmentGens(_) => "SaverCommitmentGens",
+        SetupParams::SaverEncryptionKey(_) => "SaverEncryptionKey",
+        SetupParams::SaverProvingKey(_) => "SaverProvingKey",
+        SetupParams::SaverVerifyingKey(_) => "SaverVerifyingKey",
+        SetupParams::LegoSnarkProvingKey(_) => "LegoSnarkProvingKey",
+        SetupParams::LegoSnarkVerifyingKey(_) => "LegoSnarkVerifyingKey",
+        SetupParams::R1CS(_) => "R1CS",
+        SetupParams::Bytes(_) => "Bytes",
+        SetupParams::FieldElemVec(_) => "FieldElemVec",
+        SetupParams::PSSignatureParams(_) => "PSSignatureParams",
+        SetupParams::PSSignaturePublicKey(_) => "PSSignaturePublicKey",
+        SetupParams::BBSSignatureParams23(_) => "BBSSignatureParams23",
+    }
+}
+
+/// Records a parameter of a statement either as a reference to setup params or as given inline.
+macro_rules! record_param {
+    ($desc: ident, $name: expr, $inline: expr, $reference: expr) => {
+        if let Some(r) = $reference {
+            $desc.param_refs.insert($name.to_string(), r);
+        } else if $inline.is_some() {
+            $desc.inline_params.push($name.to_string());
+        }
+    };
+}
+
+fn describe_statement<G: AffineRepr>(
+    index: usize,
+    statement: &Statement<Bls12_381, G>,
+) -> native::Result<StatementDescription> {
+    let mut desc = StatementDescription {
+        index,
+        statement_type: statement_type_name(statement).to_string(),
+        ..Default::default()
+    };
+    match statement {
+        Statement::PoKBBSSignatureG1(s) => {
+            desc.revealed_messages = Some(frs_map_to_bytes(&s.revealed_messages)?);
+            record_param!(
+                desc,
+                "signatureParams",
+                s.signature_params,
+                s.signature_params_ref
+            );
+            record_param!(desc, "publicKey", s.public_key, s.public_key_ref);
+        }
+        Statement::PoKBBSSignature23G1(s) => {
+            desc.revealed_messages = Some(frs_map_to_bytes(&s.revealed_messages)?);
+            record_param!(
+                desc,
+                "signatureParams",
+                s.signature_params,
+                s.signature_params_ref
+            );
+            record_param!(desc, "publicKey", s.public_key, s.public_key_ref);
+        }
+        Statement::PoKPSSignature(s) => {
+            desc.revealed_messages = Some(frs_map_to_bytes(&s.revealed_messages)?);
+            record_param!(
+                desc,
+                "signatureParams",
+                s.signature_params,
+                s.signature_params_ref
+            );
+            record_param!(desc, "publicKey", s.public_key, s.public_key_ref);
+        }
+        Statement::AccumulatorMembership(s) => {
+            desc.accumulated = Some(to_bytes(&s.accumulator_value)?);
+            record_param!(desc, "params", s.params, s.params_ref);
+            record_param!(desc, "publicKey", s.public_key, s.public_key_ref);
+            record_param!(desc, "provingKey", s.proving_key, s.proving_key_ref);
+        }
+        Statement::AccumulatorNonMembership(s) => {
+            desc.accumulated = Some(to_bytes(&s.accumulator_value)?);
+            record_param!(desc, "params", s.params, s.params_ref);
+            record_param!(desc, "publicKey", s.public_key, s.public_key_ref);
+            record_param!(desc, "provingKey", s.proving_key, s.proving_key_ref);
+        }
+        Statement::PedersenCommitment(s) => {
+            desc.commitment = Some(to_bytes(&s.commitment)?);
+            record_param!(desc, "commitmentKey", s.key, s.key_ref);
+        }
+        Statement::SaverProver(s) => {
+            desc.chunk_bit_size = Some(s.chunk_bit_size);
+            record_param!(
+                desc,
+                "encryptionGens",
+                s.encryption_gens,
+                s.encryption_gens_ref
+            );
+            record_param!(
+                desc,
+                "chunkedCommitmentGens",
+                s.chunked_commitment_gens,
+                s.chunked_commitment_gens_ref
+            );
+            record_param!(
+                desc,
+                "encryptionKey",
+                s.encryption_key,
+                s.encryption_key_ref
+            );
+            record_param!(
+                desc,
+                "snarkProvingKey",
+                s.snark_proving_key,
+                s.snark_proving_key_ref
+            );
+        }
+        Statement::SaverVerifier(s) => {
+            desc.chunk_bit_size = Some(s.chunk_bit_size);
+            record_param!(
+                desc,
+                "encryptionGens",
+                s.encryption_gens,
+                s.encryption_gens_ref
+            );
+            record_param!(
+                desc,
+                "chunkedCommitmentGens",
+                s.chunked_commitment_gens,
+                s.chunked_commitment_gens_ref
+            );
+            record_param!(
+                desc,
+                "encryptionKey",
+                s.encryption_key,
+                s.encryption_key_ref
+            );
+            record_param!(
+                desc,
+                "snarkVerifyingKey",
+                s.snark_verifying_key,
+                s.snark_verifying_key_ref
+            );
+        }
+        Statement::BoundCheckLegoGroth16Prover(s) => {
+            desc.min = Some(s.min);
+            desc.max = Some(s.max);
+            record_param!(
+                desc,
+                "snarkProvingKey",
+                s.snark_proving_key,
+                s.snark_proving_key_ref
+            );
+        }
+        Statement::BoundCheckLegoGroth16Verifier(s) => {
+            desc.min = Some(s.min);
+            desc.max = Some(s.max);
+            record_param!(
+                desc,
+                "snarkVerifyingKey",
+                s.snark_verifying_key,
+                s.snark_verifying_key_ref
+            );
+        }
+        Statement::R1CSCircomProver(s) => {
+            record_param!(desc, "r1cs", s.r1cs, s.r1cs_ref);
+            record_param!(desc, "wasmBytes", s.wasm_bytes, s.wasm_bytes_ref);
+            record_param!(
+                desc,
+                "snarkProvingKey",
+                s.snark_proving_key,
+                s.snark_proving_key_ref
+            );
+        }
+        Statement::R1CSCircomVerifier(s) => {
+            if let Some(p) = &s.public_inputs {
+                desc.public_inputs = Some(p.iter().map(to_bytes).collect::<Result<_, _>>()?);
+            }
+            record_param!(desc, "publicInputs", s.public_inputs, s.public_inputs_ref);
+            record_param!(
+                desc,
+                "snarkVerifyingKey",
+                s.snark_verifying_key,
+                s.snark_verifying_key_ref
+            );
+        }
+    }
+    Ok(desc)
+}
+
+/// Returns `Err` with the reason when `proof_spec` does not match `template_spec`.
+pub fn check_proof_spec_matches<G: AffineRepr>(
+    proof_spec: &ProofSpec<G>,
+    template_spec: &ProofSpec<G>,
+) -> native::Result<()> {
+    if proof_spec.context != template_spec.context {
+        return Err(mismatch(
+            "Context of the proof spec differs from the template",
+        ));
+    }
+    if proof_spec.statements.len() != template_spec.statements.len() {
+        return Err(mismatch(format!(
+            "Proof spec has {} statements but template has {}",
+            proof_spec.statements.len(),
+            template_spec.statements.len()
+        )));
+    }
+    for (i, (s, t)) in proof_spec
+        .statements
+        .0
+        .iter()
+        .zip(template_spec.statements.0.iter())
+        .enumerate()
+    {
+        check_statement_matches(
+            i,
+            s,
+            &proof_spec.setup_params,
+            t,
+            &template_spec.setup_params,
+        )?;
+    }
+    check_witness_equalities_enforced(&proof_spec.meta_statements, &template_spec.meta_statements)
+}
+
+/// Compare a parameter of a statement from both specs by its serialized value
+macro_rules! check_same_param {
+    ($index: ident, $name: expr, $param: expr, $template_param: expr) => {{
+        let p = $param.map_err(|e| param_error($index, $name, e))?;
+        let t = $template_param.map_err(|e| param_error($index, $name, e))?;
+        if to_bytes(p)? != to_bytes(t)? {
+            return Err(mismatch(format!(
+                "{} of statement {} differs from the template",
+                $name, $index
+            )));
+        }
+    }};
+}
+
+fn check_statement_matches<G: AffineRepr>(
+    i: usize,
+    statement: &Statement<Bls12_381, G>,
+    setup_params: &[SetupParams<Bls12_381, G>],
+    template: &Statement<Bls12_381, G>,
+    template_setup_params: &[SetupParams<Bls12_381, G>],
+) -> native::Result<()> {
+    let (sp, tp) = (setup_params, template_setup_params);
+    match (statement, template) {
+        (Statement::PoKBBSSignatureG1(s), Statement::PoKBBSSignatureG1(t)) => {
+            check_revealed_messages(i, &s.revealed_messages, &t.revealed_messages)?;
+            check_same_param!(
+                i,
+                "Signature params",
+                s.get_sig_params(sp, i),
+                t.get_sig_params(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Public key",
+                s.get_public_key(sp, i),
+                t.get_public_key(tp, i)
+            );
+        }
+        (Statement::PoKBBSSignature23G1(s), Statement::PoKBBSSignature23G1(t)) => {
+            check_revealed_messages(i, &s.revealed_messages, &t.revealed_messages)?;
+            check_same_param!(
+                i,
+                "Signature params",
+                s.get_sig_params(sp, i),
+                t.get_sig_params(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Public key",
+                s.get_public_key(sp, i),
+                t.get_public_key(tp, i)
+            );
+        }
+        (Statement::PoKPSSignature(s), Statement::PoKPSSignature(t)) => {
+            check_revealed_messages(i, &s.revealed_messages, &t.revealed_messages)?;
+            check_same_param!(
+                i,
+                "Signature params",
+                s.get_sig_params(sp, i),
+                t.get_sig_params(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Public key",
+                s.get_public_key(sp, i),
+                t.get_public_key(tp, i)
+            );
+        }
+        (Statement::AccumulatorMembership(s), Statement::AccumulatorMembership(t)) => {
+            if s.accumulator_value != t.accumulator_value {
+                return Err(mismatch(format!(
+                    "Accumulator value of statement {} differs from the template",
+                    i
+                )));
+            }
+            check_same_param!(
+                i,
+                "Accumulator params",
+                s.get_params(sp, i),
+                t.get_params(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Public key",
+                s.get_public_key(sp, i),
+                t.get_public_key(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Proving key",
+                s.get_proving_key(sp, i),
+                t.get_proving_key(tp, i)
+            );
+        }
+        (Statement::AccumulatorNonMembership(s), Statement::AccumulatorNonMembership(t)) => {
+            if s.accumulator_value != t.accumulator_value {
+                return Err(mismatch(format!(
+                    "Accumulator value of statement {} differs from the template",
+                    i
+                )));
+            }
+            check_same_param!(
+                i,
+                "Accumulator params",
+                s.get_params(sp, i),
+                t.get_params(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Public key",
+                s.get_public_key(sp, i),
+                t.get_public_key(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Proving key",
+                s.get_proving_key(sp, i),
+                t.get_proving_key(tp, i)
+            );
+        }
+        (Statement::PedersenCommitment(s), Statement::PedersenCommitment(t)) => {
+            if s.commitment != t.commitment {
+                return Err(mismatch(format!(
+                    "Commitment of statement {} differs from the template",
+                    i
+                )));
+            }
+            check_same_param!(
+                i,
+                "Commitment key",
+                s.get_commitment_key::<Bls12_381>(sp, i),
+                t.get_commitment_key::<Bls12_381>(tp, i)
+            );
+        }
+        (Statement::SaverProver(s), Statement::SaverProver(t)) => {
+            check_chunk_bit_size(i, s.chunk_bit_size, t.chunk_bit_size)?;
+            check_same_param!(
+                i,
+                "Encryption generators",
+                s.get_encryption_gens(sp, i),
+                t.get_encryption_gens(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Chunked commitment generators",
+                s.get_chunked_commitment_gens(sp, i),
+                t.get_chunked_commitment_gens(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Encryption key",
+                s.get_encryption_key(sp, i),
+                t.get_encryption_key(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Snark proving key",
+                s.get_snark_proving_key(sp, i),
+                t.get_snark_proving_key(tp, i)
+            );
+        }
+        (Statement::SaverVerifier(s), Statement::SaverVerifier(t)) => {
+            check_chunk_bit_size(i, s.chunk_bit_size, t.chunk_bit_size)?;
+            check_same_param!(
+                i,
+                "Encryption generators",
+                s.get_encryption_gens(sp, i),
+                t.get_encryption_gens(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Chunked commitment generators",
+                s.get_chunked_commitment_gens(sp, i),
+                t.get_chunked_commitment_gens(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Encryption key",
+                s.get_encryption_key(sp, i),
+                t.get_encryption_key(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Snark verifying key",
+                s.get_snark_verifying_key(sp, i),
+                t.get_snark_verifying_key(tp, i)
+            );
+        }
+        (Statement::BoundCheckLegoGroth16Prover(s), Statement::BoundCheckLegoGroth16Prover(t)) => {
+            check_bounds(i, (s.min, s.max), (t.min, t.max))?;
+            check_same_param!(
+                i,
+                "Snark proving key",
+                s.get_proving_key(sp, i),
+                t.get_proving_key(tp, i)
+            );
+        }
+        (
+            Statement::BoundCheckLegoGroth16Verifier(s),
+            Statement::BoundCheckLegoGroth16Verifier(t),
+        ) => {
+            check_bounds(i, (s.min, s.max), (t.min, t.max))?;
+            check_same_param!(
+                i,
+                "Snark verifying key",
+                s.get_verifying_key(sp, i),
+                t.get_verifying_key(tp, i)
+            );
+        }
+        (Statement::R1CSCircomProver(s), Statement::R1CSCircomProver(t)) => {
+            check_same_param!(i, "R1CS", s.get_r1cs(sp, i), t.get_r1cs(tp, i));
+            check_same_param!(
+                i,
+                "Wasm bytes",
+                s.get_wasm_bytes(sp, i),
+                t.get_wasm_bytes(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Snark proving key",
+                s.get_proving_key(sp, i),
+                t.get_proving_key(tp, i)
+            );
+        }
+        (Statement::R1CSCircomVerifier(s), Statement::R1CSCircomVerifier(t)) => {
+            check_same_param!(
+                i,
+                "Public inputs",
+                s.get_public_inputs(sp, i),
+                t.get_public_inputs(tp, i)
+            );
+            check_same_param!(
+                i,
+                "Snark verifying key",
+                s.get_verifying_key(sp, i),
+                t.get_verifying_key(tp, i)
+            );
+        }
+        _ => {
+            return Err(mismatch(format!(
+                "Statement {} is of type {} but template expects {}",
+                i,
+                statement_type_name(statement),
+                statement_type_name(template)
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Check that every witness equality of the template is implied by the equalities of the proof spec
+fn check_witness_equalities_enforced(
+    meta_statements: &MetaStatements,
+    template_meta_statements: &MetaStatements,
+) -> native::Result<()> {
+    let enforced = meta_statements.disjoint_witness_equalities();
+    for required in template_meta_statements.disjoint_witness_equalities() {
+        // An empty set, which a meta-statement given by the user can have, requires nothing
+        let first = match required.0.iter().next() {
+            Some(f) => f,
+            None => continue,
+        };
+        let is_enforced = enforced
+            .iter()
+            .find(|e| e.0.contains(first))
+            .map(|e| required.0.is_subset(&e.0))
+            .unwrap_or(false);
+        if !is_enforced {
+            return Err(mismatch(format!(
+                "Witness equality {:?} of the template is not enforced by the proof spec",
+                required.0
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_revealed_messages(
+    i: usize,
+    revealed: &BTreeMap<usize, Fr>,
+    template_revealed: &BTreeMap<usize, Fr>,
+) -> native::Result<()> {
+    let indices = revealed.keys().collect::<BTreeSet<_>>();
+    let template_indices = template_revealed.keys().collect::<BTreeSet<_>>();
+    if indices != template_indices {
+        return Err(mismatch(format!(
+            "Statement {} reveals messages at indices {:?} but template expects {:?}",
+            i, indices, template_indices
+        )));
+    }
+    for (j, m) in revealed {
+        if template_revealed.get(j) != Some(m) {
+            return Err(mismatch(format!(
+                "Revealed message at index {} of statement {} differs from the template",
+                j, i
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_bounds(i: usize, bounds: (u64, u64), template_bounds: (u64, u64)) -> native::Result<()> {
+    if bounds != template_bounds {
+        return Err(mismatch(format!(
+            "Statement {} has bounds {:?} but template expects {:?}",
+            i, bounds, template_bounds
+        )));
+    }
+    Ok(())
+}
+
+fn check_chunk_bit_size(i: usize, size: u8, template_size: u8) -> native::Result<()> {
+    if size != template_size {
+        return Err(mismatch(format!(
+            "Statement {} has chunk bit size {} but template expects {}",
+            i, size, template_size
+        )));
+    }
+    Ok(())
+}
+
+fn param_error(i: usize, name: &str, e: ProofSystemError) -> Error {
+    Error::from(e).context(format!("{} of statement {} could not be found", name, i))
+}
+
+fn mismatch(message: impl Into<String>) -> Error {
+    Error::new(ErrorCode::ProofSpecMismatch, message)
+}
+
+pub(crate) fn frs_map_to_bytes(
+    frs: &BTreeMap<usize, Fr>,
+) -> native::Result<BTreeMap<usize, Vec<u8>>> {
+    frs.iter()
+        .map(|(i, f)| to_bytes(f).map(|b| (*i, b)))
+        .collect()
+}
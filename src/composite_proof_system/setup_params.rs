@@ -8,11 +8,13 @@ use crate::{
     bbs::BBSSigParams,
     bbs_plus::{BBSPlusPublicKeyG2, BBSPlusSigParamsG1},
     legosnark::{LegoProvingKey, LegoVerifyingKey},
+    native::dkgith::DkgithParams,
     ps::{PSPublicKey, PSSignatureParams},
-    r1cs::gen_r1cs,
+    r1cs::get_r1cs,
     saver::{ChunkedCommGens, EncGens, SaverEk, SaverSnarkPk, SaverSnarkVk},
     utils::{
-        js_array_to_fr_vec, js_array_to_g1_affine_vec, js_array_to_g2_affine_vec, set_panic_hook,
+        from_value, js_array_to_fr_vec, js_array_to_g1_affine_vec, js_array_to_g2_affine_vec,
+        set_panic_hook,
     },
     G1Affine, G2Affine,
 };
@@ -25,7 +27,7 @@ pub fn generate_setup_param_for_bbs_plus_sig_params_g1(
     params: JsValue,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     Ok(obj_to_uint8array_uncompressed!(&SetupParams::<
         Bls12_381,
         G1Affine,
@@ -65,7 +67,7 @@ pub fn generate_setup_param_for_bbs_sig_params(
     params: JsValue,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
 
     Ok(obj_to_uint8array_uncompressed!(&SetupParams::<
         Bls12_381,
@@ -80,7 +82,7 @@ pub fn generate_setup_param_for_ps_sig_params(
     params: JsValue,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let params: PSSignatureParams = serde_wasm_bindgen::from_value(params)?;
+    let params: PSSignatureParams = from_value(params)?;
 
     Ok(obj_to_uint8array_uncompressed!(&SetupParams::<
         Bls12_381,
@@ -157,6 +159,53 @@ pub fn generate_setup_param_for_pedersen_commitment_key_g1(
     )))
 }
 
+/// Commitment key of the commitment created by `dkgithEncrypt`, for `generateDkgithStatementFromParamRefs`
+#[wasm_bindgen(js_name = generateSetupParamForDkgithCommitmentKey)]
+pub fn generate_setup_param_for_dkgith_commitment_key(
+    params: js_sys::Uint8Array,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    Ok(obj_to_uint8array_uncompressed!(&SetupParams::<
+        Bls12_381,
+        G1Affine,
+    >::PedersenCommitmentKey(
+        vec![
+        params.g_m, params.h
+    ]
+    )))
+}
+
+/// Commitment key of the commitment created by `saverEncryptForRecipients`, for
+/// `generateSaverMultiRecipientStatementFromParamRefs`. If `uncompressed` is true, expects the chunked commitment
+/// generators to be in uncompressed form else they should be compressed.
+#[wasm_bindgen(js_name = generateSetupParamForSaverMultiRecipientCommitmentKey)]
+pub fn generate_setup_param_for_saver_multi_recipient_commitment_key(
+    comm_gens: js_sys::Uint8Array,
+    uncompressed: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let comm_gens = if uncompressed {
+        obj_from_uint8array_uncompressed!(ChunkedCommGens, comm_gens, "ChunkedCommitmentGenerators")
+    } else {
+        obj_from_uint8array!(
+            ChunkedCommGens,
+            comm_gens,
+            false,
+            "ChunkedCommitmentGenerators"
+        )
+    };
+    Ok(obj_to_uint8array_uncompressed!(&SetupParams::<
+        Bls12_381,
+        G1Affine,
+    >::PedersenCommitmentKey(
+        vec![
+        comm_gens.G,
+        comm_gens.H
+    ]
+    )))
+}
+
 #[wasm_bindgen(js_name = generateSetupParamForPedersenCommitmentKeyG2)]
 pub fn generate_setup_param_for_pedersen_commitment_key_g2(
     commitment_key: js_sys::Array,
@@ -316,14 +365,16 @@ pub fn generate_setup_param_for_lego_verifying_key(
     )))
 }
 
+/// The circuit is given either as an R1CS returned by `r1csFromBytes` or as the curve name, the counts of public
+/// and private wires and the constraints
 #[wasm_bindgen(js_name = generateSetupParamForR1CS)]
 pub fn generate_setup_param_for_r1cs(
-    curve_name: &str,
-    num_public: u32,
-    num_private: u32,
-    constraints: js_sys::Array,
+    curve_name_or_r1cs: JsValue,
+    num_public: Option<u32>,
+    num_private: Option<u32>,
+    constraints: Option<js_sys::Array>,
 ) -> Result<js_sys::Uint8Array, JsValue> {
-    let r = gen_r1cs(curve_name, num_public, num_private, constraints)?;
+    let r = get_r1cs(curve_name_or_r1cs, num_public, num_private, constraints)?;
     Ok(obj_to_uint8array_uncompressed!(&SetupParams::<
         Bls12_381,
         G1Affine,
@@ -1,6 +1,7 @@
 use ark_bls12_381::Bls12_381;
-use ark_ec::pairing::Pairing;
-use ark_std::collections::BTreeSet;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_std::collections::{BTreeMap, BTreeSet};
+use coconut_crypto::MessageCommitment;
 use js_sys::Uint8Array;
 use proof_system::{
     prelude::{EqualWitnesses, MetaStatement},
@@ -14,15 +15,17 @@ use crate::{
     bbs::{BBSPublicKey, BBSSigParams},
     bbs_plus::{BBSPlusPublicKeyG2, BBSPlusSigParamsG1},
     legosnark::{LegoProvingKey, LegoVerifyingKey},
+    native::{self, dkgith::DkgithParams, ErrorCode},
     ps::{PSPublicKey, PSSignatureParams},
     r1cs::gen_r1cs,
     saver::{ChunkedCommGens, EncGens, SaverEk, SaverSnarkPk, SaverSnarkVk},
     utils::{
-        encode_messages_as_js_map_to_fr_btreemap, g1_affine_from_uint8_array,
-        g2_affine_from_uint8_array, is_positive_safe_integer, js_array_to_fr_vec,
-        js_array_to_g1_affine_vec, js_array_to_g2_affine_vec, set_panic_hook,
+        encode_messages_as_js_map_to_fr_btreemap, field_element_from_u64, fr_from_uint8_array,
+        from_value, g1_affine_from_uint8_array, g2_affine_from_uint8_array,
+        is_positive_safe_integer, js_array_to_fr_vec, js_array_to_g1_affine_vec,
+        js_array_to_g2_affine_vec, js_set_to_btree_set, set_panic_hook,
     },
-    G1Affine,
+    Fr, G1Affine,
 };
 
 pub(crate) type PoKBBSSigStmt = statement::bbs_23::PoKBBSSignature23G1<Bls12_381>;
@@ -54,7 +57,7 @@ pub fn generate_pok_bbs_sig_statement(
     encode_messages: bool,
 ) -> Result<Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSSigParams = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSSigParams = from_value(params)?;
     let pk = obj_from_uint8array!(BBSPublicKey, public_key, false, "BBSPublicKey");
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
     let statement = PoKBBSSigStmt::new_statement_from_params::<G1Affine>(params, pk, msgs);
@@ -72,7 +75,7 @@ pub fn generate_pok_bbs_plus_sig_statement(
     encode_messages: bool,
 ) -> Result<Uint8Array, JsValue> {
     set_panic_hook();
-    let params: BBSPlusSigParamsG1 = serde_wasm_bindgen::from_value(params)?;
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
     let pk = obj_from_uint8array!(BBSPlusPublicKeyG2, public_key, false, "BBSPlusPublicKeyG2");
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
     let statement = PoKBBSPlusSigStmt::new_statement_from_params::<G1Affine>(params, pk, msgs);
@@ -89,8 +92,8 @@ pub fn generate_pok_ps_sig_statement(
     revealed_msgs: js_sys::Map,
 ) -> Result<Uint8Array, JsValue> {
     set_panic_hook();
-    let params: PSSignatureParams = serde_wasm_bindgen::from_value(params)?;
-    let pk = obj_from_uint8array!(PSPublicKey, public_key, false, "BBSPlusPublicKeyG2");
+    let params: PSSignatureParams = from_value(params)?;
+    let pk = obj_from_uint8array!(PSPublicKey, public_key, false, "PSPublicKey");
     let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, false)?;
     let statement = PoKPSSigStmt::new_statement_from_params::<G1Affine>(params, pk, msgs);
     Ok(obj_to_uint8array_uncompressed!(
@@ -286,19 +289,245 @@ pub fn generate_witness_equality_meta_statement(equality: js_sys::Set) -> Result
     set_panic_hook();
     let mut set = BTreeSet::new();
     for wr in equality.values() {
-        let wr = wr.unwrap();
-        let arr_2 = js_sys::Array::from(&wr);
-        if arr_2.length() != 2 {
-            return Err(JsValue::from("Each equality should be a 2 element array"));
-        }
-        let i: u32 = serde_wasm_bindgen::from_value(arr_2.get(0)).unwrap();
-        let j: u32 = serde_wasm_bindgen::from_value(arr_2.get(1)).unwrap();
-        set.insert((i as usize, j as usize));
+        set.insert(parse_witness_ref(&wr.unwrap())?);
     }
     serde_wasm_bindgen::to_value(&MetaStatement::WitnessEquality(EqualWitnesses(set)))
         .map_err(JsValue::from)
 }
 
+/// Create a statement for proving the linear relation `\sum_i{coefficients[i] * w_i} = constant` where `w_i` are
+/// witnesses of other statements. This is a Pedersen commitment statement in group G1 with commitment key
+/// `coefficients[i] * g` and commitment `constant * g` for the generator `g`. Each coefficient and the constant is
+/// either a safe integer, which can be negative, or a field element. The witnesses `w_i` are tied to the witnesses of
+/// the other statements using meta-statements from `generateLinearRelationMetaStatements`.
+#[wasm_bindgen(js_name = generateLinearRelationStatement)]
+pub fn generate_linear_relation_statement(
+    coefficients: js_sys::Array,
+    constant: JsValue,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    if coefficients.length() == 0 {
+        return Err(native::Error::invalid_argument("Need at least 1 coefficient").into());
+    }
+    let g = G1Affine::generator();
+    let mut commitment_key = Vec::with_capacity(coefficients.length() as usize);
+    for c in coefficients.iter() {
+        commitment_key.push(g * linear_relation_term(&c)?);
+    }
+    let commitment_key = <Bls12_381 as Pairing>::G1::normalize_batch(&commitment_key);
+    let commitment = (g * linear_relation_term(&constant)?).into_affine();
+    let statement =
+        PedCommG1Stmt::new_statement_from_params::<Bls12_381>(commitment_key, commitment);
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
+/// Create the witness equalities tying the witnesses of the linear relation statement `statement`, created with
+/// `generateLinearRelationStatement` and at index `statement_index` of the proof spec, to the witnesses of other
+/// statements. `witness_refs` is an array of 2 element arrays `[statement index, witness index]`, one for each
+/// coefficient of the linear relation and in the same order.
+#[wasm_bindgen(js_name = generateLinearRelationMetaStatements)]
+pub fn generate_linear_relation_meta_statements(
+    statement_index: usize,
+    statement: Uint8Array,
+    witness_refs: js_sys::Array,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let statement = obj_from_uint8array_uncompressed!(
+        statement::Statement<Bls12_381, G1Affine>,
+        statement,
+        "PedCommG1Stmt"
+    );
+    let coefficient_count = match &statement {
+        statement::Statement::PedersenCommitment(s) => s.key.as_ref().map(|k| k.len()),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        native::Error::invalid_argument("Statement isn't a linear relation statement")
+    })?;
+    if witness_refs.length() as usize != coefficient_count {
+        return Err(native::Error::invalid_argument(format!(
+            "Linear relation has {} coefficients but {} witness references were given",
+            coefficient_count,
+            witness_refs.length()
+        ))
+        .into());
+    }
+    let meta_statements = js_sys::Array::new();
+    for (i, wr) in witness_refs.iter().enumerate() {
+        let wr = parse_witness_ref(&wr)?;
+        if wr.0 == statement_index {
+            return Err(native::Error::invalid_argument(format!(
+                "Witness reference {} is to the linear relation statement {} itself",
+                i, statement_index
+            ))
+            .into());
+        }
+        let mut set = BTreeSet::new();
+        set.insert((statement_index, i));
+        set.insert(wr);
+        let m = serde_wasm_bindgen::to_value(&MetaStatement::WitnessEquality(EqualWitnesses(set)))
+            .map_err(JsValue::from)?;
+        meta_statements.push(&m);
+    }
+    Ok(meta_statements)
+}
+
+/// Create a statement for requesting a blind BBS+ signature on the messages at `committed_indices`. The statement
+/// proves knowledge of the opening of `commitment` created with `bbsPlusCommitMsgsInG1` using the bases of `params`
+/// at these indices. Witness 0 of the statement is the blinding and witness `j+1` is the `j`-th message in the
+/// ascending order of indices. Once the proof is verified, the issuer passes `commitment` to `bbsPlusBlindSignG1`.
+#[wasm_bindgen(js_name = generateBBSPlusBlindSignatureRequestStatement)]
+pub fn generate_bbs_plus_blind_signature_request_statement(
+    params: JsValue,
+    committed_indices: js_sys::Set,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    let mut bases = vec![params.h_0];
+    bases.append(&mut get_bases_for_committed_indices(
+        &params.h,
+        &committed_indices,
+    )?);
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let statement = PedCommG1Stmt::new_statement_from_params::<Bls12_381>(bases, commitment);
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
+/// Same as `generateBBSPlusBlindSignatureRequestStatement` but for BBS signatures where `commitment` is created
+/// with `bbsCommitMsgs` and passed to `bbsBlindSign`. Witness `j` of the statement is the `j`-th message in the
+/// ascending order of indices.
+#[wasm_bindgen(js_name = generateBBSBlindSignatureRequestStatement)]
+pub fn generate_bbs_blind_signature_request_statement(
+    params: JsValue,
+    committed_indices: js_sys::Set,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let params: BBSSigParams = from_value(params)?;
+    let bases = get_bases_for_committed_indices(&params.h, &committed_indices)?;
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let statement = PedCommG1Stmt::new_statement_from_params::<Bls12_381>(bases, commitment);
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
+/// Create statements for requesting a blind PS signature. `commitments` maps each committed message index to its
+/// commitment created with `psMessageCommitment` using `params.g` and `h`. Returns a statement for each commitment
+/// in the ascending order of indices where witness 0 is the blinding and witness 1 is the message. Once the proof is
+/// verified, the issuer passes the commitments to `psBlindSign` using `psBlindedMessage`.
+#[wasm_bindgen(js_name = generatePSBlindSignatureRequestStatements)]
+pub fn generate_ps_blind_signature_request_statements(
+    params: JsValue,
+    h: Uint8Array,
+    commitments: js_sys::Map,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let params: PSSignatureParams = from_value(params)?;
+    let h = g1_affine_from_uint8_array(h)?;
+    let mut comms = BTreeMap::new();
+    for e in commitments.entries() {
+        let arr = js_sys::Array::from(&e?);
+        let index: usize = from_value(arr.get(0))?;
+        if index >= params.h.len() {
+            return Err(native::Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!("Invalid index {:?} to get signature param", index),
+            )
+            .into());
+        }
+        let comm: MessageCommitment<Bls12_381> = from_value(arr.get(1))?;
+        comms.insert(index, comm);
+    }
+    let statements = js_sys::Array::new();
+    for comm in comms.values() {
+        let statement =
+            PedCommG1Stmt::new_statement_from_params::<Bls12_381>(vec![params.g, h], **comm);
+        let statement = obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt");
+        statements.push(&statement);
+    }
+    Ok(statements)
+}
+
+/// Create a statement for proving knowledge of the message and blinding committed in `commitment` by
+/// `dkgithEncrypt`. The message is the witness at index 0 so it can be tied to other witnesses with
+/// `generateWitnessEqualityMetaStatement`. The proof returned by `dkgithEncrypt` must be verified for the same
+/// commitment to know that the message is encrypted.
+#[wasm_bindgen(js_name = generateDkgithStatement)]
+pub fn generate_dkgith_statement(
+    params: Uint8Array,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let statement = PedCommG1Stmt::new_statement_from_params::<Bls12_381>(
+        vec![params.g_m, params.h],
+        commitment,
+    );
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
+/// Same as `generateDkgithStatement` but takes the index of the setup param created with
+/// `generateSetupParamForDkgithCommitmentKey`
+#[wasm_bindgen(js_name = generateDkgithStatementFromParamRefs)]
+pub fn generate_dkgith_statement_from_param_refs(
+    commitment_key: usize,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let statement =
+        PedCommG1Stmt::new_statement_from_params_refs::<Bls12_381>(commitment_key, commitment);
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
+/// Create the statement for the commitment returned by `saverEncryptForRecipients`. The message is the witness at
+/// index 0 so it can be tied to other witnesses with `generateWitnessEqualityMetaStatement`. Verify the composite
+/// proof with `verifyCompositeProofG1WithSaverMultiRecipient` to also check that the message is encrypted. If
+/// `uncompressed_public_params` is true, it expects the chunked commitment generators in uncompressed form.
+#[wasm_bindgen(js_name = generateSaverMultiRecipientStatement)]
+pub fn generate_saver_multi_recipient_statement(
+    chunked_comm_gens: Uint8Array,
+    commitment: Uint8Array,
+    uncompressed_public_params: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let comm_gens = if uncompressed_public_params {
+        obj_from_uint8array_uncompressed!(
+            ChunkedCommGens,
+            chunked_comm_gens,
+            "ChunkedCommitmentGenerators"
+        )
+    } else {
+        obj_from_uint8array!(
+            ChunkedCommGens,
+            chunked_comm_gens,
+            false,
+            "ChunkedCommitmentGenerators"
+        )
+    };
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let statement = PedCommG1Stmt::new_statement_from_params::<Bls12_381>(
+        vec![comm_gens.G, comm_gens.H],
+        commitment,
+    );
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
+/// Same as `generateSaverMultiRecipientStatement` but takes the index of the setup param created with
+/// `generateSetupParamForSaverMultiRecipientCommitmentKey`
+#[wasm_bindgen(js_name = generateSaverMultiRecipientStatementFromParamRefs)]
+pub fn generate_saver_multi_recipient_statement_from_param_refs(
+    commitment_key: usize,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let statement =
+        PedCommG1Stmt::new_statement_from_params_refs::<Bls12_381>(commitment_key, commitment);
+    Ok(obj_to_uint8array_uncompressed!(&statement, "PedCommG1Stmt"))
+}
+
 #[wasm_bindgen(js_name = generateSaverProverStatement)]
 pub fn generate_saver_prover_statement(
     chunk_bit_size: u8,
@@ -328,10 +557,7 @@ pub fn generate_saver_prover_statement(
         snark_pk,
     )
     .map_err(|e| {
-        JsValue::from(&format!(
-            "Creating statement for SaverProver returned error: {:?}",
-            e
-        ))
+        native::Error::from(e).context("Creating statement for SaverProver returned error")
     })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -390,10 +616,7 @@ pub fn generate_saver_verifier_statement(
         snark_vk,
     )
     .map_err(|e| {
-        JsValue::from(&format!(
-            "Creating statement for SaverVerifier returned error: {:?}",
-            e
-        ))
+        native::Error::from(e).context("Creating statement for SaverVerifier returned error")
     })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -442,10 +665,8 @@ pub fn generate_bound_check_lego_prover_statement(
     let statement =
         BoundCheckLegoProverStmt::new_statement_from_params::<G1Affine>(min, max, snark_pk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for BoundCheckLegoProver returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for BoundCheckLegoProver returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -464,10 +685,8 @@ pub fn generate_bound_check_lego_prover_statement_from_param_refs(
     let statement =
         BoundCheckLegoProverStmt::new_statement_from_params_ref::<G1Affine>(min, max, snark_pk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for BoundCheckLegoProver returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for BoundCheckLegoProver returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -494,10 +713,8 @@ pub fn generate_bound_check_lego_verifier_statement(
     let statement =
         BoundCheckLegoVerifierStmt::new_statement_from_params::<G1Affine>(min, max, snark_vk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for BoundCheckLegoVerifier returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for BoundCheckLegoVerifier returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -516,10 +733,8 @@ pub fn generate_bound_check_lego_verifier_statement_from_param_refs(
     let statement =
         BoundCheckLegoVerifierStmt::new_statement_from_params_ref::<G1Affine>(min, max, snark_vk)
             .map_err(|e| {
-            JsValue::from(&format!(
-                "Creating statement for BoundCheckLegoVerifier returned error: {:?}",
-                e
-            ))
+            native::Error::from(e)
+                .context("Creating statement for BoundCheckLegoVerifier returned error")
         })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -548,10 +763,8 @@ pub fn generate_r1cs_circom_prover_statement(
     let statement =
         R1CSCircomProverStmt::new_statement_from_params::<G1Affine>(r, wasm_bytes, snark_pk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for R1CSCircomProver returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for R1CSCircomProver returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -569,10 +782,8 @@ pub fn generate_r1cs_circom_prover_statement_from_param_refs(
     let statement =
         R1CSCircomProverStmt::new_statement_from_params_ref::<G1Affine>(r1cs, wasm_bytes, snark_pk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for R1CSCircomProver returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for R1CSCircomProver returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -596,10 +807,8 @@ pub fn generate_r1cs_circom_verifier_statement(
     let statement =
         R1CSCircomVerifierStmt::new_statement_from_params::<G1Affine>(public_inputs, snark_vk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for R1CSCircomVerifier returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for R1CSCircomVerifier returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -616,10 +825,8 @@ pub fn generate_r1cs_circom_verifier_statement_from_param_refs(
     let statement =
         R1CSCircomVerifierStmt::new_statement_from_params_ref::<G1Affine>(public_inputs, snark_vk)
             .map_err(|e| {
-                JsValue::from(&format!(
-                    "Creating statement for R1CSCircomVerifier returned error: {:?}",
-                    e
-                ))
+                native::Error::from(e)
+                    .context("Creating statement for R1CSCircomVerifier returned error")
             })?;
     Ok(obj_to_uint8array_uncompressed!(
         &statement,
@@ -660,12 +867,72 @@ fn parse_saver_statement_input(
 
 pub fn get_valid_min_max(min: js_sys::Number, max: js_sys::Number) -> Result<(u64, u64), JsValue> {
     if !(is_positive_safe_integer(&min) && is_positive_safe_integer(&max)) {
-        return Err(JsValue::from(&format!(
+        return Err(native::Error::invalid_argument(format!(
             "min and max should be safe positive integers but instead found {:?}, {:?}",
             min, max
-        )));
+        ))
+        .into());
     }
     let min = min.value_of() as u64;
     let max = max.value_of() as u64;
     Ok((min, max))
 }
+
+pub(crate) fn parse_witness_ref(wr: &JsValue) -> Result<(usize, usize), JsValue> {
+    let arr_2 = js_sys::Array::from(wr);
+    if arr_2.length() != 2 {
+        return Err(
+            native::Error::invalid_argument("Each equality should be a 2 element array").into(),
+        );
+    }
+    let index = |v: JsValue| {
+        serde_wasm_bindgen::from_value::<u32>(v).map_err(|e| {
+            native::Error::invalid_argument(format!(
+                "Witness reference should be a pair of non-negative integers: {}",
+                e
+            ))
+        })
+    };
+    let i = index(arr_2.get(0))?;
+    let j = index(arr_2.get(1))?;
+    Ok((i as usize, j as usize))
+}
+
+/// A coefficient or constant of a linear relation given as a safe integer or a field element
+fn linear_relation_term(term: &JsValue) -> Result<Fr, JsValue> {
+    if let Some(n) = term.as_f64() {
+        if !js_sys::Number::is_safe_integer(term) {
+            return Err(native::Error::invalid_argument(format!(
+                "Terms of a linear relation should be safe integers but found {}",
+                n
+            ))
+            .into());
+        }
+        let f = field_element_from_u64(n.abs() as u64);
+        Ok(if n < 0.0 { -f } else { f })
+    } else {
+        fr_from_uint8_array(Uint8Array::new(term), false)
+    }
+}
+
+fn get_bases_for_committed_indices(
+    h: &[G1Affine],
+    committed_indices: &js_sys::Set,
+) -> Result<Vec<G1Affine>, JsValue> {
+    let indices = js_set_to_btree_set::<usize>(committed_indices)?;
+    if indices.is_empty() {
+        return Err(native::Error::invalid_argument("Need at least 1 committed index").into());
+    }
+    indices
+        .into_iter()
+        .map(|i| {
+            h.get(i).copied().ok_or_else(|| {
+                native::Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!("Invalid index {:?} to get signature param", i),
+                )
+                .into()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,138 @@
+use crate::{
+    common::VerifyResponse,
+    native::{
+        self,
+        dkgith::{DkgithParams, DkgithProof, DkgithPublicKey, DkgithSecretKey},
+    },
+    utils::{
+        fr_from_uint8_array, fr_to_uint8_array, g1_affine_from_uint8_array,
+        g1_affine_to_uint8_array, set_panic_hook,
+    },
+};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+// Verifiable encryption of a message to an auditor's public key without a SNARK, using "DKG in the head". The prover
+// commits to the message with `dkgithEncrypt`, which also returns the blinding and a proof containing the encrypted
+// message. The composite proof has a statement from `generateDkgithStatement` whose witness from
+// `generateDkgithWitness` has the message at index 0, so `generateWitnessEqualityMetaStatement` ties it to other
+// witnesses like a signed message. The proof is bound to the composite proof by its nonce. The verifier verifies both
+// with `verifyCompositeProofG1WithDkgith`, which takes the commitment from the statement, and the auditor decrypts
+// with `dkgithDecrypt`.
+
+#[wasm_bindgen(js_name = dkgithGenerateParams)]
+pub fn dkgith_generate_params(label: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let params = native::dkgith::generate_params(label.as_deref())?;
+    Ok(obj_to_uint8array!(&params, false, "DkgithParams"))
+}
+
+/// Generate the auditor's secret key. A random seed is used if `seed` isn't given.
+#[wasm_bindgen(js_name = dkgithGenerateSecretKey)]
+pub fn dkgith_generate_secret_key(seed: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = native::dkgith::generate_secret_key(seed.as_deref())?;
+    Ok(obj_to_uint8array!(&sk, true, "DkgithSecretKey"))
+}
+
+#[wasm_bindgen(js_name = dkgithGeneratePublicKey)]
+pub fn dkgith_generate_public_key(
+    secret_key: Uint8Array,
+    params: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(DkgithSecretKey, secret_key, true, "DkgithSecretKey");
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    let pk = native::dkgith::generate_public_key(&sk, &params);
+    Ok(obj_to_uint8array!(&pk, false, "DkgithPublicKey"))
+}
+
+/// Commit to the encoded message and encrypt it to the auditor's public key. Returns an array of the commitment,
+/// the blinding and the proof. `nonce` is the nonce of the composite proof that the commitment is used in, the proof
+/// only verifies with it.
+#[wasm_bindgen(js_name = dkgithEncrypt)]
+pub fn dkgith_encrypt(
+    message: Uint8Array,
+    params: Uint8Array,
+    public_key: Uint8Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let message = fr_from_uint8_array(message, true)?;
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    let pk = obj_from_uint8array!(DkgithPublicKey, public_key, false, "DkgithPublicKey");
+    let context = nonce.unwrap_or_default();
+    let (commitment, blinding, proof) = native::dkgith::encrypt(&message, &params, &pk, &context)
+        .map_err(|e| e.context("Encryption returned error"))?;
+    let commitment = g1_affine_to_uint8_array(&commitment)?;
+    let blinding = fr_to_uint8_array(&blinding)?;
+    let proof = obj_to_uint8array!(&proof, false, "DkgithProof");
+    let result = Array::new();
+    result.push(&commitment);
+    result.push(&blinding);
+    result.push(&proof);
+    Ok(result)
+}
+
+/// Verify that the proof encrypts the message committed in `commitment`. `nonce` is the nonce of the composite
+/// proof. `verifyCompositeProofG1WithDkgith` should be used instead to verify the composite proof as well and take
+/// the commitment from its statement.
+#[wasm_bindgen(js_name = dkgithVerifyEncryption)]
+pub fn dkgith_verify_encryption(
+    proof: Uint8Array,
+    commitment: Uint8Array,
+    params: Uint8Array,
+    public_key: Uint8Array,
+    nonce: Option<Vec<u8>>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(DkgithProof, proof, false, "DkgithProof");
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    let pk = obj_from_uint8array!(DkgithPublicKey, public_key, false, "DkgithPublicKey");
+    let context = nonce.unwrap_or_default();
+    let result = native::dkgith::verify(&proof, &commitment, &params, &pk, &context);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Decrypt the message from the proof, which should have been verified with `dkgithVerifyEncryption`. Returns an
+/// array of the message and the blinding, which are checked with `dkgithVerifyDecryption`.
+#[wasm_bindgen(js_name = dkgithDecrypt)]
+pub fn dkgith_decrypt(
+    proof: Uint8Array,
+    commitment: Uint8Array,
+    secret_key: Uint8Array,
+    params: Uint8Array,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(DkgithProof, proof, false, "DkgithProof");
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let sk = obj_from_uint8array!(DkgithSecretKey, secret_key, true, "DkgithSecretKey");
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    let (message, blinding) = native::dkgith::decrypt(&proof, &commitment, &sk, &params)
+        .map_err(|e| e.context("Decryption returned error"))?;
+    let message = fr_to_uint8_array(&message)?;
+    let blinding = fr_to_uint8_array(&blinding)?;
+    let result = Array::new();
+    result.push(&message);
+    result.push(&blinding);
+    Ok(result)
+}
+
+/// Verify that the decrypted message and blinding open `commitment`
+#[wasm_bindgen(js_name = dkgithVerifyDecryption)]
+pub fn dkgith_verify_decryption(
+    commitment: Uint8Array,
+    message: Uint8Array,
+    blinding: Uint8Array,
+    params: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let message = fr_from_uint8_array(message, false)?;
+    let blinding = fr_from_uint8_array(blinding, false)?;
+    let params = obj_from_uint8array!(DkgithParams, params, false, "DkgithParams");
+    let result = native::dkgith::verify_decryption(&commitment, &message, &blinding, &params);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
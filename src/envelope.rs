@@ -0,0 +1,92 @@
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    native::{
+        self,
+        envelope::{self, ArtifactType},
+    },
+    utils::set_panic_hook,
+};
+
+/// Header of an envelope as returned by `envelopeInspect`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvelopeInfo {
+    #[serde(rename = "type")]
+    pub artifact_type: String,
+    pub type_tag: u16,
+    pub version: u8,
+    pub curve: u8,
+    pub compressed: bool,
+    pub payload_length: usize,
+}
+
+/// If `enabled` is true, functions returning serialized params, keys, signatures, proofs, field and group elements,
+/// etc put them in an envelope that records their type, compression and curve.
+#[wasm_bindgen(js_name = setEnvelopeOutput)]
+pub fn set_envelope_output(enabled: bool) {
+    set_panic_hook();
+    envelope::set_envelope_output(enabled)
+}
+
+/// If `enabled` is true, functions taking serialized objects accept them with or without an envelope and an envelope
+/// must have the expected type. Otherwise, which is the default, the bytes are always taken to be the raw object and
+/// `envelopeUnwrap` has to be used to pass an object that is in an envelope.
+#[wasm_bindgen(js_name = setEnvelopeInput)]
+pub fn set_envelope_input(enabled: bool) {
+    set_panic_hook();
+    envelope::set_envelope_input(enabled)
+}
+
+/// Put the serialized object of type `type_name`, like "BBSPublicKey" or "AccumPk", in an envelope. `compressed`
+/// should be false if the object was serialized in uncompressed form like SNARK keys can be.
+#[wasm_bindgen(js_name = envelopeWrap)]
+pub fn envelope_wrap(
+    bytes: Vec<u8>,
+    type_name: String,
+    compressed: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let artifact_type = artifact_type_from_name(&type_name)?;
+    Ok(js_sys::Uint8Array::from(
+        envelope::wrap(&bytes, artifact_type, compressed).as_slice(),
+    ))
+}
+
+/// Return the serialized object in the envelope. If `expected_type` is given, throws if the envelope has an object
+/// of another type.
+#[wasm_bindgen(js_name = envelopeUnwrap)]
+pub fn envelope_unwrap(
+    bytes: Vec<u8>,
+    expected_type: Option<String>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let expected_type = expected_type
+        .map(|t| artifact_type_from_name(&t))
+        .transpose()?;
+    let (_, payload) = envelope::unwrap(&bytes, expected_type)?;
+    Ok(js_sys::Uint8Array::from(payload))
+}
+
+/// Return the type, version, curve id, compression and payload length of the envelope
+#[wasm_bindgen(js_name = envelopeInspect)]
+pub fn envelope_inspect(bytes: Vec<u8>) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let (header, payload) = envelope::inspect(&bytes)?;
+    let info = EnvelopeInfo {
+        artifact_type: header.artifact_type.name().to_string(),
+        type_tag: header.artifact_type.tag(),
+        version: header.version,
+        curve: header.curve,
+        compressed: header.compressed,
+        payload_length: payload.len(),
+    };
+    serde_wasm_bindgen::to_value(&info).map_err(JsValue::from)
+}
+
+fn artifact_type_from_name(name: &str) -> Result<ArtifactType, JsValue> {
+    ArtifactType::from_name(name).ok_or_else(|| {
+        native::Error::invalid_argument(format!("Unknown envelope type {}", name)).into()
+    })
+}
@@ -1,17 +1,14 @@
 use crate::{
+    native,
     utils::{
         g1_affine_from_uint8_array, g1_affine_to_uint8_array, g2_affine_from_uint8_array,
-        g2_affine_to_uint8_array, get_seeded_rng, random_bytes, set_panic_hook,
+        g2_affine_to_uint8_array, set_panic_hook,
     },
     Fr, G1Affine, G2Affine,
 };
-use ark_ec::CurveGroup;
-use ark_std::ops::Mul;
-use blake2::Blake2b512;
 use js_sys::{Array, Uint8Array};
 use secret_sharing_and_dkg::{
     common::{ParticipantId, PublicKeyBase, Share, ShareId},
-    feldman_dvss_dkg::reconstruct_threshold_public_key,
     frost_dkg::{Round1Msg, Round1State, Round2State},
 };
 use wasm_bindgen::prelude::*;
@@ -29,21 +26,16 @@ type Round2StateG2 = Round2State<G2Affine>;
 macro_rules! start_round1 {
     ($participant_id: ident, $threshold: ident, $total: ident, $schnorr_proof_ctx: ident, $pk_base: ident, $pk_base_type: ident) => {{
         set_panic_hook();
-        let mut rng = get_seeded_rng();
         let pk_base = obj_from_uint8array!($pk_base_type, $pk_base, false);
-        let (state, msg) = Round1State::start_with_random_secret::<_, Blake2b512>(
-            &mut rng,
+        let (state, msg) = native::frost_dkg::start_round1(
             $participant_id,
             $threshold,
             $total,
             &$schnorr_proof_ctx,
-            &pk_base.0,
+            &pk_base,
         )
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Starting Round1 of Frost keygen returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Starting Round1 of Frost keygen returned error")
         })?;
         let r = Array::new();
         let state = obj_to_uint8array!(&state, true, "FrostRound1State");
@@ -60,14 +52,16 @@ macro_rules! receive_round1 {
         let mut round_state = obj_from_uint8array!($round_state_type, $round_state, true);
         let msg = obj_from_uint8array!($round_msg_type, $msg, false);
         let pk_base = obj_from_uint8array!($pk_base_type, $pk_base, false);
-        round_state
-            .add_received_message::<Blake2b512>(msg, &$schnorr_proof_ctx, &pk_base.0)
-            .map_err(|e| {
-                JsValue::from(&format!(
-                    "Processing Round1 message of Frost keygen returned error: {:?}",
-                    e
-                ))
-            })?;
+        native::frost_dkg::round1_process_received_message(
+            &mut round_state,
+            msg,
+            &$schnorr_proof_ctx,
+            &pk_base,
+        )
+        .map_err(|e| {
+            native::Error::from(e)
+                .context("Processing Round1 message of Frost keygen returned error")
+        })?;
         Ok(obj_to_uint8array!(&round_state, true, "FrostRound1State"))
     }};
 }
@@ -76,12 +70,11 @@ macro_rules! finish_round1 {
     ($round_state: ident, $round_state_type: ident) => {{
         set_panic_hook();
         let round_state = obj_from_uint8array!($round_state_type, $round_state, true);
-        let (round2_state, shares) = round_state.finish().map_err(|e| {
-            JsValue::from(&format!(
-                "Processing Round1 message of Frost keygen returned error: {:?}",
-                e
-            ))
-        })?;
+        let (round2_state, shares) =
+            native::frost_dkg::finish_round1(round_state).map_err(|e| {
+                native::Error::from(e)
+                    .context("Processing Round1 message of Frost keygen returned error")
+            })?;
         let round2_state = obj_to_uint8array!(&round2_state, true, "FrostRound2State");
         let shares_array = Array::new();
         for s in shares.0 {
@@ -101,14 +94,16 @@ macro_rules! receive_round2 {
         let mut round_state = obj_from_uint8array!($round_state_type, $round_state, true);
         let share = obj_from_uint8array!(Share<Fr>, $share, false);
         let pk_base = obj_from_uint8array!($pk_base_type, $pk_base, false);
-        round_state
-            .add_received_share($sender_id, share, &pk_base.0)
-            .map_err(|e| {
-                JsValue::from(&format!(
-                    "Processing Round2 message of Frost keygen returned error: {:?}",
-                    e
-                ))
-            })?;
+        native::frost_dkg::round2_process_received_share(
+            &mut round_state,
+            $sender_id,
+            share,
+            &pk_base,
+        )
+        .map_err(|e| {
+            native::Error::from(e)
+                .context("Processing Round2 message of Frost keygen returned error")
+        })?;
         Ok(obj_to_uint8array!(&round_state, true, "FrostRound2State"))
     }};
 }
@@ -118,12 +113,11 @@ macro_rules! finish_round2 {
         set_panic_hook();
         let round_state = obj_from_uint8array!($round_state_type, $round_state, true);
         let pk_base = obj_from_uint8array!($pk_base_type, $pk_base, false);
-        let (share, pk, tpk) = round_state.finish(&pk_base.0).map_err(|e| {
-            JsValue::from(&format!(
-                "Processing Round1 message of Frost keygen returned error: {:?}",
-                e
-            ))
-        })?;
+        let (share, pk, tpk) =
+            native::frost_dkg::finish_round2(round_state, &pk_base).map_err(|e| {
+                native::Error::from(e)
+                    .context("Processing Round1 message of Frost keygen returned error")
+            })?;
         let r = Array::new();
         let share = obj_to_uint8array!(&share.share, true, "SecretKeyShare");
         let pk = obj_to_uint8array!(&pk, false, "PublicKey");
@@ -143,25 +137,23 @@ macro_rules! gen_tpk {
             let pk_id = pk_id.unwrap();
             let arr = js_sys::Array::from(&pk_id);
             if arr.length() != 2 {
-                return Err(JsValue::from(&format!(
+                return Err(native::Error::invalid_argument(format!(
                     "Each item should be of length 2 but found of length {:?}",
                     arr.length()
-                )));
+                ))
+                .into());
             }
-            let i =
-                arr.get(0).as_f64().ok_or_else(|| {
-                    JsValue::from("The 1st element of item should have been a number")
-                })? as ShareId;
+            let i = arr.get(0).as_f64().ok_or_else(|| {
+                native::Error::invalid_argument("The 1st element of item should have been a number")
+            })? as ShareId;
             let pk_bytes = Uint8Array::new(&arr.get(1));
             let pk = $pk_cons(pk_bytes)?;
             pk_with_ids.push((i, pk));
         }
-        let tpk = reconstruct_threshold_public_key(pk_with_ids, $threshold).map_err(|e| {
-            JsValue::from(&format!(
-                "Reconstructing threshold public key returned error: {:?}",
-                e
-            ))
-        })?;
+        let tpk = native::frost_dkg::threshold_public_key_from_public_keys(pk_with_ids, $threshold)
+            .map_err(|e| {
+                native::Error::from(e).context("Reconstructing threshold public key returned error")
+            })?;
         Ok(obj_to_uint8array!(&tpk, false, "ThresholdPublicKey"))
     }};
 }
@@ -169,23 +161,15 @@ macro_rules! gen_tpk {
 #[wasm_bindgen(js_name = generateRandomPublicKeyBaseInG1)]
 pub fn generate_random_public_key_base_in_g1(seed: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
     set_panic_hook();
-    let label = seed.unwrap_or_else(random_bytes);
-    Ok(obj_to_uint8array!(
-        &PublicKeyBaseG1::new::<Blake2b512>(&label),
-        false,
-        "PublicKeyBaseG1"
-    ))
+    let pk_base: PublicKeyBaseG1 = native::frost_dkg::generate_public_key_base(seed.as_deref())?;
+    Ok(obj_to_uint8array!(&pk_base, false, "PublicKeyBaseG1"))
 }
 
 #[wasm_bindgen(js_name = generateRandomPublicKeyBaseInG2)]
 pub fn generate_random_public_key_base_in_g2(seed: Option<Vec<u8>>) -> Result<Uint8Array, JsValue> {
     set_panic_hook();
-    let label = seed.unwrap_or_else(random_bytes);
-    Ok(obj_to_uint8array!(
-        &PublicKeyBaseG2::new::<Blake2b512>(&label),
-        false,
-        "PublicKeyBaseG2"
-    ))
+    let pk_base: PublicKeyBaseG2 = native::frost_dkg::generate_public_key_base(seed.as_deref())?;
+    Ok(obj_to_uint8array!(&pk_base, false, "PublicKeyBaseG2"))
 }
 
 #[wasm_bindgen(js_name = generateKeyBaseFromGivenG1Point)]
@@ -344,7 +328,9 @@ pub fn frost_keygen_g1_pubkey_from_secret_key(
     set_panic_hook();
     let secret = obj_from_uint8array!(Fr, secret, true);
     let pk_base = obj_from_uint8array!(PublicKeyBaseG1, pk_base, false);
-    g1_affine_to_uint8_array(&pk_base.0.mul(secret).into_affine())
+    g1_affine_to_uint8_array(&native::frost_dkg::public_key_from_secret_key(
+        &secret, &pk_base,
+    ))
 }
 
 #[wasm_bindgen(js_name = frostKeygenG2PubkeyFromSecretKey)]
@@ -355,7 +341,9 @@ pub fn frost_keygen_g2_pubkey_from_secret_key(
     set_panic_hook();
     let secret = obj_from_uint8array!(Fr, secret, true);
     let pk_base = obj_from_uint8array!(PublicKeyBaseG2, pk_base, false);
-    g2_affine_to_uint8_array(&pk_base.0.mul(secret).into_affine())
+    g2_affine_to_uint8_array(&native::frost_dkg::public_key_from_secret_key(
+        &secret, &pk_base,
+    ))
 }
 
 #[wasm_bindgen(js_name = frostKeygenG1ThresholdPubkeyFromPubkeys)]
@@ -0,0 +1,210 @@
+use ark_bls12_381::Bls12_381;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    common::VerifyResponse,
+    native::{
+        self,
+        groth16::{CircomProvingKey, SnarkjsProof, SnarkjsVerifyingKey},
+    },
+    r1cs::gen_r1cs,
+    utils::{js_array_from_frs, js_array_to_fr_vec, set_panic_hook},
+};
+
+// Groth16 proofs for Circom circuits that are compatible with snarkjs. Unlike the LegoGroth16 proofs of
+// `r1csSnarkSetup`, these can't be linked to signed messages in a composite proof but proving keys can come from a
+// snarkjs zkey, and verification keys, proofs and public signals can be exchanged with snarkjs as its JSON. Keys are
+// taken and returned in compressed or uncompressed form as `uncompressed` says.
+
+pub(crate) type Groth16VerifyingKey = VerifyingKey<Bls12_381>;
+pub(crate) type Groth16Proof = Proof<Bls12_381>;
+
+/// Read a proving key from a Groth16 zkey created by snarkjs for a circuit over BLS12-381
+#[wasm_bindgen(js_name = groth16ProvingKeyFromZkey)]
+pub fn groth16_proving_key_from_zkey(
+    zkey: Uint8Array,
+    return_uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let pk = native::groth16::read_zkey(&zkey.to_vec())?;
+    key_to_uint8array(&pk, return_uncompressed, "Groth16ProvingKey")
+}
+
+/// Write the proving key as a zkey that snarkjs can create proofs with
+#[wasm_bindgen(js_name = groth16ProvingKeyToZkey)]
+pub fn groth16_proving_key_to_zkey(
+    snark_pk: Uint8Array,
+    uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let pk: CircomProvingKey = key_from_uint8array(snark_pk, uncompressed, "Groth16ProvingKey")?;
+    let zkey = native::groth16::write_zkey(&pk)?;
+    Ok(Uint8Array::from(zkey.as_slice()))
+}
+
+/// Create a proving key for the circuit, given as for `r1csSnarkSetup`
+#[wasm_bindgen(js_name = groth16SnarkSetup)]
+pub fn groth16_snark_setup(
+    curve_name: &str,
+    num_public: u32,
+    num_private: u32,
+    constraints: Array,
+    return_uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let r = gen_r1cs(curve_name, num_public, num_private, constraints)?;
+    let pk = native::groth16::setup(r).map_err(|e| e.context("Groth16 setup returned error"))?;
+    key_to_uint8array(&pk, return_uncompressed, "Groth16ProvingKey")
+}
+
+/// Return the verification key from the proving key
+#[wasm_bindgen(js_name = groth16VkFromPk)]
+pub fn groth16_vk_from_pk(snark_pk: Uint8Array, uncompressed: bool) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let pk: CircomProvingKey = key_from_uint8array(snark_pk, uncompressed, "Groth16ProvingKey")?;
+    key_to_uint8array(&pk.pk.vk, uncompressed, "Groth16VerifyingKey")
+}
+
+/// Create a proof given all wires of the circuit as returned by `r1csGenerateWires`. Returns an array of the proof
+/// and the public signals of the circuit which the verifier needs.
+#[wasm_bindgen(js_name = groth16Prove)]
+pub fn groth16_prove(
+    snark_pk: Uint8Array,
+    wires: Array,
+    uncompressed: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let pk: CircomProvingKey = key_from_uint8array(snark_pk, uncompressed, "Groth16ProvingKey")?;
+    let wires = js_array_to_fr_vec(&wires)?;
+    let (proof, public_signals) = native::groth16::prove(&pk, &wires)
+        .map_err(|e| e.context("Groth16 proving returned error"))?;
+    let result = Array::new();
+    let proof = obj_to_uint8array!(&proof, false, "Groth16Proof");
+    let public_signals = js_array_from_frs(&public_signals)?;
+    result.push(&proof);
+    result.push(&public_signals);
+    Ok(result)
+}
+
+#[wasm_bindgen(js_name = groth16Verify)]
+pub fn groth16_verify(
+    proof: Uint8Array,
+    snark_vk: Uint8Array,
+    public_signals: Array,
+    uncompressed: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(Groth16Proof, proof, false, "Groth16Proof");
+    let vk: Groth16VerifyingKey =
+        key_from_uint8array(snark_vk, uncompressed, "Groth16VerifyingKey")?;
+    let public_signals = js_array_to_fr_vec(&public_signals)?;
+    let result = native::groth16::verify(&vk, &proof, &public_signals);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Convert the verification key to the object of snarkjs' `verification_key.json`
+#[wasm_bindgen(js_name = groth16VkToSnarkjs)]
+pub fn groth16_vk_to_snarkjs(snark_vk: Uint8Array, uncompressed: bool) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let vk: Groth16VerifyingKey =
+        key_from_uint8array(snark_vk, uncompressed, "Groth16VerifyingKey")?;
+    to_snarkjs(&native::groth16::verifying_key_to_snarkjs(&vk))
+}
+
+/// Create a verification key from the object of snarkjs' `verification_key.json`
+#[wasm_bindgen(js_name = groth16VkFromSnarkjs)]
+pub fn groth16_vk_from_snarkjs(
+    snark_vk: JsValue,
+    return_uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let vk: SnarkjsVerifyingKey = from_snarkjs(snark_vk, "verification key")?;
+    let vk = native::groth16::verifying_key_from_snarkjs(&vk)?;
+    key_to_uint8array(&vk, return_uncompressed, "Groth16VerifyingKey")
+}
+
+/// Convert the proof to the object of snarkjs' `proof.json`
+#[wasm_bindgen(js_name = groth16ProofToSnarkjs)]
+pub fn groth16_proof_to_snarkjs(proof: Uint8Array) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(Groth16Proof, proof, false, "Groth16Proof");
+    to_snarkjs(&native::groth16::proof_to_snarkjs(&proof))
+}
+
+/// Create a proof from the object of snarkjs' `proof.json`
+#[wasm_bindgen(js_name = groth16ProofFromSnarkjs)]
+pub fn groth16_proof_from_snarkjs(proof: JsValue) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let proof: SnarkjsProof = from_snarkjs(proof, "proof")?;
+    let proof = native::groth16::proof_from_snarkjs(&proof)?;
+    Ok(obj_to_uint8array!(&proof, false, "Groth16Proof"))
+}
+
+/// Convert the public signals returned by `groth16Prove` to the array of decimal strings of snarkjs' `public.json`
+#[wasm_bindgen(js_name = groth16PublicSignalsToSnarkjs)]
+pub fn groth16_public_signals_to_snarkjs(public_signals: Array) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let public_signals = js_array_to_fr_vec(&public_signals)?;
+    to_snarkjs(&native::groth16::public_signals_to_snarkjs(&public_signals))
+}
+
+/// Convert the array of decimal strings of snarkjs' `public.json` to public signals for `groth16Verify`
+#[wasm_bindgen(js_name = groth16PublicSignalsFromSnarkjs)]
+pub fn groth16_public_signals_from_snarkjs(public_signals: JsValue) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let public_signals: Vec<String> = from_snarkjs(public_signals, "public signals")?;
+    js_array_from_frs(&native::groth16::public_signals_from_snarkjs(
+        &public_signals,
+    )?)
+}
+
+fn to_snarkjs<T: serde::Serialize>(obj: &T) -> Result<JsValue, JsValue> {
+    obj.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| {
+            native::Error::with_details(
+                native::ErrorCode::SerializationFailed,
+                "Failed to create snarkjs JSON",
+                e,
+            )
+            .into()
+        })
+}
+
+fn from_snarkjs<T: serde::de::DeserializeOwned>(obj: JsValue, name: &str) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(obj).map_err(|e| {
+        native::Error::with_details(
+            native::ErrorCode::DeserializationFailed,
+            format!("Invalid snarkjs {}", name),
+            e,
+        )
+        .into()
+    })
+}
+
+fn key_from_uint8array<K: CanonicalDeserialize>(
+    key: Uint8Array,
+    uncompressed: bool,
+    name: &str,
+) -> Result<K, JsValue> {
+    Ok(if uncompressed {
+        obj_from_uint8array_uncompressed!(K, key, name)
+    } else {
+        obj_from_uint8array!(K, key, false, name)
+    })
+}
+
+fn key_to_uint8array<K: CanonicalSerialize>(
+    key: &K,
+    uncompressed: bool,
+    name: &str,
+) -> Result<Uint8Array, JsValue> {
+    Ok(if uncompressed {
+        obj_to_uint8array_uncompressed!(key, name)
+    } else {
+        obj_to_uint8array!(key, false, name)
+    })
+}
@@ -0,0 +1,313 @@
+//! Handles to objects that are deserialized and validated once and then kept in wasm memory. Passing a handle
+//! instead of bytes avoids deserializing and subgroup checking the same params, keys or proof spec on every call,
+//! which dominates the cost of creating composite proofs with SNARK keys. A handle must be freed by calling `free`
+//! on it once it's no longer needed.
+
+use ark_bls12_381::Bls12_381;
+use bbs_plus::setup::MultiMessageSignatureParams;
+use js_sys::Uint8Array;
+use legogroth16::circom::WitnessCalculator;
+use proof_system::{
+    meta_statement::MetaStatement, setup_params::SetupParams, statement::Statement,
+};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    bbs::{BBSPublicKey, BBSSigParams},
+    bbs_plus::{BBSPlusPublicKeyG2, BBSPlusSigParamsG1},
+    composite_proof_system::{parse_statements_meta_statements_and_setup_params, ProofSpec},
+    legosnark::{LegoProvingKey, LegoVerifyingKey},
+    native::{self, envelope, key_loader::ProvingKeyLoader},
+    saver::{SaverSnarkPk, SaverSnarkVk},
+    utils::{from_value, set_panic_hook, Uint8ArrayPartsReader},
+    G1Affine,
+};
+
+/// Handle to signature params which are created from the same object that the non-handle functions accept
+macro_rules! impl_params_handle {
+    ($handle: ident, $params: ty) => {
+        #[wasm_bindgen]
+        pub struct $handle(pub(crate) $params);
+
+        #[wasm_bindgen]
+        impl $handle {
+            #[wasm_bindgen(constructor)]
+            pub fn new(params: JsValue) -> Result<$handle, JsValue> {
+                set_panic_hook();
+                let params: $params = from_value(params)?;
+                Ok(Self(params))
+            }
+
+            #[wasm_bindgen(js_name = supportedMessageCount)]
+            pub fn supported_message_count(&self) -> usize {
+                self.0.supported_message_count()
+            }
+        }
+    };
+}
+
+/// Handle to a public key which is created from its compressed bytes
+macro_rules! impl_public_key_handle {
+    ($handle: ident, $pk: ty, $name: expr) => {
+        #[wasm_bindgen]
+        pub struct $handle(pub(crate) $pk);
+
+        #[wasm_bindgen]
+        impl $handle {
+            #[wasm_bindgen(constructor)]
+            pub fn new(public_key: Uint8Array) -> Result<$handle, JsValue> {
+                set_panic_hook();
+                Ok(Self(obj_from_uint8array!($pk, public_key, false, $name)))
+            }
+        }
+    };
+}
+
+/// Handle to a SNARK key which is created from its compressed or uncompressed bytes
+macro_rules! impl_snark_key_handle {
+    ($handle: ident, $key: ty, $name: expr) => {
+        #[wasm_bindgen]
+        pub struct $handle(pub(crate) $key);
+
+        #[wasm_bindgen]
+        impl $handle {
+            /// If `uncompressed` is true, expects the key to be in uncompressed form else it should be compressed.
+            #[wasm_bindgen(constructor)]
+            pub fn new(key: Uint8Array, uncompressed: bool) -> Result<$handle, JsValue> {
+                set_panic_hook();
+                let key = if uncompressed {
+                    obj_from_uint8array_uncompressed!($key, key, $name)
+                } else {
+                    obj_from_uint8array!($key, key, false, $name)
+                };
+                Ok(Self(key))
+            }
+
+            /// Create from the key's bytes split in several `Uint8Array`s like the chunks of a stream. The parts
+            /// are deserialized as they are read so the whole key is never copied to wasm memory, which otherwise
+            /// doubles the memory needed for large keys.
+            #[wasm_bindgen(js_name = fromParts)]
+            pub fn from_parts(
+                parts: js_sys::Array,
+                uncompressed: bool,
+            ) -> Result<$handle, JsValue> {
+                set_panic_hook();
+                let reader = Uint8ArrayPartsReader::new(&parts)?;
+                Ok(Self(envelope::deserialize_from_reader(
+                    reader,
+                    $name,
+                    !uncompressed,
+                )?))
+            }
+        }
+    };
+}
+
+/// Loader of a proving key handle from chunks of the key's bytes, like those read from a `ReadableStream`. Each chunk
+/// is parsed as it's pushed so the reader can be asked for the next chunk only once the loader has taken the
+/// previous one.
+macro_rules! impl_proving_key_loader {
+    ($loader: ident, $handle: ident, $key: ty) => {
+        #[wasm_bindgen]
+        pub struct $loader(ProvingKeyLoader<$key>);
+
+        #[wasm_bindgen]
+        impl $loader {
+            /// If `uncompressed` is true, expects the key to be in uncompressed form else it should be compressed.
+            /// If `proving_only` is true, the parts of the verification key that proving doesn't need are skipped
+            /// and the handle can only be used to create proofs.
+            #[wasm_bindgen(constructor)]
+            pub fn new(uncompressed: bool, proving_only: bool) -> $loader {
+                set_panic_hook();
+                Self(ProvingKeyLoader::new(!uncompressed, proving_only))
+            }
+
+            pub fn push(&mut self, chunk: Uint8Array) -> Result<(), JsValue> {
+                Ok(self.0.push(&chunk.to_vec())?)
+            }
+
+            /// Returns the handle once all chunks are pushed. The loader can't be used after this.
+            pub fn finish(self) -> Result<$handle, JsValue> {
+                Ok($handle(self.0.finish()?))
+            }
+        }
+    };
+}
+
+impl_params_handle!(BbsSigParamsHandle, BBSSigParams);
+impl_params_handle!(BbsPlusSigParamsG1Handle, BBSPlusSigParamsG1);
+impl_public_key_handle!(BbsPublicKeyHandle, BBSPublicKey, "BBSPublicKey");
+impl_public_key_handle!(
+    BbsPlusPublicKeyG2Handle,
+    BBSPlusPublicKeyG2,
+    "BBSPlusPublicKeyG2"
+);
+impl_snark_key_handle!(LegoProvingKeyHandle, LegoProvingKey, "LegoProvingKey");
+impl_snark_key_handle!(LegoVerifyingKeyHandle, LegoVerifyingKey, "LegoVerifyingKey");
+impl_snark_key_handle!(SaverProvingKeyHandle, SaverSnarkPk, "SaverSnarkPk");
+impl_snark_key_handle!(SaverVerifyingKeyHandle, SaverSnarkVk, "SaverSnarkVk");
+impl_proving_key_loader!(LegoProvingKeyLoader, LegoProvingKeyHandle, LegoProvingKey);
+impl_proving_key_loader!(SaverProvingKeyLoader, SaverProvingKeyHandle, SaverSnarkPk);
+
+/// Handle to a `ProofSpec` with statements in group G1. Setup params can be added from other handles so that large
+/// keys are never serialized, and statements created with the `...FromParamRefs` functions can refer to them by
+/// the index returned when adding them.
+#[wasm_bindgen]
+pub struct ProofSpecG1Handle(pub(crate) ProofSpec<G1Affine>);
+
+#[wasm_bindgen]
+impl ProofSpecG1Handle {
+    /// Takes the same arguments as `generateProofSpecG1`
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        statements: js_sys::Array,
+        meta_statements: js_sys::Array,
+        setup_params: js_sys::Array,
+        context: Option<Vec<u8>>,
+    ) -> Result<ProofSpecG1Handle, JsValue> {
+        set_panic_hook();
+        let (statements, meta_statements, setup_params) =
+            parse_statements_meta_statements_and_setup_params(
+                statements,
+                meta_statements,
+                setup_params,
+            )?;
+        Ok(Self(ProofSpec::<G1Affine>::new(
+            statements,
+            meta_statements,
+            setup_params,
+            context,
+        )))
+    }
+
+    /// Create from the bytes returned by `generateProofSpecG1`
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: Uint8Array) -> Result<ProofSpecG1Handle, JsValue> {
+        set_panic_hook();
+        Ok(Self(obj_from_uint8array_uncompressed!(
+            ProofSpec::<G1Affine>,
+            bytes,
+            "ProofSpecG1"
+        )))
+    }
+
+    /// Serialize to the same bytes as returned by `generateProofSpecG1`
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Uint8Array, JsValue> {
+        Ok(obj_to_uint8array_uncompressed!(&self.0, "ProofSpecG1"))
+    }
+
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(&self) -> bool {
+        self.0.validate().is_ok()
+    }
+
+    /// Add a statement as returned by the statement generation functions and return its index
+    #[wasm_bindgen(js_name = addStatement)]
+    pub fn add_statement(&mut self, statement: Uint8Array) -> Result<usize, JsValue> {
+        set_panic_hook();
+        let statement = obj_from_uint8array_uncompressed!(
+            Statement<Bls12_381, G1Affine>,
+            statement,
+            "Statement"
+        );
+        Ok(self.0.add_statement(statement))
+    }
+
+    #[wasm_bindgen(js_name = addMetaStatement)]
+    pub fn add_meta_statement(&mut self, meta_statement: JsValue) -> Result<usize, JsValue> {
+        set_panic_hook();
+        let meta_statement: MetaStatement = from_value(meta_statement)?;
+        Ok(self.0.add_meta_statement(meta_statement))
+    }
+
+    /// Add the params as a setup param and return its index
+    #[wasm_bindgen(js_name = addSetupParamForBBSSignatureParameters)]
+    pub fn add_setup_param_for_bbs_sig_params(&mut self, params: &BbsSigParamsHandle) -> usize {
+        self.add_setup_param(SetupParams::BBSSignatureParams23(params.0.clone()))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForBBSPlusSignatureParametersG1)]
+    pub fn add_setup_param_for_bbs_plus_sig_params_g1(
+        &mut self,
+        params: &BbsPlusSigParamsG1Handle,
+    ) -> usize {
+        self.add_setup_param(SetupParams::BBSPlusSignatureParams(params.0.clone()))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForBBSPublicKey)]
+    pub fn add_setup_param_for_bbs_public_key(&mut self, public_key: &BbsPublicKeyHandle) -> usize {
+        self.add_setup_param(SetupParams::BBSPlusPublicKey(public_key.0.clone()))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForBBSPlusPublicKeyG2)]
+    pub fn add_setup_param_for_bbs_plus_public_key(
+        &mut self,
+        public_key: &BbsPlusPublicKeyG2Handle,
+    ) -> usize {
+        self.add_setup_param(SetupParams::BBSPlusPublicKey(public_key.0.clone()))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForLegoProvingKey)]
+    pub fn add_setup_param_for_lego_proving_key(&mut self, key: &LegoProvingKeyHandle) -> usize {
+        self.add_setup_param(SetupParams::LegoSnarkProvingKey(key.0.clone()))
+    }
+
+    /// Same as `addSetupParamForLegoProvingKey` but moves the key out of the handle rather than copying it, so
+    /// that only one copy of the key is in memory. The handle can't be used after this.
+    #[wasm_bindgen(js_name = moveSetupParamForLegoProvingKey)]
+    pub fn move_setup_param_for_lego_proving_key(&mut self, key: LegoProvingKeyHandle) -> usize {
+        self.add_setup_param(SetupParams::LegoSnarkProvingKey(key.0))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForLegoVerifyingKey)]
+    pub fn add_setup_param_for_lego_verifying_key(
+        &mut self,
+        key: &LegoVerifyingKeyHandle,
+    ) -> usize {
+        self.add_setup_param(SetupParams::LegoSnarkVerifyingKey(key.0.clone()))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForSaverProvingKey)]
+    pub fn add_setup_param_for_saver_proving_key(&mut self, key: &SaverProvingKeyHandle) -> usize {
+        self.add_setup_param(SetupParams::SaverProvingKey(key.0.clone()))
+    }
+
+    /// Same as `moveSetupParamForLegoProvingKey` but for the snark proving key of SAVER
+    #[wasm_bindgen(js_name = moveSetupParamForSaverProvingKey)]
+    pub fn move_setup_param_for_saver_proving_key(&mut self, key: SaverProvingKeyHandle) -> usize {
+        self.add_setup_param(SetupParams::SaverProvingKey(key.0))
+    }
+
+    #[wasm_bindgen(js_name = addSetupParamForSaverVerifyingKey)]
+    pub fn add_setup_param_for_saver_verifying_key(
+        &mut self,
+        key: &SaverVerifyingKeyHandle,
+    ) -> usize {
+        self.add_setup_param(SetupParams::SaverVerifyingKey(key.0.clone()))
+    }
+}
+
+impl ProofSpecG1Handle {
+    fn add_setup_param(&mut self, param: SetupParams<Bls12_381, G1Affine>) -> usize {
+        self.0.setup_params.push(param);
+        self.0.setup_params.len() - 1
+    }
+}
+
+/// Handle to the witness calculator of a Circom circuit, created from the circuit's wasm which is compiled only
+/// once. Can be passed instead of the wasm bytes to the functions calculating the wires.
+#[wasm_bindgen]
+pub struct WitnessCalculatorHandle(pub(crate) WitnessCalculator<Bls12_381>);
+
+#[wasm_bindgen]
+impl WitnessCalculatorHandle {
+    #[wasm_bindgen(constructor)]
+    pub fn new(wasm_bytes: Uint8Array) -> Result<WitnessCalculatorHandle, JsValue> {
+        set_panic_hook();
+        Ok(Self(native::r1cs::witness_calculator(
+            &wasm_bytes.to_vec(),
+        )?))
+    }
+}
@@ -0,0 +1,652 @@
+use ark_bls12_381::Bls12_381;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError, Write};
+use ark_std::{collections::BTreeMap, rand::RngCore, UniformRand, Zero};
+use bbs_plus::{prelude::MessageOrBlinding, proof_23_alternate::KnowledgeOfEProof};
+use blake2::Blake2b512;
+use dock_crypto_utils::{
+    hashing_utils::affine_group_elem_from_try_and_incr, serde_utils::ArkObjectBytes,
+};
+use schnorr_pok::{
+    compute_random_oracle_challenge, error::SchnorrError, impl_proof_of_knowledge_of_discrete_log,
+    SchnorrResponse,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    bbs::{BBSPoKOfSigProof, BBSPoKOfSigProtocol, BBSPublicKey, BBSSigParams, BBSSignature},
+    bbs_plus::{
+        BBSPlusPoKOfSigProof, BBSPlusPoKOfSigProtocol, BBSPlusPublicKeyG2, BBSPlusSigG1,
+        BBSPlusSigParamsG1, BBSPlusSigningKey,
+    },
+    common::VerifyResponse,
+    native::{self, ErrorCode},
+    utils::{
+        encode_messages_as_js_array_to_fr_vec, encode_messages_as_js_map_to_fr_btreemap,
+        fr_from_uint8_array, fr_to_uint8_array, from_value, g1_affine_from_uint8_array,
+        g1_affine_to_uint8_array, get_seeded_rng, js_set_to_btree_set, random_bytes,
+        set_panic_hook,
+    },
+    Fr, G1Affine,
+};
+
+/// Label to generate the base of the verifier's public key that signs the issuer policy
+const VERIFIER_KEY_BASE_LABEL: &[u8] = b"issuer-hiding-verifier-key-base";
+
+impl_proof_of_knowledge_of_discrete_log!(PolicySignatureProtocol, PolicySignature);
+
+/// Public key of an issuer allowed by the verifier. `public_key_g1` is the issuer's secret key multiplied by
+/// `params.g1` and is needed to create proofs that hide the issuer.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IssuerKey<P: CanonicalSerialize + CanonicalDeserialize> {
+    pub params: P,
+    pub public_key: BBSPlusPublicKeyG2,
+    pub public_key_g1: G1Affine,
+}
+
+/// List of issuer keys accepted by a verifier, signed by the verifier such that holders can check that the
+/// policy comes from the verifier.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IssuerPolicy<P: CanonicalSerialize + CanonicalDeserialize> {
+    pub keys: Vec<IssuerKey<P>>,
+    pub signature: PolicySignature<G1Affine>,
+}
+
+/// Proof of knowledge of a signature from one of the issuers of an `IssuerPolicy`. Contains a proof of knowledge
+/// of signature for each issuer key where all but one are simulated. The challenges of these proofs add up to
+/// the challenge computed from the transcript.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IssuerHidingProof<P: CanonicalSerialize + CanonicalDeserialize> {
+    pub challenges: Vec<Fr>,
+    pub proofs: Vec<P>,
+}
+
+pub(crate) type BBSPlusIssuerPolicy = IssuerPolicy<BBSPlusSigParamsG1>;
+pub(crate) type BBSIssuerPolicy = IssuerPolicy<BBSSigParams>;
+pub(crate) type BBSPlusIssuerHidingProof = IssuerHidingProof<BBSPlusPoKOfSigProof>;
+pub(crate) type BBSIssuerHidingProof = IssuerHidingProof<BBSPoKOfSigProof>;
+
+#[wasm_bindgen(js_name = generateIssuerHidingVerifierSecretKey)]
+pub fn generate_issuer_hiding_verifier_secret_key(
+    seed: Option<Vec<u8>>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let seed = seed.map_or_else(random_bytes, Ok)?;
+    let sk = BBSPlusSigningKey::generate_using_seed::<Blake2b512>(&seed);
+    fr_to_uint8_array(&sk.0)
+}
+
+#[wasm_bindgen(js_name = generateIssuerHidingVerifierPublicKey)]
+pub fn generate_issuer_hiding_verifier_public_key(
+    secret_key: js_sys::Uint8Array,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = fr_from_uint8_array(secret_key, true)?;
+    g1_affine_to_uint8_array(&(verifier_key_base() * sk).into_affine())
+}
+
+/// Generate the issuer's public key in group G1 that the verifier needs along with the public key in group G2
+/// to put the issuer in an issuer policy for BBS+ signatures.
+#[wasm_bindgen(js_name = bbsPlusGeneratePublicKeyG1ForIssuerHiding)]
+pub fn bbs_plus_generate_public_key_g1_for_issuer_hiding(
+    secret_key: js_sys::Uint8Array,
+    params: JsValue,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSPlusSigningKey");
+    let params: BBSPlusSigParamsG1 = from_value(params)?;
+    g1_affine_to_uint8_array(&(params.g1 * sk.0).into_affine())
+}
+
+/// Same as `bbsPlusGeneratePublicKeyG1ForIssuerHiding` but for BBS signatures
+#[wasm_bindgen(js_name = bbsGeneratePublicKeyG1ForIssuerHiding)]
+pub fn bbs_generate_public_key_g1_for_issuer_hiding(
+    secret_key: js_sys::Uint8Array,
+    params: JsValue,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(BBSPlusSigningKey, secret_key, true, "BBSSecretKey");
+    let params: BBSSigParams = from_value(params)?;
+    g1_affine_to_uint8_array(&(params.g1 * sk.0).into_affine())
+}
+
+macro_rules! impl_issuer_hiding {
+    ($sign_policy_fn: ident, $sign_policy_js: ident, $verify_policy_fn: ident, $verify_policy_js: ident, $gen_proof_fn: ident, $gen_proof_js: ident, $verify_proof_fn: ident, $verify_proof_js: ident, $params: ident, $sig: ident, $pk: ident, $policy: ident, $proof: ident, $protocol: ident, $simulate_fn: ident, $name: expr) => {
+        /// Called by the verifier to create a policy of issuers it accepts and sign it using its secret key.
+        /// `params`, `public_keys` and `public_keys_g1` are the signature params, public key and public key in
+        /// group G1 of each issuer.
+        #[wasm_bindgen(js_name = $sign_policy_js)]
+        pub fn $sign_policy_fn(
+            params: js_sys::Array,
+            public_keys: js_sys::Array,
+            public_keys_g1: js_sys::Array,
+            verifier_secret_key: js_sys::Uint8Array,
+        ) -> Result<js_sys::Uint8Array, JsValue> {
+            set_panic_hook();
+            if params.length() != public_keys.length()
+                || params.length() != public_keys_g1.length()
+            {
+                return Err(native::Error::new(
+                    ErrorCode::MessageCountMismatch,
+                    format!(
+                        "Need same number of params, public keys and public keys in G1 but found {}, {} and {}",
+                        params.length(),
+                        public_keys.length(),
+                        public_keys_g1.length()
+                    ),
+                )
+                .into());
+            }
+            let mut keys = Vec::with_capacity(params.length() as usize);
+            for i in 0..params.length() {
+                let p: $params = from_value(params.get(i))?;
+                let pk = obj_from_uint8array!(
+                    $pk,
+                    js_sys::Uint8Array::new(&public_keys.get(i)),
+                    false,
+                    "PublicKey"
+                );
+                let pk_g1 =
+                    g1_affine_from_uint8_array(js_sys::Uint8Array::new(&public_keys_g1.get(i)))?;
+                // e(pk_g1, g2) == e(g1, pk)
+                if Bls12_381::pairing(pk_g1, p.g2) != Bls12_381::pairing(p.g1, pk.0) {
+                    return Err(native::Error::invalid_argument(format!(
+                        "Public key in G1 of issuer {} doesn't match its public key",
+                        i
+                    ))
+                    .into());
+                }
+                keys.push(IssuerKey {
+                    params: p,
+                    public_key: pk,
+                    public_key_g1: pk_g1,
+                });
+            }
+            let sk = fr_from_uint8_array(verifier_secret_key, true)?;
+            let signature = sign_policy(&keys, &sk)?;
+            let policy = $policy { keys, signature };
+            Ok(obj_to_uint8array!(&policy, false, "IssuerPolicy"))
+        }
+
+        /// Called by the holder to check that the issuer policy was signed by the verifier
+        #[wasm_bindgen(js_name = $verify_policy_js)]
+        pub fn $verify_policy_fn(
+            policy: js_sys::Uint8Array,
+            verifier_public_key: js_sys::Uint8Array,
+        ) -> Result<JsValue, JsValue> {
+            set_panic_hook();
+            let policy = obj_from_uint8array!($policy, policy, false, "IssuerPolicy");
+            let vk = g1_affine_from_uint8_array(verifier_public_key)?;
+            Ok(to_verify_response(verify_policy(&policy, &vk)))
+        }
+
+        #[doc = concat!("Create a proof of knowledge of a ", $name, " signature from the issuer at index `issuer_index` of the ")]
+        /// policy without revealing the issuer. Fails if the policy isn't signed by the verifier.
+        #[allow(clippy::too_many_arguments)]
+        #[wasm_bindgen(js_name = $gen_proof_js)]
+        pub fn $gen_proof_fn(
+            policy: js_sys::Uint8Array,
+            verifier_public_key: js_sys::Uint8Array,
+            issuer_index: usize,
+            signature: js_sys::Uint8Array,
+            messages: js_sys::Array,
+            revealed_indices: js_sys::Set,
+            encode_messages: bool,
+            nonce: Option<Vec<u8>>,
+        ) -> Result<js_sys::Uint8Array, JsValue> {
+            set_panic_hook();
+            let policy = obj_from_uint8array!($policy, policy, false, "IssuerPolicy");
+            let vk = g1_affine_from_uint8_array(verifier_public_key)?;
+            verify_policy(&policy, &vk)?;
+            if issuer_index >= policy.keys.len() {
+                return Err(native::Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!(
+                        "Issuer index {} is invalid as policy has {} issuers",
+                        issuer_index,
+                        policy.keys.len()
+                    ),
+                )
+                .into());
+            }
+            let signature = obj_from_uint8array!($sig, signature, true);
+            let messages = encode_messages_as_js_array_to_fr_vec(&messages, encode_messages)?;
+            let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices)?;
+            let revealed_msgs = revealed_indices
+                .iter()
+                .map(|i| messages.get(*i).map(|m| (*i, *m)))
+                .collect::<Option<BTreeMap<_, _>>>()
+                .ok_or_else(|| {
+                    native::Error::new(
+                        ErrorCode::IndexOutOfBounds,
+                        "Revealed index is out of bounds",
+                    )
+                })?;
+            check_revealed_indices(&policy, &revealed_msgs)?;
+            let key = &policy.keys[issuer_index];
+            signature
+                .verify(&messages, key.public_key.clone(), key.params.clone())
+                .map_err(|e| {
+                    native::Error::from(e)
+                        .context("Signature isn't valid under the issuer's public key")
+                })?;
+
+            let mut rng = get_seeded_rng()?;
+            let msg_iter = messages.iter().enumerate().map(|(idx, message)| {
+                if revealed_indices.contains(&idx) {
+                    MessageOrBlinding::RevealMessage(message)
+                } else {
+                    MessageOrBlinding::BlindMessageRandomly(message)
+                }
+            });
+            let protocol = $protocol::init(&mut rng, &signature, &key.params, msg_iter)
+                .map_err(native::Error::from)?;
+
+            let mut challenges = vec![Fr::zero(); policy.keys.len()];
+            let mut proofs = Vec::with_capacity(policy.keys.len());
+            let mut bytes = vec![];
+            write_transcript_prefix(&policy.signature, &nonce, &mut bytes)?;
+            for (i, k) in policy.keys.iter().enumerate() {
+                if i == issuer_index {
+                    protocol
+                        .challenge_contribution(&revealed_msgs, &k.params, &mut bytes)
+                        .map_err(native::Error::from)?;
+                    proofs.push(None);
+                } else {
+                    challenges[i] = Fr::rand(&mut rng);
+                    let proof = $simulate_fn(
+                        &mut rng,
+                        &k.params,
+                        &k.public_key_g1,
+                        &revealed_msgs,
+                        &challenges[i],
+                        &BTreeMap::new(),
+                    )?;
+                    proof
+                        .challenge_contribution(&revealed_msgs, &k.params, &mut bytes)
+                        .map_err(native::Error::from)?;
+                    proofs.push(Some(proof));
+                }
+            }
+            let challenge = compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes);
+            challenges[issuer_index] = challenge
+                - challenges
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != issuer_index)
+                    .fold(Fr::zero(), |a, (_, c)| a + c);
+            proofs[issuer_index] = Some(
+                protocol
+                    .gen_proof(&challenges[issuer_index])
+                    .map_err(native::Error::from)?,
+            );
+            let proof = $proof {
+                challenges,
+                proofs: proofs.into_iter().map(|p| p.unwrap()).collect(),
+            };
+            Ok(obj_to_uint8array!(&proof, false, "IssuerHidingProof"))
+        }
+
+        #[doc = concat!("Verify a proof of knowledge of a ", $name, " signature from one of the issuers of the policy.")]
+        #[wasm_bindgen(js_name = $verify_proof_js)]
+        pub fn $verify_proof_fn(
+            proof: js_sys::Uint8Array,
+            policy: js_sys::Uint8Array,
+            verifier_public_key: js_sys::Uint8Array,
+            revealed_msgs: js_sys::Map,
+            encode_messages: bool,
+            nonce: Option<Vec<u8>>,
+        ) -> Result<JsValue, JsValue> {
+            set_panic_hook();
+            let proof = obj_from_uint8array!($proof, proof, false, "IssuerHidingProof");
+            let policy = obj_from_uint8array!($policy, policy, false, "IssuerPolicy");
+            let vk = g1_affine_from_uint8_array(verifier_public_key)?;
+            let msgs = encode_messages_as_js_map_to_fr_btreemap(&revealed_msgs, encode_messages)?;
+            let res = verify_policy(&policy, &vk).and_then(|_| {
+                check_revealed_indices(&policy, &msgs)?;
+                if proof.challenges.len() != policy.keys.len()
+                    || proof.proofs.len() != policy.keys.len()
+                {
+                    return Err(native::Error::new(
+                        ErrorCode::MessageCountMismatch,
+                        format!(
+                            "Expected challenges and proofs for {} issuers but found {} and {}",
+                            policy.keys.len(),
+                            proof.challenges.len(),
+                            proof.proofs.len()
+                        ),
+                    ));
+                }
+                let mut bytes = vec![];
+                write_transcript_prefix(&policy.signature, &nonce, &mut bytes)?;
+                for (i, (k, p)) in policy.keys.iter().zip(proof.proofs.iter()).enumerate() {
+                    p.challenge_contribution(&msgs, &k.params, &mut bytes)?;
+                    p.verify(
+                        &msgs,
+                        &proof.challenges[i],
+                        k.public_key.clone(),
+                        k.params.clone(),
+                    )
+                    .map_err(|e| {
+                        native::Error::from(e).context(format!("Proof for issuer {} failed", i))
+                    })?;
+                }
+                let challenge = compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes);
+                if proof.challenges.iter().fold(Fr::zero(), |a, c| a + c) != challenge {
+                    return Err(native::Error::new(
+                        ErrorCode::InvalidProof,
+                        "Challenges don't add up to the challenge of the proof",
+                    ));
+                }
+                Ok(())
+            });
+            Ok(to_verify_response(res))
+        }
+    };
+}
+
+impl_issuer_hiding!(
+    bbs_plus_sign_issuer_policy,
+    bbsPlusSignIssuerPolicy,
+    bbs_plus_verify_issuer_policy,
+    bbsPlusVerifyIssuerPolicy,
+    bbs_plus_gen_issuer_hiding_proof,
+    bbsPlusGenIssuerHidingProof,
+    bbs_plus_verify_issuer_hiding_proof,
+    bbsPlusVerifyIssuerHidingProof,
+    BBSPlusSigParamsG1,
+    BBSPlusSigG1,
+    BBSPlusPublicKeyG2,
+    BBSPlusIssuerPolicy,
+    BBSPlusIssuerHidingProof,
+    BBSPlusPoKOfSigProtocol,
+    simulate_bbs_plus_proof,
+    "BBS+"
+);
+
+impl_issuer_hiding!(
+    bbs_sign_issuer_policy,
+    bbsSignIssuerPolicy,
+    bbs_verify_issuer_policy,
+    bbsVerifyIssuerPolicy,
+    bbs_gen_issuer_hiding_proof,
+    bbsGenIssuerHidingProof,
+    bbs_verify_issuer_hiding_proof,
+    bbsVerifyIssuerHidingProof,
+    BBSSigParams,
+    BBSSignature,
+    BBSPublicKey,
+    BBSIssuerPolicy,
+    BBSIssuerHidingProof,
+    BBSPoKOfSigProtocol,
+    simulate_bbs_proof,
+    "BBS"
+);
+
+/// Simulate a proof of knowledge of BBS+ signature for the given challenge. The randomized signature is created
+/// as `A' = g1 * a` and `A_bar = pk_g1 * a` for a random `a` so that it passes the pairing check. `msg_responses`
+/// are the responses to use for some of the unrevealed messages, the others are random.
+#[allow(non_snake_case)]
+pub(crate) fn simulate_bbs_plus_proof<R: RngCore>(
+    rng: &mut R,
+    params: &BBSPlusSigParamsG1,
+    public_key_g1: &G1Affine,
+    revealed_msgs: &BTreeMap<usize, Fr>,
+    challenge: &Fr,
+    msg_responses: &BTreeMap<usize, Fr>,
+) -> native::Result<BBSPlusPoKOfSigProof> {
+    let a = Fr::rand(rng);
+    let A_prime = (params.g1 * a).into_affine();
+    let A_bar = (*public_key_g1 * a).into_affine();
+    let d = G1Affine::rand(rng);
+
+    let resp_1 = random_response(rng, 2);
+    let bases_1 = [A_prime, params.h_0];
+    let T1 = schnorr_t(
+        &bases_1,
+        &resp_1,
+        (A_bar.into_group() - d).into_affine(),
+        challenge,
+    );
+
+    let mut bases_2 = vec![d, params.h_0];
+    bases_2.extend(unrevealed_bases(&params.h, revealed_msgs));
+    let mut resp_2 = random_response(rng, 2);
+    resp_2.0.extend(unrevealed_responses(
+        rng,
+        &params.h,
+        revealed_msgs,
+        msg_responses,
+    )?);
+    let T2 = schnorr_t(
+        &bases_2,
+        &resp_2,
+        revealed_instance(params.g1, &params.h, revealed_msgs)?,
+        challenge,
+    );
+
+    Ok(BBSPlusPoKOfSigProof {
+        A_prime,
+        A_bar,
+        d,
+        T1,
+        sc_resp_1: resp_1,
+        T2,
+        sc_resp_2: resp_2,
+    })
+}
+
+/// Simulate a proof of knowledge of BBS signature for the given challenge. The randomized signature is created
+/// as `A_bar = g1 * a` and `B_bar = pk_g1 * a` for a random `a` so that it passes the pairing check.
+/// `msg_responses` are as for `simulate_bbs_plus_proof`.
+#[allow(non_snake_case)]
+pub(crate) fn simulate_bbs_proof<R: RngCore>(
+    rng: &mut R,
+    params: &BBSSigParams,
+    public_key_g1: &G1Affine,
+    revealed_msgs: &BTreeMap<usize, Fr>,
+    challenge: &Fr,
+    msg_responses: &BTreeMap<usize, Fr>,
+) -> native::Result<BBSPoKOfSigProof> {
+    let a = Fr::rand(rng);
+    let A_bar = (params.g1 * a).into_affine();
+    let B_bar = (*public_key_g1 * a).into_affine();
+    let d = G1Affine::rand(rng);
+
+    let response = Fr::rand(rng);
+    let t = (A_bar * response - (B_bar.into_group() - d) * challenge).into_affine();
+
+    let mut bases_2 = vec![d];
+    bases_2.extend(unrevealed_bases(&params.h, revealed_msgs));
+    let mut resp_2 = random_response(rng, 1);
+    resp_2.0.extend(unrevealed_responses(
+        rng,
+        &params.h,
+        revealed_msgs,
+        msg_responses,
+    )?);
+    let T2 = schnorr_t(
+        &bases_2,
+        &resp_2,
+        revealed_instance(params.g1, &params.h, revealed_msgs)?,
+        challenge,
+    );
+
+    Ok(BBSPoKOfSigProof {
+        A_bar,
+        B_bar,
+        d,
+        sc_proof_1: KnowledgeOfEProof { t, response },
+        T2,
+        sc_resp_2: resp_2,
+    })
+}
+
+fn random_response<R: RngCore>(rng: &mut R, count: usize) -> SchnorrResponse<G1Affine> {
+    SchnorrResponse((0..count).map(|_| Fr::rand(rng)).collect())
+}
+
+/// Responses for the unrevealed messages in the order of their bases, taken from `msg_responses` if present there
+fn unrevealed_responses<R: RngCore>(
+    rng: &mut R,
+    h: &[G1Affine],
+    revealed_msgs: &BTreeMap<usize, Fr>,
+    msg_responses: &BTreeMap<usize, Fr>,
+) -> native::Result<Vec<Fr>> {
+    if let Some(i) = msg_responses
+        .keys()
+        .find(|i| **i >= h.len() || revealed_msgs.contains_key(i))
+    {
+        return Err(native::Error::new(
+            ErrorCode::IndexOutOfBounds,
+            format!("Message {} isn't an unrevealed message of the signature", i),
+        ));
+    }
+    Ok((0..h.len())
+        .filter(|i| !revealed_msgs.contains_key(i))
+        .map(|i| match msg_responses.get(&i) {
+            Some(r) => *r,
+            None => Fr::rand(rng),
+        })
+        .collect())
+}
+
+/// Commitment `t` that makes `response` valid for instance `y` and `challenge`, i.e. `t = bases * response - y * challenge`
+fn schnorr_t(
+    bases: &[G1Affine],
+    response: &SchnorrResponse<G1Affine>,
+    y: G1Affine,
+    challenge: &Fr,
+) -> G1Affine {
+    (<G1Affine as AffineRepr>::Group::msm_unchecked(bases, &response.0) - y * challenge)
+        .into_affine()
+}
+
+fn unrevealed_bases<'a>(
+    h: &'a [G1Affine],
+    revealed_msgs: &'a BTreeMap<usize, Fr>,
+) -> impl Iterator<Item = G1Affine> + 'a {
+    h.iter()
+        .enumerate()
+        .filter(|(i, _)| !revealed_msgs.contains_key(i))
+        .map(|(_, h)| *h)
+}
+
+/// Returns `-(g1 + \sum_{i in D}(h_i*{m_i}))` for revealed messages `m_i`
+fn revealed_instance(
+    g1: G1Affine,
+    h: &[G1Affine],
+    revealed_msgs: &BTreeMap<usize, Fr>,
+) -> native::Result<G1Affine> {
+    let mut instance = g1.into_group();
+    for (i, m) in revealed_msgs {
+        let h_i = h.get(*i).ok_or_else(|| {
+            native::Error::invalid_argument(format!(
+                "Revealed message {} doesn't exist as the signature params are for {} messages",
+                i,
+                h.len()
+            ))
+        })?;
+        instance += *h_i * m;
+    }
+    Ok((-instance).into_affine())
+}
+
+/// Signature params of an issuer in a policy
+trait MessageBases {
+    fn message_bases(&self) -> &[G1Affine];
+}
+
+impl MessageBases for BBSPlusSigParamsG1 {
+    fn message_bases(&self) -> &[G1Affine] {
+        &self.h
+    }
+}
+
+impl MessageBases for BBSSigParams {
+    fn message_bases(&self) -> &[G1Affine] {
+        &self.h
+    }
+}
+
+/// The revealed messages must exist for every issuer of the policy as the proofs for all are created or verified,
+/// not just for the issuer of the signature
+fn check_revealed_indices<P: CanonicalSerialize + CanonicalDeserialize + MessageBases>(
+    policy: &IssuerPolicy<P>,
+    revealed_msgs: &BTreeMap<usize, Fr>,
+) -> native::Result<()> {
+    for (i, k) in policy.keys.iter().enumerate() {
+        let count = k.params.message_bases().len();
+        if let Some(j) = revealed_msgs.keys().find(|j| **j >= count) {
+            return Err(native::Error::invalid_argument(format!(
+                "Revealed message {} doesn't exist for issuer {} which signs {} messages",
+                j, i, count
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn verifier_key_base() -> G1Affine {
+    affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(VERIFIER_KEY_BASE_LABEL)
+}
+
+/// Schnorr signature on the serialized issuer keys
+fn sign_policy<P: CanonicalSerialize + CanonicalDeserialize>(
+    keys: &[IssuerKey<P>],
+    secret_key: &Fr,
+) -> native::Result<PolicySignature<G1Affine>> {
+    let base = verifier_key_base();
+    let vk = (base * secret_key).into_affine();
+    let mut rng = get_seeded_rng()?;
+    let protocol = PolicySignatureProtocol::init(*secret_key, Fr::rand(&mut rng), &base);
+    let mut bytes = vec![];
+    protocol.challenge_contribution(&base, &vk, &mut bytes)?;
+    keys.serialize_compressed(&mut bytes)?;
+    let challenge = compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes);
+    Ok(protocol.gen_proof(&challenge))
+}
+
+fn verify_policy<P: CanonicalSerialize + CanonicalDeserialize>(
+    policy: &IssuerPolicy<P>,
+    verifier_public_key: &G1Affine,
+) -> native::Result<()> {
+    let base = verifier_key_base();
+    let mut bytes = vec![];
+    policy
+        .signature
+        .challenge_contribution(&base, verifier_public_key, &mut bytes)?;
+    policy.keys.serialize_compressed(&mut bytes)?;
+    let challenge = compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes);
+    if policy
+        .signature
+        .verify(verifier_public_key, &base, &challenge)
+    {
+        Ok(())
+    } else {
+        Err(native::Error::new(
+            ErrorCode::InvalidSignature,
+            "Issuer policy isn't signed by the verifier",
+        ))
+    }
+}
+
+/// The proof is bound to the policy by hashing the policy's signature
+fn write_transcript_prefix<W: Write>(
+    policy_signature: &PolicySignature<G1Affine>,
+    nonce: &Option<Vec<u8>>,
+    mut writer: W,
+) -> native::Result<()> {
+    if let Some(n) = nonce {
+        writer.write_all(n).map_err(SerializationError::from)?;
+    }
+    Ok(policy_signature.serialize_compressed(writer)?)
+}
+
+fn to_verify_response(res: native::Result<()>) -> JsValue {
+    serde_wasm_bindgen::to_value(&VerifyResponse::from(res)).unwrap()
+}
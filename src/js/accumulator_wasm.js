@@ -30,6 +30,16 @@ module.exports.isAccumulatorPublicKeyValid = (publicKey) => {
     return wasm.isAccumulatorPublicKeyValid(publicKey)
 };
 
+module.exports.generateAccumulatorProofOfPossession = (secretKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.generateAccumulatorProofOfPossession(secretKey, params, context);
+};
+
+module.exports.verifyAccumulatorProofOfPossession = (proof, publicKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.verifyAccumulatorProofOfPossession(proof, publicKey, params, context);
+};
+
 module.exports.generateAccumulatorKeyPair = (params, seed) => {
     requireWasmInitialized();
     return wasm.generateAccumulatorKeyPair(params, seed)
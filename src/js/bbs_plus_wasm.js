@@ -86,6 +86,16 @@ module.exports.bbsPlusIsPublicKeyG1Valid = (publicKey) => {
     return wasm.bbsPlusIsPublicKeyG1Valid(publicKey);
 };
 
+module.exports.bbsPlusGenerateProofOfPossessionG1 = (secretKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusGenerateProofOfPossessionG1(secretKey, params, context);
+};
+
+module.exports.bbsPlusVerifyProofOfPossessionG1 = (proof, publicKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusVerifyProofOfPossessionG1(proof, publicKey, params, context);
+};
+
 module.exports.bbsPlusGeneratePublicKeyG2 = (secretKey, params) => {
     requireWasmInitialized();
     return wasm.bbsPlusGeneratePublicKeyG2(secretKey, params);
@@ -96,6 +106,16 @@ module.exports.bbsPlusIsPublicKeyG2Valid = (publicKey) => {
     return wasm.bbsPlusIsPublicKeyG2Valid(publicKey);
 };
 
+module.exports.bbsPlusGenerateProofOfPossessionG2 = (secretKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusGenerateProofOfPossessionG2(secretKey, params, context);
+};
+
+module.exports.bbsPlusVerifyProofOfPossessionG2 = (proof, publicKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusVerifyProofOfPossessionG2(proof, publicKey, params, context);
+};
+
 module.exports.bbsPlusGenerateKeyPairG1 = (params, seed) => {
     requireWasmInitialized();
     return wasm.bbsPlusGenerateKeyPairG1(params, seed);
@@ -126,6 +146,16 @@ module.exports.bbsPlusSignG1 = (
     return wasm.bbsPlusSignG1(messages, secretKey, params, encodeMessages);
 };
 
+module.exports.bbsPlusSignG1WithHandles = (
+    messages,
+    secretKey,
+    params,
+    encodeMessages
+) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusSignG1WithHandles(messages, secretKey, params, encodeMessages);
+};
+
 module.exports.bbsPlusSignG2 = (
     messages,
     secretKey,
@@ -147,6 +177,17 @@ module.exports.bbsPlusVerifyG1 = (
     return wasm.bbsPlusVerifyG1(messages, signature, publicKey, params, encodeMessages);
 };
 
+module.exports.bbsPlusVerifyG1WithHandles = (
+    messages,
+    signature,
+    publicKey,
+    params,
+    encodeMessages
+) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusVerifyG1WithHandles(messages, signature, publicKey, params, encodeMessages);
+};
+
 module.exports.bbsPlusVerifyG2 = (
     messages,
     signature,
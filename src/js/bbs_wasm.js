@@ -55,6 +55,16 @@ module.exports.bbsIsPublicKeyValid = (publicKey) => {
     return wasm.bbsIsPublicKeyValid(publicKey);
 };
 
+module.exports.bbsGenerateProofOfPossession = (secretKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.bbsGenerateProofOfPossession(secretKey, params, context);
+};
+
+module.exports.bbsVerifyProofOfPossession = (proof, publicKey, params, context) => {
+    requireWasmInitialized();
+    return wasm.bbsVerifyProofOfPossession(proof, publicKey, params, context);
+};
+
 module.exports.bbsGenerateKeyPair = (params, seed) => {
     requireWasmInitialized();
     return wasm.bbsGenerateKeyPair(params, seed);
@@ -70,6 +80,16 @@ module.exports.bbsSign = (
     return wasm.bbsSign(messages, secretKey, params, encodeMessages);
 };
 
+module.exports.bbsSignWithHandles = (
+    messages,
+    secretKey,
+    params,
+    encodeMessages
+) => {
+    requireWasmInitialized();
+    return wasm.bbsSignWithHandles(messages, secretKey, params, encodeMessages);
+};
+
 module.exports.bbsVerify = (
     messages,
     signature,
@@ -81,6 +101,17 @@ module.exports.bbsVerify = (
     return wasm.bbsVerify(messages, signature, publicKey, params, encodeMessages);
 };
 
+module.exports.bbsVerifyWithHandles = (
+    messages,
+    signature,
+    publicKey,
+    params,
+    encodeMessages
+) => {
+    requireWasmInitialized();
+    return wasm.bbsVerifyWithHandles(messages, signature, publicKey, params, encodeMessages);
+};
+
 module.exports.bbsCommitMsgs = (
     messages,
     params,
@@ -0,0 +1,48 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.boundCheckSnarkSetupFromPowersOfTau = (ptau, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.boundCheckSnarkSetupFromPowersOfTau(ptau, uncompressed);
+};
+
+module.exports.r1csSnarkSetupFromPowersOfTau = (r1cs, commitWitnessCount, ptau, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.r1csSnarkSetupFromPowersOfTau(r1cs, commitWitnessCount, ptau, uncompressed);
+};
+
+module.exports.saverSnarkSetupFromPowersOfTau = (chunkBitSize, ptau, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.saverSnarkSetupFromPowersOfTau(chunkBitSize, ptau, uncompressed);
+};
+
+module.exports.legoCeremonyContribute = (snarkPk, transcript, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.legoCeremonyContribute(snarkPk, transcript, uncompressed);
+};
+
+module.exports.legoCeremonyVerifyContribution = (previousSnarkPk, newSnarkPk, transcript, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.legoCeremonyVerifyContribution(previousSnarkPk, newSnarkPk, transcript, uncompressed);
+};
+
+module.exports.legoCeremonyFinalize = (initialSnarkPk, finalSnarkPk, transcript, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.legoCeremonyFinalize(initialSnarkPk, finalSnarkPk, transcript, uncompressed);
+};
+
+module.exports.saverCeremonyContribute = (snarkPk, transcript, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.saverCeremonyContribute(snarkPk, transcript, uncompressed);
+};
+
+module.exports.saverCeremonyVerifyContribution = (previousSnarkPk, newSnarkPk, transcript, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.saverCeremonyVerifyContribution(previousSnarkPk, newSnarkPk, transcript, uncompressed);
+};
+
+module.exports.saverCeremonyFinalize = (initialSnarkPk, finalSnarkPk, transcript, encGens, chunkBitSize, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.saverCeremonyFinalize(initialSnarkPk, finalSnarkPk, transcript, encGens, chunkBitSize, uncompressed);
+};
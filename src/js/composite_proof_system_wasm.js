@@ -1,6 +1,7 @@
 const {
     wasm, requireWasmInitialized
 } = require('./init_wasm');
+const {r1csGenerateWires} = require('./r1cs_wasm');
 
 module.exports.generatePoKBBSSignatureStatement = (params, publicKey, revealedMessages, encodeMessages) => {
     requireWasmInitialized();
@@ -62,6 +63,16 @@ module.exports.generatePedersenCommitmentG1StatementFromParamRefs = (bases, comm
     return wasm.generatePedersenCommitmentG1StatementFromParamRefs(bases, commitment);
 };
 
+module.exports.generateDkgithStatement = (params, commitment) => {
+    requireWasmInitialized();
+    return wasm.generateDkgithStatement(params, commitment);
+};
+
+module.exports.generateDkgithStatementFromParamRefs = (commitmentKey, commitment) => {
+    requireWasmInitialized();
+    return wasm.generateDkgithStatementFromParamRefs(commitmentKey, commitment);
+};
+
 module.exports.generatePedersenCommitmentG2Statement = (bases, commitment) => {
     requireWasmInitialized();
     return wasm.generatePedersenCommitmentG2Statement(bases, commitment);
@@ -72,6 +83,16 @@ module.exports.generatePedersenCommitmentG2StatementFromParamRefs = (bases, comm
     return wasm.generatePedersenCommitmentG2StatementFromParamRefs(bases, commitment);
 };
 
+module.exports.generateSaverMultiRecipientStatement = (commGens, commitment, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.generateSaverMultiRecipientStatement(commGens, commitment, uncompressedPublicParams);
+};
+
+module.exports.generateSaverMultiRecipientStatementFromParamRefs = (commitmentKey, commitment) => {
+    requireWasmInitialized();
+    return wasm.generateSaverMultiRecipientStatementFromParamRefs(commitmentKey, commitment);
+};
+
 module.exports.generateSaverProverStatement = (chunkBitSize, encGens, commGens, encryptionKey, snarkPk, uncompressedPublicParams) => {
     requireWasmInitialized();
     return wasm.generateSaverProverStatement(chunkBitSize, encGens, commGens, encryptionKey, snarkPk, uncompressedPublicParams);
@@ -137,6 +158,31 @@ module.exports.generateWitnessEqualityMetaStatement = (equalities) => {
     return wasm.generateWitnessEqualityMetaStatement(equalities);
 };
 
+module.exports.generateLinearRelationStatement = (coefficients, constant) => {
+    requireWasmInitialized();
+    return wasm.generateLinearRelationStatement(coefficients, constant);
+};
+
+module.exports.generateLinearRelationMetaStatements = (statementIndex, statement, witnessRefs) => {
+    requireWasmInitialized();
+    return wasm.generateLinearRelationMetaStatements(statementIndex, statement, witnessRefs);
+};
+
+module.exports.generateBBSPlusBlindSignatureRequestStatement = (params, committedIndices, commitment) => {
+    requireWasmInitialized();
+    return wasm.generateBBSPlusBlindSignatureRequestStatement(params, committedIndices, commitment);
+};
+
+module.exports.generateBBSBlindSignatureRequestStatement = (params, committedIndices, commitment) => {
+    requireWasmInitialized();
+    return wasm.generateBBSBlindSignatureRequestStatement(params, committedIndices, commitment);
+};
+
+module.exports.generatePSBlindSignatureRequestStatements = (params, h, commitments) => {
+    requireWasmInitialized();
+    return wasm.generatePSBlindSignatureRequestStatements(params, h, commitments);
+};
+
 module.exports.generatePoKBBSSignatureWitness = (signature, unrevealedMessages, encodeMessages) => {
     requireWasmInitialized();
     return wasm.generatePoKBBSSignatureWitness(signature, unrevealedMessages, encodeMessages);
@@ -167,6 +213,36 @@ module.exports.generatePedersenCommitmentWitness = (elements) => {
     return wasm.generatePedersenCommitmentWitness(elements);
 };
 
+module.exports.generateLinearRelationWitness = (witnesses, witnessRefs) => {
+    requireWasmInitialized();
+    return wasm.generateLinearRelationWitness(witnesses, witnessRefs);
+};
+
+module.exports.generateBBSPlusBlindSignatureRequestWitness = (messagesToCommit, blinding, encodeMessages) => {
+    requireWasmInitialized();
+    return wasm.generateBBSPlusBlindSignatureRequestWitness(messagesToCommit, blinding, encodeMessages);
+};
+
+module.exports.generateBBSBlindSignatureRequestWitness = (messagesToCommit, encodeMessages) => {
+    requireWasmInitialized();
+    return wasm.generateBBSBlindSignatureRequestWitness(messagesToCommit, encodeMessages);
+};
+
+module.exports.generatePSBlindSignatureRequestWitnesses = (messagesToCommit, blindings) => {
+    requireWasmInitialized();
+    return wasm.generatePSBlindSignatureRequestWitnesses(messagesToCommit, blindings);
+};
+
+module.exports.generateDkgithWitness = (message, blinding) => {
+    requireWasmInitialized();
+    return wasm.generateDkgithWitness(message, blinding);
+};
+
+module.exports.generateSaverMultiRecipientWitness = (message, blinding) => {
+    requireWasmInitialized();
+    return wasm.generateSaverMultiRecipientWitness(message, blinding);
+};
+
 module.exports.generateSaverWitness = (message) => {
     requireWasmInitialized();
     return wasm.generateSaverWitness(message);
@@ -177,14 +253,18 @@ module.exports.generateBoundCheckWitness = (message) => {
     return wasm.generateBoundCheckWitness(message);
 };
 
-module.exports.generateR1CSCircomWitness = (inputWires, privates, publics = []) => {
+module.exports.generateR1CSCircomWitness = (inputWires, privates, publics = [], wasmBytesOrCalculator) => {
     requireWasmInitialized();
+    if (wasmBytesOrCalculator !== undefined) {
+        // Inputs which don't match the circuit's signals fail here rather than when creating the proof
+        r1csGenerateWires(wasmBytesOrCalculator, inputWires);
+    }
     return wasm.generateR1CSCircomWitness(inputWires, privates, publics);
 };
 
-module.exports.generateProofSpecG1 = (statements, metaStatements, setupParams, context) => {
+module.exports.generateProofSpecG1 = (statements, metaStatements, setupParams, context, orMetaStatements, orWitnessEqualities) => {
     requireWasmInitialized();
-    return wasm.generateProofSpecG1(statements, metaStatements, setupParams, context);
+    return wasm.generateProofSpecG1(statements, metaStatements, setupParams, context, orMetaStatements, orWitnessEqualities);
 };
 
 module.exports.isProofSpecG1Valid = (proofSpec) => {
@@ -212,11 +292,31 @@ module.exports.generateCompositeProofG2 = (proofSpec, witnesses, nonce) => {
     return wasm.generateCompositeProofG2(proofSpec, witnesses, nonce);
 };
 
+module.exports.generateCompositeProofG1WithHandle = (proofSpec, witnesses, nonce) => {
+    requireWasmInitialized();
+    return wasm.generateCompositeProofG1WithHandle(proofSpec, witnesses, nonce);
+};
+
+module.exports.verifyCompositeProofG1WithHandle = (proof, proofSpec, nonce) => {
+    requireWasmInitialized();
+    return wasm.verifyCompositeProofG1WithHandle(proof, proofSpec, nonce);
+};
+
 module.exports.verifyCompositeProofG1 = (proof, proofSpec, nonce) => {
     requireWasmInitialized();
     return wasm.verifyCompositeProofG1(proof, proofSpec, nonce);
 };
 
+module.exports.verifyCompositeProofG1WithDkgith = (proof, proofSpec, nonce, dkgithProofs) => {
+    requireWasmInitialized();
+    return wasm.verifyCompositeProofG1WithDkgith(proof, proofSpec, nonce, dkgithProofs);
+};
+
+module.exports.verifyCompositeProofG1WithSaverMultiRecipient = (proof, proofSpec, nonce, encryptions, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.verifyCompositeProofG1WithSaverMultiRecipient(proof, proofSpec, nonce, encryptions, uncompressedPublicParams);
+};
+
 module.exports.verifyCompositeProofG2 = (proof, proofSpec, nonce) => {
     requireWasmInitialized();
     return wasm.verifyCompositeProofG2(proof, proofSpec, nonce);
@@ -241,3 +341,83 @@ module.exports.saverGetCiphertextsFromProof = (proof, statementIndices) => {
     requireWasmInitialized();
     return wasm.saverGetCiphertextsFromProof(proof, statementIndices);
 };
+
+module.exports.describeProofSpec = (proofSpec) => {
+    requireWasmInitialized();
+    return wasm.describeProofSpec(proofSpec);
+};
+
+module.exports.proofSpecSatisfiedBy = (proofSpec, templateSpec) => {
+    requireWasmInitialized();
+    return wasm.proofSpecSatisfiedBy(proofSpec, templateSpec);
+};
+
+module.exports.extractFromCompositeProofG1 = (proof, proofSpec) => {
+    requireWasmInitialized();
+    return wasm.extractFromCompositeProofG1(proof, proofSpec);
+};
+
+module.exports.extractFromCompositeProofG2 = (proof, proofSpec) => {
+    requireWasmInitialized();
+    return wasm.extractFromCompositeProofG2(proof, proofSpec);
+};
+
+module.exports.generateOrMetaStatement = (proofSpecs, publicKeysG1) => {
+    requireWasmInitialized();
+    return wasm.generateOrMetaStatement(proofSpecs, publicKeysG1);
+};
+
+module.exports.generateOrProofG1 = (orMetaStatement, satisfiedIndex, witnesses, nonce) => {
+    requireWasmInitialized();
+    return wasm.generateOrProofG1(orMetaStatement, satisfiedIndex, witnesses, nonce);
+};
+
+module.exports.verifyOrProofG1 = (proof, orMetaStatement, nonce) => {
+    requireWasmInitialized();
+    return wasm.verifyOrProofG1(proof, orMetaStatement, nonce);
+};
+
+module.exports.generateOrWitnessEquality = (witnessRef, orIndex, branchWitnessRefs) => {
+    requireWasmInitialized();
+    return wasm.generateOrWitnessEquality(witnessRef, orIndex, branchWitnessRefs);
+};
+
+module.exports.generateCompositeProofG1WithOrMetaStatements = (proofSpec, witnesses, satisfiedIndices, orWitnesses, nonce) => {
+    requireWasmInitialized();
+    return wasm.generateCompositeProofG1WithOrMetaStatements(proofSpec, witnesses, satisfiedIndices, orWitnesses, nonce);
+};
+
+module.exports.verifyCompositeProofG1WithOrMetaStatements = (proof, proofSpec, nonce) => {
+    requireWasmInitialized();
+    return wasm.verifyCompositeProofG1WithOrMetaStatements(proof, proofSpec, nonce);
+};
+
+module.exports.compositeProofInit = (proofSpec, witnesses) => {
+    requireWasmInitialized();
+    return wasm.compositeProofInit(proofSpec, witnesses);
+};
+
+module.exports.interactiveProverStateFromBytes = (bytes) => {
+    requireWasmInitialized();
+    return wasm.InteractiveProverState.fromBytes(bytes);
+};
+
+module.exports.compositeProofCommitments = (state) => {
+    requireWasmInitialized();
+    return wasm.compositeProofCommitments(state);
+};
+
+module.exports.compositeProofRespond = (state, challenge) => {
+    requireWasmInitialized();
+    return wasm.compositeProofRespond(state, challenge);
+};
+
+module.exports.compositeProofChallenge = () => {
+    requireWasmInitialized();
+    return wasm.compositeProofChallenge();
+};
+
+module.exports.compositeProofVerify = (proof, proofSpec, commitments, challenge) => {
+    requireWasmInitialized();
+    return wasm.compositeProofVerify(proof, proofSpec, commitments, challenge);
+};
@@ -0,0 +1,38 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.dkgithGenerateParams = (label) => {
+    requireWasmInitialized();
+    return wasm.dkgithGenerateParams(label);
+};
+
+module.exports.dkgithGenerateSecretKey = (seed) => {
+    requireWasmInitialized();
+    return wasm.dkgithGenerateSecretKey(seed);
+};
+
+module.exports.dkgithGeneratePublicKey = (secretKey, params) => {
+    requireWasmInitialized();
+    return wasm.dkgithGeneratePublicKey(secretKey, params);
+};
+
+module.exports.dkgithEncrypt = (message, params, publicKey, nonce) => {
+    requireWasmInitialized();
+    return wasm.dkgithEncrypt(message, params, publicKey, nonce);
+};
+
+module.exports.dkgithVerifyEncryption = (proof, commitment, params, publicKey, nonce) => {
+    requireWasmInitialized();
+    return wasm.dkgithVerifyEncryption(proof, commitment, params, publicKey, nonce);
+};
+
+module.exports.dkgithDecrypt = (proof, commitment, secretKey, params) => {
+    requireWasmInitialized();
+    return wasm.dkgithDecrypt(proof, commitment, secretKey, params);
+};
+
+module.exports.dkgithVerifyDecryption = (commitment, message, blinding, params) => {
+    requireWasmInitialized();
+    return wasm.dkgithVerifyDecryption(commitment, message, blinding, params);
+};
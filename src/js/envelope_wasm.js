@@ -0,0 +1,28 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.setEnvelopeOutput = (enabled) => {
+    requireWasmInitialized();
+    return wasm.setEnvelopeOutput(enabled);
+};
+
+module.exports.setEnvelopeInput = (enabled) => {
+    requireWasmInitialized();
+    return wasm.setEnvelopeInput(enabled);
+};
+
+module.exports.envelopeWrap = (bytes, typeName, compressed) => {
+    requireWasmInitialized();
+    return wasm.envelopeWrap(bytes, typeName, compressed);
+};
+
+module.exports.envelopeUnwrap = (bytes, expectedType) => {
+    requireWasmInitialized();
+    return wasm.envelopeUnwrap(bytes, expectedType);
+};
+
+module.exports.envelopeInspect = (bytes) => {
+    requireWasmInitialized();
+    return wasm.envelopeInspect(bytes);
+};
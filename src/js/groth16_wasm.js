@@ -0,0 +1,63 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.groth16ProvingKeyFromZkey = (zkey, returnUncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16ProvingKeyFromZkey(zkey, returnUncompressed);
+};
+
+module.exports.groth16ProvingKeyToZkey = (snarkPk, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16ProvingKeyToZkey(snarkPk, uncompressed);
+};
+
+module.exports.groth16SnarkSetup = (curveName, numPublic, numPrivate, constraints, returnUncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16SnarkSetup(curveName, numPublic, numPrivate, constraints, returnUncompressed);
+};
+
+module.exports.groth16VkFromPk = (snarkPk, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16VkFromPk(snarkPk, uncompressed);
+};
+
+module.exports.groth16Prove = (snarkPk, wires, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16Prove(snarkPk, wires, uncompressed);
+};
+
+module.exports.groth16Verify = (proof, snarkVk, publicSignals, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16Verify(proof, snarkVk, publicSignals, uncompressed);
+};
+
+module.exports.groth16VkToSnarkjs = (snarkVk, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16VkToSnarkjs(snarkVk, uncompressed);
+};
+
+module.exports.groth16VkFromSnarkjs = (snarkVk, returnUncompressed) => {
+    requireWasmInitialized();
+    return wasm.groth16VkFromSnarkjs(snarkVk, returnUncompressed);
+};
+
+module.exports.groth16ProofToSnarkjs = (proof) => {
+    requireWasmInitialized();
+    return wasm.groth16ProofToSnarkjs(proof);
+};
+
+module.exports.groth16ProofFromSnarkjs = (proof) => {
+    requireWasmInitialized();
+    return wasm.groth16ProofFromSnarkjs(proof);
+};
+
+module.exports.groth16PublicSignalsToSnarkjs = (publicSignals) => {
+    requireWasmInitialized();
+    return wasm.groth16PublicSignalsToSnarkjs(publicSignals);
+};
+
+module.exports.groth16PublicSignalsFromSnarkjs = (publicSignals) => {
+    requireWasmInitialized();
+    return wasm.groth16PublicSignalsFromSnarkjs(publicSignals);
+};
@@ -0,0 +1,106 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+// Handles keep deserialized objects in wasm memory and must be freed by calling `free` on them
+
+module.exports.bbsSigParamsHandle = (params) => {
+    requireWasmInitialized();
+    return new wasm.BbsSigParamsHandle(params);
+};
+
+module.exports.bbsPlusSigParamsG1Handle = (params) => {
+    requireWasmInitialized();
+    return new wasm.BbsPlusSigParamsG1Handle(params);
+};
+
+module.exports.bbsPublicKeyHandle = (publicKey) => {
+    requireWasmInitialized();
+    return new wasm.BbsPublicKeyHandle(publicKey);
+};
+
+module.exports.bbsPlusPublicKeyG2Handle = (publicKey) => {
+    requireWasmInitialized();
+    return new wasm.BbsPlusPublicKeyG2Handle(publicKey);
+};
+
+module.exports.legoProvingKeyHandle = (key, uncompressed) => {
+    requireWasmInitialized();
+    return new wasm.LegoProvingKeyHandle(key, uncompressed);
+};
+
+module.exports.legoVerifyingKeyHandle = (key, uncompressed) => {
+    requireWasmInitialized();
+    return new wasm.LegoVerifyingKeyHandle(key, uncompressed);
+};
+
+module.exports.saverProvingKeyHandle = (key, uncompressed) => {
+    requireWasmInitialized();
+    return new wasm.SaverProvingKeyHandle(key, uncompressed);
+};
+
+module.exports.saverVerifyingKeyHandle = (key, uncompressed) => {
+    requireWasmInitialized();
+    return new wasm.SaverVerifyingKeyHandle(key, uncompressed);
+};
+
+// Proving keys can also be created from their bytes split in parts, like the chunks of a `ReadableStream`, so that
+// the whole key is never copied to wasm memory
+
+module.exports.legoProvingKeyHandleFromParts = (parts, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.LegoProvingKeyHandle.fromParts(parts, uncompressed);
+};
+
+module.exports.saverProvingKeyHandleFromParts = (parts, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.SaverProvingKeyHandle.fromParts(parts, uncompressed);
+};
+
+// Streams are read one chunk at a time and each chunk is parsed before the next is read. If `provingOnly` is true, the
+// parts of the verification key that proving doesn't need are skipped and the handle can only be used for proving.
+
+module.exports.legoProvingKeyHandleFromStream = async (stream, uncompressed, provingOnly = false) => {
+    requireWasmInitialized();
+    return loadFromStream(new wasm.LegoProvingKeyLoader(uncompressed, provingOnly), stream);
+};
+
+module.exports.saverProvingKeyHandleFromStream = async (stream, uncompressed, provingOnly = false) => {
+    requireWasmInitialized();
+    return loadFromStream(new wasm.SaverProvingKeyLoader(uncompressed, provingOnly), stream);
+};
+
+module.exports.proofSpecG1Handle = (statements, metaStatements, setupParams, context) => {
+    requireWasmInitialized();
+    return new wasm.ProofSpecG1Handle(statements, metaStatements, setupParams, context);
+};
+
+module.exports.proofSpecG1HandleFromBytes = (bytes) => {
+    requireWasmInitialized();
+    return wasm.ProofSpecG1Handle.fromBytes(bytes);
+};
+
+async function loadFromStream(loader, stream) {
+    const reader = stream.getReader();
+    try {
+        for (;;) {
+            const {done, value} = await reader.read();
+            if (done) {
+                break;
+            }
+            loader.push(value);
+        }
+    } catch (e) {
+        loader.free();
+        await reader.cancel();
+        throw e;
+    } finally {
+        reader.releaseLock();
+    }
+    return loader.finish();
+}
+
+module.exports.witnessCalculatorHandle = (wasmBytes) => {
+    requireWasmInitialized();
+    return new wasm.WitnessCalculatorHandle(wasmBytes);
+};
@@ -0,0 +1,63 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.generateIssuerHidingVerifierSecretKey = (seed) => {
+    requireWasmInitialized();
+    return wasm.generateIssuerHidingVerifierSecretKey(seed);
+};
+
+module.exports.generateIssuerHidingVerifierPublicKey = (secretKey) => {
+    requireWasmInitialized();
+    return wasm.generateIssuerHidingVerifierPublicKey(secretKey);
+};
+
+module.exports.bbsPlusGeneratePublicKeyG1ForIssuerHiding = (secretKey, params) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusGeneratePublicKeyG1ForIssuerHiding(secretKey, params);
+};
+
+module.exports.bbsGeneratePublicKeyG1ForIssuerHiding = (secretKey, params) => {
+    requireWasmInitialized();
+    return wasm.bbsGeneratePublicKeyG1ForIssuerHiding(secretKey, params);
+};
+
+module.exports.bbsPlusSignIssuerPolicy = (params, publicKeys, publicKeysG1, verifierSecretKey) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusSignIssuerPolicy(params, publicKeys, publicKeysG1, verifierSecretKey);
+};
+
+module.exports.bbsPlusVerifyIssuerPolicy = (policy, verifierPublicKey) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusVerifyIssuerPolicy(policy, verifierPublicKey);
+};
+
+module.exports.bbsPlusGenIssuerHidingProof = (policy, verifierPublicKey, issuerIndex, signature, messages, revealedIndices, encodeMessages, nonce) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusGenIssuerHidingProof(policy, verifierPublicKey, issuerIndex, signature, messages, revealedIndices, encodeMessages, nonce);
+};
+
+module.exports.bbsPlusVerifyIssuerHidingProof = (proof, policy, verifierPublicKey, revealedMessages, encodeMessages, nonce) => {
+    requireWasmInitialized();
+    return wasm.bbsPlusVerifyIssuerHidingProof(proof, policy, verifierPublicKey, revealedMessages, encodeMessages, nonce);
+};
+
+module.exports.bbsSignIssuerPolicy = (params, publicKeys, publicKeysG1, verifierSecretKey) => {
+    requireWasmInitialized();
+    return wasm.bbsSignIssuerPolicy(params, publicKeys, publicKeysG1, verifierSecretKey);
+};
+
+module.exports.bbsVerifyIssuerPolicy = (policy, verifierPublicKey) => {
+    requireWasmInitialized();
+    return wasm.bbsVerifyIssuerPolicy(policy, verifierPublicKey);
+};
+
+module.exports.bbsGenIssuerHidingProof = (policy, verifierPublicKey, issuerIndex, signature, messages, revealedIndices, encodeMessages, nonce) => {
+    requireWasmInitialized();
+    return wasm.bbsGenIssuerHidingProof(policy, verifierPublicKey, issuerIndex, signature, messages, revealedIndices, encodeMessages, nonce);
+};
+
+module.exports.bbsVerifyIssuerHidingProof = (proof, policy, verifierPublicKey, revealedMessages, encodeMessages, nonce) => {
+    requireWasmInitialized();
+    return wasm.bbsVerifyIssuerHidingProof(proof, policy, verifierPublicKey, revealedMessages, encodeMessages, nonce);
+};
@@ -0,0 +1,43 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.publicKeyToJwk = (publicKey, keyType) => {
+    requireWasmInitialized();
+    return wasm.publicKeyToJwk(publicKey, keyType);
+};
+
+module.exports.publicKeyFromJwk = (jwk, keyType) => {
+    requireWasmInitialized();
+    return wasm.publicKeyFromJwk(jwk, keyType);
+};
+
+module.exports.publicKeyToMultikey = (publicKey, keyType, id, controller) => {
+    requireWasmInitialized();
+    return wasm.publicKeyToMultikey(publicKey, keyType, id, controller);
+};
+
+module.exports.publicKeyFromMultikey = (multikey, keyType) => {
+    requireWasmInitialized();
+    return wasm.publicKeyFromMultikey(multikey, keyType);
+};
+
+module.exports.publicKeyToMultibase = (publicKey, keyType) => {
+    requireWasmInitialized();
+    return wasm.publicKeyToMultibase(publicKey, keyType);
+};
+
+module.exports.publicKeyFromMultibase = (encoded, keyType) => {
+    requireWasmInitialized();
+    return wasm.publicKeyFromMultibase(encoded, keyType);
+};
+
+module.exports.psPublicKeyToJwkSet = (publicKey) => {
+    requireWasmInitialized();
+    return wasm.psPublicKeyToJwkSet(publicKey);
+};
+
+module.exports.psPublicKeyFromJwkSet = (jwkSet) => {
+    requireWasmInitialized();
+    return wasm.psPublicKeyFromJwkSet(jwkSet);
+};
@@ -130,6 +130,16 @@ module.exports.psIsPublicKeyValid = (publicKey) => {
   return wasm.psIsPublicKeyValid(publicKey);
 };
 
+module.exports.psGenerateProofOfPossession = (secretKey, params, context) => {
+  requireWasmInitialized();
+  return wasm.psGenerateProofOfPossession(secretKey, params, context);
+};
+
+module.exports.psVerifyProofOfPossession = (proof, publicKey, params, context) => {
+  requireWasmInitialized();
+  return wasm.psVerifyProofOfPossession(proof, publicKey, params, context);
+};
+
 module.exports.psSign = (messages, secretKey, params) => {
   requireWasmInitialized();
   return wasm.psSign(messages, secretKey, params);
@@ -2,17 +2,41 @@ const {
     wasm, requireWasmInitialized
 } = require('./init_wasm');
 
-module.exports.r1csSnarkSetup = (curveName, numPublic, numPrivate, constraints, commitWitnessCount, returnUncompressed) => {
+// A circuit is given either as the curve name, counts of public and private wires and constraints or as an R1CS
+// returned by `r1csFromBytes`, which is followed directly by the rest of the arguments
+const r1csArgs = (args) => args[0] instanceof Uint8Array ? [args[0], undefined, undefined, undefined, ...args.slice(1)] : args;
+
+// Wires are calculated with a `WitnessCalculatorHandle` or, when given the circuit's wasm bytes, with a witness
+// calculator created and freed within the call
+const withCalculator = (wasmBytesOrCalculator, f) => {
+    if (!(wasmBytesOrCalculator instanceof Uint8Array)) {
+        return f(wasmBytesOrCalculator);
+    }
+    const calculator = new wasm.WitnessCalculatorHandle(wasmBytesOrCalculator);
+    try {
+        return f(calculator);
+    } finally {
+        calculator.free();
+    }
+};
+
+module.exports.r1csSnarkSetup = (...args) => {
     requireWasmInitialized();
-    return wasm.r1csSnarkSetup(curveName, numPublic, numPrivate, constraints, commitWitnessCount, returnUncompressed);
+    return wasm.r1csSnarkSetup(...r1csArgs(args));
 };
 
-module.exports.r1csGenerateWires = (wasmBytes, inputWires) => {
+module.exports.r1csGenerateWires = (wasmBytesOrCalculator, inputWires) => {
     requireWasmInitialized();
-    return wasm.r1csGenerateWires(wasmBytes, inputWires);
+    return withCalculator(wasmBytesOrCalculator, (calculator) => wasm.r1csGenerateWires(calculator, inputWires));
 };
 
-module.exports.r1csCircuitSatisfied = (curveName, numPublic, numPrivate, constraints, wasmBytes, inputWires) => {
+module.exports.r1csCircuitSatisfied = (...args) => {
     requireWasmInitialized();
-    return wasm.r1csCircuitSatisfied(curveName, numPublic, numPrivate, constraints, wasmBytes, inputWires);
-};
\ No newline at end of file
+    const [curveNameOrR1cs, numPublic, numPrivate, constraints, wasmBytesOrCalculator, inputWires] = r1csArgs(args);
+    return withCalculator(wasmBytesOrCalculator, (calculator) => wasm.r1csCircuitSatisfied(curveNameOrR1cs, numPublic, numPrivate, constraints, calculator, inputWires));
+};
+
+module.exports.r1csFromBytes = (bytes) => {
+    requireWasmInitialized();
+    return wasm.r1csFromBytes(bytes);
+};
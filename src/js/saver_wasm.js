@@ -65,6 +65,71 @@ module.exports.saverDecryptCiphertextUsingSnarkVk = (ciphertext, secretKey, decr
     return wasm.saverDecryptCiphertextUsingSnarkVk(ciphertext, secretKey, decryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams);
 };
 
+module.exports.saverCreateDecryptionTableUsingSnarkVk = (decryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverCreateDecryptionTableUsingSnarkVk(decryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams);
+};
+
+module.exports.saverCreateDecryptionTableUsingSnarkPk = (decryptionKey, snarkPk, chunkBitSize, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverCreateDecryptionTableUsingSnarkPk(decryptionKey, snarkPk, chunkBitSize, uncompressedPublicParams);
+};
+
+module.exports.saverDecryptCiphertextUsingTable = (ciphertext, secretKey, decryptionKey, table, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverDecryptCiphertextUsingTable(ciphertext, secretKey, decryptionKey, table, uncompressedPublicParams);
+};
+
+module.exports.saverBatchDecryptCiphertexts = (ciphertexts, secretKey, decryptionKey, table, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverBatchDecryptCiphertexts(ciphertexts, secretKey, decryptionKey, table, uncompressedPublicParams);
+};
+
+module.exports.saverRotateKeys = (snarkPk, encGens, chunkBitSize, uncompressedSnarkPk) => {
+    requireWasmInitialized();
+    return wasm.saverRotateKeys(snarkPk, encGens, chunkBitSize, uncompressedSnarkPk);
+};
+
+module.exports.saverReencryptCiphertext = (ciphertext, oldSecretKey, oldDecryptionKey, table, newEncryptionKey, snarkVk, encGens, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverReencryptCiphertext(ciphertext, oldSecretKey, oldDecryptionKey, table, newEncryptionKey, snarkVk, encGens, uncompressedPublicParams);
+};
+
+module.exports.saverVerifyReencryption = (oldCiphertext, newCiphertext, proof, oldDecryptionKey, newEncryptionKey, snarkVk, encGens, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverVerifyReencryption(oldCiphertext, newCiphertext, proof, oldDecryptionKey, newEncryptionKey, snarkVk, encGens, uncompressedPublicParams);
+};
+
+module.exports.saverEncrypt = (message, encryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverEncrypt(message, encryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams);
+};
+
+module.exports.saverRerandomizeCiphertext = (ciphertext, encryptionKey, randomness, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverRerandomizeCiphertext(ciphertext, encryptionKey, randomness, uncompressedPublicParams);
+};
+
+module.exports.saverProvePlaintextEquality = (message, chunkBitSize, ciphertexts, randomness, encryptionKeys, snarkVks, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverProvePlaintextEquality(message, chunkBitSize, ciphertexts, randomness, encryptionKeys, snarkVks, uncompressedPublicParams);
+};
+
+module.exports.saverVerifyPlaintextEquality = (ciphertexts, proof, encryptionKeys, snarkVks, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverVerifyPlaintextEquality(ciphertexts, proof, encryptionKeys, snarkVks, uncompressedPublicParams);
+};
+
+module.exports.saverEncryptForRecipients = (message, chunkBitSize, encryptionKeys, snarkPk, otherSnarkVks, commGens, nonce, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverEncryptForRecipients(message, chunkBitSize, encryptionKeys, snarkPk, otherSnarkVks, commGens, nonce, uncompressedPublicParams);
+};
+
+module.exports.saverVerifyEncryptionForRecipients = (ciphertexts, commitment, proof, encryptionKeys, snarkVks, commGens, chunkBitSize, nonce, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverVerifyEncryptionForRecipients(ciphertexts, commitment, proof, encryptionKeys, snarkVks, commGens, chunkBitSize, nonce, uncompressedPublicParams);
+};
+
 module.exports.saverVerifyDecryptionUsingSnarkPk = (ciphertext, message, nu, decryptionKey, snarkPk, encGens, chunkBitSize, uncompressedPublicParams) => {
     requireWasmInitialized();
     return wasm.saverVerifyDecryptionUsingSnarkPk(ciphertext, message, nu, decryptionKey, snarkPk, encGens, chunkBitSize, uncompressedPublicParams);
@@ -0,0 +1,163 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.pokDiscreteLogG1Initialize = (witness, base, blinding) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG1Initialize(witness, base, blinding);
+};
+
+module.exports.pokDiscreteLogG2Initialize = (witness, base, blinding) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG2Initialize(witness, base, blinding);
+};
+
+module.exports.pokDiscreteLogG1ChallengeContributionFromProtocol = (protocol, base, y) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG1ChallengeContributionFromProtocol(protocol, base, y);
+};
+
+module.exports.pokDiscreteLogG2ChallengeContributionFromProtocol = (protocol, base, y) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG2ChallengeContributionFromProtocol(protocol, base, y);
+};
+
+module.exports.pokDiscreteLogG1GenProof = (protocol, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG1GenProof(protocol, challenge);
+};
+
+module.exports.pokDiscreteLogG2GenProof = (protocol, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG2GenProof(protocol, challenge);
+};
+
+module.exports.pokDiscreteLogG1ChallengeContributionFromProof = (proof, base, y) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG1ChallengeContributionFromProof(proof, base, y);
+};
+
+module.exports.pokDiscreteLogG2ChallengeContributionFromProof = (proof, base, y) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG2ChallengeContributionFromProof(proof, base, y);
+};
+
+module.exports.pokDiscreteLogG1VerifyProof = (proof, base, y, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG1VerifyProof(proof, base, y, challenge);
+};
+
+module.exports.pokDiscreteLogG2VerifyProof = (proof, base, y, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogG2VerifyProof(proof, base, y, challenge);
+};
+
+module.exports.pokPedersenCommitmentOpeningG1Initialize = (witnesses, bases, blindings) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG1Initialize(witnesses, bases, blindings);
+};
+
+module.exports.pokPedersenCommitmentOpeningG2Initialize = (witnesses, bases, blindings) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG2Initialize(witnesses, bases, blindings);
+};
+
+module.exports.pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol = (protocol, bases, commitment) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol(protocol, bases, commitment);
+};
+
+module.exports.pokPedersenCommitmentOpeningG2ChallengeContributionFromProtocol = (protocol, bases, commitment) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG2ChallengeContributionFromProtocol(protocol, bases, commitment);
+};
+
+module.exports.pokPedersenCommitmentOpeningG1GenProof = (protocol, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG1GenProof(protocol, challenge);
+};
+
+module.exports.pokPedersenCommitmentOpeningG2GenProof = (protocol, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG2GenProof(protocol, challenge);
+};
+
+module.exports.pokPedersenCommitmentOpeningG1ChallengeContributionFromProof = (proof, bases, commitment) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG1ChallengeContributionFromProof(proof, bases, commitment);
+};
+
+module.exports.pokPedersenCommitmentOpeningG2ChallengeContributionFromProof = (proof, bases, commitment) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG2ChallengeContributionFromProof(proof, bases, commitment);
+};
+
+module.exports.pokPedersenCommitmentOpeningG1VerifyProof = (proof, bases, commitment, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG1VerifyProof(proof, bases, commitment, challenge);
+};
+
+module.exports.pokPedersenCommitmentOpeningG2VerifyProof = (proof, bases, commitment, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokPedersenCommitmentOpeningG2VerifyProof(proof, bases, commitment, challenge);
+};
+
+module.exports.pokG1GetResponseForWitness = (proof, index) => {
+    requireWasmInitialized();
+    return wasm.pokG1GetResponseForWitness(proof, index);
+};
+
+module.exports.pokG2GetResponseForWitness = (proof, index) => {
+    requireWasmInitialized();
+    return wasm.pokG2GetResponseForWitness(proof, index);
+};
+
+module.exports.pokDiscreteLogEqualityG1Initialize = (witness, base1, base2, blinding) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG1Initialize(witness, base1, base2, blinding);
+};
+
+module.exports.pokDiscreteLogEqualityG2Initialize = (witness, base1, base2, blinding) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG2Initialize(witness, base1, base2, blinding);
+};
+
+module.exports.pokDiscreteLogEqualityG1ChallengeContributionFromProtocol = (protocol, base1, y1, base2, y2) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG1ChallengeContributionFromProtocol(protocol, base1, y1, base2, y2);
+};
+
+module.exports.pokDiscreteLogEqualityG2ChallengeContributionFromProtocol = (protocol, base1, y1, base2, y2) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG2ChallengeContributionFromProtocol(protocol, base1, y1, base2, y2);
+};
+
+module.exports.pokDiscreteLogEqualityG1GenProof = (protocol, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG1GenProof(protocol, challenge);
+};
+
+module.exports.pokDiscreteLogEqualityG2GenProof = (protocol, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG2GenProof(protocol, challenge);
+};
+
+module.exports.pokDiscreteLogEqualityG1ChallengeContributionFromProof = (proof, base1, y1, base2, y2) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG1ChallengeContributionFromProof(proof, base1, y1, base2, y2);
+};
+
+module.exports.pokDiscreteLogEqualityG2ChallengeContributionFromProof = (proof, base1, y1, base2, y2) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG2ChallengeContributionFromProof(proof, base1, y1, base2, y2);
+};
+
+module.exports.pokDiscreteLogEqualityG1VerifyProof = (proof, base1, y1, base2, y2, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG1VerifyProof(proof, base1, y1, base2, y2, challenge);
+};
+
+module.exports.pokDiscreteLogEqualityG2VerifyProof = (proof, base1, y1, base2, y2, challenge) => {
+    requireWasmInitialized();
+    return wasm.pokDiscreteLogEqualityG2VerifyProof(proof, base1, y1, base2, y2, challenge);
+};
@@ -52,6 +52,16 @@ module.exports.generateSetupParamForPedersenCommitmentKeyG1 = (commitmentKey) =>
     return wasm.generateSetupParamForPedersenCommitmentKeyG1(commitmentKey);
 };
 
+module.exports.generateSetupParamForDkgithCommitmentKey = (params) => {
+    requireWasmInitialized();
+    return wasm.generateSetupParamForDkgithCommitmentKey(params);
+};
+
+module.exports.generateSetupParamForSaverMultiRecipientCommitmentKey = (commGens, uncompressed) => {
+    requireWasmInitialized();
+    return wasm.generateSetupParamForSaverMultiRecipientCommitmentKey(commGens, uncompressed);
+};
+
 module.exports.generateSetupParamForPedersenCommitmentKeyG2 = (commitmentKey) => {
     requireWasmInitialized();
     return wasm.generateSetupParamForPedersenCommitmentKeyG2(commitmentKey);
@@ -92,9 +102,9 @@ module.exports.generateSetupParamForLegoVerifyingKey = (verifyingKey, uncompress
     return wasm.generateSetupParamForLegoVerifyingKey(verifyingKey, uncompressed);
 };
 
-module.exports.generateSetupParamForR1CS = (curveName, numPublic, numPrivate, constraints) => {
+module.exports.generateSetupParamForR1CS = (curveNameOrR1cs, numPublic, numPrivate, constraints) => {
     requireWasmInitialized();
-    return wasm.generateSetupParamForR1CS(curveName, numPublic, numPrivate, constraints);
+    return wasm.generateSetupParamForR1CS(curveNameOrR1cs, numPublic, numPrivate, constraints);
 };
 
 module.exports.generateSetupParamForBytes = (bytes) => {
@@ -0,0 +1,53 @@
+const {
+    wasm, requireWasmInitialized
+} = require('./init_wasm');
+
+module.exports.saverThresholdDecryptorSetup = (chunkBitSize, encGens, threshold, total, returnUncompressedSnarkPk) => {
+    requireWasmInitialized();
+    return wasm.saverThresholdDecryptorSetup(chunkBitSize, encGens, threshold, total, returnUncompressedSnarkPk);
+};
+
+module.exports.saverDecryptorSnarkSetup = (chunkBitSize, encGens, returnUncompressedSnarkPk) => {
+    requireWasmInitialized();
+    return wasm.saverDecryptorSnarkSetup(chunkBitSize, encGens, returnUncompressedSnarkPk);
+};
+
+module.exports.saverEncryptionKeyContribution = (chunkBitSize, encGens, snarkPk, uncompressedSnarkPk) => {
+    requireWasmInitialized();
+    return wasm.saverEncryptionKeyContribution(chunkBitSize, encGens, snarkPk, uncompressedSnarkPk);
+};
+
+module.exports.saverVerifyEncryptionKeyContribution = (contribution, chunkBitSize, encGens, snarkPk, uncompressedSnarkPk) => {
+    requireWasmInitialized();
+    return wasm.saverVerifyEncryptionKeyContribution(contribution, chunkBitSize, encGens, snarkPk, uncompressedSnarkPk);
+};
+
+module.exports.saverDecryptorSetupGivenPublicKey = (chunkBitSize, encGens, snarkPk, thresholdPublicKey, contributions, uncompressedSnarkPk) => {
+    requireWasmInitialized();
+    return wasm.saverDecryptorSetupGivenPublicKey(chunkBitSize, encGens, snarkPk, thresholdPublicKey, contributions, uncompressedSnarkPk);
+};
+
+module.exports.saverSecretKeyShareFromDkgShare = (participantId, threshold, secretShare) => {
+    requireWasmInitialized();
+    return wasm.saverSecretKeyShareFromDkgShare(participantId, threshold, secretShare);
+};
+
+module.exports.saverGetSharePublicKey = (secretKeyShare, encGens) => {
+    requireWasmInitialized();
+    return wasm.saverGetSharePublicKey(secretKeyShare, encGens);
+};
+
+module.exports.saverPartialDecrypt = (ciphertext, secretKeyShare, encGens) => {
+    requireWasmInitialized();
+    return wasm.saverPartialDecrypt(ciphertext, secretKeyShare, encGens);
+};
+
+module.exports.saverVerifyPartialDecryption = (partialDecryption, ciphertext, sharePublicKey, encGens) => {
+    requireWasmInitialized();
+    return wasm.saverVerifyPartialDecryption(partialDecryption, ciphertext, sharePublicKey, encGens);
+};
+
+module.exports.saverCombinePartialDecryptionsUsingSnarkVk = (ciphertext, partialDecryptions, threshold, sharePublicKeys, encGens, decryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams) => {
+    requireWasmInitialized();
+    return wasm.saverCombinePartialDecryptionsUsingSnarkVk(ciphertext, partialDecryptions, threshold, sharePublicKeys, encGens, decryptionKey, snarkVk, chunkBitSize, uncompressedPublicParams);
+};
@@ -19,6 +19,19 @@ export function isAccumulatorPublicKeyValid(
     publicKey: Uint8Array
 ): boolean;
 
+export function generateAccumulatorProofOfPossession(
+    secretKey: Uint8Array,
+    params: Uint8Array,
+    context: Uint8Array
+): Uint8Array;
+
+export function verifyAccumulatorProofOfPossession(
+    proof: Uint8Array,
+    publicKey: Uint8Array,
+    params: Uint8Array,
+    context: Uint8Array
+): Required<VerifyResult>;
+
 export function generateAccumulatorKeyPair(
     params: Uint8Array,
     seed?: Uint8Array
@@ -1,4 +1,5 @@
 import {BbsPoKSigProtocol, BbsSigParams, IKeypair, VerifyResult} from "../types";
+import {BbsPublicKeyHandle, BbsSigParamsHandle} from "./handles";
 
 export const DEFAULT_BLS12381_BBS_PRIVATE_KEY_LENGTH = 32;
 
@@ -47,6 +48,19 @@ export function bbsIsPublicKeyValid(
     publicKey: Uint8Array
 ): boolean;
 
+export function bbsGenerateProofOfPossession(
+    secretKey: Uint8Array,
+    params: BbsSigParams,
+    context: Uint8Array
+): Uint8Array;
+
+export function bbsVerifyProofOfPossession(
+    proof: Uint8Array,
+    publicKey: Uint8Array,
+    params: BbsSigParams,
+    context: Uint8Array
+): Required<VerifyResult>;
+
 export function bbsGenerateKeyPair(
     params: BbsSigParams,
     seed?: Uint8Array
@@ -64,6 +78,21 @@ export function bbsSign(
     encodeMessages: boolean
 ): Uint8Array;
 
+export function bbsSignWithHandles(
+    messages: Uint8Array[],
+    secretKey: Uint8Array,
+    params: BbsSigParamsHandle,
+    encodeMessages: boolean
+): Uint8Array;
+
+export function bbsVerifyWithHandles(
+    messages: Uint8Array[],
+    signature: Uint8Array,
+    publicKey: BbsPublicKeyHandle,
+    params: BbsSigParamsHandle,
+    encodeMessages: boolean
+): Required<VerifyResult>;
+
 export function bbsVerify(
     messages: Uint8Array[],
     signature: Uint8Array,
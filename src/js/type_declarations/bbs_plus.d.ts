@@ -1,4 +1,5 @@
 import {BbsPlusPoKSigProtocol, BbsPlusSigParams, IKeypair, VerifyResult} from "../types";
+import {BbsPlusPublicKeyG2Handle, BbsPlusSigParamsG1Handle} from "./handles";
 
 export const DEFAULT_BLS12381_BBS_PLUS_PRIVATE_KEY_LENGTH = 32;
 
@@ -61,6 +62,19 @@ export function bbsPlusIsPublicKeyG1Valid(
     publicKey: Uint8Array
 ): boolean;
 
+export function bbsPlusGenerateProofOfPossessionG1(
+    secretKey: Uint8Array,
+    params: BbsPlusSigParams,
+    context: Uint8Array
+): Uint8Array;
+
+export function bbsPlusVerifyProofOfPossessionG1(
+    proof: Uint8Array,
+    publicKey: Uint8Array,
+    params: BbsPlusSigParams,
+    context: Uint8Array
+): Required<VerifyResult>;
+
 export function bbsPlusGeneratePublicKeyG2(
     secretKey: Uint8Array,
     params: BbsPlusSigParams
@@ -70,6 +84,19 @@ export function bbsPlusIsPublicKeyG2Valid(
     publicKey: Uint8Array
 ): boolean;
 
+export function bbsPlusGenerateProofOfPossessionG2(
+    secretKey: Uint8Array,
+    params: BbsPlusSigParams,
+    context: Uint8Array
+): Uint8Array;
+
+export function bbsPlusVerifyProofOfPossessionG2(
+    proof: Uint8Array,
+    publicKey: Uint8Array,
+    params: BbsPlusSigParams,
+    context: Uint8Array
+): Required<VerifyResult>;
+
 export function bbsPlusGenerateKeyPairG1(
     params: BbsPlusSigParams,
     seed?: Uint8Array
@@ -112,6 +139,21 @@ export function bbsPlusVerifyG1(
     encodeMessages: boolean
 ): Required<VerifyResult>;
 
+export function bbsPlusSignG1WithHandles(
+    messages: Uint8Array[],
+    secretKey: Uint8Array,
+    params: BbsPlusSigParamsG1Handle,
+    encodeMessages: boolean
+): Uint8Array;
+
+export function bbsPlusVerifyG1WithHandles(
+    messages: Uint8Array[],
+    signature: Uint8Array,
+    publicKey: BbsPlusPublicKeyG2Handle,
+    params: BbsPlusSigParamsG1Handle,
+    encodeMessages: boolean
+): Required<VerifyResult>;
+
 export function bbsPlusVerifyG2(
     messages: Uint8Array[],
     signature: Uint8Array,
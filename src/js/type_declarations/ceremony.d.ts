@@ -0,0 +1,61 @@
+import {VerifyResult} from "../types";
+
+export function boundCheckSnarkSetupFromPowersOfTau(
+    ptau: Uint8Array,
+    uncompressed: boolean
+): Uint8Array;
+
+export function r1csSnarkSetupFromPowersOfTau(
+    r1cs: Uint8Array,
+    commitWitnessCount: number,
+    ptau: Uint8Array,
+    uncompressed: boolean
+): Uint8Array;
+
+export function saverSnarkSetupFromPowersOfTau(
+    chunkBitSize: number,
+    ptau: Uint8Array,
+    uncompressed: boolean
+): [Uint8Array, Uint8Array];
+
+export function legoCeremonyContribute(
+    snarkPk: Uint8Array,
+    transcript: Uint8Array | undefined,
+    uncompressed: boolean
+): [Uint8Array, Uint8Array];
+
+export function legoCeremonyVerifyContribution(
+    previousSnarkPk: Uint8Array,
+    newSnarkPk: Uint8Array,
+    transcript: Uint8Array,
+    uncompressed: boolean
+): Required<VerifyResult>;
+
+export function legoCeremonyFinalize(
+    initialSnarkPk: Uint8Array,
+    finalSnarkPk: Uint8Array,
+    transcript: Uint8Array,
+    uncompressed: boolean
+): Uint8Array;
+
+export function saverCeremonyContribute(
+    snarkPk: Uint8Array,
+    transcript: Uint8Array | undefined,
+    uncompressed: boolean
+): [Uint8Array, Uint8Array];
+
+export function saverCeremonyVerifyContribution(
+    previousSnarkPk: Uint8Array,
+    newSnarkPk: Uint8Array,
+    transcript: Uint8Array,
+    uncompressed: boolean
+): Required<VerifyResult>;
+
+export function saverCeremonyFinalize(
+    initialSnarkPk: Uint8Array,
+    finalSnarkPk: Uint8Array,
+    transcript: Uint8Array,
+    encGens: Uint8Array,
+    chunkBitSize: number,
+    uncompressed: boolean
+): [Uint8Array, Uint8Array, Uint8Array, Uint8Array];
@@ -1,4 +1,5 @@
-import {BbsPlusSigParams, INonMembershipWitness, VerifyResult, Constraint, PSSigParams, BbsSigParams} from "../types";
+import {BbsPlusSigParams, INonMembershipWitness, VerifyResult, Constraint, PSSigParams, BbsSigParams, ProofSpecDescription, StatementProofDetails, SignalValue} from "../types";
+import {InteractiveProverState, ProofSpecG1Handle, WitnessCalculatorHandle} from "./handles";
 
 export function generatePoKBBSSignatureStatement(
     params: BbsSigParams,
@@ -78,6 +79,16 @@ export function generatePedersenCommitmentG1StatementFromParamRefs(
     commitment: Uint8Array
 ): Uint8Array;
 
+export function generateDkgithStatement(
+    params: Uint8Array,
+    commitment: Uint8Array
+): Uint8Array;
+
+export function generateDkgithStatementFromParamRefs(
+    commitmentKey: number,
+    commitment: Uint8Array
+): Uint8Array;
+
 export function generatePedersenCommitmentG2Statement(
     bases: Uint8Array[],
     commitment: Uint8Array
@@ -88,6 +99,17 @@ export function generatePedersenCommitmentG2StatementFromParamRefs(
     commitment: Uint8Array
 ): Uint8Array;
 
+export function generateSaverMultiRecipientStatement(
+    commGens: Uint8Array,
+    commitment: Uint8Array,
+    uncompressedPublicParams: boolean
+): Uint8Array;
+
+export function generateSaverMultiRecipientStatementFromParamRefs(
+    commitmentKey: number,
+    commitment: Uint8Array
+): Uint8Array;
+
 export function generateSaverProverStatement(
     chunkBitSize: number,
     encGens: Uint8Array,
@@ -179,6 +201,35 @@ export function generateWitnessEqualityMetaStatement(
     equalities: Set<[number, number]>,
 ): Uint8Array;
 
+export function generateLinearRelationStatement(
+    coefficients: (number | Uint8Array)[],
+    constant: number | Uint8Array
+): Uint8Array;
+
+export function generateLinearRelationMetaStatements(
+    statementIndex: number,
+    statement: Uint8Array,
+    witnessRefs: [number, number][]
+): Uint8Array[];
+
+export function generateBBSPlusBlindSignatureRequestStatement(
+    params: BbsPlusSigParams,
+    committedIndices: Set<number>,
+    commitment: Uint8Array
+): Uint8Array;
+
+export function generateBBSBlindSignatureRequestStatement(
+    params: BbsSigParams,
+    committedIndices: Set<number>,
+    commitment: Uint8Array
+): Uint8Array;
+
+export function generatePSBlindSignatureRequestStatements(
+    params: PSSigParams,
+    h: Uint8Array,
+    commitments: Map<number, Uint8Array>
+): Uint8Array[];
+
 export function generatePoKBBSSignatureWitness(
     signature: Uint8Array,
     unrevealedMessages: Map<number, Uint8Array>,
@@ -210,6 +261,37 @@ export function generatePedersenCommitmentWitness(
     elements: Uint8Array[]
 ): Uint8Array;
 
+export function generateLinearRelationWitness(
+    witnesses: Uint8Array[],
+    witnessRefs: [number, number][]
+): Uint8Array;
+
+export function generateBBSPlusBlindSignatureRequestWitness(
+    messagesToCommit: Map<number, Uint8Array>,
+    blinding: Uint8Array,
+    encodeMessages: boolean
+): Uint8Array;
+
+export function generateBBSBlindSignatureRequestWitness(
+    messagesToCommit: Map<number, Uint8Array>,
+    encodeMessages: boolean
+): Uint8Array;
+
+export function generatePSBlindSignatureRequestWitnesses(
+    messagesToCommit: Map<number, Uint8Array>,
+    blindings: Map<number, Uint8Array>
+): Uint8Array[];
+
+export function generateDkgithWitness(
+    message: Uint8Array,
+    blinding: Uint8Array
+): Uint8Array;
+
+export function generateSaverMultiRecipientWitness(
+    message: Uint8Array,
+    blinding: Uint8Array
+): Uint8Array;
+
 export function generateSaverWitness(
     message: Uint8Array
 ): Uint8Array;
@@ -218,17 +300,24 @@ export function generateBoundCheckWitness(
     message: Uint8Array
 ): Uint8Array;
 
+/**
+ * When given the circuit's wasm bytes or a witness calculator, the circuit's wires are calculated first so that
+ * inputs which don't match the circuit's signals fail here rather than when creating the proof
+ */
 export function generateR1CSCircomWitness(
-    inputWires: Map<string, Uint8Array[]>,
+    inputWires: Map<string, SignalValue[]>,
     privates: string[],
     publics?: string[],
+    wasmBytesOrCalculator?: Uint8Array | WitnessCalculatorHandle,
 ): Uint8Array;
 
 export function generateProofSpecG1(
     statements: Uint8Array[],
     metaStatements: Uint8Array[],
     setupParams: Uint8Array[],
-    context?: Uint8Array
+    context?: Uint8Array,
+    orMetaStatements?: Uint8Array[],
+    orWitnessEqualities?: Uint8Array[]
 ): Uint8Array;
 
 export function isProofSpecG1Valid(
@@ -258,12 +347,39 @@ export function generateCompositeProofG2(
     nonce?: Uint8Array
 ): Uint8Array;
 
+export function generateCompositeProofG1WithHandle(
+    proofSpec: ProofSpecG1Handle,
+    witnesses: Uint8Array[],
+    nonce?: Uint8Array
+): Uint8Array;
+
+export function verifyCompositeProofG1WithHandle(
+    proof: Uint8Array,
+    proofSpec: ProofSpecG1Handle,
+    nonce?: Uint8Array
+): Required<VerifyResult>;
+
 export function verifyCompositeProofG1(
     proof: Uint8Array,
     proofSpec: Uint8Array,
     nonce?: Uint8Array
 ): Required<VerifyResult>;
 
+export function verifyCompositeProofG1WithDkgith(
+    proof: Uint8Array,
+    proofSpec: Uint8Array,
+    nonce: Uint8Array | undefined,
+    dkgithProofs: Map<number, [Uint8Array, Uint8Array, Uint8Array]>
+): Required<VerifyResult>;
+
+export function verifyCompositeProofG1WithSaverMultiRecipient(
+    proof: Uint8Array,
+    proofSpec: Uint8Array,
+    nonce: Uint8Array | undefined,
+    encryptions: Map<number, [Uint8Array[], Uint8Array, Uint8Array[], Uint8Array[], number]>,
+    uncompressedPublicParams: boolean
+): Required<VerifyResult>;
+
 export function verifyCompositeProofG2(
     proof: Uint8Array,
     proofSpec: Uint8Array,
@@ -297,3 +413,87 @@ export function saverGetCiphertextsFromProof(
     proof: Uint8Array,
     statementIndices: number[]
 ): Uint8Array[];
+
+export function describeProofSpec(
+    proofSpec: Uint8Array
+): ProofSpecDescription;
+
+export function proofSpecSatisfiedBy(
+    proofSpec: Uint8Array,
+    templateSpec: Uint8Array
+): Required<VerifyResult>;
+
+export function extractFromCompositeProofG1(
+    proof: Uint8Array,
+    proofSpec: Uint8Array
+): StatementProofDetails[];
+
+export function extractFromCompositeProofG2(
+    proof: Uint8Array,
+    proofSpec: Uint8Array
+): StatementProofDetails[];
+
+export function generateOrMetaStatement(
+    proofSpecs: Uint8Array[],
+    publicKeysG1?: Map<number, Uint8Array>[]
+): Uint8Array;
+
+export function generateOrProofG1(
+    orMetaStatement: Uint8Array,
+    satisfiedIndex: number,
+    witnesses: Uint8Array[],
+    nonce?: Uint8Array
+): Uint8Array;
+
+export function verifyOrProofG1(
+    proof: Uint8Array,
+    orMetaStatement: Uint8Array,
+    nonce?: Uint8Array
+): Required<VerifyResult>;
+
+export function generateOrWitnessEquality(
+    witnessRef: [number, number],
+    orIndex: number,
+    branchWitnessRefs: [number, number][]
+): Uint8Array;
+
+export function generateCompositeProofG1WithOrMetaStatements(
+    proofSpec: Uint8Array,
+    witnesses: Uint8Array[],
+    satisfiedIndices: number[],
+    orWitnesses: Uint8Array[][],
+    nonce?: Uint8Array
+): Uint8Array;
+
+export function verifyCompositeProofG1WithOrMetaStatements(
+    proof: Uint8Array,
+    proofSpec: Uint8Array,
+    nonce?: Uint8Array
+): Required<VerifyResult>;
+
+export function compositeProofInit(
+    proofSpec: Uint8Array,
+    witnesses: Uint8Array[]
+): InteractiveProverState;
+
+export function interactiveProverStateFromBytes(
+    bytes: Uint8Array
+): InteractiveProverState;
+
+export function compositeProofCommitments(
+    state: InteractiveProverState
+): Uint8Array;
+
+export function compositeProofRespond(
+    state: InteractiveProverState,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function compositeProofChallenge(): Uint8Array;
+
+export function compositeProofVerify(
+    proof: Uint8Array,
+    proofSpec: Uint8Array,
+    commitments: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
@@ -0,0 +1,43 @@
+import {VerifyResult} from "../types";
+
+export function dkgithGenerateParams(
+    label?: Uint8Array
+): Uint8Array;
+
+export function dkgithGenerateSecretKey(
+    seed?: Uint8Array
+): Uint8Array;
+
+export function dkgithGeneratePublicKey(
+    secretKey: Uint8Array,
+    params: Uint8Array
+): Uint8Array;
+
+export function dkgithEncrypt(
+    message: Uint8Array,
+    params: Uint8Array,
+    publicKey: Uint8Array,
+    nonce?: Uint8Array
+): [Uint8Array, Uint8Array, Uint8Array];
+
+export function dkgithVerifyEncryption(
+    proof: Uint8Array,
+    commitment: Uint8Array,
+    params: Uint8Array,
+    publicKey: Uint8Array,
+    nonce?: Uint8Array
+): Required<VerifyResult>;
+
+export function dkgithDecrypt(
+    proof: Uint8Array,
+    commitment: Uint8Array,
+    secretKey: Uint8Array,
+    params: Uint8Array
+): [Uint8Array, Uint8Array];
+
+export function dkgithVerifyDecryption(
+    commitment: Uint8Array,
+    message: Uint8Array,
+    blinding: Uint8Array,
+    params: Uint8Array
+): Required<VerifyResult>;
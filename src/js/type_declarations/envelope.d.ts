@@ -0,0 +1,24 @@
+import {EnvelopeInfo} from "../types";
+
+export function setEnvelopeOutput(
+    enabled: boolean
+): void;
+
+export function setEnvelopeInput(
+    enabled: boolean
+): void;
+
+export function envelopeWrap(
+    bytes: Uint8Array,
+    typeName: string,
+    compressed: boolean
+): Uint8Array;
+
+export function envelopeUnwrap(
+    bytes: Uint8Array,
+    expectedType?: string
+): Uint8Array;
+
+export function envelopeInspect(
+    bytes: Uint8Array
+): EnvelopeInfo;
@@ -0,0 +1,40 @@
+import {Constraint, SnarkjsProof, SnarkjsVerifyingKey, VerifyResult} from "../types";
+
+export function groth16ProvingKeyFromZkey(zkey: Uint8Array, returnUncompressed: boolean): Uint8Array;
+
+export function groth16ProvingKeyToZkey(snarkPk: Uint8Array, uncompressed: boolean): Uint8Array;
+
+export function groth16SnarkSetup(
+    curveName: string,
+    numPublic: number,
+    numPrivate: number,
+    constraints: Constraint[],
+    returnUncompressed: boolean
+): Uint8Array;
+
+export function groth16VkFromPk(snarkPk: Uint8Array, uncompressed: boolean): Uint8Array;
+
+export function groth16Prove(
+    snarkPk: Uint8Array,
+    wires: Uint8Array[],
+    uncompressed: boolean
+): [Uint8Array, Uint8Array[]];
+
+export function groth16Verify(
+    proof: Uint8Array,
+    snarkVk: Uint8Array,
+    publicSignals: Uint8Array[],
+    uncompressed: boolean
+): Required<VerifyResult>;
+
+export function groth16VkToSnarkjs(snarkVk: Uint8Array, uncompressed: boolean): SnarkjsVerifyingKey;
+
+export function groth16VkFromSnarkjs(snarkVk: SnarkjsVerifyingKey, returnUncompressed: boolean): Uint8Array;
+
+export function groth16ProofToSnarkjs(proof: Uint8Array): SnarkjsProof;
+
+export function groth16ProofFromSnarkjs(proof: SnarkjsProof): Uint8Array;
+
+export function groth16PublicSignalsToSnarkjs(publicSignals: Uint8Array[]): string[];
+
+export function groth16PublicSignalsFromSnarkjs(publicSignals: string[]): Uint8Array[];
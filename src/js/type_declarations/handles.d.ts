@@ -0,0 +1,89 @@
+import {BbsPlusSigParams, BbsSigParams} from "../types";
+
+// Handles keep deserialized objects in wasm memory and must be freed by calling `free` on them
+
+export interface Handle {
+    free(): void;
+}
+
+export interface SigParamsHandle extends Handle {
+    supportedMessageCount(): number;
+}
+
+export interface BbsSigParamsHandle extends SigParamsHandle {}
+export interface BbsPlusSigParamsG1Handle extends SigParamsHandle {}
+export interface BbsPublicKeyHandle extends Handle {}
+export interface BbsPlusPublicKeyG2Handle extends Handle {}
+export interface LegoProvingKeyHandle extends Handle {}
+export interface LegoVerifyingKeyHandle extends Handle {}
+export interface SaverProvingKeyHandle extends Handle {}
+export interface SaverVerifyingKeyHandle extends Handle {}
+export interface WitnessCalculatorHandle extends Handle {}
+// Holds the witnesses of an interactive composite proof and is used up by `compositeProofRespond`
+export interface InteractiveProverState extends Handle {
+    // Contains the witnesses. A state restored from these bytes must be used to respond only once.
+    toBytes(): Uint8Array;
+}
+
+export interface ProofSpecG1Handle extends Handle {
+    toBytes(): Uint8Array;
+    isValid(): boolean;
+    addStatement(statement: Uint8Array): number;
+    addMetaStatement(metaStatement: object): number;
+    addSetupParamForBBSSignatureParameters(params: BbsSigParamsHandle): number;
+    addSetupParamForBBSPlusSignatureParametersG1(params: BbsPlusSigParamsG1Handle): number;
+    addSetupParamForBBSPublicKey(publicKey: BbsPublicKeyHandle): number;
+    addSetupParamForBBSPlusPublicKeyG2(publicKey: BbsPlusPublicKeyG2Handle): number;
+    addSetupParamForLegoProvingKey(key: LegoProvingKeyHandle): number;
+    addSetupParamForLegoVerifyingKey(key: LegoVerifyingKeyHandle): number;
+    addSetupParamForSaverProvingKey(key: SaverProvingKeyHandle): number;
+    addSetupParamForSaverVerifyingKey(key: SaverVerifyingKeyHandle): number;
+    // Move the key into the proof spec rather than copying it. The handle can't be used after this.
+    moveSetupParamForLegoProvingKey(key: LegoProvingKeyHandle): number;
+    moveSetupParamForSaverProvingKey(key: SaverProvingKeyHandle): number;
+}
+
+export function bbsSigParamsHandle(params: BbsSigParams): BbsSigParamsHandle;
+
+export function bbsPlusSigParamsG1Handle(params: BbsPlusSigParams): BbsPlusSigParamsG1Handle;
+
+export function bbsPublicKeyHandle(publicKey: Uint8Array): BbsPublicKeyHandle;
+
+export function bbsPlusPublicKeyG2Handle(publicKey: Uint8Array): BbsPlusPublicKeyG2Handle;
+
+export function legoProvingKeyHandle(key: Uint8Array, uncompressed: boolean): LegoProvingKeyHandle;
+
+export function legoVerifyingKeyHandle(key: Uint8Array, uncompressed: boolean): LegoVerifyingKeyHandle;
+
+export function saverProvingKeyHandle(key: Uint8Array, uncompressed: boolean): SaverProvingKeyHandle;
+
+export function saverVerifyingKeyHandle(key: Uint8Array, uncompressed: boolean): SaverVerifyingKeyHandle;
+
+export function legoProvingKeyHandleFromParts(parts: Uint8Array[], uncompressed: boolean): LegoProvingKeyHandle;
+
+export function saverProvingKeyHandleFromParts(parts: Uint8Array[], uncompressed: boolean): SaverProvingKeyHandle;
+
+// If `provingOnly` is true, the parts of the verification key that proving doesn't need are skipped and the handle can
+// only be used for proving
+export function legoProvingKeyHandleFromStream(
+    stream: ReadableStream<Uint8Array>,
+    uncompressed: boolean,
+    provingOnly?: boolean
+): Promise<LegoProvingKeyHandle>;
+
+export function saverProvingKeyHandleFromStream(
+    stream: ReadableStream<Uint8Array>,
+    uncompressed: boolean,
+    provingOnly?: boolean
+): Promise<SaverProvingKeyHandle>;
+
+export function proofSpecG1Handle(
+    statements: Uint8Array[],
+    metaStatements: Uint8Array[],
+    setupParams: Uint8Array[],
+    context?: Uint8Array
+): ProofSpecG1Handle;
+
+export function proofSpecG1HandleFromBytes(bytes: Uint8Array): ProofSpecG1Handle;
+
+export function witnessCalculatorHandle(wasmBytes: Uint8Array): WitnessCalculatorHandle;
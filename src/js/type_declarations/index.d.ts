@@ -12,3 +12,12 @@ export * from "./ps";
 export * from "./frost_dkg";
 export * from "./threshold_sig";
 
+export * from "./issuer_hiding";
+export * from "./handles";
+export * from "./envelope";
+export * from "./key_encoding";
+export * from "./schnorr";
+export * from "./threshold_saver";
+export * from "./dkgith";
+export * from "./ceremony";
+export * from "./groth16";
@@ -0,0 +1,79 @@
+import {BbsPlusSigParams, BbsSigParams, VerifyResult} from "../types";
+
+export function generateIssuerHidingVerifierSecretKey(seed?: Uint8Array): Uint8Array;
+
+export function generateIssuerHidingVerifierPublicKey(secretKey: Uint8Array): Uint8Array;
+
+export function bbsPlusGeneratePublicKeyG1ForIssuerHiding(
+    secretKey: Uint8Array,
+    params: BbsPlusSigParams
+): Uint8Array;
+
+export function bbsGeneratePublicKeyG1ForIssuerHiding(
+    secretKey: Uint8Array,
+    params: BbsSigParams
+): Uint8Array;
+
+export function bbsPlusSignIssuerPolicy(
+    params: BbsPlusSigParams[],
+    publicKeys: Uint8Array[],
+    publicKeysG1: Uint8Array[],
+    verifierSecretKey: Uint8Array
+): Uint8Array;
+
+export function bbsPlusVerifyIssuerPolicy(
+    policy: Uint8Array,
+    verifierPublicKey: Uint8Array
+): Required<VerifyResult>;
+
+export function bbsPlusGenIssuerHidingProof(
+    policy: Uint8Array,
+    verifierPublicKey: Uint8Array,
+    issuerIndex: number,
+    signature: Uint8Array,
+    messages: Uint8Array[],
+    revealedIndices: Set<number>,
+    encodeMessages: boolean,
+    nonce?: Uint8Array
+): Uint8Array;
+
+export function bbsPlusVerifyIssuerHidingProof(
+    proof: Uint8Array,
+    policy: Uint8Array,
+    verifierPublicKey: Uint8Array,
+    revealedMessages: Map<number, Uint8Array>,
+    encodeMessages: boolean,
+    nonce?: Uint8Array
+): Required<VerifyResult>;
+
+export function bbsSignIssuerPolicy(
+    params: BbsSigParams[],
+    publicKeys: Uint8Array[],
+    publicKeysG1: Uint8Array[],
+    verifierSecretKey: Uint8Array
+): Uint8Array;
+
+export function bbsVerifyIssuerPolicy(
+    policy: Uint8Array,
+    verifierPublicKey: Uint8Array
+): Required<VerifyResult>;
+
+export function bbsGenIssuerHidingProof(
+    policy: Uint8Array,
+    verifierPublicKey: Uint8Array,
+    issuerIndex: number,
+    signature: Uint8Array,
+    messages: Uint8Array[],
+    revealedIndices: Set<number>,
+    encodeMessages: boolean,
+    nonce?: Uint8Array
+): Uint8Array;
+
+export function bbsVerifyIssuerHidingProof(
+    proof: Uint8Array,
+    policy: Uint8Array,
+    verifierPublicKey: Uint8Array,
+    revealedMessages: Map<number, Uint8Array>,
+    encodeMessages: boolean,
+    nonce?: Uint8Array
+): Required<VerifyResult>;
@@ -0,0 +1,41 @@
+import {Jwk, JwkSet, Multikey, PublicKeyType} from "../types";
+
+export function publicKeyToJwk(
+    publicKey: Uint8Array,
+    keyType: PublicKeyType
+): Jwk;
+
+export function publicKeyFromJwk(
+    jwk: Jwk,
+    keyType: PublicKeyType
+): Uint8Array;
+
+export function publicKeyToMultikey(
+    publicKey: Uint8Array,
+    keyType: PublicKeyType,
+    id?: string,
+    controller?: string
+): Multikey;
+
+export function publicKeyFromMultikey(
+    multikey: Multikey,
+    keyType: PublicKeyType
+): Uint8Array;
+
+export function publicKeyToMultibase(
+    publicKey: Uint8Array,
+    keyType: PublicKeyType
+): string;
+
+export function publicKeyFromMultibase(
+    encoded: string,
+    keyType: PublicKeyType
+): Uint8Array;
+
+export function psPublicKeyToJwkSet(
+    publicKey: Uint8Array
+): JwkSet;
+
+export function psPublicKeyFromJwkSet(
+    jwkSet: JwkSet
+): Uint8Array;
@@ -77,6 +77,19 @@ export function psIsPublicKeyValid(
     publicKey: Uint8Array
 ): boolean;
 
+export function psGenerateProofOfPossession(
+    secretKey: Uint8Array,
+    params: PSSigParams,
+    context: Uint8Array
+): Uint8Array;
+
+export function psVerifyProofOfPossession(
+    proof: Uint8Array,
+    publicKey: Uint8Array,
+    params: PSSigParams,
+    context: Uint8Array
+): Required<VerifyResult>;
+
 export function psIsPublicKeyValid(
     publicKey: Uint8Array
 ): boolean;
@@ -1,7 +1,25 @@
-import {Constraint} from "../types";
+import {Constraint, SignalValue} from "../types";
+import {WitnessCalculatorHandle} from "./handles";
 
 export function r1csSnarkSetup(curveName: string, numPublic: number, numPrivate: number, constraints: Constraint[], commitWitnessCount: number, returnUncompressed: boolean): Uint8Array;
 
-export function r1csGenerateWires(wasmBytes: Uint8Array, inputWires: Map<string, Uint8Array[]>): Uint8Array[];
+/**
+ * Same as above but for an R1CS returned by `r1csFromBytes`
+ */
+export function r1csSnarkSetup(r1cs: Uint8Array, commitWitnessCount: number, returnUncompressed: boolean): Uint8Array;
+
+/**
+ * The wires are calculated with the circuit's wasm bytes or with a witness calculator created once for the circuit
+ * by `witnessCalculatorHandle`
+ */
+export function r1csGenerateWires(wasmBytesOrCalculator: Uint8Array | WitnessCalculatorHandle, inputWires: Map<string, SignalValue[]>): Uint8Array[];
+
+export function r1csCircuitSatisfied(curveName: string, numPublic: number, numPrivate: number, constraints: Constraint[], wasmBytesOrCalculator: Uint8Array | WitnessCalculatorHandle, inputWires: Map<string, SignalValue[]>): boolean;
+
+/**
+ * Same as above but for an R1CS returned by `r1csFromBytes`
+ */
+export function r1csCircuitSatisfied(r1cs: Uint8Array, wasmBytesOrCalculator: Uint8Array | WitnessCalculatorHandle, inputWires: Map<string, SignalValue[]>): boolean;
+
+export function r1csFromBytes(bytes: Uint8Array): Uint8Array;
 
-export function r1csCircuitSatisfied(curveName: string, numPublic: number, numPrivate: number, constraints: Constraint[], wasmBytes: Uint8Array, inputWires: Map<string, Uint8Array[]>): boolean;
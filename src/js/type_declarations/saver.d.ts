@@ -61,6 +61,121 @@ export function saverDecryptCiphertextUsingSnarkVk(
     uncompressedPublicParams: boolean
 ): [Uint8Array, Uint8Array];
 
+export function saverCreateDecryptionTableUsingSnarkVk(
+    decryptionKey: Uint8Array,
+    snarkVk: Uint8Array,
+    chunkBitSize: number,
+    uncompressedPublicParams: boolean
+): Uint8Array;
+
+export function saverCreateDecryptionTableUsingSnarkPk(
+    decryptionKey: Uint8Array,
+    snarkPk: Uint8Array,
+    chunkBitSize: number,
+    uncompressedPublicParams: boolean
+): Uint8Array;
+
+export function saverDecryptCiphertextUsingTable(
+    ciphertext: Uint8Array,
+    secretKey: Uint8Array,
+    decryptionKey: Uint8Array,
+    table: Uint8Array,
+    uncompressedPublicParams: boolean
+): [Uint8Array, Uint8Array];
+
+export function saverBatchDecryptCiphertexts(
+    ciphertexts: Uint8Array[],
+    secretKey: Uint8Array,
+    decryptionKey: Uint8Array,
+    table: Uint8Array,
+    uncompressedPublicParams: boolean
+): [Uint8Array, Uint8Array][];
+
+export function saverRotateKeys(
+    snarkPk: Uint8Array,
+    encGens: Uint8Array,
+    chunkBitSize: number,
+    uncompressedSnarkPk: boolean
+): [Uint8Array, Uint8Array, Uint8Array];
+
+export function saverReencryptCiphertext(
+    ciphertext: Uint8Array,
+    oldSecretKey: Uint8Array,
+    oldDecryptionKey: Uint8Array,
+    table: Uint8Array,
+    newEncryptionKey: Uint8Array,
+    snarkVk: Uint8Array,
+    encGens: Uint8Array,
+    uncompressedPublicParams: boolean
+): [Uint8Array, Uint8Array];
+
+export function saverVerifyReencryption(
+    oldCiphertext: Uint8Array,
+    newCiphertext: Uint8Array,
+    proof: Uint8Array,
+    oldDecryptionKey: Uint8Array,
+    newEncryptionKey: Uint8Array,
+    snarkVk: Uint8Array,
+    encGens: Uint8Array,
+    uncompressedPublicParams: boolean
+): Required<VerifyResult>;
+
+export function saverEncrypt(
+    message: Uint8Array,
+    encryptionKey: Uint8Array,
+    snarkVk: Uint8Array,
+    chunkBitSize: number,
+    uncompressedPublicParams: boolean
+): [Uint8Array, Uint8Array];
+
+export function saverRerandomizeCiphertext(
+    ciphertext: Uint8Array,
+    encryptionKey: Uint8Array,
+    randomness: Uint8Array | undefined,
+    uncompressedPublicParams: boolean
+): [Uint8Array, Uint8Array];
+
+export function saverProvePlaintextEquality(
+    message: Uint8Array,
+    chunkBitSize: number,
+    ciphertexts: Uint8Array[],
+    randomness: Uint8Array[],
+    encryptionKeys: Uint8Array[],
+    snarkVks: Uint8Array[],
+    uncompressedPublicParams: boolean
+): Uint8Array;
+
+export function saverVerifyPlaintextEquality(
+    ciphertexts: Uint8Array[],
+    proof: Uint8Array,
+    encryptionKeys: Uint8Array[],
+    snarkVks: Uint8Array[],
+    uncompressedPublicParams: boolean
+): Required<VerifyResult>;
+
+export function saverEncryptForRecipients(
+    message: Uint8Array,
+    chunkBitSize: number,
+    encryptionKeys: Uint8Array[],
+    snarkPk: Uint8Array,
+    otherSnarkVks: Uint8Array[],
+    commGens: Uint8Array,
+    nonce: Uint8Array | undefined,
+    uncompressedPublicParams: boolean
+): [Uint8Array[], Uint8Array, Uint8Array, Uint8Array];
+
+export function saverVerifyEncryptionForRecipients(
+    ciphertexts: Uint8Array[],
+    commitment: Uint8Array,
+    proof: Uint8Array,
+    encryptionKeys: Uint8Array[],
+    snarkVks: Uint8Array[],
+    commGens: Uint8Array,
+    chunkBitSize: number,
+    nonce: Uint8Array | undefined,
+    uncompressedPublicParams: boolean
+): Required<VerifyResult>;
+
 export function saverVerifyDecryptionUsingSnarkPk(
     ciphertext: Uint8Array,
     message: Uint8Array,
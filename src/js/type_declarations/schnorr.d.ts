@@ -0,0 +1,205 @@
+import {VerifyResult} from "../types";
+
+export function pokDiscreteLogG1Initialize(
+    witness: Uint8Array,
+    base: Uint8Array,
+    blinding?: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG2Initialize(
+    witness: Uint8Array,
+    base: Uint8Array,
+    blinding?: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG1ChallengeContributionFromProtocol(
+    protocol: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG2ChallengeContributionFromProtocol(
+    protocol: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG1GenProof(
+    protocol: Uint8Array,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG2GenProof(
+    protocol: Uint8Array,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG1ChallengeContributionFromProof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG2ChallengeContributionFromProof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogG1VerifyProof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
+
+export function pokDiscreteLogG2VerifyProof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
+
+export function pokPedersenCommitmentOpeningG1Initialize(
+    witnesses: Uint8Array[],
+    bases: Uint8Array[],
+    blindings: Map<number, Uint8Array>
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG2Initialize(
+    witnesses: Uint8Array[],
+    bases: Uint8Array[],
+    blindings: Map<number, Uint8Array>
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol(
+    protocol: Uint8Array,
+    bases: Uint8Array[],
+    commitment: Uint8Array
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG2ChallengeContributionFromProtocol(
+    protocol: Uint8Array,
+    bases: Uint8Array[],
+    commitment: Uint8Array
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG1GenProof(
+    protocol: Uint8Array,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG2GenProof(
+    protocol: Uint8Array,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG1ChallengeContributionFromProof(
+    proof: Uint8Array,
+    bases: Uint8Array[],
+    commitment: Uint8Array
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG2ChallengeContributionFromProof(
+    proof: Uint8Array,
+    bases: Uint8Array[],
+    commitment: Uint8Array
+): Uint8Array;
+
+export function pokPedersenCommitmentOpeningG1VerifyProof(
+    proof: Uint8Array,
+    bases: Uint8Array[],
+    commitment: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
+
+export function pokPedersenCommitmentOpeningG2VerifyProof(
+    proof: Uint8Array,
+    bases: Uint8Array[],
+    commitment: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
+
+export function pokG1GetResponseForWitness(
+    proof: Uint8Array,
+    index: number
+): Uint8Array;
+
+export function pokG2GetResponseForWitness(
+    proof: Uint8Array,
+    index: number
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG1Initialize(
+    witness: Uint8Array,
+    base1: Uint8Array,
+    base2: Uint8Array,
+    blinding?: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG2Initialize(
+    witness: Uint8Array,
+    base1: Uint8Array,
+    base2: Uint8Array,
+    blinding?: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG1ChallengeContributionFromProtocol(
+    protocol: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG2ChallengeContributionFromProtocol(
+    protocol: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG1GenProof(
+    protocol: Uint8Array,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG2GenProof(
+    protocol: Uint8Array,
+    challenge: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG1ChallengeContributionFromProof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG2ChallengeContributionFromProof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array
+): Uint8Array;
+
+export function pokDiscreteLogEqualityG1VerifyProof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
+
+export function pokDiscreteLogEqualityG2VerifyProof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+    challenge: Uint8Array
+): Required<VerifyResult>;
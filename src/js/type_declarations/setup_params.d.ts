@@ -40,6 +40,15 @@ export function generateSetupParamForPedersenCommitmentKeyG1(
     commitmentKey: Uint8Array[]
 ): Uint8Array;
 
+export function generateSetupParamForDkgithCommitmentKey(
+    params: Uint8Array
+): Uint8Array;
+
+export function generateSetupParamForSaverMultiRecipientCommitmentKey(
+    commGens: Uint8Array,
+    uncompressed: boolean
+): Uint8Array;
+
 export function generateSetupParamForPedersenCommitmentKeyG2(
     commitmentKey: Uint8Array[]
 ): Uint8Array;
@@ -83,6 +92,11 @@ export function generateSetupParamForR1CS(
     curveName: string, numPublic: number, numPrivate: number, constraints: Constraint[]
 ): Uint8Array;
 
+/**
+ * Same as above but for an R1CS returned by `r1csFromBytes`
+ */
+export function generateSetupParamForR1CS(r1cs: Uint8Array): Uint8Array;
+
 export function generateSetupParamForBytes(
     bytes: Uint8Array
 ): Uint8Array;
@@ -0,0 +1,75 @@
+import {VerifyResult} from "../types";
+
+export function saverThresholdDecryptorSetup(
+    chunkBitSize: number,
+    encGens: Uint8Array,
+    threshold: number,
+    total: number,
+    returnUncompressedSnarkPk: boolean,
+): [Uint8Array, Uint8Array[], Uint8Array[], Uint8Array, Uint8Array];
+
+export function saverDecryptorSnarkSetup(
+    chunkBitSize: number,
+    encGens: Uint8Array,
+    returnUncompressedSnarkPk: boolean,
+): Uint8Array;
+
+export function saverEncryptionKeyContribution(
+    chunkBitSize: number,
+    encGens: Uint8Array,
+    snarkPk: Uint8Array,
+    uncompressedSnarkPk: boolean,
+): Uint8Array;
+
+export function saverVerifyEncryptionKeyContribution(
+    contribution: Uint8Array,
+    chunkBitSize: number,
+    encGens: Uint8Array,
+    snarkPk: Uint8Array,
+    uncompressedSnarkPk: boolean,
+): Required<VerifyResult>;
+
+export function saverDecryptorSetupGivenPublicKey(
+    chunkBitSize: number,
+    encGens: Uint8Array,
+    snarkPk: Uint8Array,
+    thresholdPublicKey: Uint8Array,
+    contributions: Uint8Array[],
+    uncompressedSnarkPk: boolean,
+): [Uint8Array, Uint8Array];
+
+export function saverSecretKeyShareFromDkgShare(
+    participantId: number,
+    threshold: number,
+    secretShare: Uint8Array
+): Uint8Array;
+
+export function saverGetSharePublicKey(
+    secretKeyShare: Uint8Array,
+    encGens: Uint8Array
+): Uint8Array;
+
+export function saverPartialDecrypt(
+    ciphertext: Uint8Array,
+    secretKeyShare: Uint8Array,
+    encGens: Uint8Array
+): Uint8Array;
+
+export function saverVerifyPartialDecryption(
+    partialDecryption: Uint8Array,
+    ciphertext: Uint8Array,
+    sharePublicKey: Uint8Array,
+    encGens: Uint8Array
+): Required<VerifyResult>;
+
+export function saverCombinePartialDecryptionsUsingSnarkVk(
+    ciphertext: Uint8Array,
+    partialDecryptions: Uint8Array[],
+    threshold: number,
+    sharePublicKeys: Uint8Array[],
+    encGens: Uint8Array,
+    decryptionKey: Uint8Array,
+    snarkVk: Uint8Array,
+    chunkBitSize: number,
+    uncompressedPublicParams: boolean
+): [Uint8Array, Uint8Array];
@@ -41,3 +41,13 @@ export function pedersenCommitmentG2(
     bases: Uint8Array[],
     messages: Uint8Array[],
 ): Uint8Array;
+
+export function setRngSeed(
+    seed: Uint8Array,
+): void;
+
+export function setRngSource(
+    callback: (numBytes: number) => Uint8Array,
+): void;
+
+export function resetRngSource(): void;
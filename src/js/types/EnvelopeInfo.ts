@@ -0,0 +1,12 @@
+/**
+ * Header of an envelope around a serialized object. `type` is the name of the object's type like "BBSPublicKey" and
+ * `typeTag` is its numeric tag in the envelope.
+ */
+export interface EnvelopeInfo {
+  readonly type: string;
+  readonly typeTag: number;
+  readonly version: number;
+  readonly curve: number;
+  readonly compressed: boolean;
+  readonly payloadLength: number;
+}
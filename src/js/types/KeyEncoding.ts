@@ -0,0 +1,29 @@
+/**
+ * Public keys that are a single point and can be encoded as JWK, Multikey or multibase
+ */
+export type PublicKeyType =
+  | "BBSPublicKey"
+  | "BBSPlusPublicKeyG1"
+  | "BBSPlusPublicKeyG2"
+  | "AccumPk";
+
+export interface Jwk {
+  readonly kty: string;
+  readonly crv: string;
+  readonly x: string;
+  readonly kid?: string;
+}
+
+/**
+ * PS public key as a JWK for each of its points, identified by `kid`
+ */
+export interface JwkSet {
+  readonly keys: Jwk[];
+}
+
+export interface Multikey {
+  readonly id?: string;
+  readonly type: "Multikey";
+  readonly controller?: string;
+  readonly publicKeyMultibase: string;
+}
@@ -0,0 +1,30 @@
+export interface StatementDescription {
+    readonly index: number;
+    readonly type: string;
+    readonly revealedMessages?: { [index: string]: Uint8Array };
+    readonly min?: number;
+    readonly max?: number;
+    readonly accumulated?: Uint8Array;
+    readonly commitment?: Uint8Array;
+    readonly chunkBitSize?: number;
+    readonly publicInputs?: Uint8Array[];
+    readonly paramRefs: { [name: string]: number };
+    readonly inlineParams: string[];
+}
+
+export interface ProofSpecDescription {
+    readonly statements: StatementDescription[];
+    readonly witnessEqualities: [number, number][][];
+    readonly setupParams: string[];
+    readonly context?: Uint8Array;
+}
+
+export interface StatementProofDetails {
+    readonly index: number;
+    readonly type: string;
+    readonly revealedMessages?: { [index: string]: Uint8Array };
+    readonly accumulated?: Uint8Array;
+    readonly commitments: { [name: string]: Uint8Array };
+    readonly ciphertext?: Uint8Array;
+    readonly responses: { [witnessIndex: string]: Uint8Array };
+}
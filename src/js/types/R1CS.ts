@@ -10,3 +10,9 @@ export interface R1CS {
     readonly numPrivate: number;
     readonly constraints: Constraint[];
 }
+
+/**
+ * Value of a Circom signal as a field element in bytes like `generateFieldElementFromNumber` returns, a bigint or a
+ * decimal string
+ */
+export type SignalValue = Uint8Array | bigint | string;
@@ -0,0 +1,25 @@
+/**
+ * Groth16 verification key in the format of snarkjs' `verification_key.json`. Field elements are decimal strings.
+ */
+export interface SnarkjsVerifyingKey {
+  readonly protocol: "groth16";
+  readonly curve: string;
+  readonly nPublic: number;
+  readonly vk_alpha_1: string[];
+  readonly vk_beta_2: string[][];
+  readonly vk_gamma_2: string[][];
+  readonly vk_delta_2: string[][];
+  readonly vk_alphabeta_12?: string[][][];
+  readonly IC: string[][];
+}
+
+/**
+ * Groth16 proof in the format of snarkjs' `proof.json`
+ */
+export interface SnarkjsProof {
+  readonly pi_a: string[];
+  readonly pi_b: string[][];
+  readonly pi_c: string[];
+  readonly protocol: "groth16";
+  readonly curve: string;
+}
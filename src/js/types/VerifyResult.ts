@@ -1,4 +1,8 @@
+import { WasmError } from "./WasmError";
+
 export interface VerifyResult {
   readonly verified: boolean;
   readonly error: string;
+  readonly code?: string;
+  readonly category?: WasmError["category"];
 }
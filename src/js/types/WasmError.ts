@@ -0,0 +1,15 @@
+/**
+ * Shape of the `Error` thrown by the wasm functions. `code` is stable and can be matched on while `message` is meant
+ * for humans. `details` has the debug output of the underlying library error, if any.
+ */
+export interface WasmError extends Error {
+  readonly code: string;
+  readonly category:
+    | "deserialization"
+    | "serialization"
+    | "invalid-params"
+    | "verification-failed"
+    | "protocol-state"
+    | "internal";
+  readonly details?: string;
+}
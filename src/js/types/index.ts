@@ -26,6 +26,11 @@ export { PSPoKSigProtocol } from "./PSPoKSigProtocol";
 export { PSPoKSigProof } from "./PSPoKSigProof";
 export { IKeypair } from "./IKeypair";
 export { VerifyResult } from "./VerifyResult";
+export { WasmError } from "./WasmError";
+export { EnvelopeInfo } from "./EnvelopeInfo";
+export { PublicKeyType, Jwk, JwkSet, Multikey } from "./KeyEncoding";
 export { IUniversalAccumulator } from "./IUniversalAccumulator";
 export { INonMembershipWitness } from "./INonMembershipWitness";
-export { LCTerm, LC, Constraint, R1CS } from "./R1CS";
+export { LCTerm, LC, Constraint, R1CS, SignalValue } from "./R1CS";
+export { SnarkjsVerifyingKey, SnarkjsProof } from "./Snarkjs";
+export { StatementDescription, ProofSpecDescription, StatementProofDetails } from "./ProofSpecDescription";
@@ -59,3 +59,18 @@ module.exports.pedersenCommitmentG2 = (bases, messages) => {
     requireWasmInitialized();
     return wasm.pedersenCommitmentG2(bases, messages);
 };
+
+module.exports.setRngSeed = (seed) => {
+    requireWasmInitialized();
+    return wasm.setRngSeed(seed);
+};
+
+module.exports.setRngSource = (callback) => {
+    requireWasmInitialized();
+    return wasm.setRngSource(callback);
+};
+
+module.exports.resetRngSource = () => {
+    requireWasmInitialized();
+    return wasm.resetRngSource();
+};
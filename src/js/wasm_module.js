@@ -34,4 +34,13 @@ module.exports = {
   ...require('./r1cs_wasm'),
   ...require('./frost_dkg_wasm'),
   ...require('./threshold_sig_wasm'),
+  ...require('./issuer_hiding_wasm'),
+  ...require('./handles_wasm'),
+  ...require('./envelope_wasm'),
+  ...require('./key_encoding_wasm'),
+  ...require('./schnorr_wasm'),
+  ...require('./threshold_saver_wasm'),
+  ...require('./dkgith_wasm'),
+  ...require('./ceremony_wasm'),
+  ...require('./groth16_wasm'),
 };
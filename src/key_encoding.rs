@@ -0,0 +1,184 @@
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{
+    accumulator::AccumPk,
+    bbs::BBSPublicKey,
+    bbs_plus::{BBSPlusPublicKeyG1, BBSPlusPublicKeyG2},
+    native::{
+        self,
+        envelope::ArtifactType,
+        from_bytes,
+        key_encoding::{self, Jwk, JwkSet, KeyGroup, Multikey},
+        to_bytes,
+    },
+    ps::PSPublicKey,
+    utils::{from_value, set_panic_hook},
+};
+
+// `key_type` is the name of the public key's type as used in envelopes, i.e. one of "BBSPublicKey",
+// "BBSPlusPublicKeyG1", "BBSPlusPublicKeyG2" and "AccumPk". Public keys are checked to be valid points of the
+// expected group when both exporting and importing.
+
+/// Encode the public key as a JWK like `{kty: "OKP", crv: "BLS12381G2", x: <base64url of key>}`
+#[wasm_bindgen(js_name = publicKeyToJwk)]
+pub fn public_key_to_jwk(public_key: Uint8Array, key_type: String) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let (pk, group) = raw_public_key(public_key, &key_type)?;
+    let jwk = key_encoding::to_jwk(&pk, group)?;
+    serde_wasm_bindgen::to_value(&jwk).map_err(JsValue::from)
+}
+
+/// Decode the public key from a JWK. Throws with code `CURVE_MISMATCH` if the JWK's curve is not of the key type.
+#[wasm_bindgen(js_name = publicKeyFromJwk)]
+pub fn public_key_from_jwk(jwk: JsValue, key_type: String) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let jwk: Jwk = from_value(jwk)?;
+    let group = key_group(&key_type)?;
+    let pk = key_encoding::from_jwk(&jwk, group)?;
+    typed_public_key(&pk, &key_type)
+}
+
+/// Encode the public key as a W3C Multikey verification method with optional `id` and `controller`
+#[wasm_bindgen(js_name = publicKeyToMultikey)]
+pub fn public_key_to_multikey(
+    public_key: Uint8Array,
+    key_type: String,
+    id: Option<String>,
+    controller: Option<String>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let (pk, group) = raw_public_key(public_key, &key_type)?;
+    let multikey = key_encoding::to_multikey(&pk, group, id, controller)?;
+    serde_wasm_bindgen::to_value(&multikey).map_err(JsValue::from)
+}
+
+/// Decode the public key from a W3C Multikey. Throws with code `CURVE_MISMATCH` if the multicodec code is not of
+/// the key type.
+#[wasm_bindgen(js_name = publicKeyFromMultikey)]
+pub fn public_key_from_multikey(
+    multikey: JsValue,
+    key_type: String,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let multikey: Multikey = from_value(multikey)?;
+    let group = key_group(&key_type)?;
+    let pk = key_encoding::from_multikey(&multikey, group)?;
+    typed_public_key(&pk, &key_type)
+}
+
+/// Encode the public key as multibase base58btc with the multicodec prefix, as used in `publicKeyMultibase`
+#[wasm_bindgen(js_name = publicKeyToMultibase)]
+pub fn public_key_to_multibase(
+    public_key: Uint8Array,
+    key_type: String,
+) -> Result<String, JsValue> {
+    set_panic_hook();
+    let (pk, group) = raw_public_key(public_key, &key_type)?;
+    Ok(key_encoding::to_multibase(&pk, group)?)
+}
+
+#[wasm_bindgen(js_name = publicKeyFromMultibase)]
+pub fn public_key_from_multibase(encoded: String, key_type: String) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let group = key_group(&key_type)?;
+    let pk = key_encoding::from_multibase(&encoded, group)?;
+    typed_public_key(&pk, &key_type)
+}
+
+/// Encode the PS public key as a JWK Set like `{keys: [{kty: "OKP", crv: "BLS12381G2", x: .., kid: "alpha_tilde"},
+/// ...]}` with a JWK for each point of the key
+#[wasm_bindgen(js_name = psPublicKeyToJwkSet)]
+pub fn ps_public_key_to_jwk_set(public_key: Uint8Array) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let pk = obj_from_uint8array!(PSPublicKey, public_key, false, "PSPublicKey");
+    let jwk_set = key_encoding::ps_public_key_to_jwk_set(&pk)?;
+    serde_wasm_bindgen::to_value(&jwk_set).map_err(JsValue::from)
+}
+
+/// Decode the PS public key from a JWK Set. Throws with code `CURVE_MISMATCH` if a point's curve is not the
+/// expected one.
+#[wasm_bindgen(js_name = psPublicKeyFromJwkSet)]
+pub fn ps_public_key_from_jwk_set(jwk_set: JsValue) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let jwk_set: JwkSet = from_value(jwk_set)?;
+    let pk = key_encoding::ps_public_key_from_jwk_set(&jwk_set)?;
+    Ok(obj_to_uint8array!(&pk, false, "PSPublicKey"))
+}
+
+fn parse_key_type(key_type: &str) -> Result<ArtifactType, JsValue> {
+    match ArtifactType::from_name(key_type) {
+        Some(
+            t @ (ArtifactType::BBSPublicKey
+            | ArtifactType::BBSPlusPublicKeyG1
+            | ArtifactType::BBSPlusPublicKeyG2
+            | ArtifactType::AccumPk),
+        ) => Ok(t),
+        Some(ArtifactType::PSPublicKey) => Err(native::Error::invalid_argument(
+            "PS public keys have several points so they can only be encoded as a JWK Set with psPublicKeyToJwkSet",
+        )
+        .into()),
+        _ => Err(native::Error::invalid_argument(format!(
+            "Unsupported public key type {}",
+            key_type
+        ))
+        .into()),
+    }
+}
+
+fn key_group(key_type: &str) -> Result<KeyGroup, JsValue> {
+    Ok(match parse_key_type(key_type)? {
+        ArtifactType::BBSPlusPublicKeyG1 => KeyGroup::G1,
+        _ => KeyGroup::G2,
+    })
+}
+
+/// Compressed bytes of the public key, which can be in an envelope, after checking that it's a valid key
+fn raw_public_key(public_key: Uint8Array, key_type: &str) -> Result<(Vec<u8>, KeyGroup), JsValue> {
+    let pk = match parse_key_type(key_type)? {
+        ArtifactType::BBSPublicKey => to_bytes(&obj_from_uint8array!(
+            BBSPublicKey,
+            public_key,
+            false,
+            "BBSPublicKey"
+        ))?,
+        ArtifactType::BBSPlusPublicKeyG1 => to_bytes(&obj_from_uint8array!(
+            BBSPlusPublicKeyG1,
+            public_key,
+            false,
+            "BBSPlusPublicKeyG1"
+        ))?,
+        ArtifactType::BBSPlusPublicKeyG2 => to_bytes(&obj_from_uint8array!(
+            BBSPlusPublicKeyG2,
+            public_key,
+            false,
+            "BBSPlusPublicKeyG2"
+        ))?,
+        _ => to_bytes(&obj_from_uint8array!(AccumPk, public_key, false, "AccumPk"))?,
+    };
+    Ok((pk, key_group(key_type)?))
+}
+
+/// Public key as returned by the key generation functions after checking that it's a valid key
+fn typed_public_key(public_key: &[u8], key_type: &str) -> Result<Uint8Array, JsValue> {
+    let invalid = |e: native::Error| e.context(format!("Invalid {}", key_type));
+    Ok(match parse_key_type(key_type)? {
+        ArtifactType::BBSPublicKey => {
+            let pk: BBSPublicKey = from_bytes(public_key).map_err(invalid)?;
+            obj_to_uint8array!(&pk, false, "BBSPublicKey")
+        }
+        ArtifactType::BBSPlusPublicKeyG1 => {
+            let pk: BBSPlusPublicKeyG1 = from_bytes(public_key).map_err(invalid)?;
+            obj_to_uint8array!(&pk, false, "BBSPlusPublicKeyG1")
+        }
+        ArtifactType::BBSPlusPublicKeyG2 => {
+            let pk: BBSPlusPublicKeyG2 = from_bytes(public_key).map_err(invalid)?;
+            obj_to_uint8array!(&pk, false, "BBSPlusPublicKeyG2")
+        }
+        _ => {
+            let pk: AccumPk = from_bytes(public_key).map_err(invalid)?;
+            obj_to_uint8array!(&pk, false, "AccumPk")
+        }
+    })
+}
@@ -24,16 +24,26 @@ pub mod accumulator;
 pub mod bbs;
 pub mod bbs_plus;
 pub mod bound_check;
+pub mod ceremony;
 pub mod common;
 pub mod composite_proof_system;
+pub mod dkgith;
+pub mod envelope;
 pub mod frost_dkg;
+pub mod groth16;
+pub mod handles;
+pub mod issuer_hiding;
+pub mod key_encoding;
 pub mod legosnark;
+pub mod native;
 pub mod ps;
 pub mod r1cs;
 pub mod saver;
+pub mod schnorr;
+pub mod threshold_saver;
 pub mod threshold_sig;
 
 // Trying to keep types at one place so changing the curve is easier
-pub(crate) type Fr = <Bls12_381 as Pairing>::ScalarField;
-pub(crate) type G1Affine = <Bls12_381 as Pairing>::G1Affine;
-pub(crate) type G2Affine = <Bls12_381 as Pairing>::G2Affine;
+pub type Fr = <Bls12_381 as Pairing>::ScalarField;
+pub type G1Affine = <Bls12_381 as Pairing>::G1Affine;
+pub type G2Affine = <Bls12_381 as Pairing>::G2Affine;
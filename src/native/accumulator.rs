@@ -0,0 +1,175 @@
+use blake2::Blake2b512;
+use vb_accumulator::prelude::Accumulator;
+use zeroize::Zeroize;
+
+use crate::{
+    accumulator::{
+        AccumKeypair, AccumPk, AccumSetupParams, AccumSk, MembershipWit, NonMembershipWit,
+        PositiveAccum, UniversalAccum,
+    },
+    native::{schnorr::ProofOfPossession, Result},
+    utils::random_bytes,
+    Fr, G1Affine, G2Affine,
+};
+
+/// Domain separator for proofs of possession of accumulator secret keys
+pub const POP_DOMAIN: &[u8] = b"ACCUMULATOR-PUBLIC-KEY-POP";
+
+/// Generate accumulator params. A random label is used if `label` isn't given.
+pub fn generate_params(label: Option<&[u8]>) -> Result<AccumSetupParams> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(AccumSetupParams::new::<Blake2b512>(&label))
+}
+
+pub fn generate_secret_key(seed: Option<&[u8]>) -> Result<AccumSk> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let sk = AccumSk::generate_using_seed::<Blake2b512>(&seed);
+    seed.zeroize();
+    Ok(sk)
+}
+
+pub fn generate_public_key(secret_key: &AccumSk, params: &AccumSetupParams) -> AccumPk {
+    AccumKeypair::public_key_from_secret_key(secret_key, params)
+}
+
+/// Proof of possession of the secret key of the public key generated by `generate_public_key`, bound to `context`
+pub fn generate_proof_of_possession(
+    secret_key: &AccumSk,
+    params: &AccumSetupParams,
+    context: &[u8],
+) -> Result<ProofOfPossession<G2Affine>> {
+    let pk = generate_public_key(secret_key, params);
+    ProofOfPossession::new(
+        &[secret_key.0],
+        &[params.P_tilde],
+        &[pk.0],
+        POP_DOMAIN,
+        context,
+    )
+}
+
+pub fn verify_proof_of_possession(
+    proof: &ProofOfPossession<G2Affine>,
+    public_key: &AccumPk,
+    params: &AccumSetupParams,
+    context: &[u8],
+) -> Result<()> {
+    proof.verify(&[params.P_tilde], &[public_key.0], POP_DOMAIN, context)
+}
+
+pub fn generate_keypair(params: &AccumSetupParams, seed: Option<&[u8]>) -> Result<AccumKeypair> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let keypair = AccumKeypair::generate_using_seed::<Blake2b512>(&seed, params);
+    seed.zeroize();
+    Ok(keypair)
+}
+
+pub fn positive_accumulator_initialize(params: &AccumSetupParams) -> PositiveAccum {
+    PositiveAccum::initialize(params)
+}
+
+pub fn positive_accumulator_add(
+    accumulator: &PositiveAccum,
+    element: &Fr,
+    secret_key: &AccumSk,
+) -> PositiveAccum {
+    PositiveAccum::from_value(accumulator.compute_new_post_add(element, secret_key))
+}
+
+pub fn positive_accumulator_remove(
+    accumulator: &PositiveAccum,
+    element: &Fr,
+    secret_key: &AccumSk,
+) -> PositiveAccum {
+    PositiveAccum::from_value(accumulator.compute_new_post_remove(element, secret_key))
+}
+
+pub fn positive_accumulator_membership_witness(
+    accumulator: &PositiveAccum,
+    element: &Fr,
+    secret_key: &AccumSk,
+) -> MembershipWit {
+    accumulator.compute_membership_witness(element, secret_key)
+}
+
+/// Verify the membership witness against the accumulated value of a positive accumulator
+pub fn positive_accumulator_verify_membership(
+    accumulated: G1Affine,
+    element: &Fr,
+    witness: &MembershipWit,
+    public_key: &AccumPk,
+    params: &AccumSetupParams,
+) -> bool {
+    PositiveAccum::from_accumulated(accumulated)
+        .verify_membership(element, witness, public_key, params)
+}
+
+/// Initialize a universal accumulator given the product `f_v` of the initial elements' terms
+pub fn universal_accumulator_initialize_given_f_v(
+    f_v: Fr,
+    params: &AccumSetupParams,
+    max_size: u64,
+) -> UniversalAccum {
+    UniversalAccum::initialize_given_f_V(f_v, params, max_size)
+}
+
+pub fn universal_accumulator_add(
+    accumulator: &UniversalAccum,
+    element: &Fr,
+    secret_key: &AccumSk,
+) -> UniversalAccum {
+    let (f_v, v) = accumulator.compute_new_post_add(element, secret_key);
+    accumulator.get_updated(f_v, v)
+}
+
+pub fn universal_accumulator_remove(
+    accumulator: &UniversalAccum,
+    element: &Fr,
+    secret_key: &AccumSk,
+) -> UniversalAccum {
+    let (f_v, v) = accumulator.compute_new_post_remove(element, secret_key);
+    accumulator.get_updated(f_v, v)
+}
+
+pub fn universal_accumulator_membership_witness(
+    accumulator: &UniversalAccum,
+    element: &Fr,
+    secret_key: &AccumSk,
+) -> MembershipWit {
+    accumulator.compute_membership_witness(element, secret_key)
+}
+
+/// Compute the non-membership witness of `non_member` where `d` is computed from the members
+pub fn universal_accumulator_non_membership_witness(
+    accumulator: &UniversalAccum,
+    d: Fr,
+    non_member: &Fr,
+    secret_key: &AccumSk,
+    params: &AccumSetupParams,
+) -> Result<NonMembershipWit> {
+    Ok(accumulator.compute_non_membership_witness_given_d(d, non_member, secret_key, params)?)
+}
+
+/// Verify the membership witness against the accumulated value of a universal accumulator
+pub fn universal_accumulator_verify_membership(
+    accumulated: G1Affine,
+    element: &Fr,
+    witness: &MembershipWit,
+    public_key: &AccumPk,
+    params: &AccumSetupParams,
+) -> bool {
+    UniversalAccum::from_accumulated(accumulated)
+        .verify_membership(element, witness, public_key, params)
+}
+
+/// Verify the non-membership witness against the accumulated value of a universal accumulator
+pub fn universal_accumulator_verify_non_membership(
+    accumulated: G1Affine,
+    non_member: &Fr,
+    witness: &NonMembershipWit,
+    public_key: &AccumPk,
+    params: &AccumSetupParams,
+) -> bool {
+    UniversalAccum::from_accumulated(accumulated)
+        .verify_non_membership(non_member, witness, public_key, params)
+}
@@ -0,0 +1,152 @@
+use ark_bls12_381::Bls12_381;
+use ark_std::collections::{BTreeMap, BTreeSet};
+use bbs_plus::{prelude::KeypairG2, proof::MessageOrBlinding};
+use blake2::Blake2b512;
+use zeroize::Zeroize;
+
+use crate::{
+    bbs::{
+        BBSPoKOfSigProof, BBSPoKOfSigProtocol, BBSPublicKey, BBSSecretKey, BBSSigParams,
+        BBSSignature,
+    },
+    native::{schnorr::ProofOfPossession, Result},
+    utils::{get_seeded_rng, random_bytes},
+    Fr, G1Affine, G2Affine,
+};
+
+/// Domain separator for proofs of possession of BBS secret keys
+pub const POP_DOMAIN: &[u8] = b"BBS-PUBLIC-KEY-POP";
+
+/// Generate signature params for `message_count` messages. A random label is used if `label` isn't given.
+pub fn generate_params(message_count: u32, label: Option<&[u8]>) -> Result<BBSSigParams> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(BBSSigParams::new::<Blake2b512>(&label, message_count))
+}
+
+pub fn generate_secret_key(seed: Option<&[u8]>) -> Result<BBSSecretKey> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let sk = BBSSecretKey::generate_using_seed::<Blake2b512>(&seed);
+    seed.zeroize();
+    Ok(sk)
+}
+
+pub fn generate_public_key(secret_key: &BBSSecretKey, params: &BBSSigParams) -> BBSPublicKey {
+    BBSPublicKey::generate_using_secret_key_and_bbs23_params(secret_key, params)
+}
+
+/// Proof of possession of the secret key of the public key generated by `generate_public_key`, bound to `context`
+pub fn generate_proof_of_possession(
+    secret_key: &BBSSecretKey,
+    params: &BBSSigParams,
+    context: &[u8],
+) -> Result<ProofOfPossession<G2Affine>> {
+    let pk = generate_public_key(secret_key, params);
+    ProofOfPossession::new(&[secret_key.0], &[params.g2], &[pk.0], POP_DOMAIN, context)
+}
+
+pub fn verify_proof_of_possession(
+    proof: &ProofOfPossession<G2Affine>,
+    public_key: &BBSPublicKey,
+    params: &BBSSigParams,
+    context: &[u8],
+) -> Result<()> {
+    proof.verify(&[params.g2], &[public_key.0], POP_DOMAIN, context)
+}
+
+pub fn generate_keypair(
+    params: &BBSSigParams,
+    seed: Option<&[u8]>,
+) -> Result<KeypairG2<Bls12_381>> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let keypair = KeypairG2::generate_using_seed_and_bbs23_params::<Blake2b512>(&seed, params);
+    seed.zeroize();
+    Ok(keypair)
+}
+
+/// Commit to the messages at the given indices to request a blind signature
+pub fn commit_to_messages(
+    messages_to_commit: &BTreeMap<usize, Fr>,
+    params: &BBSSigParams,
+) -> Result<G1Affine> {
+    let msgs = messages_to_commit
+        .iter()
+        .map(|(i, m)| (*i, m))
+        .collect::<BTreeMap<_, _>>();
+    Ok(params.commit_to_messages(msgs)?)
+}
+
+pub fn sign(
+    messages: &[Fr],
+    secret_key: &BBSSecretKey,
+    params: &BBSSigParams,
+) -> Result<BBSSignature> {
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSSignature::new(&mut rng, messages, secret_key, params)?)
+}
+
+/// Sign the committed messages and the `uncommitted_messages`
+pub fn blind_sign(
+    commitment: &G1Affine,
+    uncommitted_messages: &BTreeMap<usize, Fr>,
+    secret_key: &BBSSecretKey,
+    params: &BBSSigParams,
+) -> Result<BBSSignature> {
+    let msgs = uncommitted_messages
+        .iter()
+        .map(|(i, m)| (*i, m))
+        .collect::<BTreeMap<_, _>>();
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSSignature::new_with_committed_messages(
+        &mut rng, commitment, msgs, secret_key, params,
+    )?)
+}
+
+pub fn verify(
+    messages: &[Fr],
+    signature: &BBSSignature,
+    public_key: BBSPublicKey,
+    params: BBSSigParams,
+) -> Result<()> {
+    Ok(signature.verify(messages, public_key, params)?)
+}
+
+/// Start a proof of knowledge of `signature`. Messages at `revealed_indices` are revealed and the others are hidden,
+/// using the blinding in `blindings` if given else a random one.
+pub fn initialize_proof_of_knowledge_of_signature(
+    signature: &BBSSignature,
+    params: &BBSSigParams,
+    messages: &[Fr],
+    mut blindings: BTreeMap<usize, Fr>,
+    revealed_indices: &BTreeSet<usize>,
+) -> Result<BBSPoKOfSigProtocol> {
+    let msg_iter = messages.iter().enumerate().map(|(idx, message)| {
+        if revealed_indices.contains(&idx) {
+            MessageOrBlinding::RevealMessage(message)
+        } else if let Some(blinding) = blindings.remove(&idx) {
+            MessageOrBlinding::BlindMessageWithConcreteBlinding { message, blinding }
+        } else {
+            MessageOrBlinding::BlindMessageRandomly(message)
+        }
+    });
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSPoKOfSigProtocol::init(
+        &mut rng, signature, params, msg_iter,
+    )?)
+}
+
+pub fn generate_proof_of_knowledge_of_signature(
+    protocol: BBSPoKOfSigProtocol,
+    challenge: &Fr,
+) -> Result<BBSPoKOfSigProof> {
+    Ok(protocol.gen_proof(challenge)?)
+}
+
+pub fn verify_proof_of_knowledge_of_signature(
+    proof: &BBSPoKOfSigProof,
+    revealed_messages: &BTreeMap<usize, Fr>,
+    challenge: &Fr,
+    public_key: BBSPublicKey,
+    params: BBSSigParams,
+) -> Result<()> {
+    Ok(proof.verify(revealed_messages, challenge, public_key, params)?)
+}
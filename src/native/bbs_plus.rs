@@ -0,0 +1,278 @@
+use ark_bls12_381::Bls12_381;
+use ark_std::collections::{BTreeMap, BTreeSet};
+use bbs_plus::{
+    prelude::{KeypairG1, KeypairG2},
+    proof::MessageOrBlinding,
+};
+use blake2::Blake2b512;
+use zeroize::Zeroize;
+
+use crate::{
+    bbs_plus::{
+        BBSPlusPoKOfSigProof, BBSPlusPoKOfSigProtocol, BBSPlusPublicKeyG1, BBSPlusPublicKeyG2,
+        BBSPlusSigG1, BBSPlusSigG2, BBSPlusSigParamsG1, BBSPlusSigParamsG2, BBSPlusSigningKey,
+    },
+    native::{schnorr::ProofOfPossession, Result},
+    utils::{get_seeded_rng, random_bytes},
+    Fr, G1Affine, G2Affine,
+};
+
+/// Domain separators for proofs of possession of BBS+ secret keys, different for public keys in G1 and G2
+pub const POP_DOMAIN_G1: &[u8] = b"BBS-PLUS-PUBLIC-KEY-G1-POP";
+pub const POP_DOMAIN_G2: &[u8] = b"BBS-PLUS-PUBLIC-KEY-G2-POP";
+
+/// Generate params for signatures in group G1 for `message_count` messages. A random label is used if `label`
+/// isn't given.
+pub fn generate_params_g1(message_count: u32, label: Option<&[u8]>) -> Result<BBSPlusSigParamsG1> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(BBSPlusSigParamsG1::new::<Blake2b512>(&label, message_count))
+}
+
+/// Generate params for signatures in group G2 for `message_count` messages. A random label is used if `label`
+/// isn't given.
+pub fn generate_params_g2(message_count: u32, label: Option<&[u8]>) -> Result<BBSPlusSigParamsG2> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(BBSPlusSigParamsG2::new::<Blake2b512>(&label, message_count))
+}
+
+pub fn generate_secret_key(seed: Option<&[u8]>) -> Result<BBSPlusSigningKey> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let sk = BBSPlusSigningKey::generate_using_seed::<Blake2b512>(&seed);
+    seed.zeroize();
+    Ok(sk)
+}
+
+/// Public key in group G1 for verifying signatures in group G2
+pub fn generate_public_key_g1(
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG2,
+) -> BBSPlusPublicKeyG1 {
+    BBSPlusPublicKeyG1::generate_using_secret_key(secret_key, params)
+}
+
+/// Public key in group G2 for verifying signatures in group G1
+pub fn generate_public_key_g2(
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG1,
+) -> BBSPlusPublicKeyG2 {
+    BBSPlusPublicKeyG2::generate_using_secret_key(secret_key, params)
+}
+
+/// Proof of possession of the secret key of the public key generated by `generate_public_key_g1`, bound to
+/// `context`
+pub fn generate_proof_of_possession_g1(
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG2,
+    context: &[u8],
+) -> Result<ProofOfPossession<G1Affine>> {
+    let pk = generate_public_key_g1(secret_key, params);
+    ProofOfPossession::new(
+        &[secret_key.0],
+        &[params.g2],
+        &[pk.0],
+        POP_DOMAIN_G1,
+        context,
+    )
+}
+
+pub fn verify_proof_of_possession_g1(
+    proof: &ProofOfPossession<G1Affine>,
+    public_key: &BBSPlusPublicKeyG1,
+    params: &BBSPlusSigParamsG2,
+    context: &[u8],
+) -> Result<()> {
+    proof.verify(&[params.g2], &[public_key.0], POP_DOMAIN_G1, context)
+}
+
+/// Proof of possession of the secret key of the public key generated by `generate_public_key_g2`, bound to
+/// `context`
+pub fn generate_proof_of_possession_g2(
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG1,
+    context: &[u8],
+) -> Result<ProofOfPossession<G2Affine>> {
+    let pk = generate_public_key_g2(secret_key, params);
+    ProofOfPossession::new(
+        &[secret_key.0],
+        &[params.g2],
+        &[pk.0],
+        POP_DOMAIN_G2,
+        context,
+    )
+}
+
+pub fn verify_proof_of_possession_g2(
+    proof: &ProofOfPossession<G2Affine>,
+    public_key: &BBSPlusPublicKeyG2,
+    params: &BBSPlusSigParamsG1,
+    context: &[u8],
+) -> Result<()> {
+    proof.verify(&[params.g2], &[public_key.0], POP_DOMAIN_G2, context)
+}
+
+pub fn generate_keypair_g1(
+    params: &BBSPlusSigParamsG2,
+    seed: Option<&[u8]>,
+) -> Result<KeypairG1<Bls12_381>> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let keypair = KeypairG1::generate_using_seed::<Blake2b512>(&seed, params);
+    seed.zeroize();
+    Ok(keypair)
+}
+
+pub fn generate_keypair_g2(
+    params: &BBSPlusSigParamsG1,
+    seed: Option<&[u8]>,
+) -> Result<KeypairG2<Bls12_381>> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let keypair = KeypairG2::generate_using_seed::<Blake2b512>(&seed, params);
+    seed.zeroize();
+    Ok(keypair)
+}
+
+/// Commit to the messages at the given indices with `blinding` to request a blind signature in group G1
+pub fn commit_to_messages_in_g1(
+    messages_to_commit: &BTreeMap<usize, Fr>,
+    blinding: &Fr,
+    params: &BBSPlusSigParamsG1,
+) -> Result<G1Affine> {
+    let msgs = messages_to_commit
+        .iter()
+        .map(|(i, m)| (*i, m))
+        .collect::<BTreeMap<_, _>>();
+    Ok(params.commit_to_messages(msgs, blinding)?)
+}
+
+/// Commit to the messages at the given indices with `blinding` to request a blind signature in group G2
+pub fn commit_to_messages_in_g2(
+    messages_to_commit: &BTreeMap<usize, Fr>,
+    blinding: &Fr,
+    params: &BBSPlusSigParamsG2,
+) -> Result<G2Affine> {
+    let msgs = messages_to_commit
+        .iter()
+        .map(|(i, m)| (*i, m))
+        .collect::<BTreeMap<_, _>>();
+    Ok(params.commit_to_messages(msgs, blinding)?)
+}
+
+pub fn sign_g1(
+    messages: &[Fr],
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG1,
+) -> Result<BBSPlusSigG1> {
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSPlusSigG1::new(&mut rng, messages, secret_key, params)?)
+}
+
+pub fn sign_g2(
+    messages: &[Fr],
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG2,
+) -> Result<BBSPlusSigG2> {
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSPlusSigG2::new(&mut rng, messages, secret_key, params)?)
+}
+
+/// Sign the committed messages and the `uncommitted_messages`. The signature needs to be unblinded with
+/// `unblind_signature_g1`.
+pub fn blind_sign_g1(
+    commitment: &G1Affine,
+    uncommitted_messages: &BTreeMap<usize, Fr>,
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG1,
+) -> Result<BBSPlusSigG1> {
+    let msgs = uncommitted_messages
+        .iter()
+        .map(|(i, m)| (*i, m))
+        .collect::<BTreeMap<_, _>>();
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSPlusSigG1::new_with_committed_messages(
+        &mut rng, commitment, msgs, secret_key, params,
+    )?)
+}
+
+/// Sign the committed messages and the `uncommitted_messages`. The signature needs to be unblinded with
+/// `unblind_signature_g2`.
+pub fn blind_sign_g2(
+    commitment: &G2Affine,
+    uncommitted_messages: &BTreeMap<usize, Fr>,
+    secret_key: &BBSPlusSigningKey,
+    params: &BBSPlusSigParamsG2,
+) -> Result<BBSPlusSigG2> {
+    let msgs = uncommitted_messages
+        .iter()
+        .map(|(i, m)| (*i, m))
+        .collect::<BTreeMap<_, _>>();
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSPlusSigG2::new_with_committed_messages(
+        &mut rng, commitment, msgs, secret_key, params,
+    )?)
+}
+
+pub fn unblind_signature_g1(blind_signature: BBSPlusSigG1, blinding: &Fr) -> BBSPlusSigG1 {
+    blind_signature.unblind(blinding)
+}
+
+pub fn unblind_signature_g2(blind_signature: BBSPlusSigG2, blinding: &Fr) -> BBSPlusSigG2 {
+    blind_signature.unblind(blinding)
+}
+
+pub fn verify_g1(
+    messages: &[Fr],
+    signature: &BBSPlusSigG1,
+    public_key: BBSPlusPublicKeyG2,
+    params: BBSPlusSigParamsG1,
+) -> Result<()> {
+    Ok(signature.verify(messages, public_key, params)?)
+}
+
+pub fn verify_g2(
+    messages: &[Fr],
+    signature: &BBSPlusSigG2,
+    public_key: &BBSPlusPublicKeyG1,
+    params: &BBSPlusSigParamsG2,
+) -> Result<()> {
+    Ok(signature.verify(messages, public_key, params)?)
+}
+
+/// Start a proof of knowledge of `signature`. Messages at `revealed_indices` are revealed and the others are hidden,
+/// using the blinding in `blindings` if given else a random one.
+pub fn initialize_proof_of_knowledge_of_signature(
+    signature: &BBSPlusSigG1,
+    params: &BBSPlusSigParamsG1,
+    messages: &[Fr],
+    mut blindings: BTreeMap<usize, Fr>,
+    revealed_indices: &BTreeSet<usize>,
+) -> Result<BBSPlusPoKOfSigProtocol> {
+    let msg_iter = messages.iter().enumerate().map(|(idx, message)| {
+        if revealed_indices.contains(&idx) {
+            MessageOrBlinding::RevealMessage(message)
+        } else if let Some(blinding) = blindings.remove(&idx) {
+            MessageOrBlinding::BlindMessageWithConcreteBlinding { message, blinding }
+        } else {
+            MessageOrBlinding::BlindMessageRandomly(message)
+        }
+    });
+    let mut rng = get_seeded_rng()?;
+    Ok(BBSPlusPoKOfSigProtocol::init(
+        &mut rng, signature, params, msg_iter,
+    )?)
+}
+
+pub fn generate_proof_of_knowledge_of_signature(
+    protocol: BBSPlusPoKOfSigProtocol,
+    challenge: &Fr,
+) -> Result<BBSPlusPoKOfSigProof> {
+    Ok(protocol.gen_proof(challenge)?)
+}
+
+pub fn verify_proof_of_knowledge_of_signature(
+    proof: &BBSPlusPoKOfSigProof,
+    revealed_messages: &BTreeMap<usize, Fr>,
+    challenge: &Fr,
+    public_key: BBSPlusPublicKeyG2,
+    params: BBSPlusSigParamsG1,
+) -> Result<()> {
+    Ok(proof.verify(revealed_messages, challenge, public_key, params)?)
+}
@@ -0,0 +1,97 @@
+//! Reader for the binary file format of Circom and snarkjs, used by `.r1cs` files and zkeys. A file has a magic
+//! string, a version and sections, each an id and a size followed by its data. Numbers are little-endian.
+
+use ark_ff::{BigInt, PrimeField};
+use ark_std::collections::BTreeMap;
+
+use crate::{
+    native::{self, Error, ErrorCode},
+    Fr,
+};
+
+pub(crate) struct BinFile<'a> {
+    pub version: u32,
+    sections: BTreeMap<u32, &'a [u8]>,
+}
+
+impl<'a> BinFile<'a> {
+    /// `name` is the kind of file, used in errors
+    pub fn read(bytes: &'a [u8], magic: &[u8; 4], name: &str) -> native::Result<Self> {
+        let mut reader = BinReader(bytes);
+        if reader.bytes(4)? != magic {
+            return Err(Error::new(
+                ErrorCode::DeserializationFailed,
+                format!("Bytes are not a {}", name),
+            ));
+        }
+        let version = reader.u32()?;
+        let mut sections = BTreeMap::new();
+        for _ in 0..reader.u32()? {
+            let id = reader.u32()?;
+            let size = usize::try_from(reader.u64()?).map_err(|_| truncated())?;
+            if sections.insert(id, reader.bytes(size)?).is_some() {
+                return Err(Error::new(
+                    ErrorCode::DeserializationFailed,
+                    format!("Section {} is repeated", id),
+                ));
+            }
+        }
+        Ok(Self { version, sections })
+    }
+
+    pub fn has_section(&self, id: u32) -> bool {
+        self.sections.contains_key(&id)
+    }
+
+    pub fn section(&self, id: u32) -> native::Result<BinReader<'a>> {
+        self.sections.get(&id).map(|s| BinReader(s)).ok_or_else(|| {
+            Error::new(
+                ErrorCode::DeserializationFailed,
+                format!("Section {} is missing", id),
+            )
+        })
+    }
+}
+
+pub(crate) struct BinReader<'a>(pub &'a [u8]);
+
+impl<'a> BinReader<'a> {
+    pub fn bytes(&mut self, n: usize) -> native::Result<&'a [u8]> {
+        if self.0.len() < n {
+            return Err(truncated());
+        }
+        let (b, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(b)
+    }
+
+    pub fn u32(&mut self) -> native::Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub fn u64(&mut self) -> native::Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    pub fn big_int<const N: usize>(&mut self) -> native::Result<BigInt<N>> {
+        let mut limbs = [0u64; N];
+        for l in limbs.iter_mut() {
+            *l = self.u64()?;
+        }
+        Ok(BigInt(limbs))
+    }
+
+    /// A scalar field element that isn't in Montgomery form
+    pub fn fr(&mut self) -> native::Result<Fr> {
+        Fr::from_bigint(self.big_int()?).ok_or_else(|| {
+            Error::new(
+                ErrorCode::DeserializationFailed,
+                "Field element isn't less than the modulus",
+            )
+        })
+    }
+}
+
+fn truncated() -> Error {
+    Error::new(ErrorCode::DeserializationFailed, "Unexpected end of bytes")
+}
@@ -0,0 +1,442 @@
+//! Phase 2 of a multi-party setup ceremony for the Groth16 proving keys of SAVER and the LegoGroth16 proving keys of
+//! bound checks and R1CS circuits. The trapdoors `tau`, `alpha` and `beta` of a key come from the powers of tau of
+//! phase 1 and the circuit specific trapdoors `gamma`, `delta` and, for LegoGroth16, `eta` are made secret here.
+//! The initial key is created from the powers of tau with those set to 1 (see `powers_of_tau`) so anyone can create
+//! it and no one knows any trapdoor of it that phase 1 doesn't hide. Each contributor in turn multiplies `gamma`,
+//! `delta` and `eta` by secret random values that it then forgets, updating the elements of the key that depend on
+//! them, and adds to the transcript the updated elements it multiplied with a proof of knowledge of the values. The
+//! key is secure if one contributor to each phase forgot its values. Anyone can verify each contribution from the
+//! keys before and after it, and the whole ceremony from the initial key, the final key and the transcript.
+//!
+//! A key created some other way, like with `boundCheckSnarkSetup`, can also be updated but whoever created it knows
+//! its `tau`, `alpha` and `beta` and must still be trusted to have forgotten them.
+
+use ark_bls12_381::Bls12_381;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::UniformRand;
+use blake2::Blake2b512;
+use schnorr_pok::compute_random_oracle_challenge;
+use zeroize::Zeroize;
+
+use crate::{
+    legosnark::LegoProvingKey,
+    native::{self, schnorr::ProofOfPossession, Error, ErrorCode},
+    saver::SaverSnarkPk,
+    utils::get_seeded_rng,
+    Fr, G1Affine, G2Affine,
+};
+
+type G1 = <Bls12_381 as Pairing>::G1;
+
+/// Domain separator for the proofs of knowledge in the contributions
+pub const CEREMONY_DOMAIN: &[u8] = b"SNARK-PHASE2-CEREMONY";
+
+/// Domain separator for the coefficients of the random linear combinations when verifying keys
+pub const LINEAR_COMBINATION_DOMAIN: &[u8] = b"SNARK-SETUP-LINEAR-COMBINATION";
+
+/// A contribution to the ceremony. `trapdoors_g1` and `gamma_g2` are the elements of the key after the contribution
+/// that it multiplied by its values, and the proofs prove knowledge of those values, bound to the contributions
+/// before it.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Contribution {
+    pub trapdoors_g1: Vec<G1Affine>,
+    pub gamma_g2: G2Affine,
+    pub proof_g1: ProofOfPossession<G1Affine>,
+    pub proof_g2: ProofOfPossession<G2Affine>,
+}
+
+/// Contributions to the ceremony in the order they were made
+#[derive(Clone, Debug, Default, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CeremonyTranscript {
+    pub contributions: Vec<Contribution>,
+}
+
+/// A proving key that can be updated in the ceremony
+pub trait Phase2Key: Clone + PartialEq {
+    /// Elements of the key in G1 that a contribution multiplies by one of its values each. The first is
+    /// `delta * G1`.
+    fn trapdoors_g1(&self) -> Vec<G1Affine>;
+
+    fn gamma_g2(&self) -> G2Affine;
+
+    fn delta_g2(&self) -> G2Affine;
+
+    /// Multiply the elements of `trapdoors_g1` by `values` and `gamma` by `gamma`, updating the elements that
+    /// depend on them
+    fn update(&mut self, values: &[Fr], gamma: &Fr);
+
+    /// The key without the elements that depend on the updated trapdoors, to check that a contribution changed
+    /// nothing else
+    fn without_trapdoors(&self) -> Self;
+
+    /// Check that `new` is `old` with its trapdoors updated consistently, given that the elements of
+    /// `trapdoors_g1` and `gamma_g2` were multiplied by known values
+    fn is_updated(old: &Self, new: &Self) -> native::Result<bool>;
+}
+
+impl Phase2Key for LegoProvingKey {
+    /// `delta * G1` and `eta / gamma * G1`. `eta` is multiplied by the value for `eta / gamma` and by `gamma`'s.
+    fn trapdoors_g1(&self) -> Vec<G1Affine> {
+        vec![self.common.delta_g1, self.vk.eta_gamma_inv_g1]
+    }
+
+    fn gamma_g2(&self) -> G2Affine {
+        self.vk.gamma_g2
+    }
+
+    fn delta_g2(&self) -> G2Affine {
+        self.vk.delta_g2
+    }
+
+    fn update(&mut self, values: &[Fr], gamma: &Fr) {
+        let (d, e) = (values[0], values[1]);
+        let d_inv = d.inverse().unwrap();
+        let g_inv = gamma.inverse().unwrap();
+        self.common.delta_g1 = (self.common.delta_g1 * d).into_affine();
+        self.vk.delta_g2 = (self.vk.delta_g2 * d).into_affine();
+        self.vk.gamma_g2 = (self.vk.gamma_g2 * gamma).into_affine();
+        self.common.h_query = scale(&self.common.h_query, &d_inv);
+        self.common.l_query = scale(&self.common.l_query, &d_inv);
+        self.vk.gamma_abc_g1 = scale(&self.vk.gamma_abc_g1, &g_inv);
+        self.vk.eta_gamma_inv_g1 = (self.vk.eta_gamma_inv_g1 * e).into_affine();
+        self.common.eta_delta_inv_g1 =
+            (self.common.eta_delta_inv_g1 * (e * gamma * d_inv)).into_affine();
+    }
+
+    fn without_trapdoors(&self) -> Self {
+        let mut key = self.clone();
+        key.common.delta_g1 = G1Affine::zero();
+        key.vk.delta_g2 = G2Affine::zero();
+        key.vk.gamma_g2 = G2Affine::zero();
+        key.common.h_query = vec![];
+        key.common.l_query = vec![];
+        key.vk.gamma_abc_g1 = vec![];
+        key.vk.eta_gamma_inv_g1 = G1Affine::zero();
+        key.common.eta_delta_inv_g1 = G1Affine::zero();
+        key
+    }
+
+    fn is_updated(old: &Self, new: &Self) -> native::Result<bool> {
+        let mut delta_inv_old = old.common.h_query.clone();
+        delta_inv_old.extend_from_slice(&old.common.l_query);
+        let mut delta_inv_new = new.common.h_query.clone();
+        delta_inv_new.extend_from_slice(&new.common.l_query);
+        // Both `eta_gamma_inv_g1` and `eta_delta_inv_g1` are for the same `eta`
+        let eta_updated = Bls12_381::multi_pairing(
+            [
+                new.vk.eta_gamma_inv_g1,
+                (-new.common.eta_delta_inv_g1.into_group()).into_affine(),
+            ],
+            [new.vk.gamma_g2, new.vk.delta_g2],
+        )
+        .is_zero();
+        Ok(eta_updated
+            && divided_by_same(
+                &delta_inv_old,
+                &delta_inv_new,
+                &old.vk.delta_g2,
+                &new.vk.delta_g2,
+            )?
+            && divided_by_same(
+                &old.vk.gamma_abc_g1,
+                &new.vk.gamma_abc_g1,
+                &old.vk.gamma_g2,
+                &new.vk.gamma_g2,
+            )?)
+    }
+}
+
+impl Phase2Key for SaverSnarkPk {
+    /// `delta * G1`
+    fn trapdoors_g1(&self) -> Vec<G1Affine> {
+        vec![self.pk.delta_g1]
+    }
+
+    fn gamma_g2(&self) -> G2Affine {
+        self.pk.vk.gamma_g2
+    }
+
+    fn delta_g2(&self) -> G2Affine {
+        self.pk.vk.delta_g2
+    }
+
+    fn update(&mut self, values: &[Fr], gamma: &Fr) {
+        let d = values[0];
+        let d_inv = d.inverse().unwrap();
+        self.pk.delta_g1 = (self.pk.delta_g1 * d).into_affine();
+        self.pk.vk.delta_g2 = (self.pk.vk.delta_g2 * d).into_affine();
+        self.pk.vk.gamma_g2 = (self.pk.vk.gamma_g2 * gamma).into_affine();
+        self.gamma_g1 = (self.gamma_g1 * gamma).into_affine();
+        self.pk.h_query = scale(&self.pk.h_query, &d_inv);
+        self.pk.l_query = scale(&self.pk.l_query, &d_inv);
+        self.pk.vk.gamma_abc_g1 = scale(&self.pk.vk.gamma_abc_g1, &gamma.inverse().unwrap());
+    }
+
+    fn without_trapdoors(&self) -> Self {
+        let mut key = self.clone();
+        key.pk.delta_g1 = G1Affine::zero();
+        key.pk.vk.delta_g2 = G2Affine::zero();
+        key.pk.vk.gamma_g2 = G2Affine::zero();
+        key.gamma_g1 = G1Affine::zero();
+        key.pk.h_query = vec![];
+        key.pk.l_query = vec![];
+        key.pk.vk.gamma_abc_g1 = vec![];
+        key
+    }
+
+    fn is_updated(old: &Self, new: &Self) -> native::Result<bool> {
+        let mut delta_inv_old = old.pk.h_query.clone();
+        delta_inv_old.extend_from_slice(&old.pk.l_query);
+        let mut delta_inv_new = new.pk.h_query.clone();
+        delta_inv_new.extend_from_slice(&new.pk.l_query);
+        Ok(multiplied_by_same(
+            &old.gamma_g1,
+            &new.gamma_g1,
+            &old.pk.vk.gamma_g2,
+            &new.pk.vk.gamma_g2,
+        ) && divided_by_same(
+            &delta_inv_old,
+            &delta_inv_new,
+            &old.pk.vk.delta_g2,
+            &new.pk.vk.delta_g2,
+        )? && divided_by_same(
+            &old.pk.vk.gamma_abc_g1,
+            &new.pk.vk.gamma_abc_g1,
+            &old.pk.vk.gamma_g2,
+            &new.pk.vk.gamma_g2,
+        )?)
+    }
+}
+
+/// Contribute to the ceremony by updating `key`, the key after the last contribution in `transcript` or the initial
+/// key for the first contribution. Returns the updated key and transcript to pass to the next contributor.
+pub fn contribute<K: Phase2Key>(
+    key: &K,
+    transcript: &CeremonyTranscript,
+) -> native::Result<(K, CeremonyTranscript)> {
+    let mut rng = get_seeded_rng()?;
+    let mut random_non_zero = || loop {
+        let v = Fr::rand(&mut rng);
+        if !v.is_zero() {
+            break v;
+        }
+    };
+    let mut values = (0..key.trapdoors_g1().len())
+        .map(|_| random_non_zero())
+        .collect::<Vec<_>>();
+    let mut gamma = random_non_zero();
+    let mut new_key = key.clone();
+    new_key.update(&values, &gamma);
+    let (trapdoors_g1, gamma_g2) = (new_key.trapdoors_g1(), new_key.gamma_g2());
+    let context = native::to_bytes(&transcript.contributions)?;
+    let proof_g1 = ProofOfPossession::new(
+        &values,
+        &key.trapdoors_g1(),
+        &trapdoors_g1,
+        CEREMONY_DOMAIN,
+        &context,
+    );
+    let proof_g2 = ProofOfPossession::new(
+        &[gamma],
+        &[key.gamma_g2()],
+        &[gamma_g2],
+        CEREMONY_DOMAIN,
+        &context,
+    );
+    values.zeroize();
+    gamma.zeroize();
+    let mut new_transcript = transcript.clone();
+    new_transcript.contributions.push(Contribution {
+        trapdoors_g1,
+        gamma_g2,
+        proof_g1: proof_g1?,
+        proof_g2: proof_g2?,
+    });
+    Ok((new_key, new_transcript))
+}
+
+/// Verify that `new_key` is `previous_key` updated by the last contribution in `transcript`
+pub fn verify_contribution<K: Phase2Key>(
+    previous_key: &K,
+    new_key: &K,
+    transcript: &CeremonyTranscript,
+) -> native::Result<()> {
+    let (last, earlier) = transcript
+        .contributions
+        .split_last()
+        .ok_or_else(|| Error::invalid_argument("Transcript has no contributions"))?;
+    verify_proof_of_knowledge(
+        &previous_key.trapdoors_g1(),
+        &previous_key.gamma_g2(),
+        earlier,
+        last,
+    )?;
+    if !is_from_contribution(new_key, last) {
+        return Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Key isn't the one from the last contribution",
+        ));
+    }
+    verify_update(previous_key, new_key)
+}
+
+/// Verify the whole ceremony, that `final_key` is `initial_key` updated by all contributions in `transcript`. Only
+/// the initial and final keys are needed as each contribution has the updated elements of the key after it. To
+/// not trust whoever created the initial key, the verifier should create it from the powers of tau itself.
+pub fn verify_ceremony<K: Phase2Key>(
+    initial_key: &K,
+    final_key: &K,
+    transcript: &CeremonyTranscript,
+) -> native::Result<()> {
+    if transcript.contributions.is_empty() {
+        return Err(Error::invalid_argument("Transcript has no contributions"));
+    }
+    let (mut trapdoors_g1, mut gamma_g2) = (initial_key.trapdoors_g1(), initial_key.gamma_g2());
+    for (i, contribution) in transcript.contributions.iter().enumerate() {
+        verify_proof_of_knowledge(
+            &trapdoors_g1,
+            &gamma_g2,
+            &transcript.contributions[..i],
+            contribution,
+        )
+        .map_err(|e| e.context(format!("Contribution {} is invalid", i)))?;
+        trapdoors_g1 = contribution.trapdoors_g1.clone();
+        gamma_g2 = contribution.gamma_g2;
+    }
+    if !is_from_contribution(final_key, transcript.contributions.last().unwrap()) {
+        return Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Final key isn't the one from the last contribution",
+        ));
+    }
+    verify_update(initial_key, final_key)
+}
+
+fn verify_proof_of_knowledge(
+    previous_trapdoors_g1: &[G1Affine],
+    previous_gamma_g2: &G2Affine,
+    earlier_contributions: &[Contribution],
+    contribution: &Contribution,
+) -> native::Result<()> {
+    let context = native::to_bytes(&earlier_contributions)?;
+    contribution.proof_g1.verify(
+        previous_trapdoors_g1,
+        &contribution.trapdoors_g1,
+        CEREMONY_DOMAIN,
+        &context,
+    )?;
+    contribution.proof_g2.verify(
+        &[*previous_gamma_g2],
+        &[contribution.gamma_g2],
+        CEREMONY_DOMAIN,
+        &context,
+    )
+}
+
+fn is_from_contribution<K: Phase2Key>(key: &K, contribution: &Contribution) -> bool {
+    key.trapdoors_g1() == contribution.trapdoors_g1 && key.gamma_g2() == contribution.gamma_g2
+}
+
+/// Check that `new_key` is `old_key` with its trapdoors multiplied by some values, given that the proofs of
+/// knowledge of those values verified
+fn verify_update<K: Phase2Key>(old_key: &K, new_key: &K) -> native::Result<()> {
+    if old_key.without_trapdoors() != new_key.without_trapdoors() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            "Keys differ in elements that don't depend on the trapdoors of phase 2",
+        ));
+    }
+    let (old_delta_g1, new_delta_g1) = (old_key.trapdoors_g1()[0], new_key.trapdoors_g1()[0]);
+    let updated = new_key.trapdoors_g1().iter().all(|t| !t.is_zero())
+        && !new_key.gamma_g2().is_zero()
+        && multiplied_by_same(
+            &old_delta_g1,
+            &new_delta_g1,
+            &old_key.delta_g2(),
+            &new_key.delta_g2(),
+        )
+        && K::is_updated(old_key, new_key)?;
+    if updated {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Key wasn't updated correctly for its trapdoors",
+        ))
+    }
+}
+
+/// Whether `new_g1` is `old_g1` multiplied by the same value as `new_g2` is `old_g2`
+fn multiplied_by_same(
+    old_g1: &G1Affine,
+    new_g1: &G1Affine,
+    old_g2: &G2Affine,
+    new_g2: &G2Affine,
+) -> bool {
+    Bls12_381::multi_pairing(
+        [*new_g1, (-old_g1.into_group()).into_affine()],
+        [*old_g2, *new_g2],
+    )
+    .is_zero()
+}
+
+/// Whether the elements of `new` are those of `old` divided by the value that `new_g2` is `old_g2` multiplied by,
+/// checked for a random linear combination of them whose coefficients are hashed from all the elements
+fn divided_by_same(
+    old: &[G1Affine],
+    new: &[G1Affine],
+    old_g2: &G2Affine,
+    new_g2: &G2Affine,
+) -> native::Result<bool> {
+    if old.len() != new.len() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Keys have {} and {} elements that depend on a trapdoor",
+                old.len(),
+                new.len()
+            ),
+        ));
+    }
+    let mut bytes = vec![];
+    old.serialize_uncompressed(&mut bytes)?;
+    new.serialize_uncompressed(&mut bytes)?;
+    old_g2.serialize_uncompressed(&mut bytes)?;
+    new_g2.serialize_uncompressed(&mut bytes)?;
+    let r = linear_combination_coefficients(&bytes, old.len())?;
+    let old_comb = G1::msm_unchecked(old, &r);
+    let new_comb = G1::msm_unchecked(new, &r);
+    Ok(Bls12_381::multi_pairing(
+        [new_comb.into_affine(), (-old_comb).into_affine()],
+        [*new_g2, *old_g2],
+    )
+    .is_zero())
+}
+
+/// Coefficients for checking a random linear combination of elements instead of each of them. They are derived
+/// from a hash of the serialized elements being checked, `bytes`, rather than the RNG since the RNG can be made
+/// predictable with `setRngSource`, which would let whoever created the elements pick them to pass the check.
+pub(crate) fn linear_combination_coefficients(
+    bytes: &[u8],
+    count: usize,
+) -> native::Result<Vec<Fr>> {
+    let mut hashed = LINEAR_COMBINATION_DOMAIN.to_vec();
+    hashed.extend_from_slice(bytes);
+    let mut seed = vec![];
+    compute_random_oracle_challenge::<Fr, Blake2b512>(&hashed).serialize_compressed(&mut seed)?;
+    let seed_len = seed.len();
+    Ok((0..count as u64)
+        .map(|i| {
+            seed.truncate(seed_len);
+            seed.extend_from_slice(&i.to_le_bytes());
+            compute_random_oracle_challenge::<Fr, Blake2b512>(&seed)
+        })
+        .collect())
+}
+
+fn scale(elements: &[G1Affine], s: &Fr) -> Vec<G1Affine> {
+    let s = s.into_bigint();
+    G1::normalize_batch(&elements.iter().map(|e| e.mul_bigint(s)).collect::<Vec<_>>())
+}
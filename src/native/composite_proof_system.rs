@@ -0,0 +1,167 @@
+use ark_ec::AffineRepr;
+use ark_std::collections::BTreeMap;
+use blake2::Blake2b512;
+use proof_system::prelude::Statement;
+
+use crate::{
+    composite_proof_system::{Proof, ProofSpec, Witnesses},
+    native::{
+        dkgith::{self, DkgithParams, DkgithProof, DkgithPublicKey},
+        saver::{self, MultiRecipientProof},
+        Error, ErrorCode, Result,
+    },
+    saver::{ChunkedCommGens, SaverCiphertext, SaverEk, SaverSnarkVk},
+    utils::get_seeded_rng,
+    Fr, G1Affine,
+};
+
+/// Create a proof for the statements of `proof_spec` given their witnesses. Also returns the randomness used by
+/// the statements using SAVER and LegoGroth16 proofs. The wasm functions return the serialized pair.
+pub fn generate_proof<G: AffineRepr<ScalarField = Fr>>(
+    proof_spec: ProofSpec<G>,
+    witnesses: Witnesses,
+    nonce: Option<Vec<u8>>,
+) -> Result<(Proof<G>, BTreeMap<usize, Fr>)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(Proof::<G>::new::<_, Blake2b512>(
+        &mut rng,
+        proof_spec,
+        witnesses,
+        nonce,
+        Default::default(),
+    )?)
+}
+
+pub fn verify_proof<G: AffineRepr<ScalarField = Fr>>(
+    proof: Proof<G>,
+    proof_spec: ProofSpec<G>,
+    nonce: Option<Vec<u8>>,
+) -> Result<()> {
+    let mut rng = get_seeded_rng()?;
+    Ok(proof.verify::<_, Blake2b512>(&mut rng, proof_spec, nonce, Default::default())?)
+}
+
+/// DKGitH proof for the statement at `statement_index` of a composite proof's `ProofSpec`, which is a Pedersen
+/// commitment statement created with `generateDkgithStatement`
+pub struct DkgithEncryption {
+    pub statement_index: usize,
+    pub proof: DkgithProof,
+    pub params: DkgithParams,
+    pub public_key: DkgithPublicKey,
+}
+
+/// Verify the composite proof and the DKGitH proofs of its statements. Each DKGitH proof is verified for the
+/// commitment of its statement, after checking that the statement's commitment key is the one in the DKGitH
+/// params, and with the composite proof's nonce as context so that it can't be used with another composite proof.
+pub fn verify_proof_with_dkgith(
+    proof: Proof<G1Affine>,
+    proof_spec: ProofSpec<G1Affine>,
+    nonce: Option<Vec<u8>>,
+    encryptions: &[DkgithEncryption],
+) -> Result<()> {
+    let mut commitments = Vec::with_capacity(encryptions.len());
+    for e in encryptions {
+        let (key, commitment) = commitment_statement(&proof_spec, e.statement_index)?;
+        if key != [e.params.g_m, e.params.h] {
+            return Err(Error::invalid_argument(format!(
+                "Commitment key of the statement at index {} isn't the one of the DKGitH params",
+                e.statement_index
+            )));
+        }
+        commitments.push(commitment);
+    }
+    verify_proof(proof, proof_spec, nonce.clone())?;
+    let context = nonce.unwrap_or_default();
+    for (e, commitment) in encryptions.iter().zip(commitments) {
+        dkgith::verify(&e.proof, &commitment, &e.params, &e.public_key, &context).map_err(
+            |err| {
+                err.context(format!(
+                    "Verifying the DKGitH proof for the statement at index {} returned error",
+                    e.statement_index
+                ))
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Ciphertexts and proof from `saver::encrypt_for_recipients` for the statement at `statement_index` of a composite
+/// proof's `ProofSpec`, which is a Pedersen commitment statement created with `generateSaverMultiRecipientStatement`.
+/// `snark_vks` has the snark verification key of each recipient.
+pub struct SaverMultiRecipientEncryption {
+    pub statement_index: usize,
+    pub ciphertexts: Vec<SaverCiphertext>,
+    pub proof: MultiRecipientProof,
+    pub encryption_keys: Vec<SaverEk>,
+    pub snark_vks: Vec<SaverSnarkVk>,
+    pub chunk_bit_size: u8,
+}
+
+/// Verify the composite proof and the multi-recipient SAVER proofs of its statements. Each proof is verified for the
+/// commitment of its statement with the statement's commitment key as the chunked commitment generators, and with
+/// the composite proof's nonce as context so that it can't be used with another composite proof.
+pub fn verify_proof_with_saver_multi_recipient(
+    proof: Proof<G1Affine>,
+    proof_spec: ProofSpec<G1Affine>,
+    nonce: Option<Vec<u8>>,
+    encryptions: &[SaverMultiRecipientEncryption],
+) -> Result<()> {
+    let mut commitments = Vec::with_capacity(encryptions.len());
+    for e in encryptions {
+        let (key, commitment) = commitment_statement(&proof_spec, e.statement_index)?;
+        let [g, h] = key else {
+            return Err(Error::invalid_argument(format!(
+                "Commitment key of the statement at index {} should have 2 generators but has {}",
+                e.statement_index,
+                key.len()
+            )));
+        };
+        commitments.push((ChunkedCommGens { G: *g, H: *h }, commitment));
+    }
+    verify_proof(proof, proof_spec, nonce.clone())?;
+    let context = nonce.unwrap_or_default();
+    for (e, (comm_gens, commitment)) in encryptions.iter().zip(commitments) {
+        saver::verify_encryption_for_recipients(
+            &e.ciphertexts,
+            &commitment,
+            &e.proof,
+            &e.encryption_keys,
+            &e.snark_vks,
+            &comm_gens,
+            e.chunk_bit_size,
+            &context,
+        )
+        .map_err(|err| {
+            err.context(format!(
+                "Verifying the multi-recipient encryption for the statement at index {} returned error",
+                e.statement_index
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+/// Commitment key and commitment of the Pedersen commitment statement at `statement_index`
+fn commitment_statement(
+    proof_spec: &ProofSpec<G1Affine>,
+    statement_index: usize,
+) -> Result<(&[G1Affine], G1Affine)> {
+    let statement = proof_spec
+        .statements
+        .0
+        .get(statement_index)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!("No statement at index {}", statement_index),
+            )
+        })?;
+    let Statement::PedersenCommitment(s) = statement else {
+        return Err(Error::invalid_argument(format!(
+            "Statement at index {} isn't a Pedersen commitment statement",
+            statement_index
+        )));
+    };
+    let key = s.get_commitment_key(&proof_spec.setup_params, statement_index)?;
+    Ok((key.as_slice(), s.commitment))
+}
@@ -0,0 +1,498 @@
+//! Verifiable encryption of a committed message to an auditor's public key without a circuit specific setup, using
+//! "DKG in the head" from the paper [Verifiable Encryption from MPC-in-the-Head](https://eprint.iacr.org/2021/1704)
+//! by Takahashi and Zaverucha.
+//!
+//! The prover commits to the message `m` as `C = G_m * m + H * r` for a random blinding `r` and proves with a
+//! Pedersen commitment statement in a composite proof that it knows the opening of `C`, where witness equality ties
+//! `m` to the witnesses of other statements like a signed message. Alongside the composite proof, it creates a
+//! `DkgithProof` that the ciphertexts in it decrypt to the opening of `C`.
+//!
+//! In each of `REPETITIONS` repetitions, the prover Shamir secret shares `(m, r)` among `NUM_PARTIES` parties with a
+//! polynomial of degree `NUM_PARTIES - NUM_HIDDEN` and encrypts each party's share to the auditor's public key with
+//! hashed ElGamal. The challenge picks `NUM_HIDDEN` parties of each repetition whose shares stay encrypted and the
+//! prover reveals the shares and encryption randomness of the others. These shares are one fewer than needed to
+//! reconstruct `(m, r)` so reveal nothing about it. The verifier checks that the revealed shares were encrypted
+//! correctly and computes the commitments to the hidden shares from `C` and the revealed shares to recompute the
+//! challenge. The auditor decrypts a hidden share and reconstructs `(m, r)` from it and the revealed shares. A
+//! cheating prover has to guess the hidden parties of every repetition before getting the challenge, which it can do
+//! with probability `(NUM_PARTIES choose NUM_HIDDEN)^-REPETITIONS`, about `2^-134`.
+
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{Field, One, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{collections::BTreeSet, UniformRand};
+use blake2::{Blake2b512, Digest};
+use dock_crypto_utils::{
+    concat_slices,
+    hashing_utils::{affine_group_elem_from_try_and_incr, field_elem_from_try_and_incr},
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    native::{self, Error, ErrorCode},
+    utils::{get_seeded_rng, random_bytes},
+    Fr, G1Affine,
+};
+
+/// Number of parties the message and blinding are shared among in each repetition
+pub const NUM_PARTIES: u16 = 48;
+/// Number of parties in each repetition whose shares stay encrypted
+pub const NUM_HIDDEN: u16 = 24;
+/// Number of repetitions
+pub const REPETITIONS: usize = 3;
+
+/// Domain separator for the challenge
+pub const CHALLENGE_DOMAIN: &[u8] = b"DKGITH-VERIFIABLE-ENCRYPTION";
+/// Domain separator for deriving the keys that mask the encrypted shares
+pub const ENCRYPTION_DOMAIN: &[u8] = b"DKGITH-HASHED-ELGAMAL";
+
+type G1 = <G1Affine as AffineRepr>::Group;
+
+/// Public parameters: the base `G` of the auditor's public key and the commitment key `[G_m, H]`
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DkgithParams {
+    pub g: G1Affine,
+    pub g_m: G1Affine,
+    pub h: G1Affine,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize, Zeroize, ZeroizeOnDrop,
+)]
+pub struct DkgithSecretKey(pub Fr);
+
+/// `G * secret key`
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DkgithPublicKey(pub G1Affine);
+
+/// Share of the message and the blinding held by one party
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize, Zeroize)]
+pub struct OpeningShare {
+    pub message: Fr,
+    pub blinding: Fr,
+}
+
+/// Hashed ElGamal encryption of an `OpeningShare` with randomness `k`. `r` is `G * k` and the shares are masked with
+/// keys derived from `public key * k`.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ShareCiphertext {
+    pub r: G1Affine,
+    pub message: Fr,
+    pub blinding: Fr,
+}
+
+/// Share and encryption randomness of a party whose share is revealed
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct RevealedShare {
+    pub share: OpeningShare,
+    pub randomness: Fr,
+}
+
+/// The revealed shares and the ciphertexts of the hidden shares of a repetition, each in increasing order of party
+/// id. The ids of the hidden parties are derived from the challenge.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DkgithRepetition {
+    pub revealed: Vec<RevealedShare>,
+    pub hidden: Vec<ShareCiphertext>,
+}
+
+/// Proof that the ciphertexts in it decrypt to the opening of a commitment. It is the ciphertext as well.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DkgithProof {
+    pub challenge: Vec<u8>,
+    pub repetitions: Vec<DkgithRepetition>,
+}
+
+/// Generate the parameters. A random label is used if `label` isn't given.
+pub fn generate_params(label: Option<&[u8]>) -> native::Result<DkgithParams> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(DkgithParams {
+        g: affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(&concat_slices!(
+            &label, b" : G"
+        )),
+        g_m: affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(&concat_slices!(
+            &label, b" : G_m"
+        )),
+        h: affine_group_elem_from_try_and_incr::<G1Affine, Blake2b512>(&concat_slices!(
+            &label, b" : H"
+        )),
+    })
+}
+
+pub fn generate_secret_key(seed: Option<&[u8]>) -> native::Result<DkgithSecretKey> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let sk = DkgithSecretKey(field_elem_from_try_and_incr::<Fr, Blake2b512>(&seed));
+    seed.zeroize();
+    Ok(sk)
+}
+
+pub fn generate_public_key(secret_key: &DkgithSecretKey, params: &DkgithParams) -> DkgithPublicKey {
+    DkgithPublicKey((params.g * secret_key.0).into_affine())
+}
+
+/// Commit to `message` with a random blinding and encrypt the opening to the auditor's public key. Returns the
+/// commitment, the blinding and the proof. The message and blinding are the witnesses of the Pedersen commitment
+/// statement with commitment key `[G_m, H]`. `context` should bind the proof to the composite proof, like its nonce.
+pub fn encrypt(
+    message: &Fr,
+    params: &DkgithParams,
+    public_key: &DkgithPublicKey,
+    context: &[u8],
+) -> native::Result<(G1Affine, Fr, DkgithProof)> {
+    let mut rng = get_seeded_rng()?;
+    let blinding = Fr::rand(&mut rng);
+    let commitment = commit(
+        params,
+        &OpeningShare {
+            message: *message,
+            blinding,
+        },
+    );
+    let degree = (NUM_PARTIES - NUM_HIDDEN) as usize;
+
+    let mut shares = Vec::with_capacity(REPETITIONS);
+    let mut randomness = Vec::with_capacity(REPETITIONS);
+    let mut ciphertexts = Vec::with_capacity(REPETITIONS);
+    let mut share_commitments = Vec::with_capacity(REPETITIONS);
+    for _ in 0..REPETITIONS {
+        let mut poly_m = vec![*message];
+        let mut poly_b = vec![blinding];
+        poly_m.extend((0..degree).map(|_| Fr::rand(&mut rng)));
+        poly_b.extend((0..degree).map(|_| Fr::rand(&mut rng)));
+        let rep_shares = (1..=NUM_PARTIES)
+            .map(|i| {
+                let x = Fr::from(i);
+                OpeningShare {
+                    message: evaluate(&poly_m, &x),
+                    blinding: evaluate(&poly_b, &x),
+                }
+            })
+            .collect::<Vec<_>>();
+        poly_m.zeroize();
+        poly_b.zeroize();
+        let rep_randomness = (0..NUM_PARTIES)
+            .map(|_| Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+        ciphertexts.push(
+            rep_shares
+                .iter()
+                .zip(&rep_randomness)
+                .map(|(s, k)| encrypt_share(s, k, params, public_key))
+                .collect::<native::Result<Vec<_>>>()?,
+        );
+        share_commitments.push(
+            rep_shares
+                .iter()
+                .map(|s| commit(params, s))
+                .collect::<Vec<_>>(),
+        );
+        shares.push(rep_shares);
+        randomness.push(rep_randomness);
+    }
+
+    let challenge = compute_challenge(
+        params,
+        public_key,
+        &commitment,
+        context,
+        &share_commitments,
+        &ciphertexts,
+    )?;
+    let mut repetitions = Vec::with_capacity(REPETITIONS);
+    for (rep, ((rep_shares, rep_randomness), rep_ciphertexts)) in shares
+        .into_iter()
+        .zip(randomness)
+        .zip(ciphertexts)
+        .enumerate()
+    {
+        let hidden = hidden_parties(&challenge, rep);
+        let mut revealed_shares = vec![];
+        let mut hidden_ciphertexts = vec![];
+        for (i, ((share, randomness), ct)) in rep_shares
+            .into_iter()
+            .zip(rep_randomness)
+            .zip(rep_ciphertexts)
+            .enumerate()
+        {
+            if hidden.contains(&(i as u16 + 1)) {
+                hidden_ciphertexts.push(ct);
+            } else {
+                revealed_shares.push(RevealedShare { share, randomness });
+            }
+        }
+        repetitions.push(DkgithRepetition {
+            revealed: revealed_shares,
+            hidden: hidden_ciphertexts,
+        });
+    }
+    Ok((
+        commitment,
+        blinding,
+        DkgithProof {
+            challenge,
+            repetitions,
+        },
+    ))
+}
+
+/// Verify that the ciphertexts in the proof decrypt to the opening of `commitment`
+pub fn verify(
+    proof: &DkgithProof,
+    commitment: &G1Affine,
+    params: &DkgithParams,
+    public_key: &DkgithPublicKey,
+    context: &[u8],
+) -> native::Result<()> {
+    check_proof_size(proof)?;
+    let mut share_commitments = Vec::with_capacity(REPETITIONS);
+    let mut ciphertexts = Vec::with_capacity(REPETITIONS);
+    for (rep, repetition) in proof.repetitions.iter().enumerate() {
+        let hidden = hidden_parties(&proof.challenge, rep);
+        let revealed_ids = revealed_parties(&hidden);
+        let revealed_commitments = repetition
+            .revealed
+            .iter()
+            .map(|r| commit(params, &r.share))
+            .collect::<Vec<_>>();
+
+        // Commitments to the hidden shares by interpolating the commitments to the message and the revealed shares
+        let mut xs = vec![Fr::zero()];
+        xs.extend(revealed_ids.iter().map(|i| Fr::from(*i)));
+        let mut bases = vec![*commitment];
+        bases.extend_from_slice(&revealed_commitments);
+        let hidden_commitments = G1::normalize_batch(
+            &hidden
+                .iter()
+                .map(|i| G1::msm_unchecked(&bases, &lagrange_coefficients(&xs, &Fr::from(*i))))
+                .collect::<Vec<_>>(),
+        );
+
+        let mut rep_commitments = Vec::with_capacity(NUM_PARTIES as usize);
+        let mut rep_ciphertexts = Vec::with_capacity(NUM_PARTIES as usize);
+        let (mut revealed, mut hidden_idx) =
+            (repetition.revealed.iter().zip(revealed_commitments), 0);
+        for i in 1..=NUM_PARTIES {
+            if hidden.contains(&i) {
+                rep_commitments.push(hidden_commitments[hidden_idx]);
+                rep_ciphertexts.push(repetition.hidden[hidden_idx].clone());
+                hidden_idx += 1;
+            } else {
+                let (r, c) = revealed.next().unwrap();
+                rep_commitments.push(c);
+                rep_ciphertexts.push(encrypt_share(&r.share, &r.randomness, params, public_key)?);
+            }
+        }
+        share_commitments.push(rep_commitments);
+        ciphertexts.push(rep_ciphertexts);
+    }
+    let challenge = compute_challenge(
+        params,
+        public_key,
+        commitment,
+        context,
+        &share_commitments,
+        &ciphertexts,
+    )?;
+    if challenge == proof.challenge {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Invalid verifiable encryption proof",
+        ))
+    }
+}
+
+/// Decrypt the message and blinding from the proof. The proof should be verified with `verify` first.
+pub fn decrypt(
+    proof: &DkgithProof,
+    commitment: &G1Affine,
+    secret_key: &DkgithSecretKey,
+    params: &DkgithParams,
+) -> native::Result<(Fr, Fr)> {
+    check_proof_size(proof)?;
+    for (rep, repetition) in proof.repetitions.iter().enumerate() {
+        let hidden = hidden_parties(&proof.challenge, rep);
+        let revealed_ids = revealed_parties(&hidden);
+        for (id, ct) in hidden.iter().zip(&repetition.hidden) {
+            let share = decrypt_share(ct, secret_key)?;
+            // Any hidden share and the revealed shares determine the polynomial, so interpolate at 0
+            let mut xs = revealed_ids
+                .iter()
+                .map(|i| Fr::from(*i))
+                .collect::<Vec<_>>();
+            xs.push(Fr::from(*id));
+            let coeffs = lagrange_coefficients(&xs, &Fr::zero());
+            let shares = repetition
+                .revealed
+                .iter()
+                .map(|r| &r.share)
+                .chain(ark_std::iter::once(&share));
+            let mut opening = OpeningShare {
+                message: Fr::zero(),
+                blinding: Fr::zero(),
+            };
+            for (c, s) in coeffs.iter().zip(shares) {
+                opening.message += *c * s.message;
+                opening.blinding += *c * s.blinding;
+            }
+            if commit(params, &opening) == *commitment {
+                return Ok((opening.message, opening.blinding));
+            }
+        }
+    }
+    Err(Error::new(
+        ErrorCode::InvalidDecryption,
+        "None of the encrypted shares decrypt to the opening of the commitment",
+    ))
+}
+
+/// Verify that `message` and `blinding` are the opening of `commitment`. The auditor shares them to prove that it
+/// decrypted correctly.
+pub fn verify_decryption(
+    commitment: &G1Affine,
+    message: &Fr,
+    blinding: &Fr,
+    params: &DkgithParams,
+) -> native::Result<()> {
+    let opening = OpeningShare {
+        message: *message,
+        blinding: *blinding,
+    };
+    if commit(params, &opening) == *commitment {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidDecryption,
+            "Message and blinding are not the opening of the commitment",
+        ))
+    }
+}
+
+fn commit(params: &DkgithParams, opening: &OpeningShare) -> G1Affine {
+    (params.g_m * opening.message + params.h * opening.blinding).into_affine()
+}
+
+fn encrypt_share(
+    share: &OpeningShare,
+    randomness: &Fr,
+    params: &DkgithParams,
+    public_key: &DkgithPublicKey,
+) -> native::Result<ShareCiphertext> {
+    let (mask_m, mask_b) = encryption_masks(&(public_key.0 * randomness).into_affine())?;
+    Ok(ShareCiphertext {
+        r: (params.g * randomness).into_affine(),
+        message: share.message + mask_m,
+        blinding: share.blinding + mask_b,
+    })
+}
+
+fn decrypt_share(
+    ciphertext: &ShareCiphertext,
+    secret_key: &DkgithSecretKey,
+) -> native::Result<OpeningShare> {
+    let (mask_m, mask_b) = encryption_masks(&(ciphertext.r * secret_key.0).into_affine())?;
+    Ok(OpeningShare {
+        message: ciphertext.message - mask_m,
+        blinding: ciphertext.blinding - mask_b,
+    })
+}
+
+fn encryption_masks(shared_secret: &G1Affine) -> native::Result<(Fr, Fr)> {
+    let mut bytes = ENCRYPTION_DOMAIN.to_vec();
+    shared_secret.serialize_compressed(&mut bytes)?;
+    let mask_m = field_elem_from_try_and_incr::<Fr, Blake2b512>(&concat_slices!(&bytes, b"m"));
+    let mask_b = field_elem_from_try_and_incr::<Fr, Blake2b512>(&concat_slices!(&bytes, b"b"));
+    Ok((mask_m, mask_b))
+}
+
+fn compute_challenge(
+    params: &DkgithParams,
+    public_key: &DkgithPublicKey,
+    commitment: &G1Affine,
+    context: &[u8],
+    share_commitments: &[Vec<G1Affine>],
+    ciphertexts: &[Vec<ShareCiphertext>],
+) -> native::Result<Vec<u8>> {
+    let mut bytes = CHALLENGE_DOMAIN.to_vec();
+    params.serialize_compressed(&mut bytes)?;
+    public_key.serialize_compressed(&mut bytes)?;
+    commitment.serialize_compressed(&mut bytes)?;
+    (context.len() as u64).serialize_compressed(&mut bytes)?;
+    bytes.extend_from_slice(context);
+    for (rep_commitments, rep_ciphertexts) in share_commitments.iter().zip(ciphertexts) {
+        for (c, ct) in rep_commitments.iter().zip(rep_ciphertexts) {
+            c.serialize_compressed(&mut bytes)?;
+            ct.serialize_compressed(&mut bytes)?;
+        }
+    }
+    Ok(Blake2b512::digest(&bytes).to_vec())
+}
+
+/// Ids, from 1 to `NUM_PARTIES`, of the `NUM_HIDDEN` parties of repetition `rep` whose shares stay encrypted
+fn hidden_parties(challenge: &[u8], rep: usize) -> BTreeSet<u16> {
+    let mut hidden = BTreeSet::new();
+    let mut counter = 0_u32;
+    while hidden.len() < NUM_HIDDEN as usize {
+        let digest = Blake2b512::digest(concat_slices!(
+            challenge,
+            (rep as u32).to_le_bytes(),
+            counter.to_le_bytes()
+        ));
+        for chunk in digest.chunks_exact(8) {
+            let n = u64::from_le_bytes(chunk.try_into().unwrap());
+            hidden.insert((n % NUM_PARTIES as u64) as u16 + 1);
+            if hidden.len() == NUM_HIDDEN as usize {
+                break;
+            }
+        }
+        counter += 1;
+    }
+    hidden
+}
+
+fn revealed_parties(hidden: &BTreeSet<u16>) -> Vec<u16> {
+    (1..=NUM_PARTIES).filter(|i| !hidden.contains(i)).collect()
+}
+
+fn check_proof_size(proof: &DkgithProof) -> native::Result<()> {
+    let expected_revealed = (NUM_PARTIES - NUM_HIDDEN) as usize;
+    if proof.repetitions.len() != REPETITIONS
+        || proof
+            .repetitions
+            .iter()
+            .any(|r| r.revealed.len() != expected_revealed || r.hidden.len() != NUM_HIDDEN as usize)
+    {
+        return Err(Error::new(
+            ErrorCode::InvalidProof,
+            format!(
+                "Proof should have {} repetitions with {} revealed and {} hidden shares each",
+                REPETITIONS, expected_revealed, NUM_HIDDEN
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Evaluate the polynomial with coefficients `coeffs`, constant term first, at `x`
+fn evaluate(coeffs: &[Fr], x: &Fr) -> Fr {
+    coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c)
+}
+
+/// Coefficients to evaluate at `x` the polynomial whose values are known at the distinct points `xs`
+fn lagrange_coefficients(xs: &[Fr], x: &Fr) -> Vec<Fr> {
+    xs.iter()
+        .enumerate()
+        .map(|(j, x_j)| {
+            let mut num = Fr::one();
+            let mut den = Fr::one();
+            for (k, x_k) in xs.iter().enumerate() {
+                if k != j {
+                    num *= *x - x_k;
+                    den *= *x_j - x_k;
+                }
+            }
+            num * den.inverse().unwrap()
+        })
+        .collect()
+}
@@ -0,0 +1,438 @@
+//! Versioned envelope for serialized objects. The envelope is a header followed by the `CanonicalSerialize` bytes of
+//! the object:
+//!
+//! | bytes | content                                          |
+//! |-------|--------------------------------------------------|
+//! | 0..4  | magic `0xDC 0x45 0x4E 0x56`                      |
+//! | 4     | format version, currently 1                      |
+//! | 5     | curve id, 1 for BLS12-381                        |
+//! | 6     | flags, bit 0 is set if the payload is compressed |
+//! | 7..9  | type tag as little-endian `u16`                  |
+//!
+//! Objects are serialized in an envelope only after calling `set_envelope_output(true)`. Functions deserializing an
+//! object only look for an envelope after calling `set_envelope_input(true)`, otherwise the bytes are always taken to
+//! be the raw object even if they happen to start with the magic. With envelope input enabled, both the raw bytes and
+//! an envelope are accepted and an envelope is checked to have the expected type and compression so that passing one
+//! kind of object where another is expected fails with a clear error rather than a deserialization failure or, worse,
+//! succeeding. `unwrap` can always be used to explicitly take the object out of an envelope.
+
+use ark_serialize::{CanonicalDeserialize, Read, SerializationError};
+use core::cell::Cell;
+
+use crate::native::{Error, ErrorCode, Result};
+
+pub const MAGIC: [u8; 4] = [0xDC, 0x45, 0x4E, 0x56];
+pub const FORMAT_VERSION: u8 = 1;
+pub const CURVE_BLS12_381: u8 = 1;
+pub const HEADER_SIZE: usize = 9;
+
+const FLAG_COMPRESSED: u8 = 1;
+
+thread_local! {
+    static ENVELOPE_OUTPUT: Cell<bool> = const { Cell::new(false) };
+    static ENVELOPE_INPUT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the functions returning serialized objects put them in an envelope
+pub fn set_envelope_output(enabled: bool) {
+    ENVELOPE_OUTPUT.with(|e| e.set(enabled))
+}
+
+pub fn envelope_output() -> bool {
+    ENVELOPE_OUTPUT.with(|e| e.get())
+}
+
+/// Whether the functions deserializing objects accept them in an envelope
+pub fn set_envelope_input(enabled: bool) {
+    ENVELOPE_INPUT.with(|e| e.set(enabled))
+}
+
+pub fn envelope_input() -> bool {
+    ENVELOPE_INPUT.with(|e| e.get())
+}
+
+macro_rules! artifact_types {
+    ($($variant: ident = $tag: expr),+ $(,)?) => {
+        /// Type of the object in an envelope. The tags are part of the format and must not change.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ArtifactType {
+            $($variant,)+
+        }
+
+        impl ArtifactType {
+            pub fn tag(&self) -> u16 {
+                match self {
+                    $(ArtifactType::$variant => $tag,)+
+                }
+            }
+
+            pub fn from_tag(tag: u16) -> Option<Self> {
+                match tag {
+                    $($tag => Some(ArtifactType::$variant),)+
+                    _ => None,
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(ArtifactType::$variant => stringify!($variant),)+
+                }
+            }
+
+            fn from_canonical_name(name: &str) -> Option<Self> {
+                match name {
+                    $(stringify!($variant) => Some(ArtifactType::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+artifact_types!(
+    BBSSecretKey = 1,
+    BBSPublicKey = 2,
+    BBSSigParams = 3,
+    BBSSignature = 4,
+    BBSPlusSigningKey = 10,
+    BBSPlusPublicKeyG1 = 11,
+    BBSPlusPublicKeyG2 = 12,
+    BBSPlusSigParamsG1 = 13,
+    BBSPlusSigParamsG2 = 14,
+    BBSPlusSigG1 = 15,
+    BBSPlusSigG2 = 16,
+    PSSecretKey = 20,
+    PSPublicKey = 21,
+    PSSignatureParams = 22,
+    PSSignature = 23,
+    PSBlindSignature = 24,
+    PSPoKOfSignatureProof = 25,
+    PSPoKOfMessagesProof = 26,
+    AccumSetupParams = 30,
+    AccumPk = 31,
+    MembershipProvingKey = 32,
+    NonMembershipProvingKey = 33,
+    Omega = 34,
+    SaverEncryptionGens = 40,
+    SaverCommitmentGens = 41,
+    SaverSk = 42,
+    SaverEk = 43,
+    SaverDk = 44,
+    SaverSnarkPk = 45,
+    SaverSnarkVk = 46,
+    SaverCiphertext = 47,
+    SaverSecretKeyShare = 48,
+    SaverPartialDecryption = 49,
+    LegoProvingKey = 50,
+    LegoVerifyingKey = 51,
+    SnarkCeremonyTranscript = 52,
+    Groth16ProvingKey = 53,
+    Groth16VerifyingKey = 54,
+    SaverDecryptionTable = 55,
+    SaverReencryptionProof = 56,
+    SaverPlaintextEqualityProof = 57,
+    SaverMultiRecipientProof = 58,
+    Groth16Proof = 59,
+    Statement = 60,
+    SetupParams = 61,
+    ProofSpec = 62,
+    Proof = 63,
+    OrMetaStatement = 64,
+    OrProof = 65,
+    ProofSpecWithOr = 66,
+    R1CS = 67,
+    CompositeProofWithOr = 68,
+    OrWitnessEquality = 69,
+    IssuerPolicy = 70,
+    IssuerHidingProof = 71,
+    InteractiveProverState = 72,
+    SaverEncryptionKeyContribution = 73,
+    FrostRound1State = 80,
+    FrostRound1Message = 81,
+    FrostRound2State = 82,
+    ThresholdPublicKey = 83,
+    PublicKeyBaseG1 = 84,
+    PublicKeyBaseG2 = 85,
+    BaseOTPhase = 90,
+    BaseOTPhaseOutput = 91,
+    Phase1 = 92,
+    Phase1Output = 93,
+    Phase2 = 94,
+    Phase2Output = 95,
+    GadgetVector = 96,
+    SchnorrPoKProtocol = 100,
+    SchnorrPoKProof = 101,
+    DLEqualityProtocol = 102,
+    DLEqualityProof = 103,
+    ProofOfPossession = 104,
+    DkgithParams = 110,
+    DkgithSecretKey = 111,
+    DkgithPublicKey = 112,
+    DkgithProof = 113,
+    FieldElement = 120,
+    G1Element = 121,
+    G2Element = 122,
+);
+
+impl ArtifactType {
+    /// Type for the name that the wasm functions use for an object in their error messages. Returns `None` for
+    /// names that don't identify a single type of object like "PublicKey".
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Some(t) = Self::from_canonical_name(name) {
+            return Some(t);
+        }
+        match name {
+            "MembershipPrk" => Some(ArtifactType::MembershipProvingKey),
+            "NonMembershipPrk" => Some(ArtifactType::NonMembershipProvingKey),
+            "EncryptionGenerators" => Some(ArtifactType::SaverEncryptionGens),
+            "SaverEncryptionKey" => Some(ArtifactType::SaverEk),
+            "SaverProvingKey" => Some(ArtifactType::SaverSnarkPk),
+            "SaverVerifyingKey" => Some(ArtifactType::SaverSnarkVk),
+            "LegoSnarkProvingKey" => Some(ArtifactType::LegoProvingKey),
+            "LegoSnarkVerifyingKey" => Some(ArtifactType::LegoVerifyingKey),
+            "ProofSpecG1" | "ProofSpecG2" => Some(ArtifactType::ProofSpec),
+            "BaseOTOutput" => Some(ArtifactType::BaseOTPhaseOutput),
+            "Fr" => Some(ArtifactType::FieldElement),
+            "G1Affine" => Some(ArtifactType::G1Element),
+            "G2Affine" => Some(ArtifactType::G2Element),
+            // Statements are named after their kind
+            "PedCommG1Stmt"
+            | "PedCommG2Stmt"
+            | "PoKBBSSignatureG1"
+            | "PoKBBSSignature23G1"
+            | "PokPSStatement"
+            | "AccumMemStatement"
+            | "AccumNonMemStatement"
+            | "SaverProverStmt"
+            | "SaverVerifierStatement"
+            | "BoundCheckLegoProverStmt"
+            | "BoundCheckLegoVerifierStmt"
+            | "R1CSCircomProverStmt"
+            | "R1CSCircomVerifierStmt" => Some(ArtifactType::Statement),
+            _ => None,
+        }
+    }
+
+    /// Whether an object of type `other` can be used where this type is expected. BBS public keys can be used
+    /// wherever BBS+ public keys in G2 are as they are the same kind of key.
+    pub fn accepts(&self, other: ArtifactType) -> bool {
+        *self == other
+            || matches!(
+                (self, other),
+                (ArtifactType::BBSPlusPublicKeyG2, ArtifactType::BBSPublicKey)
+            )
+    }
+}
+
+/// Header of an envelope
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EnvelopeHeader {
+    pub artifact_type: ArtifactType,
+    pub version: u8,
+    pub curve: u8,
+    pub compressed: bool,
+}
+
+impl EnvelopeHeader {
+    pub fn new(artifact_type: ArtifactType, compressed: bool) -> Self {
+        Self {
+            artifact_type,
+            version: FORMAT_VERSION,
+            curve: CURVE_BLS12_381,
+            compressed,
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0; HEADER_SIZE];
+        bytes[..4].copy_from_slice(&MAGIC);
+        bytes[4] = self.version;
+        bytes[5] = self.curve;
+        bytes[6] = if self.compressed { FLAG_COMPRESSED } else { 0 };
+        bytes[7..].copy_from_slice(&self.artifact_type.tag().to_le_bytes());
+        bytes
+    }
+}
+
+/// Returns true if `bytes` start with the envelope's magic. Doesn't check the rest of the header.
+pub fn is_envelope(bytes: &[u8]) -> bool {
+    bytes.len() >= HEADER_SIZE && bytes[..4] == MAGIC
+}
+
+/// Parse the header of an envelope and return it along with the payload
+pub fn inspect(bytes: &[u8]) -> Result<(EnvelopeHeader, &[u8])> {
+    if !is_envelope(bytes) {
+        return Err(Error::new(
+            ErrorCode::InvalidEnvelope,
+            "Bytes are not an envelope",
+        ));
+    }
+    if bytes[4] != FORMAT_VERSION {
+        return Err(Error::new(
+            ErrorCode::InvalidEnvelope,
+            format!("Unsupported envelope version {}", bytes[4]),
+        ));
+    }
+    if bytes[5] != CURVE_BLS12_381 {
+        return Err(Error::new(
+            ErrorCode::InvalidEnvelope,
+            format!("Unsupported curve id {} in envelope", bytes[5]),
+        ));
+    }
+    let tag = u16::from_le_bytes([bytes[7], bytes[8]]);
+    let artifact_type = ArtifactType::from_tag(tag).ok_or_else(|| {
+        Error::new(
+            ErrorCode::InvalidEnvelope,
+            format!("Unknown type tag {} in envelope", tag),
+        )
+    })?;
+    let header = EnvelopeHeader {
+        artifact_type,
+        version: bytes[4],
+        curve: bytes[5],
+        compressed: bytes[6] & FLAG_COMPRESSED != 0,
+    };
+    Ok((header, &bytes[HEADER_SIZE..]))
+}
+
+/// Put the serialized object in an envelope
+pub fn wrap(payload: &[u8], artifact_type: ArtifactType, compressed: bool) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + payload.len());
+    bytes.extend_from_slice(&EnvelopeHeader::new(artifact_type, compressed).to_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// Return the payload of the envelope after checking that it has the expected type, if given
+pub fn unwrap(
+    bytes: &[u8],
+    expected_type: Option<ArtifactType>,
+) -> Result<(EnvelopeHeader, &[u8])> {
+    let (header, payload) = inspect(bytes)?;
+    if let Some(expected) = expected_type {
+        if !expected.accepts(header.artifact_type) {
+            return Err(Error::new(
+                ErrorCode::ArtifactTypeMismatch,
+                format!(
+                    "Expected a {} but found a {}",
+                    expected.name(),
+                    header.artifact_type.name()
+                ),
+            ));
+        }
+    }
+    Ok((header, payload))
+}
+
+/// Return the bytes to deserialize an object named `obj_name` from. `bytes` are returned as it is if envelope input
+/// is disabled or they are not an envelope, else the envelope's payload after checking its type and compression.
+pub fn open<'a>(bytes: &'a [u8], obj_name: Option<&str>, compressed: bool) -> Result<&'a [u8]> {
+    if !envelope_input() || !is_envelope(bytes) {
+        return Ok(bytes);
+    }
+    let (header, payload) = unwrap(bytes, obj_name.and_then(ArtifactType::from_name))?;
+    if header.compressed != compressed {
+        return Err(Error::new(
+            ErrorCode::InvalidEnvelope,
+            format!(
+                "Expected {} bytes but the envelope has {} bytes",
+                compression_name(compressed),
+                compression_name(header.compressed)
+            ),
+        ));
+    }
+    Ok(payload)
+}
+
+/// Header to put before the serialized object named `obj_name` if envelope output is enabled and the name
+/// identifies a type
+pub fn header_for_output(obj_name: &str, compressed: bool) -> Option<[u8; HEADER_SIZE]> {
+    if !envelope_output() {
+        return None;
+    }
+    ArtifactType::from_name(obj_name).map(|t| EnvelopeHeader::new(t, compressed).to_bytes())
+}
+
+/// Reader over the bytes to deserialize an object from, returned by `open_reader`
+pub struct PayloadReader<R: Read> {
+    /// Bytes read while looking for a header that turned out to be part of the object
+    prefix: Vec<u8>,
+    prefix_pos: usize,
+    inner: R,
+}
+
+impl<R: Read> Read for PayloadReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> ark_std::io::Result<usize> {
+        if self.prefix_pos < self.prefix.len() {
+            let n = buf.len().min(self.prefix.len() - self.prefix_pos);
+            buf[..n].copy_from_slice(&self.prefix[self.prefix_pos..self.prefix_pos + n]);
+            self.prefix_pos += n;
+            return Ok(n);
+        }
+        self.inner.read(buf)
+    }
+}
+
+/// Same as `open` but for bytes read from `reader` so that they never have to be in memory at once. Only the first
+/// `HEADER_SIZE` bytes are read to check for an envelope and nothing is read if envelope input is disabled.
+pub fn open_reader<R: Read>(
+    mut reader: R,
+    obj_name: Option<&str>,
+    compressed: bool,
+) -> Result<PayloadReader<R>> {
+    if !envelope_input() {
+        return Ok(PayloadReader {
+            prefix: vec![],
+            prefix_pos: 0,
+            inner: reader,
+        });
+    }
+    let mut header = [0; HEADER_SIZE];
+    let mut len = 0;
+    while len < HEADER_SIZE {
+        let n = reader
+            .read(&mut header[len..])
+            .map_err(|e| Error::from(SerializationError::IoError(e)))?;
+        if n == 0 {
+            break;
+        }
+        len += n;
+    }
+    let prefix = if is_envelope(&header[..len]) {
+        open(&header, obj_name, compressed)?;
+        vec![]
+    } else {
+        header[..len].to_vec()
+    };
+    Ok(PayloadReader {
+        prefix,
+        prefix_pos: 0,
+        inner: reader,
+    })
+}
+
+/// Deserialize an object named `obj_name` from bytes read from `reader`, which can also be an envelope if envelope
+/// input is enabled
+pub fn deserialize_from_reader<T: CanonicalDeserialize, R: Read>(
+    reader: R,
+    obj_name: &str,
+    compressed: bool,
+) -> Result<T> {
+    open_reader(reader, Some(obj_name), compressed)
+        .and_then(|r| {
+            Ok(if compressed {
+                T::deserialize_compressed(r)?
+            } else {
+                T::deserialize_uncompressed(r)?
+            })
+        })
+        .map_err(|e| e.context(format!("Failed to deserialize a {} from bytes", obj_name)))
+}
+
+fn compression_name(compressed: bool) -> &'static str {
+    if compressed {
+        "compressed"
+    } else {
+        "uncompressed"
+    }
+}
@@ -0,0 +1,603 @@
+use ::bbs_plus::error::BBSPlusError;
+use ::saver::error::SaverError;
+use ark_serialize::SerializationError;
+use coconut_crypto::{BlindPSError, MessagesPoKError, PSError, SignaturePoKError};
+use core::fmt;
+use legogroth16::{
+    aggregation::error::AggregationError, circom::CircomError, error::Error as LegoGroth16Error,
+};
+use oblivious_transfer_protocols::error::OTError;
+use proof_system::prelude::ProofSystemError;
+use schnorr_pok::error::SchnorrError;
+use secret_sharing_and_dkg::error::SSError;
+use vb_accumulator::error::VBAccumulatorError;
+use wasm_bindgen::JsValue;
+
+/// Broad class of an error. A caller can use it to decide what to do with the error without knowing every code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// Bytes or a JS value couldn't be converted to the expected object
+    Deserialization,
+    /// An object couldn't be converted to bytes
+    Serialization,
+    /// An argument is not acceptable like wrong number of messages, out of bounds index or incompatible params
+    InvalidParams,
+    /// A signature, proof, witness, share or decryption didn't verify
+    VerificationFailed,
+    /// A message of a multi-party protocol was unexpected in the current state of the protocol
+    ProtocolState,
+    /// Anything else. This usually means a bug or an unsupported input.
+    Internal,
+}
+
+impl ErrorCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Deserialization => "deserialization",
+            ErrorCategory::Serialization => "serialization",
+            ErrorCategory::InvalidParams => "invalid-params",
+            ErrorCategory::VerificationFailed => "verification-failed",
+            ErrorCategory::ProtocolState => "protocol-state",
+            ErrorCategory::Internal => "internal",
+        }
+    }
+}
+
+/// Stable code of an error. The strings returned by `as_str` are part of the API and won't change across
+/// releases, unlike the error messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    DeserializationFailed,
+    SerializationFailed,
+    InvalidArgument,
+    MessageCountMismatch,
+    IndexOutOfBounds,
+    IncompatibleParams,
+    InvalidAccumulatorUpdate,
+    InvalidSignature,
+    InvalidProof,
+    InvalidDecryption,
+    InvalidShare,
+    UnsatisfiedWitnessEquality,
+    ProofSpecMismatch,
+    UnexpectedParticipant,
+    DuplicateMessage,
+    MissingMessage,
+    InvalidProtocolState,
+    InvalidEnvelope,
+    ArtifactTypeMismatch,
+    InvalidKeyEncoding,
+    CurveMismatch,
+    RngFailure,
+    Internal,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::DeserializationFailed => "DESERIALIZATION_FAILED",
+            ErrorCode::SerializationFailed => "SERIALIZATION_FAILED",
+            ErrorCode::InvalidArgument => "INVALID_ARGUMENT",
+            ErrorCode::MessageCountMismatch => "MESSAGE_COUNT_MISMATCH",
+            ErrorCode::IndexOutOfBounds => "INDEX_OUT_OF_BOUNDS",
+            ErrorCode::IncompatibleParams => "INCOMPATIBLE_PARAMS",
+            ErrorCode::InvalidAccumulatorUpdate => "INVALID_ACCUMULATOR_UPDATE",
+            ErrorCode::InvalidSignature => "INVALID_SIGNATURE",
+            ErrorCode::InvalidProof => "INVALID_PROOF",
+            ErrorCode::InvalidDecryption => "INVALID_DECRYPTION",
+            ErrorCode::InvalidShare => "INVALID_SHARE",
+            ErrorCode::UnsatisfiedWitnessEquality => "UNSATISFIED_WITNESS_EQUALITY",
+            ErrorCode::ProofSpecMismatch => "PROOF_SPEC_MISMATCH",
+            ErrorCode::UnexpectedParticipant => "UNEXPECTED_PARTICIPANT",
+            ErrorCode::DuplicateMessage => "DUPLICATE_MESSAGE",
+            ErrorCode::MissingMessage => "MISSING_MESSAGE",
+            ErrorCode::InvalidProtocolState => "INVALID_PROTOCOL_STATE",
+            ErrorCode::InvalidEnvelope => "INVALID_ENVELOPE",
+            ErrorCode::ArtifactTypeMismatch => "ARTIFACT_TYPE_MISMATCH",
+            ErrorCode::InvalidKeyEncoding => "INVALID_KEY_ENCODING",
+            ErrorCode::CurveMismatch => "CURVE_MISMATCH",
+            ErrorCode::RngFailure => "RNG_FAILURE",
+            ErrorCode::Internal => "INTERNAL_ERROR",
+        }
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            ErrorCode::DeserializationFailed
+            | ErrorCode::InvalidEnvelope
+            | ErrorCode::InvalidKeyEncoding => ErrorCategory::Deserialization,
+            ErrorCode::SerializationFailed => ErrorCategory::Serialization,
+            ErrorCode::InvalidArgument
+            | ErrorCode::MessageCountMismatch
+            | ErrorCode::IndexOutOfBounds
+            | ErrorCode::IncompatibleParams
+            | ErrorCode::InvalidAccumulatorUpdate
+            | ErrorCode::ArtifactTypeMismatch
+            | ErrorCode::CurveMismatch => ErrorCategory::InvalidParams,
+            ErrorCode::InvalidSignature
+            | ErrorCode::InvalidProof
+            | ErrorCode::InvalidDecryption
+            | ErrorCode::InvalidShare
+            | ErrorCode::UnsatisfiedWitnessEquality
+            | ErrorCode::ProofSpecMismatch => ErrorCategory::VerificationFailed,
+            ErrorCode::UnexpectedParticipant
+            | ErrorCode::DuplicateMessage
+            | ErrorCode::MissingMessage
+            | ErrorCode::InvalidProtocolState => ErrorCategory::ProtocolState,
+            ErrorCode::RngFailure | ErrorCode::Internal => ErrorCategory::Internal,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    BBSPlus(BBSPlusError),
+    PS(PSError),
+    BlindPS(BlindPSError),
+    PSSignaturePoK(SignaturePoKError),
+    PSMessagesPoK(MessagesPoKError),
+    Accumulator(VBAccumulatorError),
+    Saver(SaverError),
+    LegoGroth16(LegoGroth16Error),
+    Circom(CircomError),
+    ProofSystem(ProofSystemError),
+    SecretSharing(SSError),
+    OT(OTError),
+    Schnorr(SchnorrError),
+    Serialization(SerializationError),
+    /// Error raised by this crate rather than by one of the libraries
+    Other {
+        code: ErrorCode,
+        message: String,
+        details: Option<String>,
+    },
+    /// Another error with a description of what was being done when it happened
+    Context(String, Box<Error>),
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Error::Other {
+            code,
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Same as `new` but `details` is the debug representation of the cause
+    pub fn with_details(
+        code: ErrorCode,
+        message: impl Into<String>,
+        details: impl fmt::Debug,
+    ) -> Self {
+        Error::Other {
+            code,
+            message: message.into(),
+            details: Some(format!("{:?}", details)),
+        }
+    }
+
+    pub fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidArgument, message)
+    }
+
+    /// Prefix the message with `context` keeping the code and details
+    pub fn context(self, context: impl Into<String>) -> Self {
+        Error::Context(context.into(), Box::new(self))
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::BBSPlus(e) => bbs_plus_error_code(e),
+            Error::PS(e) => ps_error_code(e),
+            Error::BlindPS(e) => blind_ps_error_code(e),
+            Error::PSSignaturePoK(e) => ps_signature_pok_error_code(e),
+            Error::PSMessagesPoK(e) => ps_messages_pok_error_code(e),
+            Error::Accumulator(e) => accumulator_error_code(e),
+            Error::Saver(e) => saver_error_code(e),
+            Error::LegoGroth16(e) => legogroth16_error_code(e),
+            Error::Circom(e) => circom_error_code(e),
+            Error::ProofSystem(e) => proof_system_error_code(e),
+            Error::SecretSharing(e) => secret_sharing_error_code(e),
+            Error::OT(_) => ErrorCode::InvalidProtocolState,
+            Error::Schnorr(e) => schnorr_error_code(e),
+            Error::Serialization(e) => serialization_error_code(e),
+            Error::Other { code, .. } => *code,
+            Error::Context(_, e) => e.code(),
+        }
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        self.code().category()
+    }
+
+    /// Debug representation of the error returned by the underlying library, if any
+    pub fn details(&self) -> Option<String> {
+        match self {
+            Error::BBSPlus(e) => Some(format!("{:?}", e)),
+            Error::PS(e) => Some(format!("{:?}", e)),
+            Error::BlindPS(e) => Some(format!("{:?}", e)),
+            Error::PSSignaturePoK(e) => Some(format!("{:?}", e)),
+            Error::PSMessagesPoK(e) => Some(format!("{:?}", e)),
+            Error::Accumulator(e) => Some(format!("{:?}", e)),
+            Error::Saver(e) => Some(format!("{:?}", e)),
+            Error::LegoGroth16(e) => Some(format!("{:?}", e)),
+            Error::Circom(e) => Some(format!("{:?}", e)),
+            Error::ProofSystem(e) => Some(format!("{:?}", e)),
+            Error::SecretSharing(e) => Some(format!("{:?}", e)),
+            Error::OT(e) => Some(format!("{:?}", e)),
+            Error::Schnorr(e) => Some(format!("{:?}", e)),
+            Error::Serialization(e) => Some(format!("{:?}", e)),
+            Error::Other { details, .. } => details.clone(),
+            Error::Context(_, e) => e.details(),
+        }
+    }
+}
+
+/// Shows the error of the underlying library as the wasm functions did before this module was added
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Other { message, .. } => write!(f, "{}", message),
+            Error::Context(context, e) => write!(f, "{}: {}", context, e),
+            e => write!(f, "{}", e.details().unwrap_or_default()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Converts to a JS `Error` object with additional `code`, `category` and `details` fields
+impl From<Error> for JsValue {
+    fn from(e: Error) -> Self {
+        let js_error = js_sys::Error::new(&e.to_string());
+        let code = e.code();
+        let details = match e.details() {
+            Some(d) => JsValue::from(d),
+            None => JsValue::UNDEFINED,
+        };
+        // Setting a property on a newly created `Error` can't fail
+        js_sys::Reflect::set(&js_error, &"code".into(), &code.as_str().into()).unwrap();
+        js_sys::Reflect::set(
+            &js_error,
+            &"category".into(),
+            &code.category().as_str().into(),
+        )
+        .unwrap();
+        js_sys::Reflect::set(&js_error, &"details".into(), &details).unwrap();
+        js_error.into()
+    }
+}
+
+macro_rules! impl_from_error {
+    ($($variant: ident($err: ty)),+) => {
+        $(
+            impl From<$err> for Error {
+                fn from(e: $err) -> Self {
+                    Error::$variant(e)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_error!(
+    BBSPlus(BBSPlusError),
+    PS(PSError),
+    BlindPS(BlindPSError),
+    PSSignaturePoK(SignaturePoKError),
+    PSMessagesPoK(MessagesPoKError),
+    Accumulator(VBAccumulatorError),
+    Saver(SaverError),
+    LegoGroth16(LegoGroth16Error),
+    Circom(CircomError),
+    ProofSystem(ProofSystemError),
+    SecretSharing(SSError),
+    OT(OTError),
+    Schnorr(SchnorrError),
+    Serialization(SerializationError)
+);
+
+fn serialization_error_code(e: &SerializationError) -> ErrorCode {
+    match e {
+        SerializationError::NotEnoughSpace => ErrorCode::SerializationFailed,
+        _ => ErrorCode::DeserializationFailed,
+    }
+}
+
+fn schnorr_error_code(e: &SchnorrError) -> ErrorCode {
+    match e {
+        SchnorrError::ExpectedSameSizeSequences(_, _) => ErrorCode::InvalidArgument,
+        SchnorrError::IndexOutOfBounds(_, _) => ErrorCode::IndexOutOfBounds,
+        SchnorrError::InvalidResponse => ErrorCode::InvalidProof,
+        SchnorrError::Serialization(e) => serialization_error_code(e),
+    }
+}
+
+fn bbs_plus_error_code(e: &BBSPlusError) -> ErrorCode {
+    match e {
+        BBSPlusError::NoMessageToSign
+        | BBSPlusError::MessageCountIncompatibleWithSigParams(_, _) => {
+            ErrorCode::MessageCountMismatch
+        }
+        BBSPlusError::CannotInvert0
+        | BBSPlusError::MessageIndicesMustBeUniqueAndSorted(_)
+        | BBSPlusError::IncorrectNoOfCommitments(_, _)
+        | BBSPlusError::IncorrectNoOfShares(_, _) => ErrorCode::InvalidArgument,
+        BBSPlusError::InvalidMsgIdxForResponse(_) | BBSPlusError::MessageIndexIsOutOfBounds(_) => {
+            ErrorCode::IndexOutOfBounds
+        }
+        BBSPlusError::ZeroSignature | BBSPlusError::InvalidSignature => ErrorCode::InvalidSignature,
+        BBSPlusError::PairingCheckFailed
+        | BBSPlusError::FirstSchnorrVerificationFailed
+        | BBSPlusError::SecondSchnorrVerificationFailed => ErrorCode::InvalidProof,
+        BBSPlusError::Serialization(e) => serialization_error_code(e),
+        BBSPlusError::SchnorrError(e) => schnorr_error_code(e),
+        BBSPlusError::OTError(_) => ErrorCode::InvalidProtocolState,
+        BBSPlusError::IncorrectCommitment
+        | BBSPlusError::IncorrectEByParticipant(_)
+        | BBSPlusError::IncorrectSByParticipant(_) => ErrorCode::InvalidShare,
+        BBSPlusError::AlreadyHaveCommitmentFromParticipant(_)
+        | BBSPlusError::AlreadyHaveSharesFromParticipant(_)
+        | BBSPlusError::AlreadyHaveSenderPubkeyFrom(_)
+        | BBSPlusError::AlreadyHaveReceiverPubkeyFrom(_)
+        | BBSPlusError::AlreadyHaveChallengesFrom(_) => ErrorCode::DuplicateMessage,
+        BBSPlusError::MissingCommitmentFromParticipant(_)
+        | BBSPlusError::MissingSharesFromParticipant(_)
+        | BBSPlusError::MissingOTReceiverFor(_)
+        | BBSPlusError::MissingOTSenderFor(_) => ErrorCode::MissingMessage,
+        BBSPlusError::SenderIdCannotBeSameAsSelf(_, _)
+        | BBSPlusError::UnexpectedParticipant(_)
+        | BBSPlusError::NotAMultiplicationParty2(_)
+        | BBSPlusError::NotAMultiplicationParty1(_)
+        | BBSPlusError::UnexpectedMultiplicationParty1(_)
+        | BBSPlusError::UnexpectedMultiplicationParty2(_)
+        | BBSPlusError::ParticipantCannotBePresentInOthers(_)
+        | BBSPlusError::NotABaseOTSender(_)
+        | BBSPlusError::NotABaseOTReceiver(_) => ErrorCode::UnexpectedParticipant,
+        BBSPlusError::ReceiverNotReadyForChallengeFrom(_)
+        | BBSPlusError::SenderEitherNotReadyForResponseOrAlreadySentIt(_)
+        | BBSPlusError::ReceiverEitherNotReadyForHashedKeysOrAlreadyVerifiedIt(_) => {
+            ErrorCode::InvalidProtocolState
+        }
+    }
+}
+
+fn ps_error_code(e: &PSError) -> ErrorCode {
+    match e {
+        PSError::NoMessages | PSError::InvalidMessageCount { .. } => {
+            ErrorCode::MessageCountMismatch
+        }
+        PSError::MessageIndicesMustBeUniqueAndSorted(_) => ErrorCode::InvalidArgument,
+        PSError::MessageIndexIsOutOfBounds(_) => ErrorCode::IndexOutOfBounds,
+        PSError::ZeroSignature | PSError::PairingCheckFailed => ErrorCode::InvalidSignature,
+    }
+}
+
+fn blind_ps_error_code(e: &BlindPSError) -> ErrorCode {
+    match e {
+        BlindPSError::NoCommitmentsOrMessages
+        | BlindPSError::InvalidCommitmentsAndMessagesCount { .. } => {
+            ErrorCode::MessageCountMismatch
+        }
+        BlindPSError::IndexIsOutOfBounds(_) => ErrorCode::IndexOutOfBounds,
+        BlindPSError::BlindingIndicesMustBeUniqueAndSorted(_) => ErrorCode::InvalidArgument,
+        BlindPSError::IncompatibleVerificationKey => ErrorCode::IncompatibleParams,
+    }
+}
+
+fn ps_signature_pok_error_code(e: &SignaturePoKError) -> ErrorCode {
+    match e {
+        SignaturePoKError::MessageInputError(_) => ErrorCode::MessageCountMismatch,
+        SignaturePoKError::RevealedIndicesMustBeUniqueAndSorted { .. } => {
+            ErrorCode::InvalidArgument
+        }
+        SignaturePoKError::SchnorrError(_) => ErrorCode::InvalidProof,
+        SignaturePoKError::SignatureError(e) => ps_error_code(e),
+    }
+}
+
+fn ps_messages_pok_error_code(e: &MessagesPoKError) -> ErrorCode {
+    match e {
+        MessagesPoKError::MessageInputError(_)
+        | MessagesPoKError::IncompatibleComJAndMessages { .. }
+        | MessagesPoKError::SchnorrResponsesHaveDifferentLength => ErrorCode::MessageCountMismatch,
+        MessagesPoKError::RevealedIndicesMustBeUniqueAndSorted { .. } => ErrorCode::InvalidArgument,
+        MessagesPoKError::ComProofGenerationFailed(_)
+        | MessagesPoKError::ComJProofGenerationFailed { .. } => ErrorCode::Internal,
+        MessagesPoKError::SchnorrResponsesNotEqual(_)
+        | MessagesPoKError::InvalidComProof(_)
+        | MessagesPoKError::InvalidComJProof { .. } => ErrorCode::InvalidProof,
+    }
+}
+
+fn accumulator_error_code(e: &VBAccumulatorError) -> ErrorCode {
+    match e {
+        VBAccumulatorError::ProhibitedElement
+        | VBAccumulatorError::AccumulatorFull
+        | VBAccumulatorError::BatchExceedsAccumulatorCapacity
+        | VBAccumulatorError::ElementPresent
+        | VBAccumulatorError::ElementAbsent
+        | VBAccumulatorError::NewElementSameAsCurrent => ErrorCode::InvalidAccumulatorUpdate,
+        VBAccumulatorError::NeedSameNoOfElementsAndWitnesses | VBAccumulatorError::CannotBeZero => {
+            ErrorCode::InvalidArgument
+        }
+        VBAccumulatorError::SigmaResponseInvalid
+        | VBAccumulatorError::RhoResponseInvalid
+        | VBAccumulatorError::DeltaSigmaResponseInvalid
+        | VBAccumulatorError::DeltaRhoResponseInvalid
+        | VBAccumulatorError::PairingResponseInvalid
+        | VBAccumulatorError::E_d_ResponseInvalid
+        | VBAccumulatorError::E_d_inv_ResponseInvalid => ErrorCode::InvalidProof,
+        VBAccumulatorError::Serialization(e) => serialization_error_code(e),
+        VBAccumulatorError::SchnorrError(e) => schnorr_error_code(e),
+    }
+}
+
+fn legogroth16_error_code(e: &LegoGroth16Error) -> ErrorCode {
+    match e {
+        LegoGroth16Error::InvalidProof
+        | LegoGroth16Error::InvalidLinkCommitment
+        | LegoGroth16Error::InvalidWitnessCommitment => ErrorCode::InvalidProof,
+        LegoGroth16Error::VectorLongerThanExpected(_, _)
+        | LegoGroth16Error::InsufficientWitnessesForCommitment(_, _) => ErrorCode::InvalidArgument,
+        LegoGroth16Error::CircomError(e) => circom_error_code(e),
+        LegoGroth16Error::AggregationError(e) => match e {
+            AggregationError::InvalidProof(_) => ErrorCode::InvalidProof,
+            AggregationError::Serialization(_) => ErrorCode::DeserializationFailed,
+            AggregationError::InsufficientKeyLength(_)
+            | AggregationError::PublicInputsTooLarge(_)
+            | AggregationError::TooManyProofs(_)
+            | AggregationError::InvalidKeyLength
+            | AggregationError::MalformedVerifyingKey
+            | AggregationError::InvalidSRS(_) => ErrorCode::IncompatibleParams,
+        },
+        LegoGroth16Error::SynthesisError(_) | LegoGroth16Error::LinkError(_) => ErrorCode::Internal,
+    }
+}
+
+fn circom_error_code(e: &CircomError) -> ErrorCode {
+    match e {
+        CircomError::UnsupportedVersion(_)
+        | CircomError::UnsupportedCurve(_)
+        | CircomError::IncompatibleWithCurve => ErrorCode::IncompatibleParams,
+        CircomError::IncorrectNumberOfInputsProvided(_, _)
+        | CircomError::IncorrectNumberOfSignalsProvided(_, _, _) => ErrorCode::InvalidArgument,
+        CircomError::UnableToOpenR1CSFile(_)
+        | CircomError::UnableToLoadWasmModuleFromFile(_)
+        | CircomError::UnableToLoadWasmModuleFromBytes(_)
+        | CircomError::R1CSFileParsing(_) => ErrorCode::DeserializationFailed,
+        CircomError::UnknownWasmFunction(_)
+        | CircomError::WasmFunctionCallFailed(_)
+        | CircomError::WasmFunctionResultEmpty(_)
+        | CircomError::WasmFunctionResultNoti32(_)
+        | CircomError::WasmInstantiationError(_) => ErrorCode::Internal,
+    }
+}
+
+fn saver_error_code(e: &SaverError) -> ErrorCode {
+    match e {
+        SaverError::UnexpectedBase(_)
+        | SaverError::InvalidDecomposition
+        | SaverError::AtLeastOneNonNoneRequired
+        | SaverError::VectorShorterThanExpected(_, _) => ErrorCode::InvalidArgument,
+        SaverError::MalformedEncryptionKey(_, _)
+        | SaverError::MalformedDecryptionKey(_, _)
+        | SaverError::IncompatibleEncryptionKey(_, _)
+        | SaverError::IncompatibleDecryptionKey(_, _) => ErrorCode::IncompatibleParams,
+        SaverError::InvalidProof
+        | SaverError::InvalidCommitment
+        | SaverError::InvalidPairingPowers
+        | SaverError::PairingCheckFailed => ErrorCode::InvalidProof,
+        SaverError::InvalidDecryption | SaverError::CouldNotFindDiscreteLog => {
+            ErrorCode::InvalidDecryption
+        }
+        SaverError::LegoGroth16Error(e) => legogroth16_error_code(e),
+        SaverError::SynthesisError(_) => ErrorCode::Internal,
+    }
+}
+
+fn secret_sharing_error_code(e: &SSError) -> ErrorCode {
+    match e {
+        SSError::InvalidThresholdOrTotal(_, _)
+        | SSError::BelowThreshold(_, _)
+        | SSError::UnequalThresholdInReceivedShare(_, _)
+        | SSError::DoesNotSupportThreshold(_) => ErrorCode::InvalidArgument,
+        SSError::InvalidShare => ErrorCode::InvalidShare,
+        SSError::InvalidProofOfSecretKeyKnowledge => ErrorCode::InvalidProof,
+        SSError::SchnorrError(e) => schnorr_error_code(e),
+        SSError::InvalidParticipantId(_)
+        | SSError::UnequalParticipantAndShareId(_, _)
+        | SSError::SenderIdSameAsReceiver(_, _)
+        | SSError::CannotRemoveSelf(_)
+        | SSError::ParticipantNotAllowedInPhase2(_) => ErrorCode::UnexpectedParticipant,
+        SSError::AlreadyProcessedFromSender(_) => ErrorCode::DuplicateMessage,
+        SSError::MissingSomeParticipants(_) => ErrorCode::MissingMessage,
+    }
+}
+
+fn proof_system_error_code(e: &ProofSystemError) -> ErrorCode {
+    use ProofSystemError::*;
+    match e {
+        BBSPlusProtocolInvalidMessageCount(_, _) | PSProtocolInvalidMessageCount(_, _) => {
+            ErrorCode::MessageCountMismatch
+        }
+        UnequalWitnessAndStatementCount(_, _)
+        | WitnessIncompatibleWithStatement(_, _, _)
+        | ProofIncompatibleWithStatement(_, _, _)
+        | ProofIncompatibleWithBBSPlusProtocol
+        | ProofIncompatibleWithSchnorrProtocol
+        | ProofIncompatibleWithAccumulatorMembershipProtocol
+        | ProofIncompatibleWithAccumulatorNonMembershipProtocol
+        | ProofIncompatibleWithSaverProtocol
+        | ProofIncompatibleWithBoundCheckProtocol
+        | BBSProtocolNonSequentialMessageIndices(_)
+        | BBSProtocolMessageIndicesMustStartFromZero(_)
+        | PSProtocolNonSequentialMessageIndices(_)
+        | PSProtocolMessageIndicesMustStartFromZero(_)
+        | TooManyCifertexts(_)
+        | InvalidProofSpec
+        | InvalidStatement
+        | InvalidWitnessEqualities(_)
+        | InvalidWitnessEquality
+        | UnsatisfiedStatements(_, _)
+        | BoundCheckMaxNotGreaterThanMin
+        | R1CSInsufficientPrivateInputs(_, _)
+        | WitnessAlreadyBeingRevealed(_, _)
+        | NotASaverStatementProof
+        | SameStatementIdsFoundInMultipleAggregations(_)
+        | NoAggregateGroth16ProofFound
+        | InvalidNumberOfAggregateGroth16Proofs(_, _)
+        | NotFoundAggregateGroth16ProofForRequiredStatements(_, _)
+        | NoAggregateLegoGroth16ProofFound
+        | InvalidNumberOfAggregateLegoGroth16Proofs(_, _)
+        | NotFoundAggregateLegoGroth16ProofForRequiredStatements(_, _) => {
+            ErrorCode::InvalidArgument
+        }
+        BBSProtocolInvalidBlindingIndex(_)
+        | PSProtocolInvalidBlindingIndex(_)
+        | PSProtocolInvalidMessageIndex(_, _)
+        | InvalidSetupParamsIndex(_)
+        | InvalidStatementProofIndex(_) => ErrorCode::IndexOutOfBounds,
+        NeitherParamsNorRefGiven(_)
+        | IncompatibleBBSPlusSetupParamAtIndex(_)
+        | IncompatiblePSSetupParamAtIndex(_)
+        | IncompatiblePedCommSetupParamAtIndex(_)
+        | IncompatibleAccumulatorSetupParamAtIndex(_)
+        | IncompatibleSaverSetupParamAtIndex(_)
+        | IncompatibleBoundCheckSetupParamAtIndex(_)
+        | IncompatibleR1CSSetupParamAtIndex(_)
+        | SaverSnarkProvingKeyNotProvided
+        | SaverSnarkVerifyingKeyNotProvided
+        | LegoGroth16ProvingKeyNotProvided
+        | LegoGroth16VerifyingKeyNotProvided
+        | SnarckpackSrsNotProvided => ErrorCode::IncompatibleParams,
+        SubProtocolNotReadyToGenerateChallenge(_)
+        | SubProtocolAlreadyInitialized(_)
+        | SubProtocolNotReadyToGenerateProof(_) => ErrorCode::InvalidProtocolState,
+        WitnessResponseNotEqual(_, _) | UnsatisfiedWitnessEqualities(_) => {
+            ErrorCode::UnsatisfiedWitnessEquality
+        }
+        SaverInequalChunkedCommitment
+        | SaverInsufficientChunkedCommitmentResponses
+        | SaverInequalChunkedCommitmentResponse
+        | LegoGroth16InequalResponse
+        | RandomizedPairingCheckFailed => ErrorCode::InvalidProof,
+        Serialization(e) => serialization_error_code(e),
+        SchnorrError(e) => schnorr_error_code(e),
+        BBSPlusError(e) => bbs_plus_error_code(e),
+        VBAccumError(e) => accumulator_error_code(e),
+        SaverError(e) => saver_error_code(e),
+        LegoGroth16Error(e) => legogroth16_error_code(e),
+        CircomError(e) => circom_error_code(e),
+        PSSignaturePoKError(e) => ps_signature_pok_error_code(e),
+    }
+}
@@ -0,0 +1,86 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use blake2::Blake2b512;
+use secret_sharing_and_dkg::{
+    common::{ParticipantId, PublicKeyBase, Share, ShareId, Shares},
+    feldman_dvss_dkg::reconstruct_threshold_public_key,
+    frost_dkg::{Round1Msg, Round1State, Round2State},
+};
+
+use crate::{
+    native::Result,
+    utils::{get_seeded_rng, random_bytes},
+    Fr,
+};
+
+/// Generate the base for the public keys. A random label is used if `label` isn't given.
+pub fn generate_public_key_base<G: AffineRepr>(label: Option<&[u8]>) -> Result<PublicKeyBase<G>> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(PublicKeyBase::<G>::new::<Blake2b512>(&label))
+}
+
+/// Start Round1 with a random secret. Returns the Round1 state and the message to send to all others.
+pub fn start_round1<G: AffineRepr<ScalarField = Fr>>(
+    participant_id: ParticipantId,
+    threshold: ShareId,
+    total: ShareId,
+    schnorr_proof_ctx: &[u8],
+    pk_base: &PublicKeyBase<G>,
+) -> Result<(Round1State<G>, Round1Msg<G>)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(Round1State::start_with_random_secret::<_, Blake2b512>(
+        &mut rng,
+        participant_id,
+        threshold,
+        total,
+        schnorr_proof_ctx,
+        &pk_base.0,
+    )?)
+}
+
+pub fn round1_process_received_message<G: AffineRepr<ScalarField = Fr>>(
+    round_state: &mut Round1State<G>,
+    msg: Round1Msg<G>,
+    schnorr_proof_ctx: &[u8],
+    pk_base: &PublicKeyBase<G>,
+) -> Result<()> {
+    Ok(round_state.add_received_message::<Blake2b512>(msg, schnorr_proof_ctx, &pk_base.0)?)
+}
+
+/// Finish Round1. Returns the Round2 state and the shares to send to the others.
+pub fn finish_round1<G: AffineRepr<ScalarField = Fr>>(
+    round_state: Round1State<G>,
+) -> Result<(Round2State<G>, Shares<Fr>)> {
+    Ok(round_state.finish()?)
+}
+
+pub fn round2_process_received_share<G: AffineRepr<ScalarField = Fr>>(
+    round_state: &mut Round2State<G>,
+    sender_id: ParticipantId,
+    share: Share<Fr>,
+    pk_base: &PublicKeyBase<G>,
+) -> Result<()> {
+    Ok(round_state.add_received_share(sender_id, share, &pk_base.0)?)
+}
+
+/// Finish Round2. Returns the participant's secret key share, its public key and the threshold public key.
+pub fn finish_round2<G: AffineRepr<ScalarField = Fr>>(
+    round_state: Round2State<G>,
+    pk_base: &PublicKeyBase<G>,
+) -> Result<(Share<Fr>, G, G)> {
+    Ok(round_state.finish(&pk_base.0)?)
+}
+
+pub fn public_key_from_secret_key<G: AffineRepr<ScalarField = Fr>>(
+    secret: &Fr,
+    pk_base: &PublicKeyBase<G>,
+) -> G {
+    (pk_base.0 * secret).into_affine()
+}
+
+/// Get the threshold public key from the public keys of at least `threshold` participants
+pub fn threshold_public_key_from_public_keys<G: AffineRepr>(
+    public_keys: Vec<(ShareId, G)>,
+    threshold: ShareId,
+) -> Result<G> {
+    Ok(reconstruct_threshold_public_key(public_keys, threshold)?)
+}
@@ -0,0 +1,740 @@
+//! Groth16 proofs for Circom circuits that are interoperable with snarkjs, for circuits whose witnesses don't need to
+//! be linked to signed messages in a composite proof (use LegoGroth16 for those).
+//!
+//! snarkjs reduces the R1CS to a QAP differently than arkworks does by evaluating the polynomials on a coset of the
+//! evaluation domain, so its proving keys (zkeys) only work with a prover using the same reduction. `CircomReduction`
+//! is that reduction and is used both for keys read from zkeys and for keys created with `setup`, so keys and proofs
+//! can be exchanged with snarkjs either way. Proofs, verification keys and public signals can be converted to and
+//! from the JSON that snarkjs uses.
+
+use ark_bls12_381::{Bls12_381, Fq, Fq12, Fq2, G1Projective, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, Group};
+use ark_ff::{BigInt, BigInteger, One, PrimeField, Zero};
+use ark_groth16::{
+    prepare_verifying_key,
+    r1cs_to_qap::{LibsnarkReduction, R1CSToQAP},
+    Groth16, Proof, ProvingKey, VerifyingKey,
+};
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
+use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef,
+    OptimizationGoal, Result as R1CSResult, SynthesisError, SynthesisMode,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::UniformRand;
+use legogroth16::circom::{CircomCircuit, R1CS};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    native::{
+        self,
+        binfile::{BinFile, BinReader},
+        Error, ErrorCode,
+    },
+    utils::get_seeded_rng,
+    Fr, G1Affine, G2Affine,
+};
+
+/// Name of the protocol in snarkjs JSON
+pub const SNARKJS_PROTOCOL: &str = "groth16";
+/// Name of BLS12-381 in snarkjs JSON
+pub const SNARKJS_CURVE: &str = "bls12381";
+
+const ZKEY_MAGIC: &[u8; 4] = b"zkey";
+const ZKEY_VERSION: u32 = 1;
+const ZKEY_GROTH16: u32 = 1;
+const FQ_SIZE: usize = 48;
+const FR_SIZE: usize = 32;
+
+/// The R1CS to QAP reduction of snarkjs
+pub struct CircomReduction;
+
+impl R1CSToQAP for CircomReduction {
+    fn instance_map_with_evaluation<F: PrimeField, D: EvaluationDomain<F>>(
+        cs: ConstraintSystemRef<F>,
+        t: &F,
+    ) -> Result<(Vec<F>, Vec<F>, Vec<F>, F, usize, usize), SynthesisError> {
+        LibsnarkReduction::instance_map_with_evaluation::<F, D>(cs, t)
+    }
+
+    fn witness_map_from_matrices<F: PrimeField, D: EvaluationDomain<F>>(
+        matrices: &ConstraintMatrices<F>,
+        num_inputs: usize,
+        num_constraints: usize,
+        full_assignment: &[F],
+    ) -> R1CSResult<Vec<F>> {
+        let domain =
+            D::new(num_constraints + num_inputs).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+        let mut a = vec![F::zero(); domain_size];
+        let mut b = vec![F::zero(); domain_size];
+        for (i, (a_row, b_row)) in matrices
+            .a
+            .iter()
+            .zip(&matrices.b)
+            .take(num_constraints)
+            .enumerate()
+        {
+            a[i] = evaluate_row(a_row, full_assignment);
+            b[i] = evaluate_row(b_row, full_assignment);
+        }
+        // Constraints for the public inputs
+        a[num_constraints..num_constraints + num_inputs]
+            .copy_from_slice(&full_assignment[..num_inputs]);
+        let mut c = a.iter().zip(&b).map(|(a, b)| *a * b).collect::<Vec<_>>();
+
+        // Evaluate the polynomials on the coset of the domain shifted by a root of unity of twice its size
+        let root_of_unity = D::new(2 * domain_size)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?
+            .element(1);
+        for evals in [&mut a, &mut b, &mut c] {
+            domain.ifft_in_place(evals);
+            D::distribute_powers_and_mul_by_const(evals, root_of_unity, F::one());
+            domain.fft_in_place(evals);
+        }
+        Ok(a.iter()
+            .zip(&b)
+            .zip(&c)
+            .map(|((a, b), c)| *a * b - c)
+            .collect())
+    }
+
+    fn h_query_scalars<F: PrimeField, D: EvaluationDomain<F>>(
+        max_power: usize,
+        t: F,
+        _zt: F,
+        delta_inverse: F,
+    ) -> Result<Vec<F>, SynthesisError> {
+        // Lagrange coefficients at `t` for the domain of twice the size, the odd ones being for the coset's points
+        let mut scalars = (0..2 * max_power + 1)
+            .map(|i| delta_inverse * t.pow([i as u64]))
+            .collect::<Vec<_>>();
+        let domain = D::new(scalars.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        domain.ifft_in_place(&mut scalars);
+        Ok(scalars.into_iter().skip(1).step_by(2).collect())
+    }
+}
+
+/// Groth16 proving key for a Circom circuit along with rows of the matrices `A` and `B` of the circuit's constraints
+/// which the prover needs, like a zkey
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CircomProvingKey {
+    pub pk: ProvingKey<Bls12_381>,
+    /// Number of constraints of the circuit, without the ones for the public inputs
+    pub num_constraints: usize,
+    /// Rows of the matrix `A` as pairs of a coefficient and the index of a wire
+    pub a: Vec<Vec<(Fr, usize)>>,
+    /// Rows of the matrix `B` as pairs of a coefficient and the index of a wire
+    pub b: Vec<Vec<(Fr, usize)>>,
+}
+
+/// Verification key in the JSON format of snarkjs
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SnarkjsVerifyingKey {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: Vec<String>,
+    pub vk_beta_2: Vec<Vec<String>>,
+    pub vk_gamma_2: Vec<Vec<String>>,
+    pub vk_delta_2: Vec<Vec<String>>,
+    /// Pairing of `vk_alpha_1` and `vk_beta_2`. Ignored when importing a key.
+    #[serde(default)]
+    pub vk_alphabeta_12: Vec<Vec<Vec<String>>>,
+    #[serde(rename = "IC")]
+    pub ic: Vec<Vec<String>>,
+}
+
+/// Proof in the JSON format of snarkjs
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    pub pi_a: Vec<String>,
+    pub pi_b: Vec<Vec<String>>,
+    pub pi_c: Vec<String>,
+    pub protocol: String,
+    pub curve: String,
+}
+
+/// Create a proving key for the circuit. Wire 0 of the circuit is "1" and is followed by its public inputs and
+/// then the private ones, as in Circom.
+pub fn setup(r1cs: R1CS<Bls12_381>) -> native::Result<CircomProvingKey> {
+    let circuit = CircomCircuit::setup(r1cs);
+    let mut rng = get_seeded_rng()?;
+    let pk = Groth16::<Bls12_381, CircomReduction>::generate_parameters_with_qap(
+        circuit.clone(),
+        Fr::rand(&mut rng),
+        Fr::rand(&mut rng),
+        Fr::rand(&mut rng),
+        Fr::rand(&mut rng),
+        G1Projective::generator(),
+        G2Projective::generator(),
+        &mut rng,
+    )
+    .map_err(|e| Error::with_details(ErrorCode::InvalidArgument, "Groth16 setup failed", e))?;
+    // Same constraint system as the one the key is generated for
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    cs.set_optimization_goal(OptimizationGoal::Constraints);
+    cs.set_mode(SynthesisMode::Setup);
+    circuit.generate_constraints(cs.clone()).map_err(|e| {
+        Error::with_details(
+            ErrorCode::InvalidArgument,
+            "Error while generating constraints",
+            e,
+        )
+    })?;
+    cs.finalize();
+    let matrices = cs
+        .to_matrices()
+        .ok_or_else(|| Error::new(ErrorCode::Internal, "Constraint matrices weren't created"))?;
+    Ok(CircomProvingKey {
+        pk,
+        num_constraints: matrices.num_constraints,
+        a: matrices.a,
+        b: matrices.b,
+    })
+}
+
+/// Create a proof given all wires of the circuit, as calculated by the circuit's witness calculator. Returns the
+/// proof and the public signals, which are the public inputs of the circuit.
+pub fn prove(pk: &CircomProvingKey, wires: &[Fr]) -> native::Result<(Proof<Bls12_381>, Vec<Fr>)> {
+    let num_wires = pk.pk.a_query.len();
+    let num_inputs = pk.pk.vk.gamma_abc_g1.len();
+    if wires.len() != num_wires {
+        return Err(Error::invalid_argument(format!(
+            "Expected {} wires but found {}",
+            num_wires,
+            wires.len()
+        )));
+    }
+    if !wires[0].is_one() {
+        return Err(Error::invalid_argument("The first wire should be 1"));
+    }
+    if num_inputs == 0
+        || num_inputs > num_wires
+        || pk.a.len() < pk.num_constraints
+        || pk.b.len() < pk.num_constraints
+        || pk
+            .a
+            .iter()
+            .chain(&pk.b)
+            .flatten()
+            .any(|(_, i)| *i >= num_wires)
+    {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            "Constraints don't match the proving key",
+        ));
+    }
+    let matrices = ConstraintMatrices {
+        num_instance_variables: num_inputs,
+        num_witness_variables: num_wires - num_inputs,
+        num_constraints: pk.num_constraints,
+        a_num_non_zero: pk.a.iter().map(|r| r.len()).sum(),
+        b_num_non_zero: pk.b.iter().map(|r| r.len()).sum(),
+        c_num_non_zero: 0,
+        a: pk.a.clone(),
+        b: pk.b.clone(),
+        c: vec![],
+    };
+    let mut rng = get_seeded_rng()?;
+    let proof = Groth16::<Bls12_381, CircomReduction>::create_proof_with_reduction_and_matrices(
+        &pk.pk,
+        Fr::rand(&mut rng),
+        Fr::rand(&mut rng),
+        &matrices,
+        num_inputs,
+        pk.num_constraints,
+        wires,
+    )
+    .map_err(|e| Error::with_details(ErrorCode::InvalidArgument, "Groth16 proving failed", e))?;
+    Ok((proof, wires[1..num_inputs].to_vec()))
+}
+
+pub fn verify(
+    vk: &VerifyingKey<Bls12_381>,
+    proof: &Proof<Bls12_381>,
+    public_inputs: &[Fr],
+) -> native::Result<()> {
+    if public_inputs.len() + 1 != vk.gamma_abc_g1.len() {
+        return Err(Error::invalid_argument(format!(
+            "Expected {} public inputs but found {}",
+            vk.gamma_abc_g1.len().saturating_sub(1),
+            public_inputs.len()
+        )));
+    }
+    let verified =
+        Groth16::<Bls12_381>::verify_proof(&prepare_verifying_key(vk), proof, public_inputs)
+            .map_err(|e| {
+                Error::with_details(ErrorCode::InvalidArgument, "Groth16 verification failed", e)
+            })?;
+    if verified {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Groth16 proof verification failed",
+        ))
+    }
+}
+
+/// Read a proving key from a Groth16 zkey of snarkjs for BLS12-381
+pub fn read_zkey(bytes: &[u8]) -> native::Result<CircomProvingKey> {
+    read_zkey_sections(bytes).map_err(|e| e.context("Invalid zkey"))
+}
+
+/// Write the proving key as a zkey that snarkjs can prove with. The zkey has no contributions so snarkjs can't
+/// verify it against a powers of tau file.
+pub fn write_zkey(pk: &CircomProvingKey) -> native::Result<Vec<u8>> {
+    let n_vars = pk.pk.a_query.len();
+    let n_public = pk.pk.vk.gamma_abc_g1.len().saturating_sub(1);
+    let domain_size = pk.pk.h_query.len();
+    if pk.pk.vk.gamma_abc_g1.is_empty()
+        || pk.a.len() < pk.num_constraints
+        || pk.b.len() < pk.num_constraints
+        || domain_size < pk.num_constraints + n_public + 1
+    {
+        return Err(Error::invalid_argument(
+            "Constraints don't match the proving key",
+        ));
+    }
+    let mut bytes = ZKEY_MAGIC.to_vec();
+    write_u32(&mut bytes, ZKEY_VERSION);
+    write_u32(&mut bytes, 10);
+
+    write_section(&mut bytes, 1, |s| write_u32(s, ZKEY_GROTH16));
+    write_section(&mut bytes, 2, |s| {
+        write_u32(s, FQ_SIZE as u32);
+        s.extend_from_slice(&Fq::MODULUS.to_bytes_le());
+        write_u32(s, FR_SIZE as u32);
+        s.extend_from_slice(&Fr::MODULUS.to_bytes_le());
+        write_u32(s, n_vars as u32);
+        write_u32(s, n_public as u32);
+        write_u32(s, domain_size as u32);
+        write_g1(s, &pk.pk.vk.alpha_g1);
+        write_g1(s, &pk.pk.beta_g1);
+        write_g2(s, &pk.pk.vk.beta_g2);
+        write_g2(s, &pk.pk.vk.gamma_g2);
+        write_g1(s, &pk.pk.delta_g1);
+        write_g2(s, &pk.pk.vk.delta_g2);
+    });
+    write_section(&mut bytes, 3, |s| {
+        pk.pk.vk.gamma_abc_g1.iter().for_each(|p| write_g1(s, p))
+    });
+    write_section(&mut bytes, 4, |s| {
+        let rows = |m: &[Vec<(Fr, usize)>]| {
+            m[..pk.num_constraints]
+                .iter()
+                .map(|r| r.len())
+                .sum::<usize>()
+        };
+        write_u32(s, (rows(&pk.a) + rows(&pk.b) + n_public + 1) as u32);
+        for (matrix, rows) in [&pk.a, &pk.b].into_iter().enumerate() {
+            for (constraint, row) in rows[..pk.num_constraints].iter().enumerate() {
+                for (value, signal) in row {
+                    write_coefficient(s, matrix, constraint, *signal, value);
+                }
+            }
+        }
+        // Constraints for the public inputs
+        for signal in 0..=n_public {
+            write_coefficient(s, 0, pk.num_constraints + signal, signal, &Fr::one());
+        }
+    });
+    write_section(&mut bytes, 5, |s| {
+        pk.pk.a_query.iter().for_each(|p| write_g1(s, p))
+    });
+    write_section(&mut bytes, 6, |s| {
+        pk.pk.b_g1_query.iter().for_each(|p| write_g1(s, p))
+    });
+    write_section(&mut bytes, 7, |s| {
+        pk.pk.b_g2_query.iter().for_each(|p| write_g2(s, p))
+    });
+    write_section(&mut bytes, 8, |s| {
+        pk.pk.l_query.iter().for_each(|p| write_g1(s, p))
+    });
+    write_section(&mut bytes, 9, |s| {
+        pk.pk.h_query.iter().for_each(|p| write_g1(s, p))
+    });
+    // Hash of the circuit and the number of contributions
+    write_section(&mut bytes, 10, |s| {
+        s.extend_from_slice(&[0; 64]);
+        write_u32(s, 0);
+    });
+    Ok(bytes)
+}
+
+pub fn verifying_key_to_snarkjs(vk: &VerifyingKey<Bls12_381>) -> SnarkjsVerifyingKey {
+    let alpha_beta = Bls12_381::pairing(vk.alpha_g1, vk.beta_g2).0;
+    SnarkjsVerifyingKey {
+        protocol: SNARKJS_PROTOCOL.to_string(),
+        curve: SNARKJS_CURVE.to_string(),
+        n_public: vk.gamma_abc_g1.len().saturating_sub(1),
+        vk_alpha_1: g1_to_snarkjs(&vk.alpha_g1),
+        vk_beta_2: g2_to_snarkjs(&vk.beta_g2),
+        vk_gamma_2: g2_to_snarkjs(&vk.gamma_g2),
+        vk_delta_2: g2_to_snarkjs(&vk.delta_g2),
+        vk_alphabeta_12: fq12_to_snarkjs(&alpha_beta),
+        ic: vk.gamma_abc_g1.iter().map(g1_to_snarkjs).collect(),
+    }
+}
+
+pub fn verifying_key_from_snarkjs(
+    vk: &SnarkjsVerifyingKey,
+) -> native::Result<VerifyingKey<Bls12_381>> {
+    check_protocol_and_curve(&vk.protocol, &vk.curve)?;
+    if vk.ic.len() != vk.n_public + 1 {
+        return Err(Error::invalid_argument(format!(
+            "Expected {} IC points for {} public inputs but found {}",
+            vk.n_public + 1,
+            vk.n_public,
+            vk.ic.len()
+        )));
+    }
+    Ok(VerifyingKey {
+        alpha_g1: g1_from_snarkjs(&vk.vk_alpha_1)?,
+        beta_g2: g2_from_snarkjs(&vk.vk_beta_2)?,
+        gamma_g2: g2_from_snarkjs(&vk.vk_gamma_2)?,
+        delta_g2: g2_from_snarkjs(&vk.vk_delta_2)?,
+        gamma_abc_g1: vk
+            .ic
+            .iter()
+            .map(|p| g1_from_snarkjs(p))
+            .collect::<native::Result<_>>()?,
+    })
+}
+
+pub fn proof_to_snarkjs(proof: &Proof<Bls12_381>) -> SnarkjsProof {
+    SnarkjsProof {
+        pi_a: g1_to_snarkjs(&proof.a),
+        pi_b: g2_to_snarkjs(&proof.b),
+        pi_c: g1_to_snarkjs(&proof.c),
+        protocol: SNARKJS_PROTOCOL.to_string(),
+        curve: SNARKJS_CURVE.to_string(),
+    }
+}
+
+pub fn proof_from_snarkjs(proof: &SnarkjsProof) -> native::Result<Proof<Bls12_381>> {
+    check_protocol_and_curve(&proof.protocol, &proof.curve)?;
+    Ok(Proof {
+        a: g1_from_snarkjs(&proof.pi_a)?,
+        b: g2_from_snarkjs(&proof.pi_b)?,
+        c: g1_from_snarkjs(&proof.pi_c)?,
+    })
+}
+
+/// Public signals as the decimal strings of snarkjs' `public.json`
+pub fn public_signals_to_snarkjs(signals: &[Fr]) -> Vec<String> {
+    signals.iter().map(field_to_string).collect()
+}
+
+pub fn public_signals_from_snarkjs<S: AsRef<str>>(signals: &[S]) -> native::Result<Vec<Fr>> {
+    signals
+        .iter()
+        .map(|s| field_from_string(s.as_ref()))
+        .collect()
+}
+
+fn evaluate_row<F: PrimeField>(row: &[(F, usize)], assignment: &[F]) -> F {
+    row.iter()
+        .fold(F::zero(), |acc, (coeff, i)| acc + *coeff * assignment[*i])
+}
+
+fn read_zkey_sections(bytes: &[u8]) -> native::Result<CircomProvingKey> {
+    let file = BinFile::read(bytes, ZKEY_MAGIC, "zkey")?;
+    if file.version != ZKEY_VERSION {
+        return Err(Error::new(
+            ErrorCode::DeserializationFailed,
+            format!("Unsupported zkey version {}", file.version),
+        ));
+    }
+    let section = |id| file.section(id);
+
+    if section(1)?.u32()? != ZKEY_GROTH16 {
+        return Err(Error::invalid_argument("Only Groth16 zkeys are supported"));
+    }
+    let mut header = section(2)?;
+    let n8q = header.u32()? as usize;
+    let q = header.bytes(n8q)?;
+    let n8r = header.u32()? as usize;
+    let r = header.bytes(n8r)?;
+    if q != Fq::MODULUS.to_bytes_le() || r != Fr::MODULUS.to_bytes_le() {
+        return Err(Error::new(
+            ErrorCode::CurveMismatch,
+            "Only zkeys for BLS12-381 are supported",
+        ));
+    }
+    let n_vars = header.u32()? as usize;
+    let n_public = header.u32()? as usize;
+    let domain_size = header.u32()? as usize;
+    let alpha_g1 = read_g1(&mut header)?;
+    let beta_g1 = read_g1(&mut header)?;
+    let beta_g2 = read_g2(&mut header)?;
+    let gamma_g2 = read_g2(&mut header)?;
+    let delta_g1 = read_g1(&mut header)?;
+    let delta_g2 = read_g2(&mut header)?;
+    if n_public >= n_vars {
+        return Err(Error::new(
+            ErrorCode::DeserializationFailed,
+            format!("{} public inputs for {} wires", n_public, n_vars),
+        ));
+    }
+
+    let mut coefficients = section(4)?;
+    let mut matrices = [vec![], vec![]];
+    for _ in 0..coefficients.u32()? {
+        let matrix = coefficients.u32()? as usize;
+        let constraint = coefficients.u32()? as usize;
+        let signal = coefficients.u32()? as usize;
+        let value = read_coefficient(&mut coefficients)?;
+        if matrix > 1 || constraint >= domain_size || signal >= n_vars {
+            return Err(Error::new(
+                ErrorCode::DeserializationFailed,
+                format!(
+                    "Invalid coefficient of signal {} in constraint {}",
+                    signal, constraint
+                ),
+            ));
+        }
+        let rows: &mut Vec<Vec<(Fr, usize)>> = &mut matrices[matrix];
+        if rows.len() <= constraint {
+            rows.resize(constraint + 1, vec![]);
+        }
+        rows[constraint].push((value, signal));
+    }
+    // The last constraints are the ones for the public inputs which `CircomReduction` adds itself
+    let [mut a, mut b] = matrices;
+    let num_constraints = a
+        .len()
+        .max(b.len())
+        .checked_sub(n_public + 1)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorCode::DeserializationFailed,
+                "Constraints for the public inputs are missing",
+            )
+        })?;
+    if GeneralEvaluationDomain::<Fr>::compute_size_of_domain(num_constraints + n_public + 1)
+        != Some(domain_size)
+    {
+        return Err(Error::new(
+            ErrorCode::DeserializationFailed,
+            format!(
+                "Domain size {} doesn't match {} constraints",
+                domain_size, num_constraints
+            ),
+        ));
+    }
+    a.resize(num_constraints, vec![]);
+    b.resize(num_constraints, vec![]);
+
+    let pk = ProvingKey {
+        vk: VerifyingKey {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1: read_g1_vec(section(3)?, n_public + 1)?,
+        },
+        beta_g1,
+        delta_g1,
+        a_query: read_g1_vec(section(5)?, n_vars)?,
+        b_g1_query: read_g1_vec(section(6)?, n_vars)?,
+        b_g2_query: read_g2_vec(section(7)?, n_vars)?,
+        h_query: read_g1_vec(section(9)?, domain_size)?,
+        l_query: read_g1_vec(section(8)?, n_vars - n_public - 1)?,
+    };
+    Ok(CircomProvingKey {
+        pk,
+        num_constraints,
+        a,
+        b,
+    })
+}
+
+// Field elements of a zkey are in Montgomery form
+
+fn read_fq(r: &mut BinReader) -> native::Result<Fq> {
+    let b = r.big_int()?;
+    if b >= Fq::MODULUS {
+        return Err(invalid_point());
+    }
+    Ok(Fq::new_unchecked(b))
+}
+
+/// Coefficients are multiplied by the Montgomery constant once more
+fn read_coefficient(r: &mut BinReader) -> native::Result<Fr> {
+    let b = r.big_int()?;
+    if b >= Fr::MODULUS {
+        return Err(Error::new(
+            ErrorCode::DeserializationFailed,
+            "Invalid coefficient",
+        ));
+    }
+    Ok(Fr::new_unchecked(Fr::new_unchecked(b).into_bigint()))
+}
+
+pub(super) fn read_g1(r: &mut BinReader) -> native::Result<G1Affine> {
+    let (x, y) = (read_fq(r)?, read_fq(r)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    check_point(G1Affine::new_unchecked(x, y))
+}
+
+pub(super) fn read_g2(r: &mut BinReader) -> native::Result<G2Affine> {
+    let x = Fq2::new(read_fq(r)?, read_fq(r)?);
+    let y = Fq2::new(read_fq(r)?, read_fq(r)?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    check_point(G2Affine::new_unchecked(x, y))
+}
+
+pub(super) fn read_g1_vec(mut r: BinReader, n: usize) -> native::Result<Vec<G1Affine>> {
+    (0..n).map(|_| read_g1(&mut r)).collect()
+}
+
+pub(super) fn read_g2_vec(mut r: BinReader, n: usize) -> native::Result<Vec<G2Affine>> {
+    (0..n).map(|_| read_g2(&mut r)).collect()
+}
+
+pub(super) fn write_u32(bytes: &mut Vec<u8>, n: u32) {
+    bytes.extend_from_slice(&n.to_le_bytes());
+}
+
+pub(super) fn write_section(bytes: &mut Vec<u8>, id: u32, write: impl FnOnce(&mut Vec<u8>)) {
+    let mut section = vec![];
+    write(&mut section);
+    write_u32(bytes, id);
+    bytes.extend_from_slice(&(section.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(&section);
+}
+
+fn write_montgomery<const N: usize>(bytes: &mut Vec<u8>, b: &BigInt<N>) {
+    b.0.iter()
+        .for_each(|l| bytes.extend_from_slice(&l.to_le_bytes()));
+}
+
+fn write_coefficient(
+    bytes: &mut Vec<u8>,
+    matrix: usize,
+    constraint: usize,
+    signal: usize,
+    value: &Fr,
+) {
+    write_u32(bytes, matrix as u32);
+    write_u32(bytes, constraint as u32);
+    write_u32(bytes, signal as u32);
+    write_montgomery(bytes, &Fr::from_bigint(value.0).unwrap().0);
+}
+
+pub(super) fn write_g1(bytes: &mut Vec<u8>, p: &G1Affine) {
+    let (x, y) = p.xy().map(|(x, y)| (*x, *y)).unwrap_or_default();
+    write_montgomery(bytes, &x.0);
+    write_montgomery(bytes, &y.0);
+}
+
+pub(super) fn write_g2(bytes: &mut Vec<u8>, p: &G2Affine) {
+    let (x, y) = p.xy().map(|(x, y)| (*x, *y)).unwrap_or_default();
+    for c in [x.c0, x.c1, y.c0, y.c1] {
+        write_montgomery(bytes, &c.0);
+    }
+}
+
+fn check_point<P: AffineRepr>(p: P) -> native::Result<P> {
+    // Same checks as `CanonicalDeserialize` does
+    p.check().map_err(|_| invalid_point())?;
+    Ok(p)
+}
+
+fn check_protocol_and_curve(protocol: &str, curve: &str) -> native::Result<()> {
+    if protocol != SNARKJS_PROTOCOL {
+        return Err(Error::invalid_argument(format!(
+            "Expected protocol {} but found {}",
+            SNARKJS_PROTOCOL, protocol
+        )));
+    }
+    if curve != SNARKJS_CURVE {
+        return Err(Error::new(
+            ErrorCode::CurveMismatch,
+            format!("Expected curve {} but found {}", SNARKJS_CURVE, curve),
+        ));
+    }
+    Ok(())
+}
+
+fn field_to_string<F: PrimeField>(f: &F) -> String {
+    f.into_bigint().to_string()
+}
+
+/// Parse a field element from its decimal string which must be less than the modulus
+fn field_from_string<F: PrimeField>(s: &str) -> native::Result<F> {
+    F::from_str(s)
+        .ok()
+        .filter(|f| field_to_string(f) == s)
+        .ok_or_else(|| Error::invalid_argument(format!("{} is not a valid field element", s)))
+}
+
+/// Points are in affine coordinates with a 3rd coordinate which is "0" for the point at infinity and "1" otherwise
+fn g1_to_snarkjs(p: &G1Affine) -> Vec<String> {
+    match p.xy() {
+        Some((x, y)) => vec![field_to_string(x), field_to_string(y), "1".to_string()],
+        None => vec!["0".to_string(), "1".to_string(), "0".to_string()],
+    }
+}
+
+fn g2_to_snarkjs(p: &G2Affine) -> Vec<Vec<String>> {
+    let fq2 = |f: &Fq2| vec![field_to_string(&f.c0), field_to_string(&f.c1)];
+    match p.xy() {
+        Some((x, y)) => vec![fq2(x), fq2(y), fq2(&Fq2::one())],
+        None => vec![fq2(&Fq2::zero()), fq2(&Fq2::one()), fq2(&Fq2::zero())],
+    }
+}
+
+fn fq12_to_snarkjs(f: &Fq12) -> Vec<Vec<Vec<String>>> {
+    [f.c0, f.c1]
+        .iter()
+        .map(|c| {
+            [c.c0, c.c1, c.c2]
+                .iter()
+                .map(|c| vec![field_to_string(&c.c0), field_to_string(&c.c1)])
+                .collect()
+        })
+        .collect()
+}
+
+fn g1_from_snarkjs(p: &[String]) -> native::Result<G1Affine> {
+    match p {
+        [x, y, z] if z == "1" => check_point(G1Affine::new_unchecked(
+            field_from_string(x)?,
+            field_from_string(y)?,
+        )),
+        [_, _, z] if z == "0" => Ok(G1Affine::zero()),
+        _ => Err(invalid_point()),
+    }
+}
+
+fn g2_from_snarkjs(p: &[Vec<String>]) -> native::Result<G2Affine> {
+    let fq2 = |f: &[String]| match f {
+        [c0, c1] => Ok(Fq2::new(field_from_string(c0)?, field_from_string(c1)?)),
+        _ => Err(invalid_point()),
+    };
+    match p {
+        [x, y, z] => {
+            let z = fq2(z)?;
+            if z.is_zero() {
+                Ok(G2Affine::zero())
+            } else if z.is_one() {
+                check_point(G2Affine::new_unchecked(fq2(x)?, fq2(y)?))
+            } else {
+                Err(invalid_point())
+            }
+        }
+        _ => Err(invalid_point()),
+    }
+}
+
+fn invalid_point() -> Error {
+    Error::new(ErrorCode::DeserializationFailed, "Invalid curve point")
+}
@@ -0,0 +1,340 @@
+//! Encoding of public keys that are a single BLS12-381 point as JWK, W3C `Multikey` and multibase (base58btc) so
+//! that they can be put in DID documents. The multibase encoding is the multicodec code of the key's group as an
+//! unsigned varint followed by the compressed point, `bls12_381-g1-pub` (0xea) for keys in G1 and
+//! `bls12_381-g2-pub` (0xeb) for keys in G2.
+//!
+//! PS public keys have several points so they are encoded as a JWK Set with a JWK for each point, identified by its
+//! `kid`: "alpha_tilde" in G2, then "beta_tilde.<i>" in G2 and "beta.<i>" in G1 for each message index `i`. There is
+//! no multicodec for them so they can't be encoded as Multikey or multibase.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    native::{from_bytes, to_bytes, Error, ErrorCode, Result},
+    ps::PSPublicKey,
+};
+
+pub const MULTIBASE_BASE58BTC: char = 'z';
+pub const MULTIKEY_TYPE: &str = "Multikey";
+pub const JWK_KTY: &str = "OKP";
+
+/// Group of the point that is the public key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyGroup {
+    G1,
+    G2,
+}
+
+impl KeyGroup {
+    /// Multicodec code, `bls12_381-g1-pub` or `bls12_381-g2-pub`
+    pub fn multicodec(&self) -> u16 {
+        match self {
+            KeyGroup::G1 => 0xea,
+            KeyGroup::G2 => 0xeb,
+        }
+    }
+
+    pub fn multicodec_name(&self) -> &'static str {
+        match self {
+            KeyGroup::G1 => "bls12_381-g1-pub",
+            KeyGroup::G2 => "bls12_381-g2-pub",
+        }
+    }
+
+    /// Value of `crv` in a JWK
+    pub fn jwk_crv(&self) -> &'static str {
+        match self {
+            KeyGroup::G1 => "BLS12381G1",
+            KeyGroup::G2 => "BLS12381G2",
+        }
+    }
+
+    /// Size of the compressed point
+    pub fn point_size(&self) -> usize {
+        match self {
+            KeyGroup::G1 => 48,
+            KeyGroup::G2 => 96,
+        }
+    }
+
+    fn from_multicodec(code: u64) -> Option<Self> {
+        match code {
+            0xea => Some(KeyGroup::G1),
+            0xeb => Some(KeyGroup::G2),
+            _ => None,
+        }
+    }
+
+    /// Accepts the `crv` values used by the JWK drafts for BLS12-381 keys like "BLS12381G2", "Bls12381G2" and
+    /// "BLS12381_G2"
+    fn from_jwk_crv(crv: &str) -> Option<Self> {
+        match crv.replace('_', "").to_ascii_uppercase().as_str() {
+            "BLS12381G1" => Some(KeyGroup::G1),
+            "BLS12381G2" => Some(KeyGroup::G2),
+            _ => None,
+        }
+    }
+}
+
+/// Public key as a JWK. `x` is the base64url encoded compressed point. `kid` is only set for the points of a key
+/// in a JWK Set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+/// JWK Set of a public key with several points
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+/// Public key as a W3C `Multikey` verification method
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Multikey {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub key_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub controller: Option<String>,
+    pub public_key_multibase: String,
+}
+
+/// Encode the compressed public key as multibase base58btc prefixed with the multicodec code of `group`
+pub fn to_multibase(public_key: &[u8], group: KeyGroup) -> Result<String> {
+    check_point_size(public_key, group)?;
+    let mut bytes = encode_varint(group.multicodec() as u64);
+    bytes.extend_from_slice(public_key);
+    Ok(format!(
+        "{}{}",
+        MULTIBASE_BASE58BTC,
+        bs58::encode(bytes).into_string()
+    ))
+}
+
+/// Decode the compressed public key from multibase after checking that its multicodec code is of `group`
+pub fn from_multibase(encoded: &str, group: KeyGroup) -> Result<Vec<u8>> {
+    let encoded = encoded.strip_prefix(MULTIBASE_BASE58BTC).ok_or_else(|| {
+        Error::new(
+            ErrorCode::InvalidKeyEncoding,
+            "Only multibase base58btc, with prefix 'z', is supported",
+        )
+    })?;
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| Error::with_details(ErrorCode::InvalidKeyEncoding, "Invalid base58btc", e))?;
+    let (code, public_key) = decode_varint(&bytes)?;
+    match KeyGroup::from_multicodec(code) {
+        Some(g) if g == group => (),
+        Some(g) => {
+            return Err(Error::new(
+                ErrorCode::CurveMismatch,
+                format!(
+                    "Expected a {} key but found a {} key",
+                    group.multicodec_name(),
+                    g.multicodec_name()
+                ),
+            ))
+        }
+        None => {
+            return Err(Error::new(
+                ErrorCode::CurveMismatch,
+                format!(
+                    "Expected a {} key but found multicodec 0x{:x}",
+                    group.multicodec_name(),
+                    code
+                ),
+            ))
+        }
+    }
+    check_point_size(public_key, group)?;
+    Ok(public_key.to_vec())
+}
+
+pub fn to_multikey(
+    public_key: &[u8],
+    group: KeyGroup,
+    id: Option<String>,
+    controller: Option<String>,
+) -> Result<Multikey> {
+    Ok(Multikey {
+        id,
+        key_type: MULTIKEY_TYPE.to_string(),
+        controller,
+        public_key_multibase: to_multibase(public_key, group)?,
+    })
+}
+
+pub fn from_multikey(multikey: &Multikey, group: KeyGroup) -> Result<Vec<u8>> {
+    if multikey.key_type != MULTIKEY_TYPE {
+        return Err(Error::new(
+            ErrorCode::InvalidKeyEncoding,
+            format!(
+                "Expected type {} but found {}",
+                MULTIKEY_TYPE, multikey.key_type
+            ),
+        ));
+    }
+    from_multibase(&multikey.public_key_multibase, group)
+}
+
+pub fn to_jwk(public_key: &[u8], group: KeyGroup) -> Result<Jwk> {
+    check_point_size(public_key, group)?;
+    Ok(Jwk {
+        kty: JWK_KTY.to_string(),
+        crv: group.jwk_crv().to_string(),
+        x: URL_SAFE_NO_PAD.encode(public_key),
+        kid: None,
+    })
+}
+
+/// Decode the compressed public key from the JWK after checking that its curve is `group`. Both "OKP" and "EC" are
+/// accepted as `kty` since both have been used for BLS12-381 keys.
+pub fn from_jwk(jwk: &Jwk, group: KeyGroup) -> Result<Vec<u8>> {
+    if jwk.kty != JWK_KTY && jwk.kty != "EC" {
+        return Err(Error::new(
+            ErrorCode::InvalidKeyEncoding,
+            format!("Unsupported kty {} in JWK", jwk.kty),
+        ));
+    }
+    match KeyGroup::from_jwk_crv(&jwk.crv) {
+        Some(g) if g == group => (),
+        _ => {
+            return Err(Error::new(
+                ErrorCode::CurveMismatch,
+                format!("Expected crv {} but found {}", group.jwk_crv(), jwk.crv),
+            ))
+        }
+    }
+    let public_key = URL_SAFE_NO_PAD
+        .decode(jwk.x.trim_end_matches('='))
+        .map_err(|e| Error::with_details(ErrorCode::InvalidKeyEncoding, "Invalid base64url", e))?;
+    check_point_size(&public_key, group)?;
+    Ok(public_key)
+}
+
+/// Encode the PS public key as a JWK Set with a JWK for each of its points
+pub fn ps_public_key_to_jwk_set(public_key: &PSPublicKey) -> Result<JwkSet> {
+    if public_key.beta.len() != public_key.beta_tilde.len() {
+        return Err(Error::invalid_argument(
+            "PS public key should have as many beta as beta_tilde",
+        ));
+    }
+    let point_jwk = |point: Vec<u8>, group: KeyGroup, kid: String| -> Result<Jwk> {
+        let mut jwk = to_jwk(&point, group)?;
+        jwk.kid = Some(kid);
+        Ok(jwk)
+    };
+    let mut keys = Vec::with_capacity(1 + 2 * public_key.beta.len());
+    keys.push(point_jwk(
+        to_bytes(&public_key.alpha_tilde)?,
+        KeyGroup::G2,
+        "alpha_tilde".to_string(),
+    )?);
+    for (i, b) in public_key.beta_tilde.iter().enumerate() {
+        keys.push(point_jwk(
+            to_bytes(b)?,
+            KeyGroup::G2,
+            format!("beta_tilde.{}", i),
+        )?);
+    }
+    for (i, b) in public_key.beta.iter().enumerate() {
+        keys.push(point_jwk(
+            to_bytes(b)?,
+            KeyGroup::G1,
+            format!("beta.{}", i),
+        )?);
+    }
+    Ok(JwkSet { keys })
+}
+
+/// Decode the PS public key from a JWK Set after checking that it has exactly the points of a key, each of the
+/// expected curve
+pub fn ps_public_key_from_jwk_set(jwk_set: &JwkSet) -> Result<PSPublicKey> {
+    if jwk_set.keys.len() % 2 != 1 {
+        return Err(Error::new(
+            ErrorCode::InvalidKeyEncoding,
+            format!(
+                "JWK Set of a PS public key should have an odd number of keys but has {}",
+                jwk_set.keys.len()
+            ),
+        ));
+    }
+    let message_count = jwk_set.keys.len() / 2;
+    let point = |kid: &str, group: KeyGroup| -> Result<Vec<u8>> {
+        let jwk = jwk_set
+            .keys
+            .iter()
+            .find(|k| k.kid.as_deref() == Some(kid))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorCode::InvalidKeyEncoding,
+                    format!("JWK Set of a PS public key has no key with kid {}", kid),
+                )
+            })?;
+        from_jwk(jwk, group).map_err(|e| e.context(format!("Invalid key with kid {}", kid)))
+    };
+    let alpha_tilde = from_bytes(&point("alpha_tilde", KeyGroup::G2)?)?;
+    let mut beta_tilde = Vec::with_capacity(message_count);
+    let mut beta = Vec::with_capacity(message_count);
+    for i in 0..message_count {
+        beta_tilde.push(from_bytes(&point(
+            &format!("beta_tilde.{}", i),
+            KeyGroup::G2,
+        )?)?);
+        beta.push(from_bytes(&point(&format!("beta.{}", i), KeyGroup::G1)?)?);
+    }
+    Ok(PSPublicKey {
+        alpha_tilde,
+        beta,
+        beta_tilde,
+    })
+}
+
+fn check_point_size(public_key: &[u8], group: KeyGroup) -> Result<()> {
+    if public_key.len() != group.point_size() {
+        return Err(Error::new(
+            ErrorCode::InvalidKeyEncoding,
+            format!(
+                "Expected a compressed {:?} point of {} bytes but found {} bytes",
+                group,
+                group.point_size(),
+                public_key.len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn encode_varint(mut n: u64) -> Vec<u8> {
+    let mut bytes = vec![];
+    while n >= 0x80 {
+        bytes.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    bytes.push(n as u8);
+    bytes
+}
+
+/// Returns the decoded varint and the remaining bytes
+fn decode_varint(bytes: &[u8]) -> Result<(u64, &[u8])> {
+    let mut n = 0_u64;
+    // Multicodec codes are at most 9 bytes as varint
+    for (i, b) in bytes.iter().take(9).enumerate() {
+        n |= ((b & 0x7f) as u64) << (7 * i);
+        if b & 0x80 == 0 {
+            return Ok((n, &bytes[i + 1..]));
+        }
+    }
+    Err(Error::new(
+        ErrorCode::InvalidKeyEncoding,
+        "Invalid multicodec prefix",
+    ))
+}
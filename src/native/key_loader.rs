@@ -0,0 +1,430 @@
+//! Deserialization of SNARK proving keys from chunks of their bytes as the chunks arrive, like from a JS
+//! `ReadableStream`. The key is parsed element by element so only the current chunk and the bytes of an element
+//! split across chunks are buffered, rather than the whole key or all the chunks read so far.
+//!
+//! A key can also be loaded for proving only, which skips the elements of its verification key that the prover never
+//! reads. Those are set to the identity so the key can't be used to verify proofs or to get its verification key.
+
+use ark_bls12_381::{g1, g2};
+use ark_ec::{short_weierstrass::Affine, AffineRepr};
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use legogroth16::{ProvingKey as LegoGroth16ProvingKey, ProvingKeyCommon, VerifyingKey};
+
+use crate::{
+    legosnark::LegoProvingKey,
+    native::{self, envelope, Error, ErrorCode},
+    saver::SaverSnarkPk,
+    G1Affine, G2Affine,
+};
+
+/// Kind of an element in the serialization of a key. Vectors are prefixed with their length as a `u64`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElementKind {
+    G1,
+    G2,
+    G1Vec,
+    G2Vec,
+    U32,
+}
+
+/// An element of the serialization of a key and whether the prover reads it
+#[derive(Clone, Copy, Debug)]
+pub struct LayoutElement {
+    pub kind: ElementKind,
+    pub needed_for_proving: bool,
+}
+
+const fn element(kind: ElementKind) -> LayoutElement {
+    LayoutElement {
+        kind,
+        needed_for_proving: true,
+    }
+}
+
+const fn verification_only(kind: ElementKind) -> LayoutElement {
+    LayoutElement {
+        kind,
+        needed_for_proving: false,
+    }
+}
+
+/// A deserialized element of a key
+#[derive(Clone, Debug)]
+pub enum Value {
+    G1(G1Affine),
+    G2(G2Affine),
+    G1Vec(Vec<G1Affine>),
+    G2Vec(Vec<G2Affine>),
+    U32(u32),
+}
+
+/// A key that can be loaded with `ProvingKeyLoader`. `LAYOUT` lists the elements of its serialization in order and
+/// `from_values` builds the key from their values in the same order.
+pub trait LoadableKey: Sized {
+    const NAME: &'static str;
+    const LAYOUT: &'static [LayoutElement];
+
+    fn from_values(values: Values) -> native::Result<Self>;
+}
+
+/// Values of the elements of a key in the order of its layout
+pub struct Values(ark_std::vec::IntoIter<Value>);
+
+impl Values {
+    fn next(&mut self) -> native::Result<Value> {
+        self.0.next().ok_or_else(|| {
+            Error::new(
+                ErrorCode::DeserializationFailed,
+                "Key has fewer elements than its layout",
+            )
+        })
+    }
+
+    fn g1(&mut self) -> native::Result<G1Affine> {
+        match self.next()? {
+            Value::G1(v) => Ok(v),
+            v => Err(unexpected_value("G1 element", &v)),
+        }
+    }
+
+    fn g2(&mut self) -> native::Result<G2Affine> {
+        match self.next()? {
+            Value::G2(v) => Ok(v),
+            v => Err(unexpected_value("G2 element", &v)),
+        }
+    }
+
+    fn g1_vec(&mut self) -> native::Result<Vec<G1Affine>> {
+        match self.next()? {
+            Value::G1Vec(v) => Ok(v),
+            v => Err(unexpected_value("vector of G1 elements", &v)),
+        }
+    }
+
+    fn g2_vec(&mut self) -> native::Result<Vec<G2Affine>> {
+        match self.next()? {
+            Value::G2Vec(v) => Ok(v),
+            v => Err(unexpected_value("vector of G2 elements", &v)),
+        }
+    }
+
+    fn u32(&mut self) -> native::Result<u32> {
+        match self.next()? {
+            Value::U32(v) => Ok(v),
+            v => Err(unexpected_value("u32", &v)),
+        }
+    }
+}
+
+fn unexpected_value(expected: &str, found: &Value) -> Error {
+    Error::new(
+        ErrorCode::DeserializationFailed,
+        format!("Expected a {} in the key but found {:?}", expected, found),
+    )
+}
+
+impl LoadableKey for SaverSnarkPk {
+    const NAME: &'static str = "SaverSnarkPk";
+    // `saver::saver_groth16::ProvingKey` is a Groth16 proving key followed by `gamma_g1`. Of the verification key,
+    // the prover doesn't read `gamma_g2`.
+    const LAYOUT: &'static [LayoutElement] = &[
+        element(ElementKind::G1),
+        element(ElementKind::G2),
+        verification_only(ElementKind::G2),
+        element(ElementKind::G2),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1),
+        element(ElementKind::G1),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G2Vec),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1),
+    ];
+
+    fn from_values(mut v: Values) -> native::Result<Self> {
+        let vk = ark_groth16::VerifyingKey {
+            alpha_g1: v.g1()?,
+            beta_g2: v.g2()?,
+            gamma_g2: v.g2()?,
+            delta_g2: v.g2()?,
+            gamma_abc_g1: v.g1_vec()?,
+        };
+        Ok(Self {
+            pk: ark_groth16::ProvingKey {
+                vk,
+                beta_g1: v.g1()?,
+                delta_g1: v.g1()?,
+                a_query: v.g1_vec()?,
+                b_g1_query: v.g1_vec()?,
+                b_g2_query: v.g2_vec()?,
+                h_query: v.g1_vec()?,
+                l_query: v.g1_vec()?,
+            },
+            gamma_g1: v.g1()?,
+        })
+    }
+}
+
+impl LoadableKey for LegoProvingKey {
+    const NAME: &'static str = "LegoProvingKey";
+    // Of the verification key, the prover doesn't read `gamma_g2`
+    const LAYOUT: &'static [LayoutElement] = &[
+        element(ElementKind::G1),
+        element(ElementKind::G2),
+        verification_only(ElementKind::G2),
+        element(ElementKind::G2),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1),
+        element(ElementKind::U32),
+        element(ElementKind::G1),
+        element(ElementKind::G1),
+        element(ElementKind::G1),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G2Vec),
+        element(ElementKind::G1Vec),
+        element(ElementKind::G1Vec),
+    ];
+
+    fn from_values(mut v: Values) -> native::Result<Self> {
+        let vk = VerifyingKey {
+            alpha_g1: v.g1()?,
+            beta_g2: v.g2()?,
+            gamma_g2: v.g2()?,
+            delta_g2: v.g2()?,
+            gamma_abc_g1: v.g1_vec()?,
+            eta_gamma_inv_g1: v.g1()?,
+            commit_witness_count: v.u32()?,
+        };
+        Ok(LegoGroth16ProvingKey {
+            vk,
+            common: ProvingKeyCommon {
+                beta_g1: v.g1()?,
+                delta_g1: v.g1()?,
+                eta_delta_inv_g1: v.g1()?,
+                a_query: v.g1_vec()?,
+                b_g1_query: v.g1_vec()?,
+                b_g2_query: v.g2_vec()?,
+                h_query: v.g1_vec()?,
+                l_query: v.g1_vec()?,
+            },
+        })
+    }
+}
+
+/// Loads a key from chunks of its bytes given to `push` in order. `finish` returns the key once all chunks are
+/// pushed. The bytes can be an envelope if envelope input is enabled, like for `envelope::deserialize_from_reader`.
+pub struct ProvingKeyLoader<K: LoadableKey> {
+    compress: Compress,
+    proving_only: bool,
+    /// Whether the envelope header, if any, has been read
+    header_read: bool,
+    /// Bytes received but not yet parsed, which are fewer than the next element needs
+    buffer: Vec<u8>,
+    /// Index in the layout of the element being parsed
+    element: usize,
+    /// Elements left to read of the vector being parsed, if its length has been read
+    vec_remaining: Option<u64>,
+    values: Vec<Value>,
+    _key: core::marker::PhantomData<K>,
+}
+
+impl<K: LoadableKey> ProvingKeyLoader<K> {
+    /// If `proving_only` is true, the elements of the verification key that aren't needed for proving are skipped
+    /// and set to the identity
+    pub fn new(compressed: bool, proving_only: bool) -> Self {
+        Self {
+            compress: if compressed {
+                Compress::Yes
+            } else {
+                Compress::No
+            },
+            proving_only,
+            header_read: false,
+            buffer: vec![],
+            element: 0,
+            vec_remaining: None,
+            values: Vec::with_capacity(K::LAYOUT.len()),
+            _key: core::marker::PhantomData,
+        }
+    }
+
+    /// Parse as many elements as the chunk completes and keep the rest of its bytes for the next chunk
+    pub fn push(&mut self, chunk: &[u8]) -> native::Result<()> {
+        self.buffer.extend_from_slice(chunk);
+        let mut pos = 0;
+        if !self.header_read {
+            if envelope::envelope_input() {
+                if self.buffer.len() < envelope::HEADER_SIZE {
+                    return Ok(());
+                }
+                if envelope::is_envelope(&self.buffer) {
+                    envelope::open(
+                        &self.buffer[..envelope::HEADER_SIZE],
+                        Some(K::NAME),
+                        self.compress == Compress::Yes,
+                    )?;
+                    pos = envelope::HEADER_SIZE;
+                }
+            }
+            self.header_read = true;
+        }
+        let result = self.parse(&mut pos);
+        self.buffer.drain(..pos);
+        result.map_err(|e| e.context(format!("Failed to deserialize a {} from bytes", K::NAME)))
+    }
+
+    /// Return the key once all its elements are parsed
+    pub fn finish(self) -> native::Result<K> {
+        if self.element < K::LAYOUT.len() {
+            return Err(self.incomplete());
+        }
+        if !self.buffer.is_empty() {
+            return Err(Error::new(
+                ErrorCode::DeserializationFailed,
+                format!(
+                    "Found {} bytes after the end of the {}",
+                    self.buffer.len(),
+                    K::NAME
+                ),
+            ));
+        }
+        K::from_values(Values(self.values.into_iter()))
+            .map_err(|e| e.context(format!("Failed to deserialize a {} from bytes", K::NAME)))
+    }
+
+    fn incomplete(&self) -> Error {
+        Error::new(
+            ErrorCode::DeserializationFailed,
+            format!(
+                "Bytes ended after {} of the {} elements of the {}",
+                self.element,
+                K::LAYOUT.len(),
+                K::NAME
+            ),
+        )
+    }
+
+    fn parse(&mut self, pos: &mut usize) -> native::Result<()> {
+        while self.element < K::LAYOUT.len() {
+            let layout = K::LAYOUT[self.element];
+            let skip = self.proving_only && !layout.needed_for_proving;
+            let value = match layout.kind {
+                ElementKind::G1 => match self.read_point::<G1Affine>(pos, skip)? {
+                    Some(p) => Value::G1(p),
+                    None => return Ok(()),
+                },
+                ElementKind::G2 => match self.read_point::<G2Affine>(pos, skip)? {
+                    Some(p) => Value::G2(p),
+                    None => return Ok(()),
+                },
+                ElementKind::U32 => match self.take(pos, 4) {
+                    Some(b) => Value::U32(u32::from_le_bytes(b.try_into().unwrap())),
+                    None => return Ok(()),
+                },
+                ElementKind::G1Vec => {
+                    if !self.read_vec::<G1Affine>(pos, skip)? {
+                        return Ok(());
+                    }
+                    self.element += 1;
+                    continue;
+                }
+                ElementKind::G2Vec => {
+                    if !self.read_vec::<G2Affine>(pos, skip)? {
+                        return Ok(());
+                    }
+                    self.element += 1;
+                    continue;
+                }
+            };
+            self.values.push(value);
+            self.element += 1;
+        }
+        Ok(())
+    }
+
+    /// Read the elements of a vector that are in the buffer into the last value. Returns true once all are read.
+    fn read_vec<P: VecPoint>(&mut self, pos: &mut usize, skip: bool) -> native::Result<bool> {
+        let mut remaining = match self.vec_remaining {
+            Some(r) => r,
+            None => match self.take(pos, 8) {
+                Some(b) => {
+                    let len = u64::from_le_bytes(b.try_into().unwrap());
+                    // Don't trust the length for the allocation since the bytes may not have that many elements
+                    self.values
+                        .push(P::into_value(Vec::with_capacity(len.min(1 << 16) as usize)));
+                    len
+                }
+                None => return Ok(false),
+            },
+        };
+        while remaining > 0 {
+            match self.read_point::<P>(pos, skip)? {
+                Some(p) => {
+                    P::push(self.values.last_mut().unwrap(), p);
+                    remaining -= 1;
+                }
+                None => {
+                    self.vec_remaining = Some(remaining);
+                    return Ok(false);
+                }
+            }
+        }
+        self.vec_remaining = None;
+        Ok(true)
+    }
+
+    fn read_point<P: AffineRepr + CanonicalDeserialize>(
+        &self,
+        pos: &mut usize,
+        skip: bool,
+    ) -> native::Result<Option<P>> {
+        let size = P::zero().serialized_size(self.compress);
+        Ok(match self.take(pos, size) {
+            Some(_) if skip => Some(P::zero()),
+            Some(b) => Some(P::deserialize_with_mode(b, self.compress, Validate::Yes)?),
+            None => None,
+        })
+    }
+
+    fn take(&self, pos: &mut usize, n: usize) -> Option<&[u8]> {
+        if self.buffer.len() - *pos < n {
+            return None;
+        }
+        *pos += n;
+        Some(&self.buffer[*pos - n..*pos])
+    }
+}
+
+/// A group element that can be in a vector of a key
+trait VecPoint: AffineRepr + CanonicalDeserialize {
+    fn into_value(v: Vec<Self>) -> Value;
+    fn push(value: &mut Value, p: Self);
+}
+
+// For the concrete types since the crate's aliases are projections that coherence doesn't see as distinct
+impl VecPoint for Affine<g1::Config> {
+    fn into_value(v: Vec<Self>) -> Value {
+        Value::G1Vec(v)
+    }
+
+    fn push(value: &mut Value, p: Self) {
+        if let Value::G1Vec(v) = value {
+            v.push(p)
+        }
+    }
+}
+
+impl VecPoint for Affine<g2::Config> {
+    fn into_value(v: Vec<Self>) -> Value {
+        Value::G2Vec(v)
+    }
+
+    fn push(value: &mut Value, p: Self) {
+        if let Value::G2Vec(v) = value {
+            v.push(p)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Rust API that doesn't depend on a JS runtime. The functions take and return the typed objects, and `to_bytes` and
+//! `from_bytes` convert the objects to and from the same byte encoding as the wasm functions use so that a Rust
+//! server and a JS client can exchange them. The `#[wasm_bindgen]` functions only convert their arguments and
+//! results and call the functions here.
+//!
+//! Failures are returned as `Error` which has a stable `ErrorCode` and an `ErrorCategory`. When returned to JS it
+//! becomes an `Error` object with `code`, `category` and `details` properties besides the `message`.
+
+pub mod accumulator;
+pub mod bbs;
+pub mod bbs_plus;
+mod binfile;
+pub mod ceremony;
+pub mod composite_proof_system;
+pub mod dkgith;
+pub mod envelope;
+mod error;
+pub mod frost_dkg;
+pub mod groth16;
+pub mod key_encoding;
+pub mod key_loader;
+pub mod powers_of_tau;
+pub mod ps;
+pub mod r1cs;
+pub mod saver;
+pub mod schnorr;
+pub mod threshold_saver;
+pub mod threshold_sig;
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::collections::BTreeMap;
+
+use crate::{utils::encode_message_for_signing, Fr};
+
+pub use error::{Error, ErrorCategory, ErrorCode, Result};
+
+/// Serialize an object to bytes in the format the wasm functions return
+pub fn to_bytes<T: CanonicalSerialize>(obj: &T) -> Result<Vec<u8>> {
+    let mut bytes = vec![];
+    obj.serialize_compressed(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Deserialize an object from bytes in the format the wasm functions return
+pub fn from_bytes<T: CanonicalDeserialize>(bytes: &[u8]) -> Result<T> {
+    Ok(T::deserialize_compressed(bytes)?)
+}
+
+/// Convert messages to field elements. If `encode` is true, the messages are arbitrary bytes that are hashed to
+/// field elements else they are already serialized field elements. This is what the `encodeMessages` argument
+/// of the wasm functions does.
+pub fn messages_to_field_elements<M: AsRef<[u8]>>(messages: &[M], encode: bool) -> Result<Vec<Fr>> {
+    messages
+        .iter()
+        .map(|m| message_to_field_element(m.as_ref(), encode))
+        .collect()
+}
+
+/// Same as `messages_to_field_elements` but for messages with their indices
+pub fn indexed_messages_to_field_elements<M: AsRef<[u8]>>(
+    messages: &BTreeMap<usize, M>,
+    encode: bool,
+) -> Result<BTreeMap<usize, Fr>> {
+    messages
+        .iter()
+        .map(|(i, m)| Ok((*i, message_to_field_element(m.as_ref(), encode)?)))
+        .collect()
+}
+
+fn message_to_field_element(message: &[u8], encode: bool) -> Result<Fr> {
+    if encode {
+        Ok(encode_message_for_signing(message))
+    } else {
+        from_bytes(message)
+    }
+}
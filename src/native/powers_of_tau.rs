@@ -0,0 +1,419 @@
+//! Proving keys created from the powers of tau of a phase 1 ceremony, read from the `.ptau` files of snarkjs. The
+//! trapdoors `tau`, `alpha` and `beta` of a Groth16 or LegoGroth16 key only depend on the size of the circuit and
+//! come from phase 1. The keys here have the circuit specific trapdoors `gamma`, `delta` and, for LegoGroth16, `eta`
+//! set to 1 so anyone can create the same key from the powers of tau and the circuit and compare it with the initial
+//! key of a phase 2 ceremony (see `ceremony`), whose contributions then make those trapdoors secret.
+//!
+//! The QAP of the circuit is evaluated at `tau` in the exponent using the Lagrange basis of the evaluation domain,
+//! which is the inverse FFT of the powers of tau. The R1CS to QAP reduction is the one `legogroth16` and `saver`
+//! prove with so the keys work with their provers. The inverse FFT is done on curve points so creating keys for
+//! large circuits is slow.
+
+use ark_bls12_381::{Bls12_381, Fq, G1Projective, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_groth16::{ProvingKey as Groth16ProvingKey, VerifyingKey as Groth16VerifyingKey};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, AllocationMode},
+};
+use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef,
+    OptimizationGoal, SynthesisError, SynthesisMode,
+};
+use ark_serialize::CanonicalSerialize;
+use ark_std::cmp::Ordering;
+use legogroth16::{
+    circom::{CircomCircuit, R1CS},
+    ProvingKey, ProvingKeyCommon, VerifyingKey,
+};
+use saver::{circuit::BitsizeCheckCircuit, saver_groth16};
+
+use crate::{
+    legosnark::LegoProvingKey,
+    native::{
+        self,
+        binfile::BinFile,
+        ceremony::linear_combination_coefficients,
+        groth16::{
+            read_g1_vec, read_g2, read_g2_vec, write_g1, write_g2, write_section, write_u32,
+        },
+        Error, ErrorCode,
+    },
+    saver::{EncGens, SaverSnarkPk},
+    Fr, G1Affine, G2Affine,
+};
+
+const PTAU_MAGIC: &[u8; 4] = b"ptau";
+const PTAU_VERSION: u32 = 1;
+const FQ_SIZE: usize = 48;
+
+/// Powers of tau for circuits whose evaluation domain has up to `size` elements
+#[derive(Clone, Debug, PartialEq)]
+pub struct PowersOfTau {
+    /// `tau^i * G1` for `i` in `0..2 * size - 1`
+    pub tau_g1: Vec<G1Affine>,
+    /// `tau^i * G2` for `i` in `0..size`
+    pub tau_g2: Vec<G2Affine>,
+    /// `alpha * tau^i * G1` for `i` in `0..size`
+    pub alpha_tau_g1: Vec<G1Affine>,
+    /// `beta * tau^i * G1` for `i` in `0..size`
+    pub beta_tau_g1: Vec<G1Affine>,
+    pub beta_g2: G2Affine,
+}
+
+impl PowersOfTau {
+    /// Read the powers for a domain of `size` elements from a ptau file of snarkjs for BLS12-381. The file can be
+    /// for a larger domain, in which case only the first powers are read.
+    pub fn read(bytes: &[u8], size: usize) -> native::Result<Self> {
+        Self::read_sections(bytes, size).map_err(|e| e.context("Invalid ptau"))
+    }
+
+    /// Write the powers as a ptau file of snarkjs, which is how a ptau file can be trimmed to the size a circuit
+    /// needs. The file has no contributions so snarkjs can't verify it.
+    pub fn write(&self) -> native::Result<Vec<u8>> {
+        let size = self.tau_g2.len();
+        if !size.is_power_of_two()
+            || self.tau_g1.len() != 2 * size - 1
+            || self.alpha_tau_g1.len() != size
+            || self.beta_tau_g1.len() != size
+        {
+            return Err(Error::invalid_argument(
+                "Powers of tau should be for a domain whose size is a power of 2",
+            ));
+        }
+        let power = size.trailing_zeros();
+        let mut bytes = PTAU_MAGIC.to_vec();
+        write_u32(&mut bytes, PTAU_VERSION);
+        write_u32(&mut bytes, 6);
+
+        write_section(&mut bytes, 1, |s| {
+            write_u32(s, FQ_SIZE as u32);
+            s.extend_from_slice(&Fq::MODULUS.to_bytes_le());
+            write_u32(s, power);
+            write_u32(s, power);
+        });
+        write_section(&mut bytes, 2, |s| {
+            self.tau_g1.iter().for_each(|p| write_g1(s, p))
+        });
+        write_section(&mut bytes, 3, |s| {
+            self.tau_g2.iter().for_each(|p| write_g2(s, p))
+        });
+        write_section(&mut bytes, 4, |s| {
+            self.alpha_tau_g1.iter().for_each(|p| write_g1(s, p))
+        });
+        write_section(&mut bytes, 5, |s| {
+            self.beta_tau_g1.iter().for_each(|p| write_g1(s, p))
+        });
+        write_section(&mut bytes, 6, |s| write_g2(s, &self.beta_g2));
+        Ok(bytes)
+    }
+
+    /// Check that the powers are of the same `tau` in both groups and that the ones multiplied by `alpha` and
+    /// `beta` are multiplied by the same value, using random linear combinations of the powers with coefficients
+    /// hashed from them. This doesn't verify the contributions to the phase 1 ceremony, which snarkjs does.
+    pub fn verify(&self) -> native::Result<()> {
+        let size = self.tau_g2.len();
+        if size < 2
+            || self.tau_g1.len() != 2 * size - 1
+            || self.alpha_tau_g1.len() != size
+            || self.beta_tau_g1.len() != size
+        {
+            return Err(Error::invalid_argument(
+                "Powers of tau should be for a domain of at least 2 elements",
+            ));
+        }
+        let (g1, g2) = (self.tau_g1[0], self.tau_g2[0]);
+        if g1.is_zero() || g2.is_zero() || self.tau_g1[1].is_zero() {
+            return Err(invalid_powers());
+        }
+        let mut bytes = vec![];
+        self.tau_g1.serialize_uncompressed(&mut bytes)?;
+        self.tau_g2.serialize_uncompressed(&mut bytes)?;
+        self.alpha_tau_g1.serialize_uncompressed(&mut bytes)?;
+        self.beta_tau_g1.serialize_uncompressed(&mut bytes)?;
+        self.beta_g2.serialize_uncompressed(&mut bytes)?;
+        let r = linear_combination_coefficients(&bytes, self.tau_g1.len() - 1)?;
+        let comb_g1 = |points: &[G1Affine]| G1Projective::msm_unchecked(points, &r[..points.len()]);
+        let comb_g2 = |points: &[G2Affine]| G2Projective::msm_unchecked(points, &r[..points.len()]);
+        let last = self.tau_g1.len() - 1;
+        let equal = |a: (G1Projective, G2Projective), b: (G1Projective, G2Projective)| {
+            Bls12_381::multi_pairing([a.0, -b.0], [a.1, b.1]).is_zero()
+        };
+        let valid = equal(
+            (comb_g1(&self.tau_g1[1..]), g2.into_group()),
+            (comb_g1(&self.tau_g1[..last]), self.tau_g2[1].into_group()),
+        ) && equal(
+            (
+                self.tau_g1[1].into_group(),
+                comb_g2(&self.tau_g2[..size - 1]),
+            ),
+            (g1.into_group(), comb_g2(&self.tau_g2[1..])),
+        ) && equal(
+            (comb_g1(&self.alpha_tau_g1), g2.into_group()),
+            (self.alpha_tau_g1[0].into_group(), comb_g2(&self.tau_g2)),
+        ) && equal(
+            (comb_g1(&self.beta_tau_g1), g2.into_group()),
+            (self.beta_tau_g1[0].into_group(), comb_g2(&self.tau_g2)),
+        ) && equal(
+            (self.beta_tau_g1[0].into_group(), g2.into_group()),
+            (g1.into_group(), self.beta_g2.into_group()),
+        );
+        if valid {
+            Ok(())
+        } else {
+            Err(invalid_powers())
+        }
+    }
+
+    fn read_sections(bytes: &[u8], size: usize) -> native::Result<Self> {
+        let file = BinFile::read(bytes, PTAU_MAGIC, "ptau")?;
+        if file.version != PTAU_VERSION {
+            return Err(Error::new(
+                ErrorCode::DeserializationFailed,
+                format!("Unsupported ptau version {}", file.version),
+            ));
+        }
+        let mut header = file.section(1)?;
+        let n8 = header.u32()? as usize;
+        if header.bytes(n8)? != Fq::MODULUS.to_bytes_le() {
+            return Err(Error::new(
+                ErrorCode::CurveMismatch,
+                "Only ptau files for BLS12-381 are supported",
+            ));
+        }
+        let power = header.u32()?;
+        if size < 2 || power >= usize::BITS || (1 << power) < size {
+            return Err(Error::invalid_argument(format!(
+                "Powers of tau of power {} aren't enough for a domain of {} elements",
+                power, size
+            )));
+        }
+        Ok(Self {
+            tau_g1: read_g1_vec(file.section(2)?, 2 * size - 1)?,
+            tau_g2: read_g2_vec(file.section(3)?, size)?,
+            alpha_tau_g1: read_g1_vec(file.section(4)?, size)?,
+            beta_tau_g1: read_g1_vec(file.section(5)?, size)?,
+            beta_g2: read_g2(&mut file.section(6)?)?,
+        })
+    }
+}
+
+/// Create a LegoGroth16 proving key for the circuit from the ptau file `ptau`, with `gamma`, `delta` and `eta` set
+/// to 1. The first `commit_witness_count` witnesses are committed to as with `generate_random_parameters`.
+pub fn lego_proving_key<C: ConstraintSynthesizer<Fr>>(
+    circuit: C,
+    commit_witness_count: u32,
+    ptau: &[u8],
+) -> native::Result<LegoProvingKey> {
+    let qap = QapEvaluations::new(circuit, ptau)?;
+    if qap.num_witness_variables < commit_witness_count as usize {
+        return Err(Error::invalid_argument(format!(
+            "Circuit has {} witnesses but {} should be committed",
+            qap.num_witness_variables, commit_witness_count
+        )));
+    }
+    let n = qap.num_instance_variables + commit_witness_count as usize;
+    let (g1, g2) = (qap.tau_g1, qap.tau_g2);
+    Ok(ProvingKey {
+        vk: VerifyingKey {
+            alpha_g1: qap.alpha_g1,
+            beta_g2: qap.beta_g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: qap.abc[..n].to_vec(),
+            eta_gamma_inv_g1: g1,
+            commit_witness_count,
+        },
+        common: ProvingKeyCommon {
+            beta_g1: qap.beta_g1,
+            delta_g1: g1,
+            eta_delta_inv_g1: g1,
+            a_query: qap.a,
+            b_g1_query: qap.b_g1,
+            b_g2_query: qap.b_g2,
+            h_query: qap.h,
+            l_query: qap.abc[n..].to_vec(),
+        },
+    })
+}
+
+/// Create the proving key for the bound check circuit of `boundCheckSnarkSetup` from the ptau file `ptau`
+pub fn bound_check_proving_key(ptau: &[u8]) -> native::Result<LegoProvingKey> {
+    lego_proving_key(BoundCheckCircuit, 1, ptau)
+}
+
+/// Create the proving key for a Circom circuit like `r1csSnarkSetup` does but from the ptau file `ptau`
+pub fn r1cs_proving_key(
+    r1cs: R1CS<Bls12_381>,
+    commit_witness_count: u32,
+    ptau: &[u8],
+) -> native::Result<LegoProvingKey> {
+    lego_proving_key(CircomCircuit::setup(r1cs), commit_witness_count, ptau)
+}
+
+/// Create the snark proving key of SAVER for chunks of `chunk_bit_size` bits from the ptau file `ptau`, with
+/// `gamma` and `delta` set to 1. Returns the key and the encryption generators, which are the generators of the
+/// powers of tau. The decryptor's keys depend on the snark proving key so they are created for the key after the
+/// ceremony.
+pub fn saver_proving_key(
+    chunk_bit_size: u8,
+    ptau: &[u8],
+) -> native::Result<(SaverSnarkPk, EncGens)> {
+    let circuit = BitsizeCheckCircuit::<Fr>::new(chunk_bit_size, None, None, true);
+    let qap = QapEvaluations::new(circuit, ptau)?;
+    let n = qap.num_instance_variables;
+    let (g1, g2) = (qap.tau_g1, qap.tau_g2);
+    let pk = saver_groth16::ProvingKey {
+        pk: Groth16ProvingKey {
+            vk: Groth16VerifyingKey {
+                alpha_g1: qap.alpha_g1,
+                beta_g2: qap.beta_g2,
+                gamma_g2: g2,
+                delta_g2: g2,
+                gamma_abc_g1: qap.abc[..n].to_vec(),
+            },
+            beta_g1: qap.beta_g1,
+            delta_g1: g1,
+            a_query: qap.a,
+            b_g1_query: qap.b_g1,
+            b_g2_query: qap.b_g2,
+            h_query: qap.h,
+            l_query: qap.abc[n..].to_vec(),
+        },
+        gamma_g1: (-g1.into_group()).into_affine(),
+    };
+    Ok((pk, EncGens { G: g1, H: g2 }))
+}
+
+/// The QAP of a circuit evaluated at `tau` in the exponent, with the elements of the powers of tau that the keys
+/// need
+struct QapEvaluations {
+    /// `a_i(tau) * G1` of each variable `i`
+    a: Vec<G1Affine>,
+    b_g1: Vec<G1Affine>,
+    b_g2: Vec<G2Affine>,
+    /// `(beta * a_i(tau) + alpha * b_i(tau) + c_i(tau)) * G1` of each variable `i`
+    abc: Vec<G1Affine>,
+    /// `tau^i * z(tau) * G1` where `z` is the vanishing polynomial of the domain
+    h: Vec<G1Affine>,
+    num_instance_variables: usize,
+    num_witness_variables: usize,
+    tau_g1: G1Affine,
+    tau_g2: G2Affine,
+    alpha_g1: G1Affine,
+    beta_g1: G1Affine,
+    beta_g2: G2Affine,
+}
+
+impl QapEvaluations {
+    fn new<C: ConstraintSynthesizer<Fr>>(circuit: C, ptau: &[u8]) -> native::Result<Self> {
+        let cs = synthesize(circuit)?;
+        let num_instance_variables = cs.num_instance_variables();
+        let num_constraints = cs.num_constraints();
+        // Same domain as `LibsnarkReduction` uses, which has a constraint for each public input after the
+        // circuit's constraints
+        let domain = Radix2EvaluationDomain::<Fr>::new(num_constraints + num_instance_variables)
+            .ok_or_else(|| Error::invalid_argument("Circuit is too large"))?;
+        let m = domain.size();
+        let ptau = PowersOfTau::read(ptau, m)?;
+        ptau.verify()?;
+        let matrices: ConstraintMatrices<Fr> = cs.to_matrices().unwrap();
+
+        // Lagrange basis at tau
+        let lagrange_g1 = |powers: &[G1Affine]| {
+            domain.ifft(&powers.iter().map(|p| p.into_group()).collect::<Vec<_>>())
+        };
+        let l_g1 = lagrange_g1(&ptau.tau_g1[..m]);
+        let l_alpha_g1 = lagrange_g1(&ptau.alpha_tau_g1);
+        let l_beta_g1 = lagrange_g1(&ptau.beta_tau_g1);
+        let l_g2 = domain.ifft(
+            &ptau
+                .tau_g2
+                .iter()
+                .map(|p| p.into_group())
+                .collect::<Vec<_>>(),
+        );
+
+        let num_variables = num_instance_variables + cs.num_witness_variables();
+        let mut a = vec![G1Projective::zero(); num_variables];
+        let mut b_g1 = vec![G1Projective::zero(); num_variables];
+        let mut b_g2 = vec![G2Projective::zero(); num_variables];
+        let mut abc = vec![G1Projective::zero(); num_variables];
+        // Public input `i` is in `a` of the constraint `num_constraints + i`
+        let inputs = num_constraints..num_constraints + num_instance_variables;
+        a[..num_instance_variables].copy_from_slice(&l_g1[inputs.clone()]);
+        abc[..num_instance_variables].copy_from_slice(&l_beta_g1[inputs]);
+        for j in 0..num_constraints {
+            for (coeff, i) in &matrices.a[j] {
+                a[*i] += l_g1[j] * coeff;
+                abc[*i] += l_beta_g1[j] * coeff;
+            }
+            for (coeff, i) in &matrices.b[j] {
+                b_g1[*i] += l_g1[j] * coeff;
+                b_g2[*i] += l_g2[j] * coeff;
+                abc[*i] += l_alpha_g1[j] * coeff;
+            }
+            for (coeff, i) in &matrices.c[j] {
+                abc[*i] += l_g1[j] * coeff;
+            }
+        }
+        // The domain is a subgroup of size m so its vanishing polynomial is X^m - 1
+        let h = (0..m - 1)
+            .map(|i| ptau.tau_g1[i + m].into_group() - ptau.tau_g1[i])
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            a: G1Projective::normalize_batch(&a),
+            b_g1: G1Projective::normalize_batch(&b_g1),
+            b_g2: G2Projective::normalize_batch(&b_g2),
+            abc: G1Projective::normalize_batch(&abc),
+            h: G1Projective::normalize_batch(&h),
+            num_instance_variables,
+            num_witness_variables: cs.num_witness_variables(),
+            tau_g1: ptau.tau_g1[0],
+            tau_g2: ptau.tau_g2[0],
+            alpha_g1: ptau.alpha_tau_g1[0],
+            beta_g1: ptau.beta_tau_g1[0],
+            beta_g2: ptau.beta_g2,
+        })
+    }
+}
+
+/// Synthesize the circuit without assignments, as the key generators of `legogroth16` and `ark-groth16` do, so
+/// that the constraints are the same as the prover's
+fn synthesize<C: ConstraintSynthesizer<Fr>>(circuit: C) -> native::Result<ConstraintSystemRef<Fr>> {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    cs.set_optimization_goal(OptimizationGoal::Constraints);
+    cs.set_mode(SynthesisMode::Setup);
+    circuit.generate_constraints(cs.clone()).map_err(|e| {
+        Error::with_details(
+            ErrorCode::InvalidArgument,
+            "Error while generating constraints",
+            e,
+        )
+    })?;
+    cs.finalize();
+    Ok(cs)
+}
+
+fn invalid_powers() -> Error {
+    Error::new(ErrorCode::InvalidProof, "Powers of tau aren't consistent")
+}
+
+/// The circuit of `generate_snark_srs_bound_check` in `proof_system`, which doesn't expose it. It must generate the
+/// same constraints, which the bound check tests check by proving with the key created for it.
+struct BoundCheckCircuit;
+
+impl ConstraintSynthesizer<Fr> for BoundCheckCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let missing = || Err::<Fr, _>(SynthesisError::AssignmentMissing);
+        let val = FpVar::new_variable(cs.clone(), missing, AllocationMode::Witness)?;
+        let min = FpVar::new_variable(cs.clone(), missing, AllocationMode::Input)?;
+        let max = FpVar::new_variable(cs, missing, AllocationMode::Input)?;
+        val.enforce_cmp(&max, Ordering::Less, true)?;
+        val.enforce_cmp(&min, Ordering::Greater, true)?;
+        Ok(())
+    }
+}
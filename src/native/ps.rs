@@ -0,0 +1,125 @@
+use ark_bls12_381::Bls12_381;
+use ark_std::collections::BTreeMap;
+use blake2::Blake2b512;
+use coconut_crypto::CommitmentOrMessage;
+use zeroize::Zeroize;
+
+use crate::{
+    native::{schnorr::ProofOfPossession, Error, ErrorCode, Result},
+    ps::{PSBlindSignature, PSPublicKey, PSSecretKey, PSSignature, PSSignatureParams},
+    utils::{get_seeded_rng, random_bytes},
+    Fr, G1Affine, G2Affine,
+};
+
+/// Domain separator for proofs of possession of PS secret keys
+pub const POP_DOMAIN: &[u8] = b"PS-PUBLIC-KEY-POP";
+
+/// Generate signature params for `message_count` messages. A random label is used if `label` isn't given.
+pub fn generate_params(message_count: u32, label: Option<&[u8]>) -> Result<PSSignatureParams> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(PSSignatureParams::new::<Blake2b512>(&label, message_count))
+}
+
+pub fn generate_secret_key(message_count: u32, seed: Option<&[u8]>) -> Result<PSSecretKey> {
+    let mut seed = seed.map(|s| s.to_vec()).map_or_else(random_bytes, Ok)?;
+    let sk = PSSecretKey::from_seed::<Blake2b512>(&seed, message_count);
+    seed.zeroize();
+    Ok(sk)
+}
+
+pub fn generate_public_key(secret_key: &PSSecretKey, params: &PSSignatureParams) -> PSPublicKey {
+    PSPublicKey::new(secret_key, params)
+}
+
+/// Proof of possession of all the secret scalars of the public key generated by `generate_public_key`, i.e. of `x`
+/// in `alpha_tilde` and of each `y_i` in `beta_tilde_i`, bound to `context`. That each `beta_i` has the same
+/// `y_i` as `beta_tilde_i` is checked by the public key's validity check and not by this proof.
+pub fn generate_proof_of_possession(
+    secret_key: &PSSecretKey,
+    params: &PSSignatureParams,
+    context: &[u8],
+) -> Result<ProofOfPossession<G2Affine>> {
+    let pk = generate_public_key(secret_key, params);
+    let mut secrets = [vec![secret_key.x], secret_key.y.clone()].concat();
+    let proof = ProofOfPossession::new(
+        &secrets,
+        &vec![params.g_tilde; secrets.len()],
+        &pop_points(&pk),
+        POP_DOMAIN,
+        context,
+    );
+    secrets.zeroize();
+    proof
+}
+
+pub fn verify_proof_of_possession(
+    proof: &ProofOfPossession<G2Affine>,
+    public_key: &PSPublicKey,
+    params: &PSSignatureParams,
+    context: &[u8],
+) -> Result<()> {
+    if public_key.beta.len() != public_key.beta_tilde.len() {
+        return Err(Error::invalid_argument(
+            "Public key has different number of points in G1 and G2",
+        ));
+    }
+    let points = pop_points(public_key);
+    if proof.0.len() != points.len() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Proof of possession is for a key with {} messages but the public key supports {}",
+                proof.0.len().saturating_sub(1),
+                points.len() - 1
+            ),
+        ));
+    }
+    proof.verify(
+        &vec![params.g_tilde; points.len()],
+        &points,
+        POP_DOMAIN,
+        context,
+    )
+}
+
+fn pop_points(public_key: &PSPublicKey) -> Vec<G2Affine> {
+    [vec![public_key.alpha_tilde], public_key.beta_tilde.clone()].concat()
+}
+
+pub fn sign(
+    messages: &[Fr],
+    secret_key: &PSSecretKey,
+    params: &PSSignatureParams,
+) -> Result<PSSignature> {
+    let mut rng = get_seeded_rng()?;
+    Ok(PSSignature::new(&mut rng, messages, secret_key, params)?)
+}
+
+/// Sign a mix of commitments to hidden messages and revealed messages, in the order of the messages. `h` is
+/// the one the commitments were created with.
+pub fn blind_sign(
+    messages: impl IntoIterator<Item = CommitmentOrMessage<Bls12_381>>,
+    secret_key: &PSSecretKey,
+    h: &G1Affine,
+) -> Result<PSBlindSignature> {
+    Ok(PSBlindSignature::new(messages, secret_key, h)?)
+}
+
+/// Unblind using the blindings of the committed messages, keyed by the message index
+pub fn unblind_signature(
+    blind_signature: PSBlindSignature,
+    indexed_blindings: &BTreeMap<usize, Fr>,
+    public_key: &PSPublicKey,
+) -> Result<PSSignature> {
+    let blindings = indexed_blindings.iter().map(|(&idx, b)| (idx, b));
+    Ok(blind_signature.unblind(blindings, public_key)?)
+}
+
+pub fn verify(
+    messages: &[Fr],
+    signature: &PSSignature,
+    public_key: &PSPublicKey,
+    params: &PSSignatureParams,
+) -> Result<()> {
+    Ok(signature.verify(messages, public_key, params)?)
+}
@@ -0,0 +1,158 @@
+//! Parser for the binary `.r1cs` files that the Circom compiler creates, for circuits over BLS12-381, and
+//! calculation of a circuit's wires with the wasm that Circom creates for it.
+
+use ark_bls12_381::Bls12_381;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::str::FromStr;
+use legogroth16::circom::{
+    r1cs::{Constraint, Curve, LC, R1CS},
+    WitnessCalculator,
+};
+
+use crate::{
+    native::{
+        self,
+        binfile::{BinFile, BinReader},
+        Error, ErrorCode,
+    },
+    Fr,
+};
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const R1CS_VERSION: u32 = 1;
+const HEADER_SECTION: u32 = 1;
+const CONSTRAINTS_SECTION: u32 = 2;
+const WIRE_TO_LABEL_SECTION: u32 = 3;
+const CUSTOM_GATES_LIST_SECTION: u32 = 4;
+const CUSTOM_GATES_APPLICATION_SECTION: u32 = 5;
+
+/// Parse the bytes of a `.r1cs` file. Circuits with custom gates (compiled with PLONK's custom templates) aren't
+/// supported.
+pub fn read_r1cs(bytes: &[u8]) -> native::Result<R1CS<Bls12_381>> {
+    read_r1cs_sections(bytes).map_err(|e| e.context("Invalid R1CS file"))
+}
+
+/// Create the witness calculator of a circuit from its wasm. Compiling the wasm is expensive so the calculator
+/// should be reused for all inputs to the circuit.
+pub fn witness_calculator(wasm_bytes: &[u8]) -> native::Result<WitnessCalculator<Bls12_381>> {
+    WitnessCalculator::from_wasm_bytes(wasm_bytes)
+        .map_err(|e| native::Error::from(e).context("Error while loading the circuit's wasm"))
+}
+
+/// Calculate all wires of the circuit given the values of its input signals by name. The 1st wire is "1" and is
+/// followed by the public outputs, the public inputs and then the private wires.
+pub fn calculate_wires(
+    calculator: &mut WitnessCalculator<Bls12_381>,
+    inputs: Vec<(String, Vec<Fr>)>,
+) -> native::Result<Vec<Fr>> {
+    calculator
+        .calculate_witnesses(inputs, true)
+        .map_err(|e| native::Error::from(e).context("Error while generating witnesses"))
+}
+
+/// Parse the value of a signal from a decimal string, like in the input JSON of Circom. Negative values are
+/// subtracted from the modulus.
+pub fn signal_value_from_str(s: &str) -> native::Result<Fr> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, s),
+    };
+    let value = Fr::from_str(digits)
+        .ok()
+        .filter(|v| v.into_bigint().to_string() == digits)
+        .ok_or_else(|| {
+            Error::invalid_argument(format!("{} is not a valid value for a signal", s))
+        })?;
+    Ok(if negative { -value } else { value })
+}
+
+fn read_r1cs_sections(bytes: &[u8]) -> native::Result<R1CS<Bls12_381>> {
+    let file = BinFile::read(bytes, R1CS_MAGIC, "R1CS file")?;
+    if file.version != R1CS_VERSION {
+        return Err(Error::new(
+            ErrorCode::DeserializationFailed,
+            format!("Unsupported R1CS file version {}", file.version),
+        ));
+    }
+    // Circom adds these sections even when the circuit has no custom gates
+    for id in [CUSTOM_GATES_LIST_SECTION, CUSTOM_GATES_APPLICATION_SECTION] {
+        if file.has_section(id) && file.section(id)?.u32()? != 0 {
+            return Err(Error::invalid_argument(
+                "Circuits with custom gates are not supported",
+            ));
+        }
+    }
+
+    let mut header = file.section(HEADER_SECTION)?;
+    let field_size = header.u32()? as usize;
+    let prime = header.bytes(field_size)?;
+    if prime != Fr::MODULUS.to_bytes_le() {
+        return Err(Error::new(
+            ErrorCode::CurveMismatch,
+            "Only circuits over the scalar field of BLS12-381 are supported",
+        ));
+    }
+    let num_wires = header.u32()?;
+    let num_public_outputs = header.u32()?;
+    let num_public_inputs = header.u32()?;
+    let _num_private_inputs = header.u32()?;
+    let _num_labels = header.u64()?;
+    let num_constraints = header.u32()?;
+    // Wire 0 is always "1" and is followed by the public outputs and the public inputs
+    let num_public = num_public_outputs
+        .checked_add(num_public_inputs)
+        .and_then(|n| n.checked_add(1))
+        .filter(|n| *n <= num_wires)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorCode::DeserializationFailed,
+                format!(
+                    "{} public outputs and {} public inputs for {} wires",
+                    num_public_outputs, num_public_inputs, num_wires
+                ),
+            )
+        })?;
+
+    let mut section = file.section(CONSTRAINTS_SECTION)?;
+    let mut constraints = vec![];
+    for _ in 0..num_constraints {
+        constraints.push(Constraint {
+            a: read_lc(&mut section, num_wires)?,
+            b: read_lc(&mut section, num_wires)?,
+            c: read_lc(&mut section, num_wires)?,
+        });
+    }
+
+    let wire_to_label_mapping = if file.has_section(WIRE_TO_LABEL_SECTION) {
+        let mut section = file.section(WIRE_TO_LABEL_SECTION)?;
+        (0..num_wires)
+            .map(|_| Ok(section.u64()? as usize))
+            .collect::<native::Result<_>>()?
+    } else {
+        vec![]
+    };
+
+    Ok(R1CS {
+        curve: Curve::Bls12_381,
+        num_public,
+        num_private: num_wires - num_public,
+        constraints,
+        wire_to_label_mapping,
+    })
+}
+
+fn read_lc(section: &mut BinReader, num_wires: u32) -> native::Result<LC<Bls12_381>> {
+    let num_terms = section.u32()?;
+    let mut terms = vec![];
+    for _ in 0..num_terms {
+        let wire = section.u32()?;
+        if wire >= num_wires {
+            return Err(Error::new(
+                ErrorCode::DeserializationFailed,
+                format!("Wire {} of a constraint doesn't exist", wire),
+            ));
+        }
+        terms.push((wire as usize, section.fr()?));
+    }
+    Ok(LC(terms))
+}
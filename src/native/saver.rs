@@ -0,0 +1,1089 @@
+use ark_bls12_381::Bls12_381;
+use ark_ec::{
+    pairing::{Pairing, PairingOutput},
+    AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
+use ark_std::{collections::BTreeMap, UniformRand};
+use blake2::Blake2b512;
+use saver::{
+    encryption::Encryption,
+    keygen::{keygen, PreparedDecryptionKey},
+    saver_groth16::{self, get_gs_for_encryption, prepare_verifying_key, Proof},
+    setup::setup_for_groth16,
+    utils::{chunks_count, compose, decompose, CHUNK_TYPE},
+};
+use schnorr_pok::compute_random_oracle_challenge;
+use zeroize::Zeroize;
+
+use crate::{
+    native::{self, Error, ErrorCode},
+    saver::{
+        ChunkedCommGens, EncGens, SaverCiphertext, SaverDk, SaverEk, SaverSk, SaverSnarkPk,
+        SaverSnarkVk,
+    },
+    utils::{get_seeded_rng, random_bytes},
+    Fr, G1Affine,
+};
+
+type Gt = PairingOutput<Bls12_381>;
+type G1 = <Bls12_381 as Pairing>::G1;
+
+/// Domain separator for the challenge of the proof of re-encryption
+pub const REENCRYPTION_DOMAIN: &[u8] = b"SAVER-REENCRYPTION";
+/// Domain separator for the challenge of the proof of plaintext equality
+pub const PLAINTEXT_EQUALITY_DOMAIN: &[u8] = b"SAVER-PLAINTEXT-EQUALITY";
+/// Domain separator for the challenge of the proof of multi-recipient encryption
+pub const MULTI_RECIPIENT_DOMAIN: &[u8] = b"SAVER-MULTI-RECIPIENT";
+
+/// Precomputation for solving the discrete logs of decryption with baby-step giant-step. Decrypting a chunk `i`
+/// needs the discrete log of a pairing to the base `e(g_i, V_2_i)` where `g_i` is from the snark verification key
+/// and `V_2_i` from the decryption key, both public, so the table can be created once for a decryption key and
+/// reused for any number of ciphertexts.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize)]
+pub struct DecryptionTable {
+    pub chunk_bit_size: u8,
+    /// Number of baby steps, the smallest `m` such that `m * m >= 2^chunk_bit_size`
+    pub baby_step_count: u32,
+    /// `e(g_i, V_2_i)` for each chunk
+    pub bases: Vec<Gt>,
+    /// `-m * e(g_i, V_2_i)` for each chunk
+    pub giant_steps: Vec<Gt>,
+    /// For each chunk, map from the key (see `table_key`) of `j * e(g_i, V_2_i)` to `j` for `0 <= j < m`
+    pub baby_steps: Vec<BTreeMap<u64, CHUNK_TYPE>>,
+}
+
+impl DecryptionTable {
+    /// Check that the table has a baby-step giant-step precomputation for each of `chunks` chunks of its chunk size,
+    /// so it can be used with a decryption key supporting `chunks` chunks
+    pub fn check_for_chunks(&self, chunks: usize) -> native::Result<()> {
+        if !(1..=16).contains(&self.chunk_bit_size) {
+            return Err(Error::new(
+                ErrorCode::IncompatibleParams,
+                format!(
+                    "Decryption table has chunk bit size {} but it should be between 1 and 16",
+                    self.chunk_bit_size
+                ),
+            ));
+        }
+        let expected_chunks = chunks_count::<Fr>(self.chunk_bit_size) as usize;
+        if chunks != expected_chunks {
+            return Err(Error::new(
+                ErrorCode::IncompatibleParams,
+                format!(
+                    "Decryption table has chunk bit size {} which needs {} chunks but the decryption key supports {}",
+                    self.chunk_bit_size, expected_chunks, chunks
+                ),
+            ));
+        }
+        let m = baby_step_count(self.chunk_bit_size);
+        if self.baby_step_count != m {
+            return Err(Error::new(
+                ErrorCode::IncompatibleParams,
+                format!(
+                    "Decryption table has {} baby steps but chunk bit size {} needs {}",
+                    self.baby_step_count, self.chunk_bit_size, m
+                ),
+            ));
+        }
+        if self.bases.len() != chunks
+            || self.giant_steps.len() != chunks
+            || self.baby_steps.len() != chunks
+        {
+            return Err(Error::new(
+                ErrorCode::IncompatibleParams,
+                format!(
+                    "Decryption table should have {} bases, giant steps and baby steps but has {}, {} and {}",
+                    chunks,
+                    self.bases.len(),
+                    self.giant_steps.len(),
+                    self.baby_steps.len()
+                ),
+            ));
+        }
+        for (i, steps) in self.baby_steps.iter().enumerate() {
+            if steps.len() > m as usize || steps.values().any(|j| *j as u32 >= m) {
+                return Err(Error::new(
+                    ErrorCode::IncompatibleParams,
+                    format!(
+                        "Decryption table should have at most {} baby steps below {} for chunk {}",
+                        m, m, i
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Valid for DecryptionTable {
+    fn check(&self) -> Result<(), SerializationError> {
+        // The table is for a decryption key of its own chunk size, the key is checked against it before use
+        if !(1..=16).contains(&self.chunk_bit_size) {
+            return Err(SerializationError::InvalidData);
+        }
+        self.check_for_chunks(chunks_count::<Fr>(self.chunk_bit_size) as usize)
+            .map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+impl CanonicalDeserialize for DecryptionTable {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let table = Self {
+            chunk_bit_size: u8::deserialize_with_mode(&mut reader, compress, validate)?,
+            baby_step_count: u32::deserialize_with_mode(&mut reader, compress, validate)?,
+            bases: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            giant_steps: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            baby_steps: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            table.check()?;
+        }
+        Ok(table)
+    }
+}
+
+/// Proof that a ciphertext for a new encryption key encrypts the same message as a ciphertext for an old one. It
+/// proves knowledge of the chunks `m_i`, `nu = c_0 * rho` of the old ciphertext and the randomness `r'` of the new
+/// ciphertext such that `e(nu, H) = e(c_0, V_0)`, `e(c_i, V_2_i) = e(nu, V_1_i) + m_i * e(g_i, V_2_i)` for the old
+/// ciphertext and decryption key, and `c'_0 = X'_0 * r'`, `c'_i = X'_i * r' + g_i * m_i` and
+/// `psi' = \sum_i{Y'_i * m_i} + P'_1 * r'` for the new ciphertext and encryption key.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ReencryptionProof {
+    pub challenge: Fr,
+    pub resp_nu: G1Affine,
+    pub resp_chunks: Vec<Fr>,
+    pub resp_randomness: Fr,
+}
+
+/// Proof that several ciphertexts, possibly for different encryption keys and snark SRSs with the same chunk size,
+/// encrypt the same message. It proves knowledge of the chunks `m_i` and the randomness `r_j` of each ciphertext `j`
+/// such that `c_0 = X_0 * r_j`, `c_i = X_i * r_j + g_i * m_i` and `psi = \sum_i{Y_i * m_i} + P_1 * r_j` for its
+/// encryption key.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PlaintextEqualityProof {
+    pub challenge: Fr,
+    pub resp_chunks: Vec<Fr>,
+    pub resp_randomness: Vec<Fr>,
+}
+
+/// Proof that ciphertexts for several recipients encrypt the message in a Pedersen commitment `G * m + H * b` with
+/// the chunked commitment generators. The snark proof shows that the chunks of the first ciphertext are of the chunk
+/// size and the proof of plaintext equality shows that all ciphertexts have the same chunks, which compose the
+/// committed message.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultiRecipientProof {
+    pub snark_proof: Proof<Bls12_381>,
+    pub equality: PlaintextEqualityProof,
+    pub resp_blinding: Fr,
+}
+
+/// Generate encryption generators. A random label is used if `label` isn't given.
+pub fn generate_encryption_generators(label: Option<&[u8]>) -> native::Result<EncGens> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(EncGens::new::<Blake2b512>(&label))
+}
+
+/// Generate generators for the commitment to the chunks. A random label is used if `label` isn't given.
+pub fn generate_chunked_commitment_generators(
+    label: Option<&[u8]>,
+) -> native::Result<ChunkedCommGens> {
+    let label = label.map(|l| l.to_vec()).map_or_else(random_bytes, Ok)?;
+    Ok(ChunkedCommGens::new::<Blake2b512>(&label))
+}
+
+/// Setup for the decryptor for the given chunk size. Returns the snark proving key, secret key, encryption key
+/// and decryption key.
+pub fn decryptor_setup(
+    chunk_bit_size: u8,
+    enc_gens: &EncGens,
+) -> native::Result<(SaverSnarkPk, SaverSk, SaverEk, SaverDk)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(setup_for_groth16(&mut rng, chunk_bit_size, enc_gens)?)
+}
+
+/// Decrypt the ciphertext. Returns the message and the commitment to the randomness `nu` that is needed to
+/// verify the decryption.
+pub fn decrypt(
+    ciphertext: &SaverCiphertext,
+    secret_key: &SaverSk,
+    decryption_key: SaverDk,
+    snark_vk: &SaverSnarkVk,
+    chunk_bit_size: u8,
+) -> native::Result<(Fr, G1Affine)> {
+    Ok(
+        ciphertext.decrypt_given_groth16_vk(
+            secret_key,
+            decryption_key,
+            snark_vk,
+            chunk_bit_size,
+        )?,
+    )
+}
+
+/// Verify that the ciphertext decrypts to `message`
+pub fn verify_decryption(
+    ciphertext: &SaverCiphertext,
+    message: &Fr,
+    nu: &G1Affine,
+    decryption_key: SaverDk,
+    snark_vk: &SaverSnarkVk,
+    enc_gens: EncGens,
+    chunk_bit_size: u8,
+) -> native::Result<()> {
+    Ok(ciphertext.verify_decryption_given_groth16_vk(
+        message,
+        nu,
+        chunk_bit_size,
+        decryption_key,
+        snark_vk,
+        enc_gens,
+    )?)
+}
+
+/// Create the table for decrypting ciphertexts for the decryption key with `decrypt_using_table` and
+/// `batch_decrypt`
+pub fn create_decryption_table(
+    decryption_key: &SaverDk,
+    snark_vk: &SaverSnarkVk,
+    chunk_bit_size: u8,
+) -> native::Result<DecryptionTable> {
+    if !(1..=16).contains(&chunk_bit_size) {
+        return Err(Error::invalid_argument(format!(
+            "Chunk bit size should be between 1 and 16 but found {}",
+            chunk_bit_size
+        )));
+    }
+    let g_i = get_gs_for_encryption(snark_vk);
+    let n = decryption_key.V_2.len();
+    if n != chunks_count::<Fr>(chunk_bit_size) as usize {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Decryption key supports {} chunks but chunk bit size {} needs {}",
+                n,
+                chunk_bit_size,
+                chunks_count::<Fr>(chunk_bit_size)
+            ),
+        ));
+    }
+    if n > g_i.len() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Decryption key supports {} chunks but the snark verification key {}",
+                n,
+                g_i.len()
+            ),
+        ));
+    }
+    let baby_step_count = baby_step_count(chunk_bit_size);
+    let mut bases = Vec::with_capacity(n);
+    let mut giant_steps = Vec::with_capacity(n);
+    let mut baby_steps = Vec::with_capacity(n);
+    for (g, v) in g_i.iter().zip(&decryption_key.V_2) {
+        let base = Bls12_381::pairing(*g, *v);
+        let mut steps = BTreeMap::new();
+        let mut cur = Gt::zero();
+        for j in 0..baby_step_count {
+            steps.insert(table_key(&cur)?, j as CHUNK_TYPE);
+            cur += base;
+        }
+        // `cur` is now `m * base`
+        giant_steps.push(-cur);
+        bases.push(base);
+        baby_steps.push(steps);
+    }
+    Ok(DecryptionTable {
+        chunk_bit_size,
+        baby_step_count,
+        bases,
+        giant_steps,
+        baby_steps,
+    })
+}
+
+/// Same as `decrypt` but uses the table to solve the discrete logs, which is much faster
+pub fn decrypt_using_table(
+    ciphertext: &SaverCiphertext,
+    secret_key: &SaverSk,
+    decryption_key: SaverDk,
+    table: &DecryptionTable,
+) -> native::Result<(Fr, G1Affine)> {
+    let mut decrypted = batch_decrypt(
+        std::slice::from_ref(ciphertext),
+        secret_key,
+        decryption_key,
+        table,
+    )?;
+    Ok(decrypted.remove(0))
+}
+
+/// Decrypt several ciphertexts for the same decryption key. Returns the message and `nu` of each ciphertext in
+/// their order.
+pub fn batch_decrypt(
+    ciphertexts: &[SaverCiphertext],
+    secret_key: &SaverSk,
+    decryption_key: SaverDk,
+    table: &DecryptionTable,
+) -> native::Result<Vec<(Fr, G1Affine)>> {
+    let dk = PreparedDecryptionKey::from(decryption_key);
+    let rho = secret_key.0.into_bigint();
+    ciphertexts
+        .iter()
+        .enumerate()
+        .map(|(i, ct)| {
+            let nu = ct.X_r.mul_bigint(rho).into_affine();
+            let chunks = decrypt_to_chunks_given_nu(ct, &nu, &dk, table)
+                .map_err(|e| e.context(format!("Could not decrypt ciphertext {}", i)))?;
+            Ok((compose(&chunks, table.chunk_bit_size)?, nu))
+        })
+        .collect()
+}
+
+/// Decrypt the chunks of the ciphertext given `nu = c_0 * rho` where `c_0` is the first element of the ciphertext
+/// and `rho` the secret key
+pub(crate) fn decrypt_to_chunks_given_nu(
+    ciphertext: &SaverCiphertext,
+    nu: &G1Affine,
+    decryption_key: &PreparedDecryptionKey<Bls12_381>,
+    table: &DecryptionTable,
+) -> native::Result<Vec<CHUNK_TYPE>> {
+    let n = ciphertext.enc_chunks.len();
+    if n != decryption_key.V_1.len() || n != decryption_key.V_2.len() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Ciphertext has {} chunks but the decryption key supports {}",
+                n,
+                decryption_key.V_2.len()
+            ),
+        ));
+    }
+    // The table has public fields so it might not have come from deserialization
+    table.check_for_chunks(n)?;
+    let minus_nu = (-nu.into_group()).into_affine();
+    let mut chunks = Vec::with_capacity(n);
+    for (i, c_i) in ciphertext.enc_chunks.iter().enumerate() {
+        let p = Bls12_381::multi_pairing(
+            [*c_i, minus_nu],
+            [decryption_key.V_2[i].clone(), decryption_key.V_1[i].clone()],
+        );
+        chunks.push(solve_discrete_log(table, i, &p)?.ok_or_else(|| {
+            Error::new(
+                ErrorCode::InvalidDecryption,
+                format!("Could not decrypt chunk {}", i),
+            )
+        })?);
+    }
+    Ok(chunks)
+}
+
+/// Find `x < 2^chunk_bit_size` such that `p = x * e(g_i, V_2_i)` for chunk `i` with baby-step giant-step. Since the
+/// table keys are truncated, a match is checked before returning it.
+fn solve_discrete_log(
+    table: &DecryptionTable,
+    chunk_index: usize,
+    p: &Gt,
+) -> native::Result<Option<CHUNK_TYPE>> {
+    let chunk_count = 1_u32 << table.chunk_bit_size;
+    let m = table.baby_step_count;
+    let base = &table.bases[chunk_index];
+    let baby_steps = &table.baby_steps[chunk_index];
+    let giant_step = &table.giant_steps[chunk_index];
+    let mut cur = *p;
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&table_key(&cur)?) {
+            let x = i * m + *j as u32;
+            if x < chunk_count && *base * Fr::from(x) == *p {
+                return Ok(Some(x as CHUNK_TYPE));
+            }
+        }
+        cur += giant_step;
+    }
+    Ok(None)
+}
+
+/// Smallest `m` such that `m * m >= 2^chunk_bit_size`
+fn baby_step_count(chunk_bit_size: u8) -> u32 {
+    let chunk_count = 1_u32 << chunk_bit_size;
+    let mut m = 1;
+    while m * m < chunk_count {
+        m += 1;
+    }
+    m
+}
+
+/// First 8 bytes of the serialization, which are as good as random for the elements of the target group
+fn table_key(elem: &Gt) -> native::Result<u64> {
+    let mut bytes = vec![];
+    elem.serialize_compressed(&mut bytes)?;
+    let mut key = [0; 8];
+    key.copy_from_slice(&bytes[..8]);
+    Ok(u64::from_le_bytes(key))
+}
+
+/// Create new keys for the decryptor to replace its current ones, reusing the snark SRS so that provers keep the
+/// same snark proving key and encryption generators and only need the new encryption key. Returns the secret key,
+/// encryption key and decryption key. Existing ciphertexts are moved to the new keys with `reencrypt`.
+pub fn rotate_keys(
+    snark_pk: &SaverSnarkPk,
+    enc_gens: &EncGens,
+    chunk_bit_size: u8,
+) -> native::Result<(SaverSk, SaverEk, SaverDk)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(keygen(
+        &mut rng,
+        chunk_bit_size,
+        enc_gens,
+        get_gs_for_encryption(&snark_pk.pk.vk),
+        &snark_pk.pk.delta_g1,
+        &snark_pk.gamma_g1,
+    )?)
+}
+
+/// Decrypt the ciphertext with the old keys and encrypt the message for the new encryption key, both for the same
+/// snark SRS. `table` is the decryption table of the old decryption key. Returns the new ciphertext and the proof
+/// that it encrypts the same message as the old ciphertext.
+pub fn reencrypt(
+    ciphertext: &SaverCiphertext,
+    old_secret_key: &SaverSk,
+    old_decryption_key: &SaverDk,
+    table: &DecryptionTable,
+    new_encryption_key: &SaverEk,
+    snark_vk: &SaverSnarkVk,
+    enc_gens: &EncGens,
+) -> native::Result<(SaverCiphertext, ReencryptionProof)> {
+    let mut rng = get_seeded_rng()?;
+    let g_i = get_gs_for_encryption(snark_vk);
+    let nu = ciphertext
+        .X_r
+        .mul_bigint(old_secret_key.0.into_bigint())
+        .into_affine();
+    let mut chunks = decrypt_to_chunks_given_nu(
+        ciphertext,
+        &nu,
+        &PreparedDecryptionKey::from(old_decryption_key.clone()),
+        table,
+    )?;
+    let (new_ct, mut randomness) =
+        Encryption::encrypt_decomposed_message(&mut rng, chunks.clone(), new_encryption_key, g_i)?;
+    let n = chunks.len();
+    let new_ciphertext = SaverCiphertext {
+        X_r: new_ct[0],
+        enc_chunks: new_ct[1..=n].to_vec(),
+        commitment: new_ct[n + 1],
+    };
+    let mut chunks_fr = chunks.iter().map(|c| Fr::from(*c)).collect::<Vec<_>>();
+    chunks.zeroize();
+
+    let mut blinding_nu = G1::rand(&mut rng).into_affine();
+    let mut blinding_chunks = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let mut blinding_randomness = Fr::rand(&mut rng);
+    let commitments = reencryption_relations(
+        &blinding_nu,
+        &blinding_chunks,
+        &blinding_randomness,
+        old_decryption_key,
+        new_encryption_key,
+        g_i,
+        enc_gens,
+    );
+    let challenge = reencryption_challenge(
+        ciphertext,
+        &new_ciphertext,
+        old_decryption_key,
+        new_encryption_key,
+        &commitments,
+    )?;
+    let proof = ReencryptionProof {
+        challenge,
+        resp_nu: (blinding_nu + nu * challenge).into_affine(),
+        resp_chunks: blinding_chunks
+            .iter()
+            .zip(&chunks_fr)
+            .map(|(b, m)| *b + challenge * m)
+            .collect(),
+        resp_randomness: blinding_randomness + challenge * randomness,
+    };
+    blinding_nu.zeroize();
+    blinding_chunks.zeroize();
+    blinding_randomness.zeroize();
+    chunks_fr.zeroize();
+    randomness.zeroize();
+    Ok((new_ciphertext, proof))
+}
+
+/// Verify that the new ciphertext encrypts the same message as the old ciphertext
+pub fn verify_reencryption(
+    old_ciphertext: &SaverCiphertext,
+    new_ciphertext: &SaverCiphertext,
+    proof: &ReencryptionProof,
+    old_decryption_key: &SaverDk,
+    new_encryption_key: &SaverEk,
+    snark_vk: &SaverSnarkVk,
+    enc_gens: &EncGens,
+) -> native::Result<()> {
+    let g_i = get_gs_for_encryption(snark_vk);
+    let n = old_ciphertext.enc_chunks.len();
+    if n != new_ciphertext.enc_chunks.len()
+        || n != proof.resp_chunks.len()
+        || n != old_decryption_key.V_1.len()
+        || n != old_decryption_key.V_2.len()
+        || n != new_encryption_key.X.len()
+        || n != new_encryption_key.Y.len()
+        || n > g_i.len()
+    {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Ciphertexts, proof and keys should be for the same number of chunks but the old ciphertext has {}",
+                n
+            ),
+        ));
+    }
+    let mut commitments = reencryption_relations(
+        &proof.resp_nu,
+        &proof.resp_chunks,
+        &proof.resp_randomness,
+        old_decryption_key,
+        new_encryption_key,
+        g_i,
+        enc_gens,
+    );
+    // Subtract the challenge times the statement from the relations evaluated at the responses
+    let c = proof.challenge;
+    commitments.nu -= Bls12_381::pairing(old_ciphertext.X_r, old_decryption_key.V_0) * c;
+    for (i, t) in commitments.old_chunks.iter_mut().enumerate() {
+        *t -= Bls12_381::pairing(old_ciphertext.enc_chunks[i], old_decryption_key.V_2[i]) * c;
+    }
+    subtract_ciphertext(&mut commitments.new, new_ciphertext, &c);
+    let challenge = reencryption_challenge(
+        old_ciphertext,
+        new_ciphertext,
+        old_decryption_key,
+        new_encryption_key,
+        &commitments,
+    )?;
+    if challenge == proof.challenge {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Invalid proof of re-encryption",
+        ))
+    }
+}
+
+/// Values of the relations proven by `ReencryptionProof` for `nu`, the chunks and the randomness of the new
+/// ciphertext
+struct ReencryptionRelations {
+    nu: Gt,
+    old_chunks: Vec<Gt>,
+    new: SaverCiphertext,
+}
+
+fn reencryption_relations(
+    nu: &G1Affine,
+    chunks: &[Fr],
+    randomness: &Fr,
+    old_decryption_key: &SaverDk,
+    new_encryption_key: &SaverEk,
+    g_i: &[G1Affine],
+    enc_gens: &EncGens,
+) -> ReencryptionRelations {
+    let old_chunks = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            Bls12_381::multi_pairing(
+                [*nu, (g_i[i] * m).into_affine()],
+                [old_decryption_key.V_1[i], old_decryption_key.V_2[i]],
+            )
+        })
+        .collect();
+    ReencryptionRelations {
+        nu: Bls12_381::pairing(*nu, enc_gens.H),
+        old_chunks,
+        new: encryption_relation(chunks, randomness, new_encryption_key, g_i),
+    }
+}
+
+/// The ciphertext `c_0 = X_0 * r`, `c_i = X_i * r + g_i * m_i`, `psi = \sum_i{Y_i * m_i} + P_1 * r` for chunks `m_i`
+/// and randomness `r`. Used to prove knowledge of the chunks and randomness of a ciphertext.
+fn encryption_relation(
+    chunks: &[Fr],
+    randomness: &Fr,
+    encryption_key: &SaverEk,
+    g_i: &[G1Affine],
+) -> SaverCiphertext {
+    let enc_chunks = G1::normalize_batch(
+        &chunks
+            .iter()
+            .enumerate()
+            .map(|(i, m)| encryption_key.X[i] * randomness + g_i[i] * m)
+            .collect::<Vec<_>>(),
+    );
+    let mut scalars = chunks.to_vec();
+    scalars.push(*randomness);
+    let commitment = G1::msm_unchecked(&encryption_key.commitment_key(), &scalars);
+    SaverCiphertext {
+        X_r: (encryption_key.X_0 * randomness).into_affine(),
+        enc_chunks,
+        commitment: commitment.into_affine(),
+    }
+}
+
+/// Set `t` to `t - ciphertext * challenge`
+fn subtract_ciphertext(t: &mut SaverCiphertext, ciphertext: &SaverCiphertext, challenge: &Fr) {
+    t.X_r = (t.X_r.into_group() - ciphertext.X_r * challenge).into_affine();
+    for (t_i, c_i) in t.enc_chunks.iter_mut().zip(&ciphertext.enc_chunks) {
+        *t_i = (t_i.into_group() - *c_i * challenge).into_affine();
+    }
+    t.commitment = (t.commitment.into_group() - ciphertext.commitment * challenge).into_affine();
+}
+
+fn reencryption_challenge(
+    old_ciphertext: &SaverCiphertext,
+    new_ciphertext: &SaverCiphertext,
+    old_decryption_key: &SaverDk,
+    new_encryption_key: &SaverEk,
+    relations: &ReencryptionRelations,
+) -> native::Result<Fr> {
+    let mut bytes = REENCRYPTION_DOMAIN.to_vec();
+    old_ciphertext.serialize_compressed(&mut bytes)?;
+    new_ciphertext.serialize_compressed(&mut bytes)?;
+    old_decryption_key.serialize_compressed(&mut bytes)?;
+    new_encryption_key.serialize_compressed(&mut bytes)?;
+    relations.nu.serialize_compressed(&mut bytes)?;
+    relations.old_chunks.serialize_compressed(&mut bytes)?;
+    relations.new.serialize_compressed(&mut bytes)?;
+    Ok(compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes))
+}
+
+/// Encrypt the message for the encryption key outside of a composite proof. Returns the ciphertext and the
+/// randomness used, which is needed to prove plaintext equality with `prove_plaintext_equality`.
+pub fn encrypt(
+    message: &Fr,
+    encryption_key: &SaverEk,
+    snark_vk: &SaverSnarkVk,
+    chunk_bit_size: u8,
+) -> native::Result<(SaverCiphertext, Fr)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(Encryption::encrypt_given_snark_vk(
+        &mut rng,
+        message,
+        encryption_key,
+        snark_vk,
+        chunk_bit_size,
+    )?)
+}
+
+/// Re-randomize the ciphertext for the same encryption key so that it can't be linked to the original. The new
+/// ciphertext decrypts to the same message. Returns the new ciphertext and the randomness added, so the randomness
+/// of the new ciphertext is the sum of the randomness of the original and the returned one.
+pub fn rerandomize(
+    ciphertext: &SaverCiphertext,
+    encryption_key: &SaverEk,
+) -> native::Result<(SaverCiphertext, Fr)> {
+    if ciphertext.enc_chunks.len() != encryption_key.X.len() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Ciphertext has {} chunks but the encryption key supports {}",
+                ciphertext.enc_chunks.len(),
+                encryption_key.X.len()
+            ),
+        ));
+    }
+    let mut rng = get_seeded_rng()?;
+    let randomness = Fr::rand(&mut rng);
+    let enc_chunks = G1::normalize_batch(
+        &ciphertext
+            .enc_chunks
+            .iter()
+            .zip(&encryption_key.X)
+            .map(|(c, x)| *x * randomness + c)
+            .collect::<Vec<_>>(),
+    );
+    Ok((
+        SaverCiphertext {
+            X_r: (encryption_key.X_0 * randomness + ciphertext.X_r).into_affine(),
+            enc_chunks,
+            commitment: (encryption_key.P_1 * randomness + ciphertext.commitment).into_affine(),
+        },
+        randomness,
+    ))
+}
+
+/// Prove that the ciphertexts encrypt the same message. `randomness` has the randomness of each ciphertext as
+/// returned by `encrypt` or `rerandomize`, and `encryption_keys` and `snark_vks` are the keys each ciphertext is for.
+pub fn prove_plaintext_equality(
+    message: &Fr,
+    chunk_bit_size: u8,
+    ciphertexts: &[SaverCiphertext],
+    randomness: &[Fr],
+    encryption_keys: &[SaverEk],
+    snark_vks: &[SaverSnarkVk],
+) -> native::Result<PlaintextEqualityProof> {
+    check_plaintext_equality_count(ciphertexts.len())?;
+    if randomness.len() != ciphertexts.len() {
+        return Err(Error::invalid_argument(format!(
+            "Need the randomness of each of the {} ciphertexts but got {}",
+            ciphertexts.len(),
+            randomness.len()
+        )));
+    }
+    let g_is = snark_vks
+        .iter()
+        .map(get_gs_for_encryption)
+        .collect::<Vec<_>>();
+    let mut chunks = decompose_to_field_elements(message, chunk_bit_size)?;
+    let proof = prove_equal_chunks(
+        &chunks,
+        randomness,
+        ciphertexts,
+        encryption_keys,
+        &g_is,
+        None,
+    );
+    chunks.zeroize();
+    proof.map(|(proof, _)| proof)
+}
+
+/// Verify that the ciphertexts encrypt the same message
+pub fn verify_plaintext_equality(
+    ciphertexts: &[SaverCiphertext],
+    proof: &PlaintextEqualityProof,
+    encryption_keys: &[SaverEk],
+    snark_vks: &[SaverSnarkVk],
+) -> native::Result<()> {
+    check_plaintext_equality_count(ciphertexts.len())?;
+    let g_is = snark_vks
+        .iter()
+        .map(get_gs_for_encryption)
+        .collect::<Vec<_>>();
+    verify_equal_chunks(proof, ciphertexts, encryption_keys, &g_is, None)
+}
+
+/// Encrypt the message for one or more recipients with a single proof, which is verified with
+/// `verify_encryption_for_recipients`. `snark_pk` is the snark proving key of the first recipient and
+/// `other_snark_vks` the snark verification keys of the rest, and all recipients should use the same chunk size.
+/// Returns the ciphertexts, a commitment to the message with the chunked commitment generators, its blinding and the
+/// proof. The commitment is used in a composite proof to tie the message to other witnesses, like a signed message.
+/// `context` should bind the proof to the composite proof, like its nonce.
+pub fn encrypt_for_recipients(
+    message: &Fr,
+    chunk_bit_size: u8,
+    encryption_keys: &[SaverEk],
+    snark_pk: &SaverSnarkPk,
+    other_snark_vks: &[SaverSnarkVk],
+    comm_gens: &ChunkedCommGens,
+    context: &[u8],
+) -> native::Result<(Vec<SaverCiphertext>, G1Affine, Fr, MultiRecipientProof)> {
+    if encryption_keys.is_empty() || other_snark_vks.len() + 1 != encryption_keys.len() {
+        return Err(Error::invalid_argument(format!(
+            "Need a snark verification key for each recipient after the first but got {} for {} recipients",
+            other_snark_vks.len(),
+            encryption_keys.len()
+        )));
+    }
+    let mut rng = get_seeded_rng()?;
+    let (first_ct, first_randomness, snark_proof) = Encryption::encrypt_with_proof(
+        &mut rng,
+        message,
+        &encryption_keys[0],
+        snark_pk,
+        chunk_bit_size,
+    )?;
+    let mut ciphertexts = vec![first_ct];
+    let mut randomness = vec![first_randomness];
+    let mut g_is = vec![get_gs_for_encryption(&snark_pk.pk.vk)];
+    for (ek, vk) in encryption_keys[1..].iter().zip(other_snark_vks) {
+        let g_i = get_gs_for_encryption(vk);
+        let (ct, r) = Encryption::encrypt(&mut rng, message, ek, g_i, chunk_bit_size)?;
+        ciphertexts.push(ct);
+        randomness.push(r);
+        g_is.push(g_i);
+    }
+    let blinding = Fr::rand(&mut rng);
+    let commitment = (comm_gens.G * message + comm_gens.H * blinding).into_affine();
+
+    let mut chunks = decompose_to_field_elements(message, chunk_bit_size)?;
+    let result = prove_equal_chunks(
+        &chunks,
+        &randomness,
+        &ciphertexts,
+        encryption_keys,
+        &g_is,
+        Some((
+            &MessageCommitment {
+                gens: comm_gens,
+                commitment: &commitment,
+                chunk_bit_size,
+                context,
+            },
+            &blinding,
+        )),
+    );
+    chunks.zeroize();
+    randomness.zeroize();
+    let (equality, resp_blinding) = result?;
+    let proof = MultiRecipientProof {
+        snark_proof,
+        equality,
+        resp_blinding: resp_blinding
+            .ok_or_else(|| Error::new(ErrorCode::Internal, "Missing response for the blinding"))?,
+    };
+    Ok((ciphertexts, commitment, blinding, proof))
+}
+
+/// Verify that the ciphertexts for several recipients encrypt the message in the commitment. `snark_vks` has the
+/// snark verification key of each recipient, including the first.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_encryption_for_recipients(
+    ciphertexts: &[SaverCiphertext],
+    commitment: &G1Affine,
+    proof: &MultiRecipientProof,
+    encryption_keys: &[SaverEk],
+    snark_vks: &[SaverSnarkVk],
+    comm_gens: &ChunkedCommGens,
+    chunk_bit_size: u8,
+    context: &[u8],
+) -> native::Result<()> {
+    if ciphertexts.is_empty() || snark_vks.len() != ciphertexts.len() {
+        return Err(Error::invalid_argument(format!(
+            "Need a snark verification key for each of the {} ciphertexts but got {}",
+            ciphertexts.len(),
+            snark_vks.len()
+        )));
+    }
+    saver_groth16::verify_proof(
+        &prepare_verifying_key(&snark_vks[0]),
+        &proof.snark_proof,
+        &ciphertexts[0],
+    )?;
+    let g_is = snark_vks
+        .iter()
+        .map(get_gs_for_encryption)
+        .collect::<Vec<_>>();
+    verify_equal_chunks(
+        &proof.equality,
+        ciphertexts,
+        encryption_keys,
+        &g_is,
+        Some((
+            &MessageCommitment {
+                gens: comm_gens,
+                commitment,
+                chunk_bit_size,
+                context,
+            },
+            &proof.resp_blinding,
+        )),
+    )
+}
+
+/// Commitment to the message composed from the chunks, which is additionally proven by `MultiRecipientProof`
+struct MessageCommitment<'a> {
+    gens: &'a ChunkedCommGens,
+    commitment: &'a G1Affine,
+    chunk_bit_size: u8,
+    context: &'a [u8],
+}
+
+impl MessageCommitment<'_> {
+    /// `G * \sum_i{m_i * 2^{chunk_bit_size * (n - 1 - i)}} + H * b` as the chunks are big-endian
+    fn relation(&self, chunks: &[Fr], blinding: &Fr) -> G1Affine {
+        let base = Fr::from(1u64 << self.chunk_bit_size);
+        let mut message = Fr::zero();
+        for m in chunks {
+            message = message * base + m;
+        }
+        (self.gens.G * message + self.gens.H * blinding).into_affine()
+    }
+}
+
+fn decompose_to_field_elements(message: &Fr, chunk_bit_size: u8) -> native::Result<Vec<Fr>> {
+    let mut chunks = decompose(message, chunk_bit_size)?;
+    let chunks_fr = chunks.iter().map(|c| Fr::from(*c)).collect();
+    chunks.zeroize();
+    Ok(chunks_fr)
+}
+
+/// Plaintext equality is only meaningful for 2 or more ciphertexts
+fn check_plaintext_equality_count(ciphertext_count: usize) -> native::Result<()> {
+    if ciphertext_count < 2 {
+        return Err(Error::invalid_argument(
+            "Need at least 2 ciphertexts to prove plaintext equality",
+        ));
+    }
+    Ok(())
+}
+
+/// Check that the ciphertexts, keys and responses are all for the same number of chunks
+fn check_chunk_counts(
+    chunk_count: usize,
+    ciphertexts: &[SaverCiphertext],
+    encryption_keys: &[SaverEk],
+    g_is: &[&[G1Affine]],
+) -> native::Result<()> {
+    if encryption_keys.len() != ciphertexts.len() || g_is.len() != ciphertexts.len() {
+        return Err(Error::invalid_argument(format!(
+            "Need an encryption key and snark verification key for each of the {} ciphertexts but got {} and {}",
+            ciphertexts.len(),
+            encryption_keys.len(),
+            g_is.len()
+        )));
+    }
+    for ((ct, ek), g_i) in ciphertexts.iter().zip(encryption_keys).zip(g_is) {
+        if ct.enc_chunks.len() != chunk_count
+            || ek.X.len() != chunk_count
+            || ek.Y.len() != chunk_count
+            || g_i.len() < chunk_count
+        {
+            return Err(Error::new(
+                ErrorCode::IncompatibleParams,
+                format!(
+                    "Ciphertexts and keys should all be for {} chunks",
+                    chunk_count
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Sigma protocol for knowledge of the same chunks and the randomness of each ciphertext, and optionally the blinding
+/// of a commitment to the message. Returns the proof and the response for the blinding.
+fn prove_equal_chunks(
+    chunks: &[Fr],
+    randomness: &[Fr],
+    ciphertexts: &[SaverCiphertext],
+    encryption_keys: &[SaverEk],
+    g_is: &[&[G1Affine]],
+    message_commitment: Option<(&MessageCommitment, &Fr)>,
+) -> native::Result<(PlaintextEqualityProof, Option<Fr>)> {
+    check_chunk_counts(chunks.len(), ciphertexts, encryption_keys, g_is)?;
+    let mut rng = get_seeded_rng()?;
+    let mut blinding_chunks = (0..chunks.len())
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let mut blinding_randomness = (0..randomness.len())
+        .map(|_| Fr::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let mut blinding_blinding = Fr::rand(&mut rng);
+    let t = blinding_randomness
+        .iter()
+        .zip(encryption_keys)
+        .zip(g_is)
+        .map(|((r, ek), g_i)| encryption_relation(&blinding_chunks, r, ek, g_i))
+        .collect::<Vec<_>>();
+    let t_comm =
+        message_commitment.map(|(mc, _)| mc.relation(&blinding_chunks, &blinding_blinding));
+    let challenge = equality_challenge(
+        ciphertexts,
+        encryption_keys,
+        &t,
+        message_commitment.map(|(mc, _)| mc).zip(t_comm.as_ref()),
+    )?;
+    let proof = PlaintextEqualityProof {
+        challenge,
+        resp_chunks: blinding_chunks
+            .iter()
+            .zip(chunks)
+            .map(|(b, m)| *b + challenge * m)
+            .collect(),
+        resp_randomness: blinding_randomness
+            .iter()
+            .zip(randomness)
+            .map(|(b, r)| *b + challenge * r)
+            .collect(),
+    };
+    let resp_blinding = message_commitment.map(|(_, b)| blinding_blinding + challenge * b);
+    blinding_chunks.zeroize();
+    blinding_randomness.zeroize();
+    blinding_blinding.zeroize();
+    Ok((proof, resp_blinding))
+}
+
+fn verify_equal_chunks(
+    proof: &PlaintextEqualityProof,
+    ciphertexts: &[SaverCiphertext],
+    encryption_keys: &[SaverEk],
+    g_is: &[&[G1Affine]],
+    message_commitment: Option<(&MessageCommitment, &Fr)>,
+) -> native::Result<()> {
+    check_chunk_counts(proof.resp_chunks.len(), ciphertexts, encryption_keys, g_is)?;
+    if proof.resp_randomness.len() != ciphertexts.len() {
+        return Err(Error::new(
+            ErrorCode::InvalidProof,
+            format!(
+                "Proof has responses for {} ciphertexts but {} were given",
+                proof.resp_randomness.len(),
+                ciphertexts.len()
+            ),
+        ));
+    }
+    // Subtract the challenge times the statement from the relations evaluated at the responses
+    let c = proof.challenge;
+    let mut t = Vec::with_capacity(ciphertexts.len());
+    for (((r, ek), g_i), ct) in proof
+        .resp_randomness
+        .iter()
+        .zip(encryption_keys)
+        .zip(g_is)
+        .zip(ciphertexts)
+    {
+        let mut t_j = encryption_relation(&proof.resp_chunks, r, ek, g_i);
+        subtract_ciphertext(&mut t_j, ct, &c);
+        t.push(t_j);
+    }
+    let t_comm = message_commitment.map(|(mc, resp_blinding)| {
+        (mc.relation(&proof.resp_chunks, resp_blinding).into_group() - *mc.commitment * c)
+            .into_affine()
+    });
+    let challenge = equality_challenge(
+        ciphertexts,
+        encryption_keys,
+        &t,
+        message_commitment.map(|(mc, _)| mc).zip(t_comm.as_ref()),
+    )?;
+    if challenge == proof.challenge {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidProof,
+            "Invalid proof of plaintext equality",
+        ))
+    }
+}
+
+fn equality_challenge(
+    ciphertexts: &[SaverCiphertext],
+    encryption_keys: &[SaverEk],
+    t: &[SaverCiphertext],
+    message_commitment: Option<(&MessageCommitment, &G1Affine)>,
+) -> native::Result<Fr> {
+    let mut bytes = match message_commitment {
+        Some(_) => MULTI_RECIPIENT_DOMAIN.to_vec(),
+        None => PLAINTEXT_EQUALITY_DOMAIN.to_vec(),
+    };
+    ciphertexts.serialize_compressed(&mut bytes)?;
+    encryption_keys.serialize_compressed(&mut bytes)?;
+    t.serialize_compressed(&mut bytes)?;
+    if let Some((mc, t_comm)) = message_commitment {
+        mc.gens.serialize_compressed(&mut bytes)?;
+        mc.commitment.serialize_compressed(&mut bytes)?;
+        t_comm.serialize_compressed(&mut bytes)?;
+        bytes.extend_from_slice(mc.context);
+    }
+    Ok(compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes))
+}
@@ -0,0 +1,322 @@
+//! Standalone Schnorr proofs of knowledge that don't need a `ProofSpec`, for proving ownership of keys and
+//! similar. `PoKProtocol` proves knowledge of `witnesses` such that `y` is the sum of `bases[i]*witnesses[i]`,
+//! which with 1 base is knowledge of a discrete log and with more is knowledge of the opening of a Pedersen
+//! commitment `y`. `DLEqualityProtocol` is the Chaum-Pedersen proof that `y1 = base1*x` and `y2 = base2*x`
+//! for the same `x`. The protocols follow the usual 3 steps: initialize, get the challenge contribution to hash
+//! along with any other data into the challenge and then generate the proof for the challenge.
+//! `ProofOfPossession` is a non-interactive `PoKProtocol` of secret keys bound to a caller supplied context.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Write};
+use ark_std::{collections::BTreeMap, UniformRand};
+use blake2::Blake2b512;
+use schnorr_pok::{compute_random_oracle_challenge, SchnorrCommitment, SchnorrResponse};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    native::{self, Error, ErrorCode},
+    utils::get_seeded_rng,
+};
+
+/// State of the prover of knowledge of discrete logs. Contains the witnesses so must be kept secret.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize, Zeroize, ZeroizeOnDrop)]
+pub struct PoKProtocol<G: AffineRepr> {
+    commitment: SchnorrCommitment<G>,
+    witnesses: Vec<G::ScalarField>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PoKProof<G: AffineRepr> {
+    pub t: G,
+    pub response: SchnorrResponse<G>,
+}
+
+/// State of the prover of equality of discrete logs. Contains the witness so must be kept secret.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize, Zeroize, ZeroizeOnDrop)]
+pub struct DLEqualityProtocol<G: AffineRepr> {
+    #[zeroize(skip)]
+    t1: G,
+    #[zeroize(skip)]
+    t2: G,
+    blinding: G::ScalarField,
+    witness: G::ScalarField,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DLEqualityProof<G: AffineRepr> {
+    pub t1: G,
+    pub t2: G,
+    pub response: G::ScalarField,
+}
+
+/// Proof of possession of the secret keys of a public key. Has a proof of knowledge of the discrete log of each
+/// point of the public key, all for a challenge computed from a domain separator of the key type, the public key and
+/// a caller supplied context like a DID or a nonce so that the proof can't be replayed in another context.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProofOfPossession<G: AffineRepr>(pub Vec<PoKProof<G>>);
+
+impl<G: AffineRepr> PoKProtocol<G> {
+    /// `blindings` maps the index of a witness to the blinding to use for it. This is needed when proving that the
+    /// witness is equal to a witness in another proof. Random blindings are used for the other witnesses.
+    pub fn init(
+        witnesses: Vec<G::ScalarField>,
+        bases: &[G],
+        mut blindings: BTreeMap<usize, G::ScalarField>,
+    ) -> native::Result<Self> {
+        check_bases(bases)?;
+        if witnesses.len() != bases.len() {
+            return Err(Error::new(
+                ErrorCode::IncompatibleParams,
+                format!(
+                    "Number of witnesses {} should be same as the number of bases {}",
+                    witnesses.len(),
+                    bases.len()
+                ),
+            ));
+        }
+        if let Some(i) = blindings.keys().find(|i| **i >= witnesses.len()) {
+            return Err(Error::new(
+                ErrorCode::IndexOutOfBounds,
+                format!(
+                    "Blinding given for index {} but there are only {} witnesses",
+                    i,
+                    witnesses.len()
+                ),
+            ));
+        }
+        let mut rng = get_seeded_rng()?;
+        let blindings = (0..witnesses.len())
+            .map(|i| {
+                blindings
+                    .remove(&i)
+                    .unwrap_or_else(|| G::ScalarField::rand(&mut rng))
+            })
+            .collect();
+        Ok(Self {
+            commitment: SchnorrCommitment::new(bases, blindings),
+            witnesses,
+        })
+    }
+
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        bases: &[G],
+        y: &G,
+        writer: W,
+    ) -> native::Result<()> {
+        pok_challenge_contribution(bases, y, &self.commitment.t, writer)
+    }
+
+    pub fn gen_proof(self, challenge: &G::ScalarField) -> native::Result<PoKProof<G>> {
+        let response = self.commitment.response(&self.witnesses, challenge)?;
+        Ok(PoKProof {
+            t: self.commitment.t,
+            response,
+        })
+    }
+}
+
+impl<G: AffineRepr> PoKProof<G> {
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        bases: &[G],
+        y: &G,
+        writer: W,
+    ) -> native::Result<()> {
+        pok_challenge_contribution(bases, y, &self.t, writer)
+    }
+
+    pub fn verify(&self, bases: &[G], y: &G, challenge: &G::ScalarField) -> native::Result<()> {
+        check_bases(bases)?;
+        Ok(self.response.is_valid(bases, y, &self.t, challenge)?)
+    }
+
+    /// Response for the witness at index `idx`. Equal responses for the same challenge show equal witnesses.
+    pub fn get_response(&self, idx: usize) -> native::Result<&G::ScalarField> {
+        Ok(self.response.get_response(idx)?)
+    }
+}
+
+impl<G: AffineRepr> DLEqualityProtocol<G> {
+    /// A random blinding is used if `blinding` isn't given
+    pub fn init(
+        witness: G::ScalarField,
+        base1: &G,
+        base2: &G,
+        blinding: Option<G::ScalarField>,
+    ) -> native::Result<Self> {
+        let blinding = match blinding {
+            Some(b) => b,
+            None => G::ScalarField::rand(&mut get_seeded_rng()?),
+        };
+        Ok(Self {
+            t1: base1.mul_bigint(blinding.into_bigint()).into_affine(),
+            t2: base2.mul_bigint(blinding.into_bigint()).into_affine(),
+            blinding,
+            witness,
+        })
+    }
+
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        base1: &G,
+        y1: &G,
+        base2: &G,
+        y2: &G,
+        writer: W,
+    ) -> native::Result<()> {
+        dl_equality_challenge_contribution(base1, y1, base2, y2, &self.t1, &self.t2, writer)
+    }
+
+    pub fn gen_proof(self, challenge: &G::ScalarField) -> DLEqualityProof<G> {
+        DLEqualityProof {
+            t1: self.t1,
+            t2: self.t2,
+            response: self.blinding + (self.witness * challenge),
+        }
+    }
+}
+
+impl<G: AffineRepr> DLEqualityProof<G> {
+    pub fn challenge_contribution<W: Write>(
+        &self,
+        base1: &G,
+        y1: &G,
+        base2: &G,
+        y2: &G,
+        writer: W,
+    ) -> native::Result<()> {
+        dl_equality_challenge_contribution(base1, y1, base2, y2, &self.t1, &self.t2, writer)
+    }
+
+    /// Checks `base1*response - y1*challenge == t1` and `base2*response - y2*challenge == t2`
+    pub fn verify(
+        &self,
+        base1: &G,
+        y1: &G,
+        base2: &G,
+        y2: &G,
+        challenge: &G::ScalarField,
+    ) -> native::Result<()> {
+        let valid = |base: &G, y: &G, t: &G| {
+            (base.mul_bigint(self.response.into_bigint()) - y.mul_bigint(challenge.into_bigint()))
+                .into_affine()
+                == *t
+        };
+        if valid(base1, y1, &self.t1) && valid(base2, y2, &self.t2) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorCode::InvalidProof,
+                "Invalid proof of equality of discrete logs",
+            ))
+        }
+    }
+}
+
+impl<G: AffineRepr> ProofOfPossession<G> {
+    /// `public_keys[i]` should be `bases[i] * secret_keys[i]`
+    pub fn new(
+        secret_keys: &[G::ScalarField],
+        bases: &[G],
+        public_keys: &[G],
+        domain: &[u8],
+        context: &[u8],
+    ) -> native::Result<Self> {
+        check_possession_sizes(secret_keys.len(), bases, public_keys)?;
+        let protocols = secret_keys
+            .iter()
+            .zip(bases)
+            .map(|(sk, b)| PoKProtocol::init(vec![*sk], &[*b], BTreeMap::new()))
+            .collect::<native::Result<Vec<_>>>()?;
+        let mut bytes = domain.to_vec();
+        for ((p, b), pk) in protocols.iter().zip(bases).zip(public_keys) {
+            p.challenge_contribution(&[*b], pk, &mut bytes)?;
+        }
+        bytes.extend_from_slice(context);
+        let challenge = compute_random_oracle_challenge::<G::ScalarField, Blake2b512>(&bytes);
+        Ok(Self(
+            protocols
+                .into_iter()
+                .map(|p| p.gen_proof(&challenge))
+                .collect::<native::Result<Vec<_>>>()?,
+        ))
+    }
+
+    pub fn verify(
+        &self,
+        bases: &[G],
+        public_keys: &[G],
+        domain: &[u8],
+        context: &[u8],
+    ) -> native::Result<()> {
+        check_possession_sizes(self.0.len(), bases, public_keys)?;
+        let mut bytes = domain.to_vec();
+        for ((p, b), pk) in self.0.iter().zip(bases).zip(public_keys) {
+            p.challenge_contribution(&[*b], pk, &mut bytes)?;
+        }
+        bytes.extend_from_slice(context);
+        let challenge = compute_random_oracle_challenge::<G::ScalarField, Blake2b512>(&bytes);
+        for (p, (b, pk)) in self.0.iter().zip(bases.iter().zip(public_keys)) {
+            p.verify(&[*b], pk, &challenge)
+                .map_err(|_| Error::new(ErrorCode::InvalidProof, "Invalid proof of possession"))?;
+        }
+        Ok(())
+    }
+}
+
+fn check_possession_sizes<G: AffineRepr>(
+    count: usize,
+    bases: &[G],
+    public_keys: &[G],
+) -> native::Result<()> {
+    if count == 0 || count != bases.len() || count != public_keys.len() {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Expected the same non-zero number of keys, bases and public key points but found {}, {} and {}",
+                count,
+                bases.len(),
+                public_keys.len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn check_bases<G: AffineRepr>(bases: &[G]) -> native::Result<()> {
+    if bases.is_empty() {
+        return Err(Error::invalid_argument("Need at least 1 base"));
+    }
+    Ok(())
+}
+
+fn pok_challenge_contribution<G: AffineRepr, W: Write>(
+    bases: &[G],
+    y: &G,
+    t: &G,
+    mut writer: W,
+) -> native::Result<()> {
+    for b in bases {
+        b.serialize_compressed(&mut writer)?;
+    }
+    y.serialize_compressed(&mut writer)?;
+    t.serialize_compressed(&mut writer)?;
+    Ok(())
+}
+
+fn dl_equality_challenge_contribution<G: AffineRepr, W: Write>(
+    base1: &G,
+    y1: &G,
+    base2: &G,
+    y2: &G,
+    t1: &G,
+    t2: &G,
+    mut writer: W,
+) -> native::Result<()> {
+    for p in [base1, y1, base2, y2, t1, t2] {
+        p.serialize_compressed(&mut writer)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,482 @@
+//! Threshold decryption for SAVER. The decryptor's secret key `rho` is shared among several decryptors with Shamir
+//! secret sharing so that any `threshold` of them can decrypt but fewer learn nothing. The shares are either created
+//! by a dealer with `threshold_decryptor_setup` or by the decryptors with a DKG like FROST's using the generator `H`
+//! of the encryption generators as the public key base, in which case the threshold public key is given to
+//! `decryptor_setup_given_public_key` to create the encryption and decryption keys.
+//!
+//! The encryption key has secrets `s_j` besides `rho` and whoever knows them can decrypt without `rho` as
+//! `c_j - c_0 * s_j` is `G_j * m_j` for chunk `m_j` of the message. So with a DKG, each decryptor also contributes
+//! its own `s_j` with `encryption_key_contribution` and the encryption key is created for the sum of the
+//! contributions, which none of the decryptors knows as long as one of them is honest.
+//!
+//! Decryption needs the secret key only to compute `nu = c_0 * rho` where `c_0` is the first element of the
+//! ciphertext. Each decryptor computes `c_0 * rho_i` for its share `rho_i` with a proof that it used the share of
+//! its public key `H * rho_i`, and anyone can combine `threshold` of these partial decryptions into `nu` and then
+//! the message. The message and `nu` are verified like for a single decryptor with `saver::verify_decryption`.
+
+use ark_bls12_381::Bls12_381;
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{collections::BTreeSet, UniformRand};
+use blake2::Blake2b512;
+use saver::{
+    circuit::BitsizeCheckCircuit,
+    keygen::{DecryptionKey, PreparedDecryptionKey},
+    saver_groth16::{generate_srs, get_gs_for_encryption},
+    utils::{chunks_count, compose},
+};
+use schnorr_pok::compute_random_oracle_challenge;
+use secret_sharing_and_dkg::{
+    common::{lagrange_basis_at_0_for_all, Share, ShareId},
+    shamir_ss::deal_random_secret,
+};
+use zeroize::Zeroize;
+
+use crate::{
+    native::{
+        self,
+        saver::{create_decryption_table, decrypt_to_chunks_given_nu},
+        Error, ErrorCode,
+    },
+    saver::{EncGens, SaverCiphertext, SaverDk, SaverEk, SaverSnarkPk, SaverSnarkVk},
+    utils::get_seeded_rng,
+    Fr, G1Affine, G2Affine,
+};
+
+/// Domain separator for the challenge of the proof of correctness of a partial decryption
+pub const PARTIAL_DECRYPTION_DOMAIN: &[u8] = b"SAVER-PARTIAL-DECRYPTION";
+
+/// Domain separator for the challenge of the proof of a contribution to the encryption key
+pub const ENCRYPTION_KEY_CONTRIBUTION_DOMAIN: &[u8] = b"SAVER-ENCRYPTION-KEY-CONTRIBUTION";
+
+/// Share of the decryptor's secret key `rho` with its id and the threshold
+pub type SaverSecretKeyShare = Share<Fr>;
+
+/// `c_0 * rho_i` for share `rho_i` with a proof that `rho_i` is the discrete log of the share's public key
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PartialDecryption {
+    pub id: ShareId,
+    pub nu_share: G1Affine,
+    pub proof: PartialDecryptionProof,
+}
+
+/// Proof of equality of the discrete logs of `H * rho_i` in G2 and `c_0 * rho_i` in G1
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PartialDecryptionProof {
+    pub t_h: G2Affine,
+    pub t_c: G1Affine,
+    pub response: Fr,
+}
+
+/// A decryptor's contribution `s_j` to each secret of the encryption key, as `X_0 * s_j` where `X_0` is `delta_g1`
+/// of the snark proving key, `gamma_g1 * s_j` and `H * s_j`, with a proof of knowledge of the `s_j`
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct EncryptionKeyContribution {
+    pub x: Vec<G1Affine>,
+    pub gamma: Vec<G1Affine>,
+    pub h: Vec<G2Affine>,
+    pub proof: EncryptionKeyContributionProof,
+}
+
+/// Proof of knowledge of each `s_j` of a contribution and of the equality of its discrete logs in the 3 bases
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct EncryptionKeyContributionProof {
+    pub t_x: Vec<G1Affine>,
+    pub t_gamma: Vec<G1Affine>,
+    pub t_h: Vec<G2Affine>,
+    pub responses: Vec<Fr>,
+}
+
+/// Decryptors that the secret key is shared among. `threshold` of them are needed to decrypt and
+/// `share_public_keys` has the public key of the share with id `i` at index `i - 1`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdDecryptors {
+    pub threshold: ShareId,
+    pub share_public_keys: Vec<G2Affine>,
+}
+
+/// Same as `saver::decryptor_setup` but the secret key is shared among `total` decryptors with threshold
+/// `threshold`. Returns the snark proving key, the secret key shares, the public key of each share, the encryption
+/// key and decryption key. The dealer learns the secret key so it must be trusted to forget it.
+#[allow(clippy::type_complexity)]
+pub fn threshold_decryptor_setup(
+    chunk_bit_size: u8,
+    enc_gens: &EncGens,
+    threshold: ShareId,
+    total: ShareId,
+) -> native::Result<(
+    SaverSnarkPk,
+    Vec<SaverSecretKeyShare>,
+    Vec<G2Affine>,
+    SaverEk,
+    SaverDk,
+)> {
+    let mut rng = get_seeded_rng()?;
+    let (mut secret, shares, _) = deal_random_secret::<_, Fr>(&mut rng, threshold, total)?;
+    let public_key = (enc_gens.H * secret).into_affine();
+    secret.zeroize();
+    let snark_pk = snark_setup(chunk_bit_size, enc_gens)?;
+    let contribution = encryption_key_contribution(&snark_pk, enc_gens, chunk_bit_size)?;
+    let (ek, dk) = decryptor_setup_given_public_key(
+        chunk_bit_size,
+        enc_gens,
+        &snark_pk,
+        &public_key,
+        &[contribution],
+    )?;
+    let share_public_keys = shares
+        .0
+        .iter()
+        .map(|s| share_public_key(s, enc_gens))
+        .collect();
+    Ok((snark_pk, shares.0, share_public_keys, ek, dk))
+}
+
+/// Create the snark proving key for the decryptors. Whoever runs this learns the SNARK trapdoor and can create
+/// proofs for ciphertexts that don't encrypt the committed message, but it can't decrypt. A snark proving key from
+/// a ceremony, see `ceremony`, can be used instead.
+pub fn snark_setup(chunk_bit_size: u8, enc_gens: &EncGens) -> native::Result<SaverSnarkPk> {
+    let circuit = BitsizeCheckCircuit::new(chunk_bit_size, None, None, true);
+    Ok(generate_srs::<Bls12_381, _, _>(
+        circuit,
+        enc_gens,
+        &mut get_seeded_rng()?,
+    )?)
+}
+
+/// Create a decryptor's contribution to the secrets `s_j` of the encryption key for the snark proving key. The
+/// secrets are forgotten after so no one learns the sum of the contributions as long as one decryptor is honest.
+pub fn encryption_key_contribution(
+    snark_pk: &SaverSnarkPk,
+    enc_gens: &EncGens,
+    chunk_bit_size: u8,
+) -> native::Result<EncryptionKeyContribution> {
+    let mut rng = get_seeded_rng()?;
+    let n = chunks_count::<Fr>(chunk_bit_size) as usize;
+    let delta_g = snark_pk.pk.delta_g1;
+    let gamma_g = snark_pk.gamma_g1;
+    let mut s = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let mut blindings = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let x = normalize_g1(s.iter().map(|s_j| delta_g * s_j));
+    let gamma = normalize_g1(s.iter().map(|s_j| gamma_g * s_j));
+    let h = normalize_g2(s.iter().map(|s_j| enc_gens.H * s_j));
+    let t_x = normalize_g1(blindings.iter().map(|b| delta_g * b));
+    let t_gamma = normalize_g1(blindings.iter().map(|b| gamma_g * b));
+    let t_h = normalize_g2(blindings.iter().map(|b| enc_gens.H * b));
+    let challenge = encryption_key_contribution_challenge(
+        snark_pk, enc_gens, &x, &gamma, &h, &t_x, &t_gamma, &t_h,
+    )?;
+    let responses = blindings
+        .iter()
+        .zip(s.iter())
+        .map(|(b, s_j)| *b + *s_j * challenge)
+        .collect();
+    s.zeroize();
+    blindings.zeroize();
+    Ok(EncryptionKeyContribution {
+        x,
+        gamma,
+        h,
+        proof: EncryptionKeyContributionProof {
+            t_x,
+            t_gamma,
+            t_h,
+            responses,
+        },
+    })
+}
+
+/// Verify a decryptor's contribution to the secrets of the encryption key for the snark proving key
+pub fn verify_encryption_key_contribution(
+    contribution: &EncryptionKeyContribution,
+    snark_pk: &SaverSnarkPk,
+    enc_gens: &EncGens,
+    chunk_bit_size: u8,
+) -> native::Result<()> {
+    let n = chunks_count::<Fr>(chunk_bit_size) as usize;
+    let proof = &contribution.proof;
+    if [
+        contribution.x.len(),
+        contribution.gamma.len(),
+        contribution.h.len(),
+        proof.t_x.len(),
+        proof.t_gamma.len(),
+        proof.t_h.len(),
+        proof.responses.len(),
+    ]
+    .iter()
+    .any(|l| *l != n)
+    {
+        return Err(Error::new(
+            ErrorCode::IncompatibleParams,
+            format!(
+                "Contribution should have {} secrets for chunk bit size {}",
+                n, chunk_bit_size
+            ),
+        ));
+    }
+    let delta_g = snark_pk.pk.delta_g1;
+    let gamma_g = snark_pk.gamma_g1;
+    let challenge = encryption_key_contribution_challenge(
+        snark_pk,
+        enc_gens,
+        &contribution.x,
+        &contribution.gamma,
+        &contribution.h,
+        &proof.t_x,
+        &proof.t_gamma,
+        &proof.t_h,
+    )?;
+    for j in 0..n {
+        let z = proof.responses[j];
+        let valid = (delta_g * z - contribution.x[j] * challenge).into_affine() == proof.t_x[j]
+            && (gamma_g * z - contribution.gamma[j] * challenge).into_affine() == proof.t_gamma[j]
+            && (enc_gens.H * z - contribution.h[j] * challenge).into_affine() == proof.t_h[j];
+        if !valid {
+            return Err(Error::new(
+                ErrorCode::InvalidProof,
+                format!("Invalid proof for secret {} of the contribution", j),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Create the encryption key and decryption key for the snark proving key and the decryptor public key
+/// `decryption_public_key = H * rho`, which is `V_0` of the decryption key, without knowing `rho`. This is used when
+/// `rho` is created with a DKG. The secrets `s_j` of the encryption key are the sums of the decryptors'
+/// `contributions`, which are verified first, so whoever runs this can't decrypt. It learns the other secrets of the
+/// keys, which don't let it decrypt either.
+pub fn decryptor_setup_given_public_key(
+    chunk_bit_size: u8,
+    enc_gens: &EncGens,
+    snark_pk: &SaverSnarkPk,
+    decryption_public_key: &G2Affine,
+    contributions: &[EncryptionKeyContribution],
+) -> native::Result<(SaverEk, SaverDk)> {
+    if contributions.is_empty() {
+        return Err(Error::invalid_argument(
+            "Need at least 1 contribution to the encryption key",
+        ));
+    }
+    for (i, c) in contributions.iter().enumerate() {
+        verify_encryption_key_contribution(c, snark_pk, enc_gens, chunk_bit_size)
+            .map_err(|e| e.context(format!("Contribution {} is invalid", i)))?;
+    }
+    let mut rng = get_seeded_rng()?;
+    let g_i = get_gs_for_encryption(&snark_pk.pk.vk);
+    let n = chunks_count::<Fr>(chunk_bit_size) as usize;
+    // `X_0 * s_j`, `gamma_g1 * s_j` and `H * s_j` for the sum `s_j` of the contributions
+    let sum_g1 = |points: fn(&EncryptionKeyContribution) -> &Vec<G1Affine>| {
+        normalize_g1((0..n).map(|j| {
+            contributions
+                .iter()
+                .map(|c| points(c)[j])
+                .sum::<<Bls12_381 as Pairing>::G1>()
+        }))
+    };
+    let x = sum_g1(|c| &c.x);
+    let gamma_s = sum_g1(|c| &c.gamma);
+    let h_s = normalize_g2((0..n).map(|j| {
+        contributions
+            .iter()
+            .map(|c| c.h[j])
+            .sum::<<Bls12_381 as Pairing>::G2>()
+    }));
+
+    // Same as `saver::keygen::keygen` except that `V_0` and the `s_j` in the exponent are given
+    let mut t = (0..=n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let mut v = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let delta_g = snark_pk.pk.delta_g1;
+    let ek = SaverEk {
+        X_0: delta_g,
+        Y: normalize_g1((0..n).map(|i| g_i[i] * t[i + 1])),
+        Z: normalize_g2(t.iter().map(|t_i| enc_gens.H * t_i)),
+        P_1: (delta_g * t[0]
+            + (0..n)
+                .map(|j| x[j] * t[j + 1])
+                .sum::<<Bls12_381 as Pairing>::G1>())
+        .into_affine(),
+        P_2: (gamma_s.iter().sum::<<Bls12_381 as Pairing>::G1>() + snark_pk.gamma_g1).into_affine(),
+        X: x,
+    };
+    let dk = DecryptionKey {
+        V_0: *decryption_public_key,
+        V_1: normalize_g2(h_s.iter().zip(v.iter()).map(|(h_j, v_j)| *h_j * v_j)),
+        V_2: normalize_g2(v.iter().map(|v_i| *decryption_public_key * v_i)),
+    };
+    t.zeroize();
+    v.zeroize();
+    Ok((ek, dk))
+}
+
+/// Public key `H * rho_i` of the share. For shares created with a DKG with `H` as the base, this is the public key
+/// the DKG returns.
+pub fn share_public_key(share: &SaverSecretKeyShare, enc_gens: &EncGens) -> G2Affine {
+    (enc_gens.H * share.share).into_affine()
+}
+
+pub fn partial_decrypt(
+    ciphertext: &SaverCiphertext,
+    share: &SaverSecretKeyShare,
+    enc_gens: &EncGens,
+) -> native::Result<PartialDecryption> {
+    let c_0 = ciphertext.X_r;
+    let nu_share = (c_0 * share.share).into_affine();
+    let public_key = share_public_key(share, enc_gens);
+    let mut blinding = Fr::rand(&mut get_seeded_rng()?);
+    let t_h = (enc_gens.H * blinding).into_affine();
+    let t_c = (c_0 * blinding).into_affine();
+    let challenge =
+        partial_decryption_challenge(enc_gens, &public_key, &c_0, &nu_share, &t_h, &t_c)?;
+    let response = blinding + share.share * challenge;
+    blinding.zeroize();
+    Ok(PartialDecryption {
+        id: share.id,
+        nu_share,
+        proof: PartialDecryptionProof { t_h, t_c, response },
+    })
+}
+
+/// Verify that the partial decryption was created with the share whose public key is `share_public_key`
+pub fn verify_partial_decryption(
+    partial: &PartialDecryption,
+    ciphertext: &SaverCiphertext,
+    share_public_key: &G2Affine,
+    enc_gens: &EncGens,
+) -> native::Result<()> {
+    let c_0 = ciphertext.X_r;
+    let proof = &partial.proof;
+    let challenge = partial_decryption_challenge(
+        enc_gens,
+        share_public_key,
+        &c_0,
+        &partial.nu_share,
+        &proof.t_h,
+        &proof.t_c,
+    )?;
+    let valid_h =
+        (enc_gens.H * proof.response - *share_public_key * challenge).into_affine() == proof.t_h;
+    let valid_c = (c_0 * proof.response - partial.nu_share * challenge).into_affine() == proof.t_c;
+    if valid_h && valid_c {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorCode::InvalidProof,
+            format!("Invalid partial decryption from decryptor {}", partial.id),
+        ))
+    }
+}
+
+/// Combine partial decryptions from at least `threshold` of the decryptors into `nu` and decrypt the ciphertext.
+/// Each partial decryption is verified with the public key of its decryptor's share first. Returns the message and
+/// `nu` like `saver::decrypt`.
+pub fn combine_partial_decryptions(
+    ciphertext: &SaverCiphertext,
+    partials: &[PartialDecryption],
+    decryptors: &ThresholdDecryptors,
+    enc_gens: &EncGens,
+    decryption_key: &SaverDk,
+    snark_vk: &SaverSnarkVk,
+    chunk_bit_size: u8,
+) -> native::Result<(Fr, G1Affine)> {
+    let threshold = decryptors.threshold as usize;
+    if threshold == 0 || threshold > decryptors.share_public_keys.len() {
+        return Err(Error::invalid_argument(format!(
+            "Threshold {} should be between 1 and the number of decryptors {}",
+            threshold,
+            decryptors.share_public_keys.len()
+        )));
+    }
+    if partials.len() < threshold {
+        return Err(Error::invalid_argument(format!(
+            "Need {} partial decryptions but found {}",
+            threshold,
+            partials.len()
+        )));
+    }
+    let ids = partials.iter().map(|p| p.id).collect::<Vec<_>>();
+    if ids.iter().collect::<BTreeSet<_>>().len() != ids.len() {
+        return Err(Error::invalid_argument(
+            "Partial decryptions should be from different decryptors",
+        ));
+    }
+    for p in partials {
+        let public_key = (p.id as usize)
+            .checked_sub(1)
+            .and_then(|i| decryptors.share_public_keys.get(i))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorCode::IndexOutOfBounds,
+                    format!("No public key for decryptor {}", p.id),
+                )
+            })?;
+        verify_partial_decryption(p, ciphertext, public_key, enc_gens)?;
+    }
+    let partials = &partials[..threshold];
+    let lagrange = lagrange_basis_at_0_for_all::<Fr>(ids[..threshold].to_vec());
+    let nu = partials
+        .iter()
+        .zip(lagrange)
+        .map(|(p, l)| p.nu_share * l)
+        .sum::<<Bls12_381 as Pairing>::G1>()
+        .into_affine();
+    let table = create_decryption_table(decryption_key, snark_vk, chunk_bit_size)?;
+    let chunks = decrypt_to_chunks_given_nu(
+        ciphertext,
+        &nu,
+        &PreparedDecryptionKey::from(decryption_key.clone()),
+        &table,
+    )?;
+    Ok((compose(&chunks, chunk_bit_size)?, nu))
+}
+
+fn partial_decryption_challenge(
+    enc_gens: &EncGens,
+    share_public_key: &G2Affine,
+    c_0: &G1Affine,
+    nu_share: &G1Affine,
+    t_h: &G2Affine,
+    t_c: &G1Affine,
+) -> native::Result<Fr> {
+    let mut bytes = PARTIAL_DECRYPTION_DOMAIN.to_vec();
+    enc_gens.H.serialize_compressed(&mut bytes)?;
+    share_public_key.serialize_compressed(&mut bytes)?;
+    t_h.serialize_compressed(&mut bytes)?;
+    c_0.serialize_compressed(&mut bytes)?;
+    nu_share.serialize_compressed(&mut bytes)?;
+    t_c.serialize_compressed(&mut bytes)?;
+    Ok(compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn encryption_key_contribution_challenge(
+    snark_pk: &SaverSnarkPk,
+    enc_gens: &EncGens,
+    x: &[G1Affine],
+    gamma: &[G1Affine],
+    h: &[G2Affine],
+    t_x: &[G1Affine],
+    t_gamma: &[G1Affine],
+    t_h: &[G2Affine],
+) -> native::Result<Fr> {
+    let mut bytes = ENCRYPTION_KEY_CONTRIBUTION_DOMAIN.to_vec();
+    snark_pk.pk.delta_g1.serialize_compressed(&mut bytes)?;
+    snark_pk.gamma_g1.serialize_compressed(&mut bytes)?;
+    enc_gens.H.serialize_compressed(&mut bytes)?;
+    x.serialize_compressed(&mut bytes)?;
+    gamma.serialize_compressed(&mut bytes)?;
+    h.serialize_compressed(&mut bytes)?;
+    t_x.serialize_compressed(&mut bytes)?;
+    t_gamma.serialize_compressed(&mut bytes)?;
+    t_h.serialize_compressed(&mut bytes)?;
+    Ok(compute_random_oracle_challenge::<Fr, Blake2b512>(&bytes))
+}
+
+fn normalize_g1(points: impl Iterator<Item = <Bls12_381 as Pairing>::G1>) -> Vec<G1Affine> {
+    <Bls12_381 as Pairing>::G1::normalize_batch(&points.collect::<Vec<_>>())
+}
+
+fn normalize_g2(points: impl Iterator<Item = <Bls12_381 as Pairing>::G2>) -> Vec<G2Affine> {
+    <Bls12_381 as Pairing>::G2::normalize_batch(&points.collect::<Vec<_>>())
+}
@@ -0,0 +1,195 @@
+use ark_bls12_381::Bls12_381;
+use ark_std::collections::{BTreeMap, BTreeSet};
+use bbs_plus::threshold::{
+    base_ot_phase::BaseOTPhaseOutput, cointoss::Commitments, multiplication_phase,
+    randomness_generation_phase, threshold_bbs, threshold_bbs_plus,
+};
+use blake2::Blake2b512;
+use oblivious_transfer_protocols::ot_based_multiplication::{
+    dkls18_mul_2p::MultiplicationOTEParams, dkls19_batch_mul_2p,
+};
+use secret_sharing_and_dkg::common::ParticipantId;
+
+use crate::{
+    bbs::{BBSSigParams, BBSSignature},
+    bbs_plus::{BBSPlusSigG1, BBSPlusSigParamsG1},
+    native::Result,
+    threshold_sig::{KAPPA, SALT_SIZE, STATISTICAL_SECURITY_PARAMETER},
+    utils::get_seeded_rng,
+    Fr,
+};
+
+pub type Phase1 = randomness_generation_phase::Phase1<Fr, SALT_SIZE>;
+pub type BbsPhase1Output = threshold_bbs::Phase1Output<Fr>;
+pub type BbsPlusPhase1Output = threshold_bbs_plus::Phase1Output<Fr>;
+pub type Phase2 = multiplication_phase::Phase2<Fr, KAPPA, STATISTICAL_SECURITY_PARAMETER>;
+pub type Phase2Output = multiplication_phase::Phase2Output<Fr>;
+pub type Message1 = multiplication_phase::Message1<Fr>;
+pub type Message2 = multiplication_phase::Message2<Fr>;
+pub type GadgetVector =
+    dkls19_batch_mul_2p::GadgetVector<Fr, KAPPA, STATISTICAL_SECURITY_PARAMETER>;
+pub type BbsSignatureShare = threshold_bbs::BBSSignatureShare<Bls12_381>;
+pub type BbsPlusSignatureShare = threshold_bbs_plus::BBSPlusSignatureShare<Bls12_381>;
+/// Shares of the randomness and their salts that a participant sends to another in Phase1
+pub type Phase1Shares = Vec<(Fr, [u8; SALT_SIZE])>;
+
+/// Start Phase1 of threshold BBS+ signing. Returns the Phase1 state, the commitments to send to all others and
+/// the commitments for the zero sharing protocol to send to each of them.
+pub fn start_phase1_for_bbs_plus(
+    sig_batch_size: u32,
+    participant_id: ParticipantId,
+    others: BTreeSet<ParticipantId>,
+    protocol_id: Vec<u8>,
+) -> Result<(Phase1, Commitments, BTreeMap<ParticipantId, Commitments>)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(Phase1::init_for_bbs_plus(
+        &mut rng,
+        sig_batch_size,
+        participant_id,
+        others,
+        protocol_id,
+    )?)
+}
+
+/// Same as `start_phase1_for_bbs_plus` but for BBS
+pub fn start_phase1_for_bbs(
+    sig_batch_size: u32,
+    participant_id: ParticipantId,
+    others: BTreeSet<ParticipantId>,
+    protocol_id: Vec<u8>,
+) -> Result<(Phase1, Commitments, BTreeMap<ParticipantId, Commitments>)> {
+    let mut rng = get_seeded_rng()?;
+    Ok(Phase1::init_for_bbs(
+        &mut rng,
+        sig_batch_size,
+        participant_id,
+        others,
+        protocol_id,
+    )?)
+}
+
+pub fn phase1_process_commitments(
+    phase1: &mut Phase1,
+    sender_id: ParticipantId,
+    commitments: Commitments,
+    commitments_zero_share: Commitments,
+) -> Result<()> {
+    Ok(phase1.receive_commitment(sender_id, commitments, commitments_zero_share)?)
+}
+
+/// Returns the shares to send to `other_id` and the shares for the zero sharing protocol with it
+pub fn phase1_get_shares_for_other(
+    phase1: &Phase1,
+    other_id: ParticipantId,
+) -> (Phase1Shares, Phase1Shares) {
+    (
+        phase1.get_comm_shares_and_salts(),
+        phase1.get_comm_shares_and_salts_for_zero_sharing_protocol_with_other(&other_id),
+    )
+}
+
+pub fn phase1_process_shares(
+    phase1: &mut Phase1,
+    sender_id: ParticipantId,
+    shares: Phase1Shares,
+    zero_shares: Phase1Shares,
+) -> Result<()> {
+    Ok(phase1.receive_shares(sender_id, shares, zero_shares)?)
+}
+
+pub fn finish_phase1_for_bbs_plus(phase1: Phase1, secret_key: &Fr) -> Result<BbsPlusPhase1Output> {
+    Ok(phase1.finish_for_bbs_plus::<Blake2b512>(secret_key)?)
+}
+
+pub fn finish_phase1_for_bbs(phase1: Phase1, secret_key: &Fr) -> Result<BbsPhase1Output> {
+    Ok(phase1.finish_for_bbs::<Blake2b512>(secret_key)?)
+}
+
+/// Start Phase2 with the masked signing key shares and randomness from Phase1. Returns the Phase2 state and the
+/// message to send to each of the others.
+pub fn start_phase2(
+    participant_id: ParticipantId,
+    others: BTreeSet<ParticipantId>,
+    masked_signing_key_shares: Vec<Fr>,
+    masked_rs: Vec<Fr>,
+    base_ot_output: BaseOTPhaseOutput,
+    gadget_vector: &GadgetVector,
+) -> Result<(Phase2, BTreeMap<ParticipantId, Message1>)> {
+    let mut rng = get_seeded_rng()?;
+    let ote_params = MultiplicationOTEParams::<KAPPA, STATISTICAL_SECURITY_PARAMETER> {};
+    Ok(Phase2::init(
+        &mut rng,
+        participant_id,
+        masked_signing_key_shares,
+        masked_rs,
+        base_ot_output,
+        others,
+        ote_params,
+        gadget_vector,
+    )?)
+}
+
+/// Process `Message1` from `sender_id` and return the `Message2` to send back to it
+pub fn phase2_receive_message1(
+    phase2: &mut Phase2,
+    sender_id: ParticipantId,
+    message: Message1,
+    gadget_vector: &GadgetVector,
+) -> Result<Message2> {
+    Ok(phase2.receive_message1::<Blake2b512>(sender_id, message, gadget_vector)?)
+}
+
+pub fn phase2_receive_message2(
+    phase2: &mut Phase2,
+    sender_id: ParticipantId,
+    message: Message2,
+    gadget_vector: &GadgetVector,
+) -> Result<()> {
+    Ok(phase2.receive_message2::<Blake2b512>(sender_id, message, gadget_vector)?)
+}
+
+pub fn finish_phase2(phase2: Phase2) -> Phase2Output {
+    phase2.finish()
+}
+
+pub fn create_bbs_plus_signature_share(
+    messages: &[Fr],
+    index_in_output: usize,
+    phase1_output: &BbsPlusPhase1Output,
+    phase2_output: &Phase2Output,
+    params: &BBSPlusSigParamsG1,
+) -> Result<BbsPlusSignatureShare> {
+    Ok(BbsPlusSignatureShare::new(
+        messages,
+        index_in_output,
+        phase1_output,
+        phase2_output,
+        params,
+    )?)
+}
+
+pub fn create_bbs_signature_share(
+    messages: &[Fr],
+    index_in_output: usize,
+    phase1_output: &BbsPhase1Output,
+    phase2_output: &Phase2Output,
+    params: &BBSSigParams,
+) -> Result<BbsSignatureShare> {
+    Ok(BbsSignatureShare::new(
+        messages,
+        index_in_output,
+        phase1_output,
+        phase2_output,
+        params,
+    )?)
+}
+
+pub fn aggregate_bbs_plus_signature_shares(
+    shares: Vec<BbsPlusSignatureShare>,
+) -> Result<BBSPlusSigG1> {
+    Ok(BbsPlusSignatureShare::aggregate(shares)?)
+}
+
+pub fn aggregate_bbs_signature_shares(shares: Vec<BbsSignatureShare>) -> Result<BBSSignature> {
+    Ok(BbsSignatureShare::aggregate(shares)?)
+}
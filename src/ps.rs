@@ -1,9 +1,8 @@
 use crate::utils::{
-    debug_to_js_value, fr_from_uint8_array, get_seeded_rng, js_set_to_btree_set, random_bytes,
-    set_panic_hook,
+    fr_from_uint8_array, from_value, get_seeded_rng, js_set_to_btree_set, set_panic_hook,
 };
 
-use crate::{common::VerifyResponse, utils, Fr, G1Affine};
+use crate::{common::VerifyResponse, native, schnorr::ProofOfPossessionG2, utils, Fr, G1Affine};
 use ark_bls12_381::Bls12_381;
 use ark_std::collections::BTreeMap;
 use blake2::Blake2b512;
@@ -14,7 +13,7 @@ use coconut_crypto::{
 };
 use dock_crypto_utils::{concat_slices, hashing_utils::affine_group_elem_from_try_and_incr, pairs};
 use js_sys::Uint8Array;
-use serde_wasm_bindgen::{from_value, to_value};
+use serde_wasm_bindgen::to_value;
 use wasm_bindgen::prelude::*;
 use zeroize::Zeroize;
 
@@ -23,11 +22,11 @@ pub type PSBlindSignature = signature::BlindSignature<Bls12_381>;
 pub type PSPublicKey = setup::PublicKey<Bls12_381>;
 pub type PSSignatureParams = setup::SignatureParams<Bls12_381>;
 pub type PSAggregatedSignature = signature::AggregatedSignature<Bls12_381>;
-pub(crate) type PSSignature = signature::Signature<Bls12_381>;
-pub(crate) type PSPoKOfSigProtocol = proof::SignaturePoKGenerator<Bls12_381>;
-pub(crate) type PSPoKOfMessagesProtocol = proof::MessagesPoKGenerator<Bls12_381>;
-pub(crate) type PSPoKOfSignatureProof = proof::SignaturePoK<Bls12_381>;
-pub(crate) type PSPoKOfMessagesProof = proof::MessagesPoK<Bls12_381>;
+pub type PSSignature = signature::Signature<Bls12_381>;
+pub type PSPoKOfSigProtocol = proof::SignaturePoKGenerator<Bls12_381>;
+pub type PSPoKOfMessagesProtocol = proof::MessagesPoKGenerator<Bls12_381>;
+pub type PSPoKOfSignatureProof = proof::SignaturePoK<Bls12_381>;
+pub type PSPoKOfMessagesProof = proof::MessagesPoK<Bls12_381>;
 
 macro_rules! adapt_key_for_less_messages {
     ($key: ident: $key_type: ident for $new_count: ident using $pop_element: expr) => {{
@@ -96,8 +95,7 @@ pub fn ps_public_key_supported_msgs(public_key: Uint8Array) -> Result<usize, JsV
 #[wasm_bindgen(js_name = psGenerateSignatureParams)]
 pub fn ps_generate_params(message_count: u32, label: Option<Vec<u8>>) -> Result<JsValue, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let params = PSSignatureParams::new::<Blake2b512>(&label, message_count);
+    let params = native::ps::generate_params(message_count, label.as_deref())?;
 
     to_value(&params).map_err(JsValue::from)
 }
@@ -115,8 +113,7 @@ pub fn ps_generate_secret_key(
     seed: Option<Vec<u8>>,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let seed = seed.unwrap_or_else(random_bytes);
-    let sk = PSSecretKey::from_seed::<Blake2b512>(&seed, message_count);
+    let sk = native::ps::generate_secret_key(message_count, seed.as_deref())?;
     Ok(obj_to_uint8array!(&sk, true, "PSSecretKey"))
 }
 
@@ -128,7 +125,7 @@ pub fn ps_generate_public_key(
     set_panic_hook();
     let sk = obj_from_uint8array!(PSSecretKey, secret_key, true, "PSSecretKey");
     let params: PSSignatureParams = from_value(params)?;
-    let pk = PSPublicKey::new(&sk, &params);
+    let pk = native::ps::generate_public_key(&sk, &params);
 
     Ok(obj_to_uint8array!(&pk, false, "PSPublicKey"))
 }
@@ -215,6 +212,38 @@ pub fn ps_is_pubkey_valid(public_key: js_sys::Uint8Array) -> Result<bool, JsValu
     Ok(pk.valid())
 }
 
+/// Proof of possession of all the secret scalars of the public key generated by `psGeneratePublicKey`, bound to
+/// `context` like the DID of the key's owner or a verifier's nonce
+#[wasm_bindgen(js_name = psGenerateProofOfPossession)]
+pub fn ps_generate_proof_of_possession(
+    secret_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let sk = obj_from_uint8array!(PSSecretKey, secret_key, true, "PSSecretKey");
+    let params: PSSignatureParams = from_value(params)?;
+    let proof = native::ps::generate_proof_of_possession(&sk, &params, &context)?;
+    Ok(obj_to_uint8array!(&proof, false, "ProofOfPossession"))
+}
+
+/// Verify the proof of possession for the public key and `context`. This doesn't check that the public key is
+/// valid, `psIsPublicKeyValid` should be used for that.
+#[wasm_bindgen(js_name = psVerifyProofOfPossession)]
+pub fn ps_verify_proof_of_possession(
+    proof: js_sys::Uint8Array,
+    public_key: js_sys::Uint8Array,
+    params: JsValue,
+    context: Vec<u8>,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(ProofOfPossessionG2, proof, false, "ProofOfPossession");
+    let pk = obj_from_uint8array!(PSPublicKey, public_key, false, "PSPublicKey");
+    let params: PSSignatureParams = from_value(params)?;
+    let result = native::ps::verify_proof_of_possession(&proof, &pk, &params, &context);
+    Ok(to_value(&VerifyResponse::from(result)).unwrap())
+}
+
 #[wasm_bindgen(js_name = psMessageCommitment)]
 pub fn ps_message_commitment(
     message: js_sys::Uint8Array,
@@ -268,11 +297,8 @@ pub fn ps_sign(
     let sk = obj_from_uint8array!(PSSecretKey, secret_key, true, "PSSecretKey");
     let params: PSSignatureParams = from_value(params)?;
     let messages: Vec<_> = utils::js_array_to_iter(&messages).collect::<Result<_, _>>()?;
-
-    let mut rng = get_seeded_rng();
-    PSSignature::new(&mut rng, &messages, &sk, &params)
-        .map_err(utils::debug_to_js_value)
-        .and_then(|sig| Ok(obj_to_uint8array!(&sig, true, "PSSignature")))
+    let sig = native::ps::sign(&messages, &sk, &params)?;
+    Ok(obj_to_uint8array!(&sig, true, "PSSignature"))
 }
 
 #[wasm_bindgen(js_name = psBlindSign)]
@@ -290,10 +316,8 @@ pub fn ps_blind_sign(
 
     let sk = obj_from_uint8array!(PSSecretKey, secret_key, true, "PSSecretKey");
     let h = obj_from_uint8array!(G1Affine, h, false);
-
-    PSBlindSignature::new(messages, &sk, &h)
-        .map_err(utils::debug_to_js_value)
-        .and_then(|sig| Ok(obj_to_uint8array!(&sig, true, "PSBlindSignature")))
+    let sig = native::ps::blind_sign(messages, &sk, &h)?;
+    Ok(obj_to_uint8array!(&sig, true, "PSBlindSignature"))
 }
 
 #[wasm_bindgen(js_name = psUnblindSignature)]
@@ -307,18 +331,9 @@ pub fn ps_unblind_sig(
     let signature = obj_from_uint8array!(PSBlindSignature, blind_signature, false);
     let indexed_blindings: BTreeMap<_, _> =
         utils::js_map_to_iter(&indexed_blindings).collect::<Result<_, _>>()?;
-    let sorted_indexed_blindigns = indexed_blindings
-        .iter()
-        .map(|(&idx, message)| (idx, message));
     let pk = obj_from_uint8array!(PSPublicKey, public_key, false, "PSPublicKey");
-
-    Ok(obj_to_uint8array!(
-        &signature
-            .unblind(sorted_indexed_blindigns, &pk)
-            .map_err(debug_to_js_value)?,
-        true,
-        "PSSignature"
-    ))
+    let signature = native::ps::unblind_signature(signature, &indexed_blindings, &pk)?;
+    Ok(obj_to_uint8array!(&signature, true, "PSSignature"))
 }
 
 #[wasm_bindgen(js_name = psVerify)]
@@ -333,23 +348,14 @@ pub fn ps_verify(
     let pk = obj_from_uint8array!(PSPublicKey, public_key, false, "PSPublicKey");
     let params: PSSignatureParams = from_value(params)?;
     let messages: Vec<_> = utils::js_array_to_iter(&messages).collect::<Result<_, _>>()?;
-
-    signature
-        .verify(&messages, &pk, &params)
-        .map(|_| {
-            to_value(&VerifyResponse {
-                verified: true,
-                error: None,
-            })
-            .unwrap()
-        })
-        .map_err(|err| {
-            to_value(&VerifyResponse {
-                verified: false,
-                error: Some(format!("{:?}", err)),
-            })
-            .unwrap()
-        })
+    let result = native::ps::verify(&messages, &signature, &pk, &params);
+    let verified = result.is_ok();
+    let response = to_value(&VerifyResponse::from(result)).unwrap();
+    if verified {
+        Ok(response)
+    } else {
+        Err(response)
+    }
 }
 
 #[wasm_bindgen(js_name = psInitializeSignaturePoK)]
@@ -369,11 +375,11 @@ pub fn ps_initialize_signature_pok(
         .map(Result::unwrap)
         .map(|js_msg| from_value::<CommitMessage<Fr>>(js_msg).unwrap());
 
-    let mut rng = get_seeded_rng();
+    let mut rng = get_seeded_rng()?;
 
-    PSPoKOfSigProtocol::init(&mut rng, messages, &signature, &pk, &params)
-        .map_err(utils::debug_to_js_value)
-        .and_then(|protocol| to_value(&protocol).map_err(Into::into))
+    let protocol = PSPoKOfSigProtocol::init(&mut rng, messages, &signature, &pk, &params)
+        .map_err(native::Error::from)?;
+    to_value(&protocol).map_err(Into::into)
 }
 
 #[wasm_bindgen(js_name = psInitializeMessagesPoK)]
@@ -391,11 +397,11 @@ pub fn ps_initialize_messages_pok(
     let params: PSSignatureParams = from_value(params)?;
     let h = obj_from_uint8array!(G1Affine, h, false);
 
-    let mut rng = get_seeded_rng();
+    let mut rng = get_seeded_rng()?;
 
-    PSPoKOfMessagesProtocol::init(&mut rng, messages, &params, &h)
-        .map_err(utils::debug_to_js_value)
-        .and_then(|protocol| to_value(&protocol).map_err(Into::into))
+    let protocol = PSPoKOfMessagesProtocol::init(&mut rng, messages, &params, &h)
+        .map_err(native::Error::from)?;
+    to_value(&protocol).map_err(Into::into)
 }
 
 #[wasm_bindgen(js_name = psGenSignaturePoK)]
@@ -407,10 +413,10 @@ pub fn ps_gen_sig_proof(
     let protocol: PSPoKOfSigProtocol = from_value(protocol)?;
     let challenge = fr_from_uint8_array(challenge, false)?;
 
-    protocol
+    let proof = protocol
         .gen_proof(&challenge)
-        .map_err(utils::debug_to_js_value)
-        .and_then(|proof| Ok(obj_to_uint8array!(&proof, false, "PSPoKOfSignatureProof")))
+        .map_err(native::Error::from)?;
+    Ok(obj_to_uint8array!(&proof, false, "PSPoKOfSignatureProof"))
 }
 
 #[wasm_bindgen(js_name = psGenMessagesPoK)]
@@ -422,10 +428,10 @@ pub fn ps_gen_messages_proof(
     let protocol: PSPoKOfMessagesProtocol = from_value(protocol)?;
     let challenge = fr_from_uint8_array(challenge, false)?;
 
-    protocol
+    let proof = protocol
         .gen_proof(&challenge)
-        .map_err(utils::debug_to_js_value)
-        .and_then(|proof| Ok(obj_to_uint8array!(&proof, false, "PSPoKOfMessagesProof")))
+        .map_err(native::Error::from)?;
+    Ok(obj_to_uint8array!(&proof, false, "PSPoKOfMessagesProof"))
 }
 
 #[wasm_bindgen(js_name = psVerifySignaturePoK)]
@@ -448,20 +454,8 @@ pub fn ps_verify_signature_proof(
 
     proof
         .verify(&challenge, msgs_iter, &public_key, &params)
-        .map(|_| {
-            to_value(&VerifyResponse {
-                verified: true,
-                error: None,
-            })
-            .unwrap()
-        })
-        .map_err(|err| {
-            to_value(&VerifyResponse {
-                verified: false,
-                error: Some(format!("{:?}", err)),
-            })
-            .unwrap()
-        })
+        .map(|_| to_value(&VerifyResponse::from(Ok(()))).unwrap())
+        .map_err(|err| to_value(&VerifyResponse::from(Err(native::Error::from(err)))).unwrap())
 }
 
 #[wasm_bindgen(js_name = psVerifyMessagesPoK)]
@@ -476,26 +470,14 @@ pub fn ps_verify_messages_proof(
 
     let proof: PSPoKOfMessagesProof = obj_from_uint8array!(PSPoKOfMessagesProof, proof, false);
     let challenge = fr_from_uint8_array(challenge, false)?;
-    let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices);
+    let revealed_indices = js_set_to_btree_set::<usize>(&revealed_indices)?;
     let params: PSSignatureParams = from_value(params)?;
     let h = obj_from_uint8array!(G1Affine, h, false);
 
     proof
         .verify(&challenge, revealed_indices, &params, &h)
-        .map(|_| {
-            to_value(&VerifyResponse {
-                verified: true,
-                error: None,
-            })
-            .unwrap()
-        })
-        .map_err(|err| {
-            to_value(&VerifyResponse {
-                verified: false,
-                error: Some(format!("{:?}", err)),
-            })
-            .unwrap()
-        })
+        .map(|_| to_value(&VerifyResponse::from(Ok(()))).unwrap())
+        .map_err(|err| to_value(&VerifyResponse::from(Err(native::Error::from(err)))).unwrap())
 }
 
 #[wasm_bindgen(js_name = psChallengeSignaturePoKContributionFromProtocol)]
@@ -514,10 +496,7 @@ pub fn ps_challenge_signature_pok_contribution_from_protocol(
     protocol
         .challenge_contribution(&mut bytes, &pk, &params)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
 
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
@@ -539,10 +518,7 @@ pub fn ps_challenge_messages_pok_contribution_from_protocol(
     protocol
         .challenge_contribution(&mut bytes, &params, &h)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
 
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
@@ -564,10 +540,7 @@ pub fn ps_challenge_signature_pok_contribution_from_proof(
     proof
         .challenge_contribution(&mut bytes, &pk, &params)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
 
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
@@ -589,10 +562,7 @@ pub fn ps_challenge_messages_pok_contribution_from_proof(
     proof
         .challenge_contribution(&mut bytes, &params, &h)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Evaluating challenge_contribution returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Evaluating challenge_contribution returned error")
         })?;
 
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
@@ -618,7 +588,9 @@ pub fn ps_aggregate_signatures(
                 part_sig.and_then(|(participant, signature)| {
                     participant
                         .try_into()
-                        .map_err(|_| "Invalid participant id".into())
+                        .map_err(|_| {
+                            native::Error::invalid_argument("Invalid participant id").into()
+                        })
                         .map(|participant| (participant, signature))
                 })
             })
@@ -627,8 +599,14 @@ pub fn ps_aggregate_signatures(
         .iter()
         .map(|(&participant, signature)| (participant, signature));
     let h = obj_from_uint8array!(G1Affine, h, false);
-    let aggregated = PSAggregatedSignature::new(sorted_participant_signatures_iter, &h)
-        .map_err(utils::debug_to_js_value)?;
+    let aggregated =
+        PSAggregatedSignature::new(sorted_participant_signatures_iter, &h).map_err(|e| {
+            native::Error::with_details(
+                native::ErrorCode::InvalidSignature,
+                "Aggregating signatures failed",
+                e,
+            )
+        })?;
 
     Ok(obj_to_uint8array!(
         &aggregated,
@@ -641,10 +619,11 @@ pub fn ps_aggregate_signatures(
 pub fn ps_shamir_deal(message_count: u32, threshold: u16, total: u16) -> Result<JsValue, JsValue> {
     set_panic_hook();
 
-    let threshold = Threshold::new(threshold, total).ok_or("Invalid threshold")?;
-    let mut rng = get_seeded_rng();
+    let threshold = Threshold::new(threshold, total)
+        .ok_or_else(|| native::Error::invalid_argument("Invalid threshold"))?;
+    let mut rng = get_seeded_rng()?;
     let (threshold_sk, sks) = shamir_ss::deal::<_, Fr>(&mut rng, message_count, threshold)
-        .map_err(utils::debug_to_js_value)?;
+        .map_err(native::Error::from)?;
     let threshold_sk: JsValue = obj_to_uint8array!(&threshold_sk, true, "PSSecretKey").into();
     let sks: js_sys::Array = sks
         .into_iter()
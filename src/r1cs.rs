@@ -8,23 +8,26 @@ use legogroth16::circom::{
 use wasm_bindgen::prelude::*;
 use zeroize::Zeroize;
 
-use crate::utils::{get_seeded_rng, js_array_from_frs, js_array_to_fr_vec, set_panic_hook};
+use crate::{
+    handles::WitnessCalculatorHandle,
+    native::{self, ErrorCode},
+    utils::{fr_from_jsvalue, from_value, get_seeded_rng, js_array_from_frs, set_panic_hook},
+};
 
 fn parse_lc(lc: js_sys::Array) -> Result<LC<Bls12_381>, JsValue> {
     let mut terms = vec![];
     for t in lc.values() {
         let term = js_sys::Array::from(&t.unwrap());
         if term.length() != 2 {
-            return Err(JsValue::from(&format!(
+            return Err(native::Error::invalid_argument(format!(
                 "Each term should be of length 2 but found of length {:?}",
                 term.length()
-            )));
+            ))
+            .into());
         }
-        let i = term
-            .get(0)
-            .as_f64()
-            .ok_or_else(|| JsValue::from("The 1st element of term should have been a number"))?
-            as usize;
+        let i = term.get(0).as_f64().ok_or_else(|| {
+            native::Error::invalid_argument("The 1st element of term should have been a number")
+        })? as usize;
         let mut v_bytes = js_sys::Uint8Array::new(&term.get(1)).to_vec();
 
         // Create an field element from bytes in little-endian. Intentionally not calling `Fr::from_le_bytes_mod_order`
@@ -42,10 +45,11 @@ fn parse_constraints(constraints: js_sys::Array) -> Result<Vec<Constraint<Bls12_
     for c in constraints.values() {
         let constraint = js_sys::Array::from(&c.unwrap());
         if constraint.length() != 3 {
-            return Err(JsValue::from(&format!(
+            return Err(native::Error::invalid_argument(format!(
                 "Each constraint should be of length 3 but was found of length {:?}",
                 constraint.length()
-            )));
+            ))
+            .into());
         }
         let a = js_sys::Array::from(&constraint.get(0));
         let b = js_sys::Array::from(&constraint.get(1));
@@ -66,10 +70,10 @@ pub fn gen_r1cs(
     constraints: js_sys::Array,
 ) -> Result<R1CS<Bls12_381>, JsValue> {
     if curve_name != "bls12381" {
-        return Err(JsValue::from(&format!(
+        return Err(native::Error::invalid_argument(format!(
             "Only BLS12-381 curve is supported and curve name should be bls12381 but was given {:?}",
             curve_name
-        )));
+        )).into());
     }
     Ok(R1CS {
         curve: Curve::Bls12_381,
@@ -80,77 +84,160 @@ pub fn gen_r1cs(
     })
 }
 
-pub fn gen_wires(
-    wasm_bytes: js_sys::Uint8Array,
-    input_wires: js_sys::Map,
-) -> Result<Vec<Fr>, JsValue> {
-    let mut witness_calculator =
-        WitnessCalculator::<Bls12_381>::from_wasm_bytes(&wasm_bytes.to_vec()).unwrap();
+/// Get the R1CS of a circuit given either as an R1CS returned by `r1csFromBytes` or as the curve name, the counts of
+/// public and private wires and the constraints
+pub fn get_r1cs(
+    curve_name_or_r1cs: JsValue,
+    num_public: Option<u32>,
+    num_private: Option<u32>,
+    constraints: Option<js_sys::Array>,
+) -> Result<R1CS<Bls12_381>, JsValue> {
+    if curve_name_or_r1cs.is_instance_of::<js_sys::Uint8Array>() {
+        let r1cs = js_sys::Uint8Array::from(curve_name_or_r1cs);
+        return Ok(obj_from_uint8array!(R1CS<Bls12_381>, r1cs, false, "R1CS"));
+    }
+    let curve_name = curve_name_or_r1cs.as_string().ok_or_else(|| {
+        native::Error::invalid_argument(
+            "Expected the curve name or an R1CS returned by r1csFromBytes",
+        )
+    })?;
+    match (num_public, num_private, constraints) {
+        (Some(num_public), Some(num_private), Some(constraints)) => {
+            gen_r1cs(&curve_name, num_public, num_private, constraints)
+        }
+        _ => Err(native::Error::invalid_argument(
+            "The counts of public and private wires and the constraints should be given with the curve name",
+        )
+        .into()),
+    }
+}
+
+/// Values of a signal can be given as field elements in bytes like `generateFieldElementFromNumber` returns,
+/// bigints or decimal strings
+pub fn js_array_to_signal_values(array: &js_sys::Array) -> Result<Vec<Fr>, JsValue> {
+    let mut values = Vec::with_capacity(array.length() as usize);
+    for v in array.values() {
+        let v = v.unwrap();
+        let value = if let Some(s) = v.as_string() {
+            native::r1cs::signal_value_from_str(&s)?
+        } else if v.is_bigint() {
+            let s = String::from(js_sys::BigInt::from(v).to_string(10)?);
+            native::r1cs::signal_value_from_str(&s)?
+        } else {
+            fr_from_jsvalue(v)?
+        };
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Input signals as a map from the signal's name to its values
+pub fn parse_input_wires(input_wires: &js_sys::Map) -> Result<Vec<(String, Vec<Fr>)>, JsValue> {
     let mut inputs = vec![];
     for e in input_wires.entries() {
         let arr = js_sys::Array::from(&e.unwrap());
-        let name: String = serde_wasm_bindgen::from_value(arr.get(0))?;
+        let name: String = from_value(arr.get(0))?;
         let vals = js_sys::Array::from(&arr.get(1));
-        inputs.push((name, js_array_to_fr_vec(&vals)?));
+        inputs.push((name, js_array_to_signal_values(&vals)?));
     }
-    witness_calculator
-        .calculate_witnesses(inputs.into_iter(), true)
-        .map_err(|e| JsValue::from(&format!("Error while generating witnesses: {:?}", e)))
+    Ok(inputs)
+}
+
+/// Calculate all wires of the circuit with the witness calculator
+pub fn calculate_wires(
+    calculator: &mut WitnessCalculator<Bls12_381>,
+    input_wires: &js_sys::Map,
+) -> Result<Vec<Fr>, JsValue> {
+    let inputs = parse_input_wires(input_wires)?;
+    Ok(native::r1cs::calculate_wires(calculator, inputs)?)
 }
 
+/// Takes a witness calculator created once for the circuit. The JS function also accepts the circuit's wasm bytes
+/// instead
 #[wasm_bindgen(js_name = r1csGenerateWires)]
 pub fn r1cs_generate_wires(
-    wasm_bytes: js_sys::Uint8Array,
+    calculator: &mut WitnessCalculatorHandle,
     input_wires: js_sys::Map,
 ) -> Result<js_sys::Array, JsValue> {
     set_panic_hook();
-    let wires = gen_wires(wasm_bytes, input_wires)?;
+    let wires = calculate_wires(&mut calculator.0, &input_wires)?;
     js_array_from_frs(&wires)
 }
 
+/// Parse the bytes of a Circom `.r1cs` file for a circuit over BLS12-381. The returned R1CS can be passed to
+/// `r1csSnarkSetup`, `r1csCircuitSatisfied` and `generateSetupParamForR1CS` instead of the curve name, counts of
+/// public and private wires and constraints, avoiding to parse the file in JS.
+#[wasm_bindgen(js_name = r1csFromBytes)]
+pub fn r1cs_from_bytes(bytes: js_sys::Uint8Array) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let r = native::r1cs::read_r1cs(&bytes.to_vec())?;
+    Ok(obj_to_uint8array!(&r, false, "R1CS"))
+}
+
+/// The circuit is given as for `get_r1cs` and the wires are calculated as for `r1csGenerateWires`
 #[wasm_bindgen(js_name = r1csCircuitSatisfied)]
 pub fn r1cs_circuit_satisfied(
-    curve_name: &str,
-    num_public: u32,
-    num_private: u32,
-    constraints: js_sys::Array,
-    wasm_bytes: js_sys::Uint8Array,
+    curve_name_or_r1cs: JsValue,
+    num_public: Option<u32>,
+    num_private: Option<u32>,
+    constraints: Option<js_sys::Array>,
+    calculator: &mut WitnessCalculatorHandle,
     input_wires: js_sys::Map,
 ) -> Result<bool, JsValue> {
     set_panic_hook();
-    let r = gen_r1cs(curve_name, num_public, num_private, constraints)?;
+    let r = get_r1cs(curve_name_or_r1cs, num_public, num_private, constraints)?;
+    circuit_satisfied(r, calculate_wires(&mut calculator.0, &input_wires)?)
+}
+
+/// The circuit is given as for `get_r1cs`
+#[wasm_bindgen(js_name = r1csSnarkSetup)]
+pub fn r1cs_snark_setup(
+    curve_name_or_r1cs: JsValue,
+    num_public: Option<u32>,
+    num_private: Option<u32>,
+    constraints: Option<js_sys::Array>,
+    commit_witness_count: u32,
+    return_uncompressed: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let r = get_r1cs(curve_name_or_r1cs, num_public, num_private, constraints)?;
+    snark_setup(r, commit_witness_count, return_uncompressed)
+}
+
+fn circuit_satisfied(r: R1CS<Bls12_381>, wires: Vec<Fr>) -> Result<bool, JsValue> {
     let mut circuit = CircomCircuit::setup(r);
-    let wires = gen_wires(wasm_bytes, input_wires)?;
     circuit.set_wires(wires);
     let cs = ConstraintSystem::<Fr>::new_ref();
     circuit
         .clone()
         .generate_constraints(cs.clone())
-        .map_err(|e| JsValue::from(&format!("Error while generating constraints: {:?}", e)))?;
+        .map_err(|e| {
+            native::Error::with_details(
+                ErrorCode::InvalidArgument,
+                "Error while generating constraints",
+                e,
+            )
+        })?;
     cs.is_satisfied().map_err(|e| {
-        JsValue::from(&format!(
-            "Error while checking if circuit is satisfied: {:?}",
-            e
-        ))
+        native::Error::with_details(
+            ErrorCode::InvalidArgument,
+            "Error while checking if circuit is satisfied",
+            e,
+        )
+        .into()
     })
 }
 
-#[wasm_bindgen(js_name = r1csSnarkSetup)]
-pub fn r1cs_snark_setup(
-    curve_name: &str,
-    num_public: u32,
-    num_private: u32,
-    constraints: js_sys::Array,
+fn snark_setup(
+    r: R1CS<Bls12_381>,
     commit_witness_count: u32,
     return_uncompressed: bool,
 ) -> Result<js_sys::Uint8Array, JsValue> {
-    set_panic_hook();
-    let r = gen_r1cs(curve_name, num_public, num_private, constraints)?;
     let circuit = CircomCircuit::setup(r);
-    let mut rng = get_seeded_rng();
+    let mut rng = get_seeded_rng()?;
     let pk = circuit
         .generate_proving_key(commit_witness_count, &mut rng)
-        .map_err(|e| JsValue::from(&format!("SNARK setup for R1CS returned error: {:?}", e)))?;
+        .map_err(|e| native::Error::from(e).context("SNARK setup for R1CS returned error"))?;
     Ok(if return_uncompressed {
         obj_to_uint8array_uncompressed!(&pk, "LegoProvingKey")
     } else {
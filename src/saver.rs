@@ -1,39 +1,42 @@
 use crate::{
     common::VerifyResponse,
+    native::{
+        self,
+        saver::{DecryptionTable, MultiRecipientProof, PlaintextEqualityProof, ReencryptionProof},
+    },
     utils::{
         fr_from_uint8_array, fr_to_uint8_array, g1_affine_from_uint8_array,
-        g1_affine_to_uint8_array, get_seeded_rng, random_bytes, set_panic_hook,
+        g1_affine_to_uint8_array, js_array_to_fr_vec, set_panic_hook,
     },
-    Fr,
+    Fr, G1Affine,
 };
 use ark_bls12_381::Bls12_381;
 use ark_ec::pairing::Pairing;
-use blake2::Blake2b512;
+use ark_serialize::CanonicalDeserialize;
 use saver::{
     encryption::Ciphertext,
     keygen::{DecryptionKey, EncryptionKey, SecretKey},
     saver_groth16::{ProvingKey, VerifyingKey},
-    setup::{setup_for_groth16, ChunkedCommitmentGens, EncryptionGens},
+    setup::{ChunkedCommitmentGens, EncryptionGens},
 };
 use wasm_bindgen::prelude::*;
 use zeroize::Zeroize;
 
-pub(crate) type EncGens = EncryptionGens<Bls12_381>;
-pub(crate) type ChunkedCommGens = ChunkedCommitmentGens<<Bls12_381 as Pairing>::G1Affine>;
-pub(crate) type SaverSk = SecretKey<Fr>;
-pub(crate) type SaverEk = EncryptionKey<Bls12_381>;
-pub(crate) type SaverDk = DecryptionKey<Bls12_381>;
-pub(crate) type SaverSnarkPk = ProvingKey<Bls12_381>;
-pub(crate) type SaverSnarkVk = VerifyingKey<Bls12_381>;
-pub(crate) type SaverCiphertext = Ciphertext<Bls12_381>;
+pub type EncGens = EncryptionGens<Bls12_381>;
+pub type ChunkedCommGens = ChunkedCommitmentGens<<Bls12_381 as Pairing>::G1Affine>;
+pub type SaverSk = SecretKey<Fr>;
+pub type SaverEk = EncryptionKey<Bls12_381>;
+pub type SaverDk = DecryptionKey<Bls12_381>;
+pub type SaverSnarkPk = ProvingKey<Bls12_381>;
+pub type SaverSnarkVk = VerifyingKey<Bls12_381>;
+pub type SaverCiphertext = Ciphertext<Bls12_381>;
 
 #[wasm_bindgen(js_name = saverGenerateEncryptionGenerators)]
 pub fn saver_generate_encryption_generators(
     label: Option<Vec<u8>>,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let gens = EncGens::new::<Blake2b512>(&label);
+    let gens = native::saver::generate_encryption_generators(label.as_deref())?;
     Ok(obj_to_uint8array!(&gens, false, "EncryptionGenerators"))
 }
 
@@ -42,8 +45,7 @@ pub fn saver_generate_chunked_commitment_generators(
     label: Option<Vec<u8>>,
 ) -> Result<js_sys::Uint8Array, JsValue> {
     set_panic_hook();
-    let label = label.unwrap_or_else(random_bytes);
-    let gens = ChunkedCommGens::new::<Blake2b512>(&label);
+    let gens = native::saver::generate_chunked_commitment_generators(label.as_deref())?;
     Ok(obj_to_uint8array!(
         &gens,
         false,
@@ -61,9 +63,8 @@ pub fn saver_decryptor_setup(
 ) -> Result<js_sys::Array, JsValue> {
     set_panic_hook();
     let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
-    let mut rng = get_seeded_rng();
-    let (snark_pk, sk, ek, dk) = setup_for_groth16(&mut rng, chunk_bit_size, &enc_gens)
-        .map_err(|e| JsValue::from(&format!("Decryptor setup returned error: {:?}", e)))?;
+    let (snark_pk, sk, ek, dk) = native::saver::decryptor_setup(chunk_bit_size, &enc_gens)
+        .map_err(|e| e.context("Decryptor setup returned error"))?;
     let setup = js_sys::Array::new();
     let snark_pk = if return_uncompressed_snark_pk {
         obj_to_uint8array_uncompressed!(&snark_pk, "SaverSnarkPk")
@@ -278,32 +279,562 @@ pub fn saver_verify_decryption_using_snark_pk(
     )
 }
 
-fn decrypt(
+/// Create the table for decrypting many ciphertexts quickly with `saver_decrypt_ciphertext_using_table` and
+/// `saver_batch_decrypt_ciphertexts`. The table only depends on the public decryption key, snark verification key
+/// and chunk size so it can be created once and stored. If `uncompressed_public_params` is true, it expects the
+/// decryption key and snark verification key in uncompressed form.
+#[wasm_bindgen(js_name = saverCreateDecryptionTableUsingSnarkVk)]
+pub fn saver_create_decryption_table_using_snark_vk(
+    decryption_key: js_sys::Uint8Array,
+    snark_vk: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let snark_vk = if uncompressed_public_params {
+        obj_from_uint8array_uncompressed!(SaverSnarkVk, snark_vk, "SaverSnarkVk")
+    } else {
+        obj_from_uint8array!(SaverSnarkVk, snark_vk, false, "SaverSnarkVk")
+    };
+    create_decryption_table(
+        decryption_key,
+        &snark_vk,
+        chunk_bit_size,
+        uncompressed_public_params,
+    )
+}
+
+/// Same as `saver_create_decryption_table_using_snark_vk` but takes the snark proving key instead
+/// of verification key
+#[wasm_bindgen(js_name = saverCreateDecryptionTableUsingSnarkPk)]
+pub fn saver_create_decryption_table_using_snark_pk(
+    decryption_key: js_sys::Uint8Array,
+    snark_pk: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let snark_pk = if uncompressed_public_params {
+        obj_from_uint8array_uncompressed!(SaverSnarkPk, snark_pk, "SaverSnarkPk")
+    } else {
+        obj_from_uint8array!(SaverSnarkPk, snark_pk, false, "SaverSnarkPk")
+    };
+    create_decryption_table(
+        decryption_key,
+        &snark_pk.pk.vk,
+        chunk_bit_size,
+        uncompressed_public_params,
+    )
+}
+
+/// Decrypt the ciphertext using the table created with `saver_create_decryption_table_using_snark_vk` for the
+/// decryption key. Returns the message and the commitment to the randomness like
+/// `saver_decrypt_ciphertext_using_snark_vk`.
+#[wasm_bindgen(js_name = saverDecryptCiphertextUsingTable)]
+pub fn saver_decrypt_ciphertext_using_table(
     ciphertext: js_sys::Uint8Array,
     secret_key: js_sys::Uint8Array,
     decryption_key: js_sys::Uint8Array,
-    snark_vk: &SaverSnarkVk,
-    chunk_bit_size: u8,
+    table: js_sys::Uint8Array,
     uncompressed_public_params: bool,
 ) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
     let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
     let sk = obj_from_uint8array!(SaverSk, secret_key, true, "SaverSk");
-    let dk = if uncompressed_public_params {
+    let dk = dk_from_uint8array(decryption_key, uncompressed_public_params)?;
+    let table = obj_from_uint8array!(DecryptionTable, table, false, "SaverDecryptionTable");
+    table.check_for_chunks(dk.V_2.len())?;
+    let (decrypted_message, nu) = native::saver::decrypt_using_table(&ct, &sk, dk, &table)
+        .map_err(|e| e.context("Decryption returned error"))?;
+    decryption_to_array(&decrypted_message, &nu)
+}
+
+/// Decrypt several ciphertexts, like the ones returned by `saver_get_ciphertexts_from_proof`, for the same
+/// decryption key using the table created with `saver_create_decryption_table_using_snark_vk`. Returns an array
+/// with an array of the message and the commitment to the randomness for each ciphertext.
+#[wasm_bindgen(js_name = saverBatchDecryptCiphertexts)]
+pub fn saver_batch_decrypt_ciphertexts(
+    ciphertexts: js_sys::Array,
+    secret_key: js_sys::Uint8Array,
+    decryption_key: js_sys::Uint8Array,
+    table: js_sys::Uint8Array,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let cts = objs_from_js_array::<SaverCiphertext>(&ciphertexts, false, "SaverCiphertext")?;
+    let sk = obj_from_uint8array!(SaverSk, secret_key, true, "SaverSk");
+    let dk = dk_from_uint8array(decryption_key, uncompressed_public_params)?;
+    let table = obj_from_uint8array!(DecryptionTable, table, false, "SaverDecryptionTable");
+    table.check_for_chunks(dk.V_2.len())?;
+    let decrypted = native::saver::batch_decrypt(&cts, &sk, dk, &table)
+        .map_err(|e| e.context("Batch decryption returned error"))?;
+    let result = js_sys::Array::new();
+    for (m, nu) in &decrypted {
+        let dec = decryption_to_array(m, nu)?;
+        result.push(&dec);
+    }
+    Ok(result)
+}
+
+/// Create new keys for the decryptor to rotate its current ones. The new keys are for the same snark SRS, encryption
+/// generators and chunk size so provers only need the new encryption key. Returns an array of the secret key,
+/// encryption key and decryption key. Existing ciphertexts are moved to the new keys with
+/// `saver_reencrypt_ciphertext`. If `uncompressed_snark_pk` is true, it expects the snark proving key in
+/// uncompressed form.
+#[wasm_bindgen(js_name = saverRotateKeys)]
+pub fn saver_rotate_keys(
+    snark_pk: js_sys::Uint8Array,
+    enc_gens: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    uncompressed_snark_pk: bool,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let snark_pk = if uncompressed_snark_pk {
+        obj_from_uint8array_uncompressed!(SaverSnarkPk, snark_pk, "SaverSnarkPk")
+    } else {
+        obj_from_uint8array!(SaverSnarkPk, snark_pk, false, "SaverSnarkPk")
+    };
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let (sk, ek, dk) = native::saver::rotate_keys(&snark_pk, &enc_gens, chunk_bit_size)
+        .map_err(|e| e.context("Key rotation returned error"))?;
+    let keys = js_sys::Array::new();
+    let sk = obj_to_uint8array!(&sk, true, "SaverSk");
+    let ek = obj_to_uint8array!(&ek, false, "SaverEk");
+    let dk = obj_to_uint8array!(&dk, false, "SaverDk");
+    keys.push(&sk);
+    keys.push(&ek);
+    keys.push(&dk);
+    Ok(keys)
+}
+
+/// Decrypt the ciphertext with the old keys and encrypt its message for the new encryption key from
+/// `saver_rotate_keys`. `table` is the decryption table of the old decryption key. Returns an array of the new
+/// ciphertext and a proof that it encrypts the same message as the old ciphertext, which is verified with
+/// `saver_verify_reencryption`. If `uncompressed_public_params` is true, it expects the decryption key, encryption
+/// key, snark verification key and encryption generators in uncompressed form.
+// Each argument is a separately serialized artifact from JS, wasm_bindgen can't take them as one struct
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = saverReencryptCiphertext)]
+pub fn saver_reencrypt_ciphertext(
+    ciphertext: js_sys::Uint8Array,
+    old_secret_key: js_sys::Uint8Array,
+    old_decryption_key: js_sys::Uint8Array,
+    table: js_sys::Uint8Array,
+    new_encryption_key: js_sys::Uint8Array,
+    snark_vk: js_sys::Uint8Array,
+    enc_gens: js_sys::Uint8Array,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
+    let sk = obj_from_uint8array!(SaverSk, old_secret_key, true, "SaverSk");
+    let dk = dk_from_uint8array(old_decryption_key, uncompressed_public_params)?;
+    let table = obj_from_uint8array!(DecryptionTable, table, false, "SaverDecryptionTable");
+    table.check_for_chunks(dk.V_2.len())?;
+    let (ek, snark_vk, enc_gens) = reencryption_params_from_uint8arrays(
+        new_encryption_key,
+        snark_vk,
+        enc_gens,
+        uncompressed_public_params,
+    )?;
+    let (new_ct, proof) =
+        native::saver::reencrypt(&ct, &sk, &dk, &table, &ek, &snark_vk, &enc_gens)
+            .map_err(|e| e.context("Re-encryption returned error"))?;
+    let result = js_sys::Array::new();
+    let new_ct = obj_to_uint8array!(&new_ct, false, "SaverCiphertext");
+    let proof = obj_to_uint8array!(&proof, false, "SaverReencryptionProof");
+    result.push(&new_ct);
+    result.push(&proof);
+    Ok(result)
+}
+
+/// Verify that the new ciphertext from `saver_reencrypt_ciphertext` encrypts the same message as the old
+/// ciphertext. If `uncompressed_public_params` is true, it expects the decryption key, encryption key, snark
+/// verification key and encryption generators in uncompressed form.
+// Takes the same public params as `saver_reencrypt_ciphertext` plus both ciphertexts and the proof
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = saverVerifyReencryption)]
+pub fn saver_verify_reencryption(
+    old_ciphertext: js_sys::Uint8Array,
+    new_ciphertext: js_sys::Uint8Array,
+    proof: js_sys::Uint8Array,
+    old_decryption_key: js_sys::Uint8Array,
+    new_encryption_key: js_sys::Uint8Array,
+    snark_vk: js_sys::Uint8Array,
+    enc_gens: js_sys::Uint8Array,
+    uncompressed_public_params: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let old_ct = obj_from_uint8array!(SaverCiphertext, old_ciphertext, false, "SaverCiphertext");
+    let new_ct = obj_from_uint8array!(SaverCiphertext, new_ciphertext, false, "SaverCiphertext");
+    let proof = obj_from_uint8array!(ReencryptionProof, proof, false, "SaverReencryptionProof");
+    let dk = dk_from_uint8array(old_decryption_key, uncompressed_public_params)?;
+    let (ek, snark_vk, enc_gens) = reencryption_params_from_uint8arrays(
+        new_encryption_key,
+        snark_vk,
+        enc_gens,
+        uncompressed_public_params,
+    )?;
+    let result = native::saver::verify_reencryption(
+        &old_ct, &new_ct, &proof, &dk, &ek, &snark_vk, &enc_gens,
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Encrypt the message for the encryption key outside of a composite proof. Returns an array of the ciphertext and
+/// the randomness used, which is needed by `saver_prove_plaintext_equality`. If `uncompressed_public_params` is true,
+/// it expects the encryption key and snark verification key in uncompressed form.
+#[wasm_bindgen(js_name = saverEncrypt)]
+pub fn saver_encrypt(
+    message: js_sys::Uint8Array,
+    encryption_key: js_sys::Uint8Array,
+    snark_vk: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let message = fr_from_uint8_array(message, true)?;
+    let (ek, snark_vk) = if uncompressed_public_params {
+        (
+            obj_from_uint8array_uncompressed!(SaverEk, encryption_key, "SaverEk"),
+            obj_from_uint8array_uncompressed!(SaverSnarkVk, snark_vk, "SaverSnarkVk"),
+        )
+    } else {
+        (
+            obj_from_uint8array!(SaverEk, encryption_key, false, "SaverEk"),
+            obj_from_uint8array!(SaverSnarkVk, snark_vk, false, "SaverSnarkVk"),
+        )
+    };
+    let (ct, randomness) = native::saver::encrypt(&message, &ek, &snark_vk, chunk_bit_size)
+        .map_err(|e| e.context("Encryption returned error"))?;
+    ciphertext_and_randomness_to_array(&ct, &randomness)
+}
+
+/// Re-randomize the ciphertext for the same encryption key so that it can't be linked to the original. Returns an
+/// array of the new ciphertext and its randomness if the randomness of the original ciphertext is given, else the
+/// randomness added to the original one. If `uncompressed_public_params` is true, it expects the encryption key in
+/// uncompressed form.
+#[wasm_bindgen(js_name = saverRerandomizeCiphertext)]
+pub fn saver_rerandomize_ciphertext(
+    ciphertext: js_sys::Uint8Array,
+    encryption_key: js_sys::Uint8Array,
+    randomness: Option<js_sys::Uint8Array>,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
+    let ek = if uncompressed_public_params {
+        obj_from_uint8array_uncompressed!(SaverEk, encryption_key, "SaverEk")
+    } else {
+        obj_from_uint8array!(SaverEk, encryption_key, false, "SaverEk")
+    };
+    let (new_ct, mut randomness_added) = native::saver::rerandomize(&ct, &ek)
+        .map_err(|e| e.context("Re-randomization returned error"))?;
+    if let Some(randomness) = randomness {
+        randomness_added += fr_from_uint8_array(randomness, true)?;
+    }
+    ciphertext_and_randomness_to_array(&new_ct, &randomness_added)
+}
+
+/// Prove that the ciphertexts, possibly for different encryption keys, encrypt the same message. `randomness` has
+/// the randomness of each ciphertext as returned by `saver_encrypt` or `saver_rerandomize_ciphertext`, and
+/// `encryption_keys` and `snark_vks` have the keys each ciphertext is for. If `uncompressed_public_params` is true,
+/// it expects the encryption keys and snark verification keys in uncompressed form.
+#[wasm_bindgen(js_name = saverProvePlaintextEquality)]
+pub fn saver_prove_plaintext_equality(
+    message: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    ciphertexts: js_sys::Array,
+    randomness: js_sys::Array,
+    encryption_keys: js_sys::Array,
+    snark_vks: js_sys::Array,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    set_panic_hook();
+    let message = fr_from_uint8_array(message, true)?;
+    let cts = objs_from_js_array::<SaverCiphertext>(&ciphertexts, false, "SaverCiphertext")?;
+    let randomness = js_array_to_fr_vec(&randomness)?;
+    let eks =
+        objs_from_js_array::<SaverEk>(&encryption_keys, uncompressed_public_params, "SaverEk")?;
+    let vks =
+        objs_from_js_array::<SaverSnarkVk>(&snark_vks, uncompressed_public_params, "SaverSnarkVk")?;
+    let proof = native::saver::prove_plaintext_equality(
+        &message,
+        chunk_bit_size,
+        &cts,
+        &randomness,
+        &eks,
+        &vks,
+    )
+    .map_err(|e| e.context("Proving plaintext equality returned error"))?;
+    Ok(obj_to_uint8array!(
+        &proof,
+        false,
+        "SaverPlaintextEqualityProof"
+    ))
+}
+
+/// Verify that the ciphertexts encrypt the same message. If `uncompressed_public_params` is true, it expects the
+/// encryption keys and snark verification keys in uncompressed form.
+#[wasm_bindgen(js_name = saverVerifyPlaintextEquality)]
+pub fn saver_verify_plaintext_equality(
+    ciphertexts: js_sys::Array,
+    proof: js_sys::Uint8Array,
+    encryption_keys: js_sys::Array,
+    snark_vks: js_sys::Array,
+    uncompressed_public_params: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let cts = objs_from_js_array::<SaverCiphertext>(&ciphertexts, false, "SaverCiphertext")?;
+    let proof = obj_from_uint8array!(
+        PlaintextEqualityProof,
+        proof,
+        false,
+        "SaverPlaintextEqualityProof"
+    );
+    let eks =
+        objs_from_js_array::<SaverEk>(&encryption_keys, uncompressed_public_params, "SaverEk")?;
+    let vks =
+        objs_from_js_array::<SaverSnarkVk>(&snark_vks, uncompressed_public_params, "SaverSnarkVk")?;
+    let result = native::saver::verify_plaintext_equality(&cts, &proof, &eks, &vks);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Encrypt the message for one or more recipients with a single proof. `snark_pk` is the snark proving key of the
+/// first recipient and `other_snark_vks` has the snark verification keys of the rest. All recipients should use the
+/// same chunk size. Returns an array of the array of ciphertexts, a commitment to the message, its blinding and the
+/// proof. The composite proof has a statement from `generateSaverMultiRecipientStatement` for the commitment whose
+/// witness from `generateSaverMultiRecipientWitness` has the message at index 0. `nonce` is the nonce of that
+/// composite proof, the proof only verifies with it. The verifier verifies both proofs with
+/// `verifyCompositeProofG1WithSaverMultiRecipient`. If `uncompressed_public_params` is true, it expects the
+/// encryption keys, snark keys and commitment generators in uncompressed form.
+// The keys of the first recipient and of the rest come separately, as do the commitment params and nonce
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = saverEncryptForRecipients)]
+pub fn saver_encrypt_for_recipients(
+    message: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    encryption_keys: js_sys::Array,
+    snark_pk: js_sys::Uint8Array,
+    other_snark_vks: js_sys::Array,
+    chunked_comm_gens: js_sys::Uint8Array,
+    nonce: Option<Vec<u8>>,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Array, JsValue> {
+    set_panic_hook();
+    let message = fr_from_uint8_array(message, true)?;
+    let eks =
+        objs_from_js_array::<SaverEk>(&encryption_keys, uncompressed_public_params, "SaverEk")?;
+    let vks = objs_from_js_array::<SaverSnarkVk>(
+        &other_snark_vks,
+        uncompressed_public_params,
+        "SaverSnarkVk",
+    )?;
+    let (snark_pk, comm_gens) = if uncompressed_public_params {
+        (
+            obj_from_uint8array_uncompressed!(SaverSnarkPk, snark_pk, "SaverSnarkPk"),
+            obj_from_uint8array_uncompressed!(
+                ChunkedCommGens,
+                chunked_comm_gens,
+                "ChunkedCommitmentGenerators"
+            ),
+        )
+    } else {
+        (
+            obj_from_uint8array!(SaverSnarkPk, snark_pk, false, "SaverSnarkPk"),
+            obj_from_uint8array!(
+                ChunkedCommGens,
+                chunked_comm_gens,
+                false,
+                "ChunkedCommitmentGenerators"
+            ),
+        )
+    };
+    let context = nonce.unwrap_or_default();
+    let (cts, commitment, blinding, proof) = native::saver::encrypt_for_recipients(
+        &message,
+        chunk_bit_size,
+        &eks,
+        &snark_pk,
+        &vks,
+        &comm_gens,
+        &context,
+    )
+    .map_err(|e| e.context("Multi-recipient encryption returned error"))?;
+    let ciphertexts = js_sys::Array::new();
+    for ct in &cts {
+        let ct = obj_to_uint8array!(ct, false, "SaverCiphertext");
+        ciphertexts.push(&ct);
+    }
+    let commitment = g1_affine_to_uint8_array(&commitment)?;
+    let blinding = fr_to_uint8_array(&blinding)?;
+    let proof = obj_to_uint8array!(&proof, false, "SaverMultiRecipientProof");
+    let result = js_sys::Array::new();
+    result.push(&ciphertexts);
+    result.push(&commitment);
+    result.push(&blinding);
+    result.push(&proof);
+    Ok(result)
+}
+
+/// Verify that the ciphertexts from `saver_encrypt_for_recipients` encrypt the message in the commitment.
+/// `snark_vks` has the snark verification key of each recipient, including the first, and `nonce` is the nonce of
+/// the composite proof. `verifyCompositeProofG1WithSaverMultiRecipient` should be used instead to verify the
+/// composite proof as well and take the commitment from its statement. If `uncompressed_public_params` is true, it
+/// expects the encryption keys, snark verification keys and commitment generators in uncompressed form.
+// Mirrors the arguments of `saver_encrypt_for_recipients` with the ciphertexts, commitment and proof it returns
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = saverVerifyEncryptionForRecipients)]
+pub fn saver_verify_encryption_for_recipients(
+    ciphertexts: js_sys::Array,
+    commitment: js_sys::Uint8Array,
+    proof: js_sys::Uint8Array,
+    encryption_keys: js_sys::Array,
+    snark_vks: js_sys::Array,
+    chunked_comm_gens: js_sys::Uint8Array,
+    chunk_bit_size: u8,
+    nonce: Option<Vec<u8>>,
+    uncompressed_public_params: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let cts = objs_from_js_array::<SaverCiphertext>(&ciphertexts, false, "SaverCiphertext")?;
+    let commitment = g1_affine_from_uint8_array(commitment)?;
+    let proof = obj_from_uint8array!(
+        MultiRecipientProof,
+        proof,
+        false,
+        "SaverMultiRecipientProof"
+    );
+    let eks =
+        objs_from_js_array::<SaverEk>(&encryption_keys, uncompressed_public_params, "SaverEk")?;
+    let vks =
+        objs_from_js_array::<SaverSnarkVk>(&snark_vks, uncompressed_public_params, "SaverSnarkVk")?;
+    let comm_gens = if uncompressed_public_params {
+        obj_from_uint8array_uncompressed!(
+            ChunkedCommGens,
+            chunked_comm_gens,
+            "ChunkedCommitmentGenerators"
+        )
+    } else {
+        obj_from_uint8array!(
+            ChunkedCommGens,
+            chunked_comm_gens,
+            false,
+            "ChunkedCommitmentGenerators"
+        )
+    };
+    let context = nonce.unwrap_or_default();
+    let result = native::saver::verify_encryption_for_recipients(
+        &cts,
+        &commitment,
+        &proof,
+        &eks,
+        &vks,
+        &comm_gens,
+        chunk_bit_size,
+        &context,
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+pub(crate) fn objs_from_js_array<T: CanonicalDeserialize>(
+    array: &js_sys::Array,
+    uncompressed: bool,
+    name: &str,
+) -> Result<Vec<T>, JsValue> {
+    let mut objs = Vec::with_capacity(array.length() as usize);
+    for obj in array.values() {
+        let obj = js_sys::Uint8Array::new(&obj?);
+        objs.push(if uncompressed {
+            obj_from_uint8array_uncompressed!(T, obj, name)
+        } else {
+            obj_from_uint8array!(T, obj, false, name)
+        });
+    }
+    Ok(objs)
+}
+
+fn ciphertext_and_randomness_to_array(
+    ciphertext: &SaverCiphertext,
+    randomness: &Fr,
+) -> Result<js_sys::Array, JsValue> {
+    let result = js_sys::Array::new();
+    let ct = obj_to_uint8array!(ciphertext, false, "SaverCiphertext");
+    let randomness = fr_to_uint8_array(randomness)?;
+    result.push(&ct);
+    result.push(&randomness);
+    Ok(result)
+}
+
+fn reencryption_params_from_uint8arrays(
+    encryption_key: js_sys::Uint8Array,
+    snark_vk: js_sys::Uint8Array,
+    enc_gens: js_sys::Uint8Array,
+    uncompressed: bool,
+) -> Result<(SaverEk, SaverSnarkVk, EncGens), JsValue> {
+    Ok(if uncompressed {
+        (
+            obj_from_uint8array_uncompressed!(SaverEk, encryption_key, "SaverEk"),
+            obj_from_uint8array_uncompressed!(SaverSnarkVk, snark_vk, "SaverSnarkVk"),
+            obj_from_uint8array_uncompressed!(EncGens, enc_gens, "EncryptionGenerators"),
+        )
+    } else {
+        (
+            obj_from_uint8array!(SaverEk, encryption_key, false, "SaverEk"),
+            obj_from_uint8array!(SaverSnarkVk, snark_vk, false, "SaverSnarkVk"),
+            obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators"),
+        )
+    })
+}
+
+fn create_decryption_table(
+    decryption_key: js_sys::Uint8Array,
+    snark_vk: &SaverSnarkVk,
+    chunk_bit_size: u8,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    let dk = dk_from_uint8array(decryption_key, uncompressed_public_params)?;
+    let table = native::saver::create_decryption_table(&dk, snark_vk, chunk_bit_size)
+        .map_err(|e| e.context("Creating decryption table returned error"))?;
+    Ok(obj_to_uint8array!(&table, false, "SaverDecryptionTable"))
+}
+
+fn dk_from_uint8array(
+    decryption_key: js_sys::Uint8Array,
+    uncompressed: bool,
+) -> Result<SaverDk, JsValue> {
+    Ok(if uncompressed {
         obj_from_uint8array_uncompressed!(SaverDk, decryption_key, "SaverDk")
     } else {
         obj_from_uint8array!(SaverDk, decryption_key, false, "SaverDk")
-    };
-    let (decrypted_message, nu) = ct
-        .decrypt_given_groth16_vk(&sk, dk, snark_vk, chunk_bit_size)
-        .map_err(|e| JsValue::from(&format!("Decryption returned error: {:?}", e)))?;
+    })
+}
+
+fn decryption_to_array(message: &Fr, nu: &G1Affine) -> Result<js_sys::Array, JsValue> {
     let dec = js_sys::Array::new();
-    let m = fr_to_uint8_array(&decrypted_message)?;
-    let nu = g1_affine_to_uint8_array(&nu)?;
+    let m = fr_to_uint8_array(message)?;
+    let nu = g1_affine_to_uint8_array(nu)?;
     dec.push(&m);
     dec.push(&nu);
     Ok(dec)
 }
 
+fn decrypt(
+    ciphertext: js_sys::Uint8Array,
+    secret_key: js_sys::Uint8Array,
+    decryption_key: js_sys::Uint8Array,
+    snark_vk: &SaverSnarkVk,
+    chunk_bit_size: u8,
+    uncompressed_public_params: bool,
+) -> Result<js_sys::Array, JsValue> {
+    let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
+    let sk = obj_from_uint8array!(SaverSk, secret_key, true, "SaverSk");
+    let dk = dk_from_uint8array(decryption_key, uncompressed_public_params)?;
+    let (decrypted_message, nu) = native::saver::decrypt(&ct, &sk, dk, snark_vk, chunk_bit_size)
+        .map_err(|e| e.context("Decryption returned error"))?;
+    decryption_to_array(&decrypted_message, &nu)
+}
+
 fn verify_decryption(
     ciphertext: js_sys::Uint8Array,
     decrypted_message: js_sys::Uint8Array,
@@ -328,23 +859,15 @@ fn verify_decryption(
     let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
     let decrypted_message = fr_from_uint8_array(decrypted_message, false).unwrap();
     let nu = g1_affine_from_uint8_array(nu).unwrap();
-    match ct.verify_decryption_given_groth16_vk(
+    let result = native::saver::verify_decryption(
+        &ct,
         &decrypted_message,
         &nu,
-        chunk_bit_size,
         dk,
         snark_vk,
         enc_gens,
-    ) {
-        Ok(_) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: true,
-            error: None,
-        })
-        .unwrap()),
-        Err(e) => Ok(serde_wasm_bindgen::to_value(&VerifyResponse {
-            verified: false,
-            error: Some(format!("Verifying decryption returned error {:?}", e)),
-        })
-        .unwrap()),
-    }
+        chunk_bit_size,
+    );
+    let result = result.map_err(|e| e.context("Verifying decryption returned error"));
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
 }
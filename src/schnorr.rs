@@ -0,0 +1,636 @@
+use crate::{
+    common::VerifyResponse,
+    native::schnorr::{
+        DLEqualityProof, DLEqualityProtocol, PoKProof, PoKProtocol, ProofOfPossession,
+    },
+    utils::{
+        encode_messages_as_js_map_to_fr_btreemap, fr_from_uint8_array, fr_to_uint8_array,
+        g1_affine_from_uint8_array, g2_affine_from_uint8_array, js_array_to_fr_vec,
+        js_array_to_g1_affine_vec, js_array_to_g2_affine_vec, set_panic_hook,
+    },
+    Fr, G1Affine, G2Affine,
+};
+use ark_std::collections::BTreeMap;
+use js_sys::{Array, Map, Uint8Array};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+// Standalone Schnorr proofs of knowledge of a discrete log, of the opening of a Pedersen commitment and of equality
+// of discrete logs (Chaum-Pedersen). Each is a 3 step protocol where the prover initializes the protocol, both
+// parties compute the challenge by hashing the challenge contribution along with any context like a nonce or a
+// DID, and then the prover generates the proof which the verifier verifies. The protocol contains the witnesses
+// so it must be kept secret.
+
+pub type PoKProtocolG1 = PoKProtocol<G1Affine>;
+pub type PoKProtocolG2 = PoKProtocol<G2Affine>;
+pub type PoKProofG1 = PoKProof<G1Affine>;
+pub type PoKProofG2 = PoKProof<G2Affine>;
+pub type DLEqualityProtocolG1 = DLEqualityProtocol<G1Affine>;
+pub type DLEqualityProtocolG2 = DLEqualityProtocol<G2Affine>;
+pub type DLEqualityProofG1 = DLEqualityProof<G1Affine>;
+pub type DLEqualityProofG2 = DLEqualityProof<G2Affine>;
+pub type ProofOfPossessionG1 = ProofOfPossession<G1Affine>;
+pub type ProofOfPossessionG2 = ProofOfPossession<G2Affine>;
+
+macro_rules! init_pok {
+    ($witnesses: expr, $bases: expr, $blindings: expr, $protocol_type: ident) => {{
+        let protocol = $protocol_type::init($witnesses, &$bases, $blindings)?;
+        Ok(obj_to_uint8array!(&protocol, true, "SchnorrPoKProtocol"))
+    }};
+}
+
+macro_rules! pok_challenge_contribution {
+    ($obj: ident, $obj_type: ident, $obj_is_secret: expr, $bases: expr, $y: expr) => {{
+        let obj = obj_from_uint8array!($obj_type, $obj, $obj_is_secret);
+        let mut bytes = vec![];
+        obj.challenge_contribution(&$bases, &$y, &mut bytes)
+            .map_err(|e| e.context("Evaluating challenge_contribution returned error"))?;
+        Ok(Uint8Array::from(bytes.as_slice()))
+    }};
+}
+
+macro_rules! gen_pok_proof {
+    ($protocol: ident, $challenge: ident, $protocol_type: ident) => {{
+        set_panic_hook();
+        let protocol = obj_from_uint8array!($protocol_type, $protocol, true);
+        let challenge = fr_from_uint8_array($challenge, false)?;
+        let proof = protocol.gen_proof(&challenge)?;
+        Ok(obj_to_uint8array!(&proof, false, "SchnorrPoKProof"))
+    }};
+}
+
+macro_rules! verify_pok_proof {
+    ($proof: ident, $bases: expr, $y: expr, $challenge: ident, $proof_type: ident) => {{
+        let proof = obj_from_uint8array!($proof_type, $proof, false);
+        let challenge = fr_from_uint8_array($challenge, false)?;
+        let result = proof.verify(&$bases, &$y, &challenge);
+        Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+    }};
+}
+
+macro_rules! init_dl_equality {
+    ($witness: ident, $base1: ident, $base2: ident, $blinding: ident, $protocol_type: ident, $point_from_bytes: ident) => {{
+        set_panic_hook();
+        let witness = fr_from_uint8_array($witness, true)?;
+        let base1 = $point_from_bytes($base1)?;
+        let base2 = $point_from_bytes($base2)?;
+        let blinding = match $blinding {
+            Some(b) => Some(fr_from_uint8_array(b, true)?),
+            None => None,
+        };
+        let protocol = $protocol_type::init(witness, &base1, &base2, blinding)?;
+        Ok(obj_to_uint8array!(&protocol, true, "DLEqualityProtocol"))
+    }};
+}
+
+macro_rules! dl_equality_challenge_contribution {
+    ($obj: ident, $obj_type: ident, $obj_is_secret: expr, $base1: ident, $y1: ident, $base2: ident, $y2: ident, $point_from_bytes: ident) => {{
+        set_panic_hook();
+        let obj = obj_from_uint8array!($obj_type, $obj, $obj_is_secret);
+        let mut bytes = vec![];
+        obj.challenge_contribution(
+            &$point_from_bytes($base1)?,
+            &$point_from_bytes($y1)?,
+            &$point_from_bytes($base2)?,
+            &$point_from_bytes($y2)?,
+            &mut bytes,
+        )
+        .map_err(|e| e.context("Evaluating challenge_contribution returned error"))?;
+        Ok(Uint8Array::from(bytes.as_slice()))
+    }};
+}
+
+macro_rules! gen_dl_equality_proof {
+    ($protocol: ident, $challenge: ident, $protocol_type: ident) => {{
+        set_panic_hook();
+        let protocol = obj_from_uint8array!($protocol_type, $protocol, true);
+        let challenge = fr_from_uint8_array($challenge, false)?;
+        Ok(obj_to_uint8array!(
+            &protocol.gen_proof(&challenge),
+            false,
+            "DLEqualityProof"
+        ))
+    }};
+}
+
+macro_rules! verify_dl_equality_proof {
+    ($proof: ident, $base1: ident, $y1: ident, $base2: ident, $y2: ident, $challenge: ident, $proof_type: ident, $point_from_bytes: ident) => {{
+        set_panic_hook();
+        let proof = obj_from_uint8array!($proof_type, $proof, false);
+        let challenge = fr_from_uint8_array($challenge, false)?;
+        let result = proof.verify(
+            &$point_from_bytes($base1)?,
+            &$point_from_bytes($y1)?,
+            &$point_from_bytes($base2)?,
+            &$point_from_bytes($y2)?,
+            &challenge,
+        );
+        Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+    }};
+}
+
+/// Initialize the proof of knowledge of `witness` such that `base * witness = y`. `blinding` is needed only when
+/// proving that the witness is equal to a witness in another proof.
+#[wasm_bindgen(js_name = pokDiscreteLogG1Initialize)]
+pub fn pok_discrete_log_g1_initialize(
+    witness: Uint8Array,
+    base: Uint8Array,
+    blinding: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let witness = fr_from_uint8_array(witness, true)?;
+    let blindings = single_blinding(blinding)?;
+    init_pok!(
+        vec![witness],
+        [g1_affine_from_uint8_array(base)?],
+        blindings,
+        PoKProtocolG1
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG2Initialize)]
+pub fn pok_discrete_log_g2_initialize(
+    witness: Uint8Array,
+    base: Uint8Array,
+    blinding: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let witness = fr_from_uint8_array(witness, true)?;
+    let blindings = single_blinding(blinding)?;
+    init_pok!(
+        vec![witness],
+        [g2_affine_from_uint8_array(base)?],
+        blindings,
+        PoKProtocolG2
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG1ChallengeContributionFromProtocol)]
+pub fn pok_discrete_log_g1_challenge_contribution_from_protocol(
+    protocol: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        protocol,
+        PoKProtocolG1,
+        true,
+        [g1_affine_from_uint8_array(base)?],
+        g1_affine_from_uint8_array(y)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG2ChallengeContributionFromProtocol)]
+pub fn pok_discrete_log_g2_challenge_contribution_from_protocol(
+    protocol: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        protocol,
+        PoKProtocolG2,
+        true,
+        [g2_affine_from_uint8_array(base)?],
+        g2_affine_from_uint8_array(y)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG1GenProof)]
+pub fn pok_discrete_log_g1_gen_proof(
+    protocol: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    gen_pok_proof!(protocol, challenge, PoKProtocolG1)
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG2GenProof)]
+pub fn pok_discrete_log_g2_gen_proof(
+    protocol: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    gen_pok_proof!(protocol, challenge, PoKProtocolG2)
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG1ChallengeContributionFromProof)]
+pub fn pok_discrete_log_g1_challenge_contribution_from_proof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        proof,
+        PoKProofG1,
+        false,
+        [g1_affine_from_uint8_array(base)?],
+        g1_affine_from_uint8_array(y)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG2ChallengeContributionFromProof)]
+pub fn pok_discrete_log_g2_challenge_contribution_from_proof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        proof,
+        PoKProofG2,
+        false,
+        [g2_affine_from_uint8_array(base)?],
+        g2_affine_from_uint8_array(y)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG1VerifyProof)]
+pub fn pok_discrete_log_g1_verify_proof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    verify_pok_proof!(
+        proof,
+        [g1_affine_from_uint8_array(base)?],
+        g1_affine_from_uint8_array(y)?,
+        challenge,
+        PoKProofG1
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogG2VerifyProof)]
+pub fn pok_discrete_log_g2_verify_proof(
+    proof: Uint8Array,
+    base: Uint8Array,
+    y: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    verify_pok_proof!(
+        proof,
+        [g2_affine_from_uint8_array(base)?],
+        g2_affine_from_uint8_array(y)?,
+        challenge,
+        PoKProofG2
+    )
+}
+
+/// Initialize the proof of knowledge of the opening `witnesses` of the Pedersen commitment
+/// `bases[0] * witnesses[0] + bases[1] * witnesses[1] + ...`. `blindings` maps the index of a witness to its
+/// blinding and is needed only when proving that the witness is equal to a witness in another proof.
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG1Initialize)]
+pub fn pok_pedersen_commitment_opening_g1_initialize(
+    witnesses: Array,
+    bases: Array,
+    blindings: Map,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let witnesses = js_array_to_fr_vec(&witnesses)?;
+    let blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
+    init_pok!(
+        witnesses,
+        js_array_to_g1_affine_vec(&bases)?,
+        blindings,
+        PoKProtocolG1
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG2Initialize)]
+pub fn pok_pedersen_commitment_opening_g2_initialize(
+    witnesses: Array,
+    bases: Array,
+    blindings: Map,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let witnesses = js_array_to_fr_vec(&witnesses)?;
+    let blindings = encode_messages_as_js_map_to_fr_btreemap(&blindings, false)?;
+    init_pok!(
+        witnesses,
+        js_array_to_g2_affine_vec(&bases)?,
+        blindings,
+        PoKProtocolG2
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol)]
+pub fn pok_pedersen_commitment_opening_g1_challenge_contribution_from_protocol(
+    protocol: Uint8Array,
+    bases: Array,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        protocol,
+        PoKProtocolG1,
+        true,
+        js_array_to_g1_affine_vec(&bases)?,
+        g1_affine_from_uint8_array(commitment)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG2ChallengeContributionFromProtocol)]
+pub fn pok_pedersen_commitment_opening_g2_challenge_contribution_from_protocol(
+    protocol: Uint8Array,
+    bases: Array,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        protocol,
+        PoKProtocolG2,
+        true,
+        js_array_to_g2_affine_vec(&bases)?,
+        g2_affine_from_uint8_array(commitment)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG1GenProof)]
+pub fn pok_pedersen_commitment_opening_g1_gen_proof(
+    protocol: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    gen_pok_proof!(protocol, challenge, PoKProtocolG1)
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG2GenProof)]
+pub fn pok_pedersen_commitment_opening_g2_gen_proof(
+    protocol: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    gen_pok_proof!(protocol, challenge, PoKProtocolG2)
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG1ChallengeContributionFromProof)]
+pub fn pok_pedersen_commitment_opening_g1_challenge_contribution_from_proof(
+    proof: Uint8Array,
+    bases: Array,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        proof,
+        PoKProofG1,
+        false,
+        js_array_to_g1_affine_vec(&bases)?,
+        g1_affine_from_uint8_array(commitment)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG2ChallengeContributionFromProof)]
+pub fn pok_pedersen_commitment_opening_g2_challenge_contribution_from_proof(
+    proof: Uint8Array,
+    bases: Array,
+    commitment: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    pok_challenge_contribution!(
+        proof,
+        PoKProofG2,
+        false,
+        js_array_to_g2_affine_vec(&bases)?,
+        g2_affine_from_uint8_array(commitment)?
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG1VerifyProof)]
+pub fn pok_pedersen_commitment_opening_g1_verify_proof(
+    proof: Uint8Array,
+    bases: Array,
+    commitment: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    verify_pok_proof!(
+        proof,
+        js_array_to_g1_affine_vec(&bases)?,
+        g1_affine_from_uint8_array(commitment)?,
+        challenge,
+        PoKProofG1
+    )
+}
+
+#[wasm_bindgen(js_name = pokPedersenCommitmentOpeningG2VerifyProof)]
+pub fn pok_pedersen_commitment_opening_g2_verify_proof(
+    proof: Uint8Array,
+    bases: Array,
+    commitment: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    verify_pok_proof!(
+        proof,
+        js_array_to_g2_affine_vec(&bases)?,
+        g2_affine_from_uint8_array(commitment)?,
+        challenge,
+        PoKProofG2
+    )
+}
+
+/// Get the response for the witness at index `index` from a proof of knowledge of a discrete log or of a Pedersen
+/// commitment opening. If the same blinding was used for a witness in 2 proofs with the same challenge then equal
+/// responses show that the witnesses are equal.
+#[wasm_bindgen(js_name = pokG1GetResponseForWitness)]
+pub fn pok_g1_get_response_for_witness(
+    proof: Uint8Array,
+    index: usize,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(PoKProofG1, proof, false);
+    fr_to_uint8_array(proof.get_response(index)?)
+}
+
+#[wasm_bindgen(js_name = pokG2GetResponseForWitness)]
+pub fn pok_g2_get_response_for_witness(
+    proof: Uint8Array,
+    index: usize,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let proof = obj_from_uint8array!(PoKProofG2, proof, false);
+    fr_to_uint8_array(proof.get_response(index)?)
+}
+
+/// Initialize the proof that `witness` is the discrete log of both `y1` to `base1` and `y2` to `base2`, i.e.
+/// `base1 * witness = y1` and `base2 * witness = y2`
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG1Initialize)]
+pub fn pok_discrete_log_equality_g1_initialize(
+    witness: Uint8Array,
+    base1: Uint8Array,
+    base2: Uint8Array,
+    blinding: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    init_dl_equality!(
+        witness,
+        base1,
+        base2,
+        blinding,
+        DLEqualityProtocolG1,
+        g1_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG2Initialize)]
+pub fn pok_discrete_log_equality_g2_initialize(
+    witness: Uint8Array,
+    base1: Uint8Array,
+    base2: Uint8Array,
+    blinding: Option<Uint8Array>,
+) -> Result<Uint8Array, JsValue> {
+    init_dl_equality!(
+        witness,
+        base1,
+        base2,
+        blinding,
+        DLEqualityProtocolG2,
+        g2_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG1ChallengeContributionFromProtocol)]
+pub fn pok_discrete_log_equality_g1_challenge_contribution_from_protocol(
+    protocol: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    dl_equality_challenge_contribution!(
+        protocol,
+        DLEqualityProtocolG1,
+        true,
+        base1,
+        y1,
+        base2,
+        y2,
+        g1_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG2ChallengeContributionFromProtocol)]
+pub fn pok_discrete_log_equality_g2_challenge_contribution_from_protocol(
+    protocol: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    dl_equality_challenge_contribution!(
+        protocol,
+        DLEqualityProtocolG2,
+        true,
+        base1,
+        y1,
+        base2,
+        y2,
+        g2_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG1GenProof)]
+pub fn pok_discrete_log_equality_g1_gen_proof(
+    protocol: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    gen_dl_equality_proof!(protocol, challenge, DLEqualityProtocolG1)
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG2GenProof)]
+pub fn pok_discrete_log_equality_g2_gen_proof(
+    protocol: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    gen_dl_equality_proof!(protocol, challenge, DLEqualityProtocolG2)
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG1ChallengeContributionFromProof)]
+pub fn pok_discrete_log_equality_g1_challenge_contribution_from_proof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    dl_equality_challenge_contribution!(
+        proof,
+        DLEqualityProofG1,
+        false,
+        base1,
+        y1,
+        base2,
+        y2,
+        g1_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG2ChallengeContributionFromProof)]
+pub fn pok_discrete_log_equality_g2_challenge_contribution_from_proof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    dl_equality_challenge_contribution!(
+        proof,
+        DLEqualityProofG2,
+        false,
+        base1,
+        y1,
+        base2,
+        y2,
+        g2_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG1VerifyProof)]
+pub fn pok_discrete_log_equality_g1_verify_proof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    verify_dl_equality_proof!(
+        proof,
+        base1,
+        y1,
+        base2,
+        y2,
+        challenge,
+        DLEqualityProofG1,
+        g1_affine_from_uint8_array
+    )
+}
+
+#[wasm_bindgen(js_name = pokDiscreteLogEqualityG2VerifyProof)]
+pub fn pok_discrete_log_equality_g2_verify_proof(
+    proof: Uint8Array,
+    base1: Uint8Array,
+    y1: Uint8Array,
+    base2: Uint8Array,
+    y2: Uint8Array,
+    challenge: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    verify_dl_equality_proof!(
+        proof,
+        base1,
+        y1,
+        base2,
+        y2,
+        challenge,
+        DLEqualityProofG2,
+        g2_affine_from_uint8_array
+    )
+}
+
+fn single_blinding(blinding: Option<Uint8Array>) -> Result<BTreeMap<usize, Fr>, JsValue> {
+    let mut blindings = BTreeMap::new();
+    if let Some(b) = blinding {
+        blindings.insert(0, fr_from_uint8_array(b, true)?);
+    }
+    Ok(blindings)
+}
@@ -0,0 +1,348 @@
+use crate::{
+    common::VerifyResponse,
+    native::{
+        self,
+        threshold_saver::{
+            EncryptionKeyContribution, PartialDecryption, SaverSecretKeyShare, ThresholdDecryptors,
+        },
+    },
+    saver::{EncGens, SaverCiphertext, SaverDk, SaverSnarkPk, SaverSnarkVk},
+    utils::{
+        fr_from_uint8_array, fr_to_uint8_array, g1_affine_to_uint8_array,
+        g2_affine_from_uint8_array, g2_affine_to_uint8_array, set_panic_hook,
+    },
+};
+use js_sys::{Array, Uint8Array};
+use secret_sharing_and_dkg::common::{ParticipantId, ShareId};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+// Threshold decryption for SAVER where the decryptor's secret key is shared among several decryptors. Each
+// decryptor creates a partial decryption with its share and any `threshold` partial decryptions are combined into
+// the message and `nu`, which are verified with `saverVerifyDecryptionUsingSnarkVk` like for a single decryptor.
+
+/// Same as `saverDecryptorSetup` but the secret key is shared among `total` decryptors with threshold `threshold`.
+/// Returns an array of the snark proving key, an array of secret key shares, an array of the public keys of the
+/// shares, the encryption key and the decryption key. The share with id `i` is at index `i - 1`. The dealer learns the
+/// secret key so must be trusted to forget it, use a DKG and `saverDecryptorSetupGivenPublicKey` to avoid that.
+#[wasm_bindgen(js_name = saverThresholdDecryptorSetup)]
+pub fn saver_threshold_decryptor_setup(
+    chunk_bit_size: u8,
+    enc_gens: Uint8Array,
+    threshold: ShareId,
+    total: ShareId,
+    return_uncompressed_snark_pk: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let (snark_pk, shares, share_pks, ek, dk) = native::threshold_saver::threshold_decryptor_setup(
+        chunk_bit_size,
+        &enc_gens,
+        threshold,
+        total,
+    )
+    .map_err(|e| e.context("Threshold decryptor setup returned error"))?;
+    let shares_arr = Array::new();
+    for s in &shares {
+        let s = obj_to_uint8array!(s, true, "SaverSecretKeyShare");
+        shares_arr.push(&s);
+    }
+    let share_pks_arr = Array::new();
+    for pk in &share_pks {
+        let pk = g2_affine_to_uint8_array(pk)?;
+        share_pks_arr.push(&pk);
+    }
+    let snark_pk = snark_pk_to_uint8array(&snark_pk, return_uncompressed_snark_pk)?;
+    let ek = obj_to_uint8array!(&ek, false, "SaverEk");
+    let dk = obj_to_uint8array!(&dk, false, "SaverDk");
+    let setup = Array::new();
+    setup.push(&snark_pk);
+    setup.push(&shares_arr);
+    setup.push(&share_pks_arr);
+    setup.push(&ek);
+    setup.push(&dk);
+    Ok(setup)
+}
+
+/// Create the snark proving key for the decryptors to create the encryption key and decryption key for a threshold
+/// public key. Whoever runs this learns the SNARK trapdoor and can create proofs for ciphertexts that don't encrypt
+/// the committed message, but it can't decrypt. A snark proving key from a ceremony can be used instead.
+#[wasm_bindgen(js_name = saverDecryptorSnarkSetup)]
+pub fn saver_decryptor_snark_setup(
+    chunk_bit_size: u8,
+    enc_gens: Uint8Array,
+    return_uncompressed_snark_pk: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let snark_pk = native::threshold_saver::snark_setup(chunk_bit_size, &enc_gens)
+        .map_err(|e| e.context("Snark setup returned error"))?;
+    snark_pk_to_uint8array(&snark_pk, return_uncompressed_snark_pk)
+}
+
+/// Create a decryptor's contribution to the secrets of the encryption key for the snark proving key. Each decryptor
+/// creates one and sends it to whoever runs `saverDecryptorSetupGivenPublicKey`.
+#[wasm_bindgen(js_name = saverEncryptionKeyContribution)]
+pub fn saver_encryption_key_contribution(
+    chunk_bit_size: u8,
+    enc_gens: Uint8Array,
+    snark_pk: Uint8Array,
+    uncompressed_snark_pk: bool,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let snark_pk = snark_pk_from_uint8array(snark_pk, uncompressed_snark_pk)?;
+    let contribution =
+        native::threshold_saver::encryption_key_contribution(&snark_pk, &enc_gens, chunk_bit_size)?;
+    Ok(obj_to_uint8array!(
+        &contribution,
+        false,
+        "SaverEncryptionKeyContribution"
+    ))
+}
+
+/// Verify a decryptor's contribution to the secrets of the encryption key for the snark proving key
+#[wasm_bindgen(js_name = saverVerifyEncryptionKeyContribution)]
+pub fn saver_verify_encryption_key_contribution(
+    contribution: Uint8Array,
+    chunk_bit_size: u8,
+    enc_gens: Uint8Array,
+    snark_pk: Uint8Array,
+    uncompressed_snark_pk: bool,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let contribution = obj_from_uint8array!(
+        EncryptionKeyContribution,
+        contribution,
+        false,
+        "SaverEncryptionKeyContribution"
+    );
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let snark_pk = snark_pk_from_uint8array(snark_pk, uncompressed_snark_pk)?;
+    let result = native::threshold_saver::verify_encryption_key_contribution(
+        &contribution,
+        &snark_pk,
+        &enc_gens,
+        chunk_bit_size,
+    );
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Create the encryption key and decryption key for the snark proving key and the threshold public key created by
+/// the decryptors with a DKG like FROST's using `H` of the encryption generators as the public key base, see
+/// `generateKeyBaseFromGivenG2Point`. `contributions` has each decryptor's contribution to the secrets of the
+/// encryption key from `saverEncryptionKeyContribution`, which are verified first. Returns the encryption key and
+/// decryption key in an array of size 2. Whoever runs this learns the other secrets of the keys but can't decrypt as
+/// long as one of the contributing decryptors is honest.
+#[wasm_bindgen(js_name = saverDecryptorSetupGivenPublicKey)]
+pub fn saver_decryptor_setup_given_public_key(
+    chunk_bit_size: u8,
+    enc_gens: Uint8Array,
+    snark_pk: Uint8Array,
+    threshold_public_key: Uint8Array,
+    contributions: Array,
+    uncompressed_snark_pk: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let snark_pk = snark_pk_from_uint8array(snark_pk, uncompressed_snark_pk)?;
+    let pk = g2_affine_from_uint8_array(threshold_public_key)?;
+    let mut contribs = Vec::with_capacity(contributions.length() as usize);
+    for c in contributions.values() {
+        let c = Uint8Array::new(&c?);
+        contribs.push(obj_from_uint8array!(
+            EncryptionKeyContribution,
+            c,
+            false,
+            "SaverEncryptionKeyContribution"
+        ));
+    }
+    let (ek, dk) = native::threshold_saver::decryptor_setup_given_public_key(
+        chunk_bit_size,
+        &enc_gens,
+        &snark_pk,
+        &pk,
+        &contribs,
+    )
+    .map_err(|e| e.context("Decryptor setup returned error"))?;
+    let ek = obj_to_uint8array!(&ek, false, "SaverEk");
+    let dk = obj_to_uint8array!(&dk, false, "SaverDk");
+    let setup = Array::new();
+    setup.push(&ek);
+    setup.push(&dk);
+    Ok(setup)
+}
+
+/// Create the secret key share from the secret key share returned by the DKG, like `frostKeygenG2Round2Finish`
+#[wasm_bindgen(js_name = saverSecretKeyShareFromDkgShare)]
+pub fn saver_secret_key_share_from_dkg_share(
+    participant_id: ParticipantId,
+    threshold: ShareId,
+    secret_share: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let share = SaverSecretKeyShare {
+        id: participant_id,
+        threshold,
+        share: fr_from_uint8_array(secret_share, true)?,
+    };
+    Ok(obj_to_uint8array!(&share, true, "SaverSecretKeyShare"))
+}
+
+/// Public key of the share that is used to verify the decryptor's partial decryptions
+#[wasm_bindgen(js_name = saverGetSharePublicKey)]
+pub fn saver_get_share_public_key(
+    secret_key_share: Uint8Array,
+    enc_gens: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let share = obj_from_uint8array!(
+        SaverSecretKeyShare,
+        secret_key_share,
+        true,
+        "SaverSecretKeyShare"
+    );
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    g2_affine_to_uint8_array(&native::threshold_saver::share_public_key(
+        &share, &enc_gens,
+    ))
+}
+
+/// Create a partial decryption of the ciphertext with the decryptor's secret key share
+#[wasm_bindgen(js_name = saverPartialDecrypt)]
+pub fn saver_partial_decrypt(
+    ciphertext: Uint8Array,
+    secret_key_share: Uint8Array,
+    enc_gens: Uint8Array,
+) -> Result<Uint8Array, JsValue> {
+    set_panic_hook();
+    let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
+    let share = obj_from_uint8array!(
+        SaverSecretKeyShare,
+        secret_key_share,
+        true,
+        "SaverSecretKeyShare"
+    );
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let partial = native::threshold_saver::partial_decrypt(&ct, &share, &enc_gens)?;
+    Ok(obj_to_uint8array!(
+        &partial,
+        false,
+        "SaverPartialDecryption"
+    ))
+}
+
+/// Verify that the partial decryption was created with the share whose public key is `share_public_key`
+#[wasm_bindgen(js_name = saverVerifyPartialDecryption)]
+pub fn saver_verify_partial_decryption(
+    partial_decryption: Uint8Array,
+    ciphertext: Uint8Array,
+    share_public_key: Uint8Array,
+    enc_gens: Uint8Array,
+) -> Result<JsValue, JsValue> {
+    set_panic_hook();
+    let partial = obj_from_uint8array!(
+        PartialDecryption,
+        partial_decryption,
+        false,
+        "SaverPartialDecryption"
+    );
+    let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
+    let share_pk = g2_affine_from_uint8_array(share_public_key)?;
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let result =
+        native::threshold_saver::verify_partial_decryption(&partial, &ct, &share_pk, &enc_gens);
+    Ok(serde_wasm_bindgen::to_value(&VerifyResponse::from(result)).unwrap())
+}
+
+/// Combine partial decryptions from at least `threshold` different decryptors and decrypt the ciphertext. Each
+/// partial decryption is verified with the public key of its decryptor's share in `share_public_keys`, where the
+/// share with id `i` is at index `i - 1`. If `uncompressed_public_params` is true, it expects the decryption key and
+/// snark verification key in uncompressed form. Returns the message and the commitment to the randomness like
+/// `saverDecryptCiphertextUsingSnarkVk`.
+// The arguments mirror `saverDecryptCiphertextUsingSnarkVk` plus the decryptors, so grouping them would be a
+// JS-only wrapper type
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = saverCombinePartialDecryptionsUsingSnarkVk)]
+pub fn saver_combine_partial_decryptions_using_snark_vk(
+    ciphertext: Uint8Array,
+    partial_decryptions: Array,
+    threshold: ShareId,
+    share_public_keys: Array,
+    enc_gens: Uint8Array,
+    decryption_key: Uint8Array,
+    snark_vk: Uint8Array,
+    chunk_bit_size: u8,
+    uncompressed_public_params: bool,
+) -> Result<Array, JsValue> {
+    set_panic_hook();
+    let ct = obj_from_uint8array!(SaverCiphertext, ciphertext, false, "SaverCiphertext");
+    let mut partials = Vec::with_capacity(partial_decryptions.length() as usize);
+    for p in partial_decryptions.values() {
+        let p = Uint8Array::new(&p?);
+        partials.push(obj_from_uint8array!(
+            PartialDecryption,
+            p,
+            false,
+            "SaverPartialDecryption"
+        ));
+    }
+    let mut pks = Vec::with_capacity(share_public_keys.length() as usize);
+    for pk in share_public_keys.values() {
+        pks.push(g2_affine_from_uint8_array(Uint8Array::new(&pk?))?);
+    }
+    let decryptors = ThresholdDecryptors {
+        threshold,
+        share_public_keys: pks,
+    };
+    let enc_gens = obj_from_uint8array!(EncGens, enc_gens, false, "EncryptionGenerators");
+    let (dk, snark_vk) = if uncompressed_public_params {
+        (
+            obj_from_uint8array_uncompressed!(SaverDk, decryption_key, "SaverDk"),
+            obj_from_uint8array_uncompressed!(SaverSnarkVk, snark_vk, "SaverSnarkVk"),
+        )
+    } else {
+        (
+            obj_from_uint8array!(SaverDk, decryption_key, false, "SaverDk"),
+            obj_from_uint8array!(SaverSnarkVk, snark_vk, false, "SaverSnarkVk"),
+        )
+    };
+    let (decrypted_message, nu) = native::threshold_saver::combine_partial_decryptions(
+        &ct,
+        &partials,
+        &decryptors,
+        &enc_gens,
+        &dk,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .map_err(|e| e.context("Decryption returned error"))?;
+    let dec = Array::new();
+    let m = fr_to_uint8_array(&decrypted_message)?;
+    let nu = g1_affine_to_uint8_array(&nu)?;
+    dec.push(&m);
+    dec.push(&nu);
+    Ok(dec)
+}
+
+fn snark_pk_to_uint8array(
+    snark_pk: &SaverSnarkPk,
+    uncompressed: bool,
+) -> Result<Uint8Array, JsValue> {
+    Ok(if uncompressed {
+        obj_to_uint8array_uncompressed!(snark_pk, "SaverSnarkPk")
+    } else {
+        obj_to_uint8array!(snark_pk, false, "SaverSnarkPk")
+    })
+}
+
+fn snark_pk_from_uint8array(
+    snark_pk: Uint8Array,
+    uncompressed: bool,
+) -> Result<SaverSnarkPk, JsValue> {
+    Ok(if uncompressed {
+        obj_from_uint8array_uncompressed!(SaverSnarkPk, snark_pk, "SaverSnarkPk")
+    } else {
+        obj_from_uint8array!(SaverSnarkPk, snark_pk, false, "SaverSnarkPk")
+    })
+}
@@ -1,4 +1,5 @@
 use crate::{
+    native,
     threshold_sig::{BASE_OT_KEY_SIZE, KAPPA, STATISTICAL_SECURITY_PARAMETER},
     utils::{get_seeded_rng, js_array_to_iter, js_set_to_btree_set, set_panic_hook},
     G1Affine,
@@ -28,12 +29,12 @@ pub fn start_base_ot_phase(
     num_base_ot: Option<u16>,
 ) -> Result<Array, JsValue> {
     set_panic_hook();
-    let mut rng = get_seeded_rng();
+    let mut rng = get_seeded_rng()?;
     let num_base_ot = num_base_ot.unwrap_or_else(|| {
         let ote_params = MultiplicationOTEParams::<KAPPA, STATISTICAL_SECURITY_PARAMETER> {};
         ote_params.num_base_ot()
     });
-    let others = js_set_to_btree_set(&others);
+    let others = js_set_to_btree_set(&others)?;
     let (base_ot, sender_pk_and_proof) = BaseOTPhase::init::<_, Blake2b512>(
         &mut rng,
         participant_id,
@@ -41,7 +42,7 @@ pub fn start_base_ot_phase(
         num_base_ot,
         &parse_pk_base(pk_base)?,
     )
-    .map_err(|e| JsValue::from(&format!("Starting Base OT returned error: {:?}", e)))?;
+    .map_err(|e| native::Error::from(e).context("Starting Base OT returned error"))?;
     let r = Array::new();
     let base_ot = obj_to_uint8array!(&base_ot, true, "BaseOTPhase");
     let messages_to_send = Map::new();
@@ -62,7 +63,7 @@ pub fn base_ot_phase_process_sender_pubkey(
     pk_base: Uint8Array,
 ) -> Result<Array, JsValue> {
     set_panic_hook();
-    let mut rng = get_seeded_rng();
+    let mut rng = get_seeded_rng()?;
     let mut base_ot = obj_from_uint8array!(BaseOTPhase<G1Affine>, base_ot_phase, true);
     let pub_key_proof = obj_from_uint8array!(SenderPubKeyAndProof<G1Affine>, pub_key_proof, false);
     let recv_pk = base_ot
@@ -73,10 +74,7 @@ pub fn base_ot_phase_process_sender_pubkey(
             &parse_pk_base(pk_base)?,
         )
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Processing Base OT sender's public key returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Processing Base OT sender's public key returned error")
         })?;
     let r = Array::new();
     let base_ot = obj_to_uint8array!(&base_ot, true, "BaseOTPhase");
@@ -98,10 +96,7 @@ pub fn base_ot_phase_process_receiver_pubkey(
     let challenges = base_ot
         .receive_receiver_pubkey::<BASE_OT_KEY_SIZE>(receiver_id, pk)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Processing Base OT sender's public key returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Processing Base OT sender's public key returned error")
         })?;
     let r = Array::new();
     let base_ot = obj_to_uint8array!(&base_ot, true, "BaseOTPhase");
@@ -123,10 +118,7 @@ pub fn base_ot_phase_process_receiver_challenges(
     let resp = base_ot
         .receive_challenges(sender_id, challenges)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Processing Base OT sender's challenges returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Processing Base OT sender's challenges returned error")
         })?;
     let r = Array::new();
     let base_ot = obj_to_uint8array!(&base_ot, true, "BaseOTPhase");
@@ -148,10 +140,7 @@ pub fn base_ot_phase_process_sender_responses(
     let hk = base_ot
         .receive_responses(sender_id, responses)
         .map_err(|e| {
-            JsValue::from(&format!(
-                "Processing Base OT receiver's responses returned error: {:?}",
-                e
-            ))
+            native::Error::from(e).context("Processing Base OT receiver's responses returned error")
         })?;
     let r = Array::new();
     let base_ot = obj_to_uint8array!(&base_ot, true, "BaseOTPhase");
@@ -171,10 +160,7 @@ pub fn base_ot_phase_process_hashed_keys(
     let mut base_ot = obj_from_uint8array!(BaseOTPhase<G1Affine>, base_ot_phase, true);
     let hk = obj_from_uint8array!(Vec<(HashedKey, HashedKey)>, hashed_keys, false);
     base_ot.receive_hashed_keys(sender_id, hk).map_err(|e| {
-        JsValue::from(&format!(
-            "Processing Base OT receiver's responses returned error: {:?}",
-            e
-        ))
+        native::Error::from(e).context("Processing Base OT receiver's responses returned error")
     })?;
     let base_ot = obj_to_uint8array!(&base_ot, true, "BaseOTPhase");
     Ok(base_ot)
@@ -200,26 +186,29 @@ pub fn base_ot_output_check(base_ot_outputs: Array) -> Result<(), JsValue> {
             let (choices, receiver_keys) =
                 outs[*other as usize - 1].receiver.get(&base_ot.id).unwrap();
             if receiver_keys.len() != sender_keys.len() {
-                return Err(JsValue::from(&format!(
+                return Err(native::Error::invalid_argument(format!(
                     "Receiver keys count {} different from sender key count {}",
                     receiver_keys.len(),
                     sender_keys.len()
-                )));
+                ))
+                .into());
             }
             for i in 0..sender_keys.len() {
                 if choices[i] {
                     if sender_keys.0[i].1 != receiver_keys.0[i] {
-                        return Err(JsValue::from(&format!(
+                        return Err(native::Error::invalid_argument(format!(
                             "Sender key not equal to receiver at index {}",
                             i
-                        )));
+                        ))
+                        .into());
                     }
                 } else {
                     if sender_keys.0[i].0 != receiver_keys.0[i] {
-                        return Err(JsValue::from(&format!(
+                        return Err(native::Error::invalid_argument(format!(
                             "Sender key not equal to receiver at index {}",
                             i
-                        )));
+                        ))
+                        .into());
                     }
                 }
             }
@@ -11,7 +11,10 @@
  * limitations under the License.
  */
 
-use crate::{Fr, G1Affine, G2Affine};
+use crate::{
+    native::{self, envelope, Error, ErrorCode},
+    Fr, G1Affine, G2Affine,
+};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{
     collections::BTreeMap,
@@ -19,8 +22,7 @@ use ark_std::{
 };
 use blake2::Blake2b512;
 use dock_crypto_utils::concat_slices;
-use serde_wasm_bindgen::from_value;
-use std::collections::BTreeSet;
+use std::{cell::RefCell, collections::BTreeSet};
 use wasm_bindgen::prelude::*;
 use zeroize::Zeroize;
 
@@ -59,37 +61,55 @@ macro_rules! console_log {
     ($($t:tt)*) => (log(&format_args!($($t)*).to_string()))
 }
 
-pub fn fr_to_jsvalue(elem: &Fr) -> Result<JsValue, JsValue> {
-    let mut bytes = vec![];
-    elem.serialize_compressed(&mut bytes).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot serialize {:?} Fr due to error: {:?}",
-            elem, e
+/// Convert a JS value to `T` like `serde_wasm_bindgen::from_value` but failing with an `InvalidArgument` error as
+/// the value is an argument passed by the caller
+pub fn from_value<T: serde::de::DeserializeOwned>(value: JsValue) -> native::Result<T> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| {
+        Error::invalid_argument(format!(
+            "Invalid value for {}: {}",
+            core::any::type_name::<T>(),
+            e
         ))
-    })?;
+    })
+}
+
+/// Serialize a field or group element named `name` like "Fr" or "G1Affine", in an envelope if envelope output is
+/// enabled
+fn elem_to_bytes<T: CanonicalSerialize>(elem: &T, name: &str) -> native::Result<Vec<u8>> {
+    let mut bytes = envelope::header_for_output(name, true)
+        .map(|h| h.to_vec())
+        .unwrap_or_default();
+    elem.serialize_compressed(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Deserialize a field or group element named `name` from bytes which can also be an envelope if envelope input is
+/// enabled
+fn elem_from_bytes<T: CanonicalDeserialize>(bytes: &[u8], name: &str) -> native::Result<T> {
+    Ok(T::deserialize_compressed(envelope::open(
+        bytes,
+        Some(name),
+        true,
+    )?)?)
+}
+
+pub fn fr_to_jsvalue(elem: &Fr) -> Result<JsValue, JsValue> {
+    let bytes = elem_to_bytes(elem, "Fr")
+        .map_err(|e| e.context(format!("Cannot serialize {:?} Fr", elem)))?;
     // Following unwrap won't fail as its serializing only bytes
     Ok(serde_wasm_bindgen::to_value(&bytes).unwrap())
 }
 
 pub fn fr_from_jsvalue(value: JsValue) -> Result<Fr, JsValue> {
-    let bytes: Vec<u8> = serde_wasm_bindgen::from_value(value)?;
-    let elem = Fr::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize {:?} to Fr due to error: {:?}",
-            bytes, e
-        ))
-    })?;
+    let bytes: Vec<u8> = from_value(value)?;
+    let elem = elem_from_bytes(&bytes, "Fr")
+        .map_err(|e| e.context(format!("Cannot deserialize {:?} to Fr", bytes)))?;
     Ok(elem)
 }
 
 pub fn fr_to_uint8_array(elem: &Fr) -> Result<js_sys::Uint8Array, JsValue> {
-    let mut bytes = vec![];
-    elem.serialize_compressed(&mut bytes).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot serialize {:?} Fr due to error: {:?}",
-            elem, e
-        ))
-    })?;
+    let bytes = elem_to_bytes(elem, "Fr")
+        .map_err(|e| e.context(format!("Cannot serialize {:?} Fr", elem)))?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
 
@@ -103,12 +123,8 @@ pub fn fr_from_uint8_array(
     // Looking at https://github.com/rustwasm/wasm-bindgen/issues/5 and other links from this page,
     // this isn't easily doable
     let mut bytes: Vec<u8> = value.to_vec();
-    let elem = Fr::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize {:?} to Fr due to error: {:?}",
-            bytes, e
-        ))
-    })?;
+    let elem = elem_from_bytes(&bytes, "Fr")
+        .map_err(|e| e.context(format!("Cannot deserialize {:?} to Fr", bytes)))?;
     if value_is_secret {
         bytes.zeroize();
     }
@@ -116,13 +132,9 @@ pub fn fr_from_uint8_array(
 }
 
 pub fn frs_from_jsvalue(value: JsValue) -> Result<Vec<Fr>, JsValue> {
-    let bytes: Vec<u8> = serde_wasm_bindgen::from_value(value)?;
-    let elem = <Vec<Fr>>::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize to Fr vector due to error: {:?}",
-            e
-        ))
-    })?;
+    let bytes: Vec<u8> = from_value(value)?;
+    let elem = <Vec<Fr>>::deserialize_compressed(&bytes[..])
+        .map_err(|e| Error::from(e).context("Cannot deserialize to Fr vector"))?;
     Ok(elem)
 }
 
@@ -130,83 +142,63 @@ pub fn frs_to_jsvalue(elems: &[Fr]) -> Result<JsValue, JsValue> {
     let mut bytes = vec![];
     elems
         .serialize_compressed(&mut bytes)
-        .map_err(|e| JsValue::from(&format!("Cannot serialize Fr vector due to error: {:?}", e)))?;
+        .map_err(|e| Error::from(e).context("Cannot serialize Fr vector"))?;
     // Following unwrap won't fail as its serializing only bytes
     Ok(serde_wasm_bindgen::to_value(&bytes).unwrap())
 }
 
 pub fn g1_affine_to_jsvalue(elem: &G1Affine) -> Result<JsValue, JsValue> {
-    let mut bytes = vec![];
-    elem.serialize_compressed(&mut bytes)
-        .map_err(|e| JsValue::from(&format!("Cannot serialize G1Affine due to error: {:?}", e)))?;
+    let bytes =
+        elem_to_bytes(elem, "G1Affine").map_err(|e| e.context("Cannot serialize G1Affine"))?;
     // Following unwrap won't fail as its serializing only bytes
     Ok(serde_wasm_bindgen::to_value(&bytes).unwrap())
 }
 
 pub fn g1_affine_from_jsvalue(value: JsValue) -> Result<G1Affine, JsValue> {
-    let bytes: Vec<u8> = serde_wasm_bindgen::from_value(value)?;
-    let elem = G1Affine::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize to G1Affine due to error: {:?}",
-            e
-        ))
-    })?;
+    let bytes: Vec<u8> = from_value(value)?;
+    let elem = elem_from_bytes(&bytes, "G1Affine")
+        .map_err(|e| e.context("Cannot deserialize to G1Affine"))?;
     Ok(elem)
 }
 
 pub fn g1_affine_to_uint8_array(elem: &G1Affine) -> Result<js_sys::Uint8Array, JsValue> {
-    let mut bytes = vec![];
-    elem.serialize_compressed(&mut bytes)
-        .map_err(|e| JsValue::from(&format!("Cannot serialize G1Affine due to error: {:?}", e)))?;
+    let bytes =
+        elem_to_bytes(elem, "G1Affine").map_err(|e| e.context("Cannot serialize G1Affine"))?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
 
 pub fn g1_affine_from_uint8_array(value: js_sys::Uint8Array) -> Result<G1Affine, JsValue> {
     // TODO: Is there a better way to get byte slice from `value` without creating a Vec
     let bytes: Vec<u8> = value.to_vec();
-    let elem = G1Affine::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize to G1Affine due to error: {:?}",
-            e
-        ))
-    })?;
+    let elem = elem_from_bytes(&bytes, "G1Affine")
+        .map_err(|e| e.context("Cannot deserialize to G1Affine"))?;
     Ok(elem)
 }
 
 pub fn g2_affine_to_jsvalue(elem: &G2Affine) -> Result<JsValue, JsValue> {
-    let mut bytes = vec![];
-    elem.serialize_compressed(&mut bytes)
-        .map_err(|e| JsValue::from(&format!("Cannot serialize G2Affine due to error: {:?}", e)))?;
+    let bytes =
+        elem_to_bytes(elem, "G2Affine").map_err(|e| e.context("Cannot serialize G2Affine"))?;
     // Following unwrap won't fail as its serializing only bytes
     Ok(serde_wasm_bindgen::to_value(&bytes).unwrap())
 }
 
 pub fn g2_affine_from_jsvalue(value: JsValue) -> Result<G2Affine, JsValue> {
-    let bytes: Vec<u8> = serde_wasm_bindgen::from_value(value)?;
-    let elem = G2Affine::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize to G2Affine due to error: {:?}",
-            e
-        ))
-    })?;
+    let bytes: Vec<u8> = from_value(value)?;
+    let elem = elem_from_bytes(&bytes, "G2Affine")
+        .map_err(|e| e.context("Cannot deserialize to G2Affine"))?;
     Ok(elem)
 }
 
 pub fn g2_affine_to_uint8_array(elem: &G2Affine) -> Result<js_sys::Uint8Array, JsValue> {
-    let mut bytes = vec![];
-    elem.serialize_compressed(&mut bytes)
-        .map_err(|e| JsValue::from(&format!("Cannot serialize G2Affine due to error: {:?}", e)))?;
+    let bytes =
+        elem_to_bytes(elem, "G2Affine").map_err(|e| e.context("Cannot serialize G2Affine"))?;
     Ok(js_sys::Uint8Array::from(bytes.as_slice()))
 }
 
 pub fn g2_affine_from_uint8_array(value: js_sys::Uint8Array) -> Result<G2Affine, JsValue> {
     let bytes: Vec<u8> = value.to_vec();
-    let elem = G2Affine::deserialize_compressed(&bytes[..]).map_err(|e| {
-        JsValue::from(&format!(
-            "Cannot deserialize to G2Affine due to error: {:?}",
-            e
-        ))
-    })?;
+    let elem = elem_from_bytes(&bytes, "G2Affine")
+        .map_err(|e| e.context("Cannot deserialize to G2Affine"))?;
     Ok(elem)
 }
 
@@ -237,9 +229,7 @@ pub fn messages_as_bytes_to_fr_vec(
             if encode_messages {
                 encode_message_for_signing(m)
             } else {
-                Fr::deserialize_compressed(m.as_slice()).map_err(|e| {
-                    JsValue::from(&format!("Cannot deserialize to Fr due to error: {:?}", e))
-                })?
+                elem_from_bytes(m, "Fr").map_err(|e| e.context("Cannot deserialize to Fr"))?
             }
         });
     }
@@ -261,14 +251,12 @@ pub fn encode_messages_as_js_map_to_fr_btreemap(
     let mut msgs = BTreeMap::new();
     for e in messages.entries() {
         let arr = js_sys::Array::from(&e.unwrap());
-        let index: usize = serde_wasm_bindgen::from_value(arr.get(0))?;
-        let msg: Vec<u8> = serde_wasm_bindgen::from_value(arr.get(1))?;
+        let index: usize = from_value(arr.get(0))?;
+        let msg: Vec<u8> = from_value(arr.get(1))?;
         let m = if encode_messages {
             encode_message_for_signing(&msg)
         } else {
-            Fr::deserialize_compressed(msg.as_slice()).map_err(|e| {
-                JsValue::from(&format!("Cannot deserialize to Fr due to error: {:?}", e))
-            })?
+            elem_from_bytes(&msg, "Fr").map_err(|e| e.context("Cannot deserialize to Fr"))?
         };
         msgs.insert(index, m);
     }
@@ -335,22 +323,138 @@ pub fn zeroize_uint8array(value: js_sys::Uint8Array) {
     value.fill(0, 0, value.length());
 }
 
-pub fn get_seeded_rng() -> StdRng {
+/// Reads the bytes of an object split in several `Uint8Array`s, copying only as many bytes to wasm memory as asked
+/// for at a time rather than the whole object
+pub struct Uint8ArrayPartsReader {
+    parts: Vec<js_sys::Uint8Array>,
+    part: usize,
+    offset: u32,
+}
+
+impl Uint8ArrayPartsReader {
+    pub fn new(parts: &js_sys::Array) -> Result<Self, JsValue> {
+        let mut p = Vec::with_capacity(parts.length() as usize);
+        for (i, a) in parts.values().into_iter().enumerate() {
+            let a = a.unwrap();
+            if !a.is_instance_of::<js_sys::Uint8Array>() {
+                return Err(
+                    Error::invalid_argument(format!("Part {} is not a Uint8Array", i)).into(),
+                );
+            }
+            p.push(js_sys::Uint8Array::new(&a));
+        }
+        Ok(Self {
+            parts: p,
+            part: 0,
+            offset: 0,
+        })
+    }
+}
+
+impl ark_serialize::Read for Uint8ArrayPartsReader {
+    fn read(&mut self, buf: &mut [u8]) -> ark_std::io::Result<usize> {
+        while self.part < self.parts.len() {
+            let part = &self.parts[self.part];
+            let remaining = part.length() - self.offset;
+            if remaining == 0 {
+                self.part += 1;
+                self.offset = 0;
+                continue;
+            }
+            let n = remaining.min(buf.len() as u32);
+            part.subarray(self.offset, self.offset + n)
+                .copy_to(&mut buf[..n as usize]);
+            self.offset += n;
+            return Ok(n as usize);
+        }
+        Ok(0)
+    }
+}
+
+/// Where `get_seeded_rng` gets the seeds of the RNGs used by all signing, proving and key generation functions
+pub enum RngSource {
+    /// The platform's entropy source. This is the default.
+    Entropy,
+    /// Seeds are drawn from an RNG seeded with a fixed seed so the outputs are deterministic. Only meant for tests
+    /// and test vectors.
+    Seeded(Box<StdRng>),
+    /// Seeds are the bytes returned by the JS function when called with the number of bytes needed
+    Callback(js_sys::Function),
+}
+
+thread_local! {
+    static RNG_SOURCE: RefCell<RngSource> = const { RefCell::new(RngSource::Entropy) };
+}
+
+pub fn set_rng_source(source: RngSource) {
+    RNG_SOURCE.with(|s| *s.borrow_mut() = source);
+}
+
+/// Get an RNG seeded from the current `RngSource`. Fails only when the source is a callback and it fails.
+pub fn get_seeded_rng() -> native::Result<StdRng> {
     let mut buf = [0u8; 32];
-    use rand::{thread_rng, RngCore as RngCoreOld};
-    let mut rng = thread_rng();
-    rng.fill_bytes(&mut buf);
-    // getrandom is using node-js crypto module which doesn't work when building for target web. It
-    // works for `wasm-pack test` with chrome in headless and normal mode
-    // getrandom::getrandom(&mut buf).unwrap();
-    StdRng::from_seed(buf)
+    let callback = RNG_SOURCE.with(|s| match &mut *s.borrow_mut() {
+        RngSource::Entropy => {
+            use rand::{thread_rng, RngCore as RngCoreOld};
+            let mut rng = thread_rng();
+            rng.fill_bytes(&mut buf);
+            // getrandom is using node-js crypto module which doesn't work when building for target web. It
+            // works for `wasm-pack test` with chrome in headless and normal mode
+            // getrandom::getrandom(&mut buf).unwrap();
+            None
+        }
+        RngSource::Seeded(rng) => {
+            rng.fill_bytes(&mut buf);
+            None
+        }
+        RngSource::Callback(f) => Some(f.clone()),
+    });
+    // The callback is called after the borrow of the source ends as it might call a randomized function itself
+    if let Some(f) = callback {
+        let mut bytes = entropy_from_callback(&f, buf.len())?;
+        buf.copy_from_slice(&bytes);
+        bytes.zeroize();
+    }
+    let rng = StdRng::from_seed(buf);
+    buf.zeroize();
+    Ok(rng)
+}
+
+/// Call the entropy callback to get `count` bytes
+pub fn entropy_from_callback(f: &js_sys::Function, count: usize) -> native::Result<Vec<u8>> {
+    let bytes = f
+        .call1(&JsValue::NULL, &JsValue::from(count as u32))
+        .map_err(|e| {
+            Error::new(
+                ErrorCode::RngFailure,
+                format!("RNG source callback threw: {:?}", e),
+            )
+        })?;
+    if !bytes.is_instance_of::<js_sys::Uint8Array>() {
+        return Err(Error::new(
+            ErrorCode::RngFailure,
+            "RNG source callback should return a Uint8Array",
+        ));
+    }
+    let bytes = js_sys::Uint8Array::from(bytes).to_vec();
+    if bytes.len() != count {
+        return Err(Error::new(
+            ErrorCode::RngFailure,
+            format!(
+                "RNG source callback should return {} bytes but returned {}",
+                count,
+                bytes.len()
+            ),
+        ));
+    }
+    Ok(bytes)
 }
 
-pub fn random_bytes() -> Vec<u8> {
-    let mut rng = get_seeded_rng();
+pub fn random_bytes() -> native::Result<Vec<u8>> {
+    let mut rng = get_seeded_rng()?;
     let mut s = vec![0u8; 32];
     rng.fill_bytes(s.as_mut_slice());
-    s
+    Ok(s)
 }
 
 pub fn is_positive_safe_integer(num: &js_sys::Number) -> bool {
@@ -359,25 +463,25 @@ pub fn is_positive_safe_integer(num: &js_sys::Number) -> bool {
 
 pub fn js_set_to_btree_set<T: Ord + serde::de::DeserializeOwned>(
     js_set: &js_sys::Set,
-) -> BTreeSet<T> {
-    let set: BTreeSet<T> = js_set
+) -> native::Result<BTreeSet<T>> {
+    js_set
         .values()
         .into_iter()
-        .map(|i| serde_wasm_bindgen::from_value(i.unwrap()).unwrap())
-        .collect();
-    set
+        .map(|i| {
+            let i = i.map_err(|_| Error::invalid_argument("Failed to iterate over the set"))?;
+            from_value(i)
+        })
+        .collect()
 }
 
+/// Serialize an object to a `Uint8Array`. When an `$obj_name` is given, the bytes are put in an envelope if
+/// envelope output is enabled, see `native::envelope`.
 #[macro_export]
 macro_rules! obj_to_uint8array {
     ($obj:expr, $value_is_secret: expr) => {{
         let mut serz = vec![];
-        ark_serialize::CanonicalSerialize::serialize_compressed($obj, &mut serz).map_err(|e| {
-            JsValue::from(format!(
-                "Failed to serialize to bytes due to error: {:?}",
-                e
-            ))
-        })?;
+        ark_serialize::CanonicalSerialize::serialize_compressed($obj, &mut serz)
+            .map_err(|e| $crate::native::Error::from(e).context("Failed to serialize to bytes"))?;
         let s = js_sys::Uint8Array::from(serz.as_slice());
         if $value_is_secret {
             serz.zeroize();
@@ -386,12 +490,15 @@ macro_rules! obj_to_uint8array {
     }};
 
     ($obj:expr, $value_is_secret: expr, $obj_name:expr) => {{
-        let mut serz = vec![];
+        let mut serz = $crate::native::envelope::header_for_output(
+            ::core::convert::AsRef::<str>::as_ref(&$obj_name),
+            true,
+        )
+        .map(|h| h.to_vec())
+        .unwrap_or_default();
         ark_serialize::CanonicalSerialize::serialize_compressed($obj, &mut serz).map_err(|e| {
-            JsValue::from(format!(
-                "Failed to serialize a {} to bytes due to error: {:?}",
-                $obj_name, e
-            ))
+            $crate::native::Error::from(e)
+                .context(format!("Failed to serialize a {} to bytes", $obj_name))
         })?;
         let s = js_sys::Uint8Array::from(serz.as_slice());
         if $value_is_secret {
@@ -401,19 +508,19 @@ macro_rules! obj_to_uint8array {
     }};
 }
 
+/// Deserialize an object from a `Uint8Array` which can also be an envelope if envelope input is enabled. When an
+/// `$obj_name` is given, the envelope must be of that type.
 #[macro_export]
 macro_rules! obj_from_uint8array {
     ($obj_type:ty, $uint8array:expr, $value_is_secret: expr) => {{
         let mut serz = $uint8array.to_vec();
-        let deserz: $obj_type = ark_serialize::CanonicalDeserialize::deserialize_compressed(
-            &serz[..],
-        )
-        .map_err(|e| {
-            JsValue::from(format!(
-                "Failed to deserialize from bytes due to error: {:?}",
-                e
-            ))
-        })?;
+        let deserz: $obj_type = $crate::native::envelope::open(&serz, None, true)
+            .and_then(|b| {
+                Ok(ark_serialize::CanonicalDeserialize::deserialize_compressed(
+                    b,
+                )?)
+            })
+            .map_err(|e: $crate::native::Error| e.context("Failed to deserialize from bytes"))?;
         if $value_is_secret {
             serz.zeroize();
         }
@@ -422,14 +529,18 @@ macro_rules! obj_from_uint8array {
 
     ($obj_type:ty, $uint8array:expr, $value_is_secret: expr, $obj_name:expr) => {{
         let mut serz = $uint8array.to_vec();
-        let deserz: $obj_type = ark_serialize::CanonicalDeserialize::deserialize_compressed(
-            &serz[..],
+        let deserz: $obj_type = $crate::native::envelope::open(
+            &serz,
+            Some(::core::convert::AsRef::<str>::as_ref(&$obj_name)),
+            true,
         )
-        .map_err(|e| {
-            JsValue::from(format!(
-                "Failed to deserialize a {} from bytes due to error: {:?}",
-                $obj_name, e
-            ))
+        .and_then(|b| {
+            Ok(ark_serialize::CanonicalDeserialize::deserialize_compressed(
+                b,
+            )?)
+        })
+        .map_err(|e: $crate::native::Error| {
+            e.context(format!("Failed to deserialize a {} from bytes", $obj_name))
         })?;
         if $value_is_secret {
             serz.zeroize();
@@ -442,25 +553,22 @@ macro_rules! obj_from_uint8array {
 macro_rules! obj_to_uint8array_uncompressed {
     ($obj:expr) => {{
         let mut serz = vec![];
-        ark_serialize::CanonicalSerialize::serialize_uncompressed($obj, &mut serz).map_err(
-            |e| {
-                JsValue::from(format!(
-                    "Failed to serialize to bytes due to error: {:?}",
-                    e
-                ))
-            },
-        )?;
+        ark_serialize::CanonicalSerialize::serialize_uncompressed($obj, &mut serz)
+            .map_err(|e| $crate::native::Error::from(e).context("Failed to serialize to bytes"))?;
         js_sys::Uint8Array::from(serz.as_slice())
     }};
 
     ($obj:expr, $obj_name:expr) => {{
-        let mut serz = vec![];
+        let mut serz = $crate::native::envelope::header_for_output(
+            ::core::convert::AsRef::<str>::as_ref(&$obj_name),
+            false,
+        )
+        .map(|h| h.to_vec())
+        .unwrap_or_default();
         ark_serialize::CanonicalSerialize::serialize_uncompressed($obj, &mut serz).map_err(
             |e| {
-                JsValue::from(format!(
-                    "Failed to serialize a {} to bytes due to error: {:?}",
-                    $obj_name, e
-                ))
+                $crate::native::Error::from(e)
+                    .context(format!("Failed to serialize a {} to bytes", $obj_name))
             },
         )?;
         js_sys::Uint8Array::from(serz.as_slice())
@@ -471,28 +579,22 @@ macro_rules! obj_to_uint8array_uncompressed {
 macro_rules! obj_from_uint8array_uncompressed {
     ($obj_type:ty, $uint8array:expr) => {{
         let serz = $uint8array.to_vec();
-        let deserz: $obj_type = ark_serialize::CanonicalDeserialize::deserialize_uncompressed(
-            &serz[..],
-        )
-        .map_err(|e| {
-            JsValue::from(format!(
-                "Failed to deserialize from bytes due to error: {:?}",
-                e
-            ))
-        })?;
+        let deserz: $obj_type = $crate::native::envelope::open(&serz, None, false)
+            .and_then(|b| Ok(ark_serialize::CanonicalDeserialize::deserialize_uncompressed(b)?))
+            .map_err(|e: $crate::native::Error| e.context("Failed to deserialize from bytes"))?;
         deserz
     }};
 
     ($obj_type:ty, $uint8array:expr, $obj_name:expr) => {{
         let serz = $uint8array.to_vec();
-        let deserz: $obj_type = ark_serialize::CanonicalDeserialize::deserialize_uncompressed(
-            &serz[..],
+        let deserz: $obj_type = $crate::native::envelope::open(
+            &serz,
+            Some(::core::convert::AsRef::<str>::as_ref(&$obj_name)),
+            false,
         )
-        .map_err(|e| {
-            JsValue::from(format!(
-                "Failed to deserialize a {} from bytes due to error: {:?}",
-                $obj_name, e
-            ))
+        .and_then(|b| Ok(ark_serialize::CanonicalDeserialize::deserialize_uncompressed(b)?))
+        .map_err(|e: $crate::native::Error| {
+            e.context(format!("Failed to deserialize a {} from bytes", $obj_name))
         })?;
         deserz
     }};
@@ -522,27 +624,27 @@ mod tests {
 
         let f = vec![
             dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-                &random_bytes(),
+                &random_bytes().unwrap(),
             ),
             dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-                &random_bytes(),
+                &random_bytes().unwrap(),
             ),
             dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-                &random_bytes(),
+                &random_bytes().unwrap(),
             ),
             dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-                &random_bytes(),
+                &random_bytes().unwrap(),
             ),
         ];
         let jf = frs_to_jsvalue(&f).unwrap();
         assert_eq!(f, frs_from_jsvalue(jf).unwrap());
 
-        let mut rng = get_seeded_rng();
+        let mut rng = get_seeded_rng().unwrap();
         let g1 = G1Projective::rand(&mut rng).into_affine();
         let jg1 = g1_affine_to_jsvalue(&g1).unwrap();
         assert_eq!(g1, g1_affine_from_jsvalue(jg1).unwrap());
 
-        let mut rng = get_seeded_rng();
+        let mut rng = get_seeded_rng().unwrap();
         let g2 = G2Projective::rand(&mut rng).into_affine();
         let jg2 = g2_affine_to_jsvalue(&g2).unwrap();
         assert_eq!(g2, g2_affine_from_jsvalue(jg2).unwrap());
@@ -552,13 +654,13 @@ mod tests {
     pub fn fr_map() {
         let map = js_sys::Map::new();
         let f1 = dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-            &random_bytes(),
+            &random_bytes().unwrap(),
         );
         let f2 = dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-            &random_bytes(),
+            &random_bytes().unwrap(),
         );
         let f3 = dock_crypto_utils::hashing_utils::field_elem_from_try_and_incr::<Fr, Blake2b512>(
-            &random_bytes(),
+            &random_bytes().unwrap(),
         );
         map.set(&JsValue::from(1), &fr_to_jsvalue(&f1).unwrap());
         map.set(&JsValue::from(2), &fr_to_jsvalue(&f2).unwrap());
@@ -577,7 +679,11 @@ pub fn js_array_to_iter<Item: CanonicalDeserialize>(
 ) -> impl Iterator<Item = Result<Item, JsValue>> {
     messages.values().into_iter().map(|raw| {
         Item::deserialize_compressed(js_sys::Uint8Array::new(&raw.unwrap()).to_vec().as_slice())
-            .map_err(debug_to_js_value)
+            .map_err(|e| {
+                Error::from(e)
+                    .context("Cannot deserialize from bytes")
+                    .into()
+            })
     })
 }
 
@@ -589,17 +695,9 @@ pub fn js_map_to_iter<Item: CanonicalDeserialize>(
         let idx: usize = from_value(arr.get(0))?;
         let msg_bytes: Vec<u8> = from_value(arr.get(1))?;
 
-        let msg = Item::deserialize_compressed(&msg_bytes[..]).map_err(|e| {
-            JsValue::from(&format!(
-                "Cannot deserialize to `ScalarField` due to error: {:?}",
-                e
-            ))
-        })?;
+        let msg = Item::deserialize_compressed(&msg_bytes[..])
+            .map_err(|e| Error::from(e).context("Cannot deserialize to `ScalarField`"))?;
 
         Ok((idx, msg))
     })
 }
-
-pub fn debug_to_js_value<V: core::fmt::Debug>(value: V) -> JsValue {
-    JsValue::from(&format!("{:?}", value))
-}
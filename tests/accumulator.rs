@@ -7,9 +7,11 @@ use dock_crypto_wasm::{
     common::{
         generate_challenge_from_bytes, generate_random_field_element, random_ff, VerifyResponse,
     },
+    native,
     utils::{fr_from_uint8_array, js_array_from_frs},
+    Fr,
 };
-use vb_accumulator::prelude::{PositiveAccumulator, UniversalAccumulator};
+use vb_accumulator::prelude::{Accumulator, PositiveAccumulator, UniversalAccumulator};
 use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
 
@@ -32,7 +34,7 @@ fn get_params_and_keys(
 
 fn get_universal_accum(sk: JsValue, params: js_sys::Uint8Array, max_size: u32) -> JsValue {
     let initial_elements = (0..max_size + 1)
-        .map(|_| random_ff(None))
+        .map(|_| random_ff(None).unwrap())
         .collect::<Vec<_>>();
 
     let initial_fixed = universal_accumulator_fixed_initial_elements().unwrap();
@@ -296,7 +298,7 @@ pub fn universal_accumulator_initialize() {
     let initial_fixed = universal_accumulator_fixed_initial_elements().unwrap();
 
     let initial_elements = (0..max_size + 1)
-        .map(|_| random_ff(None))
+        .map(|_| random_ff(None).unwrap())
         .collect::<Vec<_>>();
 
     let initial_elements_arr = js_array_from_frs(initial_elements.as_slice()).unwrap();
@@ -624,14 +626,18 @@ pub fn positive_accumulator_batch() {
 #[allow(non_snake_case)]
 #[wasm_bindgen_test]
 pub fn universal_accumulator_d() {
-    let non_members = vec![random_ff(None), random_ff(None), random_ff(None)];
+    let non_members = vec![
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+    ];
 
     let members = vec![
-        random_ff(None),
-        random_ff(None),
-        random_ff(None),
-        random_ff(None),
-        random_ff(None),
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
     ];
 
     let d = universal_accumulator_compute_d_for_batch(
@@ -680,7 +686,11 @@ pub fn universal_accumulator_batch() {
 
     let mut accumulator_0 = get_universal_accum(sk.clone(), params.clone(), max_size);
 
-    let non_members = vec![random_ff(None), random_ff(None), random_ff(None)];
+    let non_members = vec![
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+        random_ff(None).unwrap(),
+    ];
     let non_members_array = js_array_from_frs(&non_members).unwrap();
 
     let element_1 = generate_random_field_element(None).unwrap();
@@ -1694,3 +1704,48 @@ pub fn non_membership_proof() {
     assert!(r.verified);
     assert!(r.error.is_none());
 }
+
+#[wasm_bindgen_test]
+fn native_accumulator_membership() {
+    let params = native::accumulator::generate_params(None).unwrap();
+    let keypair = native::accumulator::generate_keypair(&params, None).unwrap();
+    let element = Fr::from(10u64);
+
+    let accum = native::accumulator::positive_accumulator_initialize(&params);
+    let accum =
+        native::accumulator::positive_accumulator_add(&accum, &element, &keypair.secret_key);
+    let witness = native::accumulator::positive_accumulator_membership_witness(
+        &accum,
+        &element,
+        &keypair.secret_key,
+    );
+    assert!(native::accumulator::positive_accumulator_verify_membership(
+        *accum.value(),
+        &element,
+        &witness,
+        &keypair.public_key,
+        &params
+    ));
+    let accum =
+        native::accumulator::positive_accumulator_remove(&accum, &element, &keypair.secret_key);
+    assert!(
+        !native::accumulator::positive_accumulator_verify_membership(
+            *accum.value(),
+            &element,
+            &witness,
+            &keypair.public_key,
+            &params
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn native_accumulator_proof_of_possession() {
+    let ctx = b"did:example:issuer";
+    let params = native::accumulator::generate_params(None).unwrap();
+    let sk = native::accumulator::generate_secret_key(None).unwrap();
+    let pk = native::accumulator::generate_public_key(&sk, &params);
+    let pop = native::accumulator::generate_proof_of_possession(&sk, &params, ctx).unwrap();
+    native::accumulator::verify_proof_of_possession(&pop, &pk, &params, ctx).unwrap();
+    assert!(native::accumulator::verify_proof_of_possession(&pop, &pk, &params, b"").is_err());
+}
@@ -11,9 +11,14 @@ use dock_crypto_wasm::{
         encode_message_for_signing, field_element_as_bytes, field_element_from_number,
         generate_challenge_from_bytes, generate_random_field_element, VerifyResponse,
     },
+    native::{self, from_bytes, schnorr::ProofOfPossession, to_bytes, ErrorCategory, ErrorCode},
     utils::js_array_of_bytearrays_from_vector_of_bytevectors,
+    G2Affine,
 };
 
+mod common;
+use common::gen_encoded_msgs;
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 fn js_value_to_bytes(js_value: JsValue) -> Vec<u8> {
@@ -405,3 +410,39 @@ pub fn bbs_extend_params() {
         true
     );
 }
+
+#[wasm_bindgen_test]
+fn native_bbs_sign_verify() {
+    let msgs = gen_encoded_msgs(5);
+    let params = native::bbs::generate_params(5, Some(b"test")).unwrap();
+    let sk = native::bbs::generate_secret_key(Some(&[1, 2, 3])).unwrap();
+    let pk = native::bbs::generate_public_key(&sk, &params);
+
+    let sig = native::bbs::sign(&msgs, &sk, &params).unwrap();
+    let sig: BBSSignature = from_bytes(&to_bytes(&sig).unwrap()).unwrap();
+    native::bbs::verify(&msgs, &sig, pk.clone(), params.clone()).unwrap();
+
+    let mut wrong = msgs.clone();
+    wrong.swap(0, 1);
+    let err = native::bbs::verify(&wrong, &sig, pk, params).unwrap_err();
+    assert_eq!(err.to_string(), "InvalidSignature");
+    assert_eq!(err.code(), ErrorCode::InvalidSignature);
+    assert_eq!(err.category(), ErrorCategory::VerificationFailed);
+}
+
+#[wasm_bindgen_test]
+fn native_bbs_proof_of_possession() {
+    let ctx = b"did:example:issuer";
+    let params = native::bbs::generate_params(2, None).unwrap();
+    let sk = native::bbs::generate_secret_key(None).unwrap();
+    let pk = native::bbs::generate_public_key(&sk, &params);
+    let pop = native::bbs::generate_proof_of_possession(&sk, &params, ctx).unwrap();
+    let pop: ProofOfPossession<G2Affine> = from_bytes(&to_bytes(&pop).unwrap()).unwrap();
+    native::bbs::verify_proof_of_possession(&pop, &pk, &params, ctx).unwrap();
+    let err = native::bbs::verify_proof_of_possession(&pop, &pk, &params, b"did:example:other")
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let other_pk =
+        native::bbs::generate_public_key(&native::bbs::generate_secret_key(None).unwrap(), &params);
+    assert!(native::bbs::verify_proof_of_possession(&pop, &other_pk, &params, ctx).is_err());
+}
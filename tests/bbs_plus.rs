@@ -1,6 +1,7 @@
 #![cfg(target_arch = "wasm32")]
 extern crate wasm_bindgen_test;
 
+use ark_std::collections::{BTreeMap, BTreeSet};
 use js_sys::Uint8Array;
 use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
@@ -11,9 +12,14 @@ use dock_crypto_wasm::{
         encode_message_for_signing, field_element_as_bytes, field_element_from_number,
         generate_challenge_from_bytes, generate_random_field_element, VerifyResponse,
     },
+    native::{self, from_bytes, to_bytes},
     utils::js_array_of_bytearrays_from_vector_of_bytevectors,
+    Fr,
 };
 
+mod common;
+use common::gen_encoded_msgs;
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 fn js_value_to_bytes(js_value: JsValue) -> Vec<u8> {
@@ -552,3 +558,54 @@ pub fn bbs_extend_params() {
         true
     );
 }
+
+#[wasm_bindgen_test]
+fn native_bbs_plus_blind_sign_and_proof_of_knowledge() {
+    let msgs = gen_encoded_msgs(4);
+    let params = native::bbs_plus::generate_params_g1(4, None).unwrap();
+    let keypair = native::bbs_plus::generate_keypair_g2(&params, None).unwrap();
+
+    let committed = BTreeMap::from([(0, msgs[0]), (1, msgs[1])]);
+    let uncommitted = BTreeMap::from([(2, msgs[2]), (3, msgs[3])]);
+    let blinding = Fr::from(42u64);
+    let commitment =
+        native::bbs_plus::commit_to_messages_in_g1(&committed, &blinding, &params).unwrap();
+    let blind_sig =
+        native::bbs_plus::blind_sign_g1(&commitment, &uncommitted, &keypair.secret_key, &params)
+            .unwrap();
+    let sig: BBSPlusSigG1 = native::bbs_plus::unblind_signature_g1(blind_sig, &blinding);
+    native::bbs_plus::verify_g1(&msgs, &sig, keypair.public_key.clone(), params.clone()).unwrap();
+
+    let revealed = BTreeSet::from([1]);
+    let protocol = native::bbs_plus::initialize_proof_of_knowledge_of_signature(
+        &sig,
+        &params,
+        &msgs,
+        BTreeMap::new(),
+        &revealed,
+    )
+    .unwrap();
+    let challenge = Fr::from(7u64);
+    let proof =
+        native::bbs_plus::generate_proof_of_knowledge_of_signature(protocol, &challenge).unwrap();
+    let proof: BBSPlusPoKOfSigProof = from_bytes(&to_bytes(&proof).unwrap()).unwrap();
+    native::bbs_plus::verify_proof_of_knowledge_of_signature(
+        &proof,
+        &BTreeMap::from([(1, msgs[1])]),
+        &challenge,
+        keypair.public_key.clone(),
+        params,
+    )
+    .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn native_bbs_plus_proof_of_possession() {
+    let ctx = b"did:example:issuer";
+    let params = native::bbs_plus::generate_params_g2(2, None).unwrap();
+    let sk = native::bbs_plus::generate_secret_key(None).unwrap();
+    let pk = native::bbs_plus::generate_public_key_g1(&sk, &params);
+    let pop = native::bbs_plus::generate_proof_of_possession_g1(&sk, &params, ctx).unwrap();
+    native::bbs_plus::verify_proof_of_possession_g1(&pop, &pk, &params, ctx).unwrap();
+    assert!(native::bbs_plus::verify_proof_of_possession_g1(&pop, &pk, &params, b"").is_err());
+}
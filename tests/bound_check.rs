@@ -77,7 +77,7 @@ pub fn bbs_sig_and_bound_check_message() {
         let byte_array = if i == bounded_msg_idx {
             fr_to_uint8_array(&field_element_from_u32(msg)).unwrap()
         } else {
-            let m = random_bytes();
+            let m = random_bytes().unwrap();
             encode_message_for_signing(m).unwrap()
         };
         encoded_msgs.push(byte_array.to_vec());
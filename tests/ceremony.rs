@@ -0,0 +1,164 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use dock_crypto_wasm::native::{self, from_bytes, to_bytes, ErrorCode};
+use wasm_bindgen_test::*;
+
+mod common;
+use common::gen_encoded_msgs;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn snark_setup_ceremony() {
+    use dock_crypto_wasm::native::ceremony::{self, CeremonyTranscript};
+    use proof_system::prelude::generate_snark_srs_bound_check;
+    use saver::{
+        encryption::Encryption,
+        saver_groth16::{prepare_verifying_key, verify_proof},
+    };
+
+    let mut rng = ark_std::test_rng();
+    let initial = generate_snark_srs_bound_check::<ark_bls12_381::Bls12_381, _>(&mut rng).unwrap();
+    let (pk_1, transcript) =
+        ceremony::contribute(&initial, &CeremonyTranscript::default()).unwrap();
+    let (pk_2, transcript) = ceremony::contribute(&pk_1, &transcript).unwrap();
+    let transcript: CeremonyTranscript = from_bytes(&to_bytes(&transcript).unwrap()).unwrap();
+    assert_eq!(transcript.contributions.len(), 2);
+    assert_ne!(pk_2.common.delta_g1, initial.common.delta_g1);
+    ceremony::verify_contribution(&pk_1, &pk_2, &transcript).unwrap();
+    ceremony::verify_ceremony(&initial, &pk_2, &transcript).unwrap();
+
+    // The last contribution isn't from the initial key and keys can't skip a contribution
+    let err = ceremony::verify_contribution(&initial, &pk_2, &transcript).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let err = ceremony::verify_ceremony(&initial, &pk_1, &transcript).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let mut tampered = pk_2.clone();
+    tampered.common.h_query[0] = tampered.common.h_query[1];
+    let err = ceremony::verify_ceremony(&initial, &tampered, &transcript).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let mut reordered = transcript.clone();
+    reordered.contributions.swap(0, 1);
+    assert!(ceremony::verify_ceremony(&initial, &pk_2, &reordered).is_err());
+
+    // Keys for SAVER are created for the final snark key after its ceremony
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let (initial, _, _, _) = native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let (final_pk, transcript) =
+        ceremony::contribute(&initial, &CeremonyTranscript::default()).unwrap();
+    ceremony::verify_ceremony(&initial, &final_pk, &transcript).unwrap();
+    let (sk, ek, dk) = native::saver::rotate_keys(&final_pk, &enc_gens, chunk_bit_size).unwrap();
+    let msg = gen_encoded_msgs(1)[0];
+    let (ct, _, proof) =
+        Encryption::encrypt_with_proof(&mut rng, &msg, &ek, &final_pk, chunk_bit_size).unwrap();
+    verify_proof(&prepare_verifying_key(&final_pk.pk.vk), &proof, &ct).unwrap();
+    let (m, _) = native::saver::decrypt(&ct, &sk, dk, &final_pk.pk.vk, chunk_bit_size).unwrap();
+    assert_eq!(m, msg);
+}
+
+/// Powers of tau for a domain of `2^power` elements from known trapdoors
+fn powers_of_tau(power: u32) -> dock_crypto_wasm::native::powers_of_tau::PowersOfTau {
+    use ark_ec::{CurveGroup, Group};
+    use ark_std::{One, UniformRand};
+    use dock_crypto_wasm::{native::powers_of_tau::PowersOfTau, Fr};
+
+    let mut rng = ark_std::test_rng();
+    let (tau, alpha, beta) = (Fr::rand(&mut rng), Fr::rand(&mut rng), Fr::rand(&mut rng));
+    let size = 1 << power;
+    let powers = |n: usize, s: Fr| {
+        let mut p = s;
+        (0..n)
+            .map(|_| {
+                let q = p;
+                p *= tau;
+                q
+            })
+            .collect::<Vec<_>>()
+    };
+    let (g1, g2) = (
+        ark_bls12_381::G1Projective::generator(),
+        ark_bls12_381::G2Projective::generator(),
+    );
+    let in_g1 = |s: Vec<Fr>| s.iter().map(|s| (g1 * s).into_affine()).collect();
+    PowersOfTau {
+        tau_g1: in_g1(powers(2 * size - 1, Fr::one())),
+        tau_g2: powers(size, Fr::one())
+            .iter()
+            .map(|s| (g2 * s).into_affine())
+            .collect(),
+        alpha_tau_g1: in_g1(powers(size, alpha)),
+        beta_tau_g1: in_g1(powers(size, beta)),
+        beta_g2: (g2 * beta).into_affine(),
+    }
+}
+
+#[wasm_bindgen_test]
+fn snark_setup_ceremony_from_powers_of_tau() {
+    use ark_std::{One, UniformRand};
+    use dock_crypto_wasm::{
+        native::{
+            ceremony::{self, CeremonyTranscript},
+            powers_of_tau::{self, PowersOfTau},
+            r1cs,
+        },
+        Fr,
+    };
+    use legogroth16::{
+        circom::CircomCircuit, create_random_proof, prepare_verifying_key, verify_proof,
+        verify_witness_commitment,
+    };
+
+    let mut rng = ark_std::test_rng();
+    let ptau = powers_of_tau(3);
+    ptau.verify().unwrap();
+    let ptau_bytes = ptau.write().unwrap();
+    assert_eq!(PowersOfTau::read(&ptau_bytes, 8).unwrap(), ptau);
+    // A file for a larger domain can be used for a smaller one
+    let smaller = PowersOfTau::read(&ptau_bytes, 4).unwrap();
+    assert_eq!(smaller.tau_g1[..], ptau.tau_g1[..7]);
+    smaller.verify().unwrap();
+    let err = PowersOfTau::read(&ptau_bytes, 16).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+    let mut tampered = ptau.clone();
+    tampered.alpha_tau_g1.swap(1, 2);
+    let err = tampered.verify().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    // Anyone creates the same initial key from the powers of tau
+    let r1cs = r1cs::read_r1cs(include_bytes!("circom/multiply2.r1cs")).unwrap();
+    let initial = powers_of_tau::r1cs_proving_key(r1cs.clone(), 1, &ptau_bytes).unwrap();
+    assert_eq!(
+        powers_of_tau::r1cs_proving_key(r1cs.clone(), 1, &ptau_bytes).unwrap(),
+        initial
+    );
+    let err =
+        powers_of_tau::r1cs_proving_key(r1cs.clone(), 1, &tampered.write().unwrap()).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    let (pk_1, transcript) =
+        ceremony::contribute(&initial, &CeremonyTranscript::default()).unwrap();
+    let (pk_2, transcript) = ceremony::contribute(&pk_1, &transcript).unwrap();
+    assert_ne!(pk_2.vk.gamma_g2, initial.vk.gamma_g2);
+    assert_ne!(pk_2.vk.eta_gamma_inv_g1, pk_1.vk.eta_gamma_inv_g1);
+    ceremony::verify_contribution(&pk_1, &pk_2, &transcript).unwrap();
+    ceremony::verify_ceremony(&initial, &pk_2, &transcript).unwrap();
+
+    // A contribution can't change gamma without updating the elements divided by it
+    let mut tampered = pk_2.clone();
+    tampered.vk.gamma_abc_g1.swap(0, 1);
+    let err = ceremony::verify_ceremony(&initial, &tampered, &transcript).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    // a * b = c where c is public and a is committed
+    let (a, b) = (Fr::from(1010u64), Fr::from(1030u64));
+    for pk in [&initial, &pk_2] {
+        let mut circuit = CircomCircuit::setup(r1cs.clone());
+        circuit.set_wires(vec![Fr::one(), a * b, a, b]);
+        let v = Fr::rand(&mut rng);
+        let proof = create_random_proof(circuit, v, pk, &mut rng).unwrap();
+        verify_proof(&prepare_verifying_key(&pk.vk), &proof, &[a * b]).unwrap();
+        verify_witness_commitment(&pk.vk, &proof, 1, &[a], &v).unwrap();
+    }
+}
@@ -10,7 +10,9 @@ use dock_crypto_wasm::{
     },
     common::random_ff,
     composite_proof_system::statement::generate_witness_equality_meta_statement,
+    native::messages_to_field_elements,
     utils::{js_array_from_frs, random_bytes},
+    Fr,
 };
 use js_sys::Uint8Array;
 use wasm_bindgen::JsValue;
@@ -23,7 +25,14 @@ pub fn bbs_params_and_keys(message_count: u32) -> (JsValue, Uint8Array, Uint8Arr
 }
 
 pub fn gen_msgs(count: u32) -> Vec<Vec<u8>> {
-    (0..count).map(|_| random_bytes()).collect::<Vec<Vec<u8>>>()
+    (0..count)
+        .map(|_| random_bytes().unwrap())
+        .collect::<Vec<Vec<u8>>>()
+}
+
+/// Random messages encoded for signing as field elements like the native API takes them
+pub fn gen_encoded_msgs(count: u32) -> Vec<Fr> {
+    messages_to_field_elements(&gen_msgs(count), true).unwrap()
 }
 
 pub fn get_revealed_unrevealed(
@@ -68,7 +77,7 @@ pub fn accum_params_and_keys() -> (Uint8Array, JsValue, Uint8Array) {
 
 pub fn get_universal_accum(sk: JsValue, params: Uint8Array, max_size: u32) -> JsValue {
     let initial_elements = (0..max_size + 1)
-        .map(|_| random_ff(None))
+        .map(|_| random_ff(None).unwrap())
         .collect::<Vec<_>>();
 
     let initial_fixed = universal_accumulator_fixed_initial_elements().unwrap();
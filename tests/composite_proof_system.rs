@@ -4,9 +4,12 @@ extern crate wasm_bindgen_test;
 use web_sys::console;
 
 use ark_bls12_381::Bls12_381;
-use ark_ec::pairing::Pairing;
+use ark_ec::{pairing::Pairing, CurveGroup};
 use ark_serialize::CanonicalDeserialize;
-use ark_std::collections::BTreeSet;
+use ark_std::{
+    collections::{BTreeMap, BTreeSet},
+    UniformRand,
+};
 use dock_crypto_wasm::{
     accumulator::{
         accumulator_derive_membership_proving_key_from_non_membership_key,
@@ -33,24 +36,32 @@ use dock_crypto_wasm::{
         generate_composite_proof_g1, generate_composite_proof_g2,
         generate_pedersen_commitment_witness, generate_pok_bbs_plus_sig_witness,
         generate_pok_bbs_sig_witness, generate_proof_spec_g1, generate_proof_spec_g2,
+        interactive::{self, InteractiveProverState},
+        or_statement::{OrMetaStatement, OrWitnessEquality, ProofSpecWithOrG1},
         setup_params::{
             generate_setup_param_for_vb_accumulator_mem_proving_key,
             generate_setup_param_for_vb_accumulator_non_mem_proving_key,
             generate_setup_param_for_vb_accumulator_params,
             generate_setup_param_for_vb_accumulator_public_key,
         },
-        verify_composite_proof_g1, verify_composite_proof_g2, Witness,
+        verify_composite_proof_g1, verify_composite_proof_g2, ProofSpec, SecretWitnesses, Witness,
+        Witnesses,
     },
+    native,
     utils::{
         encode_messages_as_js_map_to_fr_btreemap, fr_from_jsvalue,
         js_array_of_bytearrays_from_vector_of_bytevectors, random_bytes,
     },
+    Fr, G1Affine,
+};
+use proof_system::{
+    prelude::{EqualWitnesses, MetaStatement, MetaStatements, Statements},
+    statement, witness,
 };
-use proof_system::statement;
 mod common;
 use common::{
-    accum_params_and_keys, bbs_params_and_keys, gen_msgs, get_revealed_unrevealed,
-    get_universal_accum, get_witness_equality_statement,
+    accum_params_and_keys, bbs_params_and_keys, gen_encoded_msgs, gen_msgs,
+    get_revealed_unrevealed, get_universal_accum, get_witness_equality_statement,
 };
 use dock_crypto_wasm::composite_proof_system::statement::{
     generate_accumulator_membership_statement,
@@ -196,8 +207,15 @@ pub fn three_bbs_plus_sigs_and_msg_equality() {
 
     let context = Some("test-context".as_bytes().to_vec());
 
-    let proof_spec =
-        generate_proof_spec_g1(statements, meta_statements, js_sys::Array::new(), context).unwrap();
+    let proof_spec = generate_proof_spec_g1(
+        statements,
+        meta_statements,
+        js_sys::Array::new(),
+        context,
+        None,
+        None,
+    )
+    .unwrap();
 
     let witness_1 = generate_pok_bbs_plus_sig_witness(sig_1, unrevealed_msgs_1, true).unwrap();
     let witness_2 = generate_pok_bbs_plus_sig_witness(sig_2, unrevealed_msgs_2, true).unwrap();
@@ -245,7 +263,7 @@ pub fn bbs_plus_sig_and_accumulator() {
         let (params_1, sk_1, pk_1) = bbs_params_and_keys(msg_count_1);
         let mut msgs_1 = vec![];
         for _ in 0..msg_count_1 - 2 {
-            let m = random_bytes();
+            let m = random_bytes().unwrap();
             let bytes = encode_message_for_signing(m).unwrap();
             msgs_1.push(bytes.to_vec());
         }
@@ -260,7 +278,7 @@ pub fn bbs_plus_sig_and_accumulator() {
         let (params_2, sk_2, pk_2) = bbs_params_and_keys(msg_count_2);
         let mut msgs_2 = vec![];
         for _ in 0..msg_count_2 as usize - 2 {
-            let m = random_bytes();
+            let m = random_bytes().unwrap();
             let bytes = encode_message_for_signing(m).unwrap();
             msgs_2.push(bytes.to_vec());
         }
@@ -574,8 +592,15 @@ pub fn bbs_plus_sig_and_accumulator() {
 
         let context = Some("test-context".as_bytes().to_vec());
 
-        let proof_spec =
-            generate_proof_spec_g1(statements, meta_statements, setup_params, context).unwrap();
+        let proof_spec = generate_proof_spec_g1(
+            statements,
+            meta_statements,
+            setup_params,
+            context,
+            None,
+            None,
+        )
+        .unwrap();
 
         let witness_1 =
             generate_pok_bbs_plus_sig_witness(sig_1, unrevealed_msgs_1.clone(), false).unwrap();
@@ -649,7 +674,7 @@ pub fn bbs_sig_and_accumulator() {
         let (params_1, sk_1, pk_1) = bbs_params_and_keys(msg_count_1);
         let mut msgs_1 = vec![];
         for _ in 0..msg_count_1 - 2 {
-            let m = random_bytes();
+            let m = random_bytes().unwrap();
             let bytes = encode_message_for_signing(m).unwrap();
             msgs_1.push(bytes.to_vec());
         }
@@ -664,7 +689,7 @@ pub fn bbs_sig_and_accumulator() {
         let (params_2, sk_2, pk_2) = bbs_params_and_keys(msg_count_2);
         let mut msgs_2 = vec![];
         for _ in 0..msg_count_2 as usize - 2 {
-            let m = random_bytes();
+            let m = random_bytes().unwrap();
             let bytes = encode_message_for_signing(m).unwrap();
             msgs_2.push(bytes.to_vec());
         }
@@ -962,8 +987,15 @@ pub fn bbs_sig_and_accumulator() {
 
         let context = Some("test-context".as_bytes().to_vec());
 
-        let proof_spec =
-            generate_proof_spec_g1(statements, meta_statements, setup_params, context).unwrap();
+        let proof_spec = generate_proof_spec_g1(
+            statements,
+            meta_statements,
+            setup_params,
+            context,
+            None,
+            None,
+        )
+        .unwrap();
 
         let witness_1 =
             generate_pok_bbs_sig_witness(sig_1, unrevealed_msgs_1.clone(), false).unwrap();
@@ -1072,6 +1104,8 @@ pub fn request_blind_bbs_sig() {
         Default::default(),
         js_sys::Array::new(),
         context,
+        None,
+        None,
     )
     .unwrap();
 
@@ -1162,8 +1196,15 @@ pub fn request_blind_bbs_plus_sig() {
 
     let context = Some("test-context".as_bytes().to_vec());
 
-    let proof_spec =
-        generate_proof_spec_g1(statements, meta_statements, js_sys::Array::new(), context).unwrap();
+    let proof_spec = generate_proof_spec_g1(
+        statements,
+        meta_statements,
+        js_sys::Array::new(),
+        context,
+        None,
+        None,
+    )
+    .unwrap();
 
     let witness_1 = generate_pok_bbs_plus_sig_witness(sig_1, unrevealed_msgs_1, true).unwrap();
 
@@ -1231,8 +1272,15 @@ pub fn pedersen_commitment_opening_equality() {
     meta_statements.push(&get_witness_equality_statement(vec![(0, 0), (1, 0)]));
     meta_statements.push(&get_witness_equality_statement(vec![(0, 1), (1, 1)]));
 
-    let proof_spec =
-        generate_proof_spec_g1(statements, meta_statements, js_sys::Array::new(), None).unwrap();
+    let proof_spec = generate_proof_spec_g1(
+        statements,
+        meta_statements,
+        js_sys::Array::new(),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
 
     let witnesses = js_sys::Array::new();
     witnesses.push(&generate_pedersen_commitment_witness(msgs_1.clone()).unwrap());
@@ -1278,3 +1326,217 @@ pub fn pedersen_commitment_opening_equality() {
     let r: VerifyResponse = serde_wasm_bindgen::from_value(result).unwrap();
     r.validate();
 }
+
+#[wasm_bindgen_test]
+pub fn or_meta_statement_over_bbs_plus_and_bbs_sigs() {
+    let mut rng = ark_std::test_rng();
+
+    // Holder has either a BBS+ signature from one issuer or a BBS signature from another, each on 4 messages
+    let msgs = gen_encoded_msgs(4);
+    let bbs_plus_params = native::bbs_plus::generate_params_g1(4, None).unwrap();
+    let bbs_plus_keypair = native::bbs_plus::generate_keypair_g2(&bbs_plus_params, None).unwrap();
+    let bbs_plus_sig =
+        native::bbs_plus::sign_g1(&msgs, &bbs_plus_keypair.secret_key, &bbs_plus_params).unwrap();
+    let bbs_params = native::bbs::generate_params(4, None).unwrap();
+    let bbs_keypair = native::bbs::generate_keypair(&bbs_params, None).unwrap();
+    let bbs_sig = native::bbs::sign(&msgs, &bbs_keypair.secret_key, &bbs_params).unwrap();
+
+    // Message 0 is revealed and message 1 is committed to with the same bases in both proof specs
+    let revealed = BTreeMap::from([(0, msgs[0])]);
+    let unrevealed = (1..4).map(|i| (i, msgs[i])).collect::<BTreeMap<_, _>>();
+    let bases = vec![G1Affine::rand(&mut rng), G1Affine::rand(&mut rng)];
+    let blinding = Fr::rand(&mut rng);
+    let commitment = (bases[0] * msgs[1] + bases[1] * blinding).into_affine();
+    let mut meta_statements = MetaStatements::new();
+    meta_statements.add(MetaStatement::WitnessEquality(EqualWitnesses(
+        vec![(0, 1), (1, 0)].into_iter().collect::<BTreeSet<_>>(),
+    )));
+
+    let mut statements_1 = Statements::new();
+    statements_1.add(
+        statement::bbs_plus::PoKBBSSignatureG1::new_statement_from_params::<G1Affine>(
+            bbs_plus_params.clone(),
+            bbs_plus_keypair.public_key.clone(),
+            revealed.clone(),
+        ),
+    );
+    statements_1.add(
+        statement::ped_comm::PedersenCommitment::new_statement_from_params::<Bls12_381>(
+            bases.clone(),
+            commitment,
+        ),
+    );
+    let mut statements_2 = Statements::new();
+    statements_2.add(
+        statement::bbs_23::PoKBBSSignature23G1::new_statement_from_params::<G1Affine>(
+            bbs_params.clone(),
+            bbs_keypair.public_key.clone(),
+            revealed,
+        ),
+    );
+    statements_2.add(
+        statement::ped_comm::PedersenCommitment::new_statement_from_params::<Bls12_381>(
+            bases, commitment,
+        ),
+    );
+    let meta_statement = OrMetaStatement {
+        proof_specs: vec![
+            ProofSpec::new(statements_1, meta_statements.clone(), vec![], None),
+            ProofSpec::new(statements_2, meta_statements, vec![], None),
+        ],
+        public_keys_g1: vec![
+            BTreeMap::from([(
+                0,
+                (bbs_plus_params.g1 * bbs_plus_keypair.secret_key.0).into_affine(),
+            )]),
+            BTreeMap::from([(0, (bbs_params.g1 * bbs_keypair.secret_key.0).into_affine())]),
+        ],
+    };
+    meta_statement.validate().unwrap();
+
+    let witnesses = |bbs_plus: bool| {
+        let mut w = Witnesses::new();
+        if bbs_plus {
+            w.add(witness::PoKBBSSignatureG1::new_as_witness(
+                bbs_plus_sig.clone(),
+                unrevealed.clone(),
+            ));
+        } else {
+            w.add(witness::PoKBBSSignature23G1::new_as_witness(
+                bbs_sig.clone(),
+                unrevealed.clone(),
+            ));
+        }
+        w.add(Witness::PedersenCommitment(vec![msgs[1], blinding]));
+        SecretWitnesses(w)
+    };
+    let nonce = Some(b"test-nonce".to_vec());
+    for (index, bbs_plus) in [(0, true), (1, false)] {
+        let proof = meta_statement
+            .prove(&mut rng, index, witnesses(bbs_plus), &nonce)
+            .unwrap();
+        meta_statement.verify(&proof, &nonce).unwrap();
+        assert!(meta_statement.verify(&proof, &None).is_err());
+    }
+
+    // Witnesses of one proof spec don't satisfy the other
+    assert!(meta_statement
+        .prove(&mut rng, 0, witnesses(false), &nonce)
+        .is_err());
+
+    // The key in G1 must be of the signer
+    let mut wrong_key = meta_statement.clone();
+    wrong_key.public_keys_g1[1].insert(0, G1Affine::rand(&mut rng));
+    assert!(wrong_key.validate().is_err());
+
+    // Holder also proves that message 1 is the one committed to in the composite proof
+    let composite_bases = vec![G1Affine::rand(&mut rng), G1Affine::rand(&mut rng)];
+    let composite_proof_spec = |value: Fr| {
+        let mut statements = Statements::new();
+        statements.add(
+            statement::ped_comm::PedersenCommitment::new_statement_from_params::<Bls12_381>(
+                composite_bases.clone(),
+                (composite_bases[0] * value + composite_bases[1] * blinding).into_affine(),
+            ),
+        );
+        ProofSpecWithOrG1 {
+            proof_spec: ProofSpec::new(statements, MetaStatements::new(), vec![], None),
+            or_meta_statements: vec![meta_statement.clone()],
+            or_witness_equalities: vec![OrWitnessEquality {
+                witness_ref: (0, 0),
+                or_index: 0,
+                branch_witness_refs: vec![(0, 1), (0, 1)],
+            }],
+        }
+    };
+    let composite_witnesses = |value: Fr| {
+        let mut w = Witnesses::new();
+        w.add(Witness::PedersenCommitment(vec![value, blinding]));
+        SecretWitnesses(w)
+    };
+    let proof_spec = composite_proof_spec(msgs[1]);
+    let proof = proof_spec
+        .prove(
+            &mut rng,
+            composite_witnesses(msgs[1]),
+            &[1],
+            vec![witnesses(false)],
+            nonce.clone(),
+        )
+        .unwrap();
+    proof_spec.verify(proof.clone(), nonce.clone()).unwrap();
+    assert!(proof_spec.verify(proof.clone(), None).is_err());
+
+    // The witness of the composite proof must be the one of the "OR" meta-statement
+    let other = Fr::rand(&mut rng);
+    assert!(composite_proof_spec(other)
+        .prove(
+            &mut rng,
+            composite_witnesses(other),
+            &[1],
+            vec![witnesses(false)],
+            nonce.clone(),
+        )
+        .is_err());
+
+    // The proof for the "OR" meta-statement can't be used with another composite proof
+    let mut other_proof = proof_spec
+        .prove(
+            &mut rng,
+            composite_witnesses(msgs[1]),
+            &[0],
+            vec![witnesses(true)],
+            nonce.clone(),
+        )
+        .unwrap();
+    proof_spec
+        .verify(other_proof.clone(), nonce.clone())
+        .unwrap();
+    other_proof.or_proofs = proof.or_proofs;
+    assert!(proof_spec.verify(other_proof, nonce.clone()).is_err());
+
+    // Each proof spec of the "OR" meta-statement needs a witness in the equality
+    let mut invalid = composite_proof_spec(msgs[1]);
+    invalid.or_witness_equalities[0].branch_witness_refs.pop();
+    assert!(invalid.validate().is_err());
+}
+
+#[wasm_bindgen_test]
+pub fn interactive_prover_state_responds_once() {
+    let mut rng = ark_std::test_rng();
+
+    let bases = vec![G1Affine::rand(&mut rng), G1Affine::rand(&mut rng)];
+    let wits = vec![Fr::rand(&mut rng), Fr::rand(&mut rng)];
+    let commitment = (bases[0] * wits[0] + bases[1] * wits[1]).into_affine();
+    let mut statements = Statements::new();
+    statements.add(
+        statement::ped_comm::PedersenCommitment::new_statement_from_params::<Bls12_381>(
+            bases, commitment,
+        ),
+    );
+    let proof_spec = ProofSpec::new(statements, MetaStatements::new(), vec![], None);
+    let mut witnesses = Witnesses::new();
+    witnesses.add(Witness::PedersenCommitment(wits));
+
+    let mut state =
+        InteractiveProverState::new(proof_spec.clone(), SecretWitnesses(witnesses)).unwrap();
+    let commitments = state.commitments().unwrap();
+    assert_eq!(state.commitments().unwrap(), commitments);
+
+    // State is kept serialized between sending the commitments and responding
+    let bytes = state.serialize().unwrap();
+    let mut restored = InteractiveProverState::deserialize(&bytes).unwrap();
+    assert_eq!(restored.commitments().unwrap(), commitments);
+    let challenge = Fr::rand(&mut rng);
+    let proof = restored.respond(&challenge).unwrap();
+    interactive::verify(&proof, &proof_spec, &commitments, &challenge).unwrap();
+    assert!(restored.serialize().is_err());
+    assert!(InteractiveProverState::deserialize(&bytes[1..]).is_err());
+
+    let proof = state.respond(&challenge).unwrap();
+    interactive::verify(&proof, &proof_spec, &commitments, &challenge).unwrap();
+
+    // Responding to a second challenge would reveal the witnesses
+    assert!(state.respond(&Fr::rand(&mut rng)).is_err());
+    assert!(state.commitments().is_err());
+}
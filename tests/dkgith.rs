@@ -0,0 +1,138 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use ark_bls12_381::Bls12_381;
+use dock_crypto_wasm::{
+    composite_proof_system::{ProofSpec, Witness, Witnesses},
+    native::{self, composite_proof_system::DkgithEncryption, from_bytes, to_bytes, ErrorCode},
+    Fr,
+};
+use proof_system::{
+    prelude::{MetaStatements, SetupParams, Statements},
+    statement::ped_comm::PedersenCommitment,
+};
+use wasm_bindgen_test::*;
+
+mod common;
+use common::gen_encoded_msgs;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn dkgith_verifiable_encryption() {
+    use dock_crypto_wasm::native::dkgith::DkgithProof;
+
+    let params = native::dkgith::generate_params(Some(b"test")).unwrap();
+    let sk = native::dkgith::generate_secret_key(None).unwrap();
+    let pk = native::dkgith::generate_public_key(&sk, &params);
+    let msg = gen_encoded_msgs(1)[0];
+
+    let (commitment, blinding, proof) =
+        native::dkgith::encrypt(&msg, &params, &pk, b"nonce").unwrap();
+    let proof: DkgithProof = from_bytes(&to_bytes(&proof).unwrap()).unwrap();
+    native::dkgith::verify(&proof, &commitment, &params, &pk, b"nonce").unwrap();
+
+    let err = native::dkgith::verify(&proof, &commitment, &params, &pk, b"other").unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let mut tampered = proof.clone();
+    tampered.repetitions[1].hidden[0].message += Fr::from(1u64);
+    let err = native::dkgith::verify(&tampered, &commitment, &params, &pk, b"nonce").unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    let (decrypted, decrypted_blinding) =
+        native::dkgith::decrypt(&proof, &commitment, &sk, &params).unwrap();
+    assert_eq!(decrypted, msg);
+    assert_eq!(decrypted_blinding, blinding);
+    native::dkgith::verify_decryption(&commitment, &decrypted, &decrypted_blinding, &params)
+        .unwrap();
+    let err =
+        native::dkgith::verify_decryption(&commitment, &msg, &Fr::from(1u64), &params).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidDecryption);
+
+    let other_sk = native::dkgith::generate_secret_key(None).unwrap();
+    let err = native::dkgith::decrypt(&proof, &commitment, &other_sk, &params).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidDecryption);
+}
+
+#[wasm_bindgen_test]
+fn dkgith_with_composite_proof() {
+    let params = native::dkgith::generate_params(Some(b"test")).unwrap();
+    let sk = native::dkgith::generate_secret_key(None).unwrap();
+    let pk = native::dkgith::generate_public_key(&sk, &params);
+    let msg = gen_encoded_msgs(1)[0];
+    let nonce = b"nonce".to_vec();
+
+    let (commitment, blinding, proof) =
+        native::dkgith::encrypt(&msg, &params, &pk, &nonce).unwrap();
+    let mut statements = Statements::new();
+    statements.add(PedersenCommitment::new_statement_from_params_refs::<
+        Bls12_381,
+    >(0, commitment));
+    let proof_spec = ProofSpec::new(
+        statements,
+        MetaStatements::new(),
+        vec![SetupParams::PedersenCommitmentKey(vec![
+            params.g_m, params.h,
+        ])],
+        None,
+    );
+    let mut witnesses = Witnesses::new();
+    witnesses.add(Witness::PedersenCommitment(vec![msg, blinding]));
+    let (composite_proof, _) = native::composite_proof_system::generate_proof(
+        proof_spec.clone(),
+        witnesses,
+        Some(nonce.clone()),
+    )
+    .unwrap();
+
+    let verify = |statement_index, proof: &native::dkgith::DkgithProof, nonce: &[u8]| {
+        native::composite_proof_system::verify_proof_with_dkgith(
+            composite_proof.clone(),
+            proof_spec.clone(),
+            Some(nonce.to_vec()),
+            &[DkgithEncryption {
+                statement_index,
+                proof: proof.clone(),
+                params: params.clone(),
+                public_key: pk.clone(),
+            }],
+        )
+    };
+    verify(0, &proof, &nonce).unwrap();
+    assert_eq!(
+        verify(1, &proof, &nonce).unwrap_err().code(),
+        ErrorCode::IndexOutOfBounds
+    );
+    assert!(verify(0, &proof, b"other").is_err());
+
+    // A proof made with another nonce or for another commitment doesn't verify with this composite proof
+    let (_, _, other_nonce_proof) = native::dkgith::encrypt(&msg, &params, &pk, b"other").unwrap();
+    assert_eq!(
+        verify(0, &other_nonce_proof, &nonce).unwrap_err().code(),
+        ErrorCode::InvalidProof
+    );
+    let (_, _, other_commitment_proof) =
+        native::dkgith::encrypt(&msg, &params, &pk, &nonce).unwrap();
+    assert_eq!(
+        verify(0, &other_commitment_proof, &nonce)
+            .unwrap_err()
+            .code(),
+        ErrorCode::InvalidProof
+    );
+
+    // The statement must have the commitment key of the params
+    let other_params = native::dkgith::generate_params(Some(b"other")).unwrap();
+    let err = native::composite_proof_system::verify_proof_with_dkgith(
+        composite_proof,
+        proof_spec,
+        Some(nonce),
+        &[DkgithEncryption {
+            statement_index: 0,
+            proof,
+            params: other_params,
+            public_key: pk,
+        }],
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+}
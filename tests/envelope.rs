@@ -0,0 +1,229 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use dock_crypto_wasm::native::{
+    self,
+    envelope::{self, ArtifactType},
+    to_bytes, ErrorCode,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn envelope_type_check() {
+    let params = native::bbs::generate_params(2, None).unwrap();
+    let sk = native::bbs::generate_secret_key(None).unwrap();
+    let pk = to_bytes(&native::bbs::generate_public_key(&sk, &params)).unwrap();
+
+    let wrapped = envelope::wrap(&pk, ArtifactType::BBSPublicKey, true);
+    let (header, payload) = envelope::inspect(&wrapped).unwrap();
+    assert_eq!(header.artifact_type, ArtifactType::BBSPublicKey);
+    assert_eq!(payload, pk.as_slice());
+
+    // Without envelope input, bytes are never treated as an envelope
+    assert!(!envelope::envelope_input());
+    assert_eq!(
+        envelope::open(&wrapped, Some("AccumPk"), true).unwrap(),
+        wrapped.as_slice()
+    );
+
+    envelope::set_envelope_input(true);
+    // Raw bytes are passed through and a BBS key can be used as a BBS+ key
+    assert_eq!(
+        envelope::open(&pk, Some("AccumPk"), true).unwrap(),
+        pk.as_slice()
+    );
+    assert!(envelope::open(&wrapped, Some("BBSPlusPublicKeyG2"), true).is_ok());
+
+    let err = envelope::open(&wrapped, Some("AccumPk"), true).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ArtifactTypeMismatch);
+    let err = envelope::open(&wrapped, Some("BBSPublicKey"), false).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidEnvelope);
+    envelope::set_envelope_input(false);
+}
+
+#[wasm_bindgen_test]
+fn envelope_names() {
+    for (name, artifact_type) in [
+        ("Fr", ArtifactType::FieldElement),
+        ("G2Affine", ArtifactType::G2Element),
+        ("SaverVerifierStatement", ArtifactType::Statement),
+        ("PedCommG1Stmt", ArtifactType::Statement),
+        ("OrMetaStatement", ArtifactType::OrMetaStatement),
+        ("EncryptionGenerators", ArtifactType::SaverEncryptionGens),
+    ] {
+        assert_eq!(ArtifactType::from_name(name), Some(artifact_type));
+    }
+    // Names are only matched exactly
+    for name in ["PublicKey", "SomeStmt", "UnknownStatement"] {
+        assert_eq!(ArtifactType::from_name(name), None);
+    }
+}
+
+/// Reader returning at most `chunk_size` bytes at a time like a stream
+struct ChunkedReader<'a> {
+    bytes: &'a [u8],
+    chunk_size: usize,
+}
+
+impl<'a> ark_serialize::Read for ChunkedReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> ark_std::io::Result<usize> {
+        let n = buf.len().min(self.chunk_size).min(self.bytes.len());
+        buf[..n].copy_from_slice(&self.bytes[..n]);
+        self.bytes = &self.bytes[n..];
+        Ok(n)
+    }
+}
+
+#[wasm_bindgen_test]
+fn streaming_key_deserialization() {
+    use ark_serialize::CanonicalSerialize;
+    type LegoProvingKey = legogroth16::ProvingKey<ark_bls12_381::Bls12_381>;
+
+    let mut rng = ark_std::test_rng();
+    let pk = proof_system::prelude::generate_snark_srs_bound_check::<ark_bls12_381::Bls12_381, _>(
+        &mut rng,
+    )
+    .unwrap();
+    let mut bytes = vec![];
+    pk.serialize_uncompressed(&mut bytes).unwrap();
+    let wrapped = envelope::wrap(&bytes, ArtifactType::LegoProvingKey, false);
+
+    // Without envelope input, the envelope is read as part of the key
+    let reader = ChunkedReader {
+        bytes: &wrapped,
+        chunk_size: 100,
+    };
+    assert!(envelope::deserialize_from_reader::<LegoProvingKey, _>(
+        reader,
+        "LegoProvingKey",
+        false
+    )
+    .is_err());
+
+    envelope::set_envelope_input(true);
+    // Chunks smaller than the header and ones that split it
+    for chunk_size in [1, 4, 7, 1000] {
+        for b in [&bytes, &wrapped] {
+            let reader = ChunkedReader {
+                bytes: b,
+                chunk_size,
+            };
+            let key: LegoProvingKey =
+                envelope::deserialize_from_reader(reader, "LegoProvingKey", false).unwrap();
+            assert_eq!(key, pk);
+        }
+    }
+
+    let reader = |bytes| ChunkedReader {
+        bytes,
+        chunk_size: 100,
+    };
+    let err = envelope::deserialize_from_reader::<LegoProvingKey, _>(
+        reader(&wrapped),
+        "SaverSnarkPk",
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ArtifactTypeMismatch);
+    let err = envelope::deserialize_from_reader::<LegoProvingKey, _>(
+        reader(&wrapped),
+        "LegoProvingKey",
+        true,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidEnvelope);
+    let err = envelope::deserialize_from_reader::<LegoProvingKey, _>(
+        reader(&bytes[..bytes.len() - 1]),
+        "LegoProvingKey",
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::DeserializationFailed);
+    assert!(envelope::deserialize_from_reader::<LegoProvingKey, _>(
+        reader(&wrapped[..5]),
+        "LegoProvingKey",
+        false
+    )
+    .is_err());
+    envelope::set_envelope_input(false);
+}
+
+#[wasm_bindgen_test]
+fn proving_key_loader() {
+    use ark_ec::AffineRepr;
+    use ark_serialize::CanonicalSerialize;
+    use dock_crypto_wasm::{native::key_loader::ProvingKeyLoader, saver::SaverSnarkPk, G2Affine};
+    type LegoProvingKey = legogroth16::ProvingKey<ark_bls12_381::Bls12_381>;
+
+    let lego_pk = proof_system::prelude::generate_snark_srs_bound_check::<
+        ark_bls12_381::Bls12_381,
+        _,
+    >(&mut ark_std::test_rng())
+    .unwrap();
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let (saver_pk, ..) = native::saver::decryptor_setup(4, &enc_gens).unwrap();
+
+    fn load<K: native::key_loader::LoadableKey>(
+        bytes: &[u8],
+        chunk_size: usize,
+        compressed: bool,
+        proving_only: bool,
+    ) -> native::Result<K> {
+        let mut loader = ProvingKeyLoader::<K>::new(compressed, proving_only);
+        for chunk in bytes.chunks(chunk_size) {
+            loader.push(chunk)?;
+        }
+        loader.finish()
+    }
+
+    for compressed in [true, false] {
+        let mut lego_bytes = vec![];
+        let mut saver_bytes = vec![];
+        if compressed {
+            lego_pk.serialize_compressed(&mut lego_bytes).unwrap();
+            saver_pk.serialize_compressed(&mut saver_bytes).unwrap();
+        } else {
+            lego_pk.serialize_uncompressed(&mut lego_bytes).unwrap();
+            saver_pk.serialize_uncompressed(&mut saver_bytes).unwrap();
+        }
+        // Chunks that split elements and vector lengths
+        let key: LegoProvingKey = load(&lego_bytes, 7, compressed, false).unwrap();
+        assert_eq!(key, lego_pk);
+        let key: SaverSnarkPk = load(&saver_bytes, 7, compressed, false).unwrap();
+        assert_eq!(key, saver_pk);
+
+        // Only the elements proving doesn't need are left out
+        let mut expected = lego_pk.clone();
+        expected.vk.gamma_g2 = G2Affine::zero();
+        let key: LegoProvingKey = load(&lego_bytes, 100, compressed, true).unwrap();
+        assert_eq!(key, expected);
+        let mut expected = saver_pk.clone();
+        expected.pk.vk.gamma_g2 = G2Affine::zero();
+        let key: SaverSnarkPk = load(&saver_bytes, 100, compressed, true).unwrap();
+        assert_eq!(key, expected);
+
+        let err =
+            load::<LegoProvingKey>(&lego_bytes[..lego_bytes.len() - 1], 100, compressed, false)
+                .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::DeserializationFailed);
+        let mut extra = lego_bytes.clone();
+        extra.push(0);
+        let err = load::<LegoProvingKey>(&extra, 100, compressed, false).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::DeserializationFailed);
+    }
+
+    let mut bytes = vec![];
+    lego_pk.serialize_uncompressed(&mut bytes).unwrap();
+    let wrapped = envelope::wrap(&bytes, ArtifactType::LegoProvingKey, false);
+    assert!(load::<LegoProvingKey>(&wrapped, 100, false, false).is_err());
+    envelope::set_envelope_input(true);
+    for b in [&bytes, &wrapped] {
+        let key: LegoProvingKey = load(b, 4, false, false).unwrap();
+        assert_eq!(key, lego_pk);
+    }
+    let err = load::<SaverSnarkPk>(&wrapped, 100, false, false).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ArtifactTypeMismatch);
+    envelope::set_envelope_input(false);
+}
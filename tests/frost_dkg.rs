@@ -0,0 +1,67 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use dock_crypto_wasm::{native, G1Affine};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn frost_keygen() {
+    let (threshold, total) = (2, 3);
+    let ctx = b"test";
+    let pk_base = native::frost_dkg::generate_public_key_base::<G1Affine>(None).unwrap();
+
+    let (mut round1_states, round1_msgs): (Vec<_>, Vec<_>) = (1..=total)
+        .map(|i| native::frost_dkg::start_round1(i, threshold, total, ctx, &pk_base).unwrap())
+        .unzip();
+    for (i, state) in round1_states.iter_mut().enumerate() {
+        for (j, msg) in round1_msgs.iter().enumerate() {
+            if i != j {
+                native::frost_dkg::round1_process_received_message(
+                    state,
+                    msg.clone(),
+                    ctx,
+                    &pk_base,
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let (mut round2_states, shares): (Vec<_>, Vec<_>) = round1_states
+        .into_iter()
+        .map(|s| native::frost_dkg::finish_round1(s).unwrap())
+        .unzip();
+    for (i, state) in round2_states.iter_mut().enumerate() {
+        for (j, s) in shares.iter().enumerate() {
+            if i != j {
+                native::frost_dkg::round2_process_received_share(
+                    state,
+                    j as u16 + 1,
+                    s.0[i].clone(),
+                    &pk_base,
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let outputs = round2_states
+        .into_iter()
+        .map(|s| native::frost_dkg::finish_round2(s, &pk_base).unwrap())
+        .collect::<Vec<_>>();
+    for (share, pk, tpk) in &outputs {
+        assert_eq!(
+            *pk,
+            native::frost_dkg::public_key_from_secret_key(&share.share, &pk_base)
+        );
+        assert_eq!(*tpk, outputs[0].2);
+    }
+    let tpk = native::frost_dkg::threshold_public_key_from_public_keys(
+        vec![(1, outputs[0].1), (3, outputs[2].1)],
+        threshold,
+    )
+    .unwrap();
+    assert_eq!(tpk, outputs[0].2);
+}
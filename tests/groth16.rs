@@ -0,0 +1,133 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use dock_crypto_wasm::{native::ErrorCode, Fr};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn groth16_snarkjs_interop() {
+    use dock_crypto_wasm::native::groth16::{self, SnarkjsProof, SnarkjsVerifyingKey};
+    use legogroth16::circom::r1cs::{Constraint, Curve, LC, R1CS};
+
+    // Wires are 1, the public output z and the private x, y and w with x * y = w and w * x = z
+    let r1cs = R1CS {
+        curve: Curve::Bls12_381,
+        num_public: 2,
+        num_private: 3,
+        constraints: vec![
+            Constraint {
+                a: LC(vec![(2, Fr::from(1u64))]),
+                b: LC(vec![(3, Fr::from(1u64))]),
+                c: LC(vec![(4, Fr::from(1u64))]),
+            },
+            Constraint {
+                a: LC(vec![(4, Fr::from(1u64))]),
+                b: LC(vec![(2, Fr::from(1u64))]),
+                c: LC(vec![(1, Fr::from(1u64))]),
+            },
+        ],
+        wire_to_label_mapping: vec![],
+    };
+    let pk = groth16::setup(r1cs).unwrap();
+    let (x, y) = (Fr::from(3u64), Fr::from(5u64));
+    let wires = vec![Fr::from(1u64), x * y * x, x, y, x * y];
+    let (proof, public_signals) = groth16::prove(&pk, &wires).unwrap();
+    assert_eq!(public_signals, vec![Fr::from(45u64)]);
+    groth16::verify(&pk.pk.vk, &proof, &public_signals).unwrap();
+
+    let err = groth16::verify(&pk.pk.vk, &proof, &[Fr::from(46u64)]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let err = groth16::verify(&pk.pk.vk, &proof, &[]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+    let err = groth16::prove(&pk, &wires[1..]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+
+    // Proofs from a key read back from a zkey verify with the original key
+    let zkey = groth16::write_zkey(&pk).unwrap();
+    let read_pk = groth16::read_zkey(&zkey).unwrap();
+    assert_eq!(read_pk, pk);
+    let (proof_1, _) = groth16::prove(&read_pk, &wires).unwrap();
+    groth16::verify(&pk.pk.vk, &proof_1, &public_signals).unwrap();
+    let err = groth16::read_zkey(&zkey[..zkey.len() - 1]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::DeserializationFailed);
+
+    let vk_json = serde_json::to_string(&groth16::verifying_key_to_snarkjs(&pk.pk.vk)).unwrap();
+    let proof_json = serde_json::to_string(&groth16::proof_to_snarkjs(&proof)).unwrap();
+    let public_json =
+        serde_json::to_string(&groth16::public_signals_to_snarkjs(&public_signals)).unwrap();
+    assert_eq!(public_json, r#"["45"]"#);
+    let vk_from_json: SnarkjsVerifyingKey = serde_json::from_str(&vk_json).unwrap();
+    let vk = groth16::verifying_key_from_snarkjs(&vk_from_json).unwrap();
+    assert_eq!(vk, pk.pk.vk);
+    let proof_from_json: SnarkjsProof = serde_json::from_str(&proof_json).unwrap();
+    let public_from_json: Vec<String> = serde_json::from_str(&public_json).unwrap();
+    groth16::verify(
+        &vk,
+        &groth16::proof_from_snarkjs(&proof_from_json).unwrap(),
+        &groth16::public_signals_from_snarkjs(&public_from_json).unwrap(),
+    )
+    .unwrap();
+
+    let mut other_curve = vk_from_json.clone();
+    other_curve.curve = "bn128".to_string();
+    let err = groth16::verifying_key_from_snarkjs(&other_curve).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::CurveMismatch);
+    // Field elements must be canonical
+    let err = groth16::public_signals_from_snarkjs(&["045"]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+}
+
+#[wasm_bindgen_test]
+fn groth16_snarkjs_fixtures() {
+    use dock_crypto_wasm::native::{
+        groth16::{self, SnarkjsProof, SnarkjsVerifyingKey},
+        r1cs,
+    };
+
+    // Key, proof and public signals for the multiply2 circuit with inputs a = 1010 and b = 1030 in the formats of
+    // `snarkjs groth16 setup`, `snarkjs zkey export verificationkey` and `snarkjs groth16 prove`
+    let pk = groth16::read_zkey(include_bytes!("circom/multiply2.zkey")).unwrap();
+    let vk_json: SnarkjsVerifyingKey =
+        serde_json::from_str(include_str!("circom/multiply2_verification_key.json")).unwrap();
+    let proof_json: SnarkjsProof =
+        serde_json::from_str(include_str!("circom/multiply2_proof.json")).unwrap();
+    let public_json: Vec<String> =
+        serde_json::from_str(include_str!("circom/multiply2_public.json")).unwrap();
+
+    let vk = groth16::verifying_key_from_snarkjs(&vk_json).unwrap();
+    assert_eq!(vk, pk.pk.vk);
+    let public_signals = groth16::public_signals_from_snarkjs(&public_json).unwrap();
+    assert_eq!(public_signals, vec![Fr::from(1040300u64)]);
+    let proof = groth16::proof_from_snarkjs(&proof_json).unwrap();
+    groth16::verify(&vk, &proof, &public_signals).unwrap();
+    let err = groth16::verify(&vk, &proof, &[Fr::from(1040301u64)]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    // Proofs from the zkey verify with the exported verification key and round trip through snarkjs JSON
+    let r1cs = r1cs::read_r1cs(include_bytes!("circom/multiply2.r1cs")).unwrap();
+    assert_eq!(
+        pk.pk.a_query.len(),
+        (r1cs.num_public + r1cs.num_private) as usize
+    );
+    let wires = vec![
+        Fr::from(1u64),
+        Fr::from(1040300u64),
+        Fr::from(1010u64),
+        Fr::from(1030u64),
+    ];
+    let (proof, public_signals) = groth16::prove(&pk, &wires).unwrap();
+    assert_eq!(
+        groth16::public_signals_to_snarkjs(&public_signals),
+        public_json
+    );
+    let proof_json = serde_json::to_string(&groth16::proof_to_snarkjs(&proof)).unwrap();
+    let proof_from_json: SnarkjsProof = serde_json::from_str(&proof_json).unwrap();
+    groth16::verify(
+        &vk,
+        &groth16::proof_from_snarkjs(&proof_from_json).unwrap(),
+        &public_signals,
+    )
+    .unwrap();
+}
@@ -7,6 +7,8 @@ import {
   generateRandomFieldElement,
   isAccumulatorParamsValid,
   isAccumulatorPublicKeyValid,
+  generateAccumulatorProofOfPossession,
+  verifyAccumulatorProofOfPossession,
   positiveAccumulatorAdd,
   positiveAccumulatorAddBatch,
   positiveAccumulatorBatchUpdates,
@@ -116,6 +118,16 @@ describe("For Positive accumulator", () => {
     expect(isAccumulatorPublicKeyValid(pk)).toBe(true);
   });
 
+  it("generate and verify proof of possession of secret key", () => {
+    const ctx = stringToBytes("did:example:signer");
+    const pop = generateAccumulatorProofOfPossession(sk, params, ctx);
+    expect(pop).toBeInstanceOf(Uint8Array);
+    expect(verifyAccumulatorProofOfPossession(pop, pk, params, ctx).verified).toBe(true);
+    expect(
+      verifyAccumulatorProofOfPossession(pop, pk, params, stringToBytes("did:example:other")).verified
+    ).toBe(false);
+  });
+
   it("initialize", () => {
     accumulator = positiveAccumulatorInitialize(params);
     expect(accumulator).toBeInstanceOf(Array);
@@ -5,6 +5,8 @@ import {
   bbsGenerateSigningKey,
   bbsGeneratePublicKey,
   bbsIsPublicKeyValid,
+  bbsGenerateProofOfPossession,
+  bbsVerifyProofOfPossession,
   bbsSign,
   generateRandomFieldElement,
   bbsCommitMsgs,
@@ -87,6 +89,16 @@ describe("For BBS signatures", () => {
     expect(bbsIsPublicKeyValid(pkG2)).toBe(true);
   });
 
+  it("generate and verify proof of possession of secret key", () => {
+    const ctx = stringToBytes("did:example:signer");
+    const pop = bbsGenerateProofOfPossession(sk, sigParamsG1, ctx);
+    expect(pop).toBeInstanceOf(Uint8Array);
+    expect(bbsVerifyProofOfPossession(pop, pkG2, sigParamsG1, ctx).verified).toBe(true);
+    expect(
+      bbsVerifyProofOfPossession(pop, pkG2, sigParamsG1, stringToBytes("did:example:other")).verified
+    ).toBe(false);
+  });
+
   it("generate keypair in G2 from given seed", () => {
     const keypair = bbsGenerateKeyPair(sigParamsG1, seed);
     expect(keypair).toBeInstanceOf(Object);
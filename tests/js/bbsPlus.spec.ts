@@ -12,6 +12,8 @@ import {
   bbsPlusGenerateKeyPairG2,
   bbsPlusIsPublicKeyG1Valid,
   bbsPlusIsPublicKeyG2Valid,
+  bbsPlusGenerateProofOfPossessionG2,
+  bbsPlusVerifyProofOfPossessionG2,
   bbsPlusSignG1,
   bbsPlusVerifyG1,
   bbsPlusSignG2,
@@ -134,6 +136,16 @@ describe("For BBS+ signatures", () => {
     expect(bbsPlusIsPublicKeyG2Valid(pkG2)).toBe(true);
   });
 
+  it("generate and verify proof of possession of secret key for public key in G2", () => {
+    const ctx = stringToBytes("did:example:signer");
+    const pop = bbsPlusGenerateProofOfPossessionG2(sk, sigParamsG1, ctx);
+    expect(pop).toBeInstanceOf(Uint8Array);
+    expect(bbsPlusVerifyProofOfPossessionG2(pop, pkG2, sigParamsG1, ctx).verified).toBe(true);
+    expect(
+      bbsPlusVerifyProofOfPossessionG2(pop, pkG2, sigParamsG1, stringToBytes("did:example:other")).verified
+    ).toBe(false);
+  });
+
   it("generate keypair in G1 from given seed", () => {
     const keypair = bbsPlusGenerateKeyPairG1(sigParamsG2, seed);
     expect(keypair).toBeInstanceOf(Object);
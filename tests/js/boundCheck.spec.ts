@@ -22,6 +22,11 @@ import {
   generateSetupParamForLegoVerifyingKey,
   generateBoundCheckLegoVerifierStatementFromParamRefs,
   generateSetupParamForLegoProvingKey,
+  legoProvingKeyHandleFromParts,
+  legoProvingKeyHandleFromStream,
+  LegoProvingKeyHandle,
+  proofSpecG1Handle,
+  generateCompositeProofG1WithHandle,
 } from "../../lib";
 import { getRevealedUnrevealed, stringToBytes } from "../utilities";
 
@@ -305,4 +310,75 @@ describe("Prove and verify bounds on signed messages", () => {
     console.timeEnd("proof ver");
     expect(res.verified).toBe(true);
   }, 10000);
+
+  it("create a proof with the proving key read in parts from a stream", async () => {
+    const partSize = 64 * 1024;
+    const parts: Uint8Array[] = [];
+    for (let i = 0; i < snarkPkDecom.length; i += partSize) {
+      parts.push(snarkPkDecom.slice(i, i + partSize));
+    }
+    const toStream = (chunks: Uint8Array[]) =>
+      new ReadableStream<Uint8Array>({
+        start(controller) {
+          chunks.forEach((p) => controller.enqueue(p));
+          controller.close();
+        },
+      });
+    const fromParts = legoProvingKeyHandleFromParts(parts, true);
+    fromParts.free();
+    expect(() => legoProvingKeyHandleFromParts(parts.slice(1), true)).toThrow();
+    await expect(legoProvingKeyHandleFromStream(toStream(parts.slice(0, -1)), true)).rejects.toThrow();
+
+    // Both the full key and the key with only the parts needed for proving create valid proofs
+    for (const provingOnly of [false, true]) {
+      proveWithKeyFromStream(await legoProvingKeyHandleFromStream(toStream(parts), true, provingOnly));
+    }
+  }, 60000);
+
+  function proveWithKeyFromStream(pkHandle: LegoProvingKeyHandle) {
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(
+      messages,
+      new Set<number>()
+    );
+    const statement1 = generatePoKBBSPlusSignatureStatement(
+      sigParams,
+      sigPk,
+      revealedMsgs,
+      false
+    );
+    const set = new Set<[number, number]>();
+    set.add([0, msgIdx]);
+    set.add([1, 0]);
+    const metaStatements = [generateWitnessEqualityMetaStatement(set)];
+    const nonce = stringToBytes("test-nonce");
+
+    // The key is moved to the proof spec so only one copy of it is in wasm memory
+    const proofSpec = proofSpecG1Handle([statement1], metaStatements, [], undefined);
+    const pkIdx = proofSpec.moveSetupParamForLegoProvingKey(pkHandle);
+    proofSpec.addStatement(
+      generateBoundCheckLegoProverStatementFromParamRefs(min, max, pkIdx)
+    );
+    const proof = generateCompositeProofG1WithHandle(
+      proofSpec,
+      [
+        generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false),
+        generateBoundCheckWitness(messages[msgIdx]),
+      ],
+      nonce
+    );
+    proofSpec.free();
+
+    const res = verifyCompositeProofG1WithDeconstructedProofSpec(
+      proof,
+      [
+        statement1,
+        generateBoundCheckLegoVerifierStatement(min, max, snarkVkDecom, true),
+      ],
+      metaStatements,
+      [],
+      undefined,
+      nonce
+    );
+    expect(res.verified).toBe(true);
+  }
 });
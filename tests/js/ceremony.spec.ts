@@ -0,0 +1,150 @@
+import * as fs from "fs";
+import * as path from "path";
+
+import {
+  bbsPlusGeneratePublicKeyG2,
+  bbsPlusGenerateSignatureParamsG1,
+  bbsPlusGenerateSigningKey,
+  bbsPlusSignG1,
+  boundCheckSnarkSetup,
+  encodeMessageForSigning,
+  generateBoundCheckLegoProverStatement,
+  generateBoundCheckLegoVerifierStatement,
+  generateBoundCheckWitness,
+  generateCompositeProofG1WithDeconstructedProofSpec,
+  generateFieldElementFromNumber,
+  generatePoKBBSPlusSignatureStatement,
+  generatePoKBBSPlusSignatureWitness,
+  generateWitnessEqualityMetaStatement,
+  initializeWasm,
+  legoCeremonyContribute,
+  legoCeremonyFinalize,
+  legoCeremonyVerifyContribution,
+  legosnarkVkFromPk,
+  r1csFromBytes,
+  r1csSnarkSetupFromPowersOfTau,
+  saverCeremonyContribute,
+  saverCeremonyFinalize,
+  saverCeremonyVerifyContribution,
+  saverDecryptCiphertextUsingSnarkVk,
+  saverDecryptorSetup,
+  saverEncrypt,
+  saverGenerateEncryptionGenerators,
+  saverGetSnarkVkFromPk,
+  verifyCompositeProofG1WithDeconstructedProofSpec,
+} from "../../lib";
+
+import {stringToBytes, getRevealedUnrevealed, areUint8ArraysEqual} from "../utilities";
+
+function circomArtifact(fileName: string): Uint8Array {
+  return new Uint8Array(fs.readFileSync(`${path.resolve("./")}/tests/circom/${fileName}`));
+}
+
+describe("Setup ceremony for snark proving keys", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("run a ceremony for the bound check proving key and use the final key", () => {
+    const initialPk = boundCheckSnarkSetup(false);
+
+    const [pk1, transcript1] = legoCeremonyContribute(initialPk, undefined, false);
+    expect(legoCeremonyVerifyContribution(initialPk, pk1, transcript1, false).verified).toBe(true);
+    const [pk2, transcript2] = legoCeremonyContribute(pk1, transcript1, false);
+    expect(legoCeremonyVerifyContribution(pk1, pk2, transcript2, false).verified).toBe(true);
+    expect(areUint8ArraysEqual(pk1, pk2)).toEqual(false);
+
+    // A contribution doesn't verify against a key it wasn't made to
+    expect(legoCeremonyVerifyContribution(initialPk, pk2, transcript2, false).verified).toBe(false);
+    expect(() => legoCeremonyFinalize(initialPk, pk1, transcript2, false)).toThrow();
+
+    const finalPk = legoCeremonyFinalize(initialPk, pk2, transcript2, false);
+    const snarkVk = legosnarkVkFromPk(finalPk, false);
+
+    const messageCount = 3;
+    const msgIdx = 1;
+    const [min, max] = [100, 200];
+    const messages: Uint8Array[] = [];
+    for (let i = 0; i < messageCount; i++) {
+      messages.push(generateFieldElementFromNumber(min + 1 + i));
+    }
+    const sigParams = bbsPlusGenerateSignatureParamsG1(messageCount);
+    const sigSk = bbsPlusGenerateSigningKey();
+    const sigPk = bbsPlusGeneratePublicKeyG2(sigSk, sigParams);
+    const sig = bbsPlusSignG1(messages, sigSk, sigParams, false);
+
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set<number>());
+    const statement1 = generatePoKBBSPlusSignatureStatement(sigParams, sigPk, revealedMsgs, false);
+    const statement2 = generateBoundCheckLegoProverStatement(min, max, finalPk, false);
+    const set = new Set<[number, number]>();
+    set.add([0, msgIdx]);
+    set.add([1, 0]);
+    const metaStatements = [generateWitnessEqualityMetaStatement(set)];
+    const witnesses = [
+      generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false),
+      generateBoundCheckWitness(messages[msgIdx]),
+    ];
+    const nonce = stringToBytes("test-nonce");
+    const proof = generateCompositeProofG1WithDeconstructedProofSpec(
+      [statement1, statement2],
+      metaStatements,
+      [],
+      witnesses,
+      undefined,
+      nonce
+    );
+    const statement3 = generateBoundCheckLegoVerifierStatement(min, max, snarkVk, false);
+    const res = verifyCompositeProofG1WithDeconstructedProofSpec(
+      proof,
+      [statement1, statement3],
+      metaStatements,
+      [],
+      undefined,
+      nonce
+    );
+    expect(res.verified).toBe(true);
+  }, 90000);
+
+  it("run a ceremony for the SAVER snark proving key and use the final keys", () => {
+    const chunkBitSize = 8;
+    const encGens = saverGenerateEncryptionGenerators();
+    const [initialPk] = saverDecryptorSetup(chunkBitSize, encGens, false);
+
+    const [pk1, transcript1] = saverCeremonyContribute(initialPk, undefined, false);
+    expect(saverCeremonyVerifyContribution(initialPk, pk1, transcript1, false).verified).toBe(true);
+    const [pk2, transcript2] = saverCeremonyContribute(pk1, transcript1, false);
+    expect(saverCeremonyVerifyContribution(pk1, pk2, transcript2, false).verified).toBe(true);
+    expect(saverCeremonyVerifyContribution(pk1, pk2, transcript1, false).verified).toBe(false);
+
+    const [snarkPk, sk, ek, dk] = saverCeremonyFinalize(initialPk, pk2, transcript2, encGens, chunkBitSize, false);
+    expect(areUint8ArraysEqual(snarkPk, pk2)).toEqual(true);
+    const snarkVk = saverGetSnarkVkFromPk(snarkPk, false);
+
+    const message = encodeMessageForSigning(stringToBytes("secret"));
+    const [ct] = saverEncrypt(message, ek, snarkVk, chunkBitSize, false);
+    const [decrypted] = saverDecryptCiphertextUsingSnarkVk(ct, sk, dk, snarkVk, chunkBitSize, false);
+    expect(decrypted).toEqual(message);
+  }, 90000);
+
+  it("run a ceremony for an R1CS proving key created from powers of tau", () => {
+    // Powers of tau for a domain of 8 elements, enough for the circuit of `multiply2`
+    const ptau = circomArtifact("bls12_381_power_3.ptau");
+    const r1cs = r1csFromBytes(circomArtifact("multiply2.r1cs"));
+
+    // Anyone gets the same initial key from the powers of tau
+    const initialPk = r1csSnarkSetupFromPowersOfTau(r1cs, 1, ptau, false);
+    expect(areUint8ArraysEqual(r1csSnarkSetupFromPowersOfTau(r1cs, 1, ptau, false), initialPk)).toEqual(true);
+    // A truncated file is rejected
+    expect(() => r1csSnarkSetupFromPowersOfTau(r1cs, 1, ptau.slice(0, ptau.length - 1), false)).toThrow();
+
+    const [pk1, transcript1] = legoCeremonyContribute(initialPk, undefined, false);
+    const [pk2, transcript2] = legoCeremonyContribute(pk1, transcript1, false);
+    expect(legoCeremonyVerifyContribution(pk1, pk2, transcript2, false).verified).toBe(true);
+    const finalPk = legoCeremonyFinalize(initialPk, pk2, transcript2, false);
+    expect(areUint8ArraysEqual(finalPk, pk2)).toEqual(true);
+
+    // A key from another setup isn't the initial key of the ceremony
+    const otherPk = boundCheckSnarkSetup(false);
+    expect(() => legoCeremonyFinalize(otherPk, pk2, transcript2, false)).toThrow();
+  }, 90000);
+});
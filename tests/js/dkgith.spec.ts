@@ -0,0 +1,147 @@
+import {
+  encodeMessageForSigning,
+  bbsPlusSignG1,
+  BbsPlusSigParams,
+  bbsPlusGeneratePublicKeyG2,
+  bbsPlusGenerateSigningKey,
+  bbsPlusGenerateSignatureParamsG1,
+  dkgithDecrypt,
+  dkgithEncrypt,
+  dkgithGenerateParams,
+  dkgithGeneratePublicKey,
+  dkgithGenerateSecretKey,
+  dkgithVerifyDecryption,
+  dkgithVerifyEncryption,
+  generateCompositeProofG1WithDeconstructedProofSpec,
+  generateProofSpecG1,
+  generateDkgithStatement,
+  generateDkgithStatementFromParamRefs,
+  generateDkgithWitness,
+  generatePoKBBSPlusSignatureStatement,
+  generatePoKBBSPlusSignatureWitness,
+  generateSetupParamForDkgithCommitmentKey,
+  generateWitnessEqualityMetaStatement,
+  initializeWasm,
+  verifyCompositeProofG1WithDeconstructedProofSpec,
+  verifyCompositeProofG1WithDkgith,
+} from "../../lib";
+
+import {stringToBytes, getRevealedUnrevealed} from "../utilities";
+
+describe("Verifiable encryption of a signed message with DKG in the head", () => {
+  const messageCount = 4;
+  const encMsgIdx = 1;
+
+  let sigParams: BbsPlusSigParams, sigPk: Uint8Array, sig: Uint8Array;
+  let params: Uint8Array, sk: Uint8Array, pk: Uint8Array;
+  let commitment: Uint8Array, encProof: Uint8Array;
+
+  const messages = new Array<Uint8Array>();
+  const nonce = stringToBytes("test-nonce");
+
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("setup", () => {
+    params = dkgithGenerateParams(stringToBytes("test"));
+    sk = dkgithGenerateSecretKey();
+    pk = dkgithGeneratePublicKey(sk, params);
+
+    sigParams = bbsPlusGenerateSignatureParamsG1(messageCount);
+    const sigSk = bbsPlusGenerateSigningKey();
+    sigPk = bbsPlusGeneratePublicKeyG2(sigSk, sigParams);
+    for (let i = 0; i < messageCount; i++) {
+      messages.push(encodeMessageForSigning(stringToBytes(`${i + 1}`)));
+    }
+    sig = bbsPlusSignG1(messages, sigSk, sigParams, false);
+  });
+
+  it("create and verify a proof of a verifiably encrypted signed message", () => {
+    let blinding: Uint8Array;
+    [commitment, blinding, encProof] = dkgithEncrypt(messages[encMsgIdx], params, pk, nonce);
+
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set<number>());
+    const statement1 = generatePoKBBSPlusSignatureStatement(sigParams, sigPk, revealedMsgs, false);
+    const statement2 = generateDkgithStatement(params, commitment);
+
+    const set = new Set<[number, number]>();
+    set.add([0, encMsgIdx]);
+    set.add([1, 0]);
+    const metaStatements = [generateWitnessEqualityMetaStatement(set)];
+
+    const witnesses = [
+      generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false),
+      generateDkgithWitness(messages[encMsgIdx], blinding),
+    ];
+    const proof = generateCompositeProofG1WithDeconstructedProofSpec(
+      [statement1, statement2],
+      metaStatements,
+      [],
+      witnesses,
+      undefined,
+      nonce
+    );
+
+    // The verifier creates the statement using setup params
+    const setupParams = [generateSetupParamForDkgithCommitmentKey(params)];
+    const statement3 = generateDkgithStatementFromParamRefs(0, commitment);
+    const res = verifyCompositeProofG1WithDeconstructedProofSpec(
+      proof,
+      [statement1, statement3],
+      metaStatements,
+      setupParams,
+      undefined,
+      nonce
+    );
+    expect(res.verified).toBe(true);
+
+    // Verifying both proofs at once with the commitment taken from the statement
+    const proofSpec = generateProofSpecG1([statement1, statement3], metaStatements, setupParams);
+    const dkgithProofs = new Map([[1, [encProof, params, pk] as [Uint8Array, Uint8Array, Uint8Array]]]);
+    expect(verifyCompositeProofG1WithDkgith(proof, proofSpec, nonce, dkgithProofs).verified).toBe(true);
+    expect(verifyCompositeProofG1WithDkgith(proof, proofSpec, stringToBytes("other-nonce"), dkgithProofs).verified).toBe(false);
+    const wrongIndex = verifyCompositeProofG1WithDkgith(proof, proofSpec, nonce, new Map([[0, dkgithProofs.get(1)!]]));
+    expect(wrongIndex.verified).toBe(false);
+    expect(wrongIndex.code).toEqual("INVALID_ARGUMENT");
+
+    // The DKGitH proof is bound to the composite proof's nonce
+    const [, , otherNonceProof] = dkgithEncrypt(messages[encMsgIdx], params, pk, stringToBytes("other-nonce"));
+    expect(
+      verifyCompositeProofG1WithDkgith(proof, proofSpec, nonce, new Map([[1, [otherNonceProof, params, pk] as [Uint8Array, Uint8Array, Uint8Array]]])).verified
+    ).toBe(false);
+
+    expect(dkgithVerifyEncryption(encProof, commitment, params, pk, nonce).verified).toBe(true);
+    expect(
+      dkgithVerifyEncryption(encProof, commitment, params, pk, stringToBytes("other-nonce")).verified
+    ).toBe(false);
+
+    // The proof doesn't verify for a commitment to a different message
+    const [otherCommitment, otherBlinding] = dkgithEncrypt(messages[0], params, pk, nonce);
+    const otherStatement = generateDkgithStatement(params, otherCommitment);
+    const otherProof = generateCompositeProofG1WithDeconstructedProofSpec(
+      [statement1, otherStatement],
+      metaStatements,
+      [],
+      [witnesses[0], generateDkgithWitness(messages[0], otherBlinding)],
+      undefined,
+      nonce
+    );
+    const otherRes = verifyCompositeProofG1WithDeconstructedProofSpec(
+      otherProof,
+      [statement1, otherStatement],
+      metaStatements,
+      [],
+      undefined,
+      nonce
+    );
+    expect(otherRes.verified).toBe(false);
+  });
+
+  it("decrypt and verify decryption", () => {
+    const [message, blinding] = dkgithDecrypt(encProof, commitment, sk, params);
+    expect(message).toEqual(messages[encMsgIdx]);
+    expect(dkgithVerifyDecryption(commitment, message, blinding, params).verified).toBe(true);
+    expect(dkgithVerifyDecryption(commitment, messages[0], blinding, params).verified).toBe(false);
+  });
+});
@@ -4,7 +4,31 @@ import {
   initializeWasm,
   isWasmInitialized,
   requireWasmInitialized,
+  generateRandomFieldElement,
+  bbsPlusGenerateSignatureParamsG1,
+  bbsPlusGenerateSigningKey,
+  bbsPlusSignG1,
+  setRngSeed,
+  setRngSource,
+  resetRngSource,
+  bbsGenerateSignatureParams,
+  bbsGenerateSigningKey,
+  bbsGeneratePublicKey,
+  generateAccumulatorParams,
+  generateAccumulatorSecretKey,
+  generateAccumulatorPublicKey,
+  isAccumulatorPublicKeyValid,
+  setEnvelopeOutput,
+  setEnvelopeInput,
+  envelopeWrap,
+  envelopeUnwrap,
+  envelopeInspect,
+  bbsIsSignatureParamsValid,
+  bbsGenerateProofOfPossession,
+  bbsVerifyProofOfPossession,
+  WasmError,
 } from "../../lib";
+import { stringToBytes } from "../utilities";
 
 describe("For WASM initialization", () => {
   it("returns false when not initialized", () => {
@@ -47,3 +71,208 @@ describe("For utils", () => {
     expect(x[0]).toEqual(165);
   });
 });
+
+describe("For RNG source", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  afterEach(() => {
+    resetRngSource();
+  });
+
+  function signWithNewKey(): Uint8Array {
+    const params = bbsPlusGenerateSignatureParamsG1(2);
+    const sk = bbsPlusGenerateSigningKey();
+    const messages = [generateFieldElementFromNumber(1), generateFieldElementFromNumber(2)];
+    return bbsPlusSignG1(messages, sk, params, false);
+  }
+
+  it("gives same outputs for same seed", () => {
+    const seed = new Uint8Array(32).fill(7);
+    setRngSeed(seed);
+    const sig1 = signWithNewKey();
+    setRngSeed(seed);
+    const sig2 = signWithNewKey();
+    expect(sig1).toEqual(sig2);
+
+    setRngSeed(new Uint8Array(32).fill(8));
+    expect(signWithNewKey()).not.toEqual(sig1);
+
+    resetRngSource();
+    expect(signWithNewKey()).not.toEqual(sig1);
+
+    expect(() => setRngSeed(new Uint8Array(31))).toThrow();
+  });
+
+  it("uses the given callback", () => {
+    const requested: number[] = [];
+    setRngSource((n) => {
+      requested.push(n);
+      return new Uint8Array(n).fill(3);
+    });
+    const e1 = generateRandomFieldElement();
+    const e2 = generateRandomFieldElement();
+    // Callback returns the same bytes every time
+    expect(e1).toEqual(e2);
+    expect(requested.length).toBeGreaterThan(0);
+    requested.forEach((n) => expect(n).toEqual(32));
+
+    expect(() => setRngSource((n) => new Uint8Array(n - 1))).toThrow();
+    expect(() => setRngSource(() => { throw new Error("no entropy"); })).toThrow();
+  });
+
+  it("throws when the callback fails after being set", () => {
+    let fail = false;
+    setRngSource((n) => {
+      if (fail) {
+        throw new Error("no entropy");
+      }
+      return new Uint8Array(n).fill(3);
+    });
+    generateRandomFieldElement();
+    fail = true;
+    try {
+      generateRandomFieldElement();
+      fail = false;
+    } catch (e) {
+      expect((e as WasmError).code).toEqual("RNG_FAILURE");
+      expect((e as WasmError).category).toEqual("internal");
+    }
+    expect(fail).toBe(true);
+    // Functions work again once the callback does
+    fail = false;
+    generateRandomFieldElement();
+  });
+
+  it("allows the callback to call randomized functions", () => {
+    let nested = false;
+    setRngSource((n) => {
+      if (nested) {
+        return new Uint8Array(n).fill(5);
+      }
+      nested = true;
+      generateRandomFieldElement();
+      nested = false;
+      return new Uint8Array(n).fill(3);
+    });
+    const e1 = generateRandomFieldElement();
+    const e2 = generateRandomFieldElement();
+    expect(e1).toEqual(e2);
+  });
+});
+
+describe("For errors", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  function expectError(f: () => unknown, code: string, category: string) {
+    try {
+      f();
+    } catch (e) {
+      expect((e as WasmError).code).toEqual(code);
+      expect((e as WasmError).category).toEqual(category);
+      return;
+    }
+    throw new Error("Expected to throw");
+  }
+
+  it("have code and category", () => {
+    const params = bbsGenerateSignatureParams(2);
+    const sk = bbsGenerateSigningKey();
+    const pk = bbsGeneratePublicKey(sk, params);
+
+    // Bytes that aren't a secret key
+    expectError(() => bbsGeneratePublicKey(new Uint8Array([1, 2, 3]), params), "DESERIALIZATION_FAILED", "deserialization");
+    // JS object that isn't signature params
+    expectError(() => bbsIsSignatureParamsValid({ ...params, h: [new Uint8Array([1, 2, 3])] }), "INVALID_ARGUMENT", "invalid-params");
+
+    // Failed verification is returned rather than thrown
+    const proof = bbsGenerateProofOfPossession(sk, params, stringToBytes("context"));
+    expect(bbsVerifyProofOfPossession(proof, pk, params, stringToBytes("context")).verified).toBe(true);
+    const result = bbsVerifyProofOfPossession(proof, pk, params, stringToBytes("other context"));
+    expect(result.verified).toBe(false);
+    expect(result.code).toEqual("INVALID_PROOF");
+    expect(result.category).toEqual("verification-failed");
+  });
+});
+
+describe("For envelope", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  afterEach(() => {
+    setEnvelopeOutput(false);
+    setEnvelopeInput(false);
+  });
+
+  function bbsPk(): Uint8Array {
+    const params = bbsGenerateSignatureParams(2);
+    return bbsGeneratePublicKey(bbsGenerateSigningKey(), params);
+  }
+
+  function expectCode(f: () => unknown, code: string) {
+    try {
+      f();
+    } catch (e) {
+      expect((e as WasmError).code).toEqual(code);
+      return;
+    }
+    throw new Error("Expected to throw");
+  }
+
+  it("wraps, inspects and unwraps", () => {
+    const pk = bbsPk();
+    const wrapped = envelopeWrap(pk, "BBSPublicKey", true);
+    expect(wrapped.length).toEqual(pk.length + 9);
+
+    const info = envelopeInspect(wrapped);
+    expect(info.type).toEqual("BBSPublicKey");
+    expect(info.version).toEqual(1);
+    expect(info.curve).toEqual(1);
+    expect(info.compressed).toBe(true);
+    expect(info.payloadLength).toEqual(pk.length);
+
+    expect(envelopeUnwrap(wrapped)).toEqual(pk);
+    expect(envelopeUnwrap(wrapped, "BBSPublicKey")).toEqual(pk);
+    expectCode(() => envelopeUnwrap(wrapped, "AccumPk"), "ARTIFACT_TYPE_MISMATCH");
+    expectCode(() => envelopeInspect(pk), "INVALID_ENVELOPE");
+    expect(() => envelopeWrap(pk, "NotAType", true)).toThrow();
+  });
+
+  it("is emitted when enabled and checked when passed", () => {
+    const params = generateAccumulatorParams();
+    const sk = generateAccumulatorSecretKey();
+    const pk = generateAccumulatorPublicKey(sk, params);
+    expect(() => envelopeInspect(pk)).toThrow();
+
+    // Without an envelope, a BBS public key can't be told apart from an accumulator public key
+    expect(isAccumulatorPublicKeyValid(bbsPk())).toBe(true);
+
+    setEnvelopeOutput(true);
+    const wrappedPk = generateAccumulatorPublicKey(sk, params);
+    expect(envelopeInspect(wrappedPk).type).toEqual("AccumPk");
+    expect(envelopeUnwrap(wrappedPk)).toEqual(pk);
+
+    // Envelopes are only accepted once envelope input is enabled
+    setEnvelopeInput(true);
+    expect(isAccumulatorPublicKeyValid(wrappedPk)).toBe(true);
+    expect(isAccumulatorPublicKeyValid(pk)).toBe(true);
+
+    const wrappedBbsPk = bbsPk();
+    expect(envelopeInspect(wrappedBbsPk).type).toEqual("BBSPublicKey");
+    expectCode(() => isAccumulatorPublicKeyValid(wrappedBbsPk), "ARTIFACT_TYPE_MISMATCH");
+  });
+
+  it("is used for field elements", () => {
+    setEnvelopeOutput(true);
+    const elem = generateRandomFieldElement();
+    expect(envelopeInspect(elem).type).toEqual("FieldElement");
+
+    setEnvelopeInput(true);
+    expect(fieldElementAsBytes(elem, false)).toEqual(envelopeUnwrap(elem, "Fr"));
+    expectCode(() => fieldElementAsBytes(bbsPk(), false), "ARTIFACT_TYPE_MISMATCH");
+  });
+});
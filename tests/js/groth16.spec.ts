@@ -0,0 +1,116 @@
+import * as r1csf from "r1csfile";
+import * as fs from "fs";
+import * as path from "path";
+
+import {
+  generateFieldElementFromNumber,
+  groth16Prove,
+  groth16ProofFromSnarkjs,
+  groth16ProofToSnarkjs,
+  groth16ProvingKeyFromZkey,
+  groth16ProvingKeyToZkey,
+  groth16PublicSignalsFromSnarkjs,
+  groth16PublicSignalsToSnarkjs,
+  groth16SnarkSetup,
+  groth16Verify,
+  groth16VkFromPk,
+  groth16VkFromSnarkjs,
+  groth16VkToSnarkjs,
+  initializeWasm,
+  r1csGenerateWires,
+} from "../../lib";
+import {areUint8ArraysEqual} from "../utilities";
+import {Constraint, LC, LCTerm} from "../../src/js";
+
+function circomArtifactPath(fileName: string): string {
+  return `${path.resolve("./")}/tests/circom/${fileName}`;
+}
+
+async function setupFromR1CSFile(r1csName: string, uncompressed: boolean): Promise<Uint8Array> {
+  const parsed = await r1csf.readR1cs(circomArtifactPath(r1csName));
+  await parsed.curve.terminate();
+  const numPublic = 1 + parsed.nPubInputs + parsed.nOutputs;
+  const parseLC = (lc: object): LC =>
+    Object.entries(lc).map(([i, v]) => [parseInt(i), parsed.F.fromMontgomery(v)] as LCTerm);
+  const constraints = parsed.constraints.map((c) => [parseLC(c[0]), parseLC(c[1]), parseLC(c[2])] as Constraint);
+  return groth16SnarkSetup(parsed.curve.name, numPublic, parsed.nVars - numPublic, constraints, uncompressed);
+}
+
+describe("Groth16 proofs for Circom circuits compatible with snarkjs", () => {
+  let wires: Uint8Array[];
+
+  beforeAll(async () => {
+    await initializeWasm();
+    const inputWires = new Map<string, Uint8Array[]>();
+    inputWires.set("a", [generateFieldElementFromNumber(1010)]);
+    inputWires.set("b", [generateFieldElementFromNumber(1030)]);
+    wires = r1csGenerateWires(new Uint8Array(fs.readFileSync(circomArtifactPath("multiply2.wasm"))), inputWires);
+  });
+
+  it("prove and verify with a key from setup or a zkey", async () => {
+    const snarkPk = await setupFromR1CSFile("multiply2.r1cs", false);
+    const snarkVk = groth16VkFromPk(snarkPk, false);
+
+    const [proof, publicSignals] = groth16Prove(snarkPk, wires, false);
+    expect(publicSignals.length).toEqual(1);
+    expect(groth16PublicSignalsToSnarkjs(publicSignals)).toEqual(["1040300"]);
+    expect(groth16Verify(proof, snarkVk, publicSignals, false).verified).toBe(true);
+    expect(groth16Verify(proof, snarkVk, [generateFieldElementFromNumber(1040301)], false).verified).toBe(false);
+
+    const zkey = groth16ProvingKeyToZkey(snarkPk, false);
+    const pkFromZkey = groth16ProvingKeyFromZkey(zkey, true);
+    expect(areUint8ArraysEqual(groth16VkFromPk(pkFromZkey, true), groth16VkFromPk(snarkPk, true))).toBe(true);
+    const [proof1, publicSignals1] = groth16Prove(pkFromZkey, wires, true);
+    expect(groth16Verify(proof1, snarkVk, publicSignals1, false).verified).toBe(true);
+
+    expect(() => groth16ProvingKeyFromZkey(zkey.slice(0, zkey.length - 1), false)).toThrow();
+  }, 30000);
+
+  it("exchange verification keys, proofs and public signals as snarkjs JSON", async () => {
+    const snarkPk = await setupFromR1CSFile("multiply2.r1cs", false);
+    const [proof, publicSignals] = groth16Prove(snarkPk, wires, false);
+
+    const vkJson = JSON.stringify(groth16VkToSnarkjs(groth16VkFromPk(snarkPk, false), false));
+    const proofJson = JSON.stringify(groth16ProofToSnarkjs(proof));
+    const publicJson = JSON.stringify(groth16PublicSignalsToSnarkjs(publicSignals));
+
+    const vk = JSON.parse(vkJson);
+    expect(vk.protocol).toEqual("groth16");
+    expect(vk.curve).toEqual("bls12381");
+    expect(vk.nPublic).toEqual(1);
+    expect(vk.IC.length).toEqual(2);
+
+    const snarkVk = groth16VkFromSnarkjs(vk, false);
+    expect(areUint8ArraysEqual(snarkVk, groth16VkFromPk(snarkPk, false))).toBe(true);
+    const proofFromJson = groth16ProofFromSnarkjs(JSON.parse(proofJson));
+    expect(areUint8ArraysEqual(proofFromJson, proof)).toBe(true);
+    const signalsFromJson = groth16PublicSignalsFromSnarkjs(JSON.parse(publicJson));
+    expect(groth16Verify(proofFromJson, snarkVk, signalsFromJson, false).verified).toBe(true);
+
+    expect(() => groth16VkFromSnarkjs({...vk, curve: "bn128"}, false)).toThrow();
+  });
+
+  it("verify a proof from snarkjs and prove with a zkey from snarkjs", () => {
+    // Artifacts of the circuit for inputs a = 1010 and b = 1030 in the formats of `snarkjs groth16 setup`,
+    // `snarkjs zkey export verificationkey` and `snarkjs groth16 prove`
+    const readJson = (fileName: string) => JSON.parse(fs.readFileSync(circomArtifactPath(fileName), "utf8"));
+    const zkey = new Uint8Array(fs.readFileSync(circomArtifactPath("multiply2.zkey")));
+    const vkJson = readJson("multiply2_verification_key.json");
+    const publicJson = readJson("multiply2_public.json");
+    expect(publicJson).toEqual(["1040300"]);
+
+    const snarkPk = groth16ProvingKeyFromZkey(zkey, false);
+    const snarkVk = groth16VkFromSnarkjs(vkJson, false);
+    expect(areUint8ArraysEqual(snarkVk, groth16VkFromPk(snarkPk, false))).toBe(true);
+    const publicSignals = groth16PublicSignalsFromSnarkjs(publicJson);
+    const proof = groth16ProofFromSnarkjs(readJson("multiply2_proof.json"));
+    expect(groth16Verify(proof, snarkVk, publicSignals, false).verified).toBe(true);
+    expect(groth16Verify(proof, snarkVk, [generateFieldElementFromNumber(1040301)], false).verified).toBe(false);
+
+    // A proof created here verifies with the verification key of snarkjs after a round trip through its JSON
+    const [proof1, publicSignals1] = groth16Prove(snarkPk, wires, false);
+    expect(groth16PublicSignalsToSnarkjs(publicSignals1)).toEqual(publicJson);
+    const proof1FromJson = groth16ProofFromSnarkjs(JSON.parse(JSON.stringify(groth16ProofToSnarkjs(proof1))));
+    expect(groth16Verify(proof1FromJson, snarkVk, publicSignals1, false).verified).toBe(true);
+  });
+});
@@ -0,0 +1,159 @@
+import {
+  initializeWasm,
+  bbsPlusGenerateSignatureParamsG1,
+  bbsPlusGenerateSigningKey,
+  bbsPlusGeneratePublicKeyG2,
+  bbsPlusSignG1,
+  bbsGenerateSignatureParams,
+  bbsGenerateSigningKey,
+  bbsGeneratePublicKey,
+  bbsSign,
+  generateIssuerHidingVerifierSecretKey,
+  generateIssuerHidingVerifierPublicKey,
+  bbsPlusGeneratePublicKeyG1ForIssuerHiding,
+  bbsGeneratePublicKeyG1ForIssuerHiding,
+  bbsPlusSignIssuerPolicy,
+  bbsPlusVerifyIssuerPolicy,
+  bbsPlusGenIssuerHidingProof,
+  bbsPlusVerifyIssuerHidingProof,
+  bbsSignIssuerPolicy,
+  bbsVerifyIssuerPolicy,
+  bbsGenIssuerHidingProof,
+  bbsVerifyIssuerHidingProof,
+  WasmError,
+} from "../../lib";
+
+import { stringToBytes } from "../utilities";
+
+describe("Proving knowledge of signature without revealing the issuer", () => {
+  const messages = [
+    stringToBytes("Message1"),
+    stringToBytes("Message2"),
+    stringToBytes("Message3"),
+    stringToBytes("Message4"),
+    stringToBytes("Message5"),
+  ];
+  const messageCount = messages.length;
+  const issuerCount = 3;
+  const nonce = stringToBytes("test nonce");
+  const revealedIndices = new Set<number>([0, 2]);
+  const revealedMsgs = new Map();
+  revealedMsgs.set(0, messages[0]);
+  revealedMsgs.set(2, messages[2]);
+
+  let verifierSk: Uint8Array, verifierPk: Uint8Array, otherVerifierPk: Uint8Array;
+
+  beforeAll(async () => {
+    await initializeWasm();
+    verifierSk = generateIssuerHidingVerifierSecretKey();
+    verifierPk = generateIssuerHidingVerifierPublicKey(verifierSk);
+    otherVerifierPk = generateIssuerHidingVerifierPublicKey(
+      generateIssuerHidingVerifierSecretKey()
+    );
+  });
+
+  function check(
+    sigParams: any[],
+    sks: Uint8Array[],
+    pks: Uint8Array[],
+    pksG1: Uint8Array[],
+    sign: (i: number) => Uint8Array,
+    signPolicy,
+    verifyPolicy,
+    genProof,
+    verifyProof
+  ) {
+    expect(() => signPolicy(sigParams, pks, pksG1.slice().reverse(), verifierSk)).toThrow();
+
+    const policy = signPolicy(sigParams, pks, pksG1, verifierSk);
+    expect(verifyPolicy(policy, verifierPk).verified).toBe(true);
+    expect(verifyPolicy(policy, otherVerifierPk).verified).toBe(false);
+
+    for (let i = 0; i < issuerCount; i++) {
+      const sig = sign(i);
+      // Policy not signed by the given verifier
+      expect(() =>
+        genProof(policy, otherVerifierPk, i, sig, messages, revealedIndices, true, nonce)
+      ).toThrow();
+      // Signature not from the issuer at the given index
+      expect(() =>
+        genProof(policy, verifierPk, (i + 1) % issuerCount, sig, messages, revealedIndices, true, nonce)
+      ).toThrow();
+
+      const proof = genProof(policy, verifierPk, i, sig, messages, revealedIndices, true, nonce);
+      expect(verifyProof(proof, policy, verifierPk, revealedMsgs, true, nonce).verified).toBe(true);
+      expect(verifyProof(proof, policy, verifierPk, revealedMsgs, true).verified).toBe(false);
+      expect(verifyProof(proof, policy, otherVerifierPk, revealedMsgs, true, nonce).verified).toBe(false);
+
+      const wrongRevealedMsgs = new Map(revealedMsgs);
+      wrongRevealedMsgs.set(2, messages[3]);
+      expect(verifyProof(proof, policy, verifierPk, wrongRevealedMsgs, true, nonce).verified).toBe(false);
+    }
+  }
+
+  it("for BBS+ signatures", () => {
+    const sigParams: any[] = [], sks: Uint8Array[] = [], pks: Uint8Array[] = [], pksG1: Uint8Array[] = [];
+    for (let i = 0; i < issuerCount; i++) {
+      sigParams.push(bbsPlusGenerateSignatureParamsG1(messageCount));
+      sks.push(bbsPlusGenerateSigningKey());
+      pks.push(bbsPlusGeneratePublicKeyG2(sks[i], sigParams[i]));
+      pksG1.push(bbsPlusGeneratePublicKeyG1ForIssuerHiding(sks[i], sigParams[i]));
+    }
+    check(
+      sigParams, sks, pks, pksG1,
+      (i) => bbsPlusSignG1(messages, sks[i], sigParams[i], true),
+      bbsPlusSignIssuerPolicy,
+      bbsPlusVerifyIssuerPolicy,
+      bbsPlusGenIssuerHidingProof,
+      bbsPlusVerifyIssuerHidingProof
+    );
+  });
+
+  it("for BBS signatures", () => {
+    const sigParams: any[] = [], sks: Uint8Array[] = [], pks: Uint8Array[] = [], pksG1: Uint8Array[] = [];
+    for (let i = 0; i < issuerCount; i++) {
+      sigParams.push(bbsGenerateSignatureParams(messageCount));
+      sks.push(bbsGenerateSigningKey());
+      pks.push(bbsGeneratePublicKey(sks[i], sigParams[i]));
+      pksG1.push(bbsGeneratePublicKeyG1ForIssuerHiding(sks[i], sigParams[i]));
+    }
+    check(
+      sigParams, sks, pks, pksG1,
+      (i) => bbsSign(messages, sks[i], sigParams[i], true),
+      bbsSignIssuerPolicy,
+      bbsVerifyIssuerPolicy,
+      bbsGenIssuerHidingProof,
+      bbsVerifyIssuerHidingProof
+    );
+  });
+
+  it("when issuers sign different numbers of messages", () => {
+    // The second issuer signs only the first 3 messages
+    const counts = [messageCount, 3, messageCount];
+    const sigParams: any[] = [], sks: Uint8Array[] = [], pks: Uint8Array[] = [], pksG1: Uint8Array[] = [];
+    for (let i = 0; i < issuerCount; i++) {
+      sigParams.push(bbsPlusGenerateSignatureParamsG1(counts[i]));
+      sks.push(bbsPlusGenerateSigningKey());
+      pks.push(bbsPlusGeneratePublicKeyG2(sks[i], sigParams[i]));
+      pksG1.push(bbsPlusGeneratePublicKeyG1ForIssuerHiding(sks[i], sigParams[i]));
+    }
+    const policy = bbsPlusSignIssuerPolicy(sigParams, pks, pksG1, verifierSk);
+    const sig = bbsPlusSignG1(messages, sks[0], sigParams[0], true);
+
+    // Revealed messages that every issuer signs
+    const proof = bbsPlusGenIssuerHidingProof(policy, verifierPk, 0, sig, messages, revealedIndices, true, nonce);
+    expect(bbsPlusVerifyIssuerHidingProof(proof, policy, verifierPk, revealedMsgs, true, nonce).verified).toBe(true);
+
+    // Revealing a message that the second issuer doesn't sign is an error rather than a panic
+    const revealed = new Set<number>([0, 4]);
+    try {
+      bbsPlusGenIssuerHidingProof(policy, verifierPk, 0, sig, messages, revealed, true, nonce);
+      throw new Error("Expected to throw");
+    } catch (e) {
+      expect((e as WasmError).code).toEqual("INVALID_ARGUMENT");
+    }
+    const result = bbsPlusVerifyIssuerHidingProof(proof, policy, verifierPk, new Map([[0, messages[0]], [4, messages[4]]]), true, nonce);
+    expect(result.verified).toBe(false);
+    expect(result.code).toEqual("INVALID_ARGUMENT");
+  });
+});
@@ -0,0 +1,115 @@
+import {
+  initializeWasm,
+  bbsGenerateSignatureParams,
+  bbsGenerateSigningKey,
+  bbsGeneratePublicKey,
+  bbsPlusGenerateSignatureParamsG1,
+  bbsPlusGenerateSignatureParamsG2,
+  bbsPlusGenerateSigningKey,
+  bbsPlusGeneratePublicKeyG1,
+  bbsPlusGeneratePublicKeyG2,
+  generateAccumulatorParams,
+  generateAccumulatorSecretKey,
+  generateAccumulatorPublicKey,
+  psGenerateSignatureParams,
+  psGenerateSigningKey,
+  psGeneratePublicKey,
+  publicKeyToJwk,
+  publicKeyFromJwk,
+  publicKeyToMultikey,
+  publicKeyFromMultikey,
+  publicKeyToMultibase,
+  publicKeyFromMultibase,
+  psPublicKeyToJwkSet,
+  psPublicKeyFromJwkSet,
+  PublicKeyType,
+  WasmError,
+} from "../../lib";
+
+describe("Encoding public keys as JWK, Multikey and multibase", () => {
+  let keys: [PublicKeyType, Uint8Array][];
+
+  beforeAll(async () => {
+    await initializeWasm();
+    const bbsParams = bbsGenerateSignatureParams(2);
+    const bbsPlusSk = bbsPlusGenerateSigningKey();
+    const accumParams = generateAccumulatorParams();
+    keys = [
+      ["BBSPublicKey", bbsGeneratePublicKey(bbsGenerateSigningKey(), bbsParams)],
+      ["BBSPlusPublicKeyG1", bbsPlusGeneratePublicKeyG1(bbsPlusSk, bbsPlusGenerateSignatureParamsG2(2))],
+      ["BBSPlusPublicKeyG2", bbsPlusGeneratePublicKeyG2(bbsPlusSk, bbsPlusGenerateSignatureParamsG1(2))],
+      ["AccumPk", generateAccumulatorPublicKey(generateAccumulatorSecretKey(), accumParams)],
+    ];
+  });
+
+  function expectCode(f: () => unknown, code: string) {
+    try {
+      f();
+    } catch (e) {
+      expect((e as WasmError).code).toEqual(code);
+      return;
+    }
+    throw new Error("Expected to throw");
+  }
+
+  it("round trips", () => {
+    for (const [keyType, pk] of keys) {
+      const crv = keyType === "BBSPlusPublicKeyG1" ? "BLS12381G1" : "BLS12381G2";
+
+      const jwk = publicKeyToJwk(pk, keyType);
+      expect(jwk.kty).toEqual("OKP");
+      expect(jwk.crv).toEqual(crv);
+      expect(publicKeyFromJwk(jwk, keyType)).toEqual(pk);
+
+      const multibase = publicKeyToMultibase(pk, keyType);
+      expect(multibase.startsWith("z")).toBe(true);
+      expect(publicKeyFromMultibase(multibase, keyType)).toEqual(pk);
+
+      const multikey = publicKeyToMultikey(pk, keyType, "did:example:123#key-1", "did:example:123");
+      expect(multikey.type).toEqual("Multikey");
+      expect(multikey.id).toEqual("did:example:123#key-1");
+      expect(multikey.controller).toEqual("did:example:123");
+      expect(multikey.publicKeyMultibase).toEqual(multibase);
+      expect(publicKeyFromMultikey(multikey, keyType)).toEqual(pk);
+    }
+
+    // Multicodec prefixes of bls12_381-g1-pub and bls12_381-g2-pub in base58btc
+    expect(publicKeyToMultibase(keys[0][1], "BBSPublicKey").startsWith("zUC7")).toBe(true);
+    expect(publicKeyToMultibase(keys[1][1], "BBSPlusPublicKeyG1").startsWith("z3t")).toBe(true);
+  });
+
+  it("rejects mismatched curves", () => {
+    const g2Pk = keys[0][1];
+    const g1Pk = keys[1][1];
+
+    expectCode(() => publicKeyFromMultibase(publicKeyToMultibase(g2Pk, "BBSPublicKey"), "BBSPlusPublicKeyG1"), "CURVE_MISMATCH");
+    expectCode(() => publicKeyFromMultibase(publicKeyToMultibase(g1Pk, "BBSPlusPublicKeyG1"), "AccumPk"), "CURVE_MISMATCH");
+    expectCode(() => publicKeyFromJwk(publicKeyToJwk(g1Pk, "BBSPlusPublicKeyG1"), "BBSPublicKey"), "CURVE_MISMATCH");
+    expectCode(() => publicKeyFromMultikey(publicKeyToMultikey(g2Pk, "AccumPk"), "BBSPlusPublicKeyG1"), "CURVE_MISMATCH");
+
+    // Exporting checks that the key is of the given type
+    expect(() => publicKeyToJwk(g1Pk, "BBSPublicKey")).toThrow();
+
+    // Multibase other than base58btc and invalid points
+    expectCode(() => publicKeyFromMultibase("m" + publicKeyToMultibase(g2Pk, "BBSPublicKey").slice(1), "BBSPublicKey"), "INVALID_KEY_ENCODING");
+    const jwk = publicKeyToJwk(g2Pk, "BBSPublicKey");
+    expect(() => publicKeyFromJwk({ ...jwk, x: jwk.x.slice(0, 20) + "A" + jwk.x.slice(21) }, "BBSPublicKey")).toThrow();
+  });
+
+  it("encodes PS public keys as a JWK Set", () => {
+    const params = psGenerateSignatureParams(2);
+    const pk = psGeneratePublicKey(psGenerateSigningKey(2), params);
+    expect(() => publicKeyToMultibase(pk, "PSPublicKey" as PublicKeyType)).toThrow();
+
+    const jwkSet = psPublicKeyToJwkSet(pk);
+    expect(jwkSet.keys.map((k) => k.kid)).toEqual(["alpha_tilde", "beta_tilde.0", "beta_tilde.1", "beta.0", "beta.1"]);
+    expect(jwkSet.keys.map((k) => k.crv)).toEqual(["BLS12381G2", "BLS12381G2", "BLS12381G2", "BLS12381G1", "BLS12381G1"]);
+    expect(psPublicKeyFromJwkSet(jwkSet)).toEqual(pk);
+    expect(psPublicKeyFromJwkSet(JSON.parse(JSON.stringify(jwkSet)))).toEqual(pk);
+
+    const keys = [...jwkSet.keys];
+    keys[3] = { ...keys[3], crv: "BLS12381G2" };
+    expectCode(() => psPublicKeyFromJwkSet({ keys }), "CURVE_MISMATCH");
+    expectCode(() => psPublicKeyFromJwkSet({ keys: jwkSet.keys.slice(0, 3) }), "INVALID_KEY_ENCODING");
+  });
+});
@@ -35,6 +35,22 @@ import {
   generateRandomFieldElement,
   bbsPlusGenerateSignatureParamsG1,
   generateWitnessEqualityMetaStatement,
+  generateLinearRelationStatement,
+  generateLinearRelationMetaStatements,
+  generateLinearRelationWitness,
+  generateBBSPlusBlindSignatureRequestStatement,
+  generateBBSBlindSignatureRequestStatement,
+  generatePSBlindSignatureRequestStatements,
+  generateBBSPlusBlindSignatureRequestWitness,
+  generateBBSBlindSignatureRequestWitness,
+  generatePSBlindSignatureRequestWitnesses,
+  bbsCommitMsgs,
+  bbsBlindSign,
+  bbsVerify,
+  psMessageCommitment,
+  psBlindSign,
+  psUnblindSignature,
+  psVerify,
   positiveAccumulatorAdd,
   positiveAccumulatorGetAccumulated,
   positiveAccumulatorInitialize,
@@ -80,6 +96,32 @@ import {
   generateSetupParamForPSSignatureParameters,
   generateSetupParamForBBSSignatureParameters,
   generatePoKBBSSignatureStatementFromParamRefs,
+  describeProofSpec,
+  proofSpecSatisfiedBy,
+  extractFromCompositeProofG1,
+  generateOrMetaStatement,
+  generateOrProofG1,
+  verifyOrProofG1,
+  WasmError,
+  generateCompositeProofG1WithOrMetaStatements,
+  verifyCompositeProofG1WithOrMetaStatements,
+  generateOrWitnessEquality,
+  bbsPlusGeneratePublicKeyG1ForIssuerHiding,
+  bbsGeneratePublicKeyG1ForIssuerHiding,
+  compositeProofInit,
+  interactiveProverStateFromBytes,
+  compositeProofCommitments,
+  compositeProofRespond,
+  compositeProofChallenge,
+  compositeProofVerify,
+  bbsPlusSigParamsG1Handle,
+  bbsPlusPublicKeyG2Handle,
+  bbsPlusSignG1WithHandles,
+  bbsPlusVerifyG1WithHandles,
+  proofSpecG1Handle,
+  proofSpecG1HandleFromBytes,
+  generateCompositeProofG1WithHandle,
+  verifyCompositeProofG1WithHandle,
 } from "../../lib";
 import { BbsSigParams, PSSigParams } from "../../lib/types";
 
@@ -540,6 +582,14 @@ describe("Proving knowledge of signatures and accumulator membership and non-mem
 
     const res = verifyCompositeProofG1(proof, proofSpec);
     expect(res.verified).toBe(true);
+
+    // Accumulator values are public values of the statements and not commitments
+    const details = extractFromCompositeProofG1(proof, proofSpec);
+    expect(details[2].accumulated).toEqual(posAccumulated);
+    expect(details[3].accumulated).toEqual(uniAccumulated);
+    expect(details[4].accumulated).toEqual(uniAccumulated);
+    expect(details[2].commitments).toEqual({});
+    expect(details[0].accumulated).toBeUndefined();
   }
 
   it("generate and verify a proof of knowledge of a BBS signature and accumulator membership", () => {
@@ -679,6 +729,216 @@ describe("Proving knowledge of a BBS+ signature while requesting a partially bli
   });
 });
 
+describe("Proving linear relations between hidden messages", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("of messages from 2 BBS+ signatures", () => {
+    // Messages of 1st signature are: salary, bonus, issuance date and expiry date
+    // Messages of 2nd signature are: annual income, tax id
+    const [sigParams1, sk1, pk1] = setupSignerBBSPlus(4);
+    const [sigParams2, sk2, pk2] = setupSignerBBSPlus(2);
+    const messages1 = [1000, 500, 19000, 19365].map((n) => generateFieldElementFromNumber(n));
+    const messages2 = [generateFieldElementFromNumber(12500), generateRandomFieldElement()];
+    const sig1 = bbsPlusSignG1(messages1, sk1, sigParams1, false);
+    const sig2 = bbsPlusSignG1(messages2, sk2, sigParams2, false);
+
+    const [revealedMsgs1, unrevealedMsgs1] = getRevealedUnrevealed(messages1, new Set<number>());
+    const [revealedMsgs2, unrevealedMsgs2] = getRevealedUnrevealed(messages2, new Set<number>([1]));
+
+    // (salary * 12) + bonus - annual income = 0, and expiry date - issuance date = 365
+    const relations: [(number | Uint8Array)[], number, [number, number][]][] = [
+      [[12, 1, -1], 0, [[0, 0], [0, 1], [1, 0]]],
+      [[1, -1], 365, [[0, 3], [0, 2]]],
+    ];
+
+    function check(constants: number[]): boolean {
+      const statements = [
+        generatePoKBBSPlusSignatureStatement(sigParams1, pk1, revealedMsgs1, false),
+        generatePoKBBSPlusSignatureStatement(sigParams2, pk2, revealedMsgs2, false),
+      ];
+      const metaStatements: Uint8Array[] = [];
+      const witnesses = [
+        generatePoKBBSPlusSignatureWitness(sig1, unrevealedMsgs1, false),
+        generatePoKBBSPlusSignatureWitness(sig2, unrevealedMsgs2, false),
+      ];
+      relations.forEach(([coefficients, , witnessRefs], i) => {
+        const relationWitness = generateLinearRelationWitness(witnesses.slice(0, 2), witnessRefs);
+        const statement = generateLinearRelationStatement(coefficients, constants[i]);
+        statements.push(statement);
+        metaStatements.push(...generateLinearRelationMetaStatements(statements.length - 1, statement, witnessRefs));
+        witnesses.push(relationWitness);
+      });
+
+      const proofSpec = generateProofSpecG1(statements, metaStatements, []);
+      expect(isProofSpecG1Valid(proofSpec)).toEqual(true);
+      const proof = generateCompositeProofG1(proofSpec, witnesses);
+      return verifyCompositeProofG1(proof, proofSpec).verified;
+    }
+
+    expect(check(relations.map((r) => r[1]))).toBe(true);
+    // Relations don't hold for these constants
+    expect(check([1, 365])).toBe(false);
+    expect(check([0, 364])).toBe(false);
+
+    // Coefficients can also be field elements
+    expect(generateLinearRelationStatement([generateFieldElementFromNumber(12)], generateFieldElementFromNumber(0))).toBeInstanceOf(Uint8Array);
+    expect(() => generateLinearRelationStatement([1.5], 0)).toThrow();
+    // A witness reference is needed for each coefficient and not to the linear relation statement itself
+    const relationStatement = generateLinearRelationStatement([1, -1], 0);
+    for (const [index, witnessRefs] of [
+      [2, [[0, 3]]],
+      [2, [[0, 3], [0, 2], [1, 0]]],
+      [2, [[0, 3], [2, 0]]],
+    ] as [number, [number, number][]][]) {
+      try {
+        generateLinearRelationMetaStatements(index, relationStatement, witnessRefs);
+        throw new Error("Expected to throw");
+      } catch (e) {
+        expect((e as WasmError).code).toEqual("INVALID_ARGUMENT");
+      }
+    }
+    // Revealed messages aren't witnesses
+    expect(() => generateLinearRelationWitness([generatePoKBBSPlusSignatureWitness(sig2, unrevealedMsgs2, false)], [[0, 1]])).toThrow();
+    // Witness references must be pairs of indices
+    for (const wr of [[0, 1.5], [0, -1], [0, "1"]]) {
+      try {
+        generateLinearRelationWitness([generatePoKBBSPlusSignatureWitness(sig2, unrevealedMsgs2, false)], [wr as [number, number]]);
+        throw new Error("Expected to throw");
+      } catch (e) {
+        expect((e as WasmError).code).toEqual("INVALID_ARGUMENT");
+      }
+      try {
+        generateWitnessEqualityMetaStatement(new Set<[number, number]>([[0, 0], wr as [number, number]]));
+        throw new Error("Expected to throw");
+      } catch (e) {
+        expect((e as WasmError).code).toEqual("INVALID_ARGUMENT");
+      }
+    }
+  });
+});
+
+describe("Requesting a blind signature while presenting a credential", () => {
+  // Credential A is a BBS+ signature with the link secret at index 2 and the requested credential B has the link
+  // secret at index 1
+  const linkSecret = generateRandomFieldElement();
+  let sigParamsA: BbsPlusSigParams, pkA: Uint8Array, sigA: Uint8Array, messagesA: Uint8Array[];
+
+  beforeAll(async () => {
+    await initializeWasm();
+    let skA: Uint8Array;
+    [sigParamsA, skA, pkA] = setupSignerBBSPlus(4);
+    messagesA = [generateRandomFieldElement(), generateRandomFieldElement(), linkSecret, generateRandomFieldElement()];
+    sigA = bbsPlusSignG1(messagesA, skA, sigParamsA, false);
+  });
+
+  // Proves knowledge of credential A and the request statements `statementsB` with witnesses `witnessesB` and returns
+  // the verification result. `linkSecretWitnessB` is the link secret's witness index in the first request statement.
+  function proveAndVerify(statementsB: Uint8Array[], witnessesB: Uint8Array[], linkSecretWitnessB: number): boolean {
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messagesA, new Set<number>([0]));
+    const statements = [generatePoKBBSPlusSignatureStatement(sigParamsA, pkA, revealedMsgs, false), ...statementsB];
+    const metaStatements = [generateWitnessEqualityMetaStatement(new Set<[number, number]>([[0, 2], [1, linkSecretWitnessB]]))];
+    const proofSpec = generateProofSpecG1(statements, metaStatements, []);
+    expect(isProofSpecG1Valid(proofSpec)).toEqual(true);
+    const witnesses = [generatePoKBBSPlusSignatureWitness(sigA, unrevealedMsgs, false), ...witnessesB];
+    const nonce = stringToBytes("test");
+    const proof = generateCompositeProofG1(proofSpec, witnesses, nonce);
+    return verifyCompositeProofG1(proof, proofSpec, nonce).verified;
+  }
+
+  function uncommitted(messages: Uint8Array[], committedIndices: Set<number>): Map<number, Uint8Array> {
+    const msgs = new Map();
+    messages.forEach((m, i) => {
+      if (!committedIndices.has(i)) {
+        msgs.set(i, m);
+      }
+    });
+    return msgs;
+  }
+
+  it("for a BBS+ signature", () => {
+    const [sigParams, sk, pk] = setupSignerBBSPlus(4);
+    const messages = [generateRandomFieldElement(), linkSecret, generateRandomFieldElement(), generateRandomFieldElement()];
+    const committedIndices = new Set<number>([1, 3]);
+    const msgsToCommit = new Map([[3, messages[3]], [1, messages[1]]]);
+    const blinding = generateRandomFieldElement();
+    const commitment = bbsPlusCommitMsgsInG1(msgsToCommit, blinding, sigParams, false);
+
+    const statement = generateBBSPlusBlindSignatureRequestStatement(sigParams, committedIndices, commitment);
+    const witness = generateBBSPlusBlindSignatureRequestWitness(msgsToCommit, blinding, false);
+    // Witness 0 is the blinding and link secret is the 1st committed message
+    expect(proveAndVerify([statement], [witness], 1)).toBe(true);
+    expect(proveAndVerify([statement], [witness], 2)).toBe(false);
+    // Commitment doesn't match the committed indices
+    const wrongStatement = generateBBSPlusBlindSignatureRequestStatement(sigParams, new Set<number>([1, 2]), commitment);
+    expect(proveAndVerify([wrongStatement], [witness], 1)).toBe(false);
+    // Committed indices must be indices
+    for (const indices of [new Set(["1"]), new Set([-1]), new Set([1.5])]) {
+      try {
+        generateBBSPlusBlindSignatureRequestStatement(sigParams, indices as Set<number>, commitment);
+        throw new Error("Expected to throw");
+      } catch (e) {
+        expect((e as WasmError).code).toEqual("INVALID_ARGUMENT");
+      }
+    }
+
+    const blindSig = bbsPlusBlindSignG1(commitment, uncommitted(messages, committedIndices), sk, sigParams, false);
+    const sig = bbsPlusUnblindSigG1(blindSig, blinding);
+    expect(bbsPlusVerifyG1(messages, sig, pk, sigParams, false).verified).toBe(true);
+  });
+
+  it("for a BBS signature", () => {
+    const [sigParams, sk, pk] = setupSignerBBS(4);
+    const messages = [generateRandomFieldElement(), linkSecret, generateRandomFieldElement(), generateRandomFieldElement()];
+    const committedIndices = new Set<number>([1, 3]);
+    const msgsToCommit = new Map([[1, messages[1]], [3, messages[3]]]);
+    const commitment = bbsCommitMsgs(msgsToCommit, sigParams, false);
+
+    const statement = generateBBSBlindSignatureRequestStatement(sigParams, committedIndices, commitment);
+    const witness = generateBBSBlindSignatureRequestWitness(msgsToCommit, false);
+    expect(proveAndVerify([statement], [witness], 0)).toBe(true);
+    expect(proveAndVerify([statement], [witness], 1)).toBe(false);
+
+    const sig = bbsBlindSign(commitment, uncommitted(messages, committedIndices), sk, sigParams, false);
+    expect(bbsVerify(messages, sig, pk, sigParams, false).verified).toBe(true);
+  });
+
+  it("for a PS signature", () => {
+    const [sigParams, sk, pk] = setupSignerPS(4);
+    const messages = [generateRandomFieldElement(), linkSecret, generateRandomFieldElement(), generateRandomFieldElement()];
+    const h = generateRandomG1Element();
+    const msgsToCommit = new Map([[1, messages[1]], [3, messages[3]]]);
+    const blindings = new Map();
+    const commitments = new Map();
+    for (const [i, m] of msgsToCommit) {
+      blindings.set(i, generateRandomFieldElement());
+      commitments.set(i, psMessageCommitment(m, blindings.get(i), h, sigParams.g));
+    }
+
+    const statements = generatePSBlindSignatureRequestStatements(sigParams, h, commitments);
+    const witnesses = generatePSBlindSignatureRequestWitnesses(msgsToCommit, blindings);
+    expect(statements.length).toEqual(2);
+    expect(witnesses.length).toEqual(2);
+    // Witness 0 of each statement is the blinding and witness 1 is the message
+    expect(proveAndVerify(statements, witnesses, 1)).toBe(true);
+    expect(proveAndVerify(statements, witnesses, 0)).toBe(false);
+    try {
+      generatePSBlindSignatureRequestStatements(sigParams, h, new Map([["1", commitments.get(1)]]) as unknown as Map<number, Uint8Array>);
+      throw new Error("Expected to throw");
+    } catch (e) {
+      expect((e as WasmError).code).toEqual("INVALID_ARGUMENT");
+    }
+
+    const msgOrComs = messages.map((m, i) =>
+      commitments.has(i) ? { BlindedMessage: commitments.get(i) } : { RevealedMessage: m }
+    );
+    const blindSig = psBlindSign(msgOrComs, sk, h);
+    const sig = psUnblindSignature(blindSig, blindings, pk);
+    expect(psVerify(messages, sig, pk, sigParams).verified).toBe(true);
+  });
+});
+
 describe("Proving equality of openings of Pedersen commitments", () => {
   let messages: Uint8Array[];
 
@@ -1170,3 +1430,382 @@ describe("Reusing setup params of BBS, BBS+ and accumulator", () => {
     check(bbsSign, generateSetupParamForBBSSignatureParameters, generateSetupParamForBBSPlusPublicKeyG2, generatePoKBBSSignatureStatementFromParamRefs, generatePoKBBSSignatureWitness)
   });
 });
+
+describe("Describing and matching proof specs", () => {
+  const messageCount = 5;
+  let sigParams: BbsPlusSigParams, sigSk: Uint8Array, sigPk: Uint8Array, messages: Uint8Array[];
+
+  beforeAll(async () => {
+    await initializeWasm();
+    [sigParams, sigSk, sigPk] = setupSignerBBSPlus(messageCount);
+    messages = setupMessages(messageCount, "Message", true);
+  });
+
+  function proofSpec(revealedIndices: Set<number>, useParamRefs: boolean, equalities: [number, number][][], context?: Uint8Array): Uint8Array {
+    const [revealedMsgs] = getRevealedUnrevealed(messages, revealedIndices);
+    const statements: Uint8Array[] = [];
+    const setupParams: Uint8Array[] = [];
+    if (useParamRefs) {
+      setupParams.push(generateSetupParamForBBSPlusSignatureParametersG1(sigParams));
+      setupParams.push(generateSetupParamForBBSPlusPublicKeyG2(sigPk));
+      statements.push(generatePoKBBSPlusSignatureStatementFromParamRefs(0, 1, revealedMsgs, false));
+      statements.push(generatePoKBBSPlusSignatureStatementFromParamRefs(0, 1, new Map(), false));
+    } else {
+      statements.push(generatePoKBBSPlusSignatureStatement(sigParams, sigPk, revealedMsgs, false));
+      statements.push(generatePoKBBSPlusSignatureStatement(sigParams, sigPk, new Map(), false));
+    }
+    const metaStatements = equalities.map((eq) => generateWitnessEqualityMetaStatement(new Set(eq)));
+    return generateProofSpecG1(statements, metaStatements, setupParams, context);
+  }
+
+  it("describe a proof spec", () => {
+    const spec = proofSpec(new Set([1, 3]), true, [[[0, 0], [1, 0]]], stringToBytes("test-context"));
+    const desc = describeProofSpec(spec);
+    expect(desc.statements.length).toEqual(2);
+    expect(desc.statements[0].type).toEqual("PoKBBSSignatureG1");
+    expect(Object.keys(desc.statements[0].revealedMessages as object)).toEqual(["1", "3"]);
+    expect(desc.statements[0].revealedMessages?.["3"]).toEqual(messages[3]);
+    expect(desc.statements[1].revealedMessages).toEqual({});
+    expect(desc.statements[0].paramRefs).toEqual({ signatureParams: 0, publicKey: 1 });
+    expect(desc.statements[0].inlineParams).toEqual([]);
+    expect(desc.witnessEqualities).toEqual([[[0, 0], [1, 0]]]);
+    expect(desc.setupParams).toEqual(["BBSPlusSignatureParams", "BBSPlusPublicKey"]);
+    expect(desc.context).toEqual(stringToBytes("test-context"));
+
+    const inlineDesc = describeProofSpec(proofSpec(new Set([1]), false, []));
+    expect(inlineDesc.statements[0].paramRefs).toEqual({});
+    expect(inlineDesc.statements[0].inlineParams).toEqual(["signatureParams", "publicKey"]);
+    expect(inlineDesc.witnessEqualities).toEqual([]);
+    expect(inlineDesc.setupParams).toEqual([]);
+    expect(inlineDesc.context).toBeUndefined();
+  });
+
+  it("match a proof spec against a template", () => {
+    const template = proofSpec(new Set([1, 3]), true, [[[0, 0], [1, 0]]]);
+
+    // Same requirements with parameters given inline and an extra witness equality
+    let res = proofSpecSatisfiedBy(proofSpec(new Set([1, 3]), false, [[[0, 0], [1, 0]], [[0, 2], [1, 2]]]), template);
+    expect(res.verified).toBe(true);
+
+    // Different revealed messages
+    res = proofSpecSatisfiedBy(proofSpec(new Set([1]), true, [[[0, 0], [1, 0]]]), template);
+    expect(res.verified).toBe(false);
+    expect(res.error).toContain("reveals messages");
+
+    // Required witness equality missing
+    res = proofSpecSatisfiedBy(proofSpec(new Set([1, 3]), true, [[[0, 2], [1, 2]]]), template);
+    expect(res.verified).toBe(false);
+    expect(res.error).toContain("Witness equality");
+
+    // An empty witness equality in the template requires nothing
+    res = proofSpecSatisfiedBy(proofSpec(new Set([1, 3]), true, [[[0, 0], [1, 0]]]), proofSpec(new Set([1, 3]), true, [[[0, 0], [1, 0]], []]));
+    expect(res.verified).toBe(true);
+
+    // Different public key
+    const [, , otherPk] = setupSignerBBSPlus(messageCount);
+    const [revealedMsgs] = getRevealedUnrevealed(messages, new Set([1, 3]));
+    const statements = [
+      generatePoKBBSPlusSignatureStatement(sigParams, otherPk, revealedMsgs, false),
+      generatePoKBBSPlusSignatureStatement(sigParams, sigPk, new Map(), false),
+    ];
+    const metaStatements = [generateWitnessEqualityMetaStatement(new Set<[number, number]>([[0, 0], [1, 0]]))];
+    res = proofSpecSatisfiedBy(generateProofSpecG1(statements, metaStatements, []), template);
+    expect(res.verified).toBe(false);
+    expect(res.error).toContain("Public key");
+
+    // Different context
+    res = proofSpecSatisfiedBy(proofSpec(new Set([1, 3]), true, [[[0, 0], [1, 0]]], stringToBytes("ctx")), template);
+    expect(res.verified).toBe(false);
+  });
+
+  it("extract revealed messages and responses from a proof", () => {
+    const revealedIndices = new Set([1, 3]);
+    const spec = proofSpec(revealedIndices, true, [[[0, 0], [1, 0]]]);
+    const sig = bbsPlusSignG1(messages, sigSk, sigParams, false);
+    const [, unrevealedMsgs] = getRevealedUnrevealed(messages, revealedIndices);
+    const witnesses = [
+      generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false),
+      generatePoKBBSPlusSignatureWitness(sig, new Map(messages.map((m, i) => [i, m])), false),
+    ];
+    const proof = generateCompositeProofG1(spec, witnesses);
+    expect(verifyCompositeProofG1(proof, spec).verified).toBe(true);
+
+    const details = extractFromCompositeProofG1(proof, spec);
+    expect(details.length).toEqual(2);
+    expect(details[0].type).toEqual("PoKBBSSignatureG1");
+    expect(details[0].revealedMessages?.["1"]).toEqual(messages[1]);
+    expect(details[0].revealedMessages?.["3"]).toEqual(messages[3]);
+    expect(Object.keys(details[0].responses)).toEqual(["0", "2", "4"]);
+    expect(Object.keys(details[1].responses)).toEqual(["0", "1", "2", "3", "4"]);
+    // Response is same for equal witnesses
+    expect(details[0].responses["0"]).toEqual(details[1].responses["0"]);
+    expect(details[0].responses["2"]).not.toEqual(details[1].responses["2"]);
+  });
+});
+
+describe("Proving one of many proof specs", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("generate and verify an OR proof over Pedersen commitments", () => {
+    const bases1 = [generateRandomG1Element(), generateRandomG1Element()];
+    const m1 = [generateRandomFieldElement(), generateRandomFieldElement()];
+    const commitment1 = pedersenCommitmentG1(bases1, m1);
+
+    const bases2 = [generateRandomG1Element(), generateRandomG1Element()];
+    const m2 = [generateRandomFieldElement(), m1[1]];
+    const commitment2 = pedersenCommitmentG1(bases2, m2);
+
+    const bases3 = [generateRandomG1Element()];
+    const m3 = [generateRandomFieldElement()];
+    const commitment3 = pedersenCommitmentG1(bases3, m3);
+
+    const eq = new Set<[number, number]>();
+    eq.add([0, 1]);
+    eq.add([1, 1]);
+    const proofSpec1 = generateProofSpecG1(
+      [
+        generatePedersenCommitmentG1Statement(bases1, commitment1),
+        generatePedersenCommitmentG1Statement(bases2, commitment2),
+      ],
+      [generateWitnessEqualityMetaStatement(eq)],
+      []
+    );
+    const proofSpec2 = generateProofSpecG1(
+      [generatePedersenCommitmentG1Statement(bases3, commitment3)],
+      [],
+      []
+    );
+    const orMetaStatement = generateOrMetaStatement([proofSpec1, proofSpec2]);
+    const nonce = stringToBytes("a nonce");
+
+    const proof1 = generateOrProofG1(
+      orMetaStatement,
+      0,
+      [generatePedersenCommitmentWitness(m1), generatePedersenCommitmentWitness(m2)],
+      nonce
+    );
+    expect(verifyOrProofG1(proof1, orMetaStatement, nonce).verified).toBe(true);
+    expect(verifyOrProofG1(proof1, orMetaStatement).verified).toBe(false);
+
+    const proof2 = generateOrProofG1(orMetaStatement, 1, [generatePedersenCommitmentWitness(m3)], nonce);
+    expect(verifyOrProofG1(proof2, orMetaStatement, nonce).verified).toBe(true);
+
+    // Witnesses don't satisfy the proof spec
+    expect(() => generateOrProofG1(orMetaStatement, 1, [generatePedersenCommitmentWitness([m1[0]])], nonce)).toThrow();
+
+    // Statements that can't be simulated aren't supported
+    const [psParams, , psPk] = setupSignerPS(2);
+    const psProofSpec = generateProofSpecG1([generatePoKPSSignatureStatement(psParams, psPk, new Map())], [], []);
+    expect(() => generateOrMetaStatement([proofSpec1, psProofSpec])).toThrow();
+  });
+
+  it("generate and verify an OR proof over credentials from different issuers along with a composite proof", () => {
+    const messageCount = 4;
+    const [bbsPlusParams, bbsPlusSk, bbsPlusPk, messages] = setupBBSPlus(messageCount, "or", true);
+    const [bbsParams, bbsSk, bbsPk] = setupSignerBBS(messageCount);
+    const bbsPlusPkG1 = bbsPlusGeneratePublicKeyG1ForIssuerHiding(bbsPlusSk, bbsPlusParams);
+    const bbsPkG1 = bbsGeneratePublicKeyG1ForIssuerHiding(bbsSk, bbsParams);
+
+    // The holder only has the credential from the BBS issuer
+    const sig = bbsSign(messages, bbsSk, bbsParams, false);
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set<number>([0]));
+    const proofSpec1 = generateProofSpecG1(
+      [generatePoKBBSPlusSignatureStatement(bbsPlusParams, bbsPlusPk, revealedMsgs, false)],
+      [],
+      []
+    );
+    const proofSpec2 = generateProofSpecG1(
+      [generatePoKBBSSignatureStatement(bbsParams, bbsPk, revealedMsgs, false)],
+      [],
+      []
+    );
+    // The keys in G1 are needed to simulate the proof for the credential the holder doesn't have
+    expect(() => generateOrMetaStatement([proofSpec1, proofSpec2])).toThrow();
+    expect(() => generateOrMetaStatement([proofSpec1, proofSpec2], [new Map([[0, bbsPkG1]]), new Map([[0, bbsPkG1]])])).toThrow();
+    const orMetaStatement = generateOrMetaStatement(
+      [proofSpec1, proofSpec2],
+      [new Map([[0, bbsPlusPkG1]]), new Map([[0, bbsPkG1]])]
+    );
+    const orWitnesses = [generatePoKBBSSignatureWitness(sig, unrevealedMsgs, false)];
+    const nonce = stringToBytes("a nonce");
+
+    const orProof = generateOrProofG1(orMetaStatement, 1, orWitnesses, nonce);
+    expect(verifyOrProofG1(orProof, orMetaStatement, nonce).verified).toBe(true);
+    // The witnesses are of the BBS credential so can't satisfy the other proof spec
+    expect(() => generateOrProofG1(orMetaStatement, 0, orWitnesses, nonce)).toThrow();
+
+    // Along with a composite proof of a commitment to the holder's secret, which is message 1 of the credential
+    const bases = [generateRandomG1Element(), generateRandomG1Element()];
+    const m = [messages[1], generateRandomFieldElement()];
+    const equality = generateOrWitnessEquality([0, 0], 0, [[0, 1], [0, 1]]);
+    const proofSpec = generateProofSpecG1(
+      [generatePedersenCommitmentG1Statement(bases, pedersenCommitmentG1(bases, m))],
+      [],
+      [],
+      undefined,
+      [orMetaStatement],
+      [equality]
+    );
+    const proof = generateCompositeProofG1WithOrMetaStatements(
+      proofSpec,
+      [generatePedersenCommitmentWitness(m)],
+      [1],
+      [orWitnesses],
+      nonce
+    );
+    expect(verifyCompositeProofG1WithOrMetaStatements(proof, proofSpec, nonce).verified).toBe(true);
+    expect(verifyCompositeProofG1WithOrMetaStatements(proof, proofSpec).verified).toBe(false);
+
+    // The secret in the composite proof must be the one in the credential
+    const other = [generateRandomFieldElement(), m[1]];
+    const otherProofSpec = generateProofSpecG1(
+      [generatePedersenCommitmentG1Statement(bases, pedersenCommitmentG1(bases, other))],
+      [],
+      [],
+      undefined,
+      [orMetaStatement],
+      [equality]
+    );
+    expect(() =>
+      generateCompositeProofG1WithOrMetaStatements(
+        otherProofSpec,
+        [generatePedersenCommitmentWitness(other)],
+        [1],
+        [orWitnesses],
+        nonce
+      )
+    ).toThrow();
+
+    // Each proof spec of the "OR" meta-statement needs a witness in the equality
+    expect(() =>
+      generateProofSpecG1(
+        [generatePedersenCommitmentG1Statement(bases, pedersenCommitmentG1(bases, m))],
+        [],
+        [],
+        undefined,
+        [orMetaStatement],
+        [generateOrWitnessEquality([0, 0], 0, [[0, 1]])]
+      )
+    ).toThrow();
+  });
+});
+
+describe("Interactive composite proofs", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("of BBS+ and PS signatures with equal messages", () => {
+    const [sigParams1, sk1, pk1, messages1] = setupBBSPlus(4, "bbs-plus", true);
+    const [sigParams2, sk2, pk2, messages2] = setupPS(3, "ps", true);
+    // 2nd message of both signatures is the same
+    messages2[1] = messages1[1];
+    const sig1 = bbsPlusSignG1(messages1, sk1, sigParams1, false);
+    const sig2 = psSign(messages2, sk2, sigParams2);
+
+    const [revealedMsgs1, unrevealedMsgs1] = getRevealedUnrevealed(messages1, new Set<number>([0]));
+    const [revealedMsgs2, unrevealedMsgs2] = getRevealedUnrevealed(messages2, new Set<number>([2]));
+    const statements = [
+      generatePoKBBSPlusSignatureStatement(sigParams1, pk1, revealedMsgs1, false),
+      generatePoKPSSignatureStatement(sigParams2, pk2, revealedMsgs2),
+    ];
+    const metaStatements = [generateWitnessEqualityMetaStatement(new Set<[number, number]>([[0, 1], [1, 1]]))];
+    const proofSpec = generateProofSpecG1(statements, metaStatements, [], stringToBytes("context"));
+    expect(isProofSpecG1Valid(proofSpec)).toEqual(true);
+    const witnesses = [
+      generatePoKBBSPlusSignatureWitness(sig1, unrevealedMsgs1, false),
+      generatePoKPSSignatureWitness(sig2, unrevealedMsgs2),
+    ];
+
+    // Prover sends the commitments, verifier replies with a challenge and prover responds with the proof. The state is
+    // kept in wasm memory between the calls.
+    const state = compositeProofInit(proofSpec, witnesses);
+    const commitments = compositeProofCommitments(state);
+    // Commitments are the same until the prover responds
+    expect(compositeProofCommitments(state)).toEqual(commitments);
+    const challenge = compositeProofChallenge();
+    const proof = compositeProofRespond(state, challenge);
+    // Responding to another challenge would reveal the witnesses so the state can only be used once
+    expect(() => compositeProofRespond(state, compositeProofChallenge())).toThrow();
+    expect(() => compositeProofCommitments(state)).toThrow();
+
+    expect(compositeProofVerify(proof, proofSpec, commitments, challenge).verified).toBe(true);
+    // Proof is only valid for the verifier's challenge and the prover's commitments
+    expect(compositeProofVerify(proof, proofSpec, commitments, compositeProofChallenge()).verified).toBe(false);
+    const otherState = compositeProofInit(proofSpec, witnesses);
+    const otherCommitments = compositeProofCommitments(otherState);
+    otherState.free();
+    expect(compositeProofVerify(proof, proofSpec, otherCommitments, challenge).verified).toBe(false);
+    // Proof isn't a non-interactive proof
+    expect(verifyCompositeProofG1(proof, proofSpec).verified).toBe(false);
+
+    // State can be serialized after sending the commitments and restored to respond, but only once
+    const stateToStore = compositeProofInit(proofSpec, witnesses);
+    const storedCommitments = compositeProofCommitments(stateToStore);
+    const stored = stateToStore.toBytes();
+    stateToStore.free();
+    const restored = interactiveProverStateFromBytes(stored);
+    expect(compositeProofCommitments(restored)).toEqual(storedCommitments);
+    const storedChallenge = compositeProofChallenge();
+    const storedProof = compositeProofRespond(restored, storedChallenge);
+    expect(compositeProofVerify(storedProof, proofSpec, storedCommitments, storedChallenge).verified).toBe(true);
+    expect(() => restored.toBytes()).toThrow();
+  });
+
+  it("fails when witnesses aren't equal", () => {
+    const [sigParams, sk, pk, messages] = setupBBSPlus(4, "bbs-plus", true);
+    const sig = bbsPlusSignG1(messages, sk, sigParams, false);
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set<number>());
+    const statements = [generatePoKBBSPlusSignatureStatement(sigParams, pk, revealedMsgs, false)];
+    const metaStatements = [generateWitnessEqualityMetaStatement(new Set<[number, number]>([[0, 1], [0, 2]]))];
+    const proofSpec = generateProofSpecG1(statements, metaStatements, []);
+    const state = compositeProofInit(proofSpec, [generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false)]);
+    const commitments = compositeProofCommitments(state);
+    const challenge = compositeProofChallenge();
+    const proof = compositeProofRespond(state, challenge);
+    expect(compositeProofVerify(proof, proofSpec, commitments, challenge).verified).toBe(false);
+  });
+});
+
+describe("Using handles to params, keys and proof spec", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("generate and verify proofs of knowledge of BBS+ signatures", () => {
+    const [sigParams, sk, pk, messages] = setupBBSPlus(5, "bbs-plus", true);
+    const paramsHandle = bbsPlusSigParamsG1Handle(sigParams);
+    const pkHandle = bbsPlusPublicKeyG2Handle(pk);
+    expect(paramsHandle.supportedMessageCount()).toEqual(5);
+
+    const sig = bbsPlusSignG1WithHandles(messages, sk, paramsHandle, false);
+    expect(bbsPlusVerifyG1WithHandles(messages, sig, pkHandle, paramsHandle, false).verified).toBe(true);
+    expect(bbsPlusVerifyG1(messages, sig, pk, sigParams, false).verified).toBe(true);
+
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set([0, 2]));
+    const proofSpec = proofSpecG1Handle([], [], [], stringToBytes("context"));
+    const paramsIdx = proofSpec.addSetupParamForBBSPlusSignatureParametersG1(paramsHandle);
+    const pkIdx = proofSpec.addSetupParamForBBSPlusPublicKeyG2(pkHandle);
+    expect(proofSpec.addStatement(generatePoKBBSPlusSignatureStatementFromParamRefs(paramsIdx, pkIdx, revealedMsgs, false))).toEqual(0);
+    expect(proofSpec.isValid()).toBe(true);
+
+    // The same proof spec is used for several proofs without deserializing it again
+    for (let i = 0; i < 2; i++) {
+      const witness = generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false);
+      const proof = generateCompositeProofG1WithHandle(proofSpec, [witness]);
+      expect(verifyCompositeProofG1WithHandle(proof, proofSpec).verified).toBe(true);
+      expect(verifyCompositeProofG1(proof, proofSpec.toBytes()).verified).toBe(true);
+    }
+
+    const fromBytes = proofSpecG1HandleFromBytes(proofSpec.toBytes());
+    const proof = generateCompositeProofG1WithHandle(fromBytes, [generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false)]);
+    expect(verifyCompositeProofG1WithHandle(proof, proofSpec).verified).toBe(true);
+
+    fromBytes.free();
+    proofSpec.free();
+    pkHandle.free();
+    paramsHandle.free();
+  });
+});
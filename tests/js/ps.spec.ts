@@ -18,6 +18,8 @@ import {
   psGenerateSigningKey,
   psGeneratePublicKey,
   psIsPublicKeyValid,
+  psGenerateProofOfPossession,
+  psVerifyProofOfPossession,
   psSign,
   psVerify,
   generateRandomFieldElement,
@@ -127,6 +129,16 @@ describe("For PS signatures", () => {
     expect(psIsPublicKeyValid(pk)).toBe(true);
   });
 
+  it("generate and verify proof of possession of secret key", () => {
+    const ctx = stringToBytes("did:example:signer");
+    const pop = psGenerateProofOfPossession(sk, sigParams, ctx);
+    expect(pop).toBeInstanceOf(Uint8Array);
+    expect(psVerifyProofOfPossession(pop, pk, sigParams, ctx).verified).toBe(true);
+    expect(
+      psVerifyProofOfPossession(pop, pk, sigParams, stringToBytes("did:example:other")).verified
+    ).toBe(false);
+  });
+
   it("generate and verify signature", () => {
     const sig = psSign(messages, sk, sigParams);
     const res = psVerify(messages, sig, pk, sigParams);
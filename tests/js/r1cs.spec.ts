@@ -28,12 +28,14 @@ import {
   legosnarkDecompressVk,
   legosnarkVkFromPk, R1CS,
   r1csCircuitSatisfied,
+  r1csFromBytes,
   r1csGenerateWires,
   r1csSnarkSetup,
   verifyCompositeProofG1WithDeconstructedProofSpec,
+  witnessCalculatorHandle,
 } from "../../lib";
 import {areUint8ArraysEqual, fromLeToBigInt, getRevealedUnrevealed} from "../utilities";
-import {Constraint, LC, LCTerm} from "../../src/js";
+import {Constraint, LC, LCTerm, SignalValue} from "../../src/js";
 
 interface ParsedR1CSFile {
   F: {fromMontgomery: (n: Uint8Array) => Uint8Array},
@@ -432,6 +434,90 @@ describe("Generate proving Key from R1CS", () => {
   });
 });
 
+describe("Parse R1CS file in wasm", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("generates proving key from parsed R1CS", async () => {
+    for (const [name, cm] of [
+      ["multiply2.r1cs", 2],
+      ["less_than_32.r1cs", 2],
+      ["less_than_public_64.r1cs", 1],
+      ["test1.r1cs", 1],
+      ["test2.r1cs", 2],
+      ["test3.r1cs", 6],
+      ["test4.r1cs", 8],
+    ]) {
+      const r1cs = r1csFromBytes(getWasmBytes(name as string));
+      const snarkPk = r1csSnarkSetup(r1cs, cm as number, false);
+      expect(
+        areUint8ArraysEqual(legosnarkDecompressVk(legosnarkVkFromPk(snarkPk, false)), legosnarkVkFromPk(snarkPk, true))
+      ).toEqual(true);
+      expect(generateSetupParamForR1CS(r1cs).length).toBeGreaterThan(0);
+    }
+    expect(() => r1csFromBytes(getWasmBytes("multiply2.wasm"))).toThrow();
+  });
+
+  it("check if circuit satisfied for parsed R1CS", async () => {
+    const inputWires = new Map<string, Uint8Array[]>();
+    inputWires.set("a", [generateFieldElementFromNumber(1010)]);
+    inputWires.set("b", [generateFieldElementFromNumber(1030)]);
+    const r1cs = r1csFromBytes(getWasmBytes("multiply2.r1cs"));
+    expect(r1csCircuitSatisfied(r1cs, getWasmBytes("multiply2.wasm"), inputWires)).toEqual(true);
+  });
+});
+
+describe("Reuse witness calculator", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("generates wires and witnesses with the same calculator", async () => {
+    const calculator = witnessCalculatorHandle(getWasmBytes("multiply2.wasm"));
+    const r1cs = r1csFromBytes(getWasmBytes("multiply2.r1cs"));
+
+    const inputWires = new Map<string, Uint8Array[]>();
+    inputWires.set("a", [generateFieldElementFromNumber(1010)]);
+    inputWires.set("b", [generateFieldElementFromNumber(1030)]);
+    const wires = r1csGenerateWires(calculator, inputWires);
+    const expectedWires = r1csGenerateWires(getWasmBytes("multiply2.wasm"), inputWires);
+    expect(wires.length).toEqual(expectedWires.length);
+    for (let i = 0; i < wires.length; i++) {
+      expect(areUint8ArraysEqual(wires[i], expectedWires[i])).toEqual(true);
+    }
+    expect(r1csCircuitSatisfied(r1cs, calculator, inputWires)).toEqual(true);
+
+    // Same values as bigints and decimal strings
+    const otherInputWires = new Map<string, SignalValue[]>();
+    otherInputWires.set("a", [BigInt(1010)]);
+    otherInputWires.set("b", ["1030"]);
+    const otherWires = r1csGenerateWires(calculator, otherInputWires);
+    for (let i = 0; i < wires.length; i++) {
+      expect(areUint8ArraysEqual(otherWires[i], wires[i])).toEqual(true);
+    }
+    expect(
+      areUint8ArraysEqual(
+        generateR1CSCircomWitness(otherInputWires, ["a", "b"], [], calculator),
+        generateR1CSCircomWitness(inputWires, ["a", "b"])
+      )
+    ).toEqual(true);
+
+    const badInputWires = new Map<string, SignalValue[]>();
+    badInputWires.set("a", ["10x"]);
+    badInputWires.set("b", ["1030"]);
+    expect(() => r1csGenerateWires(calculator, badInputWires)).toThrow();
+    // Missing input
+    const missingInputWires = new Map<string, SignalValue[]>();
+    missingInputWires.set("a", [BigInt(1010)]);
+    expect(() => r1csGenerateWires(calculator, missingInputWires)).toThrow();
+    expect(() => generateR1CSCircomWitness(missingInputWires, ["a"], [], calculator)).toThrow();
+
+    calculator.free();
+    expect(() => witnessCalculatorHandle(getWasmBytes("multiply2.r1cs"))).toThrow();
+  });
+});
+
 describe("Proof generation and verification from R1CS and WASM file", () => {
   beforeAll(async () => {
     await initializeWasm();
@@ -25,17 +25,23 @@ import {
   saverDecompressDecryptionKey,
   saverDecompressEncryptionGenerators,
   saverDecompressEncryptionKey,
+  saverBatchDecryptCiphertexts,
+  saverCreateDecryptionTableUsingSnarkVk,
   saverDecompressSnarkPk,
   saverDecryptCiphertextUsingSnarkPk,
   saverDecryptCiphertextUsingSnarkVk,
+  saverDecryptCiphertextUsingTable,
   saverDecryptorSetup,
   saverGenerateChunkedCommitmentGenerators,
   saverGenerateEncryptionGenerators,
   saverGetCiphertextFromProof,
   saverGetCiphertextsFromProof,
   saverGetSnarkVkFromPk,
+  saverReencryptCiphertext,
+  saverRotateKeys,
   saverVerifyDecryptionUsingSnarkPk,
   saverVerifyDecryptionUsingSnarkVk,
+  saverVerifyReencryption,
   verifyCompositeProofG1WithDeconstructedProofSpec,
 } from "../../lib";
 
@@ -404,4 +410,58 @@ describe("Verifiable encryption of a signed message", () => {
     console.timeEnd("proof ver");
     expect(res.verified).toBe(true);
   }, 90000);
+
+  it("batch decrypt using a decryption table", () => {
+    console.time("decryption table");
+    const table = saverCreateDecryptionTableUsingSnarkVk(
+      dkDecom,
+      snarkVkDecom,
+      chunkBitSize,
+      true
+    );
+    console.timeEnd("decryption table");
+
+    const cts = saverGetCiphertextsFromProof(proof, [1, 2, 3]);
+    console.time("batch decrypt");
+    const decrypted = saverBatchDecryptCiphertexts(cts, sk, dkDecom, table, true);
+    console.timeEnd("batch decrypt");
+    expect(decrypted.length).toEqual(3);
+
+    for (let i = 0; i < 3; i++) {
+      const [decryptedMessage, nu] = decrypted[i];
+      expect(decryptedMessage).toEqual(messages[encMsgIdx + i]);
+      const res = saverVerifyDecryptionUsingSnarkVk(
+        cts[i],
+        decryptedMessage,
+        nu,
+        dkDecom,
+        snarkVkDecom,
+        encGensDecom,
+        chunkBitSize,
+        true
+      );
+      expect(res.verified).toBe(true);
+    }
+
+    const [decryptedMessage] = saverDecryptCiphertextUsingTable(cts[0], sk, dkDecom, table, true);
+    expect(decryptedMessage).toEqual(messages[encMsgIdx]);
+  }, 30000);
+
+  it("rotate keys and re-encrypt a ciphertext", () => {
+    const [newSk, newEk, newDk] = saverRotateKeys(snarkPk, encGens, chunkBitSize, false);
+    expect(areUint8ArraysEqual(newEk, ek)).toEqual(false);
+
+    // Ciphertexts for the old keys are moved to the new keys along with a proof that the message is unchanged
+    const snarkVk = saverGetSnarkVkFromPk(snarkPk, false);
+    const table = saverCreateDecryptionTableUsingSnarkVk(dk, snarkVk, chunkBitSize, false);
+    const ct = saverGetCiphertextFromProof(proof, 1);
+    const [newCt, reencProof] = saverReencryptCiphertext(ct, sk, dk, table, newEk, snarkVk, encGens, false);
+    expect(saverVerifyReencryption(ct, newCt, reencProof, dk, newEk, snarkVk, encGens, false).verified).toBe(true);
+    expect(saverVerifyReencryption(ct, newCt, reencProof, dk, ek, snarkVk, encGens, false).verified).toBe(false);
+
+    const [decryptedMessage, nu] = saverDecryptCiphertextUsingSnarkVk(newCt, newSk, newDk, snarkVk, chunkBitSize, false);
+    expect(decryptedMessage).toEqual(messages[encMsgIdx]);
+    const res = saverVerifyDecryptionUsingSnarkVk(newCt, decryptedMessage, nu, newDk, snarkVk, encGens, chunkBitSize, false);
+    expect(res.verified).toBe(true);
+  }, 60000);
 });
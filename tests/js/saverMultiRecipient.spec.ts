@@ -0,0 +1,211 @@
+import {
+  encodeMessageForSigning,
+  bbsPlusSignG1,
+  BbsPlusSigParams,
+  bbsPlusGeneratePublicKeyG2,
+  bbsPlusGenerateSigningKey,
+  bbsPlusGenerateSignatureParamsG1,
+  generateCompositeProofG1WithDeconstructedProofSpec,
+  generatePoKBBSPlusSignatureStatement,
+  generatePoKBBSPlusSignatureWitness,
+  generateSaverMultiRecipientStatement,
+  generateSaverMultiRecipientStatementFromParamRefs,
+  generateSaverMultiRecipientWitness,
+  generateSetupParamForSaverMultiRecipientCommitmentKey,
+  generateProofSpecG1,
+  generateWitnessEqualityMetaStatement,
+  initializeWasm,
+  saverDecryptCiphertextUsingSnarkVk,
+  saverDecryptorSetup,
+  saverEncrypt,
+  saverEncryptForRecipients,
+  saverGenerateChunkedCommitmentGenerators,
+  saverGenerateEncryptionGenerators,
+  saverGetSnarkVkFromPk,
+  saverProvePlaintextEquality,
+  saverRerandomizeCiphertext,
+  saverVerifyEncryptionForRecipients,
+  saverVerifyPlaintextEquality,
+  verifyCompositeProofG1WithDeconstructedProofSpec,
+  verifyCompositeProofG1WithSaverMultiRecipient,
+} from "../../lib";
+
+import {stringToBytes, getRevealedUnrevealed, areUint8ArraysEqual} from "../utilities";
+
+describe("Encrypting the same message for several decryptors", () => {
+  const messageCount = 3;
+  const chunkBitSize = 8;
+  const encMsgIdx = 1;
+
+  let encGens: Uint8Array, commGens: Uint8Array;
+  const snarkPks = new Array<Uint8Array>(),
+    snarkVks = new Array<Uint8Array>(),
+    sks = new Array<Uint8Array>(),
+    eks = new Array<Uint8Array>(),
+    dks = new Array<Uint8Array>();
+
+  const messages = new Array<Uint8Array>();
+  const nonce = stringToBytes("test-nonce");
+
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("setup 2 decryptors", () => {
+    encGens = saverGenerateEncryptionGenerators();
+    commGens = saverGenerateChunkedCommitmentGenerators();
+    for (let i = 0; i < 2; i++) {
+      const [snarkPk, sk, ek, dk] = saverDecryptorSetup(chunkBitSize, encGens, false);
+      snarkPks.push(snarkPk);
+      snarkVks.push(saverGetSnarkVkFromPk(snarkPk, false));
+      sks.push(sk);
+      eks.push(ek);
+      dks.push(dk);
+    }
+    for (let i = 0; i < messageCount; i++) {
+      messages.push(encodeMessageForSigning(stringToBytes(`${i + 1}`)));
+    }
+  }, 60000);
+
+  it("re-randomize and prove plaintext equality of ciphertexts", () => {
+    const [ct1, r1] = saverEncrypt(messages[0], eks[0], snarkVks[0], chunkBitSize, false);
+    const [rerandomized, r1New] = saverRerandomizeCiphertext(ct1, eks[0], r1, false);
+    expect(areUint8ArraysEqual(rerandomized, ct1)).toEqual(false);
+    const [decrypted] = saverDecryptCiphertextUsingSnarkVk(rerandomized, sks[0], dks[0], snarkVks[0], chunkBitSize, false);
+    expect(decrypted).toEqual(messages[0]);
+
+    const [ct2, r2] = saverEncrypt(messages[0], eks[1], snarkVks[1], chunkBitSize, false);
+    const proof = saverProvePlaintextEquality(messages[0], chunkBitSize, [rerandomized, ct2], [r1New, r2], eks, snarkVks, false);
+    expect(saverVerifyPlaintextEquality([rerandomized, ct2], proof, eks, snarkVks, false).verified).toBe(true);
+    expect(saverVerifyPlaintextEquality([ct1, ct2], proof, eks, snarkVks, false).verified).toBe(false);
+
+    const [otherCt] = saverEncrypt(messages[1], eks[1], snarkVks[1], chunkBitSize, false);
+    expect(saverVerifyPlaintextEquality([rerandomized, otherCt], proof, eks, snarkVks, false).verified).toBe(false);
+  }, 30000);
+
+  it("encrypt a signed message for both decryptors in a composite proof", () => {
+    const sigParams: BbsPlusSigParams = bbsPlusGenerateSignatureParamsG1(messageCount);
+    const sigSk = bbsPlusGenerateSigningKey();
+    const sigPk = bbsPlusGeneratePublicKeyG2(sigSk, sigParams);
+    const sig = bbsPlusSignG1(messages, sigSk, sigParams, false);
+
+    const [cts, commitment, blinding, encProof] = saverEncryptForRecipients(
+      messages[encMsgIdx],
+      chunkBitSize,
+      eks,
+      snarkPks[0],
+      snarkVks.slice(1),
+      commGens,
+      nonce,
+      false
+    );
+    expect(cts.length).toEqual(2);
+
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set<number>());
+    const statement1 = generatePoKBBSPlusSignatureStatement(sigParams, sigPk, revealedMsgs, false);
+    const statement2 = generateSaverMultiRecipientStatement(commGens, commitment, false);
+
+    const set = new Set<[number, number]>();
+    set.add([0, encMsgIdx]);
+    set.add([1, 0]);
+    const metaStatements = [generateWitnessEqualityMetaStatement(set)];
+
+    const witnesses = [
+      generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false),
+      generateSaverMultiRecipientWitness(messages[encMsgIdx], blinding),
+    ];
+    const proof = generateCompositeProofG1WithDeconstructedProofSpec(
+      [statement1, statement2],
+      metaStatements,
+      [],
+      witnesses,
+      undefined,
+      nonce
+    );
+
+    // The verifier creates the statement using setup params
+    const setupParams = [generateSetupParamForSaverMultiRecipientCommitmentKey(commGens, false)];
+    const statement3 = generateSaverMultiRecipientStatementFromParamRefs(0, commitment);
+    const res = verifyCompositeProofG1WithDeconstructedProofSpec(
+      proof,
+      [statement1, statement3],
+      metaStatements,
+      setupParams,
+      undefined,
+      nonce
+    );
+    expect(res.verified).toBe(true);
+
+    const proofSpec = generateProofSpecG1([statement1, statement3], metaStatements, setupParams);
+    const encryptions = new Map([
+      [1, [cts, encProof, eks, snarkVks, chunkBitSize] as [Uint8Array[], Uint8Array, Uint8Array[], Uint8Array[], number]],
+    ]);
+    expect(verifyCompositeProofG1WithSaverMultiRecipient(proof, proofSpec, nonce, encryptions, false).verified).toBe(true);
+    expect(
+      verifyCompositeProofG1WithSaverMultiRecipient(proof, proofSpec, stringToBytes("other-nonce"), encryptions, false)
+        .verified
+    ).toBe(false);
+    const wrongIndex = verifyCompositeProofG1WithSaverMultiRecipient(
+      proof,
+      proofSpec,
+      nonce,
+      new Map([[0, encryptions.get(1)!]]),
+      false
+    );
+    expect(wrongIndex.verified).toBe(false);
+    expect(wrongIndex.code).toEqual("INVALID_ARGUMENT");
+
+    expect(
+      saverVerifyEncryptionForRecipients(cts, commitment, encProof, eks, snarkVks, commGens, chunkBitSize, nonce, false)
+        .verified
+    ).toBe(true);
+    expect(
+      saverVerifyEncryptionForRecipients(
+        cts,
+        commitment,
+        encProof,
+        eks,
+        snarkVks,
+        commGens,
+        chunkBitSize,
+        stringToBytes("other-nonce"),
+        false
+      ).verified
+    ).toBe(false);
+
+    // Each decryptor decrypts its ciphertext
+    for (let i = 0; i < 2; i++) {
+      const [decrypted] = saverDecryptCiphertextUsingSnarkVk(cts[i], sks[i], dks[i], snarkVks[i], chunkBitSize, false);
+      expect(decrypted).toEqual(messages[encMsgIdx]);
+    }
+  }, 60000);
+
+  it("encrypt for a single decryptor", () => {
+    const [cts, commitment, , encProof] = saverEncryptForRecipients(
+      messages[0],
+      chunkBitSize,
+      eks.slice(0, 1),
+      snarkPks[0],
+      [],
+      commGens,
+      nonce,
+      false
+    );
+    expect(cts.length).toEqual(1);
+    expect(
+      saverVerifyEncryptionForRecipients(
+        cts,
+        commitment,
+        encProof,
+        eks.slice(0, 1),
+        snarkVks.slice(0, 1),
+        commGens,
+        chunkBitSize,
+        nonce,
+        false
+      ).verified
+    ).toBe(true);
+    const [decrypted] = saverDecryptCiphertextUsingSnarkVk(cts[0], sks[0], dks[0], snarkVks[0], chunkBitSize, false);
+    expect(decrypted).toEqual(messages[0]);
+  }, 30000);
+});
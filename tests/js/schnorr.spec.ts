@@ -0,0 +1,151 @@
+import {
+  initializeWasm,
+  generateRandomFieldElement,
+  generateRandomG1Element,
+  generateRandomG2Element,
+  generateChallengeFromBytes,
+  pedersenCommitmentG1,
+  pedersenCommitmentG2,
+  pokDiscreteLogG1Initialize,
+  pokDiscreteLogG1ChallengeContributionFromProtocol,
+  pokDiscreteLogG1GenProof,
+  pokDiscreteLogG1ChallengeContributionFromProof,
+  pokDiscreteLogG1VerifyProof,
+  pokDiscreteLogG2Initialize,
+  pokDiscreteLogG2ChallengeContributionFromProtocol,
+  pokDiscreteLogG2GenProof,
+  pokDiscreteLogG2ChallengeContributionFromProof,
+  pokDiscreteLogG2VerifyProof,
+  pokPedersenCommitmentOpeningG1Initialize,
+  pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol,
+  pokPedersenCommitmentOpeningG1GenProof,
+  pokPedersenCommitmentOpeningG1ChallengeContributionFromProof,
+  pokPedersenCommitmentOpeningG1VerifyProof,
+  pokPedersenCommitmentOpeningG2Initialize,
+  pokPedersenCommitmentOpeningG2ChallengeContributionFromProtocol,
+  pokPedersenCommitmentOpeningG2GenProof,
+  pokPedersenCommitmentOpeningG2ChallengeContributionFromProof,
+  pokPedersenCommitmentOpeningG2VerifyProof,
+  pokG1GetResponseForWitness,
+  pokDiscreteLogEqualityG1Initialize,
+  pokDiscreteLogEqualityG1ChallengeContributionFromProtocol,
+  pokDiscreteLogEqualityG1GenProof,
+  pokDiscreteLogEqualityG1ChallengeContributionFromProof,
+  pokDiscreteLogEqualityG1VerifyProof,
+  pokDiscreteLogEqualityG2Initialize,
+  pokDiscreteLogEqualityG2ChallengeContributionFromProtocol,
+  pokDiscreteLogEqualityG2GenProof,
+  pokDiscreteLogEqualityG2ChallengeContributionFromProof,
+  pokDiscreteLogEqualityG2VerifyProof,
+} from "../../lib";
+import { stringToBytes } from "../utilities";
+
+function challengeFor(contribution: Uint8Array, nonce: string): Uint8Array {
+  const bytes = new Uint8Array([...contribution, ...stringToBytes(nonce)]);
+  return generateChallengeFromBytes(bytes);
+}
+
+describe("Standalone Schnorr proofs", () => {
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("prove knowledge of discrete log", () => {
+    for (const [randomElement, commit, init, ccProtocol, genProof, ccProof, verify] of [
+      [generateRandomG1Element, pedersenCommitmentG1, pokDiscreteLogG1Initialize, pokDiscreteLogG1ChallengeContributionFromProtocol, pokDiscreteLogG1GenProof, pokDiscreteLogG1ChallengeContributionFromProof, pokDiscreteLogG1VerifyProof],
+      [generateRandomG2Element, pedersenCommitmentG2, pokDiscreteLogG2Initialize, pokDiscreteLogG2ChallengeContributionFromProtocol, pokDiscreteLogG2GenProof, pokDiscreteLogG2ChallengeContributionFromProof, pokDiscreteLogG2VerifyProof],
+    ] as const) {
+      const base = randomElement();
+      const witness = generateRandomFieldElement();
+      const y = commit([base], [witness]);
+
+      const protocol = init(witness, base);
+      const challengeProver = challengeFor(ccProtocol(protocol, base, y), "nonce");
+      const proof = genProof(protocol, challengeProver);
+
+      const challengeVerifier = challengeFor(ccProof(proof, base, y), "nonce");
+      expect(challengeVerifier).toEqual(challengeProver);
+      expect(verify(proof, base, y, challengeVerifier).verified).toBe(true);
+
+      // Different nonce or public key fails
+      expect(verify(proof, base, y, challengeFor(ccProof(proof, base, y), "other")).verified).toBe(false);
+      const otherY = commit([base], [generateRandomFieldElement()]);
+      expect(verify(proof, base, otherY, challengeVerifier).verified).toBe(false);
+    }
+  });
+
+  it("prove knowledge of opening of Pedersen commitment", () => {
+    for (const [randomElement, commit, init, ccProtocol, genProof, ccProof, verify] of [
+      [generateRandomG1Element, pedersenCommitmentG1, pokPedersenCommitmentOpeningG1Initialize, pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol, pokPedersenCommitmentOpeningG1GenProof, pokPedersenCommitmentOpeningG1ChallengeContributionFromProof, pokPedersenCommitmentOpeningG1VerifyProof],
+      [generateRandomG2Element, pedersenCommitmentG2, pokPedersenCommitmentOpeningG2Initialize, pokPedersenCommitmentOpeningG2ChallengeContributionFromProtocol, pokPedersenCommitmentOpeningG2GenProof, pokPedersenCommitmentOpeningG2ChallengeContributionFromProof, pokPedersenCommitmentOpeningG2VerifyProof],
+    ] as const) {
+      const bases = [randomElement(), randomElement(), randomElement()];
+      const witnesses = [generateRandomFieldElement(), generateRandomFieldElement(), generateRandomFieldElement()];
+      const commitment = commit(bases, witnesses);
+
+      const protocol = init(witnesses, bases, new Map());
+      const challenge = challengeFor(ccProtocol(protocol, bases, commitment), "nonce");
+      const proof = genProof(protocol, challenge);
+      expect(challengeFor(ccProof(proof, bases, commitment), "nonce")).toEqual(challenge);
+      expect(verify(proof, bases, commitment, challenge).verified).toBe(true);
+
+      const wrongCommitment = commit(bases, [witnesses[0], witnesses[1], generateRandomFieldElement()]);
+      expect(verify(proof, bases, wrongCommitment, challenge).verified).toBe(false);
+
+      // Number of witnesses and bases must match
+      expect(() => init(witnesses.slice(1), bases, new Map())).toThrow();
+    }
+  });
+
+  it("prove equality of witnesses across proofs with same blinding", () => {
+    const sharedWitness = generateRandomFieldElement();
+    const blinding = generateRandomFieldElement();
+    const base = generateRandomG1Element();
+    const y = pedersenCommitmentG1([base], [sharedWitness]);
+    const bases = [generateRandomG1Element(), generateRandomG1Element()];
+    const witnesses = [generateRandomFieldElement(), sharedWitness];
+    const commitment = pedersenCommitmentG1(bases, witnesses);
+
+    const protocol1 = pokDiscreteLogG1Initialize(sharedWitness, base, blinding);
+    const protocol2 = pokPedersenCommitmentOpeningG1Initialize(witnesses, bases, new Map([[1, blinding]]));
+    const challenge = challengeFor(
+      new Uint8Array([
+        ...pokDiscreteLogG1ChallengeContributionFromProtocol(protocol1, base, y),
+        ...pokPedersenCommitmentOpeningG1ChallengeContributionFromProtocol(protocol2, bases, commitment),
+      ]),
+      "nonce"
+    );
+    const proof1 = pokDiscreteLogG1GenProof(protocol1, challenge);
+    const proof2 = pokPedersenCommitmentOpeningG1GenProof(protocol2, challenge);
+    expect(pokDiscreteLogG1VerifyProof(proof1, base, y, challenge).verified).toBe(true);
+    expect(pokPedersenCommitmentOpeningG1VerifyProof(proof2, bases, commitment, challenge).verified).toBe(true);
+    expect(pokG1GetResponseForWitness(proof1, 0)).toEqual(pokG1GetResponseForWitness(proof2, 1));
+    expect(pokG1GetResponseForWitness(proof1, 0)).not.toEqual(pokG1GetResponseForWitness(proof2, 0));
+  });
+
+  it("prove equality of discrete logs", () => {
+    for (const [randomElement, commit, init, ccProtocol, genProof, ccProof, verify] of [
+      [generateRandomG1Element, pedersenCommitmentG1, pokDiscreteLogEqualityG1Initialize, pokDiscreteLogEqualityG1ChallengeContributionFromProtocol, pokDiscreteLogEqualityG1GenProof, pokDiscreteLogEqualityG1ChallengeContributionFromProof, pokDiscreteLogEqualityG1VerifyProof],
+      [generateRandomG2Element, pedersenCommitmentG2, pokDiscreteLogEqualityG2Initialize, pokDiscreteLogEqualityG2ChallengeContributionFromProtocol, pokDiscreteLogEqualityG2GenProof, pokDiscreteLogEqualityG2ChallengeContributionFromProof, pokDiscreteLogEqualityG2VerifyProof],
+    ] as const) {
+      const base1 = randomElement();
+      const base2 = randomElement();
+      const witness = generateRandomFieldElement();
+      const y1 = commit([base1], [witness]);
+      const y2 = commit([base2], [witness]);
+
+      const protocol = init(witness, base1, base2);
+      const challenge = challengeFor(ccProtocol(protocol, base1, y1, base2, y2), "nonce");
+      const proof = genProof(protocol, challenge);
+      expect(challengeFor(ccProof(proof, base1, y1, base2, y2), "nonce")).toEqual(challenge);
+      expect(verify(proof, base1, y1, base2, y2, challenge).verified).toBe(true);
+
+      // Fails when the discrete logs differ
+      const y3 = commit([base2], [generateRandomFieldElement()]);
+      const protocol2 = init(witness, base1, base2);
+      const challenge2 = challengeFor(ccProtocol(protocol2, base1, y1, base2, y3), "nonce");
+      const proof2 = genProof(protocol2, challenge2);
+      expect(verify(proof2, base1, y1, base2, y3, challenge2).verified).toBe(false);
+    }
+  });
+});
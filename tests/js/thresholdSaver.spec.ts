@@ -0,0 +1,190 @@
+import {
+  encodeMessageForSigning,
+  bbsPlusSignG1,
+  BbsPlusSigParams,
+  bbsPlusGeneratePublicKeyG2,
+  bbsPlusGenerateSigningKey,
+  bbsPlusGenerateSignatureParamsG1,
+  generateCompositeProofG1WithDeconstructedProofSpec,
+  generatePoKBBSPlusSignatureStatement,
+  generatePoKBBSPlusSignatureWitness,
+  generateSaverProverStatement,
+  generateSaverVerifierStatement,
+  generateSaverWitness,
+  generateWitnessEqualityMetaStatement,
+  initializeWasm,
+  saverCombinePartialDecryptionsUsingSnarkVk,
+  saverDecryptorSetupGivenPublicKey,
+  saverDecryptorSnarkSetup,
+  saverEncryptionKeyContribution,
+  saverGenerateChunkedCommitmentGenerators,
+  saverGenerateEncryptionGenerators,
+  saverGetCiphertextFromProof,
+  saverGetSharePublicKey,
+  saverGetSnarkVkFromPk,
+  saverPartialDecrypt,
+  saverThresholdDecryptorSetup,
+  saverVerifyDecryptionUsingSnarkVk,
+  saverVerifyEncryptionKeyContribution,
+  saverVerifyPartialDecryption,
+  verifyCompositeProofG1WithDeconstructedProofSpec,
+} from "../../lib";
+
+import {stringToBytes, getRevealedUnrevealed, areUint8ArraysEqual} from "../utilities";
+
+describe("Threshold decryption of a verifiably encrypted message", () => {
+  const messageCount = 3;
+  const chunkBitSize = 8;
+  const encMsgIdx = 0;
+  const threshold = 2;
+  const total = 3;
+
+  let sigParams: BbsPlusSigParams, sigSk: Uint8Array, sigPk: Uint8Array, sig: Uint8Array;
+  let snarkPk: Uint8Array,
+    snarkVk: Uint8Array,
+    shares: Uint8Array[],
+    sharePks: Uint8Array[],
+    ek: Uint8Array,
+    dk: Uint8Array,
+    encGens: Uint8Array,
+    commGens: Uint8Array,
+    ct: Uint8Array;
+
+  const messages = new Array<Uint8Array>();
+
+  beforeAll(async () => {
+    await initializeWasm();
+  });
+
+  it("threshold decryptor setup", () => {
+    encGens = saverGenerateEncryptionGenerators();
+    commGens = saverGenerateChunkedCommitmentGenerators();
+    [snarkPk, shares, sharePks, ek, dk] = saverThresholdDecryptorSetup(
+      chunkBitSize,
+      encGens,
+      threshold,
+      total,
+      false
+    );
+    snarkVk = saverGetSnarkVkFromPk(snarkPk, false);
+    expect(shares.length).toEqual(total);
+    expect(sharePks.length).toEqual(total);
+    for (let i = 0; i < total; i++) {
+      expect(areUint8ArraysEqual(saverGetSharePublicKey(shares[i], encGens), sharePks[i])).toEqual(true);
+    }
+  }, 30000);
+
+  it("encrypt a signed message", () => {
+    sigParams = bbsPlusGenerateSignatureParamsG1(messageCount);
+    sigSk = bbsPlusGenerateSigningKey();
+    sigPk = bbsPlusGeneratePublicKeyG2(sigSk, sigParams);
+    for (let i = 0; i < messageCount; i++) {
+      messages.push(encodeMessageForSigning(stringToBytes(`${i + 1}`)));
+    }
+    sig = bbsPlusSignG1(messages, sigSk, sigParams, false);
+
+    const [revealedMsgs, unrevealedMsgs] = getRevealedUnrevealed(messages, new Set<number>());
+    const statement1 = generatePoKBBSPlusSignatureStatement(sigParams, sigPk, revealedMsgs, false);
+    const statement2 = generateSaverProverStatement(chunkBitSize, encGens, commGens, ek, snarkPk, false);
+    const statement3 = generateSaverVerifierStatement(chunkBitSize, encGens, commGens, ek, snarkVk, false);
+
+    const set = new Set<[number, number]>();
+    set.add([0, encMsgIdx]);
+    set.add([1, 0]);
+    const metaStatements = [generateWitnessEqualityMetaStatement(set)];
+
+    const witnesses = [
+      generatePoKBBSPlusSignatureWitness(sig, unrevealedMsgs, false),
+      generateSaverWitness(messages[encMsgIdx]),
+    ];
+    const nonce = stringToBytes("test-nonce");
+
+    const proof = generateCompositeProofG1WithDeconstructedProofSpec(
+      [statement1, statement2],
+      metaStatements,
+      [],
+      witnesses,
+      undefined,
+      nonce
+    );
+    const res = verifyCompositeProofG1WithDeconstructedProofSpec(
+      proof,
+      [statement1, statement3],
+      metaStatements,
+      [],
+      undefined,
+      nonce
+    );
+    expect(res.verified).toBe(true);
+    ct = saverGetCiphertextFromProof(proof, 1);
+  }, 30000);
+
+  it("decrypt with a threshold of decryptors", () => {
+    const partials = new Array<Uint8Array>();
+    for (let i = 0; i < total; i++) {
+      const partial = saverPartialDecrypt(ct, shares[i], encGens);
+      expect(saverVerifyPartialDecryption(partial, ct, sharePks[i], encGens).verified).toBe(true);
+      partials.push(partial);
+    }
+
+    // A partial decryption does not verify with another decryptor's public key
+    expect(saverVerifyPartialDecryption(partials[0], ct, sharePks[1], encGens).verified).toBe(false);
+
+    // Fewer than threshold partial decryptions cannot be combined
+    expect(() =>
+      saverCombinePartialDecryptionsUsingSnarkVk(ct, partials.slice(0, 1), threshold, sharePks, encGens, dk, snarkVk, chunkBitSize, false)
+    ).toThrow();
+
+    // Partial decryptions are checked against the public keys of their decryptors
+    expect(() =>
+      saverCombinePartialDecryptionsUsingSnarkVk(ct, partials.slice(0, 2), threshold, [sharePks[1], sharePks[0], sharePks[2]], encGens, dk, snarkVk, chunkBitSize, false)
+    ).toThrow();
+
+    for (const subset of [[0, 1], [1, 2], [0, 2]]) {
+      const [decryptedMessage, nu] = saverCombinePartialDecryptionsUsingSnarkVk(
+        ct,
+        subset.map((i) => partials[i]),
+        threshold,
+        sharePks,
+        encGens,
+        dk,
+        snarkVk,
+        chunkBitSize,
+        false
+      );
+      expect(decryptedMessage).toEqual(messages[encMsgIdx]);
+      const res = saverVerifyDecryptionUsingSnarkVk(
+        ct,
+        decryptedMessage,
+        nu,
+        dk,
+        snarkVk,
+        encGens,
+        chunkBitSize,
+        false
+      );
+      expect(res.verified).toBe(true);
+    }
+  }, 30000);
+
+  it("setup for a threshold public key with contributed encryption key secrets", () => {
+    const pk = saverDecryptorSnarkSetup(chunkBitSize, encGens, false);
+    const contributions = new Array<Uint8Array>();
+    for (let i = 0; i < total; i++) {
+      const contribution = saverEncryptionKeyContribution(chunkBitSize, encGens, pk, false);
+      expect(saverVerifyEncryptionKeyContribution(contribution, chunkBitSize, encGens, pk, false).verified).toBe(true);
+      contributions.push(contribution);
+    }
+
+    // A contribution for another snark proving key does not verify
+    expect(saverVerifyEncryptionKeyContribution(contributions[0], chunkBitSize, encGens, snarkPk, false).verified).toBe(false);
+
+    const [ek1, dk1] = saverDecryptorSetupGivenPublicKey(chunkBitSize, encGens, pk, sharePks[0], contributions, false);
+    expect(ek1.length).toBeGreaterThan(0);
+    expect(dk1.length).toBeGreaterThan(0);
+
+    expect(() =>
+      saverDecryptorSetupGivenPublicKey(chunkBitSize, encGens, pk, sharePks[0], [], false)
+    ).toThrow();
+  }, 30000);
+});
@@ -0,0 +1,76 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use dock_crypto_wasm::native::{
+    self,
+    key_encoding::{self, KeyGroup},
+    to_bytes, ErrorCode,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn key_encoding_round_trip() {
+    let params = native::accumulator::generate_params(None).unwrap();
+    let sk = native::accumulator::generate_secret_key(None).unwrap();
+    let pk = to_bytes(&native::accumulator::generate_public_key(&sk, &params)).unwrap();
+
+    let multibase = key_encoding::to_multibase(&pk, KeyGroup::G2).unwrap();
+    let decoded = bs58::decode(&multibase[1..]).into_vec().unwrap();
+    assert!(multibase.starts_with('z'));
+    assert_eq!(decoded[..2], [0xeb, 0x01]);
+    assert_eq!(
+        key_encoding::from_multibase(&multibase, KeyGroup::G2).unwrap(),
+        pk
+    );
+    let err = key_encoding::from_multibase(&multibase, KeyGroup::G1).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::CurveMismatch);
+
+    let jwk = key_encoding::to_jwk(&pk, KeyGroup::G2).unwrap();
+    assert_eq!(jwk.crv, "BLS12381G2");
+    assert_eq!(key_encoding::from_jwk(&jwk, KeyGroup::G2).unwrap(), pk);
+    let err = key_encoding::from_jwk(&jwk, KeyGroup::G1).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::CurveMismatch);
+}
+
+#[wasm_bindgen_test]
+fn ps_public_key_jwk_set() {
+    let params = native::ps::generate_params(3, None).unwrap();
+    let sk = native::ps::generate_secret_key(3, None).unwrap();
+    let pk = native::ps::generate_public_key(&sk, &params);
+
+    let jwk_set = key_encoding::ps_public_key_to_jwk_set(&pk).unwrap();
+    assert_eq!(jwk_set.keys.len(), 7);
+    assert_eq!(jwk_set.keys[0].kid.as_deref(), Some("alpha_tilde"));
+    assert_eq!(jwk_set.keys[0].crv, "BLS12381G2");
+    assert_eq!(jwk_set.keys[6].kid.as_deref(), Some("beta.2"));
+    assert_eq!(jwk_set.keys[6].crv, "BLS12381G1");
+    assert_eq!(
+        key_encoding::ps_public_key_from_jwk_set(&jwk_set).unwrap(),
+        pk
+    );
+
+    // Order of the keys doesn't matter
+    let mut reversed = jwk_set.clone();
+    reversed.keys.reverse();
+    assert_eq!(
+        key_encoding::ps_public_key_from_jwk_set(&reversed).unwrap(),
+        pk
+    );
+
+    let mut mismatched = jwk_set.clone();
+    mismatched.keys[6].crv = "BLS12381G2".to_string();
+    let err = key_encoding::ps_public_key_from_jwk_set(&mismatched).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::CurveMismatch);
+
+    let mut missing = jwk_set.clone();
+    missing.keys.truncate(5);
+    let err = key_encoding::ps_public_key_from_jwk_set(&missing).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidKeyEncoding);
+
+    let mut duplicated = jwk_set;
+    duplicated.keys[2] = duplicated.keys[1].clone();
+    let err = key_encoding::ps_public_key_from_jwk_set(&duplicated).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidKeyEncoding);
+}
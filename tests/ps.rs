@@ -14,10 +14,14 @@ use dock_crypto_wasm::{
         field_element_from_number, generate_challenge_from_bytes, generate_random_field_element,
         VerifyResponse,
     },
+    native::{self, from_bytes, to_bytes},
     ps::*,
     utils::js_array_of_bytearrays_from_vector_of_bytevectors,
 };
 
+mod common;
+use common::gen_encoded_msgs;
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 fn ps_setup(message_count: u32) -> (JsValue, Uint8Array, Uint8Array) {
@@ -424,3 +428,25 @@ pub fn ps_extend_params() {
 
     check_sig_ver!(ps_sign, ps_verify, messages_as_array, sk, pk, params_1);
 }
+
+#[wasm_bindgen_test]
+fn native_ps_sign_verify() {
+    let msgs = gen_encoded_msgs(3);
+    let params = native::ps::generate_params(3, None).unwrap();
+    let sk = native::ps::generate_secret_key(3, None).unwrap();
+    let pk = native::ps::generate_public_key(&sk, &params);
+    let sig = native::ps::sign(&msgs, &sk, &params).unwrap();
+    let sig: PSSignature = from_bytes(&to_bytes(&sig).unwrap()).unwrap();
+    native::ps::verify(&msgs, &sig, &pk, &params).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn native_ps_proof_of_possession() {
+    let ctx = b"did:example:issuer";
+    let params = native::ps::generate_params(3, None).unwrap();
+    let sk = native::ps::generate_secret_key(3, None).unwrap();
+    let pk = native::ps::generate_public_key(&sk, &params);
+    let pop = native::ps::generate_proof_of_possession(&sk, &params, ctx).unwrap();
+    native::ps::verify_proof_of_possession(&pop, &pk, &params, ctx).unwrap();
+    assert!(native::ps::verify_proof_of_possession(&pop, &pk, &params, b"").is_err());
+}
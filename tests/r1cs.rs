@@ -0,0 +1,57 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use dock_crypto_wasm::{native::ErrorCode, Fr};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn r1cs_file_parsing() {
+    use dock_crypto_wasm::native::{groth16, r1cs};
+
+    let bytes = include_bytes!("circom/multiply2.r1cs").to_vec();
+    let parsed = r1cs::read_r1cs(&bytes).unwrap();
+    // Wires are 1, the output c and the private inputs a and b with a * b = c
+    assert_eq!(parsed.num_public, 2);
+    assert_eq!(parsed.num_private, 2);
+    assert_eq!(parsed.constraints.len(), 1);
+    assert_eq!(parsed.constraints[0].a.terms(), &[(2, -Fr::from(1u64))]);
+    assert_eq!(parsed.wire_to_label_mapping.len(), 4);
+
+    let pk = groth16::setup(parsed).unwrap();
+    let (a, b) = (Fr::from(1010u64), Fr::from(1030u64));
+    let (proof, public_signals) = groth16::prove(&pk, &[Fr::from(1u64), a * b, a, b]).unwrap();
+    groth16::verify(&pk.pk.vk, &proof, &public_signals).unwrap();
+
+    let err = r1cs::read_r1cs(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::DeserializationFailed);
+    // The last section has the number of applications of custom gates
+    let mut with_custom_gates = bytes.clone();
+    let len = bytes.len();
+    with_custom_gates[len - 4..].copy_from_slice(&1u32.to_le_bytes());
+    let err = r1cs::read_r1cs(&with_custom_gates).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+}
+
+#[wasm_bindgen_test]
+fn circom_signal_values() {
+    use dock_crypto_wasm::native::r1cs;
+
+    assert_eq!(
+        r1cs::signal_value_from_str("1030").unwrap(),
+        Fr::from(1030u64)
+    );
+    assert_eq!(r1cs::signal_value_from_str("-1").unwrap(), -Fr::from(1u64));
+    assert_eq!(r1cs::signal_value_from_str("0").unwrap(), Fr::from(0u64));
+    for invalid in ["", "01", "1.5", "0x10", "abc"] {
+        let err = r1cs::signal_value_from_str(invalid).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidArgument);
+    }
+    // The modulus isn't a valid value
+    let err = r1cs::signal_value_from_str(
+        "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+}
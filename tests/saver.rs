@@ -1,6 +1,7 @@
 #![cfg(target_arch = "wasm32")]
 extern crate wasm_bindgen_test;
 
+use ark_bls12_381::Bls12_381;
 use ark_std::{collections::BTreeSet, vec};
 use wasm_bindgen_test::*;
 use web_sys::console;
@@ -13,12 +14,15 @@ use dock_crypto_wasm::{
         generate_pok_bbs_plus_sig_witness, generate_saver_witness, saver_get_ciphertext_from_proof,
         verify_composite_proof_g1_with_deconstructed_proof_spec,
     },
+    native::{self, from_bytes, to_bytes, ErrorCode},
     saver::*,
     utils::{js_array_of_bytearrays_from_vector_of_bytevectors, random_bytes},
 };
 
 mod common;
-use common::{bbs_params_and_keys, get_revealed_unrevealed, get_witness_equality_statement};
+use common::{
+    bbs_params_and_keys, gen_encoded_msgs, get_revealed_unrevealed, get_witness_equality_statement,
+};
 use dock_crypto_wasm::composite_proof_system::statement::{
     generate_pok_bbs_plus_sig_statement, generate_saver_prover_statement,
     generate_saver_verifier_statement,
@@ -33,7 +37,7 @@ pub fn bbs_sig_and_verifiable_encryption() {
     let mut encoded_msgs = vec![];
 
     for _ in 0..msg_count {
-        let m = random_bytes();
+        let m = random_bytes().unwrap();
         let bytes = encode_message_for_signing(m).unwrap();
         encoded_msgs.push(bytes.to_vec());
     }
@@ -216,3 +220,361 @@ pub fn bbs_sig_and_verifiable_encryption() {
     r.validate();
     assert_eq!(decrypted_message_1.to_vec(), encoded_msgs[enc_msg_idx]);
 }
+
+#[wasm_bindgen_test]
+fn saver_batch_decryption_with_table() {
+    use dock_crypto_wasm::native::saver::DecryptionTable;
+    use saver::encryption::Encryption;
+
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let (snark_pk, sk, ek, dk) = native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let snark_vk = snark_pk.pk.vk.clone();
+
+    let table = native::saver::create_decryption_table(&dk, &snark_vk, chunk_bit_size).unwrap();
+    let table: DecryptionTable = from_bytes(&to_bytes(&table).unwrap()).unwrap();
+
+    let msgs = gen_encoded_msgs(3);
+    let mut rng = ark_std::test_rng();
+    let cts = msgs
+        .iter()
+        .map(|m| {
+            Encryption::encrypt_given_snark_vk(&mut rng, m, &ek, &snark_vk, chunk_bit_size)
+                .unwrap()
+                .0
+        })
+        .collect::<Vec<_>>();
+
+    let decrypted = native::saver::batch_decrypt(&cts, &sk, dk.clone(), &table).unwrap();
+    assert_eq!(decrypted.len(), msgs.len());
+    for ((ct, msg), (m, nu)) in cts.iter().zip(&msgs).zip(&decrypted) {
+        assert_eq!(m, msg);
+        assert_eq!(
+            native::saver::decrypt(ct, &sk, dk.clone(), &snark_vk, chunk_bit_size).unwrap(),
+            (*m, *nu)
+        );
+        native::saver::verify_decryption(
+            ct,
+            m,
+            nu,
+            dk.clone(),
+            &snark_vk,
+            enc_gens.clone(),
+            chunk_bit_size,
+        )
+        .unwrap();
+    }
+
+    // The table is for the chunk size of the decryption key
+    let err = native::saver::create_decryption_table(&dk, &snark_vk, 4).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IncompatibleParams);
+
+    // A table for another chunk size or with missing steps is rejected before it is used
+    let mut bad = table.clone();
+    bad.chunk_bit_size = 4;
+    let err = native::saver::batch_decrypt(&cts, &sk, dk.clone(), &bad).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IncompatibleParams);
+    let mut bad = table.clone();
+    bad.baby_steps.pop();
+    let err = native::saver::batch_decrypt(&cts, &sk, dk.clone(), &bad).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::IncompatibleParams);
+    assert!(from_bytes::<DecryptionTable>(&to_bytes(&bad).unwrap()).is_err());
+    let mut bad = table.clone();
+    bad.baby_step_count = 1;
+    assert!(from_bytes::<DecryptionTable>(&to_bytes(&bad).unwrap()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn saver_key_rotation_and_reencryption() {
+    use dock_crypto_wasm::native::saver::ReencryptionProof;
+    use saver::encryption::Encryption;
+
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let (snark_pk, sk, ek, dk) = native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let snark_vk = snark_pk.pk.vk.clone();
+    let table = native::saver::create_decryption_table(&dk, &snark_vk, chunk_bit_size).unwrap();
+
+    let msgs = gen_encoded_msgs(2);
+    let mut rng = ark_std::test_rng();
+    let ct = Encryption::encrypt_given_snark_vk(&mut rng, &msgs[0], &ek, &snark_vk, chunk_bit_size)
+        .unwrap()
+        .0;
+
+    let (new_sk, new_ek, new_dk) =
+        native::saver::rotate_keys(&snark_pk, &enc_gens, chunk_bit_size).unwrap();
+    assert_ne!(new_ek, ek);
+    let (new_ct, proof) =
+        native::saver::reencrypt(&ct, &sk, &dk, &table, &new_ek, &snark_vk, &enc_gens).unwrap();
+    let proof: ReencryptionProof = from_bytes(&to_bytes(&proof).unwrap()).unwrap();
+    native::saver::verify_reencryption(&ct, &new_ct, &proof, &dk, &new_ek, &snark_vk, &enc_gens)
+        .unwrap();
+
+    // Only the new keys decrypt the new ciphertext
+    let (m, _) =
+        native::saver::decrypt(&new_ct, &new_sk, new_dk.clone(), &snark_vk, chunk_bit_size)
+            .unwrap();
+    assert_eq!(m, msgs[0]);
+    let new_table =
+        native::saver::create_decryption_table(&new_dk, &snark_vk, chunk_bit_size).unwrap();
+    assert!(native::saver::decrypt_using_table(&new_ct, &sk, new_dk, &new_table).is_err());
+
+    // The proof does not verify for a ciphertext of another message
+    let other_ct =
+        Encryption::encrypt_given_snark_vk(&mut rng, &msgs[1], &new_ek, &snark_vk, chunk_bit_size)
+            .unwrap()
+            .0;
+    let err = native::saver::verify_reencryption(
+        &ct, &other_ct, &proof, &dk, &new_ek, &snark_vk, &enc_gens,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let err =
+        native::saver::verify_reencryption(&ct, &new_ct, &proof, &dk, &ek, &snark_vk, &enc_gens)
+            .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+}
+
+#[wasm_bindgen_test]
+fn saver_rerandomization_and_plaintext_equality() {
+    use ark_ec::CurveGroup;
+    use dock_crypto_wasm::native::saver::{MultiRecipientProof, PlaintextEqualityProof};
+
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let comm_gens = native::saver::generate_chunked_commitment_generators(None).unwrap();
+    let (snark_pk_1, sk_1, ek_1, dk_1) =
+        native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let (snark_pk_2, sk_2, ek_2, dk_2) =
+        native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let (vk_1, vk_2) = (snark_pk_1.pk.vk.clone(), snark_pk_2.pk.vk.clone());
+    let msgs = gen_encoded_msgs(2);
+
+    let (ct_1, r_1) = native::saver::encrypt(&msgs[0], &ek_1, &vk_1, chunk_bit_size).unwrap();
+    let (rerandomized, r_added) = native::saver::rerandomize(&ct_1, &ek_1).unwrap();
+    assert_ne!(rerandomized, ct_1);
+    let (m, _) =
+        native::saver::decrypt(&rerandomized, &sk_1, dk_1.clone(), &vk_1, chunk_bit_size).unwrap();
+    assert_eq!(m, msgs[0]);
+
+    // Equality of the re-randomized ciphertext and a ciphertext for another decryptor
+    let (ct_2, r_2) = native::saver::encrypt(&msgs[0], &ek_2, &vk_2, chunk_bit_size).unwrap();
+    let cts = [rerandomized.clone(), ct_2.clone()];
+    let (eks, vks) = ([ek_1.clone(), ek_2.clone()], [vk_1.clone(), vk_2.clone()]);
+    let proof = native::saver::prove_plaintext_equality(
+        &msgs[0],
+        chunk_bit_size,
+        &cts,
+        &[r_1 + r_added, r_2],
+        &eks,
+        &vks,
+    )
+    .unwrap();
+    let proof: PlaintextEqualityProof = from_bytes(&to_bytes(&proof).unwrap()).unwrap();
+    native::saver::verify_plaintext_equality(&cts, &proof, &eks, &vks).unwrap();
+
+    let (other_ct, _) = native::saver::encrypt(&msgs[1], &ek_2, &vk_2, chunk_bit_size).unwrap();
+    let err = native::saver::verify_plaintext_equality(
+        &[rerandomized.clone(), other_ct],
+        &proof,
+        &eks,
+        &vks,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let err = native::saver::verify_plaintext_equality(&cts[..1], &proof, &eks[..1], &vks[..1])
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+
+    // Multi-recipient encryption with a commitment to the message
+    let (cts, commitment, blinding, proof) = native::saver::encrypt_for_recipients(
+        &msgs[1],
+        chunk_bit_size,
+        &eks,
+        &snark_pk_1,
+        &vks[1..],
+        &comm_gens,
+        b"nonce",
+    )
+    .unwrap();
+    let proof: MultiRecipientProof = from_bytes(&to_bytes(&proof).unwrap()).unwrap();
+    assert_eq!(
+        commitment,
+        (comm_gens.G * msgs[1] + comm_gens.H * blinding).into_affine()
+    );
+    native::saver::verify_encryption_for_recipients(
+        &cts,
+        &commitment,
+        &proof,
+        &eks,
+        &vks,
+        &comm_gens,
+        chunk_bit_size,
+        b"nonce",
+    )
+    .unwrap();
+    for (ct, (sk, dk, vk)) in cts
+        .iter()
+        .zip([(&sk_1, &dk_1, &vk_1), (&sk_2, &dk_2, &vk_2)])
+    {
+        let (m, _) = native::saver::decrypt(ct, sk, dk.clone(), vk, chunk_bit_size).unwrap();
+        assert_eq!(m, msgs[1]);
+    }
+
+    let err = native::saver::verify_encryption_for_recipients(
+        &cts,
+        &commitment,
+        &proof,
+        &eks,
+        &vks,
+        &comm_gens,
+        chunk_bit_size,
+        b"other",
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+    let other_commitment = (comm_gens.G * msgs[0] + comm_gens.H * blinding).into_affine();
+    let err = native::saver::verify_encryption_for_recipients(
+        &cts,
+        &other_commitment,
+        &proof,
+        &eks,
+        &vks,
+        &comm_gens,
+        chunk_bit_size,
+        b"nonce",
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    // A single recipient
+    let (cts, commitment, _, proof) = native::saver::encrypt_for_recipients(
+        &msgs[0],
+        chunk_bit_size,
+        &eks[..1],
+        &snark_pk_1,
+        &[],
+        &comm_gens,
+        b"nonce",
+    )
+    .unwrap();
+    assert_eq!(cts.len(), 1);
+    native::saver::verify_encryption_for_recipients(
+        &cts,
+        &commitment,
+        &proof,
+        &eks[..1],
+        &vks[..1],
+        &comm_gens,
+        chunk_bit_size,
+        b"nonce",
+    )
+    .unwrap();
+    let (m, _) = native::saver::decrypt(&cts[0], &sk_1, dk_1, &vk_1, chunk_bit_size).unwrap();
+    assert_eq!(m, msgs[0]);
+}
+
+#[wasm_bindgen_test]
+fn saver_multi_recipient_with_composite_proof() {
+    use dock_crypto_wasm::{
+        composite_proof_system::{ProofSpec, Witness, Witnesses},
+        native::composite_proof_system::SaverMultiRecipientEncryption,
+    };
+    use proof_system::{
+        prelude::{MetaStatements, SetupParams, Statements},
+        statement::ped_comm::PedersenCommitment,
+    };
+
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let comm_gens = native::saver::generate_chunked_commitment_generators(None).unwrap();
+    let (snark_pk_1, _, ek_1, _) =
+        native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let (snark_pk_2, _, ek_2, _) =
+        native::saver::decryptor_setup(chunk_bit_size, &enc_gens).unwrap();
+    let eks = vec![ek_1, ek_2];
+    let vks = vec![snark_pk_1.pk.vk.clone(), snark_pk_2.pk.vk.clone()];
+    let msg = gen_encoded_msgs(1)[0];
+    let nonce = b"nonce".to_vec();
+
+    let (cts, commitment, blinding, proof) = native::saver::encrypt_for_recipients(
+        &msg,
+        chunk_bit_size,
+        &eks,
+        &snark_pk_1,
+        &vks[1..],
+        &comm_gens,
+        &nonce,
+    )
+    .unwrap();
+    let mut statements = Statements::new();
+    statements.add(PedersenCommitment::new_statement_from_params_refs::<
+        Bls12_381,
+    >(0, commitment));
+    let proof_spec = ProofSpec::new(
+        statements,
+        MetaStatements::new(),
+        vec![SetupParams::PedersenCommitmentKey(vec![
+            comm_gens.G,
+            comm_gens.H,
+        ])],
+        None,
+    );
+    let mut witnesses = Witnesses::new();
+    witnesses.add(Witness::PedersenCommitment(vec![msg, blinding]));
+    let (composite_proof, _) = native::composite_proof_system::generate_proof(
+        proof_spec.clone(),
+        witnesses,
+        Some(nonce.clone()),
+    )
+    .unwrap();
+
+    let verify = |statement_index, nonce: &[u8]| {
+        native::composite_proof_system::verify_proof_with_saver_multi_recipient(
+            composite_proof.clone(),
+            proof_spec.clone(),
+            Some(nonce.to_vec()),
+            &[SaverMultiRecipientEncryption {
+                statement_index,
+                ciphertexts: cts.clone(),
+                proof: proof.clone(),
+                encryption_keys: eks.clone(),
+                snark_vks: vks.clone(),
+                chunk_bit_size,
+            }],
+        )
+    };
+    verify(0, &nonce).unwrap();
+    assert_eq!(
+        verify(1, &nonce).unwrap_err().code(),
+        ErrorCode::IndexOutOfBounds
+    );
+    assert!(verify(0, b"other").is_err());
+
+    // A proof made with another nonce and commitment doesn't verify with this composite proof
+    let (cts, _, _, other_nonce_proof) = native::saver::encrypt_for_recipients(
+        &msg,
+        chunk_bit_size,
+        &eks,
+        &snark_pk_1,
+        &vks[1..],
+        &comm_gens,
+        b"other",
+    )
+    .unwrap();
+    let err = native::composite_proof_system::verify_proof_with_saver_multi_recipient(
+        composite_proof,
+        proof_spec,
+        Some(nonce),
+        &[SaverMultiRecipientEncryption {
+            statement_index: 0,
+            ciphertexts: cts,
+            proof: other_nonce_proof,
+            encryption_keys: eks,
+            snark_vks: vks,
+            chunk_bit_size,
+        }],
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+}
@@ -0,0 +1,50 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use ark_std::collections::BTreeMap;
+use dock_crypto_wasm::{
+    native::{
+        from_bytes,
+        schnorr::{DLEqualityProof, DLEqualityProtocol, PoKProtocol},
+        to_bytes, ErrorCode,
+    },
+    Fr, G1Affine,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn schnorr_dl_equality() {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_std::UniformRand;
+
+    let mut rng = ark_std::test_rng();
+    let base1 = (G1Affine::generator() * Fr::rand(&mut rng)).into_affine();
+    let base2 = (G1Affine::generator() * Fr::rand(&mut rng)).into_affine();
+    let x = Fr::rand(&mut rng);
+    let y1 = (base1 * x).into_affine();
+    let y2 = (base2 * x).into_affine();
+
+    let protocol = DLEqualityProtocol::init(x, &base1, &base2, None).unwrap();
+    let mut bytes = vec![];
+    protocol
+        .challenge_contribution(&base1, &y1, &base2, &y2, &mut bytes)
+        .unwrap();
+    let challenge = Fr::rand(&mut rng);
+    let proof: DLEqualityProof<G1Affine> =
+        from_bytes(&to_bytes(&protocol.gen_proof(&challenge)).unwrap()).unwrap();
+    proof.verify(&base1, &y1, &base2, &y2, &challenge).unwrap();
+
+    let y3 = (base2 * Fr::rand(&mut rng)).into_affine();
+    let err = proof
+        .verify(&base1, &y1, &base2, &y3, &challenge)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    let protocol = PoKProtocol::init(vec![x], &[base1], BTreeMap::new()).unwrap();
+    let proof = protocol.gen_proof(&challenge).unwrap();
+    proof.verify(&[base1], &y1, &challenge).unwrap();
+    let err = proof.verify(&[base1], &y2, &challenge).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+}
@@ -0,0 +1,203 @@
+#![cfg(target_arch = "wasm32")]
+extern crate wasm_bindgen_test;
+
+use ark_ec::CurveGroup;
+use dock_crypto_wasm::{
+    native::{self, threshold_saver::ThresholdDecryptors, ErrorCode},
+    Fr,
+};
+use wasm_bindgen_test::*;
+
+mod common;
+use common::gen_encoded_msgs;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn saver_threshold_decryption() {
+    use saver::encryption::Encryption;
+
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    let (snark_pk, shares, share_pks, ek, dk) =
+        native::threshold_saver::threshold_decryptor_setup(chunk_bit_size, &enc_gens, 2, 3)
+            .unwrap();
+    let snark_vk = snark_pk.pk.vk.clone();
+
+    let msg = gen_encoded_msgs(1)[0];
+    let (ct, _) = Encryption::encrypt_given_snark_vk(
+        &mut ark_std::test_rng(),
+        &msg,
+        &ek,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .unwrap();
+
+    let partials = [&shares[2], &shares[0]]
+        .iter()
+        .map(|s| native::threshold_saver::partial_decrypt(&ct, s, &enc_gens).unwrap())
+        .collect::<Vec<_>>();
+    native::threshold_saver::verify_partial_decryption(&partials[0], &ct, &share_pks[2], &enc_gens)
+        .unwrap();
+    let err = native::threshold_saver::verify_partial_decryption(
+        &partials[0],
+        &ct,
+        &share_pks[1],
+        &enc_gens,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    let decryptors = ThresholdDecryptors {
+        threshold: 2,
+        share_public_keys: share_pks.clone(),
+    };
+    let err = native::threshold_saver::combine_partial_decryptions(
+        &ct,
+        &partials[..1],
+        &decryptors,
+        &enc_gens,
+        &dk,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidArgument);
+
+    // A partial decryption checked against another decryptor's public key is rejected
+    let mut swapped = partials.clone();
+    swapped[0].id = 2;
+    let err = native::threshold_saver::combine_partial_decryptions(
+        &ct,
+        &swapped,
+        &decryptors,
+        &enc_gens,
+        &dk,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    let (decrypted, nu) = native::threshold_saver::combine_partial_decryptions(
+        &ct,
+        &partials,
+        &decryptors,
+        &enc_gens,
+        &dk,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .unwrap();
+    assert_eq!(decrypted, msg);
+    native::saver::verify_decryption(
+        &ct,
+        &decrypted,
+        &nu,
+        dk,
+        &snark_vk,
+        enc_gens,
+        chunk_bit_size,
+    )
+    .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn saver_threshold_decryption_with_contributed_encryption_key() {
+    use saver::encryption::Encryption;
+    use secret_sharing_and_dkg::shamir_ss::deal_random_secret;
+
+    let chunk_bit_size = 8;
+    let enc_gens = native::saver::generate_encryption_generators(None).unwrap();
+    // Stands in for the shares and threshold public key a DKG would create
+    let (secret, shares, _) = deal_random_secret::<_, Fr>(&mut ark_std::test_rng(), 2, 3).unwrap();
+    let public_key = (enc_gens.H * secret).into_affine();
+    let share_pks = shares
+        .0
+        .iter()
+        .map(|s| native::threshold_saver::share_public_key(s, &enc_gens))
+        .collect::<Vec<_>>();
+
+    let snark_pk = native::threshold_saver::snark_setup(chunk_bit_size, &enc_gens).unwrap();
+    let snark_vk = snark_pk.pk.vk.clone();
+    let contributions = (0..3)
+        .map(|_| {
+            native::threshold_saver::encryption_key_contribution(
+                &snark_pk,
+                &enc_gens,
+                chunk_bit_size,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for c in &contributions {
+        native::threshold_saver::verify_encryption_key_contribution(
+            c,
+            &snark_pk,
+            &enc_gens,
+            chunk_bit_size,
+        )
+        .unwrap();
+    }
+
+    // A contribution whose elements don't share the discrete logs is rejected
+    let mut bad = contributions.clone();
+    bad[1].h.swap(0, 1);
+    let err = native::threshold_saver::decryptor_setup_given_public_key(
+        chunk_bit_size,
+        &enc_gens,
+        &snark_pk,
+        &public_key,
+        &bad,
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidProof);
+
+    let (ek, dk) = native::threshold_saver::decryptor_setup_given_public_key(
+        chunk_bit_size,
+        &enc_gens,
+        &snark_pk,
+        &public_key,
+        &contributions,
+    )
+    .unwrap();
+
+    let msg = gen_encoded_msgs(1)[0];
+    let (ct, _) = Encryption::encrypt_given_snark_vk(
+        &mut ark_std::test_rng(),
+        &msg,
+        &ek,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .unwrap();
+    let partials = [&shares.0[1], &shares.0[2]]
+        .iter()
+        .map(|s| native::threshold_saver::partial_decrypt(&ct, s, &enc_gens).unwrap())
+        .collect::<Vec<_>>();
+    let (decrypted, nu) = native::threshold_saver::combine_partial_decryptions(
+        &ct,
+        &partials,
+        &ThresholdDecryptors {
+            threshold: 2,
+            share_public_keys: share_pks,
+        },
+        &enc_gens,
+        &dk,
+        &snark_vk,
+        chunk_bit_size,
+    )
+    .unwrap();
+    assert_eq!(decrypted, msg);
+    native::saver::verify_decryption(
+        &ct,
+        &decrypted,
+        &nu,
+        dk,
+        &snark_vk,
+        enc_gens,
+        chunk_bit_size,
+    )
+    .unwrap();
+}